@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -975,58 +976,75 @@ impl BatchPutAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateItemName" => {
-                        return RusotoError::Service(BatchPutAttributesError::DuplicateItemName(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            BatchPutAttributesError::DuplicateItemName,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            BatchPutAttributesError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchPutAttributesError::InvalidParameterValue,
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(BatchPutAttributesError::MissingParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            BatchPutAttributesError::MissingParameter,
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(BatchPutAttributesError::NoSuchDomain(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            BatchPutAttributesError::NoSuchDomain,
+                        )
                     }
                     "NumberDomainAttributesExceeded" => {
-                        return RusotoError::Service(
-                            BatchPutAttributesError::NumberDomainAttributesExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchPutAttributesError::NumberDomainAttributesExceeded,
                         )
                     }
                     "NumberDomainBytesExceeded" => {
-                        return RusotoError::Service(
-                            BatchPutAttributesError::NumberDomainBytesExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchPutAttributesError::NumberDomainBytesExceeded,
                         )
                     }
                     "NumberItemAttributesExceeded" => {
-                        return RusotoError::Service(
-                            BatchPutAttributesError::NumberItemAttributesExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchPutAttributesError::NumberItemAttributesExceeded,
                         )
                     }
                     "NumberSubmittedAttributesExceeded" => {
-                        return RusotoError::Service(
-                            BatchPutAttributesError::NumberSubmittedAttributesExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchPutAttributesError::NumberSubmittedAttributesExceeded,
                         )
                     }
                     "NumberSubmittedItemsExceeded" => {
-                        return RusotoError::Service(
-                            BatchPutAttributesError::NumberSubmittedItemsExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchPutAttributesError::NumberSubmittedItemsExceeded,
                         )
                     }
                     _ => {}
@@ -1089,19 +1107,28 @@ impl CreateDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(CreateDomainError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDomainError::InvalidParameterValue,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(CreateDomainError::MissingParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDomainError::MissingParameter,
+                        )
                     }
                     "NumberDomainsExceeded" => {
-                        return RusotoError::Service(CreateDomainError::NumberDomainsExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDomainError::NumberDomainsExceeded,
+                        )
                     }
                     _ => {}
                 }
@@ -1151,24 +1178,36 @@ impl DeleteAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AttributeDoesNotExist" => {
-                        return RusotoError::Service(DeleteAttributesError::AttributeDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAttributesError::AttributeDoesNotExist,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DeleteAttributesError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAttributesError::InvalidParameterValue,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(DeleteAttributesError::MissingParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAttributesError::MissingParameter,
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(DeleteAttributesError::NoSuchDomain(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAttributesError::NoSuchDomain,
+                        )
                     }
                     _ => {}
                 }
@@ -1213,9 +1252,12 @@ impl DeleteDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "MissingParameter" => {
-                        return RusotoError::Service(DeleteDomainError::MissingParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDomainError::MissingParameter,
+                        )
                     }
                     _ => {}
                 }
@@ -1259,14 +1301,20 @@ impl DomainMetadataError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "MissingParameter" => {
-                        return RusotoError::Service(DomainMetadataError::MissingParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DomainMetadataError::MissingParameter,
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(DomainMetadataError::NoSuchDomain(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DomainMetadataError::NoSuchDomain,
+                        )
                     }
                     _ => {}
                 }
@@ -1313,19 +1361,28 @@ impl GetAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(GetAttributesError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetAttributesError::InvalidParameterValue,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(GetAttributesError::MissingParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetAttributesError::MissingParameter,
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(GetAttributesError::NoSuchDomain(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetAttributesError::NoSuchDomain,
+                        )
                     }
                     _ => {}
                 }
@@ -1371,14 +1428,20 @@ impl ListDomainsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(ListDomainsError::InvalidNextToken(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListDomainsError::InvalidNextToken,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(ListDomainsError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListDomainsError::InvalidParameterValue,
+                        )
                     }
                     _ => {}
                 }
@@ -1433,40 +1496,59 @@ impl PutAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AttributeDoesNotExist" => {
-                        return RusotoError::Service(PutAttributesError::AttributeDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutAttributesError::AttributeDoesNotExist,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(PutAttributesError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutAttributesError::InvalidParameterValue,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(PutAttributesError::MissingParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutAttributesError::MissingParameter,
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(PutAttributesError::NoSuchDomain(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutAttributesError::NoSuchDomain,
+                        )
                     }
                     "NumberDomainAttributesExceeded" => {
-                        return RusotoError::Service(
-                            PutAttributesError::NumberDomainAttributesExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutAttributesError::NumberDomainAttributesExceeded,
                         )
                     }
                     "NumberDomainBytesExceeded" => {
-                        return RusotoError::Service(PutAttributesError::NumberDomainBytesExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutAttributesError::NumberDomainBytesExceeded,
+                        )
                     }
                     "NumberItemAttributesExceeded" => {
-                        return RusotoError::Service(
-                            PutAttributesError::NumberItemAttributesExceeded(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutAttributesError::NumberItemAttributesExceeded,
                         )
                     }
                     _ => {}
@@ -1531,49 +1613,76 @@ impl SelectError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(SelectError::InvalidNextToken(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SelectError::InvalidNextToken,
+                        )
                     }
                     "InvalidNumberPredicates" => {
-                        return RusotoError::Service(SelectError::InvalidNumberPredicates(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SelectError::InvalidNumberPredicates,
+                        )
                     }
                     "InvalidNumberValueTests" => {
-                        return RusotoError::Service(SelectError::InvalidNumberValueTests(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SelectError::InvalidNumberValueTests,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(SelectError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SelectError::InvalidParameterValue,
+                        )
                     }
                     "InvalidQueryExpression" => {
-                        return RusotoError::Service(SelectError::InvalidQueryExpression(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SelectError::InvalidQueryExpression,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(SelectError::MissingParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SelectError::MissingParameter,
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(SelectError::NoSuchDomain(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SelectError::NoSuchDomain,
+                        )
                     }
                     "RequestTimeout" => {
-                        return RusotoError::Service(SelectError::RequestTimeout(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SelectError::RequestTimeout,
+                        )
                     }
                     "TooManyRequestedAttributes" => {
-                        return RusotoError::Service(SelectError::TooManyRequestedAttributes(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SelectError::TooManyRequestedAttributes,
+                        )
                     }
                     _ => {}
                 }
@@ -1677,11 +1786,12 @@ pub struct SimpleDbClient {
 impl SimpleDbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SimpleDbClient {
         SimpleDbClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SimpleDB"),
         }
     }
 
@@ -1696,12 +1806,32 @@ impl SimpleDbClient {
     {
         SimpleDbClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SimpleDB"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SimpleDbClient {
-        SimpleDbClient { client, region }
+        SimpleDbClient {
+            client,
+            region: region_for_service(region, "SimpleDB"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SimpleDbClient {
+        SimpleDbClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SimpleDB"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SimpleDbClient {
+        SimpleDbClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1713,6 +1843,8 @@ impl SimpleDb for SimpleDbClient {
         input: BatchDeleteAttributesRequest,
     ) -> Result<(), RusotoError<BatchDeleteAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("BatchDeleteAttributes");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("BatchDeleteAttributes");
         let mut params = params;
         BatchDeleteAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -1733,6 +1865,8 @@ impl SimpleDb for SimpleDbClient {
         input: BatchPutAttributesRequest,
     ) -> Result<(), RusotoError<BatchPutAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("BatchPutAttributes");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("BatchPutAttributes");
         let mut params = params;
         BatchPutAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -1753,6 +1887,8 @@ impl SimpleDb for SimpleDbClient {
         input: CreateDomainRequest,
     ) -> Result<(), RusotoError<CreateDomainError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("CreateDomain");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("CreateDomain");
         let mut params = params;
         CreateDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -1773,6 +1909,8 @@ impl SimpleDb for SimpleDbClient {
         input: DeleteAttributesRequest,
     ) -> Result<(), RusotoError<DeleteAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("DeleteAttributes");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("DeleteAttributes");
         let mut params = params;
         DeleteAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -1793,6 +1931,8 @@ impl SimpleDb for SimpleDbClient {
         input: DeleteDomainRequest,
     ) -> Result<(), RusotoError<DeleteDomainError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("DeleteDomain");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("DeleteDomain");
         let mut params = params;
         DeleteDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -1813,6 +1953,8 @@ impl SimpleDb for SimpleDbClient {
         input: DomainMetadataRequest,
     ) -> Result<DomainMetadataResult, RusotoError<DomainMetadataError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("DomainMetadata");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("DomainMetadata");
         let mut params = params;
         DomainMetadataRequestSerializer::serialize(&mut params, "", &input);
@@ -1844,6 +1986,8 @@ impl SimpleDb for SimpleDbClient {
         input: GetAttributesRequest,
     ) -> Result<GetAttributesResult, RusotoError<GetAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("GetAttributes");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("GetAttributes");
         let mut params = params;
         GetAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -1875,6 +2019,8 @@ impl SimpleDb for SimpleDbClient {
         input: ListDomainsRequest,
     ) -> Result<ListDomainsResult, RusotoError<ListDomainsError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("ListDomains");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("ListDomains");
         let mut params = params;
         ListDomainsRequestSerializer::serialize(&mut params, "", &input);
@@ -1905,6 +2051,8 @@ impl SimpleDb for SimpleDbClient {
         input: PutAttributesRequest,
     ) -> Result<(), RusotoError<PutAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("PutAttributes");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("PutAttributes");
         let mut params = params;
         PutAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -1922,6 +2070,8 @@ impl SimpleDb for SimpleDbClient {
     /// <p> The <code>Select</code> operation returns a set of attributes for <code>ItemNames</code> that match the select expression. <code>Select</code> is similar to the standard SQL SELECT statement. </p> <p> The total size of the response cannot exceed 1 MB in total size. Amazon SimpleDB automatically adjusts the number of items returned per page to enforce this limit. For example, if the client asks to retrieve 2500 items, but each individual item is 10 kB in size, the system returns 100 items and an appropriate <code>NextToken</code> so the client can access the next page of results. </p> <p> For information on how to construct select expressions, see Using Select to Create Amazon SimpleDB Queries in the Developer Guide. </p>
     async fn select(&self, input: SelectRequest) -> Result<SelectResult, RusotoError<SelectError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("Select");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("Select");
         let mut params = params;
         SelectRequestSerializer::serialize(&mut params, "", &input);
@@ -1946,3 +2096,157 @@ impl SimpleDb for SimpleDbClient {
         Ok(result)
     }
 }
+
+/// A blocking client for the Amazon SimpleDB API, running the calls of a `SimpleDbClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SimpleDbClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SimpleDbBlockingClient {
+    client: SimpleDbClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SimpleDbBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SimpleDbBlockingClient {
+        SimpleDbBlockingClient::from_client(SimpleDbClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SimpleDbBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SimpleDbBlockingClient::from_client(SimpleDbClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> SimpleDbBlockingClient {
+        SimpleDbBlockingClient::from_client(SimpleDbClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SimpleDbBlockingClient {
+        SimpleDbBlockingClient::from_client(SimpleDbClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SimpleDbClient) -> SimpleDbBlockingClient {
+        SimpleDbBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> SimpleDbBlockingClient {
+        SimpleDbBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SimpleDbBlockingClient {
+        SimpleDbBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`SimpleDb::batch_delete_attributes`].
+    pub fn batch_delete_attributes(
+        &self,
+        input: BatchDeleteAttributesRequest,
+    ) -> Result<(), RusotoError<BatchDeleteAttributesError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_attributes(input))
+    }
+
+    /// Blocking version of [`SimpleDb::batch_put_attributes`].
+    pub fn batch_put_attributes(
+        &self,
+        input: BatchPutAttributesRequest,
+    ) -> Result<(), RusotoError<BatchPutAttributesError>> {
+        self.runtime
+            .block_on(self.client.batch_put_attributes(input))
+    }
+
+    /// Blocking version of [`SimpleDb::create_domain`].
+    pub fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<(), RusotoError<CreateDomainError>> {
+        self.runtime.block_on(self.client.create_domain(input))
+    }
+
+    /// Blocking version of [`SimpleDb::delete_attributes`].
+    pub fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> Result<(), RusotoError<DeleteAttributesError>> {
+        self.runtime.block_on(self.client.delete_attributes(input))
+    }
+
+    /// Blocking version of [`SimpleDb::delete_domain`].
+    pub fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<(), RusotoError<DeleteDomainError>> {
+        self.runtime.block_on(self.client.delete_domain(input))
+    }
+
+    /// Blocking version of [`SimpleDb::domain_metadata`].
+    pub fn domain_metadata(
+        &self,
+        input: DomainMetadataRequest,
+    ) -> Result<DomainMetadataResult, RusotoError<DomainMetadataError>> {
+        self.runtime.block_on(self.client.domain_metadata(input))
+    }
+
+    /// Blocking version of [`SimpleDb::get_attributes`].
+    pub fn get_attributes(
+        &self,
+        input: GetAttributesRequest,
+    ) -> Result<GetAttributesResult, RusotoError<GetAttributesError>> {
+        self.runtime.block_on(self.client.get_attributes(input))
+    }
+
+    /// Blocking version of [`SimpleDb::list_domains`].
+    pub fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<ListDomainsResult, RusotoError<ListDomainsError>> {
+        self.runtime.block_on(self.client.list_domains(input))
+    }
+
+    /// Blocking version of [`SimpleDb::put_attributes`].
+    pub fn put_attributes(
+        &self,
+        input: PutAttributesRequest,
+    ) -> Result<(), RusotoError<PutAttributesError>> {
+        self.runtime.block_on(self.client.put_attributes(input))
+    }
+
+    /// Blocking version of [`SimpleDb::select`].
+    pub fn select(&self, input: SelectRequest) -> Result<SelectResult, RusotoError<SelectError>> {
+        self.runtime.block_on(self.client.select(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -878,20 +879,38 @@ impl CancelRotateSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(CancelRotateSecretError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelRotateSecretError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelRotateSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelRotateSecretError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelRotateSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelRotateSecretError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelRotateSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelRotateSecretError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -938,35 +957,78 @@ impl CreateSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionFailure" => {
-                    return RusotoError::Service(CreateSecretError::EncryptionFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSecretError::EncryptionFailure,
+                    )
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(CreateSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSecretError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSecretError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSecretError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSecretError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSecretError::LimitExceeded,
+                    )
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(CreateSecretError::MalformedPolicyDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSecretError::MalformedPolicyDocument,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateSecretError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSecretError::PreconditionNotMet,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateSecretError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSecretError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSecretError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1006,19 +1068,30 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourcePolicyError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourcePolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourcePolicyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1054,18 +1127,38 @@ impl DeleteSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeleteSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSecretError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSecretError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSecretError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSecretError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1098,12 +1191,22 @@ impl DescribeSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSecretError::InternalServiceError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSecretError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1136,17 +1239,30 @@ impl GetRandomPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetRandomPasswordError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRandomPasswordError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetRandomPasswordError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRandomPasswordError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetRandomPasswordError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRandomPasswordError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1180,17 +1296,30 @@ impl GetResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetResourcePolicyError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourcePolicyError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResourcePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePolicyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1228,21 +1357,46 @@ impl GetSecretValueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DecryptionFailure" => {
-                    return RusotoError::Service(GetSecretValueError::DecryptionFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSecretValueError::DecryptionFailure,
+                    )
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetSecretValueError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSecretValueError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetSecretValueError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSecretValueError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSecretValueError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSecretValueError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSecretValueError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSecretValueError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1278,21 +1432,30 @@ impl ListSecretVersionIdsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListSecretVersionIdsError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSecretVersionIdsError::InternalServiceError,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSecretVersionIdsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSecretVersionIdsError::InvalidNextToken,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSecretVersionIdsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSecretVersionIdsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1326,15 +1489,30 @@ impl ListSecretsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListSecretsError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecretsError::InternalServiceError,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSecretsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecretsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSecretsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecretsError::InvalidParameter,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1374,28 +1552,54 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(PutResourcePolicyError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResourcePolicyError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourcePolicyError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourcePolicyError::InvalidRequest,
+                    )
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(PutResourcePolicyError::MalformedPolicyDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResourcePolicyError::MalformedPolicyDocument,
+                    )
                 }
                 "PublicPolicyException" => {
-                    return RusotoError::Service(PutResourcePolicyError::PublicPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourcePolicyError::PublicPolicy,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourcePolicyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1440,27 +1644,62 @@ impl PutSecretValueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionFailure" => {
-                    return RusotoError::Service(PutSecretValueError::EncryptionFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSecretValueError::EncryptionFailure,
+                    )
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(PutSecretValueError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSecretValueError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutSecretValueError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSecretValueError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutSecretValueError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSecretValueError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSecretValueError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSecretValueError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(PutSecretValueError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSecretValueError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutSecretValueError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSecretValueError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1500,18 +1739,38 @@ impl RestoreSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(RestoreSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreSecretError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RestoreSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreSecretError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RestoreSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreSecretError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreSecretError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1548,18 +1807,38 @@ impl RotateSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(RotateSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RotateSecretError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RotateSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RotateSecretError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RotateSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RotateSecretError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RotateSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RotateSecretError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1596,18 +1875,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1644,18 +1943,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1702,35 +2021,78 @@ impl UpdateSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionFailure" => {
-                    return RusotoError::Service(UpdateSecretError::EncryptionFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecretError::EncryptionFailure,
+                    )
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(UpdateSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecretError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecretError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecretError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateSecretError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecretError::LimitExceeded,
+                    )
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(UpdateSecretError::MalformedPolicyDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSecretError::MalformedPolicyDocument,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(UpdateSecretError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecretError::PreconditionNotMet,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateSecretError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecretError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecretError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1774,31 +2136,46 @@ impl UpdateSecretVersionStageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(
-                        UpdateSecretVersionStageError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecretVersionStageError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateSecretVersionStageError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSecretVersionStageError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSecretVersionStageError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSecretVersionStageError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateSecretVersionStageError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSecretVersionStageError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSecretVersionStageError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSecretVersionStageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1840,31 +2217,46 @@ impl ValidateResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ValidateResourcePolicyError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ValidateResourcePolicyError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ValidateResourcePolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ValidateResourcePolicyError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ValidateResourcePolicyError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ValidateResourcePolicyError::InvalidRequest,
+                    )
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(
-                        ValidateResourcePolicyError::MalformedPolicyDocument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ValidateResourcePolicyError::MalformedPolicyDocument,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ValidateResourcePolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ValidateResourcePolicyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2013,11 +2405,12 @@ pub struct SecretsManagerClient {
 impl SecretsManagerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SecretsManagerClient {
         SecretsManagerClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Secrets Manager"),
         }
     }
 
@@ -2032,12 +2425,32 @@ impl SecretsManagerClient {
     {
         SecretsManagerClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Secrets Manager"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SecretsManagerClient {
-        SecretsManagerClient { client, region }
+        SecretsManagerClient {
+            client,
+            region: region_for_service(region, "Secrets Manager"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SecretsManagerClient {
+        SecretsManagerClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Secrets Manager"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SecretsManagerClient {
+        SecretsManagerClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2049,6 +2462,7 @@ impl SecretsManager for SecretsManagerClient {
         input: CancelRotateSecretRequest,
     ) -> Result<CancelRotateSecretResponse, RusotoError<CancelRotateSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelRotateSecret");
         request.add_header("x-amz-target", "secretsmanager.CancelRotateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2067,6 +2481,7 @@ impl SecretsManager for SecretsManagerClient {
         input: CreateSecretRequest,
     ) -> Result<CreateSecretResponse, RusotoError<CreateSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSecret");
         request.add_header("x-amz-target", "secretsmanager.CreateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2085,6 +2500,7 @@ impl SecretsManager for SecretsManagerClient {
         input: DeleteResourcePolicyRequest,
     ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteResourcePolicy");
         request.add_header("x-amz-target", "secretsmanager.DeleteResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2104,6 +2520,7 @@ impl SecretsManager for SecretsManagerClient {
         input: DeleteSecretRequest,
     ) -> Result<DeleteSecretResponse, RusotoError<DeleteSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSecret");
         request.add_header("x-amz-target", "secretsmanager.DeleteSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2122,6 +2539,7 @@ impl SecretsManager for SecretsManagerClient {
         input: DescribeSecretRequest,
     ) -> Result<DescribeSecretResponse, RusotoError<DescribeSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSecret");
         request.add_header("x-amz-target", "secretsmanager.DescribeSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2140,6 +2558,7 @@ impl SecretsManager for SecretsManagerClient {
         input: GetRandomPasswordRequest,
     ) -> Result<GetRandomPasswordResponse, RusotoError<GetRandomPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRandomPassword");
         request.add_header("x-amz-target", "secretsmanager.GetRandomPassword");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2158,6 +2577,7 @@ impl SecretsManager for SecretsManagerClient {
         input: GetResourcePolicyRequest,
     ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResourcePolicy");
         request.add_header("x-amz-target", "secretsmanager.GetResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2176,6 +2596,7 @@ impl SecretsManager for SecretsManagerClient {
         input: GetSecretValueRequest,
     ) -> Result<GetSecretValueResponse, RusotoError<GetSecretValueError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSecretValue");
         request.add_header("x-amz-target", "secretsmanager.GetSecretValue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2194,6 +2615,7 @@ impl SecretsManager for SecretsManagerClient {
         input: ListSecretVersionIdsRequest,
     ) -> Result<ListSecretVersionIdsResponse, RusotoError<ListSecretVersionIdsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSecretVersionIds");
         request.add_header("x-amz-target", "secretsmanager.ListSecretVersionIds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2213,6 +2635,7 @@ impl SecretsManager for SecretsManagerClient {
         input: ListSecretsRequest,
     ) -> Result<ListSecretsResponse, RusotoError<ListSecretsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSecrets");
         request.add_header("x-amz-target", "secretsmanager.ListSecrets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2231,6 +2654,7 @@ impl SecretsManager for SecretsManagerClient {
         input: PutResourcePolicyRequest,
     ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutResourcePolicy");
         request.add_header("x-amz-target", "secretsmanager.PutResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2249,6 +2673,7 @@ impl SecretsManager for SecretsManagerClient {
         input: PutSecretValueRequest,
     ) -> Result<PutSecretValueResponse, RusotoError<PutSecretValueError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutSecretValue");
         request.add_header("x-amz-target", "secretsmanager.PutSecretValue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2267,6 +2692,7 @@ impl SecretsManager for SecretsManagerClient {
         input: RestoreSecretRequest,
     ) -> Result<RestoreSecretResponse, RusotoError<RestoreSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RestoreSecret");
         request.add_header("x-amz-target", "secretsmanager.RestoreSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2285,6 +2711,7 @@ impl SecretsManager for SecretsManagerClient {
         input: RotateSecretRequest,
     ) -> Result<RotateSecretResponse, RusotoError<RotateSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RotateSecret");
         request.add_header("x-amz-target", "secretsmanager.RotateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2303,6 +2730,7 @@ impl SecretsManager for SecretsManagerClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "secretsmanager.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2320,6 +2748,7 @@ impl SecretsManager for SecretsManagerClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "secretsmanager.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2337,6 +2766,7 @@ impl SecretsManager for SecretsManagerClient {
         input: UpdateSecretRequest,
     ) -> Result<UpdateSecretResponse, RusotoError<UpdateSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSecret");
         request.add_header("x-amz-target", "secretsmanager.UpdateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2355,6 +2785,7 @@ impl SecretsManager for SecretsManagerClient {
         input: UpdateSecretVersionStageRequest,
     ) -> Result<UpdateSecretVersionStageResponse, RusotoError<UpdateSecretVersionStageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSecretVersionStage");
         request.add_header("x-amz-target", "secretsmanager.UpdateSecretVersionStage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2374,6 +2805,7 @@ impl SecretsManager for SecretsManagerClient {
         input: ValidateResourcePolicyRequest,
     ) -> Result<ValidateResourcePolicyResponse, RusotoError<ValidateResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ValidateResourcePolicy");
         request.add_header("x-amz-target", "secretsmanager.ValidateResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2387,3 +2819,243 @@ impl SecretsManager for SecretsManagerClient {
             .deserialize::<ValidateResourcePolicyResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Secrets Manager API, running the calls of a `SecretsManagerClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SecretsManagerClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SecretsManagerBlockingClient {
+    client: SecretsManagerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SecretsManagerBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SecretsManagerBlockingClient {
+        SecretsManagerBlockingClient::from_client(SecretsManagerClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SecretsManagerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SecretsManagerBlockingClient::from_client(SecretsManagerClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> SecretsManagerBlockingClient {
+        SecretsManagerBlockingClient::from_client(SecretsManagerClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SecretsManagerBlockingClient {
+        SecretsManagerBlockingClient::from_client(SecretsManagerClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SecretsManagerClient) -> SecretsManagerBlockingClient {
+        SecretsManagerBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> SecretsManagerBlockingClient {
+        SecretsManagerBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SecretsManagerBlockingClient {
+        SecretsManagerBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`SecretsManager::cancel_rotate_secret`].
+    pub fn cancel_rotate_secret(
+        &self,
+        input: CancelRotateSecretRequest,
+    ) -> Result<CancelRotateSecretResponse, RusotoError<CancelRotateSecretError>> {
+        self.runtime
+            .block_on(self.client.cancel_rotate_secret(input))
+    }
+
+    /// Blocking version of [`SecretsManager::create_secret`].
+    pub fn create_secret(
+        &self,
+        input: CreateSecretRequest,
+    ) -> Result<CreateSecretResponse, RusotoError<CreateSecretError>> {
+        self.runtime.block_on(self.client.create_secret(input))
+    }
+
+    /// Blocking version of [`SecretsManager::delete_resource_policy`].
+    pub fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_policy(input))
+    }
+
+    /// Blocking version of [`SecretsManager::delete_secret`].
+    pub fn delete_secret(
+        &self,
+        input: DeleteSecretRequest,
+    ) -> Result<DeleteSecretResponse, RusotoError<DeleteSecretError>> {
+        self.runtime.block_on(self.client.delete_secret(input))
+    }
+
+    /// Blocking version of [`SecretsManager::describe_secret`].
+    pub fn describe_secret(
+        &self,
+        input: DescribeSecretRequest,
+    ) -> Result<DescribeSecretResponse, RusotoError<DescribeSecretError>> {
+        self.runtime.block_on(self.client.describe_secret(input))
+    }
+
+    /// Blocking version of [`SecretsManager::get_random_password`].
+    pub fn get_random_password(
+        &self,
+        input: GetRandomPasswordRequest,
+    ) -> Result<GetRandomPasswordResponse, RusotoError<GetRandomPasswordError>> {
+        self.runtime
+            .block_on(self.client.get_random_password(input))
+    }
+
+    /// Blocking version of [`SecretsManager::get_resource_policy`].
+    pub fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyRequest,
+    ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_resource_policy(input))
+    }
+
+    /// Blocking version of [`SecretsManager::get_secret_value`].
+    pub fn get_secret_value(
+        &self,
+        input: GetSecretValueRequest,
+    ) -> Result<GetSecretValueResponse, RusotoError<GetSecretValueError>> {
+        self.runtime.block_on(self.client.get_secret_value(input))
+    }
+
+    /// Blocking version of [`SecretsManager::list_secret_version_ids`].
+    pub fn list_secret_version_ids(
+        &self,
+        input: ListSecretVersionIdsRequest,
+    ) -> Result<ListSecretVersionIdsResponse, RusotoError<ListSecretVersionIdsError>> {
+        self.runtime
+            .block_on(self.client.list_secret_version_ids(input))
+    }
+
+    /// Blocking version of [`SecretsManager::list_secrets`].
+    pub fn list_secrets(
+        &self,
+        input: ListSecretsRequest,
+    ) -> Result<ListSecretsResponse, RusotoError<ListSecretsError>> {
+        self.runtime.block_on(self.client.list_secrets(input))
+    }
+
+    /// Blocking version of [`SecretsManager::put_resource_policy`].
+    pub fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_resource_policy(input))
+    }
+
+    /// Blocking version of [`SecretsManager::put_secret_value`].
+    pub fn put_secret_value(
+        &self,
+        input: PutSecretValueRequest,
+    ) -> Result<PutSecretValueResponse, RusotoError<PutSecretValueError>> {
+        self.runtime.block_on(self.client.put_secret_value(input))
+    }
+
+    /// Blocking version of [`SecretsManager::restore_secret`].
+    pub fn restore_secret(
+        &self,
+        input: RestoreSecretRequest,
+    ) -> Result<RestoreSecretResponse, RusotoError<RestoreSecretError>> {
+        self.runtime.block_on(self.client.restore_secret(input))
+    }
+
+    /// Blocking version of [`SecretsManager::rotate_secret`].
+    pub fn rotate_secret(
+        &self,
+        input: RotateSecretRequest,
+    ) -> Result<RotateSecretResponse, RusotoError<RotateSecretError>> {
+        self.runtime.block_on(self.client.rotate_secret(input))
+    }
+
+    /// Blocking version of [`SecretsManager::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`SecretsManager::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`SecretsManager::update_secret`].
+    pub fn update_secret(
+        &self,
+        input: UpdateSecretRequest,
+    ) -> Result<UpdateSecretResponse, RusotoError<UpdateSecretError>> {
+        self.runtime.block_on(self.client.update_secret(input))
+    }
+
+    /// Blocking version of [`SecretsManager::update_secret_version_stage`].
+    pub fn update_secret_version_stage(
+        &self,
+        input: UpdateSecretVersionStageRequest,
+    ) -> Result<UpdateSecretVersionStageResponse, RusotoError<UpdateSecretVersionStageError>> {
+        self.runtime
+            .block_on(self.client.update_secret_version_stage(input))
+    }
+
+    /// Blocking version of [`SecretsManager::validate_resource_policy`].
+    pub fn validate_resource_policy(
+        &self,
+        input: ValidateResourcePolicyRequest,
+    ) -> Result<ValidateResourcePolicyResponse, RusotoError<ValidateResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.validate_resource_policy(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -6879,21 +6880,46 @@ impl AcceptInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(AcceptInvitationError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInvitationError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(AcceptInvitationError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInvitationError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AcceptInvitationError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInvitationError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AcceptInvitationError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInvitationError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AcceptInvitationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInvitationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6931,18 +6957,38 @@ impl BatchDisableStandardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(BatchDisableStandardsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDisableStandardsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(BatchDisableStandardsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDisableStandardsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDisableStandardsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDisableStandardsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchDisableStandardsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDisableStandardsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6979,18 +7025,38 @@ impl BatchEnableStandardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(BatchEnableStandardsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchEnableStandardsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(BatchEnableStandardsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchEnableStandardsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchEnableStandardsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchEnableStandardsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchEnableStandardsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchEnableStandardsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7027,18 +7093,38 @@ impl BatchImportFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(BatchImportFindingsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchImportFindingsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(BatchImportFindingsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchImportFindingsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchImportFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchImportFindingsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchImportFindingsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchImportFindingsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7075,18 +7161,38 @@ impl BatchUpdateFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(BatchUpdateFindingsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateFindingsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(BatchUpdateFindingsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateFindingsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchUpdateFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateFindingsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchUpdateFindingsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateFindingsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7125,21 +7231,46 @@ impl CreateActionTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateActionTargetError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateActionTargetError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(CreateActionTargetError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateActionTargetError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateActionTargetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateActionTargetError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateActionTargetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateActionTargetError::LimitExceeded,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateActionTargetError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateActionTargetError::ResourceConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7179,21 +7310,46 @@ impl CreateInsightError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateInsightError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInsightError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(CreateInsightError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInsightError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateInsightError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInsightError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateInsightError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInsightError::LimitExceeded,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateInsightError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInsightError::ResourceConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7233,21 +7389,46 @@ impl CreateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateMembersError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMembersError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(CreateMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMembersError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateMembersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMembersError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMembersError::LimitExceeded,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateMembersError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMembersError::ResourceConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7285,18 +7466,38 @@ impl DeclineInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeclineInvitationsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeclineInvitationsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeclineInvitationsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeclineInvitationsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeclineInvitationsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeclineInvitationsError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeclineInvitationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeclineInvitationsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7333,18 +7534,38 @@ impl DeleteActionTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteActionTargetError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteActionTargetError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeleteActionTargetError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteActionTargetError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteActionTargetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteActionTargetError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteActionTargetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteActionTargetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7383,21 +7604,46 @@ impl DeleteInsightError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteInsightError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInsightError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeleteInsightError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInsightError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteInsightError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInsightError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteInsightError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInsightError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteInsightError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInsightError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7437,21 +7683,46 @@ impl DeleteInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteInvitationsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInvitationsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeleteInvitationsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInvitationsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteInvitationsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInvitationsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteInvitationsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInvitationsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteInvitationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInvitationsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7491,21 +7762,46 @@ impl DeleteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteMembersError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMembersError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeleteMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMembersError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteMembersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMembersError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMembersError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMembersError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7543,20 +7839,38 @@ impl DescribeActionTargetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeActionTargetsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeActionTargetsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeActionTargetsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeActionTargetsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeActionTargetsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeActionTargetsError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeActionTargetsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeActionTargetsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7595,21 +7909,46 @@ impl DescribeHubError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeHubError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHubError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeHubError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHubError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeHubError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHubError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeHubError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHubError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeHubError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHubError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7649,26 +7988,38 @@ impl DescribeOrganizationConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeOrganizationConfigurationError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeOrganizationConfigurationError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigurationError::InvalidAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigurationError::InvalidAccess,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigurationError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigurationError::InvalidInput,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigurationError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigurationError::LimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7711,18 +8062,38 @@ impl DescribeProductsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeProductsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProductsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeProductsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProductsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeProductsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProductsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeProductsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProductsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7757,15 +8128,30 @@ impl DescribeStandardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeStandardsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStandardsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeStandardsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStandardsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeStandardsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStandardsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7801,24 +8187,38 @@ impl DescribeStandardsControlsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeStandardsControlsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStandardsControlsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeStandardsControlsError::InvalidAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStandardsControlsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeStandardsControlsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStandardsControlsError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStandardsControlsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStandardsControlsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7859,31 +8259,46 @@ impl DisableImportFindingsForProductError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisableImportFindingsForProductError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableImportFindingsForProductError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(
-                        DisableImportFindingsForProductError::InvalidAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableImportFindingsForProductError::InvalidAccess,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        DisableImportFindingsForProductError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableImportFindingsForProductError::InvalidInput,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        DisableImportFindingsForProductError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableImportFindingsForProductError::LimitExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisableImportFindingsForProductError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableImportFindingsForProductError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7929,26 +8344,38 @@ impl DisableOrganizationAdminAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisableOrganizationAdminAccountError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableOrganizationAdminAccountError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(
-                        DisableOrganizationAdminAccountError::InvalidAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableOrganizationAdminAccountError::InvalidAccess,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        DisableOrganizationAdminAccountError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableOrganizationAdminAccountError::InvalidInput,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        DisableOrganizationAdminAccountError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableOrganizationAdminAccountError::LimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7989,18 +8416,38 @@ impl DisableSecurityHubError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisableSecurityHubError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableSecurityHubError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DisableSecurityHubError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableSecurityHubError::InvalidAccess,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisableSecurityHubError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableSecurityHubError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableSecurityHubError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableSecurityHubError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8041,31 +8488,46 @@ impl DisassociateFromMasterAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateFromMasterAccountError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::InvalidAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateFromMasterAccountError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateFromMasterAccountError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateFromMasterAccountError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateFromMasterAccountError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateFromMasterAccountError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8107,23 +8569,46 @@ impl DisassociateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisassociateMembersError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateMembersError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DisassociateMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateMembersError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateMembersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateMembersError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisassociateMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateMembersError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateMembersError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateMembersError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8165,31 +8650,46 @@ impl EnableImportFindingsForProductError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(EnableImportFindingsForProductError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableImportFindingsForProductError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(
-                        EnableImportFindingsForProductError::InvalidAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableImportFindingsForProductError::InvalidAccess,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableImportFindingsForProductError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableImportFindingsForProductError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        EnableImportFindingsForProductError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableImportFindingsForProductError::LimitExceeded,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
-                        EnableImportFindingsForProductError::ResourceConflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableImportFindingsForProductError::ResourceConflict,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8231,26 +8731,38 @@ impl EnableOrganizationAdminAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(EnableOrganizationAdminAccountError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableOrganizationAdminAccountError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(
-                        EnableOrganizationAdminAccountError::InvalidAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableOrganizationAdminAccountError::InvalidAccess,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableOrganizationAdminAccountError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableOrganizationAdminAccountError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        EnableOrganizationAdminAccountError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableOrganizationAdminAccountError::LimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8289,21 +8801,46 @@ impl EnableSecurityHubError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableSecurityHubError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSecurityHubError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(EnableSecurityHubError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSecurityHubError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(EnableSecurityHubError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSecurityHubError::InvalidAccess,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(EnableSecurityHubError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSecurityHubError::LimitExceeded,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(EnableSecurityHubError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSecurityHubError::ResourceConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8341,18 +8878,38 @@ impl GetEnabledStandardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetEnabledStandardsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEnabledStandardsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetEnabledStandardsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEnabledStandardsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetEnabledStandardsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEnabledStandardsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetEnabledStandardsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEnabledStandardsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8389,18 +8946,38 @@ impl GetFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetFindingsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFindingsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetFindingsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFindingsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFindingsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetFindingsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFindingsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8439,21 +9016,46 @@ impl GetInsightResultsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetInsightResultsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightResultsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetInsightResultsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightResultsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInsightResultsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightResultsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetInsightResultsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightResultsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetInsightResultsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightResultsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8493,21 +9095,46 @@ impl GetInsightsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetInsightsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetInsightsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInsightsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetInsightsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetInsightsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8545,18 +9172,38 @@ impl GetInvitationsCountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetInvitationsCountError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInvitationsCountError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetInvitationsCountError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInvitationsCountError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInvitationsCountError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInvitationsCountError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetInvitationsCountError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInvitationsCountError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8595,21 +9242,46 @@ impl GetMasterAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetMasterAccountError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMasterAccountError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetMasterAccountError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMasterAccountError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMasterAccountError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMasterAccountError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetMasterAccountError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMasterAccountError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMasterAccountError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMasterAccountError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8649,21 +9321,46 @@ impl GetMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetMembersError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMembersError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMembersError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMembersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMembersError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMembersError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMembersError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8703,21 +9400,46 @@ impl InviteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(InviteMembersError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteMembersError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(InviteMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteMembersError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(InviteMembersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteMembersError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(InviteMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteMembersError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InviteMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteMembersError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8755,21 +9477,30 @@ impl ListEnabledProductsForImportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListEnabledProductsForImportError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEnabledProductsForImportError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(ListEnabledProductsForImportError::InvalidAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEnabledProductsForImportError::InvalidAccess,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListEnabledProductsForImportError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEnabledProductsForImportError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8805,18 +9536,38 @@ impl ListInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListInvitationsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInvitationsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(ListInvitationsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInvitationsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListInvitationsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInvitationsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListInvitationsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInvitationsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8853,18 +9604,38 @@ impl ListMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListMembersError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMembersError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(ListMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMembersError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListMembersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMembersError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMembersError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8903,26 +9674,38 @@ impl ListOrganizationAdminAccountsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListOrganizationAdminAccountsError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOrganizationAdminAccountsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(ListOrganizationAdminAccountsError::InvalidAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOrganizationAdminAccountsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListOrganizationAdminAccountsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOrganizationAdminAccountsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOrganizationAdminAccountsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOrganizationAdminAccountsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8957,17 +9740,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9001,15 +9797,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(TagResourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9043,15 +9854,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UntagResourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9087,18 +9913,38 @@ impl UpdateActionTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateActionTargetError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateActionTargetError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(UpdateActionTargetError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateActionTargetError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateActionTargetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateActionTargetError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateActionTargetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateActionTargetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9137,21 +9983,46 @@ impl UpdateFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateFindingsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFindingsError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(UpdateFindingsError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFindingsError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFindingsError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFindingsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFindingsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFindingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFindingsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9191,21 +10062,46 @@ impl UpdateInsightError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateInsightError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInsightError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(UpdateInsightError::InvalidAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInsightError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateInsightError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInsightError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateInsightError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInsightError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateInsightError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInsightError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9245,26 +10141,38 @@ impl UpdateOrganizationConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateOrganizationConfigurationError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateOrganizationConfigurationError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(
-                        UpdateOrganizationConfigurationError::InvalidAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOrganizationConfigurationError::InvalidAccess,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        UpdateOrganizationConfigurationError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOrganizationConfigurationError::InvalidInput,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateOrganizationConfigurationError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOrganizationConfigurationError::LimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9309,31 +10217,46 @@ impl UpdateSecurityHubConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateSecurityHubConfigurationError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSecurityHubConfigurationError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(
-                        UpdateSecurityHubConfigurationError::InvalidAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecurityHubConfigurationError::InvalidAccess,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateSecurityHubConfigurationError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSecurityHubConfigurationError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateSecurityHubConfigurationError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecurityHubConfigurationError::LimitExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateSecurityHubConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecurityHubConfigurationError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9373,22 +10296,38 @@ impl UpdateStandardsControlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateStandardsControlError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStandardsControlError::Internal,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(UpdateStandardsControlError::InvalidAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateStandardsControlError::InvalidAccess,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateStandardsControlError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStandardsControlError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStandardsControlError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateStandardsControlError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9730,11 +10669,12 @@ pub struct SecurityHubClient {
 impl SecurityHubClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SecurityHubClient {
         SecurityHubClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SecurityHub"),
         }
     }
 
@@ -9749,12 +10689,32 @@ impl SecurityHubClient {
     {
         SecurityHubClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SecurityHub"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SecurityHubClient {
-        SecurityHubClient { client, region }
+        SecurityHubClient {
+            client,
+            region: region_for_service(region, "SecurityHub"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SecurityHubClient {
+        SecurityHubClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SecurityHub"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SecurityHubClient {
+        SecurityHubClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -9769,6 +10729,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/master";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("AcceptInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9800,6 +10761,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/standards/deregister";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchDisableStandards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9831,6 +10793,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/standards/register";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchEnableStandards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9862,6 +10825,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/findings/import";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchImportFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9893,6 +10857,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/findings/batchupdate";
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchUpdateFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9924,6 +10889,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/actionTargets";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("CreateActionTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9955,6 +10921,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/insights";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("CreateInsight");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9986,6 +10953,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("CreateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10017,6 +10985,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/invitations/decline";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeclineInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10051,6 +11020,7 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteActionTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10079,6 +11049,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/insights/{insight_arn}", insight_arn = input.insight_arn);
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteInsight");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10107,6 +11078,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/invitations/delete";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10138,6 +11110,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members/delete";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10169,6 +11142,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/actionTargets/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeActionTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10200,6 +11174,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeHub");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10236,6 +11211,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/organization/configuration";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeOrganizationConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10266,6 +11242,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/products";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeProducts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10303,6 +11280,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/standards";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeStandards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10344,6 +11322,7 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeStandardsControls");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10387,6 +11366,7 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisableImportFindingsForProduct");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10420,6 +11400,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/organization/admin/disable";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisableOrganizationAdminAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10452,6 +11433,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisableSecurityHub");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10482,6 +11464,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/master/disassociate";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisassociateFromMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10510,6 +11493,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members/disassociate";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisassociateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10544,6 +11528,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/productSubscriptions";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("EnableImportFindingsForProduct");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10578,6 +11563,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/organization/admin/enable";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("EnableOrganizationAdminAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10609,6 +11595,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("EnableSecurityHub");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10640,6 +11627,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/standards/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetEnabledStandards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10671,6 +11659,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/findings";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10705,6 +11694,7 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetInsightResults");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10733,6 +11723,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/insights/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetInsights");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10763,6 +11754,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/invitations/count";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetInvitationsCount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10790,6 +11782,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/master";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10818,6 +11811,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10849,6 +11843,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members/invite";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("InviteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10881,6 +11876,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/productSubscriptions";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListEnabledProductsForImport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10918,6 +11914,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/invitations";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10955,6 +11952,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10998,6 +11996,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/organization/admin";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListOrganizationAdminAccounts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11035,6 +12034,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11063,6 +12063,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11094,6 +12095,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11131,6 +12133,7 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateActionTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11162,6 +12165,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/findings";
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11193,6 +12197,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/insights/{insight_arn}", insight_arn = input.insight_arn);
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateInsight");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11227,6 +12232,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/organization/configuration";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateOrganizationConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11263,6 +12269,7 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateSecurityHubConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11297,6 +12304,7 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateStandardsControl");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11319,3 +12327,512 @@ impl SecurityHub for SecurityHubClient {
         }
     }
 }
+
+/// A blocking client for the AWS SecurityHub API, running the calls of a `SecurityHubClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SecurityHubClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SecurityHubBlockingClient {
+    client: SecurityHubClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SecurityHubBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SecurityHubBlockingClient {
+        SecurityHubBlockingClient::from_client(SecurityHubClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SecurityHubBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SecurityHubBlockingClient::from_client(SecurityHubClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> SecurityHubBlockingClient {
+        SecurityHubBlockingClient::from_client(SecurityHubClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SecurityHubBlockingClient {
+        SecurityHubBlockingClient::from_client(SecurityHubClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SecurityHubClient) -> SecurityHubBlockingClient {
+        SecurityHubBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> SecurityHubBlockingClient {
+        SecurityHubBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SecurityHubBlockingClient {
+        SecurityHubBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`SecurityHub::accept_invitation`].
+    pub fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> Result<AcceptInvitationResponse, RusotoError<AcceptInvitationError>> {
+        self.runtime.block_on(self.client.accept_invitation(input))
+    }
+
+    /// Blocking version of [`SecurityHub::batch_disable_standards`].
+    pub fn batch_disable_standards(
+        &self,
+        input: BatchDisableStandardsRequest,
+    ) -> Result<BatchDisableStandardsResponse, RusotoError<BatchDisableStandardsError>> {
+        self.runtime
+            .block_on(self.client.batch_disable_standards(input))
+    }
+
+    /// Blocking version of [`SecurityHub::batch_enable_standards`].
+    pub fn batch_enable_standards(
+        &self,
+        input: BatchEnableStandardsRequest,
+    ) -> Result<BatchEnableStandardsResponse, RusotoError<BatchEnableStandardsError>> {
+        self.runtime
+            .block_on(self.client.batch_enable_standards(input))
+    }
+
+    /// Blocking version of [`SecurityHub::batch_import_findings`].
+    pub fn batch_import_findings(
+        &self,
+        input: BatchImportFindingsRequest,
+    ) -> Result<BatchImportFindingsResponse, RusotoError<BatchImportFindingsError>> {
+        self.runtime
+            .block_on(self.client.batch_import_findings(input))
+    }
+
+    /// Blocking version of [`SecurityHub::batch_update_findings`].
+    pub fn batch_update_findings(
+        &self,
+        input: BatchUpdateFindingsRequest,
+    ) -> Result<BatchUpdateFindingsResponse, RusotoError<BatchUpdateFindingsError>> {
+        self.runtime
+            .block_on(self.client.batch_update_findings(input))
+    }
+
+    /// Blocking version of [`SecurityHub::create_action_target`].
+    pub fn create_action_target(
+        &self,
+        input: CreateActionTargetRequest,
+    ) -> Result<CreateActionTargetResponse, RusotoError<CreateActionTargetError>> {
+        self.runtime
+            .block_on(self.client.create_action_target(input))
+    }
+
+    /// Blocking version of [`SecurityHub::create_insight`].
+    pub fn create_insight(
+        &self,
+        input: CreateInsightRequest,
+    ) -> Result<CreateInsightResponse, RusotoError<CreateInsightError>> {
+        self.runtime.block_on(self.client.create_insight(input))
+    }
+
+    /// Blocking version of [`SecurityHub::create_members`].
+    pub fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> Result<CreateMembersResponse, RusotoError<CreateMembersError>> {
+        self.runtime.block_on(self.client.create_members(input))
+    }
+
+    /// Blocking version of [`SecurityHub::decline_invitations`].
+    pub fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> Result<DeclineInvitationsResponse, RusotoError<DeclineInvitationsError>> {
+        self.runtime
+            .block_on(self.client.decline_invitations(input))
+    }
+
+    /// Blocking version of [`SecurityHub::delete_action_target`].
+    pub fn delete_action_target(
+        &self,
+        input: DeleteActionTargetRequest,
+    ) -> Result<DeleteActionTargetResponse, RusotoError<DeleteActionTargetError>> {
+        self.runtime
+            .block_on(self.client.delete_action_target(input))
+    }
+
+    /// Blocking version of [`SecurityHub::delete_insight`].
+    pub fn delete_insight(
+        &self,
+        input: DeleteInsightRequest,
+    ) -> Result<DeleteInsightResponse, RusotoError<DeleteInsightError>> {
+        self.runtime.block_on(self.client.delete_insight(input))
+    }
+
+    /// Blocking version of [`SecurityHub::delete_invitations`].
+    pub fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> Result<DeleteInvitationsResponse, RusotoError<DeleteInvitationsError>> {
+        self.runtime.block_on(self.client.delete_invitations(input))
+    }
+
+    /// Blocking version of [`SecurityHub::delete_members`].
+    pub fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> Result<DeleteMembersResponse, RusotoError<DeleteMembersError>> {
+        self.runtime.block_on(self.client.delete_members(input))
+    }
+
+    /// Blocking version of [`SecurityHub::describe_action_targets`].
+    pub fn describe_action_targets(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> Result<DescribeActionTargetsResponse, RusotoError<DescribeActionTargetsError>> {
+        self.runtime
+            .block_on(self.client.describe_action_targets(input))
+    }
+
+    /// Blocking version of [`SecurityHub::describe_hub`].
+    pub fn describe_hub(
+        &self,
+        input: DescribeHubRequest,
+    ) -> Result<DescribeHubResponse, RusotoError<DescribeHubError>> {
+        self.runtime.block_on(self.client.describe_hub(input))
+    }
+
+    /// Blocking version of [`SecurityHub::describe_organization_configuration`].
+    pub fn describe_organization_configuration(
+        &self,
+    ) -> Result<
+        DescribeOrganizationConfigurationResponse,
+        RusotoError<DescribeOrganizationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_organization_configuration())
+    }
+
+    /// Blocking version of [`SecurityHub::describe_products`].
+    pub fn describe_products(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> Result<DescribeProductsResponse, RusotoError<DescribeProductsError>> {
+        self.runtime.block_on(self.client.describe_products(input))
+    }
+
+    /// Blocking version of [`SecurityHub::describe_standards`].
+    pub fn describe_standards(
+        &self,
+        input: DescribeStandardsRequest,
+    ) -> Result<DescribeStandardsResponse, RusotoError<DescribeStandardsError>> {
+        self.runtime.block_on(self.client.describe_standards(input))
+    }
+
+    /// Blocking version of [`SecurityHub::describe_standards_controls`].
+    pub fn describe_standards_controls(
+        &self,
+        input: DescribeStandardsControlsRequest,
+    ) -> Result<DescribeStandardsControlsResponse, RusotoError<DescribeStandardsControlsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_standards_controls(input))
+    }
+
+    /// Blocking version of [`SecurityHub::disable_import_findings_for_product`].
+    pub fn disable_import_findings_for_product(
+        &self,
+        input: DisableImportFindingsForProductRequest,
+    ) -> Result<
+        DisableImportFindingsForProductResponse,
+        RusotoError<DisableImportFindingsForProductError>,
+    > {
+        self.runtime
+            .block_on(self.client.disable_import_findings_for_product(input))
+    }
+
+    /// Blocking version of [`SecurityHub::disable_organization_admin_account`].
+    pub fn disable_organization_admin_account(
+        &self,
+        input: DisableOrganizationAdminAccountRequest,
+    ) -> Result<
+        DisableOrganizationAdminAccountResponse,
+        RusotoError<DisableOrganizationAdminAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.disable_organization_admin_account(input))
+    }
+
+    /// Blocking version of [`SecurityHub::disable_security_hub`].
+    pub fn disable_security_hub(
+        &self,
+    ) -> Result<DisableSecurityHubResponse, RusotoError<DisableSecurityHubError>> {
+        self.runtime.block_on(self.client.disable_security_hub())
+    }
+
+    /// Blocking version of [`SecurityHub::disassociate_from_master_account`].
+    pub fn disassociate_from_master_account(
+        &self,
+    ) -> Result<
+        DisassociateFromMasterAccountResponse,
+        RusotoError<DisassociateFromMasterAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_from_master_account())
+    }
+
+    /// Blocking version of [`SecurityHub::disassociate_members`].
+    pub fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> Result<DisassociateMembersResponse, RusotoError<DisassociateMembersError>> {
+        self.runtime
+            .block_on(self.client.disassociate_members(input))
+    }
+
+    /// Blocking version of [`SecurityHub::enable_import_findings_for_product`].
+    pub fn enable_import_findings_for_product(
+        &self,
+        input: EnableImportFindingsForProductRequest,
+    ) -> Result<
+        EnableImportFindingsForProductResponse,
+        RusotoError<EnableImportFindingsForProductError>,
+    > {
+        self.runtime
+            .block_on(self.client.enable_import_findings_for_product(input))
+    }
+
+    /// Blocking version of [`SecurityHub::enable_organization_admin_account`].
+    pub fn enable_organization_admin_account(
+        &self,
+        input: EnableOrganizationAdminAccountRequest,
+    ) -> Result<
+        EnableOrganizationAdminAccountResponse,
+        RusotoError<EnableOrganizationAdminAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.enable_organization_admin_account(input))
+    }
+
+    /// Blocking version of [`SecurityHub::enable_security_hub`].
+    pub fn enable_security_hub(
+        &self,
+        input: EnableSecurityHubRequest,
+    ) -> Result<EnableSecurityHubResponse, RusotoError<EnableSecurityHubError>> {
+        self.runtime
+            .block_on(self.client.enable_security_hub(input))
+    }
+
+    /// Blocking version of [`SecurityHub::get_enabled_standards`].
+    pub fn get_enabled_standards(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> Result<GetEnabledStandardsResponse, RusotoError<GetEnabledStandardsError>> {
+        self.runtime
+            .block_on(self.client.get_enabled_standards(input))
+    }
+
+    /// Blocking version of [`SecurityHub::get_findings`].
+    pub fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> Result<GetFindingsResponse, RusotoError<GetFindingsError>> {
+        self.runtime.block_on(self.client.get_findings(input))
+    }
+
+    /// Blocking version of [`SecurityHub::get_insight_results`].
+    pub fn get_insight_results(
+        &self,
+        input: GetInsightResultsRequest,
+    ) -> Result<GetInsightResultsResponse, RusotoError<GetInsightResultsError>> {
+        self.runtime
+            .block_on(self.client.get_insight_results(input))
+    }
+
+    /// Blocking version of [`SecurityHub::get_insights`].
+    pub fn get_insights(
+        &self,
+        input: GetInsightsRequest,
+    ) -> Result<GetInsightsResponse, RusotoError<GetInsightsError>> {
+        self.runtime.block_on(self.client.get_insights(input))
+    }
+
+    /// Blocking version of [`SecurityHub::get_invitations_count`].
+    pub fn get_invitations_count(
+        &self,
+    ) -> Result<GetInvitationsCountResponse, RusotoError<GetInvitationsCountError>> {
+        self.runtime.block_on(self.client.get_invitations_count())
+    }
+
+    /// Blocking version of [`SecurityHub::get_master_account`].
+    pub fn get_master_account(
+        &self,
+    ) -> Result<GetMasterAccountResponse, RusotoError<GetMasterAccountError>> {
+        self.runtime.block_on(self.client.get_master_account())
+    }
+
+    /// Blocking version of [`SecurityHub::get_members`].
+    pub fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> Result<GetMembersResponse, RusotoError<GetMembersError>> {
+        self.runtime.block_on(self.client.get_members(input))
+    }
+
+    /// Blocking version of [`SecurityHub::invite_members`].
+    pub fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> Result<InviteMembersResponse, RusotoError<InviteMembersError>> {
+        self.runtime.block_on(self.client.invite_members(input))
+    }
+
+    /// Blocking version of [`SecurityHub::list_enabled_products_for_import`].
+    pub fn list_enabled_products_for_import(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> Result<ListEnabledProductsForImportResponse, RusotoError<ListEnabledProductsForImportError>>
+    {
+        self.runtime
+            .block_on(self.client.list_enabled_products_for_import(input))
+    }
+
+    /// Blocking version of [`SecurityHub::list_invitations`].
+    pub fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> Result<ListInvitationsResponse, RusotoError<ListInvitationsError>> {
+        self.runtime.block_on(self.client.list_invitations(input))
+    }
+
+    /// Blocking version of [`SecurityHub::list_members`].
+    pub fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> Result<ListMembersResponse, RusotoError<ListMembersError>> {
+        self.runtime.block_on(self.client.list_members(input))
+    }
+
+    /// Blocking version of [`SecurityHub::list_organization_admin_accounts`].
+    pub fn list_organization_admin_accounts(
+        &self,
+        input: ListOrganizationAdminAccountsRequest,
+    ) -> Result<
+        ListOrganizationAdminAccountsResponse,
+        RusotoError<ListOrganizationAdminAccountsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_organization_admin_accounts(input))
+    }
+
+    /// Blocking version of [`SecurityHub::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`SecurityHub::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`SecurityHub::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`SecurityHub::update_action_target`].
+    pub fn update_action_target(
+        &self,
+        input: UpdateActionTargetRequest,
+    ) -> Result<UpdateActionTargetResponse, RusotoError<UpdateActionTargetError>> {
+        self.runtime
+            .block_on(self.client.update_action_target(input))
+    }
+
+    /// Blocking version of [`SecurityHub::update_findings`].
+    pub fn update_findings(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> Result<UpdateFindingsResponse, RusotoError<UpdateFindingsError>> {
+        self.runtime.block_on(self.client.update_findings(input))
+    }
+
+    /// Blocking version of [`SecurityHub::update_insight`].
+    pub fn update_insight(
+        &self,
+        input: UpdateInsightRequest,
+    ) -> Result<UpdateInsightResponse, RusotoError<UpdateInsightError>> {
+        self.runtime.block_on(self.client.update_insight(input))
+    }
+
+    /// Blocking version of [`SecurityHub::update_organization_configuration`].
+    pub fn update_organization_configuration(
+        &self,
+        input: UpdateOrganizationConfigurationRequest,
+    ) -> Result<
+        UpdateOrganizationConfigurationResponse,
+        RusotoError<UpdateOrganizationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_organization_configuration(input))
+    }
+
+    /// Blocking version of [`SecurityHub::update_security_hub_configuration`].
+    pub fn update_security_hub_configuration(
+        &self,
+        input: UpdateSecurityHubConfigurationRequest,
+    ) -> Result<
+        UpdateSecurityHubConfigurationResponse,
+        RusotoError<UpdateSecurityHubConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_security_hub_configuration(input))
+    }
+
+    /// Blocking version of [`SecurityHub::update_standards_control`].
+    pub fn update_standards_control(
+        &self,
+        input: UpdateStandardsControlRequest,
+    ) -> Result<UpdateStandardsControlResponse, RusotoError<UpdateStandardsControlError>> {
+        self.runtime
+            .block_on(self.client.update_standards_control(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -720,48 +721,70 @@ impl AssociateServiceQuotaTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
-                        AssociateServiceQuotaTemplateError::AWSServiceAccessNotEnabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateServiceQuotaTemplateError::AWSServiceAccessNotEnabled,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateServiceQuotaTemplateError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateServiceQuotaTemplateError::AccessDenied,
+                    )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        AssociateServiceQuotaTemplateError::DependencyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateServiceQuotaTemplateError::DependencyAccessDenied,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
-                        AssociateServiceQuotaTemplateError::NoAvailableOrganization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateServiceQuotaTemplateError::NoAvailableOrganization,
                     )
                 }
                 "OrganizationNotInAllFeaturesModeException" => {
-                    return RusotoError::Service(
-                        AssociateServiceQuotaTemplateError::OrganizationNotInAllFeaturesMode(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateServiceQuotaTemplateError::OrganizationNotInAllFeaturesMode,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AssociateServiceQuotaTemplateError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateServiceQuotaTemplateError::Service,
+                    )
                 }
                 "TemplatesNotAvailableInRegionException" => {
-                    return RusotoError::Service(
-                        AssociateServiceQuotaTemplateError::TemplatesNotAvailableInRegion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateServiceQuotaTemplateError::TemplatesNotAvailableInRegion,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        AssociateServiceQuotaTemplateError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateServiceQuotaTemplateError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -825,16 +848,16 @@ impl DeleteServiceQuotaIncreaseRequestFromTemplateError {
     ) -> RusotoError<DeleteServiceQuotaIncreaseRequestFromTemplateError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "AWSServiceAccessNotEnabledException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::AWSServiceAccessNotEnabled(err.msg)),
-"AccessDeniedException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::AccessDenied(err.msg)),
-"DependencyAccessDeniedException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::DependencyAccessDenied(err.msg)),
-"IllegalArgumentException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::IllegalArgument(err.msg)),
-"NoAvailableOrganizationException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::NoAvailableOrganization(err.msg)),
-"NoSuchResourceException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::NoSuchResource(err.msg)),
-"ServiceException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::Service(err.msg)),
-"TemplatesNotAvailableInRegionException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::TemplatesNotAvailableInRegion(err.msg)),
-"TooManyRequestsException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+                                "AWSServiceAccessNotEnabledException" => return RusotoError::service_error(&res, &err.typ, err.msg, DeleteServiceQuotaIncreaseRequestFromTemplateError::AWSServiceAccessNotEnabled),
+"AccessDeniedException" => return RusotoError::service_error(&res, &err.typ, err.msg, DeleteServiceQuotaIncreaseRequestFromTemplateError::AccessDenied),
+"DependencyAccessDeniedException" => return RusotoError::service_error(&res, &err.typ, err.msg, DeleteServiceQuotaIncreaseRequestFromTemplateError::DependencyAccessDenied),
+"IllegalArgumentException" => return RusotoError::service_error(&res, &err.typ, err.msg, DeleteServiceQuotaIncreaseRequestFromTemplateError::IllegalArgument),
+"NoAvailableOrganizationException" => return RusotoError::service_error(&res, &err.typ, err.msg, DeleteServiceQuotaIncreaseRequestFromTemplateError::NoAvailableOrganization),
+"NoSuchResourceException" => return RusotoError::service_error(&res, &err.typ, err.msg, DeleteServiceQuotaIncreaseRequestFromTemplateError::NoSuchResource),
+"ServiceException" => return RusotoError::service_error(&res, &err.typ, err.msg, DeleteServiceQuotaIncreaseRequestFromTemplateError::Service),
+"TemplatesNotAvailableInRegionException" => return RusotoError::service_error(&res, &err.typ, err.msg, DeleteServiceQuotaIncreaseRequestFromTemplateError::TemplatesNotAvailableInRegion),
+"TooManyRequestsException" => return RusotoError::service_error(&res, &err.typ, err.msg, DeleteServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests),
+"ValidationException" => return RusotoError::validation_error(&res, err.msg),
 _ => {}
                             }
         }
@@ -904,50 +927,70 @@ impl DisassociateServiceQuotaTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
-                        DisassociateServiceQuotaTemplateError::AWSServiceAccessNotEnabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateServiceQuotaTemplateError::AWSServiceAccessNotEnabled,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DisassociateServiceQuotaTemplateError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateServiceQuotaTemplateError::AccessDenied,
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        DisassociateServiceQuotaTemplateError::DependencyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateServiceQuotaTemplateError::DependencyAccessDenied,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
-                        DisassociateServiceQuotaTemplateError::NoAvailableOrganization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateServiceQuotaTemplateError::NoAvailableOrganization,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisassociateServiceQuotaTemplateError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateServiceQuotaTemplateError::Service,
+                    )
                 }
                 "ServiceQuotaTemplateNotInUseException" => {
-                    return RusotoError::Service(
-                        DisassociateServiceQuotaTemplateError::ServiceQuotaTemplateNotInUse(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateServiceQuotaTemplateError::ServiceQuotaTemplateNotInUse,
                     )
                 }
                 "TemplatesNotAvailableInRegionException" => {
-                    return RusotoError::Service(
-                        DisassociateServiceQuotaTemplateError::TemplatesNotAvailableInRegion(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateServiceQuotaTemplateError::TemplatesNotAvailableInRegion,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DisassociateServiceQuotaTemplateError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateServiceQuotaTemplateError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1004,29 +1047,46 @@ impl GetAWSDefaultServiceQuotaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAWSDefaultServiceQuotaError::AccessDenied,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::IllegalArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAWSDefaultServiceQuotaError::IllegalArgument,
+                    )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::NoSuchResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAWSDefaultServiceQuotaError::NoSuchResource,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAWSDefaultServiceQuotaError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAWSDefaultServiceQuotaError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1074,54 +1134,70 @@ impl GetAssociationForServiceQuotaTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
-                        GetAssociationForServiceQuotaTemplateError::AWSServiceAccessNotEnabled(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssociationForServiceQuotaTemplateError::AWSServiceAccessNotEnabled,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetAssociationForServiceQuotaTemplateError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssociationForServiceQuotaTemplateError::AccessDenied,
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetAssociationForServiceQuotaTemplateError::DependencyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssociationForServiceQuotaTemplateError::DependencyAccessDenied,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
-                        GetAssociationForServiceQuotaTemplateError::NoAvailableOrganization(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssociationForServiceQuotaTemplateError::NoAvailableOrganization,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
-                        GetAssociationForServiceQuotaTemplateError::Service(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssociationForServiceQuotaTemplateError::Service,
                     )
                 }
                 "ServiceQuotaTemplateNotInUseException" => {
-                    return RusotoError::Service(
-                        GetAssociationForServiceQuotaTemplateError::ServiceQuotaTemplateNotInUse(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssociationForServiceQuotaTemplateError::ServiceQuotaTemplateNotInUse,
                     )
                 }
                 "TemplatesNotAvailableInRegionException" => {
-                    return RusotoError::Service(
-                        GetAssociationForServiceQuotaTemplateError::TemplatesNotAvailableInRegion(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssociationForServiceQuotaTemplateError::TemplatesNotAvailableInRegion,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetAssociationForServiceQuotaTemplateError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssociationForServiceQuotaTemplateError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1182,31 +1258,46 @@ impl GetRequestedServiceQuotaChangeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRequestedServiceQuotaChangeError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRequestedServiceQuotaChangeError::AccessDenied,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
-                        GetRequestedServiceQuotaChangeError::IllegalArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRequestedServiceQuotaChangeError::IllegalArgument,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
-                        GetRequestedServiceQuotaChangeError::NoSuchResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRequestedServiceQuotaChangeError::NoSuchResource,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRequestedServiceQuotaChangeError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRequestedServiceQuotaChangeError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetRequestedServiceQuotaChangeError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRequestedServiceQuotaChangeError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1252,21 +1343,46 @@ impl GetServiceQuotaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetServiceQuotaError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaError::AccessDenied,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(GetServiceQuotaError::IllegalArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaError::IllegalArgument,
+                    )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(GetServiceQuotaError::NoSuchResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaError::NoSuchResource,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetServiceQuotaError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetServiceQuotaError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1316,57 +1432,76 @@ impl GetServiceQuotaIncreaseRequestFromTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
-                        GetServiceQuotaIncreaseRequestFromTemplateError::AWSServiceAccessNotEnabled(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaIncreaseRequestFromTemplateError::AWSServiceAccessNotEnabled,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetServiceQuotaIncreaseRequestFromTemplateError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaIncreaseRequestFromTemplateError::AccessDenied,
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetServiceQuotaIncreaseRequestFromTemplateError::DependencyAccessDenied(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaIncreaseRequestFromTemplateError::DependencyAccessDenied,
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
-                        GetServiceQuotaIncreaseRequestFromTemplateError::IllegalArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaIncreaseRequestFromTemplateError::IllegalArgument,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
-                        GetServiceQuotaIncreaseRequestFromTemplateError::NoAvailableOrganization(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaIncreaseRequestFromTemplateError::NoAvailableOrganization,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
-                        GetServiceQuotaIncreaseRequestFromTemplateError::NoSuchResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaIncreaseRequestFromTemplateError::NoSuchResource,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
-                        GetServiceQuotaIncreaseRequestFromTemplateError::Service(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaIncreaseRequestFromTemplateError::Service,
                     )
                 }
-                "TemplatesNotAvailableInRegionException" => return RusotoError::Service(
-                    GetServiceQuotaIncreaseRequestFromTemplateError::TemplatesNotAvailableInRegion(
-                        err.msg,
-                    ),
+                "TemplatesNotAvailableInRegionException" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    GetServiceQuotaIncreaseRequestFromTemplateError::TemplatesNotAvailableInRegion,
                 ),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1432,34 +1567,54 @@ impl ListAWSDefaultServiceQuotasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAWSDefaultServiceQuotasError::AccessDenied,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::IllegalArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAWSDefaultServiceQuotasError::IllegalArgument,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        ListAWSDefaultServiceQuotasError::InvalidPaginationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAWSDefaultServiceQuotasError::InvalidPaginationToken,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::NoSuchResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAWSDefaultServiceQuotasError::NoSuchResource,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAWSDefaultServiceQuotasError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAWSDefaultServiceQuotasError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1506,38 +1661,54 @@ impl ListRequestedServiceQuotaChangeHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListRequestedServiceQuotaChangeHistoryError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRequestedServiceQuotaChangeHistoryError::AccessDenied,
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
-                        ListRequestedServiceQuotaChangeHistoryError::IllegalArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRequestedServiceQuotaChangeHistoryError::IllegalArgument,
                     )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        ListRequestedServiceQuotaChangeHistoryError::InvalidPaginationToken(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRequestedServiceQuotaChangeHistoryError::InvalidPaginationToken,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
-                        ListRequestedServiceQuotaChangeHistoryError::NoSuchResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRequestedServiceQuotaChangeHistoryError::NoSuchResource,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
-                        ListRequestedServiceQuotaChangeHistoryError::Service(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRequestedServiceQuotaChangeHistoryError::Service,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListRequestedServiceQuotaChangeHistoryError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRequestedServiceQuotaChangeHistoryError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1594,42 +1765,54 @@ impl ListRequestedServiceQuotaChangeHistoryByQuotaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListRequestedServiceQuotaChangeHistoryByQuotaError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRequestedServiceQuotaChangeHistoryByQuotaError::AccessDenied,
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
-                        ListRequestedServiceQuotaChangeHistoryByQuotaError::IllegalArgument(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRequestedServiceQuotaChangeHistoryByQuotaError::IllegalArgument,
                     )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        ListRequestedServiceQuotaChangeHistoryByQuotaError::InvalidPaginationToken(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRequestedServiceQuotaChangeHistoryByQuotaError::InvalidPaginationToken,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
-                        ListRequestedServiceQuotaChangeHistoryByQuotaError::NoSuchResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRequestedServiceQuotaChangeHistoryByQuotaError::NoSuchResource,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
-                        ListRequestedServiceQuotaChangeHistoryByQuotaError::Service(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRequestedServiceQuotaChangeHistoryByQuotaError::Service,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListRequestedServiceQuotaChangeHistoryByQuotaError::TooManyRequests(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRequestedServiceQuotaChangeHistoryByQuotaError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1690,52 +1873,68 @@ impl ListServiceQuotaIncreaseRequestsInTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
-                        ListServiceQuotaIncreaseRequestsInTemplateError::AWSServiceAccessNotEnabled(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceQuotaIncreaseRequestsInTemplateError::AWSServiceAccessNotEnabled,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListServiceQuotaIncreaseRequestsInTemplateError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceQuotaIncreaseRequestsInTemplateError::AccessDenied,
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListServiceQuotaIncreaseRequestsInTemplateError::DependencyAccessDenied(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceQuotaIncreaseRequestsInTemplateError::DependencyAccessDenied,
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
-                        ListServiceQuotaIncreaseRequestsInTemplateError::IllegalArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceQuotaIncreaseRequestsInTemplateError::IllegalArgument,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
-                        ListServiceQuotaIncreaseRequestsInTemplateError::NoAvailableOrganization(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceQuotaIncreaseRequestsInTemplateError::NoAvailableOrganization,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
-                        ListServiceQuotaIncreaseRequestsInTemplateError::Service(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceQuotaIncreaseRequestsInTemplateError::Service,
                     )
                 }
-                "TemplatesNotAvailableInRegionException" => return RusotoError::Service(
-                    ListServiceQuotaIncreaseRequestsInTemplateError::TemplatesNotAvailableInRegion(
-                        err.msg,
-                    ),
+                "TemplatesNotAvailableInRegionException" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    ListServiceQuotaIncreaseRequestsInTemplateError::TemplatesNotAvailableInRegion,
                 ),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListServiceQuotaIncreaseRequestsInTemplateError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceQuotaIncreaseRequestsInTemplateError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1796,26 +1995,54 @@ impl ListServiceQuotasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListServiceQuotasError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceQuotasError::AccessDenied,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(ListServiceQuotasError::IllegalArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceQuotasError::IllegalArgument,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListServiceQuotasError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListServiceQuotasError::InvalidPaginationToken,
+                    )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(ListServiceQuotasError::NoSuchResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceQuotasError::NoSuchResource,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListServiceQuotasError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceQuotasError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListServiceQuotasError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceQuotasError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1856,21 +2083,46 @@ impl ListServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListServicesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServicesError::AccessDenied,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(ListServicesError::IllegalArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServicesError::IllegalArgument,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListServicesError::InvalidPaginationToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServicesError::InvalidPaginationToken,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListServicesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServicesError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListServicesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServicesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1910,21 +2162,46 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AccessDenied,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::IllegalArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::IllegalArgument,
+                    )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NoSuchResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NoSuchResource,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1976,62 +2253,84 @@ impl PutServiceQuotaIncreaseRequestIntoTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
-                        PutServiceQuotaIncreaseRequestIntoTemplateError::AWSServiceAccessNotEnabled(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutServiceQuotaIncreaseRequestIntoTemplateError::AWSServiceAccessNotEnabled,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        PutServiceQuotaIncreaseRequestIntoTemplateError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutServiceQuotaIncreaseRequestIntoTemplateError::AccessDenied,
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        PutServiceQuotaIncreaseRequestIntoTemplateError::DependencyAccessDenied(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutServiceQuotaIncreaseRequestIntoTemplateError::DependencyAccessDenied,
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
-                        PutServiceQuotaIncreaseRequestIntoTemplateError::IllegalArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutServiceQuotaIncreaseRequestIntoTemplateError::IllegalArgument,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
-                        PutServiceQuotaIncreaseRequestIntoTemplateError::NoAvailableOrganization(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutServiceQuotaIncreaseRequestIntoTemplateError::NoAvailableOrganization,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
-                        PutServiceQuotaIncreaseRequestIntoTemplateError::NoSuchResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutServiceQuotaIncreaseRequestIntoTemplateError::NoSuchResource,
                     )
                 }
                 "QuotaExceededException" => {
-                    return RusotoError::Service(
-                        PutServiceQuotaIncreaseRequestIntoTemplateError::QuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutServiceQuotaIncreaseRequestIntoTemplateError::QuotaExceeded,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
-                        PutServiceQuotaIncreaseRequestIntoTemplateError::Service(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutServiceQuotaIncreaseRequestIntoTemplateError::Service,
                     )
                 }
-                "TemplatesNotAvailableInRegionException" => return RusotoError::Service(
-                    PutServiceQuotaIncreaseRequestIntoTemplateError::TemplatesNotAvailableInRegion(
-                        err.msg,
-                    ),
+                "TemplatesNotAvailableInRegionException" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    PutServiceQuotaIncreaseRequestIntoTemplateError::TemplatesNotAvailableInRegion,
                 ),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutServiceQuotaIncreaseRequestIntoTemplateError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutServiceQuotaIncreaseRequestIntoTemplateError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2106,49 +2405,78 @@ impl RequestServiceQuotaIncreaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RequestServiceQuotaIncreaseError::AccessDenied,
+                    )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        RequestServiceQuotaIncreaseError::DependencyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestServiceQuotaIncreaseError::DependencyAccessDenied,
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::IllegalArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RequestServiceQuotaIncreaseError::IllegalArgument,
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
-                        RequestServiceQuotaIncreaseError::InvalidResourceState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestServiceQuotaIncreaseError::InvalidResourceState,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::NoSuchResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RequestServiceQuotaIncreaseError::NoSuchResource,
+                    )
                 }
                 "QuotaExceededException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::QuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RequestServiceQuotaIncreaseError::QuotaExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        RequestServiceQuotaIncreaseError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestServiceQuotaIncreaseError::ResourceAlreadyExists,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestServiceQuotaIncreaseError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RequestServiceQuotaIncreaseError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2202,27 +2530,62 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessDenied,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(TagResourceError::IllegalArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::IllegalArgument,
+                    )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(TagResourceError::NoSuchResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NoSuchResource,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Service,
+                    )
                 }
                 "TagPolicyViolationException" => {
-                    return RusotoError::Service(TagResourceError::TagPolicyViolation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TagPolicyViolation,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyRequests,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2264,21 +2627,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessDenied,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::IllegalArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::IllegalArgument,
+                    )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(UntagResourceError::NoSuchResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NoSuchResource,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2452,11 +2840,12 @@ pub struct ServiceQuotasClient {
 impl ServiceQuotasClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ServiceQuotasClient {
         ServiceQuotasClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Service Quotas"),
         }
     }
 
@@ -2471,12 +2860,32 @@ impl ServiceQuotasClient {
     {
         ServiceQuotasClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Service Quotas"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ServiceQuotasClient {
-        ServiceQuotasClient { client, region }
+        ServiceQuotasClient {
+            client,
+            region: region_for_service(region, "Service Quotas"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ServiceQuotasClient {
+        ServiceQuotasClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Service Quotas"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ServiceQuotasClient {
+        ServiceQuotasClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2490,6 +2899,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<AssociateServiceQuotaTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateServiceQuotaTemplate");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.AssociateServiceQuotaTemplate",
@@ -2514,6 +2924,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<DeleteServiceQuotaIncreaseRequestFromTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteServiceQuotaIncreaseRequestFromTemplate");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.DeleteServiceQuotaIncreaseRequestFromTemplate",
@@ -2541,6 +2952,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<DisassociateServiceQuotaTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateServiceQuotaTemplate");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.DisassociateServiceQuotaTemplate",
@@ -2566,6 +2978,7 @@ impl ServiceQuotas for ServiceQuotasClient {
     ) -> Result<GetAWSDefaultServiceQuotaResponse, RusotoError<GetAWSDefaultServiceQuotaError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAWSDefaultServiceQuota");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.GetAWSDefaultServiceQuota",
@@ -2590,6 +3003,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<GetAssociationForServiceQuotaTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAssociationForServiceQuotaTemplate");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.GetAssociationForServiceQuotaTemplate",
@@ -2617,6 +3031,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<GetRequestedServiceQuotaChangeError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRequestedServiceQuotaChange");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.GetRequestedServiceQuotaChange",
@@ -2639,6 +3054,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         input: GetServiceQuotaRequest,
     ) -> Result<GetServiceQuotaResponse, RusotoError<GetServiceQuotaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetServiceQuota");
         request.add_header("x-amz-target", "ServiceQuotasV20190624.GetServiceQuota");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2660,6 +3076,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<GetServiceQuotaIncreaseRequestFromTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetServiceQuotaIncreaseRequestFromTemplate");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.GetServiceQuotaIncreaseRequestFromTemplate",
@@ -2686,6 +3103,7 @@ impl ServiceQuotas for ServiceQuotasClient {
     ) -> Result<ListAWSDefaultServiceQuotasResponse, RusotoError<ListAWSDefaultServiceQuotasError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAWSDefaultServiceQuotas");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.ListAWSDefaultServiceQuotas",
@@ -2711,6 +3129,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<ListRequestedServiceQuotaChangeHistoryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRequestedServiceQuotaChangeHistory");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.ListRequestedServiceQuotaChangeHistory",
@@ -2739,6 +3158,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<ListRequestedServiceQuotaChangeHistoryByQuotaError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRequestedServiceQuotaChangeHistoryByQuota");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.ListRequestedServiceQuotaChangeHistoryByQuota",
@@ -2767,6 +3187,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<ListServiceQuotaIncreaseRequestsInTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListServiceQuotaIncreaseRequestsInTemplate");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.ListServiceQuotaIncreaseRequestsInTemplate",
@@ -2792,6 +3213,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         input: ListServiceQuotasRequest,
     ) -> Result<ListServiceQuotasResponse, RusotoError<ListServiceQuotasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListServiceQuotas");
         request.add_header("x-amz-target", "ServiceQuotasV20190624.ListServiceQuotas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2810,6 +3232,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         input: ListServicesRequest,
     ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListServices");
         request.add_header("x-amz-target", "ServiceQuotasV20190624.ListServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2828,6 +3251,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "ServiceQuotasV20190624.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2849,6 +3273,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<PutServiceQuotaIncreaseRequestIntoTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutServiceQuotaIncreaseRequestIntoTemplate");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.PutServiceQuotaIncreaseRequestIntoTemplate",
@@ -2875,6 +3300,7 @@ impl ServiceQuotas for ServiceQuotasClient {
     ) -> Result<RequestServiceQuotaIncreaseResponse, RusotoError<RequestServiceQuotaIncreaseError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RequestServiceQuotaIncrease");
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.RequestServiceQuotaIncrease",
@@ -2897,6 +3323,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "ServiceQuotasV20190624.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2915,6 +3342,7 @@ impl ServiceQuotas for ServiceQuotasClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "ServiceQuotasV20190624.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2927,3 +3355,292 @@ impl ServiceQuotas for ServiceQuotasClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 }
+
+/// A blocking client for the Service Quotas API, running the calls of a `ServiceQuotasClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ServiceQuotasClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ServiceQuotasBlockingClient {
+    client: ServiceQuotasClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ServiceQuotasBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ServiceQuotasBlockingClient {
+        ServiceQuotasBlockingClient::from_client(ServiceQuotasClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ServiceQuotasBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ServiceQuotasBlockingClient::from_client(ServiceQuotasClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ServiceQuotasBlockingClient {
+        ServiceQuotasBlockingClient::from_client(ServiceQuotasClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ServiceQuotasBlockingClient {
+        ServiceQuotasBlockingClient::from_client(ServiceQuotasClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ServiceQuotasClient) -> ServiceQuotasBlockingClient {
+        ServiceQuotasBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ServiceQuotasBlockingClient {
+        ServiceQuotasBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ServiceQuotasBlockingClient {
+        ServiceQuotasBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ServiceQuotas::associate_service_quota_template`].
+    pub fn associate_service_quota_template(
+        &self,
+    ) -> Result<
+        AssociateServiceQuotaTemplateResponse,
+        RusotoError<AssociateServiceQuotaTemplateError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_service_quota_template())
+    }
+
+    /// Blocking version of [`ServiceQuotas::delete_service_quota_increase_request_from_template`].
+    pub fn delete_service_quota_increase_request_from_template(
+        &self,
+        input: DeleteServiceQuotaIncreaseRequestFromTemplateRequest,
+    ) -> Result<
+        DeleteServiceQuotaIncreaseRequestFromTemplateResponse,
+        RusotoError<DeleteServiceQuotaIncreaseRequestFromTemplateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_service_quota_increase_request_from_template(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceQuotas::disassociate_service_quota_template`].
+    pub fn disassociate_service_quota_template(
+        &self,
+    ) -> Result<
+        DisassociateServiceQuotaTemplateResponse,
+        RusotoError<DisassociateServiceQuotaTemplateError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_service_quota_template())
+    }
+
+    /// Blocking version of [`ServiceQuotas::get_aws_default_service_quota`].
+    pub fn get_aws_default_service_quota(
+        &self,
+        input: GetAWSDefaultServiceQuotaRequest,
+    ) -> Result<GetAWSDefaultServiceQuotaResponse, RusotoError<GetAWSDefaultServiceQuotaError>>
+    {
+        self.runtime
+            .block_on(self.client.get_aws_default_service_quota(input))
+    }
+
+    /// Blocking version of [`ServiceQuotas::get_association_for_service_quota_template`].
+    pub fn get_association_for_service_quota_template(
+        &self,
+    ) -> Result<
+        GetAssociationForServiceQuotaTemplateResponse,
+        RusotoError<GetAssociationForServiceQuotaTemplateError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_association_for_service_quota_template())
+    }
+
+    /// Blocking version of [`ServiceQuotas::get_requested_service_quota_change`].
+    pub fn get_requested_service_quota_change(
+        &self,
+        input: GetRequestedServiceQuotaChangeRequest,
+    ) -> Result<
+        GetRequestedServiceQuotaChangeResponse,
+        RusotoError<GetRequestedServiceQuotaChangeError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_requested_service_quota_change(input))
+    }
+
+    /// Blocking version of [`ServiceQuotas::get_service_quota`].
+    pub fn get_service_quota(
+        &self,
+        input: GetServiceQuotaRequest,
+    ) -> Result<GetServiceQuotaResponse, RusotoError<GetServiceQuotaError>> {
+        self.runtime.block_on(self.client.get_service_quota(input))
+    }
+
+    /// Blocking version of [`ServiceQuotas::get_service_quota_increase_request_from_template`].
+    pub fn get_service_quota_increase_request_from_template(
+        &self,
+        input: GetServiceQuotaIncreaseRequestFromTemplateRequest,
+    ) -> Result<
+        GetServiceQuotaIncreaseRequestFromTemplateResponse,
+        RusotoError<GetServiceQuotaIncreaseRequestFromTemplateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_service_quota_increase_request_from_template(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceQuotas::list_aws_default_service_quotas`].
+    pub fn list_aws_default_service_quotas(
+        &self,
+        input: ListAWSDefaultServiceQuotasRequest,
+    ) -> Result<ListAWSDefaultServiceQuotasResponse, RusotoError<ListAWSDefaultServiceQuotasError>>
+    {
+        self.runtime
+            .block_on(self.client.list_aws_default_service_quotas(input))
+    }
+
+    /// Blocking version of [`ServiceQuotas::list_requested_service_quota_change_history`].
+    pub fn list_requested_service_quota_change_history(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryRequest,
+    ) -> Result<
+        ListRequestedServiceQuotaChangeHistoryResponse,
+        RusotoError<ListRequestedServiceQuotaChangeHistoryError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_requested_service_quota_change_history(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceQuotas::list_requested_service_quota_change_history_by_quota`].
+    pub fn list_requested_service_quota_change_history_by_quota(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryByQuotaRequest,
+    ) -> Result<
+        ListRequestedServiceQuotaChangeHistoryByQuotaResponse,
+        RusotoError<ListRequestedServiceQuotaChangeHistoryByQuotaError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_requested_service_quota_change_history_by_quota(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceQuotas::list_service_quota_increase_requests_in_template`].
+    pub fn list_service_quota_increase_requests_in_template(
+        &self,
+        input: ListServiceQuotaIncreaseRequestsInTemplateRequest,
+    ) -> Result<
+        ListServiceQuotaIncreaseRequestsInTemplateResponse,
+        RusotoError<ListServiceQuotaIncreaseRequestsInTemplateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_service_quota_increase_requests_in_template(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceQuotas::list_service_quotas`].
+    pub fn list_service_quotas(
+        &self,
+        input: ListServiceQuotasRequest,
+    ) -> Result<ListServiceQuotasResponse, RusotoError<ListServiceQuotasError>> {
+        self.runtime
+            .block_on(self.client.list_service_quotas(input))
+    }
+
+    /// Blocking version of [`ServiceQuotas::list_services`].
+    pub fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
+        self.runtime.block_on(self.client.list_services(input))
+    }
+
+    /// Blocking version of [`ServiceQuotas::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`ServiceQuotas::put_service_quota_increase_request_into_template`].
+    pub fn put_service_quota_increase_request_into_template(
+        &self,
+        input: PutServiceQuotaIncreaseRequestIntoTemplateRequest,
+    ) -> Result<
+        PutServiceQuotaIncreaseRequestIntoTemplateResponse,
+        RusotoError<PutServiceQuotaIncreaseRequestIntoTemplateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .put_service_quota_increase_request_into_template(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceQuotas::request_service_quota_increase`].
+    pub fn request_service_quota_increase(
+        &self,
+        input: RequestServiceQuotaIncreaseRequest,
+    ) -> Result<RequestServiceQuotaIncreaseResponse, RusotoError<RequestServiceQuotaIncreaseError>>
+    {
+        self.runtime
+            .block_on(self.client.request_service_quota_increase(input))
+    }
+
+    /// Blocking version of [`ServiceQuotas::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`ServiceQuotas::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
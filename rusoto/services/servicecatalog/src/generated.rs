@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -4084,19 +4085,30 @@ impl AcceptPortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(AcceptPortfolioShareError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptPortfolioShareError::InvalidParameters,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AcceptPortfolioShareError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptPortfolioShareError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AcceptPortfolioShareError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptPortfolioShareError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4134,26 +4146,38 @@ impl AssociateBudgetWithResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(
-                        AssociateBudgetWithResourceError::DuplicateResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateBudgetWithResourceError::DuplicateResource,
                     )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        AssociateBudgetWithResourceError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateBudgetWithResourceError::InvalidParameters,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateBudgetWithResourceError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateBudgetWithResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateBudgetWithResourceError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateBudgetWithResourceError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4194,21 +4218,30 @@ impl AssociatePrincipalWithPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        AssociatePrincipalWithPortfolioError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePrincipalWithPortfolioError::InvalidParameters,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        AssociatePrincipalWithPortfolioError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePrincipalWithPortfolioError::LimitExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociatePrincipalWithPortfolioError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePrincipalWithPortfolioError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4250,21 +4283,30 @@ impl AssociateProductWithPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        AssociateProductWithPortfolioError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateProductWithPortfolioError::InvalidParameters,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateProductWithPortfolioError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateProductWithPortfolioError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateProductWithPortfolioError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateProductWithPortfolioError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4304,25 +4346,30 @@ impl AssociateServiceActionWithProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(
-                        AssociateServiceActionWithProvisioningArtifactError::DuplicateResource(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateServiceActionWithProvisioningArtifactError::DuplicateResource,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        AssociateServiceActionWithProvisioningArtifactError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateServiceActionWithProvisioningArtifactError::LimitExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateServiceActionWithProvisioningArtifactError::ResourceNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateServiceActionWithProvisioningArtifactError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4370,36 +4417,54 @@ impl AssociateTagOptionWithResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(
-                        AssociateTagOptionWithResourceError::DuplicateResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTagOptionWithResourceError::DuplicateResource,
                     )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        AssociateTagOptionWithResourceError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTagOptionWithResourceError::InvalidParameters,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(AssociateTagOptionWithResourceError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateTagOptionWithResourceError::InvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        AssociateTagOptionWithResourceError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTagOptionWithResourceError::LimitExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateTagOptionWithResourceError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTagOptionWithResourceError::ResourceNotFound,
                     )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(
-                        AssociateTagOptionWithResourceError::TagOptionNotMigrated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTagOptionWithResourceError::TagOptionNotMigrated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4442,13 +4507,14 @@ impl BatchAssociateServiceActionWithProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        BatchAssociateServiceActionWithProvisioningArtifactError::InvalidParameters(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchAssociateServiceActionWithProvisioningArtifactError::InvalidParameters,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4479,12 +4545,13 @@ impl BatchDisassociateServiceActionFromProvisioningArtifactError {
     ) -> RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "InvalidParametersException" => return RusotoError::Service(
-                    BatchDisassociateServiceActionFromProvisioningArtifactError::InvalidParameters(
-                        err.msg,
-                    ),
+                "InvalidParametersException" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    BatchDisassociateServiceActionFromProvisioningArtifactError::InvalidParameters,
                 ),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4516,12 +4583,22 @@ impl CopyProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CopyProductError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopyProductError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CopyProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopyProductError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4556,18 +4633,38 @@ impl CreateConstraintError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateConstraintError::DuplicateResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConstraintError::DuplicateResource,
+                    )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreateConstraintError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConstraintError::InvalidParameters,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateConstraintError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConstraintError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateConstraintError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConstraintError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4602,17 +4699,30 @@ impl CreatePortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreatePortfolioError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePortfolioError::InvalidParameters,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePortfolioError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePortfolioError::LimitExceeded,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(CreatePortfolioError::TagOptionNotMigrated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePortfolioError::TagOptionNotMigrated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4650,27 +4760,46 @@ impl CreatePortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePortfolioShareError::InvalidParameters,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePortfolioShareError::InvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePortfolioShareError::LimitExceeded,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::OperationNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePortfolioShareError::OperationNotSupported,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePortfolioShareError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4706,15 +4835,30 @@ impl CreateProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreateProductError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProductError::InvalidParameters,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProductError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProductError::LimitExceeded,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(CreateProductError::TagOptionNotMigrated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProductError::TagOptionNotMigrated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4750,21 +4894,30 @@ impl CreateProvisionedProductPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        CreateProvisionedProductPlanError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProvisionedProductPlanError::InvalidParameters,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreateProvisionedProductPlanError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisionedProductPlanError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateProvisionedProductPlanError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProvisionedProductPlanError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4804,21 +4957,30 @@ impl CreateProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        CreateProvisioningArtifactError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProvisioningArtifactError::InvalidParameters,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProvisioningArtifactError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisioningArtifactError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProvisioningArtifactError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisioningArtifactError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4850,14 +5012,22 @@ impl CreateServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreateServiceActionError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateServiceActionError::InvalidParameters,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateServiceActionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceActionError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4890,17 +5060,30 @@ impl CreateTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateTagOptionError::DuplicateResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagOptionError::DuplicateResource,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTagOptionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagOptionError::LimitExceeded,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(CreateTagOptionError::TagOptionNotMigrated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTagOptionError::TagOptionNotMigrated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4932,12 +5115,22 @@ impl DeleteConstraintError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DeleteConstraintError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConstraintError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConstraintError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConstraintError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4972,20 +5165,38 @@ impl DeletePortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DeletePortfolioError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePortfolioError::InvalidParameters,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeletePortfolioError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePortfolioError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePortfolioError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePortfolioError::ResourceNotFound,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(DeletePortfolioError::TagOptionNotMigrated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePortfolioError::TagOptionNotMigrated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5022,24 +5233,38 @@ impl DeletePortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DeletePortfolioShareError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePortfolioShareError::InvalidParameters,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeletePortfolioShareError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePortfolioShareError::InvalidState,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(DeletePortfolioShareError::OperationNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePortfolioShareError::OperationNotSupported,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePortfolioShareError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePortfolioShareError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5076,18 +5301,38 @@ impl DeleteProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DeleteProductError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProductError::InvalidParameters,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteProductError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProductError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProductError::ResourceNotFound,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(DeleteProductError::TagOptionNotMigrated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProductError::TagOptionNotMigrated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5122,16 +5367,22 @@ impl DeleteProvisionedProductPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        DeleteProvisionedProductPlanError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisionedProductPlanError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteProvisionedProductPlanError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisionedProductPlanError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5170,21 +5421,30 @@ impl DeleteProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        DeleteProvisioningArtifactError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisioningArtifactError::InvalidParameters,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteProvisioningArtifactError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProvisioningArtifactError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProvisioningArtifactError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProvisioningArtifactError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5216,14 +5476,22 @@ impl DeleteServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteServiceActionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteServiceActionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteServiceActionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteServiceActionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5256,17 +5524,30 @@ impl DeleteTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteTagOptionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagOptionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTagOptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagOptionError::ResourceNotFound,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(DeleteTagOptionError::TagOptionNotMigrated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTagOptionError::TagOptionNotMigrated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5296,9 +5577,14 @@ impl DescribeConstraintError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeConstraintError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConstraintError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5326,11 +5612,14 @@ impl DescribeCopyProductStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCopyProductStatusError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCopyProductStatusError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5358,9 +5647,14 @@ impl DescribePortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePortfolioError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePortfolioError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5394,21 +5688,30 @@ impl DescribePortfolioShareStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        DescribePortfolioShareStatusError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePortfolioShareStatusError::InvalidParameters,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        DescribePortfolioShareStatusError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePortfolioShareStatusError::OperationNotSupported,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribePortfolioShareStatusError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePortfolioShareStatusError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5446,16 +5749,22 @@ impl DescribePortfolioSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DescribePortfolioSharesError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePortfolioSharesError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePortfolioSharesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePortfolioSharesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5486,12 +5795,22 @@ impl DescribeProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DescribeProductError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProductError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProductError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5522,16 +5841,22 @@ impl DescribeProductAsAdminError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DescribeProductAsAdminError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProductAsAdminError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProductAsAdminError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProductAsAdminError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5562,16 +5887,22 @@ impl DescribeProductViewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DescribeProductViewError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProductViewError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProductViewError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProductViewError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5604,16 +5935,22 @@ impl DescribeProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        DescribeProvisionedProductError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisionedProductError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProvisionedProductError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProvisionedProductError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5646,16 +5983,22 @@ impl DescribeProvisionedProductPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        DescribeProvisionedProductPlanError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisionedProductPlanError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeProvisionedProductPlanError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisionedProductPlanError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5692,16 +6035,22 @@ impl DescribeProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        DescribeProvisioningArtifactError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisioningArtifactError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeProvisioningArtifactError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisioningArtifactError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5738,16 +6087,22 @@ impl DescribeProvisioningParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        DescribeProvisioningParametersError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisioningParametersError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeProvisioningParametersError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisioningParametersError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5780,9 +6135,14 @@ impl DescribeRecordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRecordError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRecordError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5810,11 +6170,14 @@ impl DescribeServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeServiceActionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeServiceActionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5846,16 +6209,22 @@ impl DescribeServiceActionExecutionParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        DescribeServiceActionExecutionParametersError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServiceActionExecutionParametersError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeServiceActionExecutionParametersError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServiceActionExecutionParametersError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5890,14 +6259,22 @@ impl DescribeTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTagOptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagOptionError::ResourceNotFound,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(DescribeTagOptionError::TagOptionNotMigrated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTagOptionError::TagOptionNotMigrated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5932,21 +6309,30 @@ impl DisableAWSOrganizationsAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(DisableAWSOrganizationsAccessError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableAWSOrganizationsAccessError::InvalidState,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        DisableAWSOrganizationsAccessError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableAWSOrganizationsAccessError::OperationNotSupported,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisableAWSOrganizationsAccessError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableAWSOrganizationsAccessError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5982,11 +6368,14 @@ impl DisassociateBudgetFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateBudgetFromResourceError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateBudgetFromResourceError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6020,16 +6409,22 @@ impl DisassociatePrincipalFromPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        DisassociatePrincipalFromPortfolioError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePrincipalFromPortfolioError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociatePrincipalFromPortfolioError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePrincipalFromPortfolioError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6068,21 +6463,30 @@ impl DisassociateProductFromPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        DisassociateProductFromPortfolioError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateProductFromPortfolioError::InvalidParameters,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        DisassociateProductFromPortfolioError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateProductFromPortfolioError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateProductFromPortfolioError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateProductFromPortfolioError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6120,13 +6524,14 @@ impl DisassociateServiceActionFromProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateServiceActionFromProvisioningArtifactError::ResourceNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateServiceActionFromProvisioningArtifactError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6160,16 +6565,22 @@ impl DisassociateTagOptionFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateTagOptionFromResourceError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateTagOptionFromResourceError::ResourceNotFound,
                     )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(
-                        DisassociateTagOptionFromResourceError::TagOptionNotMigrated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateTagOptionFromResourceError::TagOptionNotMigrated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6208,21 +6619,30 @@ impl EnableAWSOrganizationsAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(EnableAWSOrganizationsAccessError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableAWSOrganizationsAccessError::InvalidState,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        EnableAWSOrganizationsAccessError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAWSOrganizationsAccessError::OperationNotSupported,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        EnableAWSOrganizationsAccessError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAWSOrganizationsAccessError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6262,21 +6682,30 @@ impl ExecuteProvisionedProductPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        ExecuteProvisionedProductPlanError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteProvisionedProductPlanError::InvalidParameters,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ExecuteProvisionedProductPlanError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExecuteProvisionedProductPlanError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ExecuteProvisionedProductPlanError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteProvisionedProductPlanError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6316,21 +6745,30 @@ impl ExecuteProvisionedProductServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        ExecuteProvisionedProductServiceActionError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteProvisionedProductServiceActionError::InvalidParameters,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
-                        ExecuteProvisionedProductServiceActionError::InvalidState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteProvisionedProductServiceActionError::InvalidState,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ExecuteProvisionedProductServiceActionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteProvisionedProductServiceActionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6370,16 +6808,22 @@ impl GetAWSOrganizationsAccessStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        GetAWSOrganizationsAccessStatusError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAWSOrganizationsAccessStatusError::OperationNotSupported,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetAWSOrganizationsAccessStatusError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAWSOrganizationsAccessStatusError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6416,16 +6860,22 @@ impl GetProvisionedProductOutputsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        GetProvisionedProductOutputsError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProvisionedProductOutputsError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetProvisionedProductOutputsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProvisionedProductOutputsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6466,26 +6916,38 @@ impl ImportAsProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(
-                        ImportAsProvisionedProductError::DuplicateResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportAsProvisionedProductError::DuplicateResource,
                     )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        ImportAsProvisionedProductError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportAsProvisionedProductError::InvalidParameters,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ImportAsProvisionedProductError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportAsProvisionedProductError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ImportAsProvisionedProductError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportAsProvisionedProductError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6520,16 +6982,22 @@ impl ListAcceptedPortfolioSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        ListAcceptedPortfolioSharesError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAcceptedPortfolioSharesError::InvalidParameters,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        ListAcceptedPortfolioSharesError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAcceptedPortfolioSharesError::OperationNotSupported,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6564,16 +7032,22 @@ impl ListBudgetsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListBudgetsForResourceError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBudgetsForResourceError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBudgetsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBudgetsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6606,16 +7080,22 @@ impl ListConstraintsForPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        ListConstraintsForPortfolioError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConstraintsForPortfolioError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListConstraintsForPortfolioError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConstraintsForPortfolioError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6648,12 +7128,22 @@ impl ListLaunchPathsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListLaunchPathsError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLaunchPathsError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLaunchPathsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLaunchPathsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6688,21 +7178,30 @@ impl ListOrganizationPortfolioAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        ListOrganizationPortfolioAccessError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOrganizationPortfolioAccessError::InvalidParameters,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        ListOrganizationPortfolioAccessError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOrganizationPortfolioAccessError::OperationNotSupported,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListOrganizationPortfolioAccessError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOrganizationPortfolioAccessError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6740,16 +7239,22 @@ impl ListPortfolioAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListPortfolioAccessError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPortfolioAccessError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPortfolioAccessError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPortfolioAccessError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6778,9 +7283,14 @@ impl ListPortfoliosError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListPortfoliosError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPortfoliosError::InvalidParameters,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6810,16 +7320,22 @@ impl ListPortfoliosForProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListPortfoliosForProductError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPortfoliosForProductError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPortfoliosForProductError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPortfoliosForProductError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6852,16 +7368,22 @@ impl ListPrincipalsForPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        ListPrincipalsForPortfolioError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalsForPortfolioError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPrincipalsForPortfolioError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPrincipalsForPortfolioError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6894,16 +7416,22 @@ impl ListProvisionedProductPlansError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        ListProvisionedProductPlansError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisionedProductPlansError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListProvisionedProductPlansError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisionedProductPlansError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6936,16 +7464,22 @@ impl ListProvisioningArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListProvisioningArtifactsError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProvisioningArtifactsError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProvisioningArtifactsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProvisioningArtifactsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6978,16 +7512,22 @@ impl ListProvisioningArtifactsForServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        ListProvisioningArtifactsForServiceActionError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisioningArtifactsForServiceActionError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListProvisioningArtifactsForServiceActionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisioningArtifactsForServiceActionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7020,9 +7560,14 @@ impl ListRecordHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListRecordHistoryError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecordHistoryError::InvalidParameters,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7054,21 +7599,30 @@ impl ListResourcesForTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListResourcesForTagOptionError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourcesForTagOptionError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListResourcesForTagOptionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourcesForTagOptionError::ResourceNotFound,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(
-                        ListResourcesForTagOptionError::TagOptionNotMigrated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesForTagOptionError::TagOptionNotMigrated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7100,11 +7654,14 @@ impl ListServiceActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListServiceActionsError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListServiceActionsError::InvalidParameters,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7136,16 +7693,22 @@ impl ListServiceActionsForProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        ListServiceActionsForProvisioningArtifactError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceActionsForProvisioningArtifactError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListServiceActionsForProvisioningArtifactError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServiceActionsForProvisioningArtifactError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7182,16 +7745,22 @@ impl ListStackInstancesForProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        ListStackInstancesForProvisionedProductError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStackInstancesForProvisionedProductError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListStackInstancesForProvisionedProductError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStackInstancesForProvisionedProductError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7226,12 +7795,22 @@ impl ListTagOptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListTagOptionsError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagOptionsError::InvalidParameters,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(ListTagOptionsError::TagOptionNotMigrated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagOptionsError::TagOptionNotMigrated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7264,15 +7843,30 @@ impl ProvisionProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(ProvisionProductError::DuplicateResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ProvisionProductError::DuplicateResource,
+                    )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ProvisionProductError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ProvisionProductError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ProvisionProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ProvisionProductError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7302,11 +7896,14 @@ impl RejectPortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RejectPortfolioShareError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectPortfolioShareError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7334,11 +7931,14 @@ impl ScanProvisionedProductsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ScanProvisionedProductsError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ScanProvisionedProductsError::InvalidParameters,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7366,9 +7966,14 @@ impl SearchProductsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(SearchProductsError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchProductsError::InvalidParameters,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7398,16 +8003,22 @@ impl SearchProductsAsAdminError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(SearchProductsAsAdminError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchProductsAsAdminError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchProductsAsAdminError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchProductsAsAdminError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7436,11 +8047,14 @@ impl SearchProvisionedProductsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(SearchProvisionedProductsError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchProvisionedProductsError::InvalidParameters,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7470,11 +8084,14 @@ impl TerminateProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        TerminateProvisionedProductError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TerminateProvisionedProductError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7504,12 +8121,22 @@ impl UpdateConstraintError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateConstraintError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConstraintError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateConstraintError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConstraintError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7544,20 +8171,38 @@ impl UpdatePortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdatePortfolioError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePortfolioError::InvalidParameters,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdatePortfolioError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePortfolioError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePortfolioError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePortfolioError::ResourceNotFound,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(UpdatePortfolioError::TagOptionNotMigrated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePortfolioError::TagOptionNotMigrated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7594,24 +8239,38 @@ impl UpdatePortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdatePortfolioShareError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePortfolioShareError::InvalidParameters,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdatePortfolioShareError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePortfolioShareError::InvalidState,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(UpdatePortfolioShareError::OperationNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePortfolioShareError::OperationNotSupported,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePortfolioShareError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePortfolioShareError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7646,15 +8305,30 @@ impl UpdateProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateProductError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProductError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProductError::ResourceNotFound,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(UpdateProductError::TagOptionNotMigrated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProductError::TagOptionNotMigrated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7686,16 +8360,22 @@ impl UpdateProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateProvisionedProductError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProvisionedProductError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProvisionedProductError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProvisionedProductError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7730,21 +8410,30 @@ impl UpdateProvisionedProductPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        UpdateProvisionedProductPropertiesError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProvisionedProductPropertiesError::InvalidParameters,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
-                        UpdateProvisionedProductPropertiesError::InvalidState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProvisionedProductPropertiesError::InvalidState,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateProvisionedProductPropertiesError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProvisionedProductPropertiesError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7784,16 +8473,22 @@ impl UpdateProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
-                        UpdateProvisioningArtifactError::InvalidParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProvisioningArtifactError::InvalidParameters,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProvisioningArtifactError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProvisioningArtifactError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7824,16 +8519,22 @@ impl UpdateServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateServiceActionError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateServiceActionError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateServiceActionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateServiceActionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7868,20 +8569,38 @@ impl UpdateTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(UpdateTagOptionError::DuplicateResource(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTagOptionError::DuplicateResource,
+                    )
+                }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateTagOptionError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTagOptionError::InvalidParameters,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTagOptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTagOptionError::ResourceNotFound,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(UpdateTagOptionError::TagOptionNotMigrated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTagOptionError::TagOptionNotMigrated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8492,11 +9211,12 @@ pub struct ServiceCatalogClient {
 impl ServiceCatalogClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ServiceCatalogClient {
         ServiceCatalogClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Service Catalog"),
         }
     }
 
@@ -8511,12 +9231,32 @@ impl ServiceCatalogClient {
     {
         ServiceCatalogClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Service Catalog"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ServiceCatalogClient {
-        ServiceCatalogClient { client, region }
+        ServiceCatalogClient {
+            client,
+            region: region_for_service(region, "Service Catalog"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ServiceCatalogClient {
+        ServiceCatalogClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Service Catalog"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ServiceCatalogClient {
+        ServiceCatalogClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -8528,6 +9268,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: AcceptPortfolioShareInput,
     ) -> Result<AcceptPortfolioShareOutput, RusotoError<AcceptPortfolioShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AcceptPortfolioShare");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.AcceptPortfolioShare",
@@ -8550,6 +9291,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<AssociateBudgetWithResourceOutput, RusotoError<AssociateBudgetWithResourceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateBudgetWithResource");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.AssociateBudgetWithResource",
@@ -8575,6 +9317,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<AssociatePrincipalWithPortfolioError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociatePrincipalWithPortfolio");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.AssociatePrincipalWithPortfolio",
@@ -8598,6 +9341,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<AssociateProductWithPortfolioOutput, RusotoError<AssociateProductWithPortfolioError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateProductWithPortfolio");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.AssociateProductWithPortfolio",
@@ -8623,6 +9367,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<AssociateServiceActionWithProvisioningArtifactError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateServiceActionWithProvisioningArtifact");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.AssociateServiceActionWithProvisioningArtifact",
@@ -8651,6 +9396,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<AssociateTagOptionWithResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateTagOptionWithResource");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.AssociateTagOptionWithResource",
@@ -8676,6 +9422,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<BatchAssociateServiceActionWithProvisioningArtifactError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchAssociateServiceActionWithProvisioningArtifact");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.BatchAssociateServiceActionWithProvisioningArtifact",
@@ -8704,6 +9451,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDisassociateServiceActionFromProvisioningArtifact");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.BatchDisassociateServiceActionFromProvisioningArtifact",
@@ -8729,6 +9477,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CopyProductInput,
     ) -> Result<CopyProductOutput, RusotoError<CopyProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CopyProduct");
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.CopyProduct");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8747,6 +9496,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateConstraintInput,
     ) -> Result<CreateConstraintOutput, RusotoError<CreateConstraintError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateConstraint");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreateConstraint",
@@ -8768,6 +9518,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreatePortfolioInput,
     ) -> Result<CreatePortfolioOutput, RusotoError<CreatePortfolioError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePortfolio");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreatePortfolio",
@@ -8789,6 +9540,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreatePortfolioShareInput,
     ) -> Result<CreatePortfolioShareOutput, RusotoError<CreatePortfolioShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePortfolioShare");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreatePortfolioShare",
@@ -8810,6 +9562,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateProductInput,
     ) -> Result<CreateProductOutput, RusotoError<CreateProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProduct");
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.CreateProduct");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8829,6 +9582,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<CreateProvisionedProductPlanOutput, RusotoError<CreateProvisionedProductPlanError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProvisionedProductPlan");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreateProvisionedProductPlan",
@@ -8852,6 +9606,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<CreateProvisioningArtifactOutput, RusotoError<CreateProvisioningArtifactError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProvisioningArtifact");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreateProvisioningArtifact",
@@ -8874,6 +9629,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateServiceActionInput,
     ) -> Result<CreateServiceActionOutput, RusotoError<CreateServiceActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateServiceAction");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreateServiceAction",
@@ -8895,6 +9651,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateTagOptionInput,
     ) -> Result<CreateTagOptionOutput, RusotoError<CreateTagOptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTagOption");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreateTagOption",
@@ -8916,6 +9673,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeleteConstraintInput,
     ) -> Result<DeleteConstraintOutput, RusotoError<DeleteConstraintError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteConstraint");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeleteConstraint",
@@ -8937,6 +9695,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeletePortfolioInput,
     ) -> Result<DeletePortfolioOutput, RusotoError<DeletePortfolioError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePortfolio");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeletePortfolio",
@@ -8958,6 +9717,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeletePortfolioShareInput,
     ) -> Result<DeletePortfolioShareOutput, RusotoError<DeletePortfolioShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePortfolioShare");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeletePortfolioShare",
@@ -8979,6 +9739,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeleteProductInput,
     ) -> Result<DeleteProductOutput, RusotoError<DeleteProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProduct");
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.DeleteProduct");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8998,6 +9759,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DeleteProvisionedProductPlanOutput, RusotoError<DeleteProvisionedProductPlanError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProvisionedProductPlan");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeleteProvisionedProductPlan",
@@ -9021,6 +9783,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DeleteProvisioningArtifactOutput, RusotoError<DeleteProvisioningArtifactError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProvisioningArtifact");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeleteProvisioningArtifact",
@@ -9043,6 +9806,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeleteServiceActionInput,
     ) -> Result<DeleteServiceActionOutput, RusotoError<DeleteServiceActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteServiceAction");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeleteServiceAction",
@@ -9064,6 +9828,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeleteTagOptionInput,
     ) -> Result<DeleteTagOptionOutput, RusotoError<DeleteTagOptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTagOption");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeleteTagOption",
@@ -9085,6 +9850,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeConstraintInput,
     ) -> Result<DescribeConstraintOutput, RusotoError<DescribeConstraintError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConstraint");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeConstraint",
@@ -9106,6 +9872,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeCopyProductStatusInput,
     ) -> Result<DescribeCopyProductStatusOutput, RusotoError<DescribeCopyProductStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCopyProductStatus");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeCopyProductStatus",
@@ -9128,6 +9895,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribePortfolioInput,
     ) -> Result<DescribePortfolioOutput, RusotoError<DescribePortfolioError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePortfolio");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribePortfolio",
@@ -9150,6 +9918,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DescribePortfolioShareStatusOutput, RusotoError<DescribePortfolioShareStatusError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePortfolioShareStatus");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribePortfolioShareStatus",
@@ -9172,6 +9941,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribePortfolioSharesInput,
     ) -> Result<DescribePortfolioSharesOutput, RusotoError<DescribePortfolioSharesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePortfolioShares");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribePortfolioShares",
@@ -9194,6 +9964,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeProductInput,
     ) -> Result<DescribeProductOutput, RusotoError<DescribeProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProduct");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProduct",
@@ -9215,6 +9986,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeProductAsAdminInput,
     ) -> Result<DescribeProductAsAdminOutput, RusotoError<DescribeProductAsAdminError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProductAsAdmin");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProductAsAdmin",
@@ -9237,6 +10009,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeProductViewInput,
     ) -> Result<DescribeProductViewOutput, RusotoError<DescribeProductViewError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProductView");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProductView",
@@ -9259,6 +10032,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DescribeProvisionedProductOutput, RusotoError<DescribeProvisionedProductError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProvisionedProduct");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProvisionedProduct",
@@ -9284,6 +10058,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DescribeProvisionedProductPlanError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProvisionedProductPlan");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProvisionedProductPlan",
@@ -9307,6 +10082,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DescribeProvisioningArtifactOutput, RusotoError<DescribeProvisioningArtifactError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProvisioningArtifact");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProvisioningArtifact",
@@ -9332,6 +10108,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DescribeProvisioningParametersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProvisioningParameters");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProvisioningParameters",
@@ -9354,6 +10131,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeRecordInput,
     ) -> Result<DescribeRecordOutput, RusotoError<DescribeRecordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRecord");
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.DescribeRecord");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9372,6 +10150,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeServiceActionInput,
     ) -> Result<DescribeServiceActionOutput, RusotoError<DescribeServiceActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeServiceAction");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeServiceAction",
@@ -9396,6 +10175,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DescribeServiceActionExecutionParametersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeServiceActionExecutionParameters");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeServiceActionExecutionParameters",
@@ -9421,6 +10201,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeTagOptionInput,
     ) -> Result<DescribeTagOptionOutput, RusotoError<DescribeTagOptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTagOption");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeTagOption",
@@ -9442,6 +10223,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DisableAWSOrganizationsAccessOutput, RusotoError<DisableAWSOrganizationsAccessError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableAWSOrganizationsAccess");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DisableAWSOrganizationsAccess",
@@ -9466,6 +10248,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociateBudgetFromResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateBudgetFromResource");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DisassociateBudgetFromResource",
@@ -9491,6 +10274,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociatePrincipalFromPortfolioError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociatePrincipalFromPortfolio");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DisassociatePrincipalFromPortfolio",
@@ -9519,6 +10303,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociateProductFromPortfolioError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateProductFromPortfolio");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DisassociateProductFromPortfolio",
@@ -9547,6 +10332,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociateServiceActionFromProvisioningArtifactError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateServiceActionFromProvisioningArtifact");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DisassociateServiceActionFromProvisioningArtifact",
@@ -9575,6 +10361,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociateTagOptionFromResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateTagOptionFromResource");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DisassociateTagOptionFromResource",
@@ -9600,6 +10387,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<EnableAWSOrganizationsAccessOutput, RusotoError<EnableAWSOrganizationsAccessError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableAWSOrganizationsAccess");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.EnableAWSOrganizationsAccess",
@@ -9622,6 +10410,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ExecuteProvisionedProductPlanOutput, RusotoError<ExecuteProvisionedProductPlanError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExecuteProvisionedProductPlan");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ExecuteProvisionedProductPlan",
@@ -9647,6 +10436,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ExecuteProvisionedProductServiceActionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExecuteProvisionedProductServiceAction");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ExecuteProvisionedProductServiceAction",
@@ -9674,6 +10464,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<GetAWSOrganizationsAccessStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAWSOrganizationsAccessStatus");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.GetAWSOrganizationsAccessStatus",
@@ -9696,6 +10487,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<GetProvisionedProductOutputsOutput, RusotoError<GetProvisionedProductOutputsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetProvisionedProductOutputs");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.GetProvisionedProductOutputs",
@@ -9719,6 +10511,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ImportAsProvisionedProductOutput, RusotoError<ImportAsProvisionedProductError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportAsProvisionedProduct");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ImportAsProvisionedProduct",
@@ -9742,6 +10535,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ListAcceptedPortfolioSharesOutput, RusotoError<ListAcceptedPortfolioSharesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAcceptedPortfolioShares");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListAcceptedPortfolioShares",
@@ -9764,6 +10558,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListBudgetsForResourceInput,
     ) -> Result<ListBudgetsForResourceOutput, RusotoError<ListBudgetsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListBudgetsForResource");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListBudgetsForResource",
@@ -9787,6 +10582,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ListConstraintsForPortfolioOutput, RusotoError<ListConstraintsForPortfolioError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListConstraintsForPortfolio");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListConstraintsForPortfolio",
@@ -9809,6 +10605,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListLaunchPathsInput,
     ) -> Result<ListLaunchPathsOutput, RusotoError<ListLaunchPathsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLaunchPaths");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListLaunchPaths",
@@ -9833,6 +10630,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ListOrganizationPortfolioAccessError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListOrganizationPortfolioAccess");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListOrganizationPortfolioAccess",
@@ -9855,6 +10653,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListPortfolioAccessInput,
     ) -> Result<ListPortfolioAccessOutput, RusotoError<ListPortfolioAccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPortfolioAccess");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListPortfolioAccess",
@@ -9876,6 +10675,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListPortfoliosInput,
     ) -> Result<ListPortfoliosOutput, RusotoError<ListPortfoliosError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPortfolios");
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.ListPortfolios");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9894,6 +10694,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListPortfoliosForProductInput,
     ) -> Result<ListPortfoliosForProductOutput, RusotoError<ListPortfoliosForProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPortfoliosForProduct");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListPortfoliosForProduct",
@@ -9917,6 +10718,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ListPrincipalsForPortfolioOutput, RusotoError<ListPrincipalsForPortfolioError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPrincipalsForPortfolio");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListPrincipalsForPortfolio",
@@ -9940,6 +10742,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ListProvisionedProductPlansOutput, RusotoError<ListProvisionedProductPlansError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProvisionedProductPlans");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListProvisionedProductPlans",
@@ -9962,6 +10765,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListProvisioningArtifactsInput,
     ) -> Result<ListProvisioningArtifactsOutput, RusotoError<ListProvisioningArtifactsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProvisioningArtifacts");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListProvisioningArtifacts",
@@ -9987,6 +10791,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ListProvisioningArtifactsForServiceActionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProvisioningArtifactsForServiceAction");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListProvisioningArtifactsForServiceAction",
@@ -10012,6 +10817,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListRecordHistoryInput,
     ) -> Result<ListRecordHistoryOutput, RusotoError<ListRecordHistoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRecordHistory");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListRecordHistory",
@@ -10033,6 +10839,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListResourcesForTagOptionInput,
     ) -> Result<ListResourcesForTagOptionOutput, RusotoError<ListResourcesForTagOptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResourcesForTagOption");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListResourcesForTagOption",
@@ -10055,6 +10862,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListServiceActionsInput,
     ) -> Result<ListServiceActionsOutput, RusotoError<ListServiceActionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListServiceActions");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListServiceActions",
@@ -10079,6 +10887,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ListServiceActionsForProvisioningArtifactError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListServiceActionsForProvisioningArtifact");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListServiceActionsForProvisioningArtifact",
@@ -10107,6 +10916,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ListStackInstancesForProvisionedProductError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListStackInstancesForProvisionedProduct");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListStackInstancesForProvisionedProduct",
@@ -10132,6 +10942,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListTagOptionsInput,
     ) -> Result<ListTagOptionsOutput, RusotoError<ListTagOptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagOptions");
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.ListTagOptions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10150,6 +10961,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ProvisionProductInput,
     ) -> Result<ProvisionProductOutput, RusotoError<ProvisionProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ProvisionProduct");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ProvisionProduct",
@@ -10171,6 +10983,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: RejectPortfolioShareInput,
     ) -> Result<RejectPortfolioShareOutput, RusotoError<RejectPortfolioShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RejectPortfolioShare");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.RejectPortfolioShare",
@@ -10192,6 +11005,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ScanProvisionedProductsInput,
     ) -> Result<ScanProvisionedProductsOutput, RusotoError<ScanProvisionedProductsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ScanProvisionedProducts");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ScanProvisionedProducts",
@@ -10214,6 +11028,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: SearchProductsInput,
     ) -> Result<SearchProductsOutput, RusotoError<SearchProductsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchProducts");
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.SearchProducts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10232,6 +11047,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: SearchProductsAsAdminInput,
     ) -> Result<SearchProductsAsAdminOutput, RusotoError<SearchProductsAsAdminError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchProductsAsAdmin");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.SearchProductsAsAdmin",
@@ -10253,6 +11069,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: SearchProvisionedProductsInput,
     ) -> Result<SearchProvisionedProductsOutput, RusotoError<SearchProvisionedProductsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchProvisionedProducts");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.SearchProvisionedProducts",
@@ -10276,6 +11093,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<TerminateProvisionedProductOutput, RusotoError<TerminateProvisionedProductError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TerminateProvisionedProduct");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.TerminateProvisionedProduct",
@@ -10298,6 +11116,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateConstraintInput,
     ) -> Result<UpdateConstraintOutput, RusotoError<UpdateConstraintError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateConstraint");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdateConstraint",
@@ -10319,6 +11138,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdatePortfolioInput,
     ) -> Result<UpdatePortfolioOutput, RusotoError<UpdatePortfolioError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePortfolio");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdatePortfolio",
@@ -10340,6 +11160,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdatePortfolioShareInput,
     ) -> Result<UpdatePortfolioShareOutput, RusotoError<UpdatePortfolioShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePortfolioShare");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdatePortfolioShare",
@@ -10361,6 +11182,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateProductInput,
     ) -> Result<UpdateProductOutput, RusotoError<UpdateProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateProduct");
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.UpdateProduct");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10379,6 +11201,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateProvisionedProductInput,
     ) -> Result<UpdateProvisionedProductOutput, RusotoError<UpdateProvisionedProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateProvisionedProduct");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdateProvisionedProduct",
@@ -10404,6 +11227,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<UpdateProvisionedProductPropertiesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateProvisionedProductProperties");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdateProvisionedProductProperties",
@@ -10430,6 +11254,7 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<UpdateProvisioningArtifactOutput, RusotoError<UpdateProvisioningArtifactError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateProvisioningArtifact");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdateProvisioningArtifact",
@@ -10452,6 +11277,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateServiceActionInput,
     ) -> Result<UpdateServiceActionOutput, RusotoError<UpdateServiceActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateServiceAction");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdateServiceAction",
@@ -10473,6 +11299,7 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateTagOptionInput,
     ) -> Result<UpdateTagOptionOutput, RusotoError<UpdateTagOptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTagOption");
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdateTagOption",
@@ -10488,3 +11315,940 @@ impl ServiceCatalog for ServiceCatalogClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTagOptionOutput, _>()
     }
 }
+
+/// A blocking client for the AWS Service Catalog API, running the calls of a `ServiceCatalogClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ServiceCatalogClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ServiceCatalogBlockingClient {
+    client: ServiceCatalogClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ServiceCatalogBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ServiceCatalogBlockingClient {
+        ServiceCatalogBlockingClient::from_client(ServiceCatalogClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ServiceCatalogBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ServiceCatalogBlockingClient::from_client(ServiceCatalogClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ServiceCatalogBlockingClient {
+        ServiceCatalogBlockingClient::from_client(ServiceCatalogClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ServiceCatalogBlockingClient {
+        ServiceCatalogBlockingClient::from_client(ServiceCatalogClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ServiceCatalogClient) -> ServiceCatalogBlockingClient {
+        ServiceCatalogBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ServiceCatalogBlockingClient {
+        ServiceCatalogBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ServiceCatalogBlockingClient {
+        ServiceCatalogBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ServiceCatalog::accept_portfolio_share`].
+    pub fn accept_portfolio_share(
+        &self,
+        input: AcceptPortfolioShareInput,
+    ) -> Result<AcceptPortfolioShareOutput, RusotoError<AcceptPortfolioShareError>> {
+        self.runtime
+            .block_on(self.client.accept_portfolio_share(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::associate_budget_with_resource`].
+    pub fn associate_budget_with_resource(
+        &self,
+        input: AssociateBudgetWithResourceInput,
+    ) -> Result<AssociateBudgetWithResourceOutput, RusotoError<AssociateBudgetWithResourceError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_budget_with_resource(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::associate_principal_with_portfolio`].
+    pub fn associate_principal_with_portfolio(
+        &self,
+        input: AssociatePrincipalWithPortfolioInput,
+    ) -> Result<
+        AssociatePrincipalWithPortfolioOutput,
+        RusotoError<AssociatePrincipalWithPortfolioError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_principal_with_portfolio(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::associate_product_with_portfolio`].
+    pub fn associate_product_with_portfolio(
+        &self,
+        input: AssociateProductWithPortfolioInput,
+    ) -> Result<AssociateProductWithPortfolioOutput, RusotoError<AssociateProductWithPortfolioError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_product_with_portfolio(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::associate_service_action_with_provisioning_artifact`].
+    pub fn associate_service_action_with_provisioning_artifact(
+        &self,
+        input: AssociateServiceActionWithProvisioningArtifactInput,
+    ) -> Result<
+        AssociateServiceActionWithProvisioningArtifactOutput,
+        RusotoError<AssociateServiceActionWithProvisioningArtifactError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .associate_service_action_with_provisioning_artifact(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceCatalog::associate_tag_option_with_resource`].
+    pub fn associate_tag_option_with_resource(
+        &self,
+        input: AssociateTagOptionWithResourceInput,
+    ) -> Result<
+        AssociateTagOptionWithResourceOutput,
+        RusotoError<AssociateTagOptionWithResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_tag_option_with_resource(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::batch_associate_service_action_with_provisioning_artifact`].
+    pub fn batch_associate_service_action_with_provisioning_artifact(
+        &self,
+        input: BatchAssociateServiceActionWithProvisioningArtifactInput,
+    ) -> Result<
+        BatchAssociateServiceActionWithProvisioningArtifactOutput,
+        RusotoError<BatchAssociateServiceActionWithProvisioningArtifactError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .batch_associate_service_action_with_provisioning_artifact(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceCatalog::batch_disassociate_service_action_from_provisioning_artifact`].
+    pub fn batch_disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> Result<
+        BatchDisassociateServiceActionFromProvisioningArtifactOutput,
+        RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .batch_disassociate_service_action_from_provisioning_artifact(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceCatalog::copy_product`].
+    pub fn copy_product(
+        &self,
+        input: CopyProductInput,
+    ) -> Result<CopyProductOutput, RusotoError<CopyProductError>> {
+        self.runtime.block_on(self.client.copy_product(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::create_constraint`].
+    pub fn create_constraint(
+        &self,
+        input: CreateConstraintInput,
+    ) -> Result<CreateConstraintOutput, RusotoError<CreateConstraintError>> {
+        self.runtime.block_on(self.client.create_constraint(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::create_portfolio`].
+    pub fn create_portfolio(
+        &self,
+        input: CreatePortfolioInput,
+    ) -> Result<CreatePortfolioOutput, RusotoError<CreatePortfolioError>> {
+        self.runtime.block_on(self.client.create_portfolio(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::create_portfolio_share`].
+    pub fn create_portfolio_share(
+        &self,
+        input: CreatePortfolioShareInput,
+    ) -> Result<CreatePortfolioShareOutput, RusotoError<CreatePortfolioShareError>> {
+        self.runtime
+            .block_on(self.client.create_portfolio_share(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::create_product`].
+    pub fn create_product(
+        &self,
+        input: CreateProductInput,
+    ) -> Result<CreateProductOutput, RusotoError<CreateProductError>> {
+        self.runtime.block_on(self.client.create_product(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::create_provisioned_product_plan`].
+    pub fn create_provisioned_product_plan(
+        &self,
+        input: CreateProvisionedProductPlanInput,
+    ) -> Result<CreateProvisionedProductPlanOutput, RusotoError<CreateProvisionedProductPlanError>>
+    {
+        self.runtime
+            .block_on(self.client.create_provisioned_product_plan(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::create_provisioning_artifact`].
+    pub fn create_provisioning_artifact(
+        &self,
+        input: CreateProvisioningArtifactInput,
+    ) -> Result<CreateProvisioningArtifactOutput, RusotoError<CreateProvisioningArtifactError>>
+    {
+        self.runtime
+            .block_on(self.client.create_provisioning_artifact(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::create_service_action`].
+    pub fn create_service_action(
+        &self,
+        input: CreateServiceActionInput,
+    ) -> Result<CreateServiceActionOutput, RusotoError<CreateServiceActionError>> {
+        self.runtime
+            .block_on(self.client.create_service_action(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::create_tag_option`].
+    pub fn create_tag_option(
+        &self,
+        input: CreateTagOptionInput,
+    ) -> Result<CreateTagOptionOutput, RusotoError<CreateTagOptionError>> {
+        self.runtime.block_on(self.client.create_tag_option(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::delete_constraint`].
+    pub fn delete_constraint(
+        &self,
+        input: DeleteConstraintInput,
+    ) -> Result<DeleteConstraintOutput, RusotoError<DeleteConstraintError>> {
+        self.runtime.block_on(self.client.delete_constraint(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::delete_portfolio`].
+    pub fn delete_portfolio(
+        &self,
+        input: DeletePortfolioInput,
+    ) -> Result<DeletePortfolioOutput, RusotoError<DeletePortfolioError>> {
+        self.runtime.block_on(self.client.delete_portfolio(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::delete_portfolio_share`].
+    pub fn delete_portfolio_share(
+        &self,
+        input: DeletePortfolioShareInput,
+    ) -> Result<DeletePortfolioShareOutput, RusotoError<DeletePortfolioShareError>> {
+        self.runtime
+            .block_on(self.client.delete_portfolio_share(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::delete_product`].
+    pub fn delete_product(
+        &self,
+        input: DeleteProductInput,
+    ) -> Result<DeleteProductOutput, RusotoError<DeleteProductError>> {
+        self.runtime.block_on(self.client.delete_product(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::delete_provisioned_product_plan`].
+    pub fn delete_provisioned_product_plan(
+        &self,
+        input: DeleteProvisionedProductPlanInput,
+    ) -> Result<DeleteProvisionedProductPlanOutput, RusotoError<DeleteProvisionedProductPlanError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_provisioned_product_plan(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::delete_provisioning_artifact`].
+    pub fn delete_provisioning_artifact(
+        &self,
+        input: DeleteProvisioningArtifactInput,
+    ) -> Result<DeleteProvisioningArtifactOutput, RusotoError<DeleteProvisioningArtifactError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_provisioning_artifact(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::delete_service_action`].
+    pub fn delete_service_action(
+        &self,
+        input: DeleteServiceActionInput,
+    ) -> Result<DeleteServiceActionOutput, RusotoError<DeleteServiceActionError>> {
+        self.runtime
+            .block_on(self.client.delete_service_action(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::delete_tag_option`].
+    pub fn delete_tag_option(
+        &self,
+        input: DeleteTagOptionInput,
+    ) -> Result<DeleteTagOptionOutput, RusotoError<DeleteTagOptionError>> {
+        self.runtime.block_on(self.client.delete_tag_option(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_constraint`].
+    pub fn describe_constraint(
+        &self,
+        input: DescribeConstraintInput,
+    ) -> Result<DescribeConstraintOutput, RusotoError<DescribeConstraintError>> {
+        self.runtime
+            .block_on(self.client.describe_constraint(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_copy_product_status`].
+    pub fn describe_copy_product_status(
+        &self,
+        input: DescribeCopyProductStatusInput,
+    ) -> Result<DescribeCopyProductStatusOutput, RusotoError<DescribeCopyProductStatusError>> {
+        self.runtime
+            .block_on(self.client.describe_copy_product_status(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_portfolio`].
+    pub fn describe_portfolio(
+        &self,
+        input: DescribePortfolioInput,
+    ) -> Result<DescribePortfolioOutput, RusotoError<DescribePortfolioError>> {
+        self.runtime.block_on(self.client.describe_portfolio(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_portfolio_share_status`].
+    pub fn describe_portfolio_share_status(
+        &self,
+        input: DescribePortfolioShareStatusInput,
+    ) -> Result<DescribePortfolioShareStatusOutput, RusotoError<DescribePortfolioShareStatusError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_portfolio_share_status(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_portfolio_shares`].
+    pub fn describe_portfolio_shares(
+        &self,
+        input: DescribePortfolioSharesInput,
+    ) -> Result<DescribePortfolioSharesOutput, RusotoError<DescribePortfolioSharesError>> {
+        self.runtime
+            .block_on(self.client.describe_portfolio_shares(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_product`].
+    pub fn describe_product(
+        &self,
+        input: DescribeProductInput,
+    ) -> Result<DescribeProductOutput, RusotoError<DescribeProductError>> {
+        self.runtime.block_on(self.client.describe_product(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_product_as_admin`].
+    pub fn describe_product_as_admin(
+        &self,
+        input: DescribeProductAsAdminInput,
+    ) -> Result<DescribeProductAsAdminOutput, RusotoError<DescribeProductAsAdminError>> {
+        self.runtime
+            .block_on(self.client.describe_product_as_admin(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_product_view`].
+    pub fn describe_product_view(
+        &self,
+        input: DescribeProductViewInput,
+    ) -> Result<DescribeProductViewOutput, RusotoError<DescribeProductViewError>> {
+        self.runtime
+            .block_on(self.client.describe_product_view(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_provisioned_product`].
+    pub fn describe_provisioned_product(
+        &self,
+        input: DescribeProvisionedProductInput,
+    ) -> Result<DescribeProvisionedProductOutput, RusotoError<DescribeProvisionedProductError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_provisioned_product(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_provisioned_product_plan`].
+    pub fn describe_provisioned_product_plan(
+        &self,
+        input: DescribeProvisionedProductPlanInput,
+    ) -> Result<
+        DescribeProvisionedProductPlanOutput,
+        RusotoError<DescribeProvisionedProductPlanError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_provisioned_product_plan(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_provisioning_artifact`].
+    pub fn describe_provisioning_artifact(
+        &self,
+        input: DescribeProvisioningArtifactInput,
+    ) -> Result<DescribeProvisioningArtifactOutput, RusotoError<DescribeProvisioningArtifactError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_provisioning_artifact(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_provisioning_parameters`].
+    pub fn describe_provisioning_parameters(
+        &self,
+        input: DescribeProvisioningParametersInput,
+    ) -> Result<
+        DescribeProvisioningParametersOutput,
+        RusotoError<DescribeProvisioningParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_provisioning_parameters(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_record`].
+    pub fn describe_record(
+        &self,
+        input: DescribeRecordInput,
+    ) -> Result<DescribeRecordOutput, RusotoError<DescribeRecordError>> {
+        self.runtime.block_on(self.client.describe_record(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_service_action`].
+    pub fn describe_service_action(
+        &self,
+        input: DescribeServiceActionInput,
+    ) -> Result<DescribeServiceActionOutput, RusotoError<DescribeServiceActionError>> {
+        self.runtime
+            .block_on(self.client.describe_service_action(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_service_action_execution_parameters`].
+    pub fn describe_service_action_execution_parameters(
+        &self,
+        input: DescribeServiceActionExecutionParametersInput,
+    ) -> Result<
+        DescribeServiceActionExecutionParametersOutput,
+        RusotoError<DescribeServiceActionExecutionParametersError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_service_action_execution_parameters(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceCatalog::describe_tag_option`].
+    pub fn describe_tag_option(
+        &self,
+        input: DescribeTagOptionInput,
+    ) -> Result<DescribeTagOptionOutput, RusotoError<DescribeTagOptionError>> {
+        self.runtime
+            .block_on(self.client.describe_tag_option(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::disable_aws_organizations_access`].
+    pub fn disable_aws_organizations_access(
+        &self,
+    ) -> Result<DisableAWSOrganizationsAccessOutput, RusotoError<DisableAWSOrganizationsAccessError>>
+    {
+        self.runtime
+            .block_on(self.client.disable_aws_organizations_access())
+    }
+
+    /// Blocking version of [`ServiceCatalog::disassociate_budget_from_resource`].
+    pub fn disassociate_budget_from_resource(
+        &self,
+        input: DisassociateBudgetFromResourceInput,
+    ) -> Result<
+        DisassociateBudgetFromResourceOutput,
+        RusotoError<DisassociateBudgetFromResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_budget_from_resource(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::disassociate_principal_from_portfolio`].
+    pub fn disassociate_principal_from_portfolio(
+        &self,
+        input: DisassociatePrincipalFromPortfolioInput,
+    ) -> Result<
+        DisassociatePrincipalFromPortfolioOutput,
+        RusotoError<DisassociatePrincipalFromPortfolioError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_principal_from_portfolio(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::disassociate_product_from_portfolio`].
+    pub fn disassociate_product_from_portfolio(
+        &self,
+        input: DisassociateProductFromPortfolioInput,
+    ) -> Result<
+        DisassociateProductFromPortfolioOutput,
+        RusotoError<DisassociateProductFromPortfolioError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_product_from_portfolio(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::disassociate_service_action_from_provisioning_artifact`].
+    pub fn disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: DisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> Result<
+        DisassociateServiceActionFromProvisioningArtifactOutput,
+        RusotoError<DisassociateServiceActionFromProvisioningArtifactError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_service_action_from_provisioning_artifact(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceCatalog::disassociate_tag_option_from_resource`].
+    pub fn disassociate_tag_option_from_resource(
+        &self,
+        input: DisassociateTagOptionFromResourceInput,
+    ) -> Result<
+        DisassociateTagOptionFromResourceOutput,
+        RusotoError<DisassociateTagOptionFromResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_tag_option_from_resource(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::enable_aws_organizations_access`].
+    pub fn enable_aws_organizations_access(
+        &self,
+    ) -> Result<EnableAWSOrganizationsAccessOutput, RusotoError<EnableAWSOrganizationsAccessError>>
+    {
+        self.runtime
+            .block_on(self.client.enable_aws_organizations_access())
+    }
+
+    /// Blocking version of [`ServiceCatalog::execute_provisioned_product_plan`].
+    pub fn execute_provisioned_product_plan(
+        &self,
+        input: ExecuteProvisionedProductPlanInput,
+    ) -> Result<ExecuteProvisionedProductPlanOutput, RusotoError<ExecuteProvisionedProductPlanError>>
+    {
+        self.runtime
+            .block_on(self.client.execute_provisioned_product_plan(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::execute_provisioned_product_service_action`].
+    pub fn execute_provisioned_product_service_action(
+        &self,
+        input: ExecuteProvisionedProductServiceActionInput,
+    ) -> Result<
+        ExecuteProvisionedProductServiceActionOutput,
+        RusotoError<ExecuteProvisionedProductServiceActionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .execute_provisioned_product_service_action(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceCatalog::get_aws_organizations_access_status`].
+    pub fn get_aws_organizations_access_status(
+        &self,
+    ) -> Result<
+        GetAWSOrganizationsAccessStatusOutput,
+        RusotoError<GetAWSOrganizationsAccessStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_aws_organizations_access_status())
+    }
+
+    /// Blocking version of [`ServiceCatalog::get_provisioned_product_outputs`].
+    pub fn get_provisioned_product_outputs(
+        &self,
+        input: GetProvisionedProductOutputsInput,
+    ) -> Result<GetProvisionedProductOutputsOutput, RusotoError<GetProvisionedProductOutputsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_provisioned_product_outputs(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::import_as_provisioned_product`].
+    pub fn import_as_provisioned_product(
+        &self,
+        input: ImportAsProvisionedProductInput,
+    ) -> Result<ImportAsProvisionedProductOutput, RusotoError<ImportAsProvisionedProductError>>
+    {
+        self.runtime
+            .block_on(self.client.import_as_provisioned_product(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_accepted_portfolio_shares`].
+    pub fn list_accepted_portfolio_shares(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> Result<ListAcceptedPortfolioSharesOutput, RusotoError<ListAcceptedPortfolioSharesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_accepted_portfolio_shares(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_budgets_for_resource`].
+    pub fn list_budgets_for_resource(
+        &self,
+        input: ListBudgetsForResourceInput,
+    ) -> Result<ListBudgetsForResourceOutput, RusotoError<ListBudgetsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_budgets_for_resource(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_constraints_for_portfolio`].
+    pub fn list_constraints_for_portfolio(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> Result<ListConstraintsForPortfolioOutput, RusotoError<ListConstraintsForPortfolioError>>
+    {
+        self.runtime
+            .block_on(self.client.list_constraints_for_portfolio(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_launch_paths`].
+    pub fn list_launch_paths(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> Result<ListLaunchPathsOutput, RusotoError<ListLaunchPathsError>> {
+        self.runtime.block_on(self.client.list_launch_paths(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_organization_portfolio_access`].
+    pub fn list_organization_portfolio_access(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> Result<
+        ListOrganizationPortfolioAccessOutput,
+        RusotoError<ListOrganizationPortfolioAccessError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_organization_portfolio_access(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_portfolio_access`].
+    pub fn list_portfolio_access(
+        &self,
+        input: ListPortfolioAccessInput,
+    ) -> Result<ListPortfolioAccessOutput, RusotoError<ListPortfolioAccessError>> {
+        self.runtime
+            .block_on(self.client.list_portfolio_access(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_portfolios`].
+    pub fn list_portfolios(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> Result<ListPortfoliosOutput, RusotoError<ListPortfoliosError>> {
+        self.runtime.block_on(self.client.list_portfolios(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_portfolios_for_product`].
+    pub fn list_portfolios_for_product(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> Result<ListPortfoliosForProductOutput, RusotoError<ListPortfoliosForProductError>> {
+        self.runtime
+            .block_on(self.client.list_portfolios_for_product(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_principals_for_portfolio`].
+    pub fn list_principals_for_portfolio(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> Result<ListPrincipalsForPortfolioOutput, RusotoError<ListPrincipalsForPortfolioError>>
+    {
+        self.runtime
+            .block_on(self.client.list_principals_for_portfolio(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_provisioned_product_plans`].
+    pub fn list_provisioned_product_plans(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> Result<ListProvisionedProductPlansOutput, RusotoError<ListProvisionedProductPlansError>>
+    {
+        self.runtime
+            .block_on(self.client.list_provisioned_product_plans(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_provisioning_artifacts`].
+    pub fn list_provisioning_artifacts(
+        &self,
+        input: ListProvisioningArtifactsInput,
+    ) -> Result<ListProvisioningArtifactsOutput, RusotoError<ListProvisioningArtifactsError>> {
+        self.runtime
+            .block_on(self.client.list_provisioning_artifacts(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_provisioning_artifacts_for_service_action`].
+    pub fn list_provisioning_artifacts_for_service_action(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> Result<
+        ListProvisioningArtifactsForServiceActionOutput,
+        RusotoError<ListProvisioningArtifactsForServiceActionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_provisioning_artifacts_for_service_action(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_record_history`].
+    pub fn list_record_history(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> Result<ListRecordHistoryOutput, RusotoError<ListRecordHistoryError>> {
+        self.runtime
+            .block_on(self.client.list_record_history(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_resources_for_tag_option`].
+    pub fn list_resources_for_tag_option(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> Result<ListResourcesForTagOptionOutput, RusotoError<ListResourcesForTagOptionError>> {
+        self.runtime
+            .block_on(self.client.list_resources_for_tag_option(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_service_actions`].
+    pub fn list_service_actions(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> Result<ListServiceActionsOutput, RusotoError<ListServiceActionsError>> {
+        self.runtime
+            .block_on(self.client.list_service_actions(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_service_actions_for_provisioning_artifact`].
+    pub fn list_service_actions_for_provisioning_artifact(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> Result<
+        ListServiceActionsForProvisioningArtifactOutput,
+        RusotoError<ListServiceActionsForProvisioningArtifactError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_service_actions_for_provisioning_artifact(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_stack_instances_for_provisioned_product`].
+    pub fn list_stack_instances_for_provisioned_product(
+        &self,
+        input: ListStackInstancesForProvisionedProductInput,
+    ) -> Result<
+        ListStackInstancesForProvisionedProductOutput,
+        RusotoError<ListStackInstancesForProvisionedProductError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_stack_instances_for_provisioned_product(input),
+        )
+    }
+
+    /// Blocking version of [`ServiceCatalog::list_tag_options`].
+    pub fn list_tag_options(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> Result<ListTagOptionsOutput, RusotoError<ListTagOptionsError>> {
+        self.runtime.block_on(self.client.list_tag_options(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::provision_product`].
+    pub fn provision_product(
+        &self,
+        input: ProvisionProductInput,
+    ) -> Result<ProvisionProductOutput, RusotoError<ProvisionProductError>> {
+        self.runtime.block_on(self.client.provision_product(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::reject_portfolio_share`].
+    pub fn reject_portfolio_share(
+        &self,
+        input: RejectPortfolioShareInput,
+    ) -> Result<RejectPortfolioShareOutput, RusotoError<RejectPortfolioShareError>> {
+        self.runtime
+            .block_on(self.client.reject_portfolio_share(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::scan_provisioned_products`].
+    pub fn scan_provisioned_products(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> Result<ScanProvisionedProductsOutput, RusotoError<ScanProvisionedProductsError>> {
+        self.runtime
+            .block_on(self.client.scan_provisioned_products(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::search_products`].
+    pub fn search_products(
+        &self,
+        input: SearchProductsInput,
+    ) -> Result<SearchProductsOutput, RusotoError<SearchProductsError>> {
+        self.runtime.block_on(self.client.search_products(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::search_products_as_admin`].
+    pub fn search_products_as_admin(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> Result<SearchProductsAsAdminOutput, RusotoError<SearchProductsAsAdminError>> {
+        self.runtime
+            .block_on(self.client.search_products_as_admin(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::search_provisioned_products`].
+    pub fn search_provisioned_products(
+        &self,
+        input: SearchProvisionedProductsInput,
+    ) -> Result<SearchProvisionedProductsOutput, RusotoError<SearchProvisionedProductsError>> {
+        self.runtime
+            .block_on(self.client.search_provisioned_products(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::terminate_provisioned_product`].
+    pub fn terminate_provisioned_product(
+        &self,
+        input: TerminateProvisionedProductInput,
+    ) -> Result<TerminateProvisionedProductOutput, RusotoError<TerminateProvisionedProductError>>
+    {
+        self.runtime
+            .block_on(self.client.terminate_provisioned_product(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::update_constraint`].
+    pub fn update_constraint(
+        &self,
+        input: UpdateConstraintInput,
+    ) -> Result<UpdateConstraintOutput, RusotoError<UpdateConstraintError>> {
+        self.runtime.block_on(self.client.update_constraint(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::update_portfolio`].
+    pub fn update_portfolio(
+        &self,
+        input: UpdatePortfolioInput,
+    ) -> Result<UpdatePortfolioOutput, RusotoError<UpdatePortfolioError>> {
+        self.runtime.block_on(self.client.update_portfolio(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::update_portfolio_share`].
+    pub fn update_portfolio_share(
+        &self,
+        input: UpdatePortfolioShareInput,
+    ) -> Result<UpdatePortfolioShareOutput, RusotoError<UpdatePortfolioShareError>> {
+        self.runtime
+            .block_on(self.client.update_portfolio_share(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::update_product`].
+    pub fn update_product(
+        &self,
+        input: UpdateProductInput,
+    ) -> Result<UpdateProductOutput, RusotoError<UpdateProductError>> {
+        self.runtime.block_on(self.client.update_product(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::update_provisioned_product`].
+    pub fn update_provisioned_product(
+        &self,
+        input: UpdateProvisionedProductInput,
+    ) -> Result<UpdateProvisionedProductOutput, RusotoError<UpdateProvisionedProductError>> {
+        self.runtime
+            .block_on(self.client.update_provisioned_product(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::update_provisioned_product_properties`].
+    pub fn update_provisioned_product_properties(
+        &self,
+        input: UpdateProvisionedProductPropertiesInput,
+    ) -> Result<
+        UpdateProvisionedProductPropertiesOutput,
+        RusotoError<UpdateProvisionedProductPropertiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_provisioned_product_properties(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::update_provisioning_artifact`].
+    pub fn update_provisioning_artifact(
+        &self,
+        input: UpdateProvisioningArtifactInput,
+    ) -> Result<UpdateProvisioningArtifactOutput, RusotoError<UpdateProvisioningArtifactError>>
+    {
+        self.runtime
+            .block_on(self.client.update_provisioning_artifact(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::update_service_action`].
+    pub fn update_service_action(
+        &self,
+        input: UpdateServiceActionInput,
+    ) -> Result<UpdateServiceActionOutput, RusotoError<UpdateServiceActionError>> {
+        self.runtime
+            .block_on(self.client.update_service_action(input))
+    }
+
+    /// Blocking version of [`ServiceCatalog::update_tag_option`].
+    pub fn update_tag_option(
+        &self,
+        input: UpdateTagOptionInput,
+    ) -> Result<UpdateTagOptionOutput, RusotoError<UpdateTagOptionError>> {
+        self.runtime.block_on(self.client.update_tag_option(input))
+    }
+}
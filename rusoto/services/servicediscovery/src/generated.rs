@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1066,27 +1067,46 @@ impl CreateHttpNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::DuplicateRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateHttpNamespaceError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHttpNamespaceError::InvalidInput,
+                    )
                 }
                 "NamespaceAlreadyExists" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::NamespaceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateHttpNamespaceError::NamespaceAlreadyExists,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateHttpNamespaceError::ResourceLimitExceeded,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHttpNamespaceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1126,31 +1146,46 @@ impl CreatePrivateDnsNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(CreatePrivateDnsNamespaceError::DuplicateRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePrivateDnsNamespaceError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(CreatePrivateDnsNamespaceError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePrivateDnsNamespaceError::InvalidInput,
+                    )
                 }
                 "NamespaceAlreadyExists" => {
-                    return RusotoError::Service(
-                        CreatePrivateDnsNamespaceError::NamespaceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePrivateDnsNamespaceError::NamespaceAlreadyExists,
                     )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreatePrivateDnsNamespaceError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePrivateDnsNamespaceError::ResourceLimitExceeded,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePrivateDnsNamespaceError::TooManyTags(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePrivateDnsNamespaceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1194,31 +1229,46 @@ impl CreatePublicDnsNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(CreatePublicDnsNamespaceError::DuplicateRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePublicDnsNamespaceError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(CreatePublicDnsNamespaceError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePublicDnsNamespaceError::InvalidInput,
+                    )
                 }
                 "NamespaceAlreadyExists" => {
-                    return RusotoError::Service(
-                        CreatePublicDnsNamespaceError::NamespaceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePublicDnsNamespaceError::NamespaceAlreadyExists,
                     )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreatePublicDnsNamespaceError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePublicDnsNamespaceError::ResourceLimitExceeded,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePublicDnsNamespaceError::TooManyTags(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePublicDnsNamespaceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1262,21 +1312,46 @@ impl CreateServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(CreateServiceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::InvalidInput,
+                    )
                 }
                 "NamespaceNotFound" => {
-                    return RusotoError::Service(CreateServiceError::NamespaceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::NamespaceNotFound,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateServiceError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceAlreadyExists" => {
-                    return RusotoError::Service(CreateServiceError::ServiceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::ServiceAlreadyExists,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateServiceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1314,18 +1389,38 @@ impl DeleteNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(DeleteNamespaceError::DuplicateRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamespaceError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(DeleteNamespaceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamespaceError::InvalidInput,
+                    )
                 }
                 "NamespaceNotFound" => {
-                    return RusotoError::Service(DeleteNamespaceError::NamespaceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamespaceError::NamespaceNotFound,
+                    )
                 }
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteNamespaceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamespaceError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1360,15 +1455,30 @@ impl DeleteServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(DeleteServiceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteServiceError::InvalidInput,
+                    )
                 }
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteServiceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteServiceError::ResourceInUse,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(DeleteServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteServiceError::ServiceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1406,21 +1516,46 @@ impl DeregisterInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(DeregisterInstanceError::DuplicateRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterInstanceError::DuplicateRequest,
+                    )
                 }
                 "InstanceNotFound" => {
-                    return RusotoError::Service(DeregisterInstanceError::InstanceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterInstanceError::InstanceNotFound,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(DeregisterInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterInstanceError::InvalidInput,
+                    )
                 }
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeregisterInstanceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterInstanceError::ResourceInUse,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(DeregisterInstanceError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterInstanceError::ServiceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1458,20 +1593,38 @@ impl DiscoverInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(DiscoverInstancesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DiscoverInstancesError::InvalidInput,
+                    )
                 }
                 "NamespaceNotFound" => {
-                    return RusotoError::Service(DiscoverInstancesError::NamespaceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DiscoverInstancesError::NamespaceNotFound,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(DiscoverInstancesError::RequestLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DiscoverInstancesError::RequestLimitExceeded,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(DiscoverInstancesError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DiscoverInstancesError::ServiceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1506,15 +1659,30 @@ impl GetInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceNotFound" => {
-                    return RusotoError::Service(GetInstanceError::InstanceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceError::InstanceNotFound,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(GetInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceError::InvalidInput,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(GetInstanceError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceError::ServiceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1548,21 +1716,30 @@ impl GetInstancesHealthStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceNotFound" => {
-                    return RusotoError::Service(GetInstancesHealthStatusError::InstanceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstancesHealthStatusError::InstanceNotFound,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(GetInstancesHealthStatusError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstancesHealthStatusError::InvalidInput,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(GetInstancesHealthStatusError::ServiceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstancesHealthStatusError::ServiceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1594,12 +1771,22 @@ impl GetNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetNamespaceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNamespaceError::InvalidInput,
+                    )
                 }
                 "NamespaceNotFound" => {
-                    return RusotoError::Service(GetNamespaceError::NamespaceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNamespaceError::NamespaceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1630,12 +1817,22 @@ impl GetOperationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetOperationError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationError::InvalidInput,
+                    )
                 }
                 "OperationNotFound" => {
-                    return RusotoError::Service(GetOperationError::OperationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationError::OperationNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1666,12 +1863,22 @@ impl GetServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetServiceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceError::InvalidInput,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(GetServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceError::ServiceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1702,12 +1909,22 @@ impl ListInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListInstancesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstancesError::InvalidInput,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(ListInstancesError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstancesError::ServiceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1736,9 +1953,14 @@ impl ListNamespacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListNamespacesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNamespacesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1766,9 +1988,14 @@ impl ListOperationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListOperationsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOperationsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1796,9 +2023,14 @@ impl ListServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListServicesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServicesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1828,14 +2060,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1872,23 +2112,46 @@ impl RegisterInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(RegisterInstanceError::DuplicateRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterInstanceError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(RegisterInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterInstanceError::InvalidInput,
+                    )
                 }
                 "ResourceInUse" => {
-                    return RusotoError::Service(RegisterInstanceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterInstanceError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(RegisterInstanceError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterInstanceError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(RegisterInstanceError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterInstanceError::ServiceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1924,15 +2187,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1964,12 +2242,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2006,26 +2294,38 @@ impl UpdateInstanceCustomHealthStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomHealthNotFound" => {
-                    return RusotoError::Service(
-                        UpdateInstanceCustomHealthStatusError::CustomHealthNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInstanceCustomHealthStatusError::CustomHealthNotFound,
                     )
                 }
                 "InstanceNotFound" => {
-                    return RusotoError::Service(
-                        UpdateInstanceCustomHealthStatusError::InstanceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInstanceCustomHealthStatusError::InstanceNotFound,
                     )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(
-                        UpdateInstanceCustomHealthStatusError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInstanceCustomHealthStatusError::InvalidInput,
                     )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(
-                        UpdateInstanceCustomHealthStatusError::ServiceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInstanceCustomHealthStatusError::ServiceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2068,15 +2368,30 @@ impl UpdateServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(UpdateServiceError::DuplicateRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateServiceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceError::InvalidInput,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(UpdateServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceError::ServiceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2245,11 +2560,12 @@ pub struct ServiceDiscoveryClient {
 impl ServiceDiscoveryClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ServiceDiscoveryClient {
         ServiceDiscoveryClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "ServiceDiscovery"),
         }
     }
 
@@ -2264,12 +2580,32 @@ impl ServiceDiscoveryClient {
     {
         ServiceDiscoveryClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "ServiceDiscovery"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ServiceDiscoveryClient {
-        ServiceDiscoveryClient { client, region }
+        ServiceDiscoveryClient {
+            client,
+            region: region_for_service(region, "ServiceDiscovery"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ServiceDiscoveryClient {
+        ServiceDiscoveryClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("ServiceDiscovery"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ServiceDiscoveryClient {
+        ServiceDiscoveryClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2281,6 +2617,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: CreateHttpNamespaceRequest,
     ) -> Result<CreateHttpNamespaceResponse, RusotoError<CreateHttpNamespaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateHttpNamespace");
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.CreateHttpNamespace",
@@ -2303,6 +2640,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
     ) -> Result<CreatePrivateDnsNamespaceResponse, RusotoError<CreatePrivateDnsNamespaceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePrivateDnsNamespace");
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.CreatePrivateDnsNamespace",
@@ -2325,6 +2663,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: CreatePublicDnsNamespaceRequest,
     ) -> Result<CreatePublicDnsNamespaceResponse, RusotoError<CreatePublicDnsNamespaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePublicDnsNamespace");
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.CreatePublicDnsNamespace",
@@ -2347,6 +2686,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: CreateServiceRequest,
     ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateService");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.CreateService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2365,6 +2705,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: DeleteNamespaceRequest,
     ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteNamespace");
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.DeleteNamespace",
@@ -2386,6 +2727,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: DeleteServiceRequest,
     ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteService");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.DeleteService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2404,6 +2746,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: DeregisterInstanceRequest,
     ) -> Result<DeregisterInstanceResponse, RusotoError<DeregisterInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterInstance");
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.DeregisterInstance",
@@ -2425,6 +2768,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: DiscoverInstancesRequest,
     ) -> Result<DiscoverInstancesResponse, RusotoError<DiscoverInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DiscoverInstances");
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.DiscoverInstances",
@@ -2446,6 +2790,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetInstanceRequest,
     ) -> Result<GetInstanceResponse, RusotoError<GetInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInstance");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2464,6 +2809,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetInstancesHealthStatusRequest,
     ) -> Result<GetInstancesHealthStatusResponse, RusotoError<GetInstancesHealthStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInstancesHealthStatus");
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.GetInstancesHealthStatus",
@@ -2486,6 +2832,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetNamespaceRequest,
     ) -> Result<GetNamespaceResponse, RusotoError<GetNamespaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetNamespace");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetNamespace");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2504,6 +2851,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetOperationRequest,
     ) -> Result<GetOperationResponse, RusotoError<GetOperationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOperation");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetOperation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2522,6 +2870,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetServiceRequest,
     ) -> Result<GetServiceResponse, RusotoError<GetServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetService");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2540,6 +2889,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListInstancesRequest,
     ) -> Result<ListInstancesResponse, RusotoError<ListInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListInstances");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2558,6 +2908,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListNamespacesRequest,
     ) -> Result<ListNamespacesResponse, RusotoError<ListNamespacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListNamespaces");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListNamespaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2576,6 +2927,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListOperationsRequest,
     ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListOperations");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListOperations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2594,6 +2946,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListServicesRequest,
     ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListServices");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2612,6 +2965,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.ListTagsForResource",
@@ -2633,6 +2987,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: RegisterInstanceRequest,
     ) -> Result<RegisterInstanceResponse, RusotoError<RegisterInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterInstance");
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.RegisterInstance",
@@ -2654,6 +3009,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2672,6 +3028,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2690,6 +3047,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: UpdateInstanceCustomHealthStatusRequest,
     ) -> Result<(), RusotoError<UpdateInstanceCustomHealthStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateInstanceCustomHealthStatus");
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.UpdateInstanceCustomHealthStatus",
@@ -2713,6 +3071,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: UpdateServiceRequest,
     ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateService");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.UpdateService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2725,3 +3084,278 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateServiceResponse, _>()
     }
 }
+
+/// A blocking client for the ServiceDiscovery API, running the calls of a `ServiceDiscoveryClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ServiceDiscoveryClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ServiceDiscoveryBlockingClient {
+    client: ServiceDiscoveryClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ServiceDiscoveryBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ServiceDiscoveryBlockingClient {
+        ServiceDiscoveryBlockingClient::from_client(ServiceDiscoveryClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ServiceDiscoveryBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ServiceDiscoveryBlockingClient::from_client(ServiceDiscoveryClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ServiceDiscoveryBlockingClient {
+        ServiceDiscoveryBlockingClient::from_client(ServiceDiscoveryClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ServiceDiscoveryBlockingClient {
+        ServiceDiscoveryBlockingClient::from_client(ServiceDiscoveryClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ServiceDiscoveryClient) -> ServiceDiscoveryBlockingClient {
+        ServiceDiscoveryBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ServiceDiscoveryBlockingClient {
+        ServiceDiscoveryBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ServiceDiscoveryBlockingClient {
+        ServiceDiscoveryBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ServiceDiscovery::create_http_namespace`].
+    pub fn create_http_namespace(
+        &self,
+        input: CreateHttpNamespaceRequest,
+    ) -> Result<CreateHttpNamespaceResponse, RusotoError<CreateHttpNamespaceError>> {
+        self.runtime
+            .block_on(self.client.create_http_namespace(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::create_private_dns_namespace`].
+    pub fn create_private_dns_namespace(
+        &self,
+        input: CreatePrivateDnsNamespaceRequest,
+    ) -> Result<CreatePrivateDnsNamespaceResponse, RusotoError<CreatePrivateDnsNamespaceError>>
+    {
+        self.runtime
+            .block_on(self.client.create_private_dns_namespace(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::create_public_dns_namespace`].
+    pub fn create_public_dns_namespace(
+        &self,
+        input: CreatePublicDnsNamespaceRequest,
+    ) -> Result<CreatePublicDnsNamespaceResponse, RusotoError<CreatePublicDnsNamespaceError>> {
+        self.runtime
+            .block_on(self.client.create_public_dns_namespace(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::create_service`].
+    pub fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
+        self.runtime.block_on(self.client.create_service(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::delete_namespace`].
+    pub fn delete_namespace(
+        &self,
+        input: DeleteNamespaceRequest,
+    ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
+        self.runtime.block_on(self.client.delete_namespace(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::delete_service`].
+    pub fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
+        self.runtime.block_on(self.client.delete_service(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::deregister_instance`].
+    pub fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> Result<DeregisterInstanceResponse, RusotoError<DeregisterInstanceError>> {
+        self.runtime
+            .block_on(self.client.deregister_instance(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::discover_instances`].
+    pub fn discover_instances(
+        &self,
+        input: DiscoverInstancesRequest,
+    ) -> Result<DiscoverInstancesResponse, RusotoError<DiscoverInstancesError>> {
+        self.runtime.block_on(self.client.discover_instances(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::get_instance`].
+    pub fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> Result<GetInstanceResponse, RusotoError<GetInstanceError>> {
+        self.runtime.block_on(self.client.get_instance(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::get_instances_health_status`].
+    pub fn get_instances_health_status(
+        &self,
+        input: GetInstancesHealthStatusRequest,
+    ) -> Result<GetInstancesHealthStatusResponse, RusotoError<GetInstancesHealthStatusError>> {
+        self.runtime
+            .block_on(self.client.get_instances_health_status(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::get_namespace`].
+    pub fn get_namespace(
+        &self,
+        input: GetNamespaceRequest,
+    ) -> Result<GetNamespaceResponse, RusotoError<GetNamespaceError>> {
+        self.runtime.block_on(self.client.get_namespace(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::get_operation`].
+    pub fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> Result<GetOperationResponse, RusotoError<GetOperationError>> {
+        self.runtime.block_on(self.client.get_operation(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::get_service`].
+    pub fn get_service(
+        &self,
+        input: GetServiceRequest,
+    ) -> Result<GetServiceResponse, RusotoError<GetServiceError>> {
+        self.runtime.block_on(self.client.get_service(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::list_instances`].
+    pub fn list_instances(
+        &self,
+        input: ListInstancesRequest,
+    ) -> Result<ListInstancesResponse, RusotoError<ListInstancesError>> {
+        self.runtime.block_on(self.client.list_instances(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::list_namespaces`].
+    pub fn list_namespaces(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> Result<ListNamespacesResponse, RusotoError<ListNamespacesError>> {
+        self.runtime.block_on(self.client.list_namespaces(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::list_operations`].
+    pub fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
+        self.runtime.block_on(self.client.list_operations(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::list_services`].
+    pub fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
+        self.runtime.block_on(self.client.list_services(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::register_instance`].
+    pub fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> Result<RegisterInstanceResponse, RusotoError<RegisterInstanceError>> {
+        self.runtime.block_on(self.client.register_instance(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::update_instance_custom_health_status`].
+    pub fn update_instance_custom_health_status(
+        &self,
+        input: UpdateInstanceCustomHealthStatusRequest,
+    ) -> Result<(), RusotoError<UpdateInstanceCustomHealthStatusError>> {
+        self.runtime
+            .block_on(self.client.update_instance_custom_health_status(input))
+    }
+
+    /// Blocking version of [`ServiceDiscovery::update_service`].
+    pub fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
+        self.runtime.block_on(self.client.update_service(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -7101,19 +7102,28 @@ impl CloneReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CloneReceiptRuleSetError::AlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CloneReceiptRuleSetError::AlreadyExists,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CloneReceiptRuleSetError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CloneReceiptRuleSetError::LimitExceeded,
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(CloneReceiptRuleSetError::RuleSetDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CloneReceiptRuleSetError::RuleSetDoesNotExist,
+                        )
                     }
                     _ => {}
                 }
@@ -7161,23 +7171,28 @@ impl CreateConfigurationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateConfigurationSetError::ConfigurationSetAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateConfigurationSetError::ConfigurationSetAlreadyExists,
                         )
                     }
                     "InvalidConfigurationSet" => {
-                        return RusotoError::Service(
-                            CreateConfigurationSetError::InvalidConfigurationSet(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateConfigurationSetError::InvalidConfigurationSet,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateConfigurationSetError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateConfigurationSetError::LimitExceeded,
+                        )
                     }
                     _ => {}
                 }
@@ -7236,40 +7251,46 @@ impl CreateConfigurationSetEventDestinationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
-                        CreateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
-                        ),
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        CreateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist,
                     ),
-                    "EventDestinationAlreadyExists" => return RusotoError::Service(
-                        CreateConfigurationSetEventDestinationError::EventDestinationAlreadyExists(
-                            parsed_error.message,
-                        ),
+                    "EventDestinationAlreadyExists" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        CreateConfigurationSetEventDestinationError::EventDestinationAlreadyExists,
                     ),
-                    "InvalidCloudWatchDestination" => return RusotoError::Service(
-                        CreateConfigurationSetEventDestinationError::InvalidCloudWatchDestination(
-                            parsed_error.message,
-                        ),
+                    "InvalidCloudWatchDestination" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        CreateConfigurationSetEventDestinationError::InvalidCloudWatchDestination,
                     ),
                     "InvalidFirehoseDestination" => {
-                        return RusotoError::Service(
-                            CreateConfigurationSetEventDestinationError::InvalidFirehoseDestination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateConfigurationSetEventDestinationError::InvalidFirehoseDestination,
                         )
                     }
                     "InvalidSNSDestination" => {
-                        return RusotoError::Service(
-                            CreateConfigurationSetEventDestinationError::InvalidSNSDestination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateConfigurationSetEventDestinationError::InvalidSNSDestination,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            CreateConfigurationSetEventDestinationError::LimitExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateConfigurationSetEventDestinationError::LimitExceeded,
                         )
                     }
                     _ => {}
@@ -7334,22 +7355,25 @@ impl CreateConfigurationSetTrackingOptionsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
-                        CreateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
-                        ),
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        CreateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist,
                     ),
                     "InvalidTrackingOptions" => {
-                        return RusotoError::Service(
-                            CreateConfigurationSetTrackingOptionsError::InvalidTrackingOptions(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateConfigurationSetTrackingOptionsError::InvalidTrackingOptions,
                         )
                     }
-                    "TrackingOptionsAlreadyExistsException" => return RusotoError::Service(
-                        CreateConfigurationSetTrackingOptionsError::TrackingOptionsAlreadyExists(
-                            parsed_error.message,
-                        ),
+                    "TrackingOptionsAlreadyExistsException" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        CreateConfigurationSetTrackingOptionsError::TrackingOptionsAlreadyExists,
                     ),
                     _ => {}
                 }
@@ -7406,7 +7430,7 @@ impl CreateCustomVerificationEmailTemplateError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CustomVerificationEmailInvalidContent" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(parsed_error.message)),"CustomVerificationEmailTemplateAlreadyExists" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateAlreadyExists(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(parsed_error.message)),"LimitExceeded" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::LimitExceeded(parsed_error.message)),_ => {}
+                                    "CustomVerificationEmailInvalidContent" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent),"CustomVerificationEmailTemplateAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateAlreadyExists),"FromEmailAddressNotVerified" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified),"LimitExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateCustomVerificationEmailTemplateError::LimitExceeded),_ => {}
                                 }
             }
         }
@@ -7451,14 +7475,20 @@ impl CreateReceiptFilterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateReceiptFilterError::AlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateReceiptFilterError::AlreadyExists,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateReceiptFilterError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateReceiptFilterError::LimitExceeded,
+                        )
                     }
                     _ => {}
                 }
@@ -7513,39 +7543,60 @@ impl CreateReceiptRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateReceiptRuleError::AlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateReceiptRuleError::AlreadyExists,
+                        )
                     }
                     "InvalidLambdaFunction" => {
-                        return RusotoError::Service(CreateReceiptRuleError::InvalidLambdaFunction(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateReceiptRuleError::InvalidLambdaFunction,
+                        )
                     }
                     "InvalidS3Configuration" => {
-                        return RusotoError::Service(
-                            CreateReceiptRuleError::InvalidS3Configuration(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateReceiptRuleError::InvalidS3Configuration,
                         )
                     }
                     "InvalidSnsTopic" => {
-                        return RusotoError::Service(CreateReceiptRuleError::InvalidSnsTopic(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateReceiptRuleError::InvalidSnsTopic,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateReceiptRuleError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateReceiptRuleError::LimitExceeded,
+                        )
                     }
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(CreateReceiptRuleError::RuleDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateReceiptRuleError::RuleDoesNotExist,
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(CreateReceiptRuleError::RuleSetDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateReceiptRuleError::RuleSetDoesNotExist,
+                        )
                     }
                     _ => {}
                 }
@@ -7595,14 +7646,20 @@ impl CreateReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateReceiptRuleSetError::AlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateReceiptRuleSetError::AlreadyExists,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateReceiptRuleSetError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateReceiptRuleSetError::LimitExceeded,
+                        )
                     }
                     _ => {}
                 }
@@ -7649,19 +7706,28 @@ impl CreateTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateTemplateError::AlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTemplateError::AlreadyExists,
+                        )
                     }
                     "InvalidTemplate" => {
-                        return RusotoError::Service(CreateTemplateError::InvalidTemplate(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTemplateError::InvalidTemplate,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateTemplateError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTemplateError::LimitExceeded,
+                        )
                     }
                     _ => {}
                 }
@@ -7705,10 +7771,11 @@ impl DeleteConfigurationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
-                            DeleteConfigurationSetError::ConfigurationSetDoesNotExist(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteConfigurationSetError::ConfigurationSetDoesNotExist,
                         )
                     }
                     _ => {}
@@ -7756,15 +7823,17 @@ impl DeleteConfigurationSetEventDestinationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
-                        DeleteConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
-                        ),
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DeleteConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist,
                     ),
-                    "EventDestinationDoesNotExist" => return RusotoError::Service(
-                        DeleteConfigurationSetEventDestinationError::EventDestinationDoesNotExist(
-                            parsed_error.message,
-                        ),
+                    "EventDestinationDoesNotExist" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DeleteConfigurationSetEventDestinationError::EventDestinationDoesNotExist,
                     ),
                     _ => {}
                 }
@@ -7814,16 +7883,18 @@ impl DeleteConfigurationSetTrackingOptionsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
-                        DeleteConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
-                        ),
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DeleteConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist,
                     ),
                     "TrackingOptionsDoesNotExistException" => {
-                        return RusotoError::Service(
-                            DeleteConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist,
                         )
                     }
                     _ => {}
@@ -8009,9 +8080,12 @@ impl DeleteReceiptRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(DeleteReceiptRuleError::RuleSetDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteReceiptRuleError::RuleSetDoesNotExist,
+                        )
                     }
                     _ => {}
                 }
@@ -8053,9 +8127,12 @@ impl DeleteReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CannotDelete" => {
-                        return RusotoError::Service(DeleteReceiptRuleSetError::CannotDelete(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteReceiptRuleSetError::CannotDelete,
+                        )
                     }
                     _ => {}
                 }
@@ -8203,10 +8280,11 @@ impl DescribeConfigurationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
-                            DescribeConfigurationSetError::ConfigurationSetDoesNotExist(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeConfigurationSetError::ConfigurationSetDoesNotExist,
                         )
                     }
                     _ => {}
@@ -8253,14 +8331,20 @@ impl DescribeReceiptRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(DescribeReceiptRuleError::RuleDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeReceiptRuleError::RuleDoesNotExist,
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(DescribeReceiptRuleError::RuleSetDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeReceiptRuleError::RuleSetDoesNotExist,
+                        )
                     }
                     _ => {}
                 }
@@ -8303,8 +8387,11 @@ impl DescribeReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(
-                            DescribeReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReceiptRuleSetError::RuleSetDoesNotExist,
                         )
                     }
                     _ => {}
@@ -8382,7 +8469,7 @@ impl GetCustomVerificationEmailTemplateError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(GetCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),_ => {}
+                                    "CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, GetCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist),_ => {}
                                 }
             }
         }
@@ -8666,9 +8753,12 @@ impl GetTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(GetTemplateError::TemplateDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetTemplateError::TemplateDoesNotExist,
+                        )
                     }
                     _ => {}
                 }
@@ -8990,17 +9080,19 @@ impl PutConfigurationSetDeliveryOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
-                            PutConfigurationSetDeliveryOptionsError::ConfigurationSetDoesNotExist(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutConfigurationSetDeliveryOptionsError::ConfigurationSetDoesNotExist,
                         )
                     }
                     "InvalidDeliveryOptions" => {
-                        return RusotoError::Service(
-                            PutConfigurationSetDeliveryOptionsError::InvalidDeliveryOptions(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutConfigurationSetDeliveryOptionsError::InvalidDeliveryOptions,
                         )
                     }
                     _ => {}
@@ -9048,9 +9140,12 @@ impl PutIdentityPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidPolicy" => {
-                        return RusotoError::Service(PutIdentityPolicyError::InvalidPolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutIdentityPolicyError::InvalidPolicy,
+                        )
                     }
                     _ => {}
                 }
@@ -9094,13 +9189,19 @@ impl ReorderReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(ReorderReceiptRuleSetError::RuleDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ReorderReceiptRuleSetError::RuleDoesNotExist,
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(
-                            ReorderReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ReorderReceiptRuleSetError::RuleSetDoesNotExist,
                         )
                     }
                     _ => {}
@@ -9144,9 +9245,12 @@ impl SendBounceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "MessageRejected" => {
-                        return RusotoError::Service(SendBounceError::MessageRejected(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendBounceError::MessageRejected,
+                        )
                     }
                     _ => {}
                 }
@@ -9198,39 +9302,51 @@ impl SendBulkTemplatedEmailError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
-                        return RusotoError::Service(
-                            SendBulkTemplatedEmailError::AccountSendingPaused(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SendBulkTemplatedEmailError::AccountSendingPaused,
                         )
                     }
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
-                            SendBulkTemplatedEmailError::ConfigurationSetDoesNotExist(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SendBulkTemplatedEmailError::ConfigurationSetDoesNotExist,
                         )
                     }
                     "ConfigurationSetSendingPausedException" => {
-                        return RusotoError::Service(
-                            SendBulkTemplatedEmailError::ConfigurationSetSendingPaused(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SendBulkTemplatedEmailError::ConfigurationSetSendingPaused,
                         )
                     }
                     "MailFromDomainNotVerifiedException" => {
-                        return RusotoError::Service(
-                            SendBulkTemplatedEmailError::MailFromDomainNotVerified(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SendBulkTemplatedEmailError::MailFromDomainNotVerified,
                         )
                     }
                     "MessageRejected" => {
-                        return RusotoError::Service(SendBulkTemplatedEmailError::MessageRejected(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendBulkTemplatedEmailError::MessageRejected,
+                        )
                     }
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(
-                            SendBulkTemplatedEmailError::TemplateDoesNotExist(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SendBulkTemplatedEmailError::TemplateDoesNotExist,
                         )
                     }
                     _ => {}
@@ -9293,7 +9409,7 @@ impl SendCustomVerificationEmailError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(SendCustomVerificationEmailError::ConfigurationSetDoesNotExist(parsed_error.message)),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(SendCustomVerificationEmailError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::Service(SendCustomVerificationEmailError::FromEmailAddressNotVerified(parsed_error.message)),"MessageRejected" => return RusotoError::Service(SendCustomVerificationEmailError::MessageRejected(parsed_error.message)),"ProductionAccessNotGranted" => return RusotoError::Service(SendCustomVerificationEmailError::ProductionAccessNotGranted(parsed_error.message)),_ => {}
+                                    "ConfigurationSetDoesNotExist" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, SendCustomVerificationEmailError::ConfigurationSetDoesNotExist),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, SendCustomVerificationEmailError::CustomVerificationEmailTemplateDoesNotExist),"FromEmailAddressNotVerified" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, SendCustomVerificationEmailError::FromEmailAddressNotVerified),"MessageRejected" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, SendCustomVerificationEmailError::MessageRejected),"ProductionAccessNotGranted" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, SendCustomVerificationEmailError::ProductionAccessNotGranted),_ => {}
                                 }
             }
         }
@@ -9353,29 +9469,44 @@ impl SendEmailError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
-                        return RusotoError::Service(SendEmailError::AccountSendingPaused(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendEmailError::AccountSendingPaused,
+                        )
                     }
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(SendEmailError::ConfigurationSetDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendEmailError::ConfigurationSetDoesNotExist,
+                        )
                     }
                     "ConfigurationSetSendingPausedException" => {
-                        return RusotoError::Service(SendEmailError::ConfigurationSetSendingPaused(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendEmailError::ConfigurationSetSendingPaused,
+                        )
                     }
                     "MailFromDomainNotVerifiedException" => {
-                        return RusotoError::Service(SendEmailError::MailFromDomainNotVerified(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendEmailError::MailFromDomainNotVerified,
+                        )
                     }
                     "MessageRejected" => {
-                        return RusotoError::Service(SendEmailError::MessageRejected(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendEmailError::MessageRejected,
+                        )
                     }
                     _ => {}
                 }
@@ -9429,29 +9560,44 @@ impl SendRawEmailError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
-                        return RusotoError::Service(SendRawEmailError::AccountSendingPaused(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendRawEmailError::AccountSendingPaused,
+                        )
                     }
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
-                            SendRawEmailError::ConfigurationSetDoesNotExist(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SendRawEmailError::ConfigurationSetDoesNotExist,
                         )
                     }
                     "ConfigurationSetSendingPausedException" => {
-                        return RusotoError::Service(
-                            SendRawEmailError::ConfigurationSetSendingPaused(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SendRawEmailError::ConfigurationSetSendingPaused,
                         )
                     }
                     "MailFromDomainNotVerifiedException" => {
-                        return RusotoError::Service(SendRawEmailError::MailFromDomainNotVerified(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendRawEmailError::MailFromDomainNotVerified,
+                        )
                     }
                     "MessageRejected" => {
-                        return RusotoError::Service(SendRawEmailError::MessageRejected(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendRawEmailError::MessageRejected,
+                        )
                     }
                     _ => {}
                 }
@@ -9507,40 +9653,52 @@ impl SendTemplatedEmailError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
-                        return RusotoError::Service(SendTemplatedEmailError::AccountSendingPaused(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendTemplatedEmailError::AccountSendingPaused,
+                        )
                     }
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
-                            SendTemplatedEmailError::ConfigurationSetDoesNotExist(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SendTemplatedEmailError::ConfigurationSetDoesNotExist,
                         )
                     }
                     "ConfigurationSetSendingPausedException" => {
-                        return RusotoError::Service(
-                            SendTemplatedEmailError::ConfigurationSetSendingPaused(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SendTemplatedEmailError::ConfigurationSetSendingPaused,
                         )
                     }
                     "MailFromDomainNotVerifiedException" => {
-                        return RusotoError::Service(
-                            SendTemplatedEmailError::MailFromDomainNotVerified(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SendTemplatedEmailError::MailFromDomainNotVerified,
                         )
                     }
                     "MessageRejected" => {
-                        return RusotoError::Service(SendTemplatedEmailError::MessageRejected(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendTemplatedEmailError::MessageRejected,
+                        )
                     }
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(SendTemplatedEmailError::TemplateDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendTemplatedEmailError::TemplateDoesNotExist,
+                        )
                     }
                     _ => {}
                 }
@@ -9591,8 +9749,11 @@ impl SetActiveReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(
-                            SetActiveReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetActiveReceiptRuleSetError::RuleSetDoesNotExist,
                         )
                     }
                     _ => {}
@@ -9813,13 +9974,19 @@ impl SetReceiptRulePositionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(SetReceiptRulePositionError::RuleDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetReceiptRulePositionError::RuleDoesNotExist,
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(
-                            SetReceiptRulePositionError::RuleSetDoesNotExist(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetReceiptRulePositionError::RuleSetDoesNotExist,
                         )
                     }
                     _ => {}
@@ -9867,23 +10034,28 @@ impl TestRenderTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidRenderingParameter" => {
-                        return RusotoError::Service(
-                            TestRenderTemplateError::InvalidRenderingParameter(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            TestRenderTemplateError::InvalidRenderingParameter,
                         )
                     }
                     "MissingRenderingAttribute" => {
-                        return RusotoError::Service(
-                            TestRenderTemplateError::MissingRenderingAttribute(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            TestRenderTemplateError::MissingRenderingAttribute,
                         )
                     }
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(TestRenderTemplateError::TemplateDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TestRenderTemplateError::TemplateDoesNotExist,
+                        )
                     }
                     _ => {}
                 }
@@ -9972,33 +10144,38 @@ impl UpdateConfigurationSetEventDestinationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
-                        UpdateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
-                        ),
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        UpdateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist,
                     ),
-                    "EventDestinationDoesNotExist" => return RusotoError::Service(
-                        UpdateConfigurationSetEventDestinationError::EventDestinationDoesNotExist(
-                            parsed_error.message,
-                        ),
+                    "EventDestinationDoesNotExist" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        UpdateConfigurationSetEventDestinationError::EventDestinationDoesNotExist,
                     ),
-                    "InvalidCloudWatchDestination" => return RusotoError::Service(
-                        UpdateConfigurationSetEventDestinationError::InvalidCloudWatchDestination(
-                            parsed_error.message,
-                        ),
+                    "InvalidCloudWatchDestination" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        UpdateConfigurationSetEventDestinationError::InvalidCloudWatchDestination,
                     ),
                     "InvalidFirehoseDestination" => {
-                        return RusotoError::Service(
-                            UpdateConfigurationSetEventDestinationError::InvalidFirehoseDestination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateConfigurationSetEventDestinationError::InvalidFirehoseDestination,
                         )
                     }
                     "InvalidSNSDestination" => {
-                        return RusotoError::Service(
-                            UpdateConfigurationSetEventDestinationError::InvalidSNSDestination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateConfigurationSetEventDestinationError::InvalidSNSDestination,
                         )
                     }
                     _ => {}
@@ -10056,7 +10233,7 @@ impl UpdateConfigurationSetReputationMetricsEnabledError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(UpdateConfigurationSetReputationMetricsEnabledError::ConfigurationSetDoesNotExist(parsed_error.message)),_ => {}
+                                    "ConfigurationSetDoesNotExist" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateConfigurationSetReputationMetricsEnabledError::ConfigurationSetDoesNotExist),_ => {}
                                 }
             }
         }
@@ -10100,10 +10277,11 @@ impl UpdateConfigurationSetSendingEnabledError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
-                            UpdateConfigurationSetSendingEnabledError::ConfigurationSetDoesNotExist(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateConfigurationSetSendingEnabledError::ConfigurationSetDoesNotExist,
                         )
                     }
                     _ => {}
@@ -10153,23 +10331,26 @@ impl UpdateConfigurationSetTrackingOptionsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
-                        UpdateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
-                        ),
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        UpdateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist,
                     ),
                     "InvalidTrackingOptions" => {
-                        return RusotoError::Service(
-                            UpdateConfigurationSetTrackingOptionsError::InvalidTrackingOptions(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateConfigurationSetTrackingOptionsError::InvalidTrackingOptions,
                         )
                     }
                     "TrackingOptionsDoesNotExistException" => {
-                        return RusotoError::Service(
-                            UpdateConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist,
                         )
                     }
                     _ => {}
@@ -10225,7 +10406,7 @@ impl UpdateCustomVerificationEmailTemplateError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CustomVerificationEmailInvalidContent" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(parsed_error.message)),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(parsed_error.message)),_ => {}
+                                    "CustomVerificationEmailInvalidContent" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist),"FromEmailAddressNotVerified" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified),_ => {}
                                 }
             }
         }
@@ -10277,34 +10458,52 @@ impl UpdateReceiptRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidLambdaFunction" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::InvalidLambdaFunction(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateReceiptRuleError::InvalidLambdaFunction,
+                        )
                     }
                     "InvalidS3Configuration" => {
-                        return RusotoError::Service(
-                            UpdateReceiptRuleError::InvalidS3Configuration(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateReceiptRuleError::InvalidS3Configuration,
                         )
                     }
                     "InvalidSnsTopic" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::InvalidSnsTopic(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateReceiptRuleError::InvalidSnsTopic,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateReceiptRuleError::LimitExceeded,
+                        )
                     }
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::RuleDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateReceiptRuleError::RuleDoesNotExist,
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::RuleSetDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateReceiptRuleError::RuleSetDoesNotExist,
+                        )
                     }
                     _ => {}
                 }
@@ -10353,14 +10552,20 @@ impl UpdateTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTemplate" => {
-                        return RusotoError::Service(UpdateTemplateError::InvalidTemplate(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateTemplateError::InvalidTemplate,
+                        )
                     }
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(UpdateTemplateError::TemplateDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateTemplateError::TemplateDoesNotExist,
+                        )
                     }
                     _ => {}
                 }
@@ -10998,11 +11203,12 @@ pub struct SesClient {
 impl SesClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SesClient {
         SesClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SES"),
         }
     }
 
@@ -11017,12 +11223,32 @@ impl SesClient {
     {
         SesClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SES"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SesClient {
-        SesClient { client, region }
+        SesClient {
+            client,
+            region: region_for_service(region, "SES"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SesClient {
+        SesClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SES"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SesClient {
+        SesClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -11034,6 +11260,7 @@ impl Ses for SesClient {
         input: CloneReceiptRuleSetRequest,
     ) -> Result<CloneReceiptRuleSetResponse, RusotoError<CloneReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CloneReceiptRuleSet");
         let params = self.new_params("CloneReceiptRuleSet");
         let mut params = params;
         CloneReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11056,6 +11283,7 @@ impl Ses for SesClient {
         input: CreateConfigurationSetRequest,
     ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateConfigurationSet");
         let params = self.new_params("CreateConfigurationSet");
         let mut params = params;
         CreateConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11081,6 +11309,7 @@ impl Ses for SesClient {
         RusotoError<CreateConfigurationSetEventDestinationError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateConfigurationSetEventDestination");
         let params = self.new_params("CreateConfigurationSetEventDestination");
         let mut params = params;
         CreateConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
@@ -11109,6 +11338,7 @@ impl Ses for SesClient {
         RusotoError<CreateConfigurationSetTrackingOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateConfigurationSetTrackingOptions");
         let params = self.new_params("CreateConfigurationSetTrackingOptions");
         let mut params = params;
         CreateConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -11134,6 +11364,7 @@ impl Ses for SesClient {
         input: CreateCustomVerificationEmailTemplateRequest,
     ) -> Result<(), RusotoError<CreateCustomVerificationEmailTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateCustomVerificationEmailTemplate");
         let params = self.new_params("CreateCustomVerificationEmailTemplate");
         let mut params = params;
         CreateCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -11157,6 +11388,7 @@ impl Ses for SesClient {
         input: CreateReceiptFilterRequest,
     ) -> Result<CreateReceiptFilterResponse, RusotoError<CreateReceiptFilterError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateReceiptFilter");
         let params = self.new_params("CreateReceiptFilter");
         let mut params = params;
         CreateReceiptFilterRequestSerializer::serialize(&mut params, "", &input);
@@ -11179,6 +11411,7 @@ impl Ses for SesClient {
         input: CreateReceiptRuleRequest,
     ) -> Result<CreateReceiptRuleResponse, RusotoError<CreateReceiptRuleError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateReceiptRule");
         let params = self.new_params("CreateReceiptRule");
         let mut params = params;
         CreateReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -11201,6 +11434,7 @@ impl Ses for SesClient {
         input: CreateReceiptRuleSetRequest,
     ) -> Result<CreateReceiptRuleSetResponse, RusotoError<CreateReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateReceiptRuleSet");
         let params = self.new_params("CreateReceiptRuleSet");
         let mut params = params;
         CreateReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11223,6 +11457,7 @@ impl Ses for SesClient {
         input: CreateTemplateRequest,
     ) -> Result<CreateTemplateResponse, RusotoError<CreateTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateTemplate");
         let params = self.new_params("CreateTemplate");
         let mut params = params;
         CreateTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -11245,6 +11480,7 @@ impl Ses for SesClient {
         input: DeleteConfigurationSetRequest,
     ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteConfigurationSet");
         let params = self.new_params("DeleteConfigurationSet");
         let mut params = params;
         DeleteConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11270,6 +11506,7 @@ impl Ses for SesClient {
         RusotoError<DeleteConfigurationSetEventDestinationError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteConfigurationSetEventDestination");
         let params = self.new_params("DeleteConfigurationSetEventDestination");
         let mut params = params;
         DeleteConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
@@ -11298,6 +11535,7 @@ impl Ses for SesClient {
         RusotoError<DeleteConfigurationSetTrackingOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteConfigurationSetTrackingOptions");
         let params = self.new_params("DeleteConfigurationSetTrackingOptions");
         let mut params = params;
         DeleteConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -11323,6 +11561,7 @@ impl Ses for SesClient {
         input: DeleteCustomVerificationEmailTemplateRequest,
     ) -> Result<(), RusotoError<DeleteCustomVerificationEmailTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteCustomVerificationEmailTemplate");
         let params = self.new_params("DeleteCustomVerificationEmailTemplate");
         let mut params = params;
         DeleteCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -11346,6 +11585,7 @@ impl Ses for SesClient {
         input: DeleteIdentityRequest,
     ) -> Result<DeleteIdentityResponse, RusotoError<DeleteIdentityError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteIdentity");
         let params = self.new_params("DeleteIdentity");
         let mut params = params;
         DeleteIdentityRequestSerializer::serialize(&mut params, "", &input);
@@ -11368,6 +11608,7 @@ impl Ses for SesClient {
         input: DeleteIdentityPolicyRequest,
     ) -> Result<DeleteIdentityPolicyResponse, RusotoError<DeleteIdentityPolicyError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteIdentityPolicy");
         let params = self.new_params("DeleteIdentityPolicy");
         let mut params = params;
         DeleteIdentityPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -11390,6 +11631,7 @@ impl Ses for SesClient {
         input: DeleteReceiptFilterRequest,
     ) -> Result<DeleteReceiptFilterResponse, RusotoError<DeleteReceiptFilterError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteReceiptFilter");
         let params = self.new_params("DeleteReceiptFilter");
         let mut params = params;
         DeleteReceiptFilterRequestSerializer::serialize(&mut params, "", &input);
@@ -11412,6 +11654,7 @@ impl Ses for SesClient {
         input: DeleteReceiptRuleRequest,
     ) -> Result<DeleteReceiptRuleResponse, RusotoError<DeleteReceiptRuleError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteReceiptRule");
         let params = self.new_params("DeleteReceiptRule");
         let mut params = params;
         DeleteReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -11434,6 +11677,7 @@ impl Ses for SesClient {
         input: DeleteReceiptRuleSetRequest,
     ) -> Result<DeleteReceiptRuleSetResponse, RusotoError<DeleteReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteReceiptRuleSet");
         let params = self.new_params("DeleteReceiptRuleSet");
         let mut params = params;
         DeleteReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11456,6 +11700,7 @@ impl Ses for SesClient {
         input: DeleteTemplateRequest,
     ) -> Result<DeleteTemplateResponse, RusotoError<DeleteTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteTemplate");
         let params = self.new_params("DeleteTemplate");
         let mut params = params;
         DeleteTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -11478,6 +11723,7 @@ impl Ses for SesClient {
         input: DeleteVerifiedEmailAddressRequest,
     ) -> Result<(), RusotoError<DeleteVerifiedEmailAddressError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteVerifiedEmailAddress");
         let params = self.new_params("DeleteVerifiedEmailAddress");
         let mut params = params;
         DeleteVerifiedEmailAddressRequestSerializer::serialize(&mut params, "", &input);
@@ -11499,6 +11745,7 @@ impl Ses for SesClient {
     ) -> Result<DescribeActiveReceiptRuleSetResponse, RusotoError<DescribeActiveReceiptRuleSetError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeActiveReceiptRuleSet");
         let params = self.new_params("DescribeActiveReceiptRuleSet");
         let mut params = params;
         DescribeActiveReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11532,6 +11779,7 @@ impl Ses for SesClient {
         input: DescribeConfigurationSetRequest,
     ) -> Result<DescribeConfigurationSetResponse, RusotoError<DescribeConfigurationSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeConfigurationSet");
         let params = self.new_params("DescribeConfigurationSet");
         let mut params = params;
         DescribeConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11565,6 +11813,7 @@ impl Ses for SesClient {
         input: DescribeReceiptRuleRequest,
     ) -> Result<DescribeReceiptRuleResponse, RusotoError<DescribeReceiptRuleError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeReceiptRule");
         let params = self.new_params("DescribeReceiptRule");
         let mut params = params;
         DescribeReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -11598,6 +11847,7 @@ impl Ses for SesClient {
         input: DescribeReceiptRuleSetRequest,
     ) -> Result<DescribeReceiptRuleSetResponse, RusotoError<DescribeReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeReceiptRuleSet");
         let params = self.new_params("DescribeReceiptRuleSet");
         let mut params = params;
         DescribeReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11630,6 +11880,7 @@ impl Ses for SesClient {
         &self,
     ) -> Result<GetAccountSendingEnabledResponse, RusotoError<GetAccountSendingEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetAccountSendingEnabled");
         let params = self.new_params("GetAccountSendingEnabled");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11665,6 +11916,7 @@ impl Ses for SesClient {
         RusotoError<GetCustomVerificationEmailTemplateError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetCustomVerificationEmailTemplate");
         let params = self.new_params("GetCustomVerificationEmailTemplate");
         let mut params = params;
         GetCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -11702,6 +11954,7 @@ impl Ses for SesClient {
     ) -> Result<GetIdentityDkimAttributesResponse, RusotoError<GetIdentityDkimAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityDkimAttributes");
         let params = self.new_params("GetIdentityDkimAttributes");
         let mut params = params;
         GetIdentityDkimAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -11738,6 +11991,7 @@ impl Ses for SesClient {
         RusotoError<GetIdentityMailFromDomainAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityMailFromDomainAttributes");
         let params = self.new_params("GetIdentityMailFromDomainAttributes");
         let mut params = params;
         GetIdentityMailFromDomainAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -11777,6 +12031,7 @@ impl Ses for SesClient {
         RusotoError<GetIdentityNotificationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityNotificationAttributes");
         let params = self.new_params("GetIdentityNotificationAttributes");
         let mut params = params;
         GetIdentityNotificationAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -11813,6 +12068,7 @@ impl Ses for SesClient {
         input: GetIdentityPoliciesRequest,
     ) -> Result<GetIdentityPoliciesResponse, RusotoError<GetIdentityPoliciesError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityPolicies");
         let params = self.new_params("GetIdentityPolicies");
         let mut params = params;
         GetIdentityPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -11849,6 +12105,7 @@ impl Ses for SesClient {
         RusotoError<GetIdentityVerificationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityVerificationAttributes");
         let params = self.new_params("GetIdentityVerificationAttributes");
         let mut params = params;
         GetIdentityVerificationAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -11882,6 +12139,7 @@ impl Ses for SesClient {
     /// <p>Provides the sending limits for the Amazon SES account. </p> <p>You can execute this operation no more than once per second.</p>
     async fn get_send_quota(&self) -> Result<GetSendQuotaResponse, RusotoError<GetSendQuotaError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetSendQuota");
         let params = self.new_params("GetSendQuota");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11911,6 +12169,7 @@ impl Ses for SesClient {
         &self,
     ) -> Result<GetSendStatisticsResponse, RusotoError<GetSendStatisticsError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetSendStatistics");
         let params = self.new_params("GetSendStatistics");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11943,6 +12202,7 @@ impl Ses for SesClient {
         input: GetTemplateRequest,
     ) -> Result<GetTemplateResponse, RusotoError<GetTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetTemplate");
         let params = self.new_params("GetTemplate");
         let mut params = params;
         GetTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -11973,6 +12233,7 @@ impl Ses for SesClient {
         input: ListConfigurationSetsRequest,
     ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListConfigurationSets");
         let params = self.new_params("ListConfigurationSets");
         let mut params = params;
         ListConfigurationSetsRequestSerializer::serialize(&mut params, "", &input);
@@ -12009,6 +12270,7 @@ impl Ses for SesClient {
         RusotoError<ListCustomVerificationEmailTemplatesError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListCustomVerificationEmailTemplates");
         let params = self.new_params("ListCustomVerificationEmailTemplates");
         let mut params = params;
         ListCustomVerificationEmailTemplatesRequestSerializer::serialize(&mut params, "", &input);
@@ -12045,6 +12307,7 @@ impl Ses for SesClient {
         input: ListIdentitiesRequest,
     ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListIdentities");
         let params = self.new_params("ListIdentities");
         let mut params = params;
         ListIdentitiesRequestSerializer::serialize(&mut params, "", &input);
@@ -12076,6 +12339,7 @@ impl Ses for SesClient {
         input: ListIdentityPoliciesRequest,
     ) -> Result<ListIdentityPoliciesResponse, RusotoError<ListIdentityPoliciesError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListIdentityPolicies");
         let params = self.new_params("ListIdentityPolicies");
         let mut params = params;
         ListIdentityPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -12109,6 +12373,7 @@ impl Ses for SesClient {
         input: ListReceiptFiltersRequest,
     ) -> Result<ListReceiptFiltersResponse, RusotoError<ListReceiptFiltersError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListReceiptFilters");
         let params = self.new_params("ListReceiptFilters");
         let mut params = params;
         ListReceiptFiltersRequestSerializer::serialize(&mut params, "", &input);
@@ -12142,6 +12407,7 @@ impl Ses for SesClient {
         input: ListReceiptRuleSetsRequest,
     ) -> Result<ListReceiptRuleSetsResponse, RusotoError<ListReceiptRuleSetsError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListReceiptRuleSets");
         let params = self.new_params("ListReceiptRuleSets");
         let mut params = params;
         ListReceiptRuleSetsRequestSerializer::serialize(&mut params, "", &input);
@@ -12175,6 +12441,7 @@ impl Ses for SesClient {
         input: ListTemplatesRequest,
     ) -> Result<ListTemplatesResponse, RusotoError<ListTemplatesError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListTemplates");
         let params = self.new_params("ListTemplates");
         let mut params = params;
         ListTemplatesRequestSerializer::serialize(&mut params, "", &input);
@@ -12206,6 +12473,7 @@ impl Ses for SesClient {
     ) -> Result<ListVerifiedEmailAddressesResponse, RusotoError<ListVerifiedEmailAddressesError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListVerifiedEmailAddresses");
         let params = self.new_params("ListVerifiedEmailAddresses");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -12241,6 +12509,7 @@ impl Ses for SesClient {
         RusotoError<PutConfigurationSetDeliveryOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("PutConfigurationSetDeliveryOptions");
         let params = self.new_params("PutConfigurationSetDeliveryOptions");
         let mut params = params;
         PutConfigurationSetDeliveryOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -12266,6 +12535,7 @@ impl Ses for SesClient {
         input: PutIdentityPolicyRequest,
     ) -> Result<PutIdentityPolicyResponse, RusotoError<PutIdentityPolicyError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("PutIdentityPolicy");
         let params = self.new_params("PutIdentityPolicy");
         let mut params = params;
         PutIdentityPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -12288,6 +12558,7 @@ impl Ses for SesClient {
         input: ReorderReceiptRuleSetRequest,
     ) -> Result<ReorderReceiptRuleSetResponse, RusotoError<ReorderReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ReorderReceiptRuleSet");
         let params = self.new_params("ReorderReceiptRuleSet");
         let mut params = params;
         ReorderReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -12310,6 +12581,7 @@ impl Ses for SesClient {
         input: SendBounceRequest,
     ) -> Result<SendBounceResponse, RusotoError<SendBounceError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendBounce");
         let params = self.new_params("SendBounce");
         let mut params = params;
         SendBounceRequestSerializer::serialize(&mut params, "", &input);
@@ -12340,6 +12612,7 @@ impl Ses for SesClient {
         input: SendBulkTemplatedEmailRequest,
     ) -> Result<SendBulkTemplatedEmailResponse, RusotoError<SendBulkTemplatedEmailError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendBulkTemplatedEmail");
         let params = self.new_params("SendBulkTemplatedEmail");
         let mut params = params;
         SendBulkTemplatedEmailRequestSerializer::serialize(&mut params, "", &input);
@@ -12374,6 +12647,7 @@ impl Ses for SesClient {
     ) -> Result<SendCustomVerificationEmailResponse, RusotoError<SendCustomVerificationEmailError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendCustomVerificationEmail");
         let params = self.new_params("SendCustomVerificationEmail");
         let mut params = params;
         SendCustomVerificationEmailRequestSerializer::serialize(&mut params, "", &input);
@@ -12407,6 +12681,7 @@ impl Ses for SesClient {
         input: SendEmailRequest,
     ) -> Result<SendEmailResponse, RusotoError<SendEmailError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendEmail");
         let params = self.new_params("SendEmail");
         let mut params = params;
         SendEmailRequestSerializer::serialize(&mut params, "", &input);
@@ -12437,6 +12712,7 @@ impl Ses for SesClient {
         input: SendRawEmailRequest,
     ) -> Result<SendRawEmailResponse, RusotoError<SendRawEmailError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendRawEmail");
         let params = self.new_params("SendRawEmail");
         let mut params = params;
         SendRawEmailRequestSerializer::serialize(&mut params, "", &input);
@@ -12468,6 +12744,7 @@ impl Ses for SesClient {
         input: SendTemplatedEmailRequest,
     ) -> Result<SendTemplatedEmailResponse, RusotoError<SendTemplatedEmailError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendTemplatedEmail");
         let params = self.new_params("SendTemplatedEmail");
         let mut params = params;
         SendTemplatedEmailRequestSerializer::serialize(&mut params, "", &input);
@@ -12501,6 +12778,7 @@ impl Ses for SesClient {
         input: SetActiveReceiptRuleSetRequest,
     ) -> Result<SetActiveReceiptRuleSetResponse, RusotoError<SetActiveReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetActiveReceiptRuleSet");
         let params = self.new_params("SetActiveReceiptRuleSet");
         let mut params = params;
         SetActiveReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -12523,6 +12801,7 @@ impl Ses for SesClient {
         input: SetIdentityDkimEnabledRequest,
     ) -> Result<SetIdentityDkimEnabledResponse, RusotoError<SetIdentityDkimEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityDkimEnabled");
         let params = self.new_params("SetIdentityDkimEnabled");
         let mut params = params;
         SetIdentityDkimEnabledRequestSerializer::serialize(&mut params, "", &input);
@@ -12548,6 +12827,7 @@ impl Ses for SesClient {
         RusotoError<SetIdentityFeedbackForwardingEnabledError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityFeedbackForwardingEnabled");
         let params = self.new_params("SetIdentityFeedbackForwardingEnabled");
         let mut params = params;
         SetIdentityFeedbackForwardingEnabledRequestSerializer::serialize(&mut params, "", &input);
@@ -12576,6 +12856,7 @@ impl Ses for SesClient {
         RusotoError<SetIdentityHeadersInNotificationsEnabledError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityHeadersInNotificationsEnabled");
         let params = self.new_params("SetIdentityHeadersInNotificationsEnabled");
         let mut params = params;
         SetIdentityHeadersInNotificationsEnabledRequestSerializer::serialize(
@@ -12606,6 +12887,7 @@ impl Ses for SesClient {
     ) -> Result<SetIdentityMailFromDomainResponse, RusotoError<SetIdentityMailFromDomainError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityMailFromDomain");
         let params = self.new_params("SetIdentityMailFromDomain");
         let mut params = params;
         SetIdentityMailFromDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -12629,6 +12911,7 @@ impl Ses for SesClient {
     ) -> Result<SetIdentityNotificationTopicResponse, RusotoError<SetIdentityNotificationTopicError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityNotificationTopic");
         let params = self.new_params("SetIdentityNotificationTopic");
         let mut params = params;
         SetIdentityNotificationTopicRequestSerializer::serialize(&mut params, "", &input);
@@ -12651,6 +12934,7 @@ impl Ses for SesClient {
         input: SetReceiptRulePositionRequest,
     ) -> Result<SetReceiptRulePositionResponse, RusotoError<SetReceiptRulePositionError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetReceiptRulePosition");
         let params = self.new_params("SetReceiptRulePosition");
         let mut params = params;
         SetReceiptRulePositionRequestSerializer::serialize(&mut params, "", &input);
@@ -12673,6 +12957,7 @@ impl Ses for SesClient {
         input: TestRenderTemplateRequest,
     ) -> Result<TestRenderTemplateResponse, RusotoError<TestRenderTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("TestRenderTemplate");
         let params = self.new_params("TestRenderTemplate");
         let mut params = params;
         TestRenderTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -12706,6 +12991,7 @@ impl Ses for SesClient {
         input: UpdateAccountSendingEnabledRequest,
     ) -> Result<(), RusotoError<UpdateAccountSendingEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateAccountSendingEnabled");
         let params = self.new_params("UpdateAccountSendingEnabled");
         let mut params = params;
         UpdateAccountSendingEnabledRequestSerializer::serialize(&mut params, "", &input);
@@ -12729,6 +13015,7 @@ impl Ses for SesClient {
         RusotoError<UpdateConfigurationSetEventDestinationError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetEventDestination");
         let params = self.new_params("UpdateConfigurationSetEventDestination");
         let mut params = params;
         UpdateConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
@@ -12754,6 +13041,7 @@ impl Ses for SesClient {
         input: UpdateConfigurationSetReputationMetricsEnabledRequest,
     ) -> Result<(), RusotoError<UpdateConfigurationSetReputationMetricsEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetReputationMetricsEnabled");
         let params = self.new_params("UpdateConfigurationSetReputationMetricsEnabled");
         let mut params = params;
         UpdateConfigurationSetReputationMetricsEnabledRequestSerializer::serialize(
@@ -12781,6 +13069,7 @@ impl Ses for SesClient {
         input: UpdateConfigurationSetSendingEnabledRequest,
     ) -> Result<(), RusotoError<UpdateConfigurationSetSendingEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetSendingEnabled");
         let params = self.new_params("UpdateConfigurationSetSendingEnabled");
         let mut params = params;
         UpdateConfigurationSetSendingEnabledRequestSerializer::serialize(&mut params, "", &input);
@@ -12807,6 +13096,7 @@ impl Ses for SesClient {
         RusotoError<UpdateConfigurationSetTrackingOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetTrackingOptions");
         let params = self.new_params("UpdateConfigurationSetTrackingOptions");
         let mut params = params;
         UpdateConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -12832,6 +13122,7 @@ impl Ses for SesClient {
         input: UpdateCustomVerificationEmailTemplateRequest,
     ) -> Result<(), RusotoError<UpdateCustomVerificationEmailTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateCustomVerificationEmailTemplate");
         let params = self.new_params("UpdateCustomVerificationEmailTemplate");
         let mut params = params;
         UpdateCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -12855,6 +13146,7 @@ impl Ses for SesClient {
         input: UpdateReceiptRuleRequest,
     ) -> Result<UpdateReceiptRuleResponse, RusotoError<UpdateReceiptRuleError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateReceiptRule");
         let params = self.new_params("UpdateReceiptRule");
         let mut params = params;
         UpdateReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -12877,6 +13169,7 @@ impl Ses for SesClient {
         input: UpdateTemplateRequest,
     ) -> Result<UpdateTemplateResponse, RusotoError<UpdateTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateTemplate");
         let params = self.new_params("UpdateTemplate");
         let mut params = params;
         UpdateTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -12899,6 +13192,7 @@ impl Ses for SesClient {
         input: VerifyDomainDkimRequest,
     ) -> Result<VerifyDomainDkimResponse, RusotoError<VerifyDomainDkimError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyDomainDkim");
         let params = self.new_params("VerifyDomainDkim");
         let mut params = params;
         VerifyDomainDkimRequestSerializer::serialize(&mut params, "", &input);
@@ -12930,6 +13224,7 @@ impl Ses for SesClient {
         input: VerifyDomainIdentityRequest,
     ) -> Result<VerifyDomainIdentityResponse, RusotoError<VerifyDomainIdentityError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyDomainIdentity");
         let params = self.new_params("VerifyDomainIdentity");
         let mut params = params;
         VerifyDomainIdentityRequestSerializer::serialize(&mut params, "", &input);
@@ -12963,6 +13258,7 @@ impl Ses for SesClient {
         input: VerifyEmailAddressRequest,
     ) -> Result<(), RusotoError<VerifyEmailAddressError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyEmailAddress");
         let params = self.new_params("VerifyEmailAddress");
         let mut params = params;
         VerifyEmailAddressRequestSerializer::serialize(&mut params, "", &input);
@@ -12983,6 +13279,7 @@ impl Ses for SesClient {
         input: VerifyEmailIdentityRequest,
     ) -> Result<VerifyEmailIdentityResponse, RusotoError<VerifyEmailIdentityError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyEmailIdentity");
         let params = self.new_params("VerifyEmailIdentity");
         let mut params = params;
         VerifyEmailIdentityRequestSerializer::serialize(&mut params, "", &input);
@@ -13000,6 +13297,759 @@ impl Ses for SesClient {
     }
 }
 
+/// A blocking client for the Amazon SES API, running the calls of a `SesClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SesClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SesBlockingClient {
+    client: SesClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SesBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SesBlockingClient {
+        SesBlockingClient::from_client(SesClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SesBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SesBlockingClient::from_client(SesClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> SesBlockingClient {
+        SesBlockingClient::from_client(SesClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SesBlockingClient {
+        SesBlockingClient::from_client(SesClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SesClient) -> SesBlockingClient {
+        SesBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> SesBlockingClient {
+        SesBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SesBlockingClient {
+        SesBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Ses::clone_receipt_rule_set`].
+    pub fn clone_receipt_rule_set(
+        &self,
+        input: CloneReceiptRuleSetRequest,
+    ) -> Result<CloneReceiptRuleSetResponse, RusotoError<CloneReceiptRuleSetError>> {
+        self.runtime
+            .block_on(self.client.clone_receipt_rule_set(input))
+    }
+
+    /// Blocking version of [`Ses::create_configuration_set`].
+    pub fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.create_configuration_set(input))
+    }
+
+    /// Blocking version of [`Ses::create_configuration_set_event_destination`].
+    pub fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        CreateConfigurationSetEventDestinationResponse,
+        RusotoError<CreateConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_configuration_set_event_destination(input),
+        )
+    }
+
+    /// Blocking version of [`Ses::create_configuration_set_tracking_options`].
+    pub fn create_configuration_set_tracking_options(
+        &self,
+        input: CreateConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        CreateConfigurationSetTrackingOptionsResponse,
+        RusotoError<CreateConfigurationSetTrackingOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_configuration_set_tracking_options(input))
+    }
+
+    /// Blocking version of [`Ses::create_custom_verification_email_template`].
+    pub fn create_custom_verification_email_template(
+        &self,
+        input: CreateCustomVerificationEmailTemplateRequest,
+    ) -> Result<(), RusotoError<CreateCustomVerificationEmailTemplateError>> {
+        self.runtime
+            .block_on(self.client.create_custom_verification_email_template(input))
+    }
+
+    /// Blocking version of [`Ses::create_receipt_filter`].
+    pub fn create_receipt_filter(
+        &self,
+        input: CreateReceiptFilterRequest,
+    ) -> Result<CreateReceiptFilterResponse, RusotoError<CreateReceiptFilterError>> {
+        self.runtime
+            .block_on(self.client.create_receipt_filter(input))
+    }
+
+    /// Blocking version of [`Ses::create_receipt_rule`].
+    pub fn create_receipt_rule(
+        &self,
+        input: CreateReceiptRuleRequest,
+    ) -> Result<CreateReceiptRuleResponse, RusotoError<CreateReceiptRuleError>> {
+        self.runtime
+            .block_on(self.client.create_receipt_rule(input))
+    }
+
+    /// Blocking version of [`Ses::create_receipt_rule_set`].
+    pub fn create_receipt_rule_set(
+        &self,
+        input: CreateReceiptRuleSetRequest,
+    ) -> Result<CreateReceiptRuleSetResponse, RusotoError<CreateReceiptRuleSetError>> {
+        self.runtime
+            .block_on(self.client.create_receipt_rule_set(input))
+    }
+
+    /// Blocking version of [`Ses::create_template`].
+    pub fn create_template(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> Result<CreateTemplateResponse, RusotoError<CreateTemplateError>> {
+        self.runtime.block_on(self.client.create_template(input))
+    }
+
+    /// Blocking version of [`Ses::delete_configuration_set`].
+    pub fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_set(input))
+    }
+
+    /// Blocking version of [`Ses::delete_configuration_set_event_destination`].
+    pub fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        DeleteConfigurationSetEventDestinationResponse,
+        RusotoError<DeleteConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_configuration_set_event_destination(input),
+        )
+    }
+
+    /// Blocking version of [`Ses::delete_configuration_set_tracking_options`].
+    pub fn delete_configuration_set_tracking_options(
+        &self,
+        input: DeleteConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        DeleteConfigurationSetTrackingOptionsResponse,
+        RusotoError<DeleteConfigurationSetTrackingOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_configuration_set_tracking_options(input))
+    }
+
+    /// Blocking version of [`Ses::delete_custom_verification_email_template`].
+    pub fn delete_custom_verification_email_template(
+        &self,
+        input: DeleteCustomVerificationEmailTemplateRequest,
+    ) -> Result<(), RusotoError<DeleteCustomVerificationEmailTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_custom_verification_email_template(input))
+    }
+
+    /// Blocking version of [`Ses::delete_identity`].
+    pub fn delete_identity(
+        &self,
+        input: DeleteIdentityRequest,
+    ) -> Result<DeleteIdentityResponse, RusotoError<DeleteIdentityError>> {
+        self.runtime.block_on(self.client.delete_identity(input))
+    }
+
+    /// Blocking version of [`Ses::delete_identity_policy`].
+    pub fn delete_identity_policy(
+        &self,
+        input: DeleteIdentityPolicyRequest,
+    ) -> Result<DeleteIdentityPolicyResponse, RusotoError<DeleteIdentityPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_identity_policy(input))
+    }
+
+    /// Blocking version of [`Ses::delete_receipt_filter`].
+    pub fn delete_receipt_filter(
+        &self,
+        input: DeleteReceiptFilterRequest,
+    ) -> Result<DeleteReceiptFilterResponse, RusotoError<DeleteReceiptFilterError>> {
+        self.runtime
+            .block_on(self.client.delete_receipt_filter(input))
+    }
+
+    /// Blocking version of [`Ses::delete_receipt_rule`].
+    pub fn delete_receipt_rule(
+        &self,
+        input: DeleteReceiptRuleRequest,
+    ) -> Result<DeleteReceiptRuleResponse, RusotoError<DeleteReceiptRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_receipt_rule(input))
+    }
+
+    /// Blocking version of [`Ses::delete_receipt_rule_set`].
+    pub fn delete_receipt_rule_set(
+        &self,
+        input: DeleteReceiptRuleSetRequest,
+    ) -> Result<DeleteReceiptRuleSetResponse, RusotoError<DeleteReceiptRuleSetError>> {
+        self.runtime
+            .block_on(self.client.delete_receipt_rule_set(input))
+    }
+
+    /// Blocking version of [`Ses::delete_template`].
+    pub fn delete_template(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> Result<DeleteTemplateResponse, RusotoError<DeleteTemplateError>> {
+        self.runtime.block_on(self.client.delete_template(input))
+    }
+
+    /// Blocking version of [`Ses::delete_verified_email_address`].
+    pub fn delete_verified_email_address(
+        &self,
+        input: DeleteVerifiedEmailAddressRequest,
+    ) -> Result<(), RusotoError<DeleteVerifiedEmailAddressError>> {
+        self.runtime
+            .block_on(self.client.delete_verified_email_address(input))
+    }
+
+    /// Blocking version of [`Ses::describe_active_receipt_rule_set`].
+    pub fn describe_active_receipt_rule_set(
+        &self,
+        input: DescribeActiveReceiptRuleSetRequest,
+    ) -> Result<DescribeActiveReceiptRuleSetResponse, RusotoError<DescribeActiveReceiptRuleSetError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_active_receipt_rule_set(input))
+    }
+
+    /// Blocking version of [`Ses::describe_configuration_set`].
+    pub fn describe_configuration_set(
+        &self,
+        input: DescribeConfigurationSetRequest,
+    ) -> Result<DescribeConfigurationSetResponse, RusotoError<DescribeConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.describe_configuration_set(input))
+    }
+
+    /// Blocking version of [`Ses::describe_receipt_rule`].
+    pub fn describe_receipt_rule(
+        &self,
+        input: DescribeReceiptRuleRequest,
+    ) -> Result<DescribeReceiptRuleResponse, RusotoError<DescribeReceiptRuleError>> {
+        self.runtime
+            .block_on(self.client.describe_receipt_rule(input))
+    }
+
+    /// Blocking version of [`Ses::describe_receipt_rule_set`].
+    pub fn describe_receipt_rule_set(
+        &self,
+        input: DescribeReceiptRuleSetRequest,
+    ) -> Result<DescribeReceiptRuleSetResponse, RusotoError<DescribeReceiptRuleSetError>> {
+        self.runtime
+            .block_on(self.client.describe_receipt_rule_set(input))
+    }
+
+    /// Blocking version of [`Ses::get_account_sending_enabled`].
+    pub fn get_account_sending_enabled(
+        &self,
+    ) -> Result<GetAccountSendingEnabledResponse, RusotoError<GetAccountSendingEnabledError>> {
+        self.runtime
+            .block_on(self.client.get_account_sending_enabled())
+    }
+
+    /// Blocking version of [`Ses::get_custom_verification_email_template`].
+    pub fn get_custom_verification_email_template(
+        &self,
+        input: GetCustomVerificationEmailTemplateRequest,
+    ) -> Result<
+        GetCustomVerificationEmailTemplateResponse,
+        RusotoError<GetCustomVerificationEmailTemplateError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_custom_verification_email_template(input))
+    }
+
+    /// Blocking version of [`Ses::get_identity_dkim_attributes`].
+    pub fn get_identity_dkim_attributes(
+        &self,
+        input: GetIdentityDkimAttributesRequest,
+    ) -> Result<GetIdentityDkimAttributesResponse, RusotoError<GetIdentityDkimAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_identity_dkim_attributes(input))
+    }
+
+    /// Blocking version of [`Ses::get_identity_mail_from_domain_attributes`].
+    pub fn get_identity_mail_from_domain_attributes(
+        &self,
+        input: GetIdentityMailFromDomainAttributesRequest,
+    ) -> Result<
+        GetIdentityMailFromDomainAttributesResponse,
+        RusotoError<GetIdentityMailFromDomainAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_identity_mail_from_domain_attributes(input))
+    }
+
+    /// Blocking version of [`Ses::get_identity_notification_attributes`].
+    pub fn get_identity_notification_attributes(
+        &self,
+        input: GetIdentityNotificationAttributesRequest,
+    ) -> Result<
+        GetIdentityNotificationAttributesResponse,
+        RusotoError<GetIdentityNotificationAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_identity_notification_attributes(input))
+    }
+
+    /// Blocking version of [`Ses::get_identity_policies`].
+    pub fn get_identity_policies(
+        &self,
+        input: GetIdentityPoliciesRequest,
+    ) -> Result<GetIdentityPoliciesResponse, RusotoError<GetIdentityPoliciesError>> {
+        self.runtime
+            .block_on(self.client.get_identity_policies(input))
+    }
+
+    /// Blocking version of [`Ses::get_identity_verification_attributes`].
+    pub fn get_identity_verification_attributes(
+        &self,
+        input: GetIdentityVerificationAttributesRequest,
+    ) -> Result<
+        GetIdentityVerificationAttributesResponse,
+        RusotoError<GetIdentityVerificationAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_identity_verification_attributes(input))
+    }
+
+    /// Blocking version of [`Ses::get_send_quota`].
+    pub fn get_send_quota(&self) -> Result<GetSendQuotaResponse, RusotoError<GetSendQuotaError>> {
+        self.runtime.block_on(self.client.get_send_quota())
+    }
+
+    /// Blocking version of [`Ses::get_send_statistics`].
+    pub fn get_send_statistics(
+        &self,
+    ) -> Result<GetSendStatisticsResponse, RusotoError<GetSendStatisticsError>> {
+        self.runtime.block_on(self.client.get_send_statistics())
+    }
+
+    /// Blocking version of [`Ses::get_template`].
+    pub fn get_template(
+        &self,
+        input: GetTemplateRequest,
+    ) -> Result<GetTemplateResponse, RusotoError<GetTemplateError>> {
+        self.runtime.block_on(self.client.get_template(input))
+    }
+
+    /// Blocking version of [`Ses::list_configuration_sets`].
+    pub fn list_configuration_sets(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
+        self.runtime
+            .block_on(self.client.list_configuration_sets(input))
+    }
+
+    /// Blocking version of [`Ses::list_custom_verification_email_templates`].
+    pub fn list_custom_verification_email_templates(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> Result<
+        ListCustomVerificationEmailTemplatesResponse,
+        RusotoError<ListCustomVerificationEmailTemplatesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_custom_verification_email_templates(input))
+    }
+
+    /// Blocking version of [`Ses::list_identities`].
+    pub fn list_identities(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
+        self.runtime.block_on(self.client.list_identities(input))
+    }
+
+    /// Blocking version of [`Ses::list_identity_policies`].
+    pub fn list_identity_policies(
+        &self,
+        input: ListIdentityPoliciesRequest,
+    ) -> Result<ListIdentityPoliciesResponse, RusotoError<ListIdentityPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_identity_policies(input))
+    }
+
+    /// Blocking version of [`Ses::list_receipt_filters`].
+    pub fn list_receipt_filters(
+        &self,
+        input: ListReceiptFiltersRequest,
+    ) -> Result<ListReceiptFiltersResponse, RusotoError<ListReceiptFiltersError>> {
+        self.runtime
+            .block_on(self.client.list_receipt_filters(input))
+    }
+
+    /// Blocking version of [`Ses::list_receipt_rule_sets`].
+    pub fn list_receipt_rule_sets(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> Result<ListReceiptRuleSetsResponse, RusotoError<ListReceiptRuleSetsError>> {
+        self.runtime
+            .block_on(self.client.list_receipt_rule_sets(input))
+    }
+
+    /// Blocking version of [`Ses::list_templates`].
+    pub fn list_templates(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> Result<ListTemplatesResponse, RusotoError<ListTemplatesError>> {
+        self.runtime.block_on(self.client.list_templates(input))
+    }
+
+    /// Blocking version of [`Ses::list_verified_email_addresses`].
+    pub fn list_verified_email_addresses(
+        &self,
+    ) -> Result<ListVerifiedEmailAddressesResponse, RusotoError<ListVerifiedEmailAddressesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_verified_email_addresses())
+    }
+
+    /// Blocking version of [`Ses::put_configuration_set_delivery_options`].
+    pub fn put_configuration_set_delivery_options(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetDeliveryOptionsResponse,
+        RusotoError<PutConfigurationSetDeliveryOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_configuration_set_delivery_options(input))
+    }
+
+    /// Blocking version of [`Ses::put_identity_policy`].
+    pub fn put_identity_policy(
+        &self,
+        input: PutIdentityPolicyRequest,
+    ) -> Result<PutIdentityPolicyResponse, RusotoError<PutIdentityPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_identity_policy(input))
+    }
+
+    /// Blocking version of [`Ses::reorder_receipt_rule_set`].
+    pub fn reorder_receipt_rule_set(
+        &self,
+        input: ReorderReceiptRuleSetRequest,
+    ) -> Result<ReorderReceiptRuleSetResponse, RusotoError<ReorderReceiptRuleSetError>> {
+        self.runtime
+            .block_on(self.client.reorder_receipt_rule_set(input))
+    }
+
+    /// Blocking version of [`Ses::send_bounce`].
+    pub fn send_bounce(
+        &self,
+        input: SendBounceRequest,
+    ) -> Result<SendBounceResponse, RusotoError<SendBounceError>> {
+        self.runtime.block_on(self.client.send_bounce(input))
+    }
+
+    /// Blocking version of [`Ses::send_bulk_templated_email`].
+    pub fn send_bulk_templated_email(
+        &self,
+        input: SendBulkTemplatedEmailRequest,
+    ) -> Result<SendBulkTemplatedEmailResponse, RusotoError<SendBulkTemplatedEmailError>> {
+        self.runtime
+            .block_on(self.client.send_bulk_templated_email(input))
+    }
+
+    /// Blocking version of [`Ses::send_custom_verification_email`].
+    pub fn send_custom_verification_email(
+        &self,
+        input: SendCustomVerificationEmailRequest,
+    ) -> Result<SendCustomVerificationEmailResponse, RusotoError<SendCustomVerificationEmailError>>
+    {
+        self.runtime
+            .block_on(self.client.send_custom_verification_email(input))
+    }
+
+    /// Blocking version of [`Ses::send_email`].
+    pub fn send_email(
+        &self,
+        input: SendEmailRequest,
+    ) -> Result<SendEmailResponse, RusotoError<SendEmailError>> {
+        self.runtime.block_on(self.client.send_email(input))
+    }
+
+    /// Blocking version of [`Ses::send_raw_email`].
+    pub fn send_raw_email(
+        &self,
+        input: SendRawEmailRequest,
+    ) -> Result<SendRawEmailResponse, RusotoError<SendRawEmailError>> {
+        self.runtime.block_on(self.client.send_raw_email(input))
+    }
+
+    /// Blocking version of [`Ses::send_templated_email`].
+    pub fn send_templated_email(
+        &self,
+        input: SendTemplatedEmailRequest,
+    ) -> Result<SendTemplatedEmailResponse, RusotoError<SendTemplatedEmailError>> {
+        self.runtime
+            .block_on(self.client.send_templated_email(input))
+    }
+
+    /// Blocking version of [`Ses::set_active_receipt_rule_set`].
+    pub fn set_active_receipt_rule_set(
+        &self,
+        input: SetActiveReceiptRuleSetRequest,
+    ) -> Result<SetActiveReceiptRuleSetResponse, RusotoError<SetActiveReceiptRuleSetError>> {
+        self.runtime
+            .block_on(self.client.set_active_receipt_rule_set(input))
+    }
+
+    /// Blocking version of [`Ses::set_identity_dkim_enabled`].
+    pub fn set_identity_dkim_enabled(
+        &self,
+        input: SetIdentityDkimEnabledRequest,
+    ) -> Result<SetIdentityDkimEnabledResponse, RusotoError<SetIdentityDkimEnabledError>> {
+        self.runtime
+            .block_on(self.client.set_identity_dkim_enabled(input))
+    }
+
+    /// Blocking version of [`Ses::set_identity_feedback_forwarding_enabled`].
+    pub fn set_identity_feedback_forwarding_enabled(
+        &self,
+        input: SetIdentityFeedbackForwardingEnabledRequest,
+    ) -> Result<
+        SetIdentityFeedbackForwardingEnabledResponse,
+        RusotoError<SetIdentityFeedbackForwardingEnabledError>,
+    > {
+        self.runtime
+            .block_on(self.client.set_identity_feedback_forwarding_enabled(input))
+    }
+
+    /// Blocking version of [`Ses::set_identity_headers_in_notifications_enabled`].
+    pub fn set_identity_headers_in_notifications_enabled(
+        &self,
+        input: SetIdentityHeadersInNotificationsEnabledRequest,
+    ) -> Result<
+        SetIdentityHeadersInNotificationsEnabledResponse,
+        RusotoError<SetIdentityHeadersInNotificationsEnabledError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .set_identity_headers_in_notifications_enabled(input),
+        )
+    }
+
+    /// Blocking version of [`Ses::set_identity_mail_from_domain`].
+    pub fn set_identity_mail_from_domain(
+        &self,
+        input: SetIdentityMailFromDomainRequest,
+    ) -> Result<SetIdentityMailFromDomainResponse, RusotoError<SetIdentityMailFromDomainError>>
+    {
+        self.runtime
+            .block_on(self.client.set_identity_mail_from_domain(input))
+    }
+
+    /// Blocking version of [`Ses::set_identity_notification_topic`].
+    pub fn set_identity_notification_topic(
+        &self,
+        input: SetIdentityNotificationTopicRequest,
+    ) -> Result<SetIdentityNotificationTopicResponse, RusotoError<SetIdentityNotificationTopicError>>
+    {
+        self.runtime
+            .block_on(self.client.set_identity_notification_topic(input))
+    }
+
+    /// Blocking version of [`Ses::set_receipt_rule_position`].
+    pub fn set_receipt_rule_position(
+        &self,
+        input: SetReceiptRulePositionRequest,
+    ) -> Result<SetReceiptRulePositionResponse, RusotoError<SetReceiptRulePositionError>> {
+        self.runtime
+            .block_on(self.client.set_receipt_rule_position(input))
+    }
+
+    /// Blocking version of [`Ses::test_render_template`].
+    pub fn test_render_template(
+        &self,
+        input: TestRenderTemplateRequest,
+    ) -> Result<TestRenderTemplateResponse, RusotoError<TestRenderTemplateError>> {
+        self.runtime
+            .block_on(self.client.test_render_template(input))
+    }
+
+    /// Blocking version of [`Ses::update_account_sending_enabled`].
+    pub fn update_account_sending_enabled(
+        &self,
+        input: UpdateAccountSendingEnabledRequest,
+    ) -> Result<(), RusotoError<UpdateAccountSendingEnabledError>> {
+        self.runtime
+            .block_on(self.client.update_account_sending_enabled(input))
+    }
+
+    /// Blocking version of [`Ses::update_configuration_set_event_destination`].
+    pub fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        UpdateConfigurationSetEventDestinationResponse,
+        RusotoError<UpdateConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .update_configuration_set_event_destination(input),
+        )
+    }
+
+    /// Blocking version of [`Ses::update_configuration_set_reputation_metrics_enabled`].
+    pub fn update_configuration_set_reputation_metrics_enabled(
+        &self,
+        input: UpdateConfigurationSetReputationMetricsEnabledRequest,
+    ) -> Result<(), RusotoError<UpdateConfigurationSetReputationMetricsEnabledError>> {
+        self.runtime.block_on(
+            self.client
+                .update_configuration_set_reputation_metrics_enabled(input),
+        )
+    }
+
+    /// Blocking version of [`Ses::update_configuration_set_sending_enabled`].
+    pub fn update_configuration_set_sending_enabled(
+        &self,
+        input: UpdateConfigurationSetSendingEnabledRequest,
+    ) -> Result<(), RusotoError<UpdateConfigurationSetSendingEnabledError>> {
+        self.runtime
+            .block_on(self.client.update_configuration_set_sending_enabled(input))
+    }
+
+    /// Blocking version of [`Ses::update_configuration_set_tracking_options`].
+    pub fn update_configuration_set_tracking_options(
+        &self,
+        input: UpdateConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        UpdateConfigurationSetTrackingOptionsResponse,
+        RusotoError<UpdateConfigurationSetTrackingOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_configuration_set_tracking_options(input))
+    }
+
+    /// Blocking version of [`Ses::update_custom_verification_email_template`].
+    pub fn update_custom_verification_email_template(
+        &self,
+        input: UpdateCustomVerificationEmailTemplateRequest,
+    ) -> Result<(), RusotoError<UpdateCustomVerificationEmailTemplateError>> {
+        self.runtime
+            .block_on(self.client.update_custom_verification_email_template(input))
+    }
+
+    /// Blocking version of [`Ses::update_receipt_rule`].
+    pub fn update_receipt_rule(
+        &self,
+        input: UpdateReceiptRuleRequest,
+    ) -> Result<UpdateReceiptRuleResponse, RusotoError<UpdateReceiptRuleError>> {
+        self.runtime
+            .block_on(self.client.update_receipt_rule(input))
+    }
+
+    /// Blocking version of [`Ses::update_template`].
+    pub fn update_template(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> Result<UpdateTemplateResponse, RusotoError<UpdateTemplateError>> {
+        self.runtime.block_on(self.client.update_template(input))
+    }
+
+    /// Blocking version of [`Ses::verify_domain_dkim`].
+    pub fn verify_domain_dkim(
+        &self,
+        input: VerifyDomainDkimRequest,
+    ) -> Result<VerifyDomainDkimResponse, RusotoError<VerifyDomainDkimError>> {
+        self.runtime.block_on(self.client.verify_domain_dkim(input))
+    }
+
+    /// Blocking version of [`Ses::verify_domain_identity`].
+    pub fn verify_domain_identity(
+        &self,
+        input: VerifyDomainIdentityRequest,
+    ) -> Result<VerifyDomainIdentityResponse, RusotoError<VerifyDomainIdentityError>> {
+        self.runtime
+            .block_on(self.client.verify_domain_identity(input))
+    }
+
+    /// Blocking version of [`Ses::verify_email_address`].
+    pub fn verify_email_address(
+        &self,
+        input: VerifyEmailAddressRequest,
+    ) -> Result<(), RusotoError<VerifyEmailAddressError>> {
+        self.runtime
+            .block_on(self.client.verify_email_address(input))
+    }
+
+    /// Blocking version of [`Ses::verify_email_identity`].
+    pub fn verify_email_identity(
+        &self,
+        input: VerifyEmailIdentityRequest,
+    ) -> Result<VerifyEmailIdentityResponse, RusotoError<VerifyEmailIdentityError>> {
+        self.runtime
+            .block_on(self.client.verify_email_identity(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -965,44 +966,70 @@ impl AssociateDRTLogBucketError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(
-                        AssociateDRTLogBucketError::AccessDeniedForDependency(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDRTLogBucketError::AccessDeniedForDependency,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDRTLogBucketError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDRTLogBucketError::InvalidOperation,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDRTLogBucketError::InvalidParameter,
+                    )
                 }
                 "LimitsExceededException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::LimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDRTLogBucketError::LimitsExceeded,
+                    )
                 }
                 "NoAssociatedRoleException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::NoAssociatedRole(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDRTLogBucketError::NoAssociatedRole,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::OptimisticLock(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDRTLogBucketError::OptimisticLock,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDRTLogBucketError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1049,26 +1076,54 @@ impl AssociateDRTRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::AccessDeniedForDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDRTRoleError::AccessDeniedForDependency,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDRTRoleError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDRTRoleError::InvalidOperation,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDRTRoleError::InvalidParameter,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::OptimisticLock(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDRTRoleError::OptimisticLock,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDRTRoleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1109,25 +1164,46 @@ impl AssociateHealthCheckError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateHealthCheckError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateHealthCheckError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateHealthCheckError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateHealthCheckError::InvalidParameter,
+                    )
                 }
                 "LimitsExceededException" => {
-                    return RusotoError::Service(AssociateHealthCheckError::LimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateHealthCheckError::LimitsExceeded,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(AssociateHealthCheckError::OptimisticLock(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateHealthCheckError::OptimisticLock,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateHealthCheckError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateHealthCheckError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1169,31 +1245,46 @@ impl AssociateProactiveEngagementDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(
-                        AssociateProactiveEngagementDetailsError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateProactiveEngagementDetailsError::InternalError,
                     )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(
-                        AssociateProactiveEngagementDetailsError::InvalidOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateProactiveEngagementDetailsError::InvalidOperation,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        AssociateProactiveEngagementDetailsError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateProactiveEngagementDetailsError::InvalidParameter,
                     )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(
-                        AssociateProactiveEngagementDetailsError::OptimisticLock(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateProactiveEngagementDetailsError::OptimisticLock,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateProactiveEngagementDetailsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateProactiveEngagementDetailsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1247,29 +1338,62 @@ impl CreateProtectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateProtectionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProtectionError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(CreateProtectionError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProtectionError::InvalidOperation,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CreateProtectionError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProtectionError::InvalidResource,
+                    )
                 }
                 "LimitsExceededException" => {
-                    return RusotoError::Service(CreateProtectionError::LimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProtectionError::LimitsExceeded,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(CreateProtectionError::OptimisticLock(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProtectionError::OptimisticLock,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateProtectionError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProtectionError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProtectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProtectionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1313,34 +1437,54 @@ impl CreateProtectionGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateProtectionGroupError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProtectionGroupError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateProtectionGroupError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProtectionGroupError::InvalidParameter,
+                    )
                 }
                 "LimitsExceededException" => {
-                    return RusotoError::Service(CreateProtectionGroupError::LimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProtectionGroupError::LimitsExceeded,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(CreateProtectionGroupError::OptimisticLock(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProtectionGroupError::OptimisticLock,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateProtectionGroupError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProtectionGroupError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProtectionGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProtectionGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1375,14 +1519,22 @@ impl CreateSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateSubscriptionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSubscriptionError::InternalError,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSubscriptionError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSubscriptionError::ResourceAlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1415,15 +1567,30 @@ impl DeleteProtectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteProtectionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProtectionError::InternalError,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DeleteProtectionError::OptimisticLock(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProtectionError::OptimisticLock,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProtectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProtectionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1457,19 +1624,30 @@ impl DeleteProtectionGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteProtectionGroupError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProtectionGroupError::InternalError,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DeleteProtectionGroupError::OptimisticLock(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProtectionGroupError::OptimisticLock,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProtectionGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProtectionGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1503,17 +1681,30 @@ impl DeleteSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteSubscriptionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSubscriptionError::InternalError,
+                    )
                 }
                 "LockedSubscriptionException" => {
-                    return RusotoError::Service(DeleteSubscriptionError::LockedSubscription(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSubscriptionError::LockedSubscription,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSubscriptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSubscriptionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1545,12 +1736,22 @@ impl DescribeAttackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAttackError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAttackError::AccessDenied,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeAttackError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAttackError::InternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1579,11 +1780,14 @@ impl DescribeAttackStatisticsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeAttackStatisticsError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAttackStatisticsError::InternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1613,12 +1817,22 @@ impl DescribeDRTAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeDRTAccessError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDRTAccessError::InternalError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDRTAccessError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDRTAccessError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1651,16 +1865,22 @@ impl DescribeEmergencyContactSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(
-                        DescribeEmergencyContactSettingsError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEmergencyContactSettingsError::InternalError,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeEmergencyContactSettingsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEmergencyContactSettingsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1697,15 +1917,30 @@ impl DescribeProtectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeProtectionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProtectionError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeProtectionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProtectionError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProtectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProtectionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1737,16 +1972,22 @@ impl DescribeProtectionGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeProtectionGroupError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProtectionGroupError::InternalError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProtectionGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProtectionGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1777,14 +2018,22 @@ impl DescribeSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeSubscriptionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSubscriptionError::InternalError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSubscriptionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSubscriptionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1823,31 +2072,46 @@ impl DisableProactiveEngagementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisableProactiveEngagementError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableProactiveEngagementError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DisableProactiveEngagementError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableProactiveEngagementError::InvalidOperation,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisableProactiveEngagementError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableProactiveEngagementError::InvalidParameter,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DisableProactiveEngagementError::OptimisticLock(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableProactiveEngagementError::OptimisticLock,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableProactiveEngagementError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableProactiveEngagementError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1889,36 +2153,54 @@ impl DisassociateDRTLogBucketError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(
-                        DisassociateDRTLogBucketError::AccessDeniedForDependency(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDRTLogBucketError::AccessDeniedForDependency,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateDRTLogBucketError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateDRTLogBucketError::InvalidOperation,
+                    )
                 }
                 "NoAssociatedRoleException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::NoAssociatedRole(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateDRTLogBucketError::NoAssociatedRole,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::OptimisticLock(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateDRTLogBucketError::OptimisticLock,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateDRTLogBucketError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1959,22 +2241,38 @@ impl DisassociateDRTRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisassociateDRTRoleError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDRTRoleError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DisassociateDRTRoleError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateDRTRoleError::InvalidOperation,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DisassociateDRTRoleError::OptimisticLock(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDRTRoleError::OptimisticLock,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateDRTRoleError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateDRTRoleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2011,26 +2309,38 @@ impl DisassociateHealthCheckError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisassociateHealthCheckError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateHealthCheckError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateHealthCheckError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateHealthCheckError::InvalidParameter,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DisassociateHealthCheckError::OptimisticLock(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateHealthCheckError::OptimisticLock,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateHealthCheckError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateHealthCheckError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2069,31 +2379,46 @@ impl EnableProactiveEngagementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(EnableProactiveEngagementError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableProactiveEngagementError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(EnableProactiveEngagementError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableProactiveEngagementError::InvalidOperation,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(EnableProactiveEngagementError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableProactiveEngagementError::InvalidParameter,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(EnableProactiveEngagementError::OptimisticLock(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableProactiveEngagementError::OptimisticLock,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableProactiveEngagementError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableProactiveEngagementError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2125,9 +2450,14 @@ impl GetSubscriptionStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetSubscriptionStateError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSubscriptionStateError::InternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2159,15 +2489,30 @@ impl ListAttacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListAttacksError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttacksError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(ListAttacksError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttacksError::InvalidOperation,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAttacksError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttacksError::InvalidParameter,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2201,19 +2546,30 @@ impl ListProtectionGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListProtectionGroupsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProtectionGroupsError::InternalError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListProtectionGroupsError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProtectionGroupsError::InvalidPaginationToken,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProtectionGroupsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProtectionGroupsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2247,17 +2603,30 @@ impl ListProtectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListProtectionsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProtectionsError::InternalError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListProtectionsError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProtectionsError::InvalidPaginationToken,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProtectionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProtectionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2293,21 +2662,30 @@ impl ListResourcesInProtectionGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(
-                        ListResourcesInProtectionGroupError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesInProtectionGroupError::InternalError,
                     )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        ListResourcesInProtectionGroupError::InvalidPaginationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesInProtectionGroupError::InvalidPaginationToken,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListResourcesInProtectionGroupError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesInProtectionGroupError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2349,26 +2727,38 @@ impl UpdateEmergencyContactSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(
-                        UpdateEmergencyContactSettingsError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEmergencyContactSettingsError::InternalError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        UpdateEmergencyContactSettingsError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEmergencyContactSettingsError::InvalidParameter,
                     )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(
-                        UpdateEmergencyContactSettingsError::OptimisticLock(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEmergencyContactSettingsError::OptimisticLock,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateEmergencyContactSettingsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEmergencyContactSettingsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2411,24 +2801,38 @@ impl UpdateProtectionGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateProtectionGroupError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProtectionGroupError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateProtectionGroupError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProtectionGroupError::InvalidParameter,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(UpdateProtectionGroupError::OptimisticLock(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProtectionGroupError::OptimisticLock,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProtectionGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProtectionGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2467,23 +2871,46 @@ impl UpdateSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSubscriptionError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSubscriptionError::InvalidParameter,
+                    )
                 }
                 "LockedSubscriptionException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::LockedSubscription(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSubscriptionError::LockedSubscription,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::OptimisticLock(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSubscriptionError::OptimisticLock,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSubscriptionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2698,11 +3125,12 @@ pub struct ShieldClient {
 impl ShieldClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ShieldClient {
         ShieldClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Shield"),
         }
     }
 
@@ -2717,12 +3145,32 @@ impl ShieldClient {
     {
         ShieldClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Shield"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ShieldClient {
-        ShieldClient { client, region }
+        ShieldClient {
+            client,
+            region: region_for_service(region, "Shield"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ShieldClient {
+        ShieldClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Shield"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ShieldClient {
+        ShieldClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2734,6 +3182,7 @@ impl Shield for ShieldClient {
         input: AssociateDRTLogBucketRequest,
     ) -> Result<AssociateDRTLogBucketResponse, RusotoError<AssociateDRTLogBucketError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateDRTLogBucket");
         request.add_header("x-amz-target", "AWSShield_20160616.AssociateDRTLogBucket");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2753,6 +3202,7 @@ impl Shield for ShieldClient {
         input: AssociateDRTRoleRequest,
     ) -> Result<AssociateDRTRoleResponse, RusotoError<AssociateDRTRoleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateDRTRole");
         request.add_header("x-amz-target", "AWSShield_20160616.AssociateDRTRole");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2771,6 +3221,7 @@ impl Shield for ShieldClient {
         input: AssociateHealthCheckRequest,
     ) -> Result<AssociateHealthCheckResponse, RusotoError<AssociateHealthCheckError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateHealthCheck");
         request.add_header("x-amz-target", "AWSShield_20160616.AssociateHealthCheck");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2793,6 +3244,7 @@ impl Shield for ShieldClient {
         RusotoError<AssociateProactiveEngagementDetailsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateProactiveEngagementDetails");
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.AssociateProactiveEngagementDetails",
@@ -2818,6 +3270,7 @@ impl Shield for ShieldClient {
         input: CreateProtectionRequest,
     ) -> Result<CreateProtectionResponse, RusotoError<CreateProtectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProtection");
         request.add_header("x-amz-target", "AWSShield_20160616.CreateProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2836,6 +3289,7 @@ impl Shield for ShieldClient {
         input: CreateProtectionGroupRequest,
     ) -> Result<CreateProtectionGroupResponse, RusotoError<CreateProtectionGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProtectionGroup");
         request.add_header("x-amz-target", "AWSShield_20160616.CreateProtectionGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2854,6 +3308,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<CreateSubscriptionResponse, RusotoError<CreateSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSubscription");
         request.add_header("x-amz-target", "AWSShield_20160616.CreateSubscription");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2871,6 +3326,7 @@ impl Shield for ShieldClient {
         input: DeleteProtectionRequest,
     ) -> Result<DeleteProtectionResponse, RusotoError<DeleteProtectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProtection");
         request.add_header("x-amz-target", "AWSShield_20160616.DeleteProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2889,6 +3345,7 @@ impl Shield for ShieldClient {
         input: DeleteProtectionGroupRequest,
     ) -> Result<DeleteProtectionGroupResponse, RusotoError<DeleteProtectionGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProtectionGroup");
         request.add_header("x-amz-target", "AWSShield_20160616.DeleteProtectionGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2907,6 +3364,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DeleteSubscriptionResponse, RusotoError<DeleteSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSubscription");
         request.add_header("x-amz-target", "AWSShield_20160616.DeleteSubscription");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2924,6 +3382,7 @@ impl Shield for ShieldClient {
         input: DescribeAttackRequest,
     ) -> Result<DescribeAttackResponse, RusotoError<DescribeAttackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAttack");
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeAttack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2941,6 +3400,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DescribeAttackStatisticsResponse, RusotoError<DescribeAttackStatisticsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAttackStatistics");
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.DescribeAttackStatistics",
@@ -2961,6 +3421,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DescribeDRTAccessResponse, RusotoError<DescribeDRTAccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDRTAccess");
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeDRTAccess");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2980,6 +3441,7 @@ impl Shield for ShieldClient {
         RusotoError<DescribeEmergencyContactSettingsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEmergencyContactSettings");
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.DescribeEmergencyContactSettings",
@@ -3004,6 +3466,7 @@ impl Shield for ShieldClient {
         input: DescribeProtectionRequest,
     ) -> Result<DescribeProtectionResponse, RusotoError<DescribeProtectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProtection");
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3022,6 +3485,7 @@ impl Shield for ShieldClient {
         input: DescribeProtectionGroupRequest,
     ) -> Result<DescribeProtectionGroupResponse, RusotoError<DescribeProtectionGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProtectionGroup");
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeProtectionGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3040,6 +3504,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DescribeSubscriptionResponse, RusotoError<DescribeSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSubscription");
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeSubscription");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -3058,6 +3523,7 @@ impl Shield for ShieldClient {
     ) -> Result<DisableProactiveEngagementResponse, RusotoError<DisableProactiveEngagementError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableProactiveEngagement");
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.DisableProactiveEngagement",
@@ -3079,6 +3545,7 @@ impl Shield for ShieldClient {
         input: DisassociateDRTLogBucketRequest,
     ) -> Result<DisassociateDRTLogBucketResponse, RusotoError<DisassociateDRTLogBucketError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateDRTLogBucket");
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.DisassociateDRTLogBucket",
@@ -3100,6 +3567,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DisassociateDRTRoleResponse, RusotoError<DisassociateDRTRoleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateDRTRole");
         request.add_header("x-amz-target", "AWSShield_20160616.DisassociateDRTRole");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -3117,6 +3585,7 @@ impl Shield for ShieldClient {
         input: DisassociateHealthCheckRequest,
     ) -> Result<DisassociateHealthCheckResponse, RusotoError<DisassociateHealthCheckError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateHealthCheck");
         request.add_header("x-amz-target", "AWSShield_20160616.DisassociateHealthCheck");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3136,6 +3605,7 @@ impl Shield for ShieldClient {
     ) -> Result<EnableProactiveEngagementResponse, RusotoError<EnableProactiveEngagementError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableProactiveEngagement");
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.EnableProactiveEngagement",
@@ -3156,6 +3626,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<GetSubscriptionStateResponse, RusotoError<GetSubscriptionStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSubscriptionState");
         request.add_header("x-amz-target", "AWSShield_20160616.GetSubscriptionState");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -3174,6 +3645,7 @@ impl Shield for ShieldClient {
         input: ListAttacksRequest,
     ) -> Result<ListAttacksResponse, RusotoError<ListAttacksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAttacks");
         request.add_header("x-amz-target", "AWSShield_20160616.ListAttacks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3192,6 +3664,7 @@ impl Shield for ShieldClient {
         input: ListProtectionGroupsRequest,
     ) -> Result<ListProtectionGroupsResponse, RusotoError<ListProtectionGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProtectionGroups");
         request.add_header("x-amz-target", "AWSShield_20160616.ListProtectionGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3211,6 +3684,7 @@ impl Shield for ShieldClient {
         input: ListProtectionsRequest,
     ) -> Result<ListProtectionsResponse, RusotoError<ListProtectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProtections");
         request.add_header("x-amz-target", "AWSShield_20160616.ListProtections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3232,6 +3706,7 @@ impl Shield for ShieldClient {
         RusotoError<ListResourcesInProtectionGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResourcesInProtectionGroup");
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.ListResourcesInProtectionGroup",
@@ -3257,6 +3732,7 @@ impl Shield for ShieldClient {
         RusotoError<UpdateEmergencyContactSettingsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateEmergencyContactSettings");
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.UpdateEmergencyContactSettings",
@@ -3279,6 +3755,7 @@ impl Shield for ShieldClient {
         input: UpdateProtectionGroupRequest,
     ) -> Result<UpdateProtectionGroupResponse, RusotoError<UpdateProtectionGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateProtectionGroup");
         request.add_header("x-amz-target", "AWSShield_20160616.UpdateProtectionGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3298,6 +3775,7 @@ impl Shield for ShieldClient {
         input: UpdateSubscriptionRequest,
     ) -> Result<UpdateSubscriptionResponse, RusotoError<UpdateSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSubscription");
         request.add_header("x-amz-target", "AWSShield_20160616.UpdateSubscription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3310,3 +3788,340 @@ impl Shield for ShieldClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSubscriptionResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Shield API, running the calls of a `ShieldClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ShieldClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ShieldBlockingClient {
+    client: ShieldClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ShieldBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ShieldBlockingClient {
+        ShieldBlockingClient::from_client(ShieldClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ShieldBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ShieldBlockingClient::from_client(ShieldClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ShieldBlockingClient {
+        ShieldBlockingClient::from_client(ShieldClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ShieldBlockingClient {
+        ShieldBlockingClient::from_client(ShieldClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ShieldClient) -> ShieldBlockingClient {
+        ShieldBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> ShieldBlockingClient {
+        ShieldBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ShieldBlockingClient {
+        ShieldBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Shield::associate_drt_log_bucket`].
+    pub fn associate_drt_log_bucket(
+        &self,
+        input: AssociateDRTLogBucketRequest,
+    ) -> Result<AssociateDRTLogBucketResponse, RusotoError<AssociateDRTLogBucketError>> {
+        self.runtime
+            .block_on(self.client.associate_drt_log_bucket(input))
+    }
+
+    /// Blocking version of [`Shield::associate_drt_role`].
+    pub fn associate_drt_role(
+        &self,
+        input: AssociateDRTRoleRequest,
+    ) -> Result<AssociateDRTRoleResponse, RusotoError<AssociateDRTRoleError>> {
+        self.runtime.block_on(self.client.associate_drt_role(input))
+    }
+
+    /// Blocking version of [`Shield::associate_health_check`].
+    pub fn associate_health_check(
+        &self,
+        input: AssociateHealthCheckRequest,
+    ) -> Result<AssociateHealthCheckResponse, RusotoError<AssociateHealthCheckError>> {
+        self.runtime
+            .block_on(self.client.associate_health_check(input))
+    }
+
+    /// Blocking version of [`Shield::associate_proactive_engagement_details`].
+    pub fn associate_proactive_engagement_details(
+        &self,
+        input: AssociateProactiveEngagementDetailsRequest,
+    ) -> Result<
+        AssociateProactiveEngagementDetailsResponse,
+        RusotoError<AssociateProactiveEngagementDetailsError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_proactive_engagement_details(input))
+    }
+
+    /// Blocking version of [`Shield::create_protection`].
+    pub fn create_protection(
+        &self,
+        input: CreateProtectionRequest,
+    ) -> Result<CreateProtectionResponse, RusotoError<CreateProtectionError>> {
+        self.runtime.block_on(self.client.create_protection(input))
+    }
+
+    /// Blocking version of [`Shield::create_protection_group`].
+    pub fn create_protection_group(
+        &self,
+        input: CreateProtectionGroupRequest,
+    ) -> Result<CreateProtectionGroupResponse, RusotoError<CreateProtectionGroupError>> {
+        self.runtime
+            .block_on(self.client.create_protection_group(input))
+    }
+
+    /// Blocking version of [`Shield::create_subscription`].
+    pub fn create_subscription(
+        &self,
+    ) -> Result<CreateSubscriptionResponse, RusotoError<CreateSubscriptionError>> {
+        self.runtime.block_on(self.client.create_subscription())
+    }
+
+    /// Blocking version of [`Shield::delete_protection`].
+    pub fn delete_protection(
+        &self,
+        input: DeleteProtectionRequest,
+    ) -> Result<DeleteProtectionResponse, RusotoError<DeleteProtectionError>> {
+        self.runtime.block_on(self.client.delete_protection(input))
+    }
+
+    /// Blocking version of [`Shield::delete_protection_group`].
+    pub fn delete_protection_group(
+        &self,
+        input: DeleteProtectionGroupRequest,
+    ) -> Result<DeleteProtectionGroupResponse, RusotoError<DeleteProtectionGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_protection_group(input))
+    }
+
+    /// Blocking version of [`Shield::delete_subscription`].
+    pub fn delete_subscription(
+        &self,
+    ) -> Result<DeleteSubscriptionResponse, RusotoError<DeleteSubscriptionError>> {
+        self.runtime.block_on(self.client.delete_subscription())
+    }
+
+    /// Blocking version of [`Shield::describe_attack`].
+    pub fn describe_attack(
+        &self,
+        input: DescribeAttackRequest,
+    ) -> Result<DescribeAttackResponse, RusotoError<DescribeAttackError>> {
+        self.runtime.block_on(self.client.describe_attack(input))
+    }
+
+    /// Blocking version of [`Shield::describe_attack_statistics`].
+    pub fn describe_attack_statistics(
+        &self,
+    ) -> Result<DescribeAttackStatisticsResponse, RusotoError<DescribeAttackStatisticsError>> {
+        self.runtime
+            .block_on(self.client.describe_attack_statistics())
+    }
+
+    /// Blocking version of [`Shield::describe_drt_access`].
+    pub fn describe_drt_access(
+        &self,
+    ) -> Result<DescribeDRTAccessResponse, RusotoError<DescribeDRTAccessError>> {
+        self.runtime.block_on(self.client.describe_drt_access())
+    }
+
+    /// Blocking version of [`Shield::describe_emergency_contact_settings`].
+    pub fn describe_emergency_contact_settings(
+        &self,
+    ) -> Result<
+        DescribeEmergencyContactSettingsResponse,
+        RusotoError<DescribeEmergencyContactSettingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_emergency_contact_settings())
+    }
+
+    /// Blocking version of [`Shield::describe_protection`].
+    pub fn describe_protection(
+        &self,
+        input: DescribeProtectionRequest,
+    ) -> Result<DescribeProtectionResponse, RusotoError<DescribeProtectionError>> {
+        self.runtime
+            .block_on(self.client.describe_protection(input))
+    }
+
+    /// Blocking version of [`Shield::describe_protection_group`].
+    pub fn describe_protection_group(
+        &self,
+        input: DescribeProtectionGroupRequest,
+    ) -> Result<DescribeProtectionGroupResponse, RusotoError<DescribeProtectionGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_protection_group(input))
+    }
+
+    /// Blocking version of [`Shield::describe_subscription`].
+    pub fn describe_subscription(
+        &self,
+    ) -> Result<DescribeSubscriptionResponse, RusotoError<DescribeSubscriptionError>> {
+        self.runtime.block_on(self.client.describe_subscription())
+    }
+
+    /// Blocking version of [`Shield::disable_proactive_engagement`].
+    pub fn disable_proactive_engagement(
+        &self,
+    ) -> Result<DisableProactiveEngagementResponse, RusotoError<DisableProactiveEngagementError>>
+    {
+        self.runtime
+            .block_on(self.client.disable_proactive_engagement())
+    }
+
+    /// Blocking version of [`Shield::disassociate_drt_log_bucket`].
+    pub fn disassociate_drt_log_bucket(
+        &self,
+        input: DisassociateDRTLogBucketRequest,
+    ) -> Result<DisassociateDRTLogBucketResponse, RusotoError<DisassociateDRTLogBucketError>> {
+        self.runtime
+            .block_on(self.client.disassociate_drt_log_bucket(input))
+    }
+
+    /// Blocking version of [`Shield::disassociate_drt_role`].
+    pub fn disassociate_drt_role(
+        &self,
+    ) -> Result<DisassociateDRTRoleResponse, RusotoError<DisassociateDRTRoleError>> {
+        self.runtime.block_on(self.client.disassociate_drt_role())
+    }
+
+    /// Blocking version of [`Shield::disassociate_health_check`].
+    pub fn disassociate_health_check(
+        &self,
+        input: DisassociateHealthCheckRequest,
+    ) -> Result<DisassociateHealthCheckResponse, RusotoError<DisassociateHealthCheckError>> {
+        self.runtime
+            .block_on(self.client.disassociate_health_check(input))
+    }
+
+    /// Blocking version of [`Shield::enable_proactive_engagement`].
+    pub fn enable_proactive_engagement(
+        &self,
+    ) -> Result<EnableProactiveEngagementResponse, RusotoError<EnableProactiveEngagementError>>
+    {
+        self.runtime
+            .block_on(self.client.enable_proactive_engagement())
+    }
+
+    /// Blocking version of [`Shield::get_subscription_state`].
+    pub fn get_subscription_state(
+        &self,
+    ) -> Result<GetSubscriptionStateResponse, RusotoError<GetSubscriptionStateError>> {
+        self.runtime.block_on(self.client.get_subscription_state())
+    }
+
+    /// Blocking version of [`Shield::list_attacks`].
+    pub fn list_attacks(
+        &self,
+        input: ListAttacksRequest,
+    ) -> Result<ListAttacksResponse, RusotoError<ListAttacksError>> {
+        self.runtime.block_on(self.client.list_attacks(input))
+    }
+
+    /// Blocking version of [`Shield::list_protection_groups`].
+    pub fn list_protection_groups(
+        &self,
+        input: ListProtectionGroupsRequest,
+    ) -> Result<ListProtectionGroupsResponse, RusotoError<ListProtectionGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_protection_groups(input))
+    }
+
+    /// Blocking version of [`Shield::list_protections`].
+    pub fn list_protections(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> Result<ListProtectionsResponse, RusotoError<ListProtectionsError>> {
+        self.runtime.block_on(self.client.list_protections(input))
+    }
+
+    /// Blocking version of [`Shield::list_resources_in_protection_group`].
+    pub fn list_resources_in_protection_group(
+        &self,
+        input: ListResourcesInProtectionGroupRequest,
+    ) -> Result<
+        ListResourcesInProtectionGroupResponse,
+        RusotoError<ListResourcesInProtectionGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_resources_in_protection_group(input))
+    }
+
+    /// Blocking version of [`Shield::update_emergency_contact_settings`].
+    pub fn update_emergency_contact_settings(
+        &self,
+        input: UpdateEmergencyContactSettingsRequest,
+    ) -> Result<
+        UpdateEmergencyContactSettingsResponse,
+        RusotoError<UpdateEmergencyContactSettingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_emergency_contact_settings(input))
+    }
+
+    /// Blocking version of [`Shield::update_protection_group`].
+    pub fn update_protection_group(
+        &self,
+        input: UpdateProtectionGroupRequest,
+    ) -> Result<UpdateProtectionGroupResponse, RusotoError<UpdateProtectionGroupError>> {
+        self.runtime
+            .block_on(self.client.update_protection_group(input))
+    }
+
+    /// Blocking version of [`Shield::update_subscription`].
+    pub fn update_subscription(
+        &self,
+        input: UpdateSubscriptionRequest,
+    ) -> Result<UpdateSubscriptionResponse, RusotoError<UpdateSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.update_subscription(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1022,32 +1023,54 @@ impl AddProfilePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AddProfilePermissionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddProfilePermissionError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AddProfilePermissionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddProfilePermissionError::Conflict,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(AddProfilePermissionError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddProfilePermissionError::InternalServiceError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddProfilePermissionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddProfilePermissionError::ResourceNotFound,
+                    )
                 }
                 "ServiceLimitExceededException" => {
-                    return RusotoError::Service(AddProfilePermissionError::ServiceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddProfilePermissionError::ServiceLimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddProfilePermissionError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddProfilePermissionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1086,24 +1109,38 @@ impl CancelSigningProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelSigningProfileError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelSigningProfileError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CancelSigningProfileError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelSigningProfileError::InternalServiceError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelSigningProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelSigningProfileError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CancelSigningProfileError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelSigningProfileError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1140,20 +1177,38 @@ impl DescribeSigningJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeSigningJobError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSigningJobError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DescribeSigningJobError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSigningJobError::InternalServiceError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSigningJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSigningJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeSigningJobError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSigningJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1190,20 +1245,38 @@ impl GetSigningPlatformError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSigningPlatformError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSigningPlatformError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetSigningPlatformError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSigningPlatformError::InternalServiceError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSigningPlatformError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSigningPlatformError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetSigningPlatformError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSigningPlatformError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1240,20 +1313,38 @@ impl GetSigningProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSigningProfileError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSigningProfileError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetSigningProfileError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSigningProfileError::InternalServiceError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSigningProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSigningProfileError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetSigningProfileError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSigningProfileError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1290,24 +1381,38 @@ impl ListProfilePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListProfilePermissionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProfilePermissionsError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListProfilePermissionsError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProfilePermissionsError::InternalServiceError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProfilePermissionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProfilePermissionsError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListProfilePermissionsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProfilePermissionsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1342,17 +1447,30 @@ impl ListSigningJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSigningJobsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSigningJobsError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListSigningJobsError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSigningJobsError::InternalServiceError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListSigningJobsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSigningJobsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1386,19 +1504,30 @@ impl ListSigningPlatformsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSigningPlatformsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSigningPlatformsError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListSigningPlatformsError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSigningPlatformsError::InternalServiceError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListSigningPlatformsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSigningPlatformsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1432,17 +1561,30 @@ impl ListSigningProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSigningProfilesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSigningProfilesError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListSigningProfilesError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSigningProfilesError::InternalServiceError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListSigningProfilesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSigningProfilesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1478,20 +1620,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServiceError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1528,20 +1688,38 @@ impl PutSigningProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutSigningProfileError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSigningProfileError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(PutSigningProfileError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutSigningProfileError::InternalServiceError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutSigningProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSigningProfileError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutSigningProfileError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSigningProfileError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1580,29 +1758,46 @@ impl RemoveProfilePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveProfilePermissionError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveProfilePermissionError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(RemoveProfilePermissionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveProfilePermissionError::Conflict,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        RemoveProfilePermissionError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveProfilePermissionError::InternalServiceError,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveProfilePermissionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveProfilePermissionError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RemoveProfilePermissionError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveProfilePermissionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1640,20 +1835,38 @@ impl RevokeSignatureError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RevokeSignatureError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeSignatureError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(RevokeSignatureError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokeSignatureError::InternalServiceError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RevokeSignatureError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeSignatureError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RevokeSignatureError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeSignatureError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1690,24 +1903,38 @@ impl RevokeSigningProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RevokeSigningProfileError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeSigningProfileError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(RevokeSigningProfileError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokeSigningProfileError::InternalServiceError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RevokeSigningProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokeSigningProfileError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RevokeSigningProfileError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokeSigningProfileError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1746,23 +1973,46 @@ impl StartSigningJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartSigningJobError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSigningJobError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(StartSigningJobError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSigningJobError::InternalServiceError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartSigningJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSigningJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartSigningJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSigningJobError::Throttling,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartSigningJobError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSigningJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1800,18 +2050,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServiceError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1848,18 +2118,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServiceError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1993,11 +2283,12 @@ pub struct SignerClient {
 impl SignerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SignerClient {
         SignerClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "signer"),
         }
     }
 
@@ -2012,12 +2303,32 @@ impl SignerClient {
     {
         SignerClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "signer"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SignerClient {
-        SignerClient { client, region }
+        SignerClient {
+            client,
+            region: region_for_service(region, "signer"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SignerClient {
+        SignerClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("signer"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SignerClient {
+        SignerClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2035,6 +2346,7 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("POST", "signer", &self.region, &request_uri);
+        request.set_operation("AddProfilePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2069,6 +2381,7 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "signer", &self.region, &request_uri);
+        request.set_operation("CancelSigningProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2096,6 +2409,7 @@ impl Signer for SignerClient {
         let request_uri = format!("/signing-jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("DescribeSigningJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2127,6 +2441,7 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("GetSigningPlatform");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2158,6 +2473,7 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("GetSigningProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2195,6 +2511,7 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListProfilePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2229,6 +2546,7 @@ impl Signer for SignerClient {
         let request_uri = "/signing-jobs";
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListSigningJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2287,6 +2605,7 @@ impl Signer for SignerClient {
         let request_uri = "/signing-platforms";
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListSigningPlatforms");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2333,6 +2652,7 @@ impl Signer for SignerClient {
         let request_uri = "/signing-profiles";
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListSigningProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2381,6 +2701,7 @@ impl Signer for SignerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2412,6 +2733,7 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "signer", &self.region, &request_uri);
+        request.set_operation("PutSigningProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2447,6 +2769,7 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "signer", &self.region, &request_uri);
+        request.set_operation("RemoveProfilePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2479,6 +2802,7 @@ impl Signer for SignerClient {
         let request_uri = format!("/signing-jobs/{job_id}/revoke", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PUT", "signer", &self.region, &request_uri);
+        request.set_operation("RevokeSignature");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2512,6 +2836,7 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "signer", &self.region, &request_uri);
+        request.set_operation("RevokeSigningProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2542,6 +2867,7 @@ impl Signer for SignerClient {
         let request_uri = "/signing-jobs";
 
         let mut request = SignedRequest::new("POST", "signer", &self.region, &request_uri);
+        request.set_operation("StartSigningJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2573,6 +2899,7 @@ impl Signer for SignerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "signer", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2604,6 +2931,7 @@ impl Signer for SignerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "signer", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2629,3 +2957,226 @@ impl Signer for SignerClient {
         }
     }
 }
+
+/// A blocking client for the signer API, running the calls of a `SignerClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SignerClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SignerBlockingClient {
+    client: SignerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SignerBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SignerBlockingClient {
+        SignerBlockingClient::from_client(SignerClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SignerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SignerBlockingClient::from_client(SignerClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> SignerBlockingClient {
+        SignerBlockingClient::from_client(SignerClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SignerBlockingClient {
+        SignerBlockingClient::from_client(SignerClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SignerClient) -> SignerBlockingClient {
+        SignerBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> SignerBlockingClient {
+        SignerBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SignerBlockingClient {
+        SignerBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Signer::add_profile_permission`].
+    pub fn add_profile_permission(
+        &self,
+        input: AddProfilePermissionRequest,
+    ) -> Result<AddProfilePermissionResponse, RusotoError<AddProfilePermissionError>> {
+        self.runtime
+            .block_on(self.client.add_profile_permission(input))
+    }
+
+    /// Blocking version of [`Signer::cancel_signing_profile`].
+    pub fn cancel_signing_profile(
+        &self,
+        input: CancelSigningProfileRequest,
+    ) -> Result<(), RusotoError<CancelSigningProfileError>> {
+        self.runtime
+            .block_on(self.client.cancel_signing_profile(input))
+    }
+
+    /// Blocking version of [`Signer::describe_signing_job`].
+    pub fn describe_signing_job(
+        &self,
+        input: DescribeSigningJobRequest,
+    ) -> Result<DescribeSigningJobResponse, RusotoError<DescribeSigningJobError>> {
+        self.runtime
+            .block_on(self.client.describe_signing_job(input))
+    }
+
+    /// Blocking version of [`Signer::get_signing_platform`].
+    pub fn get_signing_platform(
+        &self,
+        input: GetSigningPlatformRequest,
+    ) -> Result<GetSigningPlatformResponse, RusotoError<GetSigningPlatformError>> {
+        self.runtime
+            .block_on(self.client.get_signing_platform(input))
+    }
+
+    /// Blocking version of [`Signer::get_signing_profile`].
+    pub fn get_signing_profile(
+        &self,
+        input: GetSigningProfileRequest,
+    ) -> Result<GetSigningProfileResponse, RusotoError<GetSigningProfileError>> {
+        self.runtime
+            .block_on(self.client.get_signing_profile(input))
+    }
+
+    /// Blocking version of [`Signer::list_profile_permissions`].
+    pub fn list_profile_permissions(
+        &self,
+        input: ListProfilePermissionsRequest,
+    ) -> Result<ListProfilePermissionsResponse, RusotoError<ListProfilePermissionsError>> {
+        self.runtime
+            .block_on(self.client.list_profile_permissions(input))
+    }
+
+    /// Blocking version of [`Signer::list_signing_jobs`].
+    pub fn list_signing_jobs(
+        &self,
+        input: ListSigningJobsRequest,
+    ) -> Result<ListSigningJobsResponse, RusotoError<ListSigningJobsError>> {
+        self.runtime.block_on(self.client.list_signing_jobs(input))
+    }
+
+    /// Blocking version of [`Signer::list_signing_platforms`].
+    pub fn list_signing_platforms(
+        &self,
+        input: ListSigningPlatformsRequest,
+    ) -> Result<ListSigningPlatformsResponse, RusotoError<ListSigningPlatformsError>> {
+        self.runtime
+            .block_on(self.client.list_signing_platforms(input))
+    }
+
+    /// Blocking version of [`Signer::list_signing_profiles`].
+    pub fn list_signing_profiles(
+        &self,
+        input: ListSigningProfilesRequest,
+    ) -> Result<ListSigningProfilesResponse, RusotoError<ListSigningProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_signing_profiles(input))
+    }
+
+    /// Blocking version of [`Signer::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Signer::put_signing_profile`].
+    pub fn put_signing_profile(
+        &self,
+        input: PutSigningProfileRequest,
+    ) -> Result<PutSigningProfileResponse, RusotoError<PutSigningProfileError>> {
+        self.runtime
+            .block_on(self.client.put_signing_profile(input))
+    }
+
+    /// Blocking version of [`Signer::remove_profile_permission`].
+    pub fn remove_profile_permission(
+        &self,
+        input: RemoveProfilePermissionRequest,
+    ) -> Result<RemoveProfilePermissionResponse, RusotoError<RemoveProfilePermissionError>> {
+        self.runtime
+            .block_on(self.client.remove_profile_permission(input))
+    }
+
+    /// Blocking version of [`Signer::revoke_signature`].
+    pub fn revoke_signature(
+        &self,
+        input: RevokeSignatureRequest,
+    ) -> Result<(), RusotoError<RevokeSignatureError>> {
+        self.runtime.block_on(self.client.revoke_signature(input))
+    }
+
+    /// Blocking version of [`Signer::revoke_signing_profile`].
+    pub fn revoke_signing_profile(
+        &self,
+        input: RevokeSigningProfileRequest,
+    ) -> Result<(), RusotoError<RevokeSigningProfileError>> {
+        self.runtime
+            .block_on(self.client.revoke_signing_profile(input))
+    }
+
+    /// Blocking version of [`Signer::start_signing_job`].
+    pub fn start_signing_job(
+        &self,
+        input: StartSigningJobRequest,
+    ) -> Result<StartSigningJobResponse, RusotoError<StartSigningJobError>> {
+        self.runtime.block_on(self.client.start_signing_job(input))
+    }
+
+    /// Blocking version of [`Signer::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Signer::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
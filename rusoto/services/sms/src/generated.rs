@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1569,21 +1570,46 @@ impl CreateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(CreateAppError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateAppError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(CreateAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::MissingRequiredParameter,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1631,49 +1657,78 @@ impl CreateReplicationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(CreateReplicationJobError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationJobError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateReplicationJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReplicationJobError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        CreateReplicationJobError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationJobError::MissingRequiredParameter,
                     )
                 }
                 "NoConnectorsAvailableException" => {
-                    return RusotoError::Service(CreateReplicationJobError::NoConnectorsAvailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReplicationJobError::NoConnectorsAvailable,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateReplicationJobError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReplicationJobError::OperationNotPermitted,
+                    )
                 }
                 "ReplicationJobAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateReplicationJobError::ReplicationJobAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationJobError::ReplicationJobAlreadyExists,
                     )
                 }
                 "ServerCannotBeReplicatedException" => {
-                    return RusotoError::Service(
-                        CreateReplicationJobError::ServerCannotBeReplicated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationJobError::ServerCannotBeReplicated,
                     )
                 }
                 "TemporarilyUnavailableException" => {
-                    return RusotoError::Service(CreateReplicationJobError::TemporarilyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReplicationJobError::TemporarilyUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateReplicationJobError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReplicationJobError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1723,21 +1778,46 @@ impl DeleteAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(DeleteAppError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteAppError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(DeleteAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::MissingRequiredParameter,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1779,31 +1859,46 @@ impl DeleteAppLaunchConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(DeleteAppLaunchConfigurationError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAppLaunchConfigurationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DeleteAppLaunchConfigurationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppLaunchConfigurationError::InvalidParameter,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        DeleteAppLaunchConfigurationError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppLaunchConfigurationError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        DeleteAppLaunchConfigurationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppLaunchConfigurationError::OperationNotPermitted,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        DeleteAppLaunchConfigurationError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppLaunchConfigurationError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1853,31 +1948,46 @@ impl DeleteAppReplicationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(
-                        DeleteAppReplicationConfigurationError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppReplicationConfigurationError::InternalError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DeleteAppReplicationConfigurationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppReplicationConfigurationError::InvalidParameter,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        DeleteAppReplicationConfigurationError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppReplicationConfigurationError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        DeleteAppReplicationConfigurationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppReplicationConfigurationError::OperationNotPermitted,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        DeleteAppReplicationConfigurationError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppReplicationConfigurationError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1929,31 +2039,46 @@ impl DeleteAppValidationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(
-                        DeleteAppValidationConfigurationError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppValidationConfigurationError::InternalError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DeleteAppValidationConfigurationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppValidationConfigurationError::InvalidParameter,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        DeleteAppValidationConfigurationError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppValidationConfigurationError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        DeleteAppValidationConfigurationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppValidationConfigurationError::OperationNotPermitted,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        DeleteAppValidationConfigurationError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppValidationConfigurationError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2003,31 +2128,46 @@ impl DeleteReplicationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteReplicationJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteReplicationJobError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        DeleteReplicationJobError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReplicationJobError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteReplicationJobError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteReplicationJobError::OperationNotPermitted,
+                    )
                 }
                 "ReplicationJobNotFoundException" => {
-                    return RusotoError::Service(DeleteReplicationJobError::ReplicationJobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteReplicationJobError::ReplicationJobNotFound,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteReplicationJobError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteReplicationJobError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2067,26 +2207,38 @@ impl DeleteServerCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteServerCatalogError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteServerCatalogError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        DeleteServerCatalogError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteServerCatalogError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteServerCatalogError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteServerCatalogError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteServerCatalogError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteServerCatalogError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2123,26 +2275,38 @@ impl DisassociateConnectorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateConnectorError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateConnectorError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        DisassociateConnectorError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateConnectorError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DisassociateConnectorError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateConnectorError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DisassociateConnectorError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateConnectorError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2183,27 +2347,46 @@ impl GenerateChangeSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GenerateChangeSetError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateChangeSetError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GenerateChangeSetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateChangeSetError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GenerateChangeSetError::MissingRequiredParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateChangeSetError::MissingRequiredParameter,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GenerateChangeSetError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateChangeSetError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GenerateChangeSetError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateChangeSetError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2243,27 +2426,46 @@ impl GenerateTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GenerateTemplateError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateTemplateError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GenerateTemplateError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateTemplateError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GenerateTemplateError::MissingRequiredParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateTemplateError::MissingRequiredParameter,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GenerateTemplateError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateTemplateError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GenerateTemplateError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateTemplateError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2303,21 +2505,46 @@ impl GetAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GetAppError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAppError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GetAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppError::MissingRequiredParameter,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2357,31 +2584,46 @@ impl GetAppLaunchConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GetAppLaunchConfigurationError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppLaunchConfigurationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAppLaunchConfigurationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppLaunchConfigurationError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        GetAppLaunchConfigurationError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppLaunchConfigurationError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        GetAppLaunchConfigurationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppLaunchConfigurationError::OperationNotPermitted,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        GetAppLaunchConfigurationError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppLaunchConfigurationError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2429,31 +2671,46 @@ impl GetAppReplicationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(
-                        GetAppReplicationConfigurationError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppReplicationConfigurationError::InternalError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        GetAppReplicationConfigurationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppReplicationConfigurationError::InvalidParameter,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        GetAppReplicationConfigurationError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppReplicationConfigurationError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        GetAppReplicationConfigurationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppReplicationConfigurationError::OperationNotPermitted,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        GetAppReplicationConfigurationError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppReplicationConfigurationError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2503,31 +2760,46 @@ impl GetAppValidationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GetAppValidationConfigurationError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppValidationConfigurationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        GetAppValidationConfigurationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppValidationConfigurationError::InvalidParameter,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        GetAppValidationConfigurationError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppValidationConfigurationError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        GetAppValidationConfigurationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppValidationConfigurationError::OperationNotPermitted,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        GetAppValidationConfigurationError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppValidationConfigurationError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2575,31 +2847,46 @@ impl GetAppValidationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GetAppValidationOutputError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppValidationOutputError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAppValidationOutputError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppValidationOutputError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        GetAppValidationOutputError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppValidationOutputError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        GetAppValidationOutputError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppValidationOutputError::OperationNotPermitted,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        GetAppValidationOutputError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppValidationOutputError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2633,9 +2920,14 @@ impl GetConnectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetConnectorsError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectorsError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2667,19 +2959,30 @@ impl GetReplicationJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetReplicationJobsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetReplicationJobsError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GetReplicationJobsError::MissingRequiredParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetReplicationJobsError::MissingRequiredParameter,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetReplicationJobsError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetReplicationJobsError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2713,19 +3016,30 @@ impl GetReplicationRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetReplicationRunsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetReplicationRunsError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GetReplicationRunsError::MissingRequiredParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetReplicationRunsError::MissingRequiredParameter,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetReplicationRunsError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetReplicationRunsError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2761,18 +3075,38 @@ impl GetServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GetServersError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServersError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetServersError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServersError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GetServersError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServersError::MissingRequiredParameter,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetServersError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServersError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2811,27 +3145,46 @@ impl ImportAppCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(ImportAppCatalogError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportAppCatalogError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ImportAppCatalogError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportAppCatalogError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(ImportAppCatalogError::MissingRequiredParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportAppCatalogError::MissingRequiredParameter,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ImportAppCatalogError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportAppCatalogError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(ImportAppCatalogError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportAppCatalogError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2871,31 +3224,46 @@ impl ImportServerCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ImportServerCatalogError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportServerCatalogError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        ImportServerCatalogError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportServerCatalogError::MissingRequiredParameter,
                     )
                 }
                 "NoConnectorsAvailableException" => {
-                    return RusotoError::Service(ImportServerCatalogError::NoConnectorsAvailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportServerCatalogError::NoConnectorsAvailable,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ImportServerCatalogError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportServerCatalogError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(ImportServerCatalogError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportServerCatalogError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2935,21 +3303,46 @@ impl LaunchAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(LaunchAppError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LaunchAppError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(LaunchAppError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LaunchAppError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(LaunchAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LaunchAppError::MissingRequiredParameter,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(LaunchAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LaunchAppError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(LaunchAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LaunchAppError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2989,21 +3382,46 @@ impl ListAppsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(ListAppsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAppsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppsError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(ListAppsError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppsError::MissingRequiredParameter,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListAppsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppsError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(ListAppsError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppsError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3043,31 +3461,46 @@ impl NotifyAppValidationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(NotifyAppValidationOutputError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyAppValidationOutputError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(NotifyAppValidationOutputError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyAppValidationOutputError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        NotifyAppValidationOutputError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyAppValidationOutputError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        NotifyAppValidationOutputError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyAppValidationOutputError::OperationNotPermitted,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        NotifyAppValidationOutputError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyAppValidationOutputError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3113,31 +3546,46 @@ impl PutAppLaunchConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(PutAppLaunchConfigurationError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAppLaunchConfigurationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAppLaunchConfigurationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAppLaunchConfigurationError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        PutAppLaunchConfigurationError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppLaunchConfigurationError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        PutAppLaunchConfigurationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppLaunchConfigurationError::OperationNotPermitted,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        PutAppLaunchConfigurationError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppLaunchConfigurationError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3185,31 +3633,46 @@ impl PutAppReplicationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(
-                        PutAppReplicationConfigurationError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppReplicationConfigurationError::InternalError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        PutAppReplicationConfigurationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppReplicationConfigurationError::InvalidParameter,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        PutAppReplicationConfigurationError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppReplicationConfigurationError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        PutAppReplicationConfigurationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppReplicationConfigurationError::OperationNotPermitted,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        PutAppReplicationConfigurationError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppReplicationConfigurationError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3259,31 +3722,46 @@ impl PutAppValidationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(PutAppValidationConfigurationError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAppValidationConfigurationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        PutAppValidationConfigurationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppValidationConfigurationError::InvalidParameter,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        PutAppValidationConfigurationError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppValidationConfigurationError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        PutAppValidationConfigurationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppValidationConfigurationError::OperationNotPermitted,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        PutAppValidationConfigurationError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppValidationConfigurationError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3331,29 +3809,46 @@ impl StartAppReplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(StartAppReplicationError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAppReplicationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartAppReplicationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartAppReplicationError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        StartAppReplicationError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAppReplicationError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartAppReplicationError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartAppReplicationError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(StartAppReplicationError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartAppReplicationError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3395,31 +3890,46 @@ impl StartOnDemandAppReplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(StartOnDemandAppReplicationError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartOnDemandAppReplicationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        StartOnDemandAppReplicationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartOnDemandAppReplicationError::InvalidParameter,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        StartOnDemandAppReplicationError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartOnDemandAppReplicationError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        StartOnDemandAppReplicationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartOnDemandAppReplicationError::OperationNotPermitted,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        StartOnDemandAppReplicationError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartOnDemandAppReplicationError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3469,36 +3979,54 @@ impl StartOnDemandReplicationRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DryRunOperationException" => {
-                    return RusotoError::Service(StartOnDemandReplicationRunError::DryRunOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartOnDemandReplicationRunError::DryRunOperation,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        StartOnDemandReplicationRunError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartOnDemandReplicationRunError::InvalidParameter,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        StartOnDemandReplicationRunError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartOnDemandReplicationRunError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        StartOnDemandReplicationRunError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartOnDemandReplicationRunError::OperationNotPermitted,
                     )
                 }
                 "ReplicationRunLimitExceededException" => {
-                    return RusotoError::Service(
-                        StartOnDemandReplicationRunError::ReplicationRunLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartOnDemandReplicationRunError::ReplicationRunLimitExceeded,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        StartOnDemandReplicationRunError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartOnDemandReplicationRunError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3547,27 +4075,46 @@ impl StopAppReplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(StopAppReplicationError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopAppReplicationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopAppReplicationError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopAppReplicationError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(StopAppReplicationError::MissingRequiredParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopAppReplicationError::MissingRequiredParameter,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StopAppReplicationError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopAppReplicationError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(StopAppReplicationError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopAppReplicationError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3607,23 +4154,46 @@ impl TerminateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(TerminateAppError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TerminateAppError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TerminateAppError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TerminateAppError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(TerminateAppError::MissingRequiredParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TerminateAppError::MissingRequiredParameter,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(TerminateAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TerminateAppError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(TerminateAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TerminateAppError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3663,21 +4233,46 @@ impl UpdateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(UpdateAppError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateAppError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(UpdateAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppError::MissingRequiredParameter,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppError::OperationNotPermitted,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3723,44 +4318,70 @@ impl UpdateReplicationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(UpdateReplicationJobError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateReplicationJobError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateReplicationJobError::InvalidParameter,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        UpdateReplicationJobError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateReplicationJobError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateReplicationJobError::OperationNotPermitted,
+                    )
                 }
                 "ReplicationJobNotFoundException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::ReplicationJobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateReplicationJobError::ReplicationJobNotFound,
+                    )
                 }
                 "ServerCannotBeReplicatedException" => {
-                    return RusotoError::Service(
-                        UpdateReplicationJobError::ServerCannotBeReplicated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateReplicationJobError::ServerCannotBeReplicated,
                     )
                 }
                 "TemporarilyUnavailableException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::TemporarilyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateReplicationJobError::TemporarilyUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateReplicationJobError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4026,11 +4647,12 @@ pub struct ServerMigrationServiceClient {
 impl ServerMigrationServiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ServerMigrationServiceClient {
         ServerMigrationServiceClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SMS"),
         }
     }
 
@@ -4045,12 +4667,32 @@ impl ServerMigrationServiceClient {
     {
         ServerMigrationServiceClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SMS"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ServerMigrationServiceClient {
-        ServerMigrationServiceClient { client, region }
+        ServerMigrationServiceClient {
+            client,
+            region: region_for_service(region, "SMS"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ServerMigrationServiceClient {
+        ServerMigrationServiceClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SMS"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ServerMigrationServiceClient {
+        ServerMigrationServiceClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4062,6 +4704,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: CreateAppRequest,
     ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateApp");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.CreateApp",
@@ -4083,6 +4726,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: CreateReplicationJobRequest,
     ) -> Result<CreateReplicationJobResponse, RusotoError<CreateReplicationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateReplicationJob");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.CreateReplicationJob",
@@ -4105,6 +4749,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: DeleteAppRequest,
     ) -> Result<DeleteAppResponse, RusotoError<DeleteAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApp");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DeleteApp",
@@ -4127,6 +4772,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<DeleteAppLaunchConfigurationResponse, RusotoError<DeleteAppLaunchConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAppLaunchConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DeleteAppLaunchConfiguration",
@@ -4152,6 +4798,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         RusotoError<DeleteAppReplicationConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAppReplicationConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DeleteAppReplicationConfiguration",
@@ -4180,6 +4827,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         RusotoError<DeleteAppValidationConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAppValidationConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DeleteAppValidationConfiguration",
@@ -4205,6 +4853,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: DeleteReplicationJobRequest,
     ) -> Result<DeleteReplicationJobResponse, RusotoError<DeleteReplicationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteReplicationJob");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DeleteReplicationJob",
@@ -4226,6 +4875,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         &self,
     ) -> Result<DeleteServerCatalogResponse, RusotoError<DeleteServerCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteServerCatalog");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DeleteServerCatalog",
@@ -4246,6 +4896,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: DisassociateConnectorRequest,
     ) -> Result<DisassociateConnectorResponse, RusotoError<DisassociateConnectorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateConnector");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DisassociateConnector",
@@ -4268,6 +4919,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GenerateChangeSetRequest,
     ) -> Result<GenerateChangeSetResponse, RusotoError<GenerateChangeSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GenerateChangeSet");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GenerateChangeSet",
@@ -4289,6 +4941,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GenerateTemplateRequest,
     ) -> Result<GenerateTemplateResponse, RusotoError<GenerateTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GenerateTemplate");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GenerateTemplate",
@@ -4310,6 +4963,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetAppRequest,
     ) -> Result<GetAppResponse, RusotoError<GetAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetApp");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetApp",
@@ -4332,6 +4986,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<GetAppLaunchConfigurationResponse, RusotoError<GetAppLaunchConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAppLaunchConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetAppLaunchConfiguration",
@@ -4357,6 +5012,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         RusotoError<GetAppReplicationConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAppReplicationConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetAppReplicationConfiguration",
@@ -4382,6 +5038,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         RusotoError<GetAppValidationConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAppValidationConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetAppValidationConfiguration",
@@ -4404,6 +5061,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetAppValidationOutputRequest,
     ) -> Result<GetAppValidationOutputResponse, RusotoError<GetAppValidationOutputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAppValidationOutput");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetAppValidationOutput",
@@ -4426,6 +5084,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetConnectorsRequest,
     ) -> Result<GetConnectorsResponse, RusotoError<GetConnectorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetConnectors");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetConnectors",
@@ -4447,6 +5106,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetReplicationJobsRequest,
     ) -> Result<GetReplicationJobsResponse, RusotoError<GetReplicationJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetReplicationJobs");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetReplicationJobs",
@@ -4468,6 +5128,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetReplicationRunsRequest,
     ) -> Result<GetReplicationRunsResponse, RusotoError<GetReplicationRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetReplicationRuns");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetReplicationRuns",
@@ -4489,6 +5150,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetServersRequest,
     ) -> Result<GetServersResponse, RusotoError<GetServersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetServers");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetServers",
@@ -4510,6 +5172,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: ImportAppCatalogRequest,
     ) -> Result<ImportAppCatalogResponse, RusotoError<ImportAppCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportAppCatalog");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.ImportAppCatalog",
@@ -4530,6 +5193,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         &self,
     ) -> Result<ImportServerCatalogResponse, RusotoError<ImportServerCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportServerCatalog");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.ImportServerCatalog",
@@ -4550,6 +5214,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: LaunchAppRequest,
     ) -> Result<LaunchAppResponse, RusotoError<LaunchAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("LaunchApp");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.LaunchApp",
@@ -4571,6 +5236,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: ListAppsRequest,
     ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListApps");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.ListApps",
@@ -4593,6 +5259,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<NotifyAppValidationOutputResponse, RusotoError<NotifyAppValidationOutputError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("NotifyAppValidationOutput");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.NotifyAppValidationOutput",
@@ -4616,6 +5283,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<PutAppLaunchConfigurationResponse, RusotoError<PutAppLaunchConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutAppLaunchConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.PutAppLaunchConfiguration",
@@ -4641,6 +5309,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         RusotoError<PutAppReplicationConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutAppReplicationConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.PutAppReplicationConfiguration",
@@ -4666,6 +5335,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         RusotoError<PutAppValidationConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutAppValidationConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.PutAppValidationConfiguration",
@@ -4688,6 +5358,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: StartAppReplicationRequest,
     ) -> Result<StartAppReplicationResponse, RusotoError<StartAppReplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartAppReplication");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.StartAppReplication",
@@ -4710,6 +5381,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<StartOnDemandAppReplicationResponse, RusotoError<StartOnDemandAppReplicationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartOnDemandAppReplication");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.StartOnDemandAppReplication",
@@ -4733,6 +5405,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<StartOnDemandReplicationRunResponse, RusotoError<StartOnDemandReplicationRunError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartOnDemandReplicationRun");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.StartOnDemandReplicationRun",
@@ -4755,6 +5428,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: StopAppReplicationRequest,
     ) -> Result<StopAppReplicationResponse, RusotoError<StopAppReplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopAppReplication");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.StopAppReplication",
@@ -4776,6 +5450,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: TerminateAppRequest,
     ) -> Result<TerminateAppResponse, RusotoError<TerminateAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TerminateApp");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.TerminateApp",
@@ -4797,6 +5472,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: UpdateAppRequest,
     ) -> Result<UpdateAppResponse, RusotoError<UpdateAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateApp");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.UpdateApp",
@@ -4818,6 +5494,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: UpdateReplicationJobRequest,
     ) -> Result<UpdateReplicationJobResponse, RusotoError<UpdateReplicationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateReplicationJob");
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.UpdateReplicationJob",
@@ -4834,3 +5511,414 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .deserialize::<UpdateReplicationJobResponse, _>()
     }
 }
+
+/// A blocking client for the SMS API, running the calls of a `ServerMigrationServiceClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ServerMigrationServiceClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ServerMigrationServiceBlockingClient {
+    client: ServerMigrationServiceClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ServerMigrationServiceBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ServerMigrationServiceBlockingClient {
+        ServerMigrationServiceBlockingClient::from_client(ServerMigrationServiceClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ServerMigrationServiceBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ServerMigrationServiceBlockingClient::from_client(ServerMigrationServiceClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ServerMigrationServiceBlockingClient {
+        ServerMigrationServiceBlockingClient::from_client(
+            ServerMigrationServiceClient::new_with_client(client, region),
+        )
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ServerMigrationServiceBlockingClient {
+        ServerMigrationServiceBlockingClient::from_client(
+            ServerMigrationServiceClient::new_with_config(config),
+        )
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(
+        client: ServerMigrationServiceClient,
+    ) -> ServerMigrationServiceBlockingClient {
+        ServerMigrationServiceBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ServerMigrationServiceBlockingClient {
+        ServerMigrationServiceBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ServerMigrationServiceBlockingClient {
+        ServerMigrationServiceBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ServerMigrationService::create_app`].
+    pub fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
+        self.runtime.block_on(self.client.create_app(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::create_replication_job`].
+    pub fn create_replication_job(
+        &self,
+        input: CreateReplicationJobRequest,
+    ) -> Result<CreateReplicationJobResponse, RusotoError<CreateReplicationJobError>> {
+        self.runtime
+            .block_on(self.client.create_replication_job(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::delete_app`].
+    pub fn delete_app(
+        &self,
+        input: DeleteAppRequest,
+    ) -> Result<DeleteAppResponse, RusotoError<DeleteAppError>> {
+        self.runtime.block_on(self.client.delete_app(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::delete_app_launch_configuration`].
+    pub fn delete_app_launch_configuration(
+        &self,
+        input: DeleteAppLaunchConfigurationRequest,
+    ) -> Result<DeleteAppLaunchConfigurationResponse, RusotoError<DeleteAppLaunchConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_app_launch_configuration(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::delete_app_replication_configuration`].
+    pub fn delete_app_replication_configuration(
+        &self,
+        input: DeleteAppReplicationConfigurationRequest,
+    ) -> Result<
+        DeleteAppReplicationConfigurationResponse,
+        RusotoError<DeleteAppReplicationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_app_replication_configuration(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::delete_app_validation_configuration`].
+    pub fn delete_app_validation_configuration(
+        &self,
+        input: DeleteAppValidationConfigurationRequest,
+    ) -> Result<
+        DeleteAppValidationConfigurationResponse,
+        RusotoError<DeleteAppValidationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_app_validation_configuration(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::delete_replication_job`].
+    pub fn delete_replication_job(
+        &self,
+        input: DeleteReplicationJobRequest,
+    ) -> Result<DeleteReplicationJobResponse, RusotoError<DeleteReplicationJobError>> {
+        self.runtime
+            .block_on(self.client.delete_replication_job(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::delete_server_catalog`].
+    pub fn delete_server_catalog(
+        &self,
+    ) -> Result<DeleteServerCatalogResponse, RusotoError<DeleteServerCatalogError>> {
+        self.runtime.block_on(self.client.delete_server_catalog())
+    }
+
+    /// Blocking version of [`ServerMigrationService::disassociate_connector`].
+    pub fn disassociate_connector(
+        &self,
+        input: DisassociateConnectorRequest,
+    ) -> Result<DisassociateConnectorResponse, RusotoError<DisassociateConnectorError>> {
+        self.runtime
+            .block_on(self.client.disassociate_connector(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::generate_change_set`].
+    pub fn generate_change_set(
+        &self,
+        input: GenerateChangeSetRequest,
+    ) -> Result<GenerateChangeSetResponse, RusotoError<GenerateChangeSetError>> {
+        self.runtime
+            .block_on(self.client.generate_change_set(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::generate_template`].
+    pub fn generate_template(
+        &self,
+        input: GenerateTemplateRequest,
+    ) -> Result<GenerateTemplateResponse, RusotoError<GenerateTemplateError>> {
+        self.runtime.block_on(self.client.generate_template(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::get_app`].
+    pub fn get_app(
+        &self,
+        input: GetAppRequest,
+    ) -> Result<GetAppResponse, RusotoError<GetAppError>> {
+        self.runtime.block_on(self.client.get_app(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::get_app_launch_configuration`].
+    pub fn get_app_launch_configuration(
+        &self,
+        input: GetAppLaunchConfigurationRequest,
+    ) -> Result<GetAppLaunchConfigurationResponse, RusotoError<GetAppLaunchConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_app_launch_configuration(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::get_app_replication_configuration`].
+    pub fn get_app_replication_configuration(
+        &self,
+        input: GetAppReplicationConfigurationRequest,
+    ) -> Result<
+        GetAppReplicationConfigurationResponse,
+        RusotoError<GetAppReplicationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_app_replication_configuration(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::get_app_validation_configuration`].
+    pub fn get_app_validation_configuration(
+        &self,
+        input: GetAppValidationConfigurationRequest,
+    ) -> Result<
+        GetAppValidationConfigurationResponse,
+        RusotoError<GetAppValidationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_app_validation_configuration(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::get_app_validation_output`].
+    pub fn get_app_validation_output(
+        &self,
+        input: GetAppValidationOutputRequest,
+    ) -> Result<GetAppValidationOutputResponse, RusotoError<GetAppValidationOutputError>> {
+        self.runtime
+            .block_on(self.client.get_app_validation_output(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::get_connectors`].
+    pub fn get_connectors(
+        &self,
+        input: GetConnectorsRequest,
+    ) -> Result<GetConnectorsResponse, RusotoError<GetConnectorsError>> {
+        self.runtime.block_on(self.client.get_connectors(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::get_replication_jobs`].
+    pub fn get_replication_jobs(
+        &self,
+        input: GetReplicationJobsRequest,
+    ) -> Result<GetReplicationJobsResponse, RusotoError<GetReplicationJobsError>> {
+        self.runtime
+            .block_on(self.client.get_replication_jobs(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::get_replication_runs`].
+    pub fn get_replication_runs(
+        &self,
+        input: GetReplicationRunsRequest,
+    ) -> Result<GetReplicationRunsResponse, RusotoError<GetReplicationRunsError>> {
+        self.runtime
+            .block_on(self.client.get_replication_runs(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::get_servers`].
+    pub fn get_servers(
+        &self,
+        input: GetServersRequest,
+    ) -> Result<GetServersResponse, RusotoError<GetServersError>> {
+        self.runtime.block_on(self.client.get_servers(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::import_app_catalog`].
+    pub fn import_app_catalog(
+        &self,
+        input: ImportAppCatalogRequest,
+    ) -> Result<ImportAppCatalogResponse, RusotoError<ImportAppCatalogError>> {
+        self.runtime.block_on(self.client.import_app_catalog(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::import_server_catalog`].
+    pub fn import_server_catalog(
+        &self,
+    ) -> Result<ImportServerCatalogResponse, RusotoError<ImportServerCatalogError>> {
+        self.runtime.block_on(self.client.import_server_catalog())
+    }
+
+    /// Blocking version of [`ServerMigrationService::launch_app`].
+    pub fn launch_app(
+        &self,
+        input: LaunchAppRequest,
+    ) -> Result<LaunchAppResponse, RusotoError<LaunchAppError>> {
+        self.runtime.block_on(self.client.launch_app(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::list_apps`].
+    pub fn list_apps(
+        &self,
+        input: ListAppsRequest,
+    ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
+        self.runtime.block_on(self.client.list_apps(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::notify_app_validation_output`].
+    pub fn notify_app_validation_output(
+        &self,
+        input: NotifyAppValidationOutputRequest,
+    ) -> Result<NotifyAppValidationOutputResponse, RusotoError<NotifyAppValidationOutputError>>
+    {
+        self.runtime
+            .block_on(self.client.notify_app_validation_output(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::put_app_launch_configuration`].
+    pub fn put_app_launch_configuration(
+        &self,
+        input: PutAppLaunchConfigurationRequest,
+    ) -> Result<PutAppLaunchConfigurationResponse, RusotoError<PutAppLaunchConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_app_launch_configuration(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::put_app_replication_configuration`].
+    pub fn put_app_replication_configuration(
+        &self,
+        input: PutAppReplicationConfigurationRequest,
+    ) -> Result<
+        PutAppReplicationConfigurationResponse,
+        RusotoError<PutAppReplicationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_app_replication_configuration(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::put_app_validation_configuration`].
+    pub fn put_app_validation_configuration(
+        &self,
+        input: PutAppValidationConfigurationRequest,
+    ) -> Result<
+        PutAppValidationConfigurationResponse,
+        RusotoError<PutAppValidationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_app_validation_configuration(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::start_app_replication`].
+    pub fn start_app_replication(
+        &self,
+        input: StartAppReplicationRequest,
+    ) -> Result<StartAppReplicationResponse, RusotoError<StartAppReplicationError>> {
+        self.runtime
+            .block_on(self.client.start_app_replication(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::start_on_demand_app_replication`].
+    pub fn start_on_demand_app_replication(
+        &self,
+        input: StartOnDemandAppReplicationRequest,
+    ) -> Result<StartOnDemandAppReplicationResponse, RusotoError<StartOnDemandAppReplicationError>>
+    {
+        self.runtime
+            .block_on(self.client.start_on_demand_app_replication(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::start_on_demand_replication_run`].
+    pub fn start_on_demand_replication_run(
+        &self,
+        input: StartOnDemandReplicationRunRequest,
+    ) -> Result<StartOnDemandReplicationRunResponse, RusotoError<StartOnDemandReplicationRunError>>
+    {
+        self.runtime
+            .block_on(self.client.start_on_demand_replication_run(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::stop_app_replication`].
+    pub fn stop_app_replication(
+        &self,
+        input: StopAppReplicationRequest,
+    ) -> Result<StopAppReplicationResponse, RusotoError<StopAppReplicationError>> {
+        self.runtime
+            .block_on(self.client.stop_app_replication(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::terminate_app`].
+    pub fn terminate_app(
+        &self,
+        input: TerminateAppRequest,
+    ) -> Result<TerminateAppResponse, RusotoError<TerminateAppError>> {
+        self.runtime.block_on(self.client.terminate_app(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::update_app`].
+    pub fn update_app(
+        &self,
+        input: UpdateAppRequest,
+    ) -> Result<UpdateAppResponse, RusotoError<UpdateAppError>> {
+        self.runtime.block_on(self.client.update_app(input))
+    }
+
+    /// Blocking version of [`ServerMigrationService::update_replication_job`].
+    pub fn update_replication_job(
+        &self,
+        input: UpdateReplicationJobRequest,
+    ) -> Result<UpdateReplicationJobResponse, RusotoError<UpdateReplicationJobError>> {
+        self.runtime
+            .block_on(self.client.update_replication_job(input))
+    }
+}
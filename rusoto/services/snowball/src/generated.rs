@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1112,15 +1113,30 @@ impl CancelClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(CancelClusterError::InvalidJobState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelClusterError::InvalidJobState,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CancelClusterError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelClusterError::InvalidResource,
+                    )
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(CancelClusterError::KMSRequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelClusterError::KMSRequestFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1154,15 +1170,30 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(CancelJobError::InvalidJobState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::InvalidJobState,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CancelJobError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::InvalidResource,
+                    )
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(CancelJobError::KMSRequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::KMSRequestFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1194,12 +1225,22 @@ impl CreateAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAddressException" => {
-                    return RusotoError::Service(CreateAddressError::InvalidAddress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAddressError::InvalidAddress,
+                    )
                 }
                 "UnsupportedAddressException" => {
-                    return RusotoError::Service(CreateAddressError::UnsupportedAddress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAddressError::UnsupportedAddress,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1234,20 +1275,38 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(CreateClusterError::Ec2RequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::Ec2RequestFailed,
+                    )
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidInputCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateClusterError::InvalidInputCombination,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::InvalidResource,
+                    )
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(CreateClusterError::KMSRequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::KMSRequestFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1286,21 +1345,46 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterLimitExceededException" => {
-                    return RusotoError::Service(CreateJobError::ClusterLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::ClusterLimitExceeded,
+                    )
                 }
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(CreateJobError::Ec2RequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::Ec2RequestFailed,
+                    )
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(CreateJobError::InvalidInputCombination(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::InvalidInputCombination,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CreateJobError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::InvalidResource,
+                    )
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(CreateJobError::KMSRequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::KMSRequestFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1340,29 +1424,46 @@ impl CreateReturnShippingLabelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateReturnShippingLabelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReturnShippingLabelError::Conflict,
+                    )
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(
-                        CreateReturnShippingLabelError::InvalidInputCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReturnShippingLabelError::InvalidInputCombination,
                     )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(CreateReturnShippingLabelError::InvalidJobState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReturnShippingLabelError::InvalidJobState,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CreateReturnShippingLabelError::InvalidResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReturnShippingLabelError::InvalidResource,
+                    )
                 }
                 "ReturnShippingLabelAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateReturnShippingLabelError::ReturnShippingLabelAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReturnShippingLabelError::ReturnShippingLabelAlreadyExists,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1398,9 +1499,14 @@ impl DescribeAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeAddressError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAddressError::InvalidResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1430,12 +1536,22 @@ impl DescribeAddressesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeAddressesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAddressesError::InvalidNextToken,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeAddressesError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAddressesError::InvalidResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1464,9 +1580,14 @@ impl DescribeClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeClusterError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterError::InvalidResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1494,9 +1615,14 @@ impl DescribeJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeJobError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobError::InvalidResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1530,21 +1656,30 @@ impl DescribeReturnShippingLabelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeReturnShippingLabelError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeReturnShippingLabelError::Conflict,
+                    )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(DescribeReturnShippingLabelError::InvalidJobState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeReturnShippingLabelError::InvalidJobState,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeReturnShippingLabelError::InvalidResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeReturnShippingLabelError::InvalidResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1576,12 +1711,22 @@ impl GetJobManifestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(GetJobManifestError::InvalidJobState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobManifestError::InvalidJobState,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(GetJobManifestError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobManifestError::InvalidResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1612,12 +1757,22 @@ impl GetJobUnlockCodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(GetJobUnlockCodeError::InvalidJobState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobUnlockCodeError::InvalidJobState,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(GetJobUnlockCodeError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobUnlockCodeError::InvalidResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1642,7 +1797,7 @@ impl GetSnowballUsageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetSnowballUsageError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1670,12 +1825,22 @@ impl GetSoftwareUpdatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(GetSoftwareUpdatesError::InvalidJobState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSoftwareUpdatesError::InvalidJobState,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(GetSoftwareUpdatesError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSoftwareUpdatesError::InvalidResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1706,12 +1871,22 @@ impl ListClusterJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListClusterJobsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClusterJobsError::InvalidNextToken,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(ListClusterJobsError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClusterJobsError::InvalidResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1740,9 +1915,14 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListClustersError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1772,16 +1952,22 @@ impl ListCompatibleImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(ListCompatibleImagesError::Ec2RequestFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCompatibleImagesError::Ec2RequestFailed,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCompatibleImagesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCompatibleImagesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1810,9 +1996,14 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListJobsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1848,23 +2039,46 @@ impl UpdateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(UpdateClusterError::Ec2RequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterError::Ec2RequestFailed,
+                    )
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidInputCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterError::InvalidInputCombination,
+                    )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidJobState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterError::InvalidJobState,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterError::InvalidResource,
+                    )
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(UpdateClusterError::KMSRequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterError::KMSRequestFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1906,24 +2120,54 @@ impl UpdateJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterLimitExceededException" => {
-                    return RusotoError::Service(UpdateJobError::ClusterLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::ClusterLimitExceeded,
+                    )
                 }
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(UpdateJobError::Ec2RequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::Ec2RequestFailed,
+                    )
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidInputCombination(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::InvalidInputCombination,
+                    )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidJobState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::InvalidJobState,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::InvalidResource,
+                    )
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(UpdateJobError::KMSRequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::KMSRequestFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1958,16 +2202,22 @@ impl UpdateJobShipmentStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(UpdateJobShipmentStateError::InvalidJobState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateJobShipmentStateError::InvalidJobState,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(UpdateJobShipmentStateError::InvalidResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateJobShipmentStateError::InvalidResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2128,11 +2378,12 @@ pub struct SnowballClient {
 impl SnowballClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SnowballClient {
         SnowballClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Snowball"),
         }
     }
 
@@ -2147,12 +2398,32 @@ impl SnowballClient {
     {
         SnowballClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Snowball"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SnowballClient {
-        SnowballClient { client, region }
+        SnowballClient {
+            client,
+            region: region_for_service(region, "Snowball"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SnowballClient {
+        SnowballClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Snowball"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SnowballClient {
+        SnowballClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2164,6 +2435,7 @@ impl Snowball for SnowballClient {
         input: CancelClusterRequest,
     ) -> Result<CancelClusterResult, RusotoError<CancelClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelCluster");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.CancelCluster",
@@ -2185,6 +2457,7 @@ impl Snowball for SnowballClient {
         input: CancelJobRequest,
     ) -> Result<CancelJobResult, RusotoError<CancelJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelJob");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.CancelJob",
@@ -2206,6 +2479,7 @@ impl Snowball for SnowballClient {
         input: CreateAddressRequest,
     ) -> Result<CreateAddressResult, RusotoError<CreateAddressError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAddress");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.CreateAddress",
@@ -2227,6 +2501,7 @@ impl Snowball for SnowballClient {
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCluster");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.CreateCluster",
@@ -2248,6 +2523,7 @@ impl Snowball for SnowballClient {
         input: CreateJobRequest,
     ) -> Result<CreateJobResult, RusotoError<CreateJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateJob");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.CreateJob",
@@ -2269,6 +2545,7 @@ impl Snowball for SnowballClient {
         input: CreateReturnShippingLabelRequest,
     ) -> Result<CreateReturnShippingLabelResult, RusotoError<CreateReturnShippingLabelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateReturnShippingLabel");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.CreateReturnShippingLabel",
@@ -2291,6 +2568,7 @@ impl Snowball for SnowballClient {
         input: DescribeAddressRequest,
     ) -> Result<DescribeAddressResult, RusotoError<DescribeAddressError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAddress");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.DescribeAddress",
@@ -2312,6 +2590,7 @@ impl Snowball for SnowballClient {
         input: DescribeAddressesRequest,
     ) -> Result<DescribeAddressesResult, RusotoError<DescribeAddressesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAddresses");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.DescribeAddresses",
@@ -2333,6 +2612,7 @@ impl Snowball for SnowballClient {
         input: DescribeClusterRequest,
     ) -> Result<DescribeClusterResult, RusotoError<DescribeClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCluster");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.DescribeCluster",
@@ -2354,6 +2634,7 @@ impl Snowball for SnowballClient {
         input: DescribeJobRequest,
     ) -> Result<DescribeJobResult, RusotoError<DescribeJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeJob");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.DescribeJob",
@@ -2376,6 +2657,7 @@ impl Snowball for SnowballClient {
     ) -> Result<DescribeReturnShippingLabelResult, RusotoError<DescribeReturnShippingLabelError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeReturnShippingLabel");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.DescribeReturnShippingLabel",
@@ -2398,6 +2680,7 @@ impl Snowball for SnowballClient {
         input: GetJobManifestRequest,
     ) -> Result<GetJobManifestResult, RusotoError<GetJobManifestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetJobManifest");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.GetJobManifest",
@@ -2419,6 +2702,7 @@ impl Snowball for SnowballClient {
         input: GetJobUnlockCodeRequest,
     ) -> Result<GetJobUnlockCodeResult, RusotoError<GetJobUnlockCodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetJobUnlockCode");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.GetJobUnlockCode",
@@ -2439,6 +2723,7 @@ impl Snowball for SnowballClient {
         &self,
     ) -> Result<GetSnowballUsageResult, RusotoError<GetSnowballUsageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSnowballUsage");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.GetSnowballUsage",
@@ -2459,6 +2744,7 @@ impl Snowball for SnowballClient {
         input: GetSoftwareUpdatesRequest,
     ) -> Result<GetSoftwareUpdatesResult, RusotoError<GetSoftwareUpdatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSoftwareUpdates");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.GetSoftwareUpdates",
@@ -2480,6 +2766,7 @@ impl Snowball for SnowballClient {
         input: ListClusterJobsRequest,
     ) -> Result<ListClusterJobsResult, RusotoError<ListClusterJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListClusterJobs");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.ListClusterJobs",
@@ -2501,6 +2788,7 @@ impl Snowball for SnowballClient {
         input: ListClustersRequest,
     ) -> Result<ListClustersResult, RusotoError<ListClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListClusters");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.ListClusters",
@@ -2522,6 +2810,7 @@ impl Snowball for SnowballClient {
         input: ListCompatibleImagesRequest,
     ) -> Result<ListCompatibleImagesResult, RusotoError<ListCompatibleImagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCompatibleImages");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.ListCompatibleImages",
@@ -2543,6 +2832,7 @@ impl Snowball for SnowballClient {
         input: ListJobsRequest,
     ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListJobs");
         request.add_header("x-amz-target", "AWSIESnowballJobManagementService.ListJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2561,6 +2851,7 @@ impl Snowball for SnowballClient {
         input: UpdateClusterRequest,
     ) -> Result<UpdateClusterResult, RusotoError<UpdateClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCluster");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.UpdateCluster",
@@ -2582,6 +2873,7 @@ impl Snowball for SnowballClient {
         input: UpdateJobRequest,
     ) -> Result<UpdateJobResult, RusotoError<UpdateJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateJob");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.UpdateJob",
@@ -2603,6 +2895,7 @@ impl Snowball for SnowballClient {
         input: UpdateJobShipmentStateRequest,
     ) -> Result<UpdateJobShipmentStateResult, RusotoError<UpdateJobShipmentStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateJobShipmentState");
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.UpdateJobShipmentState",
@@ -2619,3 +2912,260 @@ impl Snowball for SnowballClient {
             .deserialize::<UpdateJobShipmentStateResult, _>()
     }
 }
+
+/// A blocking client for the Amazon Snowball API, running the calls of a `SnowballClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SnowballClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SnowballBlockingClient {
+    client: SnowballClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SnowballBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SnowballBlockingClient {
+        SnowballBlockingClient::from_client(SnowballClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SnowballBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SnowballBlockingClient::from_client(SnowballClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> SnowballBlockingClient {
+        SnowballBlockingClient::from_client(SnowballClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SnowballBlockingClient {
+        SnowballBlockingClient::from_client(SnowballClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SnowballClient) -> SnowballBlockingClient {
+        SnowballBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> SnowballBlockingClient {
+        SnowballBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SnowballBlockingClient {
+        SnowballBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Snowball::cancel_cluster`].
+    pub fn cancel_cluster(
+        &self,
+        input: CancelClusterRequest,
+    ) -> Result<CancelClusterResult, RusotoError<CancelClusterError>> {
+        self.runtime.block_on(self.client.cancel_cluster(input))
+    }
+
+    /// Blocking version of [`Snowball::cancel_job`].
+    pub fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResult, RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// Blocking version of [`Snowball::create_address`].
+    pub fn create_address(
+        &self,
+        input: CreateAddressRequest,
+    ) -> Result<CreateAddressResult, RusotoError<CreateAddressError>> {
+        self.runtime.block_on(self.client.create_address(input))
+    }
+
+    /// Blocking version of [`Snowball::create_cluster`].
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// Blocking version of [`Snowball::create_job`].
+    pub fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResult, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// Blocking version of [`Snowball::create_return_shipping_label`].
+    pub fn create_return_shipping_label(
+        &self,
+        input: CreateReturnShippingLabelRequest,
+    ) -> Result<CreateReturnShippingLabelResult, RusotoError<CreateReturnShippingLabelError>> {
+        self.runtime
+            .block_on(self.client.create_return_shipping_label(input))
+    }
+
+    /// Blocking version of [`Snowball::describe_address`].
+    pub fn describe_address(
+        &self,
+        input: DescribeAddressRequest,
+    ) -> Result<DescribeAddressResult, RusotoError<DescribeAddressError>> {
+        self.runtime.block_on(self.client.describe_address(input))
+    }
+
+    /// Blocking version of [`Snowball::describe_addresses`].
+    pub fn describe_addresses(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> Result<DescribeAddressesResult, RusotoError<DescribeAddressesError>> {
+        self.runtime.block_on(self.client.describe_addresses(input))
+    }
+
+    /// Blocking version of [`Snowball::describe_cluster`].
+    pub fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<DescribeClusterResult, RusotoError<DescribeClusterError>> {
+        self.runtime.block_on(self.client.describe_cluster(input))
+    }
+
+    /// Blocking version of [`Snowball::describe_job`].
+    pub fn describe_job(
+        &self,
+        input: DescribeJobRequest,
+    ) -> Result<DescribeJobResult, RusotoError<DescribeJobError>> {
+        self.runtime.block_on(self.client.describe_job(input))
+    }
+
+    /// Blocking version of [`Snowball::describe_return_shipping_label`].
+    pub fn describe_return_shipping_label(
+        &self,
+        input: DescribeReturnShippingLabelRequest,
+    ) -> Result<DescribeReturnShippingLabelResult, RusotoError<DescribeReturnShippingLabelError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_return_shipping_label(input))
+    }
+
+    /// Blocking version of [`Snowball::get_job_manifest`].
+    pub fn get_job_manifest(
+        &self,
+        input: GetJobManifestRequest,
+    ) -> Result<GetJobManifestResult, RusotoError<GetJobManifestError>> {
+        self.runtime.block_on(self.client.get_job_manifest(input))
+    }
+
+    /// Blocking version of [`Snowball::get_job_unlock_code`].
+    pub fn get_job_unlock_code(
+        &self,
+        input: GetJobUnlockCodeRequest,
+    ) -> Result<GetJobUnlockCodeResult, RusotoError<GetJobUnlockCodeError>> {
+        self.runtime
+            .block_on(self.client.get_job_unlock_code(input))
+    }
+
+    /// Blocking version of [`Snowball::get_snowball_usage`].
+    pub fn get_snowball_usage(
+        &self,
+    ) -> Result<GetSnowballUsageResult, RusotoError<GetSnowballUsageError>> {
+        self.runtime.block_on(self.client.get_snowball_usage())
+    }
+
+    /// Blocking version of [`Snowball::get_software_updates`].
+    pub fn get_software_updates(
+        &self,
+        input: GetSoftwareUpdatesRequest,
+    ) -> Result<GetSoftwareUpdatesResult, RusotoError<GetSoftwareUpdatesError>> {
+        self.runtime
+            .block_on(self.client.get_software_updates(input))
+    }
+
+    /// Blocking version of [`Snowball::list_cluster_jobs`].
+    pub fn list_cluster_jobs(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> Result<ListClusterJobsResult, RusotoError<ListClusterJobsError>> {
+        self.runtime.block_on(self.client.list_cluster_jobs(input))
+    }
+
+    /// Blocking version of [`Snowball::list_clusters`].
+    pub fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<ListClustersResult, RusotoError<ListClustersError>> {
+        self.runtime.block_on(self.client.list_clusters(input))
+    }
+
+    /// Blocking version of [`Snowball::list_compatible_images`].
+    pub fn list_compatible_images(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> Result<ListCompatibleImagesResult, RusotoError<ListCompatibleImagesError>> {
+        self.runtime
+            .block_on(self.client.list_compatible_images(input))
+    }
+
+    /// Blocking version of [`Snowball::list_jobs`].
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// Blocking version of [`Snowball::update_cluster`].
+    pub fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> Result<UpdateClusterResult, RusotoError<UpdateClusterError>> {
+        self.runtime.block_on(self.client.update_cluster(input))
+    }
+
+    /// Blocking version of [`Snowball::update_job`].
+    pub fn update_job(
+        &self,
+        input: UpdateJobRequest,
+    ) -> Result<UpdateJobResult, RusotoError<UpdateJobError>> {
+        self.runtime.block_on(self.client.update_job(input))
+    }
+
+    /// Blocking version of [`Snowball::update_job_shipment_state`].
+    pub fn update_job_shipment_state(
+        &self,
+        input: UpdateJobShipmentStateRequest,
+    ) -> Result<UpdateJobShipmentStateResult, RusotoError<UpdateJobShipmentStateError>> {
+        self.runtime
+            .block_on(self.client.update_job_shipment_state(input))
+    }
+}
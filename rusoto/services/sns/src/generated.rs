@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -2357,24 +2358,36 @@ impl AddPermissionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(AddPermissionError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddPermissionError::AuthorizationError,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(AddPermissionError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddPermissionError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(AddPermissionError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddPermissionError::InvalidParameter,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(AddPermissionError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddPermissionError::NotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -2427,28 +2440,36 @@ impl CheckIfPhoneNumberIsOptedOutError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            CheckIfPhoneNumberIsOptedOutError::AuthorizationError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CheckIfPhoneNumberIsOptedOutError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(
-                            CheckIfPhoneNumberIsOptedOutError::InternalError(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CheckIfPhoneNumberIsOptedOutError::InternalError,
                         )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
-                            CheckIfPhoneNumberIsOptedOutError::InvalidParameter(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CheckIfPhoneNumberIsOptedOutError::InvalidParameter,
                         )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(CheckIfPhoneNumberIsOptedOutError::Throttled(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CheckIfPhoneNumberIsOptedOutError::Throttled,
+                        )
                     }
                     _ => {}
                 }
@@ -2507,37 +2528,51 @@ impl ConfirmSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(ConfirmSubscriptionError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ConfirmSubscriptionError::AuthorizationError,
+                        )
                     }
                     "FilterPolicyLimitExceeded" => {
-                        return RusotoError::Service(
-                            ConfirmSubscriptionError::FilterPolicyLimitExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ConfirmSubscriptionError::FilterPolicyLimitExceeded,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ConfirmSubscriptionError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ConfirmSubscriptionError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(ConfirmSubscriptionError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ConfirmSubscriptionError::InvalidParameter,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(ConfirmSubscriptionError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ConfirmSubscriptionError::NotFound,
+                        )
                     }
                     "SubscriptionLimitExceeded" => {
-                        return RusotoError::Service(
-                            ConfirmSubscriptionError::SubscriptionLimitExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ConfirmSubscriptionError::SubscriptionLimitExceeded,
                         )
                     }
                     _ => {}
@@ -2593,20 +2628,27 @@ impl CreatePlatformApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            CreatePlatformApplicationError::AuthorizationError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreatePlatformApplicationError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(CreatePlatformApplicationError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePlatformApplicationError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
-                            CreatePlatformApplicationError::InvalidParameter(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreatePlatformApplicationError::InvalidParameter,
                         )
                     }
                     _ => {}
@@ -2657,24 +2699,36 @@ impl CreatePlatformEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            CreatePlatformEndpointError::AuthorizationError(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreatePlatformEndpointError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(CreatePlatformEndpointError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePlatformEndpointError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(CreatePlatformEndpointError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePlatformEndpointError::InvalidParameter,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(CreatePlatformEndpointError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePlatformEndpointError::NotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -2735,49 +2789,76 @@ impl CreateTopicError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(CreateTopicError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTopicError::AuthorizationError,
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(CreateTopicError::ConcurrentAccess(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTopicError::ConcurrentAccess,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(CreateTopicError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTopicError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(CreateTopicError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTopicError::InvalidParameter,
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(CreateTopicError::InvalidSecurity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTopicError::InvalidSecurity,
+                        )
                     }
                     "StaleTag" => {
-                        return RusotoError::Service(CreateTopicError::StaleTag(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTopicError::StaleTag,
+                        )
                     }
                     "TagLimitExceeded" => {
-                        return RusotoError::Service(CreateTopicError::TagLimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTopicError::TagLimitExceeded,
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(CreateTopicError::TagPolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTopicError::TagPolicy,
+                        )
                     }
                     "TopicLimitExceeded" => {
-                        return RusotoError::Service(CreateTopicError::TopicLimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTopicError::TopicLimitExceeded,
+                        )
                     }
                     _ => {}
                 }
@@ -2831,19 +2912,28 @@ impl DeleteEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(DeleteEndpointError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteEndpointError::AuthorizationError,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(DeleteEndpointError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteEndpointError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(DeleteEndpointError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteEndpointError::InvalidParameter,
+                        )
                     }
                     _ => {}
                 }
@@ -2891,20 +2981,27 @@ impl DeletePlatformApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            DeletePlatformApplicationError::AuthorizationError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeletePlatformApplicationError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(DeletePlatformApplicationError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePlatformApplicationError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
-                            DeletePlatformApplicationError::InvalidParameter(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeletePlatformApplicationError::InvalidParameter,
                         )
                     }
                     _ => {}
@@ -2961,39 +3058,60 @@ impl DeleteTopicError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(DeleteTopicError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTopicError::AuthorizationError,
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(DeleteTopicError::ConcurrentAccess(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTopicError::ConcurrentAccess,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(DeleteTopicError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTopicError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(DeleteTopicError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTopicError::InvalidParameter,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(DeleteTopicError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTopicError::NotFound,
+                        )
                     }
                     "StaleTag" => {
-                        return RusotoError::Service(DeleteTopicError::StaleTag(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTopicError::StaleTag,
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(DeleteTopicError::TagPolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTopicError::TagPolicy,
+                        )
                     }
                     _ => {}
                 }
@@ -3047,24 +3165,36 @@ impl GetEndpointAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            GetEndpointAttributesError::AuthorizationError(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetEndpointAttributesError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(GetEndpointAttributesError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetEndpointAttributesError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(GetEndpointAttributesError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetEndpointAttributesError::InvalidParameter,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(GetEndpointAttributesError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetEndpointAttributesError::NotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -3117,29 +3247,35 @@ impl GetPlatformApplicationAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            GetPlatformApplicationAttributesError::AuthorizationError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetPlatformApplicationAttributesError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(
-                            GetPlatformApplicationAttributesError::InternalError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetPlatformApplicationAttributesError::InternalError,
                         )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
-                            GetPlatformApplicationAttributesError::InvalidParameter(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetPlatformApplicationAttributesError::InvalidParameter,
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(
-                            GetPlatformApplicationAttributesError::NotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetPlatformApplicationAttributesError::NotFound,
                         )
                     }
                     _ => {}
@@ -3197,24 +3333,36 @@ impl GetSMSAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(GetSMSAttributesError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetSMSAttributesError::AuthorizationError,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(GetSMSAttributesError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetSMSAttributesError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(GetSMSAttributesError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetSMSAttributesError::InvalidParameter,
+                        )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(GetSMSAttributesError::Throttled(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetSMSAttributesError::Throttled,
+                        )
                     }
                     _ => {}
                 }
@@ -3265,26 +3413,36 @@ impl GetSubscriptionAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            GetSubscriptionAttributesError::AuthorizationError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetSubscriptionAttributesError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(GetSubscriptionAttributesError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetSubscriptionAttributesError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
-                            GetSubscriptionAttributesError::InvalidParameter(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetSubscriptionAttributesError::InvalidParameter,
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(GetSubscriptionAttributesError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetSubscriptionAttributesError::NotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -3337,29 +3495,44 @@ impl GetTopicAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(GetTopicAttributesError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetTopicAttributesError::AuthorizationError,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(GetTopicAttributesError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetTopicAttributesError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(GetTopicAttributesError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetTopicAttributesError::InvalidParameter,
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(GetTopicAttributesError::InvalidSecurity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetTopicAttributesError::InvalidSecurity,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(GetTopicAttributesError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetTopicAttributesError::NotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -3413,29 +3586,35 @@ impl ListEndpointsByPlatformApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            ListEndpointsByPlatformApplicationError::AuthorizationError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListEndpointsByPlatformApplicationError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(
-                            ListEndpointsByPlatformApplicationError::InternalError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListEndpointsByPlatformApplicationError::InternalError,
                         )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
-                            ListEndpointsByPlatformApplicationError::InvalidParameter(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListEndpointsByPlatformApplicationError::InvalidParameter,
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(
-                            ListEndpointsByPlatformApplicationError::NotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListEndpointsByPlatformApplicationError::NotFound,
                         )
                     }
                     _ => {}
@@ -3493,24 +3672,36 @@ impl ListPhoneNumbersOptedOutError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            ListPhoneNumbersOptedOutError::AuthorizationError(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListPhoneNumbersOptedOutError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListPhoneNumbersOptedOutError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListPhoneNumbersOptedOutError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
-                            ListPhoneNumbersOptedOutError::InvalidParameter(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListPhoneNumbersOptedOutError::InvalidParameter,
                         )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(ListPhoneNumbersOptedOutError::Throttled(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListPhoneNumbersOptedOutError::Throttled,
+                        )
                     }
                     _ => {}
                 }
@@ -3559,18 +3750,27 @@ impl ListPlatformApplicationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            ListPlatformApplicationsError::AuthorizationError(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListPlatformApplicationsError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListPlatformApplicationsError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListPlatformApplicationsError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
-                            ListPlatformApplicationsError::InvalidParameter(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListPlatformApplicationsError::InvalidParameter,
                         )
                     }
                     _ => {}
@@ -3619,19 +3819,28 @@ impl ListSubscriptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(ListSubscriptionsError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListSubscriptionsError::AuthorizationError,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListSubscriptionsError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListSubscriptionsError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(ListSubscriptionsError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListSubscriptionsError::InvalidParameter,
+                        )
                     }
                     _ => {}
                 }
@@ -3681,24 +3890,36 @@ impl ListSubscriptionsByTopicError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            ListSubscriptionsByTopicError::AuthorizationError(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListSubscriptionsByTopicError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListSubscriptionsByTopicError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListSubscriptionsByTopicError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
-                            ListSubscriptionsByTopicError::InvalidParameter(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListSubscriptionsByTopicError::InvalidParameter,
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(ListSubscriptionsByTopicError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListSubscriptionsByTopicError::NotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -3751,29 +3972,44 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(ListTagsForResourceError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::AuthorizationError,
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(ListTagsForResourceError::ConcurrentAccess(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::ConcurrentAccess,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::InvalidParameter,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::ResourceNotFound,
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(ListTagsForResourceError::TagPolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::TagPolicy,
+                        )
                     }
                     _ => {}
                 }
@@ -3823,19 +4059,28 @@ impl ListTopicsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(ListTopicsError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTopicsError::AuthorizationError,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListTopicsError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTopicsError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(ListTopicsError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTopicsError::InvalidParameter,
+                        )
                     }
                     _ => {}
                 }
@@ -3885,24 +4130,36 @@ impl OptInPhoneNumberError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(OptInPhoneNumberError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            OptInPhoneNumberError::AuthorizationError,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(OptInPhoneNumberError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            OptInPhoneNumberError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(OptInPhoneNumberError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            OptInPhoneNumberError::InvalidParameter,
+                        )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(OptInPhoneNumberError::Throttled(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            OptInPhoneNumberError::Throttled,
+                        )
                     }
                     _ => {}
                 }
@@ -3973,72 +4230,116 @@ impl PublishError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(PublishError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::AuthorizationError,
+                        )
                     }
                     "EndpointDisabled" => {
-                        return RusotoError::Service(PublishError::EndpointDisabled(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::EndpointDisabled,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(PublishError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(PublishError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::InvalidParameter,
+                        )
                     }
                     "ParameterValueInvalid" => {
-                        return RusotoError::Service(PublishError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::InvalidParameterValue,
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(PublishError::InvalidSecurity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::InvalidSecurity,
+                        )
                     }
                     "KMSAccessDenied" => {
-                        return RusotoError::Service(PublishError::KMSAccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::KMSAccessDenied,
+                        )
                     }
                     "KMSDisabled" => {
-                        return RusotoError::Service(PublishError::KMSDisabled(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::KMSDisabled,
+                        )
                     }
                     "KMSInvalidState" => {
-                        return RusotoError::Service(PublishError::KMSInvalidState(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::KMSInvalidState,
+                        )
                     }
                     "KMSNotFound" => {
-                        return RusotoError::Service(PublishError::KMSNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::KMSNotFound,
+                        )
                     }
                     "KMSOptInRequired" => {
-                        return RusotoError::Service(PublishError::KMSOptInRequired(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::KMSOptInRequired,
+                        )
                     }
                     "KMSThrottling" => {
-                        return RusotoError::Service(PublishError::KMSThrottling(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::KMSThrottling,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(PublishError::NotFound(parsed_error.message))
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PublishError::NotFound,
+                        )
                     }
                     "PlatformApplicationDisabled" => {
-                        return RusotoError::Service(PublishError::PlatformApplicationDisabled(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PublishError::PlatformApplicationDisabled,
+                        )
                     }
                     _ => {}
                 }
@@ -4099,24 +4400,36 @@ impl RemovePermissionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(RemovePermissionError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemovePermissionError::AuthorizationError,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(RemovePermissionError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemovePermissionError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(RemovePermissionError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemovePermissionError::InvalidParameter,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(RemovePermissionError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemovePermissionError::NotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4167,24 +4480,36 @@ impl SetEndpointAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            SetEndpointAttributesError::AuthorizationError(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetEndpointAttributesError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SetEndpointAttributesError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetEndpointAttributesError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(SetEndpointAttributesError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetEndpointAttributesError::InvalidParameter,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SetEndpointAttributesError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetEndpointAttributesError::NotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4237,29 +4562,35 @@ impl SetPlatformApplicationAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            SetPlatformApplicationAttributesError::AuthorizationError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetPlatformApplicationAttributesError::AuthorizationError,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(
-                            SetPlatformApplicationAttributesError::InternalError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetPlatformApplicationAttributesError::InternalError,
                         )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
-                            SetPlatformApplicationAttributesError::InvalidParameter(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetPlatformApplicationAttributesError::InvalidParameter,
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(
-                            SetPlatformApplicationAttributesError::NotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetPlatformApplicationAttributesError::NotFound,
                         )
                     }
                     _ => {}
@@ -4317,24 +4648,36 @@ impl SetSMSAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(SetSMSAttributesError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSMSAttributesError::AuthorizationError,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SetSMSAttributesError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSMSAttributesError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(SetSMSAttributesError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSMSAttributesError::InvalidParameter,
+                        )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(SetSMSAttributesError::Throttled(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSMSAttributesError::Throttled,
+                        )
                     }
                     _ => {}
                 }
@@ -4387,33 +4730,44 @@ impl SetSubscriptionAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
-                            SetSubscriptionAttributesError::AuthorizationError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetSubscriptionAttributesError::AuthorizationError,
                         )
                     }
                     "FilterPolicyLimitExceeded" => {
-                        return RusotoError::Service(
-                            SetSubscriptionAttributesError::FilterPolicyLimitExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetSubscriptionAttributesError::FilterPolicyLimitExceeded,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SetSubscriptionAttributesError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSubscriptionAttributesError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
-                            SetSubscriptionAttributesError::InvalidParameter(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetSubscriptionAttributesError::InvalidParameter,
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SetSubscriptionAttributesError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSubscriptionAttributesError::NotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4469,29 +4823,44 @@ impl SetTopicAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(SetTopicAttributesError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetTopicAttributesError::AuthorizationError,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SetTopicAttributesError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetTopicAttributesError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(SetTopicAttributesError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetTopicAttributesError::InvalidParameter,
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(SetTopicAttributesError::InvalidSecurity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetTopicAttributesError::InvalidSecurity,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SetTopicAttributesError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetTopicAttributesError::NotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4549,37 +4918,60 @@ impl SubscribeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(SubscribeError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SubscribeError::AuthorizationError,
+                        )
                     }
                     "FilterPolicyLimitExceeded" => {
-                        return RusotoError::Service(SubscribeError::FilterPolicyLimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SubscribeError::FilterPolicyLimitExceeded,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SubscribeError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SubscribeError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(SubscribeError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SubscribeError::InvalidParameter,
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(SubscribeError::InvalidSecurity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SubscribeError::InvalidSecurity,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SubscribeError::NotFound(parsed_error.message))
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SubscribeError::NotFound,
+                        )
                     }
                     "SubscriptionLimitExceeded" => {
-                        return RusotoError::Service(SubscribeError::SubscriptionLimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SubscribeError::SubscriptionLimitExceeded,
+                        )
                     }
                     _ => {}
                 }
@@ -4639,39 +5031,60 @@ impl TagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(TagResourceError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::AuthorizationError,
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(TagResourceError::ConcurrentAccess(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::ConcurrentAccess,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(TagResourceError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::InvalidParameter,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(TagResourceError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::ResourceNotFound,
+                        )
                     }
                     "StaleTag" => {
-                        return RusotoError::Service(TagResourceError::StaleTag(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::StaleTag,
+                        )
                     }
                     "TagLimitExceeded" => {
-                        return RusotoError::Service(TagResourceError::TagLimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::TagLimitExceeded,
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(TagResourceError::TagPolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::TagPolicy,
+                        )
                     }
                     _ => {}
                 }
@@ -4727,29 +5140,44 @@ impl UnsubscribeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(UnsubscribeError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UnsubscribeError::AuthorizationError,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(UnsubscribeError::InternalError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UnsubscribeError::InternalError,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(UnsubscribeError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UnsubscribeError::InvalidParameter,
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(UnsubscribeError::InvalidSecurity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UnsubscribeError::InvalidSecurity,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(UnsubscribeError::NotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UnsubscribeError::NotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4807,39 +5235,60 @@ impl UntagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(UntagResourceError::AuthorizationError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::AuthorizationError,
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(UntagResourceError::ConcurrentAccess(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::ConcurrentAccess,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(UntagResourceError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::InvalidParameter,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(UntagResourceError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::ResourceNotFound,
+                        )
                     }
                     "StaleTag" => {
-                        return RusotoError::Service(UntagResourceError::StaleTag(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::StaleTag,
+                        )
                     }
                     "TagLimitExceeded" => {
-                        return RusotoError::Service(UntagResourceError::TagLimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::TagLimitExceeded,
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(UntagResourceError::TagPolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::TagPolicy,
+                        )
                     }
                     _ => {}
                 }
@@ -5088,11 +5537,12 @@ pub struct SnsClient {
 impl SnsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SnsClient {
         SnsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SNS"),
         }
     }
 
@@ -5107,12 +5557,32 @@ impl SnsClient {
     {
         SnsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SNS"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SnsClient {
-        SnsClient { client, region }
+        SnsClient {
+            client,
+            region: region_for_service(region, "SNS"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SnsClient {
+        SnsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SNS"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SnsClient {
+        SnsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -5124,6 +5594,7 @@ impl Sns for SnsClient {
         input: AddPermissionInput,
     ) -> Result<(), RusotoError<AddPermissionError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("AddPermission");
         let params = self.new_params("AddPermission");
         let mut params = params;
         AddPermissionInputSerializer::serialize(&mut params, "", &input);
@@ -5145,6 +5616,7 @@ impl Sns for SnsClient {
     ) -> Result<CheckIfPhoneNumberIsOptedOutResponse, RusotoError<CheckIfPhoneNumberIsOptedOutError>>
     {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CheckIfPhoneNumberIsOptedOut");
         let params = self.new_params("CheckIfPhoneNumberIsOptedOut");
         let mut params = params;
         CheckIfPhoneNumberIsOptedOutInputSerializer::serialize(&mut params, "", &input);
@@ -5178,6 +5650,7 @@ impl Sns for SnsClient {
         input: ConfirmSubscriptionInput,
     ) -> Result<ConfirmSubscriptionResponse, RusotoError<ConfirmSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ConfirmSubscription");
         let params = self.new_params("ConfirmSubscription");
         let mut params = params;
         ConfirmSubscriptionInputSerializer::serialize(&mut params, "", &input);
@@ -5212,6 +5685,7 @@ impl Sns for SnsClient {
     ) -> Result<CreatePlatformApplicationResponse, RusotoError<CreatePlatformApplicationError>>
     {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CreatePlatformApplication");
         let params = self.new_params("CreatePlatformApplication");
         let mut params = params;
         CreatePlatformApplicationInputSerializer::serialize(&mut params, "", &input);
@@ -5245,6 +5719,7 @@ impl Sns for SnsClient {
         input: CreatePlatformEndpointInput,
     ) -> Result<CreateEndpointResponse, RusotoError<CreatePlatformEndpointError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CreatePlatformEndpoint");
         let params = self.new_params("CreatePlatformEndpoint");
         let mut params = params;
         CreatePlatformEndpointInputSerializer::serialize(&mut params, "", &input);
@@ -5278,6 +5753,7 @@ impl Sns for SnsClient {
         input: CreateTopicInput,
     ) -> Result<CreateTopicResponse, RusotoError<CreateTopicError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CreateTopic");
         let params = self.new_params("CreateTopic");
         let mut params = params;
         CreateTopicInputSerializer::serialize(&mut params, "", &input);
@@ -5308,6 +5784,7 @@ impl Sns for SnsClient {
         input: DeleteEndpointInput,
     ) -> Result<(), RusotoError<DeleteEndpointError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("DeleteEndpoint");
         let params = self.new_params("DeleteEndpoint");
         let mut params = params;
         DeleteEndpointInputSerializer::serialize(&mut params, "", &input);
@@ -5328,6 +5805,7 @@ impl Sns for SnsClient {
         input: DeletePlatformApplicationInput,
     ) -> Result<(), RusotoError<DeletePlatformApplicationError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("DeletePlatformApplication");
         let params = self.new_params("DeletePlatformApplication");
         let mut params = params;
         DeletePlatformApplicationInputSerializer::serialize(&mut params, "", &input);
@@ -5348,6 +5826,7 @@ impl Sns for SnsClient {
         input: DeleteTopicInput,
     ) -> Result<(), RusotoError<DeleteTopicError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("DeleteTopic");
         let params = self.new_params("DeleteTopic");
         let mut params = params;
         DeleteTopicInputSerializer::serialize(&mut params, "", &input);
@@ -5368,6 +5847,7 @@ impl Sns for SnsClient {
         input: GetEndpointAttributesInput,
     ) -> Result<GetEndpointAttributesResponse, RusotoError<GetEndpointAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetEndpointAttributes");
         let params = self.new_params("GetEndpointAttributes");
         let mut params = params;
         GetEndpointAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5404,6 +5884,7 @@ impl Sns for SnsClient {
         RusotoError<GetPlatformApplicationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetPlatformApplicationAttributes");
         let params = self.new_params("GetPlatformApplicationAttributes");
         let mut params = params;
         GetPlatformApplicationAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5440,6 +5921,7 @@ impl Sns for SnsClient {
         input: GetSMSAttributesInput,
     ) -> Result<GetSMSAttributesResponse, RusotoError<GetSMSAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetSMSAttributes");
         let params = self.new_params("GetSMSAttributes");
         let mut params = params;
         GetSMSAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5472,6 +5954,7 @@ impl Sns for SnsClient {
     ) -> Result<GetSubscriptionAttributesResponse, RusotoError<GetSubscriptionAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetSubscriptionAttributes");
         let params = self.new_params("GetSubscriptionAttributes");
         let mut params = params;
         GetSubscriptionAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5505,6 +5988,7 @@ impl Sns for SnsClient {
         input: GetTopicAttributesInput,
     ) -> Result<GetTopicAttributesResponse, RusotoError<GetTopicAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetTopicAttributes");
         let params = self.new_params("GetTopicAttributes");
         let mut params = params;
         GetTopicAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5541,6 +6025,7 @@ impl Sns for SnsClient {
         RusotoError<ListEndpointsByPlatformApplicationError>,
     > {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListEndpointsByPlatformApplication");
         let params = self.new_params("ListEndpointsByPlatformApplication");
         let mut params = params;
         ListEndpointsByPlatformApplicationInputSerializer::serialize(&mut params, "", &input);
@@ -5577,6 +6062,7 @@ impl Sns for SnsClient {
         input: ListPhoneNumbersOptedOutInput,
     ) -> Result<ListPhoneNumbersOptedOutResponse, RusotoError<ListPhoneNumbersOptedOutError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListPhoneNumbersOptedOut");
         let params = self.new_params("ListPhoneNumbersOptedOut");
         let mut params = params;
         ListPhoneNumbersOptedOutInputSerializer::serialize(&mut params, "", &input);
@@ -5610,6 +6096,7 @@ impl Sns for SnsClient {
         input: ListPlatformApplicationsInput,
     ) -> Result<ListPlatformApplicationsResponse, RusotoError<ListPlatformApplicationsError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListPlatformApplications");
         let params = self.new_params("ListPlatformApplications");
         let mut params = params;
         ListPlatformApplicationsInputSerializer::serialize(&mut params, "", &input);
@@ -5643,6 +6130,7 @@ impl Sns for SnsClient {
         input: ListSubscriptionsInput,
     ) -> Result<ListSubscriptionsResponse, RusotoError<ListSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListSubscriptions");
         let params = self.new_params("ListSubscriptions");
         let mut params = params;
         ListSubscriptionsInputSerializer::serialize(&mut params, "", &input);
@@ -5676,6 +6164,7 @@ impl Sns for SnsClient {
         input: ListSubscriptionsByTopicInput,
     ) -> Result<ListSubscriptionsByTopicResponse, RusotoError<ListSubscriptionsByTopicError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListSubscriptionsByTopic");
         let params = self.new_params("ListSubscriptionsByTopic");
         let mut params = params;
         ListSubscriptionsByTopicInputSerializer::serialize(&mut params, "", &input);
@@ -5709,6 +6198,7 @@ impl Sns for SnsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceRequestSerializer::serialize(&mut params, "", &input);
@@ -5742,6 +6232,7 @@ impl Sns for SnsClient {
         input: ListTopicsInput,
     ) -> Result<ListTopicsResponse, RusotoError<ListTopicsError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListTopics");
         let params = self.new_params("ListTopics");
         let mut params = params;
         ListTopicsInputSerializer::serialize(&mut params, "", &input);
@@ -5772,6 +6263,7 @@ impl Sns for SnsClient {
         input: OptInPhoneNumberInput,
     ) -> Result<OptInPhoneNumberResponse, RusotoError<OptInPhoneNumberError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("OptInPhoneNumber");
         let params = self.new_params("OptInPhoneNumber");
         let mut params = params;
         OptInPhoneNumberInputSerializer::serialize(&mut params, "", &input);
@@ -5794,6 +6286,7 @@ impl Sns for SnsClient {
         input: PublishInput,
     ) -> Result<PublishResponse, RusotoError<PublishError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("Publish");
         let params = self.new_params("Publish");
         let mut params = params;
         PublishInputSerializer::serialize(&mut params, "", &input);
@@ -5824,6 +6317,7 @@ impl Sns for SnsClient {
         input: RemovePermissionInput,
     ) -> Result<(), RusotoError<RemovePermissionError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("RemovePermission");
         let params = self.new_params("RemovePermission");
         let mut params = params;
         RemovePermissionInputSerializer::serialize(&mut params, "", &input);
@@ -5844,6 +6338,7 @@ impl Sns for SnsClient {
         input: SetEndpointAttributesInput,
     ) -> Result<(), RusotoError<SetEndpointAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetEndpointAttributes");
         let params = self.new_params("SetEndpointAttributes");
         let mut params = params;
         SetEndpointAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5864,6 +6359,7 @@ impl Sns for SnsClient {
         input: SetPlatformApplicationAttributesInput,
     ) -> Result<(), RusotoError<SetPlatformApplicationAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetPlatformApplicationAttributes");
         let params = self.new_params("SetPlatformApplicationAttributes");
         let mut params = params;
         SetPlatformApplicationAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5887,6 +6383,7 @@ impl Sns for SnsClient {
         input: SetSMSAttributesInput,
     ) -> Result<SetSMSAttributesResponse, RusotoError<SetSMSAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetSMSAttributes");
         let params = self.new_params("SetSMSAttributes");
         let mut params = params;
         SetSMSAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5909,6 +6406,7 @@ impl Sns for SnsClient {
         input: SetSubscriptionAttributesInput,
     ) -> Result<(), RusotoError<SetSubscriptionAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetSubscriptionAttributes");
         let params = self.new_params("SetSubscriptionAttributes");
         let mut params = params;
         SetSubscriptionAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5929,6 +6427,7 @@ impl Sns for SnsClient {
         input: SetTopicAttributesInput,
     ) -> Result<(), RusotoError<SetTopicAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetTopicAttributes");
         let params = self.new_params("SetTopicAttributes");
         let mut params = params;
         SetTopicAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5949,6 +6448,7 @@ impl Sns for SnsClient {
         input: SubscribeInput,
     ) -> Result<SubscribeResponse, RusotoError<SubscribeError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("Subscribe");
         let params = self.new_params("Subscribe");
         let mut params = params;
         SubscribeInputSerializer::serialize(&mut params, "", &input);
@@ -5979,6 +6479,7 @@ impl Sns for SnsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("TagResource");
         let params = self.new_params("TagResource");
         let mut params = params;
         TagResourceRequestSerializer::serialize(&mut params, "", &input);
@@ -6001,6 +6502,7 @@ impl Sns for SnsClient {
         input: UnsubscribeInput,
     ) -> Result<(), RusotoError<UnsubscribeError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("Unsubscribe");
         let params = self.new_params("Unsubscribe");
         let mut params = params;
         UnsubscribeInputSerializer::serialize(&mut params, "", &input);
@@ -6021,6 +6523,7 @@ impl Sns for SnsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("UntagResource");
         let params = self.new_params("UntagResource");
         let mut params = params;
         UntagResourceRequestSerializer::serialize(&mut params, "", &input);
@@ -6038,6 +6541,373 @@ impl Sns for SnsClient {
     }
 }
 
+/// A blocking client for the Amazon SNS API, running the calls of a `SnsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SnsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SnsBlockingClient {
+    client: SnsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SnsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SnsBlockingClient {
+        SnsBlockingClient::from_client(SnsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SnsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SnsBlockingClient::from_client(SnsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> SnsBlockingClient {
+        SnsBlockingClient::from_client(SnsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SnsBlockingClient {
+        SnsBlockingClient::from_client(SnsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SnsClient) -> SnsBlockingClient {
+        SnsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> SnsBlockingClient {
+        SnsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SnsBlockingClient {
+        SnsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Sns::add_permission`].
+    pub fn add_permission(
+        &self,
+        input: AddPermissionInput,
+    ) -> Result<(), RusotoError<AddPermissionError>> {
+        self.runtime.block_on(self.client.add_permission(input))
+    }
+
+    /// Blocking version of [`Sns::check_if_phone_number_is_opted_out`].
+    pub fn check_if_phone_number_is_opted_out(
+        &self,
+        input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> Result<CheckIfPhoneNumberIsOptedOutResponse, RusotoError<CheckIfPhoneNumberIsOptedOutError>>
+    {
+        self.runtime
+            .block_on(self.client.check_if_phone_number_is_opted_out(input))
+    }
+
+    /// Blocking version of [`Sns::confirm_subscription`].
+    pub fn confirm_subscription(
+        &self,
+        input: ConfirmSubscriptionInput,
+    ) -> Result<ConfirmSubscriptionResponse, RusotoError<ConfirmSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.confirm_subscription(input))
+    }
+
+    /// Blocking version of [`Sns::create_platform_application`].
+    pub fn create_platform_application(
+        &self,
+        input: CreatePlatformApplicationInput,
+    ) -> Result<CreatePlatformApplicationResponse, RusotoError<CreatePlatformApplicationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_platform_application(input))
+    }
+
+    /// Blocking version of [`Sns::create_platform_endpoint`].
+    pub fn create_platform_endpoint(
+        &self,
+        input: CreatePlatformEndpointInput,
+    ) -> Result<CreateEndpointResponse, RusotoError<CreatePlatformEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_platform_endpoint(input))
+    }
+
+    /// Blocking version of [`Sns::create_topic`].
+    pub fn create_topic(
+        &self,
+        input: CreateTopicInput,
+    ) -> Result<CreateTopicResponse, RusotoError<CreateTopicError>> {
+        self.runtime.block_on(self.client.create_topic(input))
+    }
+
+    /// Blocking version of [`Sns::delete_endpoint`].
+    pub fn delete_endpoint(
+        &self,
+        input: DeleteEndpointInput,
+    ) -> Result<(), RusotoError<DeleteEndpointError>> {
+        self.runtime.block_on(self.client.delete_endpoint(input))
+    }
+
+    /// Blocking version of [`Sns::delete_platform_application`].
+    pub fn delete_platform_application(
+        &self,
+        input: DeletePlatformApplicationInput,
+    ) -> Result<(), RusotoError<DeletePlatformApplicationError>> {
+        self.runtime
+            .block_on(self.client.delete_platform_application(input))
+    }
+
+    /// Blocking version of [`Sns::delete_topic`].
+    pub fn delete_topic(
+        &self,
+        input: DeleteTopicInput,
+    ) -> Result<(), RusotoError<DeleteTopicError>> {
+        self.runtime.block_on(self.client.delete_topic(input))
+    }
+
+    /// Blocking version of [`Sns::get_endpoint_attributes`].
+    pub fn get_endpoint_attributes(
+        &self,
+        input: GetEndpointAttributesInput,
+    ) -> Result<GetEndpointAttributesResponse, RusotoError<GetEndpointAttributesError>> {
+        self.runtime
+            .block_on(self.client.get_endpoint_attributes(input))
+    }
+
+    /// Blocking version of [`Sns::get_platform_application_attributes`].
+    pub fn get_platform_application_attributes(
+        &self,
+        input: GetPlatformApplicationAttributesInput,
+    ) -> Result<
+        GetPlatformApplicationAttributesResponse,
+        RusotoError<GetPlatformApplicationAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_platform_application_attributes(input))
+    }
+
+    /// Blocking version of [`Sns::get_sms_attributes`].
+    pub fn get_sms_attributes(
+        &self,
+        input: GetSMSAttributesInput,
+    ) -> Result<GetSMSAttributesResponse, RusotoError<GetSMSAttributesError>> {
+        self.runtime.block_on(self.client.get_sms_attributes(input))
+    }
+
+    /// Blocking version of [`Sns::get_subscription_attributes`].
+    pub fn get_subscription_attributes(
+        &self,
+        input: GetSubscriptionAttributesInput,
+    ) -> Result<GetSubscriptionAttributesResponse, RusotoError<GetSubscriptionAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_subscription_attributes(input))
+    }
+
+    /// Blocking version of [`Sns::get_topic_attributes`].
+    pub fn get_topic_attributes(
+        &self,
+        input: GetTopicAttributesInput,
+    ) -> Result<GetTopicAttributesResponse, RusotoError<GetTopicAttributesError>> {
+        self.runtime
+            .block_on(self.client.get_topic_attributes(input))
+    }
+
+    /// Blocking version of [`Sns::list_endpoints_by_platform_application`].
+    pub fn list_endpoints_by_platform_application(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> Result<
+        ListEndpointsByPlatformApplicationResponse,
+        RusotoError<ListEndpointsByPlatformApplicationError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_endpoints_by_platform_application(input))
+    }
+
+    /// Blocking version of [`Sns::list_phone_numbers_opted_out`].
+    pub fn list_phone_numbers_opted_out(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> Result<ListPhoneNumbersOptedOutResponse, RusotoError<ListPhoneNumbersOptedOutError>> {
+        self.runtime
+            .block_on(self.client.list_phone_numbers_opted_out(input))
+    }
+
+    /// Blocking version of [`Sns::list_platform_applications`].
+    pub fn list_platform_applications(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> Result<ListPlatformApplicationsResponse, RusotoError<ListPlatformApplicationsError>> {
+        self.runtime
+            .block_on(self.client.list_platform_applications(input))
+    }
+
+    /// Blocking version of [`Sns::list_subscriptions`].
+    pub fn list_subscriptions(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> Result<ListSubscriptionsResponse, RusotoError<ListSubscriptionsError>> {
+        self.runtime.block_on(self.client.list_subscriptions(input))
+    }
+
+    /// Blocking version of [`Sns::list_subscriptions_by_topic`].
+    pub fn list_subscriptions_by_topic(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> Result<ListSubscriptionsByTopicResponse, RusotoError<ListSubscriptionsByTopicError>> {
+        self.runtime
+            .block_on(self.client.list_subscriptions_by_topic(input))
+    }
+
+    /// Blocking version of [`Sns::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Sns::list_topics`].
+    pub fn list_topics(
+        &self,
+        input: ListTopicsInput,
+    ) -> Result<ListTopicsResponse, RusotoError<ListTopicsError>> {
+        self.runtime.block_on(self.client.list_topics(input))
+    }
+
+    /// Blocking version of [`Sns::opt_in_phone_number`].
+    pub fn opt_in_phone_number(
+        &self,
+        input: OptInPhoneNumberInput,
+    ) -> Result<OptInPhoneNumberResponse, RusotoError<OptInPhoneNumberError>> {
+        self.runtime
+            .block_on(self.client.opt_in_phone_number(input))
+    }
+
+    /// Blocking version of [`Sns::publish`].
+    pub fn publish(
+        &self,
+        input: PublishInput,
+    ) -> Result<PublishResponse, RusotoError<PublishError>> {
+        self.runtime.block_on(self.client.publish(input))
+    }
+
+    /// Blocking version of [`Sns::remove_permission`].
+    pub fn remove_permission(
+        &self,
+        input: RemovePermissionInput,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        self.runtime.block_on(self.client.remove_permission(input))
+    }
+
+    /// Blocking version of [`Sns::set_endpoint_attributes`].
+    pub fn set_endpoint_attributes(
+        &self,
+        input: SetEndpointAttributesInput,
+    ) -> Result<(), RusotoError<SetEndpointAttributesError>> {
+        self.runtime
+            .block_on(self.client.set_endpoint_attributes(input))
+    }
+
+    /// Blocking version of [`Sns::set_platform_application_attributes`].
+    pub fn set_platform_application_attributes(
+        &self,
+        input: SetPlatformApplicationAttributesInput,
+    ) -> Result<(), RusotoError<SetPlatformApplicationAttributesError>> {
+        self.runtime
+            .block_on(self.client.set_platform_application_attributes(input))
+    }
+
+    /// Blocking version of [`Sns::set_sms_attributes`].
+    pub fn set_sms_attributes(
+        &self,
+        input: SetSMSAttributesInput,
+    ) -> Result<SetSMSAttributesResponse, RusotoError<SetSMSAttributesError>> {
+        self.runtime.block_on(self.client.set_sms_attributes(input))
+    }
+
+    /// Blocking version of [`Sns::set_subscription_attributes`].
+    pub fn set_subscription_attributes(
+        &self,
+        input: SetSubscriptionAttributesInput,
+    ) -> Result<(), RusotoError<SetSubscriptionAttributesError>> {
+        self.runtime
+            .block_on(self.client.set_subscription_attributes(input))
+    }
+
+    /// Blocking version of [`Sns::set_topic_attributes`].
+    pub fn set_topic_attributes(
+        &self,
+        input: SetTopicAttributesInput,
+    ) -> Result<(), RusotoError<SetTopicAttributesError>> {
+        self.runtime
+            .block_on(self.client.set_topic_attributes(input))
+    }
+
+    /// Blocking version of [`Sns::subscribe`].
+    pub fn subscribe(
+        &self,
+        input: SubscribeInput,
+    ) -> Result<SubscribeResponse, RusotoError<SubscribeError>> {
+        self.runtime.block_on(self.client.subscribe(input))
+    }
+
+    /// Blocking version of [`Sns::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Sns::unsubscribe`].
+    pub fn unsubscribe(
+        &self,
+        input: UnsubscribeInput,
+    ) -> Result<(), RusotoError<UnsubscribeError>> {
+        self.runtime.block_on(self.client.unsubscribe(input))
+    }
+
+    /// Blocking version of [`Sns::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
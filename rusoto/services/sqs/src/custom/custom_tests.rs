@@ -172,12 +172,25 @@ async fn test_parse_queue_does_not_exist_error() {
     let result = client.get_queue_url(request).await;
     assert!(result.is_err());
     let err = result.err().unwrap();
-    assert_eq!(
-        RusotoError::Service(GetQueueUrlError::QueueDoesNotExist(
-            "The specified queue does not exist for this wsdl version.".to_owned()
-        )),
-        err
-    );
+    match err {
+        RusotoError::Service(error, metadata) => {
+            assert_eq!(
+                GetQueueUrlError::QueueDoesNotExist(
+                    "The specified queue does not exist for this wsdl version.".to_owned()
+                ),
+                error
+            );
+            assert_eq!(
+                metadata.code.as_deref(),
+                Some("AWS.SimpleQueueService.NonExistentQueue")
+            );
+            assert_eq!(
+                metadata.request_id.as_deref(),
+                Some("8f8f9957-c0d9-536a-9ca6-ca7483be06ad")
+            );
+        }
+        _ => panic!("Expected a service error"),
+    }
 }
 
 #[tokio::test]
@@ -244,4 +257,49 @@ async fn should_deserialize_map_parameters_in_response_body() {
         message_attributes,
         message.message_attributes.unwrap(),
     );
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn should_call_the_blocking_client() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(
+            r#"<?xml version="1.0"?>
+        <GetQueueUrlResponse>
+            <GetQueueUrlResult>
+                <QueueUrl>https://queue.amazonaws.com/123456789012/test-queue</QueueUrl>
+            </GetQueueUrlResult>
+            <ResponseMetadata>
+                <RequestId>470a6f13-2ed9-4181-ad8a-2fdea142988e</RequestId>
+            </ResponseMetadata>
+        </GetQueueUrlResponse>"#,
+        )
+        .with_request_checker(|request: &SignedRequest| {
+            if let Some(SignedRequestPayload::Buffer(ref buffer)) = request.payload {
+                let params: Params = serde_urlencoded::from_bytes(buffer).unwrap();
+                assert_eq!(Some(&Some("GetQueueUrl".to_owned())), params.get("Action"));
+                assert_eq!(
+                    Some(&Some("test-queue".to_owned())),
+                    params.get("QueueName")
+                );
+            } else {
+                panic!("Unexpected request.payload: {:?}", request.payload);
+            }
+        });
+
+    let request = GetQueueUrlRequest {
+        queue_name: "test-queue".to_owned(),
+        ..Default::default()
+    };
+
+    let client = crate::generated::SqsBlockingClient::new_with(
+        mock,
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let result = client.get_queue_url(request).unwrap();
+    assert_eq!(
+        result.queue_url.as_deref(),
+        Some("https://queue.amazonaws.com/123456789012/test-queue")
+    );
+}
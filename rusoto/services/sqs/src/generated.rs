@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -2155,9 +2156,12 @@ impl AddPermissionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OverLimit" => {
-                        return RusotoError::Service(AddPermissionError::OverLimit(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddPermissionError::OverLimit,
+                        )
                     }
                     _ => {}
                 }
@@ -2201,15 +2205,19 @@ impl ChangeMessageVisibilityError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.MessageNotInflight" => {
-                        return RusotoError::Service(
-                            ChangeMessageVisibilityError::MessageNotInflight(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ChangeMessageVisibilityError::MessageNotInflight,
                         )
                     }
                     "ReceiptHandleIsInvalid" => {
-                        return RusotoError::Service(
-                            ChangeMessageVisibilityError::ReceiptHandleIsInvalid(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ChangeMessageVisibilityError::ReceiptHandleIsInvalid,
                         )
                     }
                     _ => {}
@@ -2263,31 +2271,35 @@ impl ChangeMessageVisibilityBatchError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.BatchEntryIdsNotDistinct" => {
-                        return RusotoError::Service(
-                            ChangeMessageVisibilityBatchError::BatchEntryIdsNotDistinct(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ChangeMessageVisibilityBatchError::BatchEntryIdsNotDistinct,
                         )
                     }
                     "AWS.SimpleQueueService.EmptyBatchRequest" => {
-                        return RusotoError::Service(
-                            ChangeMessageVisibilityBatchError::EmptyBatchRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ChangeMessageVisibilityBatchError::EmptyBatchRequest,
                         )
                     }
                     "AWS.SimpleQueueService.InvalidBatchEntryId" => {
-                        return RusotoError::Service(
-                            ChangeMessageVisibilityBatchError::InvalidBatchEntryId(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ChangeMessageVisibilityBatchError::InvalidBatchEntryId,
                         )
                     }
                     "AWS.SimpleQueueService.TooManyEntriesInBatchRequest" => {
-                        return RusotoError::Service(
-                            ChangeMessageVisibilityBatchError::TooManyEntriesInBatchRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ChangeMessageVisibilityBatchError::TooManyEntriesInBatchRequest,
                         )
                     }
                     _ => {}
@@ -2343,14 +2355,20 @@ impl CreateQueueError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.QueueDeletedRecently" => {
-                        return RusotoError::Service(CreateQueueError::QueueDeletedRecently(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateQueueError::QueueDeletedRecently,
+                        )
                     }
                     "QueueAlreadyExists" => {
-                        return RusotoError::Service(CreateQueueError::QueueNameExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateQueueError::QueueNameExists,
+                        )
                     }
                     _ => {}
                 }
@@ -2395,14 +2413,20 @@ impl DeleteMessageError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidIdFormat" => {
-                        return RusotoError::Service(DeleteMessageError::InvalidIdFormat(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteMessageError::InvalidIdFormat,
+                        )
                     }
                     "ReceiptHandleIsInvalid" => {
-                        return RusotoError::Service(DeleteMessageError::ReceiptHandleIsInvalid(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteMessageError::ReceiptHandleIsInvalid,
+                        )
                     }
                     _ => {}
                 }
@@ -2451,25 +2475,35 @@ impl DeleteMessageBatchError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.BatchEntryIdsNotDistinct" => {
-                        return RusotoError::Service(
-                            DeleteMessageBatchError::BatchEntryIdsNotDistinct(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteMessageBatchError::BatchEntryIdsNotDistinct,
                         )
                     }
                     "AWS.SimpleQueueService.EmptyBatchRequest" => {
-                        return RusotoError::Service(DeleteMessageBatchError::EmptyBatchRequest(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteMessageBatchError::EmptyBatchRequest,
+                        )
                     }
                     "AWS.SimpleQueueService.InvalidBatchEntryId" => {
-                        return RusotoError::Service(DeleteMessageBatchError::InvalidBatchEntryId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteMessageBatchError::InvalidBatchEntryId,
+                        )
                     }
                     "AWS.SimpleQueueService.TooManyEntriesInBatchRequest" => {
-                        return RusotoError::Service(
-                            DeleteMessageBatchError::TooManyEntriesInBatchRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteMessageBatchError::TooManyEntriesInBatchRequest,
                         )
                     }
                     _ => {}
@@ -2551,9 +2585,12 @@ impl GetQueueAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidAttributeName" => {
-                        return RusotoError::Service(GetQueueAttributesError::InvalidAttributeName(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetQueueAttributesError::InvalidAttributeName,
+                        )
                     }
                     _ => {}
                 }
@@ -2595,9 +2632,12 @@ impl GetQueueUrlError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.NonExistentQueue" => {
-                        return RusotoError::Service(GetQueueUrlError::QueueDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetQueueUrlError::QueueDoesNotExist,
+                        )
                     }
                     _ => {}
                 }
@@ -2641,10 +2681,11 @@ impl ListDeadLetterSourceQueuesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.NonExistentQueue" => {
-                        return RusotoError::Service(
-                            ListDeadLetterSourceQueuesError::QueueDoesNotExist(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListDeadLetterSourceQueuesError::QueueDoesNotExist,
                         )
                     }
                     _ => {}
@@ -2757,14 +2798,20 @@ impl PurgeQueueError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.PurgeQueueInProgress" => {
-                        return RusotoError::Service(PurgeQueueError::PurgeQueueInProgress(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PurgeQueueError::PurgeQueueInProgress,
+                        )
                     }
                     "AWS.SimpleQueueService.NonExistentQueue" => {
-                        return RusotoError::Service(PurgeQueueError::QueueDoesNotExist(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PurgeQueueError::QueueDoesNotExist,
+                        )
                     }
                     _ => {}
                 }
@@ -2807,9 +2854,12 @@ impl ReceiveMessageError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OverLimit" => {
-                        return RusotoError::Service(ReceiveMessageError::OverLimit(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ReceiveMessageError::OverLimit,
+                        )
                     }
                     _ => {}
                 }
@@ -2887,14 +2937,20 @@ impl SendMessageError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidMessageContents" => {
-                        return RusotoError::Service(SendMessageError::InvalidMessageContents(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendMessageError::InvalidMessageContents,
+                        )
                     }
                     "AWS.SimpleQueueService.UnsupportedOperation" => {
-                        return RusotoError::Service(SendMessageError::UnsupportedOperation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendMessageError::UnsupportedOperation,
+                        )
                     }
                     _ => {}
                 }
@@ -2947,36 +3003,52 @@ impl SendMessageBatchError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.BatchEntryIdsNotDistinct" => {
-                        return RusotoError::Service(
-                            SendMessageBatchError::BatchEntryIdsNotDistinct(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SendMessageBatchError::BatchEntryIdsNotDistinct,
                         )
                     }
                     "AWS.SimpleQueueService.BatchRequestTooLong" => {
-                        return RusotoError::Service(SendMessageBatchError::BatchRequestTooLong(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendMessageBatchError::BatchRequestTooLong,
+                        )
                     }
                     "AWS.SimpleQueueService.EmptyBatchRequest" => {
-                        return RusotoError::Service(SendMessageBatchError::EmptyBatchRequest(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendMessageBatchError::EmptyBatchRequest,
+                        )
                     }
                     "AWS.SimpleQueueService.InvalidBatchEntryId" => {
-                        return RusotoError::Service(SendMessageBatchError::InvalidBatchEntryId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendMessageBatchError::InvalidBatchEntryId,
+                        )
                     }
                     "AWS.SimpleQueueService.TooManyEntriesInBatchRequest" => {
-                        return RusotoError::Service(
-                            SendMessageBatchError::TooManyEntriesInBatchRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SendMessageBatchError::TooManyEntriesInBatchRequest,
                         )
                     }
                     "AWS.SimpleQueueService.UnsupportedOperation" => {
-                        return RusotoError::Service(SendMessageBatchError::UnsupportedOperation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SendMessageBatchError::UnsupportedOperation,
+                        )
                     }
                     _ => {}
                 }
@@ -3025,9 +3097,12 @@ impl SetQueueAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidAttributeName" => {
-                        return RusotoError::Service(SetQueueAttributesError::InvalidAttributeName(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetQueueAttributesError::InvalidAttributeName,
+                        )
                     }
                     _ => {}
                 }
@@ -3251,11 +3326,12 @@ pub struct SqsClient {
 impl SqsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SqsClient {
         SqsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SQS"),
         }
     }
 
@@ -3270,12 +3346,32 @@ impl SqsClient {
     {
         SqsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SQS"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SqsClient {
-        SqsClient { client, region }
+        SqsClient {
+            client,
+            region: region_for_service(region, "SQS"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SqsClient {
+        SqsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SQS"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SqsClient {
+        SqsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3287,6 +3383,7 @@ impl Sqs for SqsClient {
         input: AddPermissionRequest,
     ) -> Result<(), RusotoError<AddPermissionError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("AddPermission");
         let params = self.new_params("AddPermission");
         let mut params = params;
         AddPermissionRequestSerializer::serialize(&mut params, "", &input);
@@ -3307,6 +3404,7 @@ impl Sqs for SqsClient {
         input: ChangeMessageVisibilityRequest,
     ) -> Result<(), RusotoError<ChangeMessageVisibilityError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ChangeMessageVisibility");
         let params = self.new_params("ChangeMessageVisibility");
         let mut params = params;
         ChangeMessageVisibilityRequestSerializer::serialize(&mut params, "", &input);
@@ -3328,6 +3426,7 @@ impl Sqs for SqsClient {
     ) -> Result<ChangeMessageVisibilityBatchResult, RusotoError<ChangeMessageVisibilityBatchError>>
     {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ChangeMessageVisibilityBatch");
         let params = self.new_params("ChangeMessageVisibilityBatch");
         let mut params = params;
         ChangeMessageVisibilityBatchRequestSerializer::serialize(&mut params, "", &input);
@@ -3361,6 +3460,7 @@ impl Sqs for SqsClient {
         input: CreateQueueRequest,
     ) -> Result<CreateQueueResult, RusotoError<CreateQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("CreateQueue");
         let params = self.new_params("CreateQueue");
         let mut params = params;
         CreateQueueRequestSerializer::serialize(&mut params, "", &input);
@@ -3391,6 +3491,7 @@ impl Sqs for SqsClient {
         input: DeleteMessageRequest,
     ) -> Result<(), RusotoError<DeleteMessageError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("DeleteMessage");
         let params = self.new_params("DeleteMessage");
         let mut params = params;
         DeleteMessageRequestSerializer::serialize(&mut params, "", &input);
@@ -3411,6 +3512,7 @@ impl Sqs for SqsClient {
         input: DeleteMessageBatchRequest,
     ) -> Result<DeleteMessageBatchResult, RusotoError<DeleteMessageBatchError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("DeleteMessageBatch");
         let params = self.new_params("DeleteMessageBatch");
         let mut params = params;
         DeleteMessageBatchRequestSerializer::serialize(&mut params, "", &input);
@@ -3444,6 +3546,7 @@ impl Sqs for SqsClient {
         input: DeleteQueueRequest,
     ) -> Result<(), RusotoError<DeleteQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("DeleteQueue");
         let params = self.new_params("DeleteQueue");
         let mut params = params;
         DeleteQueueRequestSerializer::serialize(&mut params, "", &input);
@@ -3464,6 +3567,7 @@ impl Sqs for SqsClient {
         input: GetQueueAttributesRequest,
     ) -> Result<GetQueueAttributesResult, RusotoError<GetQueueAttributesError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("GetQueueAttributes");
         let params = self.new_params("GetQueueAttributes");
         let mut params = params;
         GetQueueAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -3497,6 +3601,7 @@ impl Sqs for SqsClient {
         input: GetQueueUrlRequest,
     ) -> Result<GetQueueUrlResult, RusotoError<GetQueueUrlError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("GetQueueUrl");
         let params = self.new_params("GetQueueUrl");
         let mut params = params;
         GetQueueUrlRequestSerializer::serialize(&mut params, "", &input);
@@ -3528,6 +3633,7 @@ impl Sqs for SqsClient {
     ) -> Result<ListDeadLetterSourceQueuesResult, RusotoError<ListDeadLetterSourceQueuesError>>
     {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ListDeadLetterSourceQueues");
         let params = self.new_params("ListDeadLetterSourceQueues");
         let mut params = params;
         ListDeadLetterSourceQueuesRequestSerializer::serialize(&mut params, "", &input);
@@ -3561,6 +3667,7 @@ impl Sqs for SqsClient {
         input: ListQueueTagsRequest,
     ) -> Result<ListQueueTagsResult, RusotoError<ListQueueTagsError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ListQueueTags");
         let params = self.new_params("ListQueueTags");
         let mut params = params;
         ListQueueTagsRequestSerializer::serialize(&mut params, "", &input);
@@ -3592,6 +3699,7 @@ impl Sqs for SqsClient {
         input: ListQueuesRequest,
     ) -> Result<ListQueuesResult, RusotoError<ListQueuesError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ListQueues");
         let params = self.new_params("ListQueues");
         let mut params = params;
         ListQueuesRequestSerializer::serialize(&mut params, "", &input);
@@ -3622,6 +3730,7 @@ impl Sqs for SqsClient {
         input: PurgeQueueRequest,
     ) -> Result<(), RusotoError<PurgeQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("PurgeQueue");
         let params = self.new_params("PurgeQueue");
         let mut params = params;
         PurgeQueueRequestSerializer::serialize(&mut params, "", &input);
@@ -3642,6 +3751,7 @@ impl Sqs for SqsClient {
         input: ReceiveMessageRequest,
     ) -> Result<ReceiveMessageResult, RusotoError<ReceiveMessageError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ReceiveMessage");
         let params = self.new_params("ReceiveMessage");
         let mut params = params;
         ReceiveMessageRequestSerializer::serialize(&mut params, "", &input);
@@ -3673,6 +3783,7 @@ impl Sqs for SqsClient {
         input: RemovePermissionRequest,
     ) -> Result<(), RusotoError<RemovePermissionError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("RemovePermission");
         let params = self.new_params("RemovePermission");
         let mut params = params;
         RemovePermissionRequestSerializer::serialize(&mut params, "", &input);
@@ -3693,6 +3804,7 @@ impl Sqs for SqsClient {
         input: SendMessageRequest,
     ) -> Result<SendMessageResult, RusotoError<SendMessageError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("SendMessage");
         let params = self.new_params("SendMessage");
         let mut params = params;
         SendMessageRequestSerializer::serialize(&mut params, "", &input);
@@ -3723,6 +3835,7 @@ impl Sqs for SqsClient {
         input: SendMessageBatchRequest,
     ) -> Result<SendMessageBatchResult, RusotoError<SendMessageBatchError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("SendMessageBatch");
         let params = self.new_params("SendMessageBatch");
         let mut params = params;
         SendMessageBatchRequestSerializer::serialize(&mut params, "", &input);
@@ -3754,6 +3867,7 @@ impl Sqs for SqsClient {
         input: SetQueueAttributesRequest,
     ) -> Result<(), RusotoError<SetQueueAttributesError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("SetQueueAttributes");
         let params = self.new_params("SetQueueAttributes");
         let mut params = params;
         SetQueueAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -3771,6 +3885,7 @@ impl Sqs for SqsClient {
     /// <p><p>Add cost allocation tags to the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>When you use queue tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a queue isn&#39;t recommended.</p> </li> <li> <p>Tags don&#39;t have any semantic meaning. Amazon SQS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> </ul> <p>For a full list of tag restrictions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-limits.html#limits-queues">Limits Related to Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant cross-account permissions to a role and a user name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
     async fn tag_queue(&self, input: TagQueueRequest) -> Result<(), RusotoError<TagQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("TagQueue");
         let params = self.new_params("TagQueue");
         let mut params = params;
         TagQueueRequestSerializer::serialize(&mut params, "", &input);
@@ -3791,6 +3906,7 @@ impl Sqs for SqsClient {
         input: UntagQueueRequest,
     ) -> Result<(), RusotoError<UntagQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("UntagQueue");
         let params = self.new_params("UntagQueue");
         let mut params = params;
         UntagQueueRequestSerializer::serialize(&mut params, "", &input);
@@ -3806,6 +3922,246 @@ impl Sqs for SqsClient {
     }
 }
 
+/// A blocking client for the Amazon SQS API, running the calls of a `SqsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SqsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SqsBlockingClient {
+    client: SqsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SqsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SqsBlockingClient {
+        SqsBlockingClient::from_client(SqsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SqsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SqsBlockingClient::from_client(SqsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> SqsBlockingClient {
+        SqsBlockingClient::from_client(SqsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SqsBlockingClient {
+        SqsBlockingClient::from_client(SqsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SqsClient) -> SqsBlockingClient {
+        SqsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> SqsBlockingClient {
+        SqsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SqsBlockingClient {
+        SqsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Sqs::add_permission`].
+    pub fn add_permission(
+        &self,
+        input: AddPermissionRequest,
+    ) -> Result<(), RusotoError<AddPermissionError>> {
+        self.runtime.block_on(self.client.add_permission(input))
+    }
+
+    /// Blocking version of [`Sqs::change_message_visibility`].
+    pub fn change_message_visibility(
+        &self,
+        input: ChangeMessageVisibilityRequest,
+    ) -> Result<(), RusotoError<ChangeMessageVisibilityError>> {
+        self.runtime
+            .block_on(self.client.change_message_visibility(input))
+    }
+
+    /// Blocking version of [`Sqs::change_message_visibility_batch`].
+    pub fn change_message_visibility_batch(
+        &self,
+        input: ChangeMessageVisibilityBatchRequest,
+    ) -> Result<ChangeMessageVisibilityBatchResult, RusotoError<ChangeMessageVisibilityBatchError>>
+    {
+        self.runtime
+            .block_on(self.client.change_message_visibility_batch(input))
+    }
+
+    /// Blocking version of [`Sqs::create_queue`].
+    pub fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> Result<CreateQueueResult, RusotoError<CreateQueueError>> {
+        self.runtime.block_on(self.client.create_queue(input))
+    }
+
+    /// Blocking version of [`Sqs::delete_message`].
+    pub fn delete_message(
+        &self,
+        input: DeleteMessageRequest,
+    ) -> Result<(), RusotoError<DeleteMessageError>> {
+        self.runtime.block_on(self.client.delete_message(input))
+    }
+
+    /// Blocking version of [`Sqs::delete_message_batch`].
+    pub fn delete_message_batch(
+        &self,
+        input: DeleteMessageBatchRequest,
+    ) -> Result<DeleteMessageBatchResult, RusotoError<DeleteMessageBatchError>> {
+        self.runtime
+            .block_on(self.client.delete_message_batch(input))
+    }
+
+    /// Blocking version of [`Sqs::delete_queue`].
+    pub fn delete_queue(
+        &self,
+        input: DeleteQueueRequest,
+    ) -> Result<(), RusotoError<DeleteQueueError>> {
+        self.runtime.block_on(self.client.delete_queue(input))
+    }
+
+    /// Blocking version of [`Sqs::get_queue_attributes`].
+    pub fn get_queue_attributes(
+        &self,
+        input: GetQueueAttributesRequest,
+    ) -> Result<GetQueueAttributesResult, RusotoError<GetQueueAttributesError>> {
+        self.runtime
+            .block_on(self.client.get_queue_attributes(input))
+    }
+
+    /// Blocking version of [`Sqs::get_queue_url`].
+    pub fn get_queue_url(
+        &self,
+        input: GetQueueUrlRequest,
+    ) -> Result<GetQueueUrlResult, RusotoError<GetQueueUrlError>> {
+        self.runtime.block_on(self.client.get_queue_url(input))
+    }
+
+    /// Blocking version of [`Sqs::list_dead_letter_source_queues`].
+    pub fn list_dead_letter_source_queues(
+        &self,
+        input: ListDeadLetterSourceQueuesRequest,
+    ) -> Result<ListDeadLetterSourceQueuesResult, RusotoError<ListDeadLetterSourceQueuesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_dead_letter_source_queues(input))
+    }
+
+    /// Blocking version of [`Sqs::list_queue_tags`].
+    pub fn list_queue_tags(
+        &self,
+        input: ListQueueTagsRequest,
+    ) -> Result<ListQueueTagsResult, RusotoError<ListQueueTagsError>> {
+        self.runtime.block_on(self.client.list_queue_tags(input))
+    }
+
+    /// Blocking version of [`Sqs::list_queues`].
+    pub fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> Result<ListQueuesResult, RusotoError<ListQueuesError>> {
+        self.runtime.block_on(self.client.list_queues(input))
+    }
+
+    /// Blocking version of [`Sqs::purge_queue`].
+    pub fn purge_queue(
+        &self,
+        input: PurgeQueueRequest,
+    ) -> Result<(), RusotoError<PurgeQueueError>> {
+        self.runtime.block_on(self.client.purge_queue(input))
+    }
+
+    /// Blocking version of [`Sqs::receive_message`].
+    pub fn receive_message(
+        &self,
+        input: ReceiveMessageRequest,
+    ) -> Result<ReceiveMessageResult, RusotoError<ReceiveMessageError>> {
+        self.runtime.block_on(self.client.receive_message(input))
+    }
+
+    /// Blocking version of [`Sqs::remove_permission`].
+    pub fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        self.runtime.block_on(self.client.remove_permission(input))
+    }
+
+    /// Blocking version of [`Sqs::send_message`].
+    pub fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> Result<SendMessageResult, RusotoError<SendMessageError>> {
+        self.runtime.block_on(self.client.send_message(input))
+    }
+
+    /// Blocking version of [`Sqs::send_message_batch`].
+    pub fn send_message_batch(
+        &self,
+        input: SendMessageBatchRequest,
+    ) -> Result<SendMessageBatchResult, RusotoError<SendMessageBatchError>> {
+        self.runtime.block_on(self.client.send_message_batch(input))
+    }
+
+    /// Blocking version of [`Sqs::set_queue_attributes`].
+    pub fn set_queue_attributes(
+        &self,
+        input: SetQueueAttributesRequest,
+    ) -> Result<(), RusotoError<SetQueueAttributesError>> {
+        self.runtime
+            .block_on(self.client.set_queue_attributes(input))
+    }
+
+    /// Blocking version of [`Sqs::tag_queue`].
+    pub fn tag_queue(&self, input: TagQueueRequest) -> Result<(), RusotoError<TagQueueError>> {
+        self.runtime.block_on(self.client.tag_queue(input))
+    }
+
+    /// Blocking version of [`Sqs::untag_queue`].
+    pub fn untag_queue(
+        &self,
+        input: UntagQueueRequest,
+    ) -> Result<(), RusotoError<UntagQueueError>> {
+        self.runtime.block_on(self.client.untag_queue(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
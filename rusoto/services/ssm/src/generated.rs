@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -8840,25 +8841,46 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddTagsToResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsToResourceError::InternalServerError,
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidResourceId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToResourceError::InvalidResourceId,
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidResourceType(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsToResourceError::InvalidResourceType,
+                    )
                 }
                 "TooManyTagsError" => {
-                    return RusotoError::Service(AddTagsToResourceError::TooManyTagsError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToResourceError::TooManyTagsError,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(AddTagsToResourceError::TooManyUpdates(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToResourceError::TooManyUpdates,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8896,18 +8918,38 @@ impl CancelCommandError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateInstanceId" => {
-                    return RusotoError::Service(CancelCommandError::DuplicateInstanceId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelCommandError::DuplicateInstanceId,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CancelCommandError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelCommandError::InternalServerError,
+                    )
                 }
                 "InvalidCommandId" => {
-                    return RusotoError::Service(CancelCommandError::InvalidCommandId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelCommandError::InvalidCommandId,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(CancelCommandError::InvalidInstanceId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelCommandError::InvalidInstanceId,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8942,16 +8984,22 @@ impl CancelMaintenanceWindowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        CancelMaintenanceWindowExecutionError::DoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelMaintenanceWindowExecutionError::DoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        CancelMaintenanceWindowExecutionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelMaintenanceWindowExecutionError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8984,11 +9032,14 @@ impl CreateActivationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateActivationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateActivationError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9036,51 +9087,94 @@ impl CreateAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationAlreadyExists" => {
-                    return RusotoError::Service(CreateAssociationError::AssociationAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationError::AssociationAlreadyExists,
+                    )
                 }
                 "AssociationLimitExceeded" => {
-                    return RusotoError::Service(CreateAssociationError::AssociationLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationError::AssociationLimitExceeded,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateAssociationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssociationError::InvalidDocument,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidDocumentVersion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationError::InvalidDocumentVersion,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidInstanceId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssociationError::InvalidInstanceId,
+                    )
                 }
                 "InvalidOutputLocation" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidOutputLocation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationError::InvalidOutputLocation,
+                    )
                 }
                 "InvalidParameters" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssociationError::InvalidParameters,
+                    )
                 }
                 "InvalidSchedule" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidSchedule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssociationError::InvalidSchedule,
+                    )
                 }
                 "InvalidTarget" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidTarget(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssociationError::InvalidTarget,
+                    )
                 }
                 "UnsupportedPlatformType" => {
-                    return RusotoError::Service(CreateAssociationError::UnsupportedPlatformType(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationError::UnsupportedPlatformType,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9138,61 +9232,94 @@ impl CreateAssociationBatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateAssociationBatchError::AssociationLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssociationBatchError::AssociationLimitExceeded,
                     )
                 }
                 "DuplicateInstanceId" => {
-                    return RusotoError::Service(CreateAssociationBatchError::DuplicateInstanceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationBatchError::DuplicateInstanceId,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationBatchError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationBatchError::InvalidDocument,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(
-                        CreateAssociationBatchError::InvalidDocumentVersion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssociationBatchError::InvalidDocumentVersion,
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidInstanceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationBatchError::InvalidInstanceId,
+                    )
                 }
                 "InvalidOutputLocation" => {
-                    return RusotoError::Service(
-                        CreateAssociationBatchError::InvalidOutputLocation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssociationBatchError::InvalidOutputLocation,
                     )
                 }
                 "InvalidParameters" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidParameters(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationBatchError::InvalidParameters,
+                    )
                 }
                 "InvalidSchedule" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidSchedule(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationBatchError::InvalidSchedule,
+                    )
                 }
                 "InvalidTarget" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidTarget(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssociationBatchError::InvalidTarget,
+                    )
                 }
                 "UnsupportedPlatformType" => {
-                    return RusotoError::Service(
-                        CreateAssociationBatchError::UnsupportedPlatformType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssociationBatchError::UnsupportedPlatformType,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9246,34 +9373,54 @@ impl CreateDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DocumentAlreadyExists" => {
-                    return RusotoError::Service(CreateDocumentError::DocumentAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentError::DocumentAlreadyExists,
+                    )
                 }
                 "DocumentLimitExceeded" => {
-                    return RusotoError::Service(CreateDocumentError::DocumentLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentError::DocumentLimitExceeded,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDocumentError::InternalServerError,
+                    )
                 }
                 "InvalidDocumentContent" => {
-                    return RusotoError::Service(CreateDocumentError::InvalidDocumentContent(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentError::InvalidDocumentContent,
+                    )
                 }
                 "InvalidDocumentSchemaVersion" => {
-                    return RusotoError::Service(CreateDocumentError::InvalidDocumentSchemaVersion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentError::InvalidDocumentSchemaVersion,
+                    )
                 }
                 "MaxDocumentSizeExceeded" => {
-                    return RusotoError::Service(CreateDocumentError::MaxDocumentSizeExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentError::MaxDocumentSizeExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9310,21 +9457,30 @@ impl CreateMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
-                        CreateMaintenanceWindowError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMaintenanceWindowError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateMaintenanceWindowError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMaintenanceWindowError::InternalServerError,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateMaintenanceWindowError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMaintenanceWindowError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9364,20 +9520,38 @@ impl CreateOpsItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateOpsItemError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOpsItemError::InternalServerError,
+                    )
                 }
                 "OpsItemAlreadyExistsException" => {
-                    return RusotoError::Service(CreateOpsItemError::OpsItemAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOpsItemError::OpsItemAlreadyExists,
+                    )
                 }
                 "OpsItemInvalidParameterException" => {
-                    return RusotoError::Service(CreateOpsItemError::OpsItemInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOpsItemError::OpsItemInvalidParameter,
+                    )
                 }
                 "OpsItemLimitExceededException" => {
-                    return RusotoError::Service(CreateOpsItemError::OpsItemLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOpsItemError::OpsItemLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9416,31 +9590,46 @@ impl CreateOpsMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateOpsMetadataError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOpsMetadataError::InternalServerError,
+                    )
                 }
                 "OpsMetadataAlreadyExistsException" => {
-                    return RusotoError::Service(CreateOpsMetadataError::OpsMetadataAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOpsMetadataError::OpsMetadataAlreadyExists,
+                    )
                 }
                 "OpsMetadataInvalidArgumentException" => {
-                    return RusotoError::Service(
-                        CreateOpsMetadataError::OpsMetadataInvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOpsMetadataError::OpsMetadataInvalidArgument,
                     )
                 }
                 "OpsMetadataLimitExceededException" => {
-                    return RusotoError::Service(CreateOpsMetadataError::OpsMetadataLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOpsMetadataError::OpsMetadataLimitExceeded,
+                    )
                 }
                 "OpsMetadataTooManyUpdatesException" => {
-                    return RusotoError::Service(CreateOpsMetadataError::OpsMetadataTooManyUpdates(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOpsMetadataError::OpsMetadataTooManyUpdates,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9476,21 +9665,30 @@ impl CreatePatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
-                        CreatePatchBaselineError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePatchBaselineError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreatePatchBaselineError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePatchBaselineError::InternalServerError,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreatePatchBaselineError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePatchBaselineError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9528,26 +9726,38 @@ impl CreateResourceDataSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateResourceDataSyncError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResourceDataSyncError::InternalServerError,
+                    )
                 }
                 "ResourceDataSyncAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateResourceDataSyncError::ResourceDataSyncAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceDataSyncError::ResourceDataSyncAlreadyExists,
                     )
                 }
                 "ResourceDataSyncCountExceededException" => {
-                    return RusotoError::Service(
-                        CreateResourceDataSyncError::ResourceDataSyncCountExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceDataSyncError::ResourceDataSyncCountExceeded,
                     )
                 }
                 "ResourceDataSyncInvalidConfigurationException" => {
-                    return RusotoError::Service(
-                        CreateResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceDataSyncError::ResourceDataSyncInvalidConfiguration,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9590,22 +9800,38 @@ impl DeleteActivationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteActivationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteActivationError::InternalServerError,
+                    )
                 }
                 "InvalidActivation" => {
-                    return RusotoError::Service(DeleteActivationError::InvalidActivation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteActivationError::InvalidActivation,
+                    )
                 }
                 "InvalidActivationId" => {
-                    return RusotoError::Service(DeleteActivationError::InvalidActivationId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteActivationError::InvalidActivationId,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(DeleteActivationError::TooManyUpdates(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteActivationError::TooManyUpdates,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9644,25 +9870,46 @@ impl DeleteAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(DeleteAssociationError::AssociationDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAssociationError::AssociationDoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteAssociationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAssociationError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DeleteAssociationError::InvalidDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssociationError::InvalidDocument,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(DeleteAssociationError::InvalidInstanceId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssociationError::InvalidInstanceId,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(DeleteAssociationError::TooManyUpdates(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssociationError::TooManyUpdates,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9700,20 +9947,38 @@ impl DeleteDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociatedInstances" => {
-                    return RusotoError::Service(DeleteDocumentError::AssociatedInstances(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentError::AssociatedInstances,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DeleteDocumentError::InvalidDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentError::InvalidDocument,
+                    )
                 }
                 "InvalidDocumentOperation" => {
-                    return RusotoError::Service(DeleteDocumentError::InvalidDocumentOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentError::InvalidDocumentOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9752,25 +10017,46 @@ impl DeleteInventoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteInventoryError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInventoryError::InternalServerError,
+                    )
                 }
                 "InvalidDeleteInventoryParametersException" => {
-                    return RusotoError::Service(
-                        DeleteInventoryError::InvalidDeleteInventoryParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInventoryError::InvalidDeleteInventoryParameters,
                     )
                 }
                 "InvalidInventoryRequestException" => {
-                    return RusotoError::Service(DeleteInventoryError::InvalidInventoryRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInventoryError::InvalidInventoryRequest,
+                    )
                 }
                 "InvalidOptionException" => {
-                    return RusotoError::Service(DeleteInventoryError::InvalidOption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInventoryError::InvalidOption,
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(DeleteInventoryError::InvalidTypeName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInventoryError::InvalidTypeName,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9804,11 +10090,14 @@ impl DeleteMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteMaintenanceWindowError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMaintenanceWindowError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9840,21 +10129,30 @@ impl DeleteOpsMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteOpsMetadataError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteOpsMetadataError::InternalServerError,
+                    )
                 }
                 "OpsMetadataInvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DeleteOpsMetadataError::OpsMetadataInvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOpsMetadataError::OpsMetadataInvalidArgument,
                     )
                 }
                 "OpsMetadataNotFoundException" => {
-                    return RusotoError::Service(DeleteOpsMetadataError::OpsMetadataNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteOpsMetadataError::OpsMetadataNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9886,12 +10184,22 @@ impl DeleteParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteParameterError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteParameterError::InternalServerError,
+                    )
                 }
                 "ParameterNotFound" => {
-                    return RusotoError::Service(DeleteParameterError::ParameterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteParameterError::ParameterNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9920,11 +10228,14 @@ impl DeleteParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteParametersError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteParametersError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9954,14 +10265,22 @@ impl DeletePatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeletePatchBaselineError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePatchBaselineError::InternalServerError,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeletePatchBaselineError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePatchBaselineError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9994,21 +10313,30 @@ impl DeleteResourceDataSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteResourceDataSyncError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourceDataSyncError::InternalServerError,
+                    )
                 }
                 "ResourceDataSyncInvalidConfigurationException" => {
-                    return RusotoError::Service(
-                        DeleteResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceDataSyncError::ResourceDataSyncInvalidConfiguration,
                     )
                 }
                 "ResourceDataSyncNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteResourceDataSyncError::ResourceDataSyncNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceDataSyncError::ResourceDataSyncNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10044,16 +10372,22 @@ impl DeregisterManagedInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DeregisterManagedInstanceError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterManagedInstanceError::InternalServerError,
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(DeregisterManagedInstanceError::InvalidInstanceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterManagedInstanceError::InvalidInstanceId,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10088,16 +10422,22 @@ impl DeregisterPatchBaselineForPatchGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DeregisterPatchBaselineForPatchGroupError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterPatchBaselineForPatchGroupError::InternalServerError,
                     )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(
-                        DeregisterPatchBaselineForPatchGroupError::InvalidResourceId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterPatchBaselineForPatchGroupError::InvalidResourceId,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10136,21 +10476,30 @@ impl DeregisterTargetFromMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        DeregisterTargetFromMaintenanceWindowError::DoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterTargetFromMaintenanceWindowError::DoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DeregisterTargetFromMaintenanceWindowError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterTargetFromMaintenanceWindowError::InternalServerError,
                     )
                 }
                 "TargetInUseException" => {
-                    return RusotoError::Service(
-                        DeregisterTargetFromMaintenanceWindowError::TargetInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterTargetFromMaintenanceWindowError::TargetInUse,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10190,16 +10539,22 @@ impl DeregisterTaskFromMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        DeregisterTaskFromMaintenanceWindowError::DoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterTaskFromMaintenanceWindowError::DoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DeregisterTaskFromMaintenanceWindowError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterTaskFromMaintenanceWindowError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10236,19 +10591,30 @@ impl DescribeActivationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeActivationsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeActivationsError::InternalServerError,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(DescribeActivationsError::InvalidFilter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeActivationsError::InvalidFilter,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeActivationsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeActivationsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10286,29 +10652,46 @@ impl DescribeAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(DescribeAssociationError::AssociationDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAssociationError::AssociationDoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeAssociationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAssociationError::InternalServerError,
+                    )
                 }
                 "InvalidAssociationVersion" => {
-                    return RusotoError::Service(
-                        DescribeAssociationError::InvalidAssociationVersion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssociationError::InvalidAssociationVersion,
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DescribeAssociationError::InvalidDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssociationError::InvalidDocument,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(DescribeAssociationError::InvalidInstanceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAssociationError::InvalidInstanceId,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10350,28 +10733,38 @@ impl DescribeAssociationExecutionTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
-                        DescribeAssociationExecutionTargetsError::AssociationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssociationExecutionTargetsError::AssociationDoesNotExist,
                     )
                 }
                 "AssociationExecutionDoesNotExist" => {
-                    return RusotoError::Service(
-                        DescribeAssociationExecutionTargetsError::AssociationExecutionDoesNotExist(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssociationExecutionTargetsError::AssociationExecutionDoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeAssociationExecutionTargetsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssociationExecutionTargetsError::InternalServerError,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
-                        DescribeAssociationExecutionTargetsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssociationExecutionTargetsError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10416,21 +10809,30 @@ impl DescribeAssociationExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
-                        DescribeAssociationExecutionsError::AssociationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssociationExecutionsError::AssociationDoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeAssociationExecutionsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssociationExecutionsError::InternalServerError,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
-                        DescribeAssociationExecutionsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssociationExecutionsError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10474,26 +10876,38 @@ impl DescribeAutomationExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeAutomationExecutionsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAutomationExecutionsError::InternalServerError,
                     )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(
-                        DescribeAutomationExecutionsError::InvalidFilterKey(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAutomationExecutionsError::InvalidFilterKey,
                     )
                 }
                 "InvalidFilterValue" => {
-                    return RusotoError::Service(
-                        DescribeAutomationExecutionsError::InvalidFilterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAutomationExecutionsError::InvalidFilterValue,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
-                        DescribeAutomationExecutionsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAutomationExecutionsError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10542,31 +10956,46 @@ impl DescribeAutomationStepExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationExecutionNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeAutomationStepExecutionsError::AutomationExecutionNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAutomationStepExecutionsError::AutomationExecutionNotFound,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeAutomationStepExecutionsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAutomationStepExecutionsError::InternalServerError,
                     )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(
-                        DescribeAutomationStepExecutionsError::InvalidFilterKey(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAutomationStepExecutionsError::InvalidFilterKey,
                     )
                 }
                 "InvalidFilterValue" => {
-                    return RusotoError::Service(
-                        DescribeAutomationStepExecutionsError::InvalidFilterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAutomationStepExecutionsError::InvalidFilterValue,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
-                        DescribeAutomationStepExecutionsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAutomationStepExecutionsError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10608,11 +11037,14 @@ impl DescribeAvailablePatchesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeAvailablePatchesError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAvailablePatchesError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10644,19 +11076,30 @@ impl DescribeDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeDocumentError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDocumentError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DescribeDocumentError::InvalidDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDocumentError::InvalidDocument,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(DescribeDocumentError::InvalidDocumentVersion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDocumentError::InvalidDocumentVersion,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10692,21 +11135,30 @@ impl DescribeDocumentPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeDocumentPermissionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDocumentPermissionError::InternalServerError,
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DescribeDocumentPermissionError::InvalidDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDocumentPermissionError::InvalidDocument,
+                    )
                 }
                 "InvalidPermissionType" => {
-                    return RusotoError::Service(
-                        DescribeDocumentPermissionError::InvalidPermissionType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDocumentPermissionError::InvalidPermissionType,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10746,21 +11198,30 @@ impl DescribeEffectiveInstanceAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeEffectiveInstanceAssociationsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEffectiveInstanceAssociationsError::InternalServerError,
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(
-                        DescribeEffectiveInstanceAssociationsError::InvalidInstanceId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEffectiveInstanceAssociationsError::InvalidInstanceId,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
-                        DescribeEffectiveInstanceAssociationsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEffectiveInstanceAssociationsError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10804,28 +11265,38 @@ impl DescribeEffectivePatchesForPatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        DescribeEffectivePatchesForPatchBaselineError::DoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEffectivePatchesForPatchBaselineError::DoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeEffectivePatchesForPatchBaselineError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEffectivePatchesForPatchBaselineError::InternalServerError,
                     )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(
-                        DescribeEffectivePatchesForPatchBaselineError::InvalidResourceId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEffectivePatchesForPatchBaselineError::InvalidResourceId,
                     )
                 }
                 "UnsupportedOperatingSystem" => {
-                    return RusotoError::Service(
-                        DescribeEffectivePatchesForPatchBaselineError::UnsupportedOperatingSystem(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEffectivePatchesForPatchBaselineError::UnsupportedOperatingSystem,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10870,21 +11341,30 @@ impl DescribeInstanceAssociationsStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeInstanceAssociationsStatusError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceAssociationsStatusError::InternalServerError,
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(
-                        DescribeInstanceAssociationsStatusError::InvalidInstanceId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceAssociationsStatusError::InvalidInstanceId,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
-                        DescribeInstanceAssociationsStatusError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceAssociationsStatusError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10930,33 +11410,46 @@ impl DescribeInstanceInformationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeInstanceInformationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceInformationError::InternalServerError,
                     )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(
-                        DescribeInstanceInformationError::InvalidFilterKey(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceInformationError::InvalidFilterKey,
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(
-                        DescribeInstanceInformationError::InvalidInstanceId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceInformationError::InvalidInstanceId,
                     )
                 }
                 "InvalidInstanceInformationFilterValue" => {
-                    return RusotoError::Service(
-                        DescribeInstanceInformationError::InvalidInstanceInformationFilterValue(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceInformationError::InvalidInstanceInformationFilterValue,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
-                        DescribeInstanceInformationError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceInformationError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10998,16 +11491,22 @@ impl DescribeInstancePatchStatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeInstancePatchStatesError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstancePatchStatesError::InternalServerError,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
-                        DescribeInstancePatchStatesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstancePatchStatesError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11044,21 +11543,30 @@ impl DescribeInstancePatchStatesForPatchGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeInstancePatchStatesForPatchGroupError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstancePatchStatesForPatchGroupError::InternalServerError,
                     )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(
-                        DescribeInstancePatchStatesForPatchGroupError::InvalidFilter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstancePatchStatesForPatchGroupError::InvalidFilter,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
-                        DescribeInstancePatchStatesForPatchGroupError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstancePatchStatesForPatchGroupError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11100,26 +11608,38 @@ impl DescribeInstancePatchesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeInstancePatchesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInstancePatchesError::InternalServerError,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(DescribeInstancePatchesError::InvalidFilter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInstancePatchesError::InvalidFilter,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(DescribeInstancePatchesError::InvalidInstanceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInstancePatchesError::InvalidInstanceId,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeInstancePatchesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInstancePatchesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11156,21 +11676,30 @@ impl DescribeInventoryDeletionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeInventoryDeletionsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInventoryDeletionsError::InternalServerError,
                     )
                 }
                 "InvalidDeletionIdException" => {
-                    return RusotoError::Service(
-                        DescribeInventoryDeletionsError::InvalidDeletionId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInventoryDeletionsError::InvalidDeletionId,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeInventoryDeletionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInventoryDeletionsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11206,20 +11735,22 @@ impl DescribeMaintenanceWindowExecutionTaskInvocationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceWindowExecutionTaskInvocationsError::DoesNotExist(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceWindowExecutionTaskInvocationsError::DoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceWindowExecutionTaskInvocationsError::InternalServerError(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceWindowExecutionTaskInvocationsError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11256,16 +11787,22 @@ impl DescribeMaintenanceWindowExecutionTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceWindowExecutionTasksError::DoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceWindowExecutionTasksError::DoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceWindowExecutionTasksError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceWindowExecutionTasksError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11300,11 +11837,14 @@ impl DescribeMaintenanceWindowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceWindowExecutionsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceWindowExecutionsError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11338,16 +11878,22 @@ impl DescribeMaintenanceWindowScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceWindowScheduleError::DoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceWindowScheduleError::DoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceWindowScheduleError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceWindowScheduleError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11384,16 +11930,22 @@ impl DescribeMaintenanceWindowTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceWindowTargetsError::DoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceWindowTargetsError::DoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceWindowTargetsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceWindowTargetsError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11430,16 +11982,22 @@ impl DescribeMaintenanceWindowTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(DescribeMaintenanceWindowTasksError::DoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMaintenanceWindowTasksError::DoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceWindowTasksError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceWindowTasksError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11472,11 +12030,14 @@ impl DescribeMaintenanceWindowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceWindowsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceWindowsError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11508,11 +12069,14 @@ impl DescribeMaintenanceWindowsForTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceWindowsForTargetError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceWindowsForTargetError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11542,11 +12106,14 @@ impl DescribeOpsItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeOpsItemsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeOpsItemsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11582,27 +12149,46 @@ impl DescribeParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeParametersError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeParametersError::InternalServerError,
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidFilterKey(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeParametersError::InvalidFilterKey,
+                    )
                 }
                 "InvalidFilterOption" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidFilterOption(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeParametersError::InvalidFilterOption,
+                    )
                 }
                 "InvalidFilterValue" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidFilterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeParametersError::InvalidFilterValue,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeParametersError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11634,11 +12220,14 @@ impl DescribePatchBaselinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribePatchBaselinesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePatchBaselinesError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11668,16 +12257,22 @@ impl DescribePatchGroupStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribePatchGroupStateError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePatchGroupStateError::InternalServerError,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribePatchGroupStateError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePatchGroupStateError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11706,11 +12301,14 @@ impl DescribePatchGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribePatchGroupsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePatchGroupsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11738,11 +12336,14 @@ impl DescribePatchPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribePatchPropertiesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePatchPropertiesError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11774,17 +12375,30 @@ impl DescribeSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeSessionsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSessionsError::InternalServerError,
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(DescribeSessionsError::InvalidFilterKey(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSessionsError::InvalidFilterKey,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeSessionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSessionsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11816,16 +12430,22 @@ impl GetAutomationExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationExecutionNotFoundException" => {
-                    return RusotoError::Service(
-                        GetAutomationExecutionError::AutomationExecutionNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutomationExecutionError::AutomationExecutionNotFound,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetAutomationExecutionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAutomationExecutionError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11862,24 +12482,38 @@ impl GetCalendarStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCalendarStateError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCalendarStateError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(GetCalendarStateError::InvalidDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCalendarStateError::InvalidDocument,
+                    )
                 }
                 "InvalidDocumentType" => {
-                    return RusotoError::Service(GetCalendarStateError::InvalidDocumentType(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCalendarStateError::InvalidDocumentType,
+                    )
                 }
                 "UnsupportedCalendarException" => {
-                    return RusotoError::Service(GetCalendarStateError::UnsupportedCalendar(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCalendarStateError::UnsupportedCalendar,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11918,31 +12552,46 @@ impl GetCommandInvocationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCommandInvocationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommandInvocationError::InternalServerError,
+                    )
                 }
                 "InvalidCommandId" => {
-                    return RusotoError::Service(GetCommandInvocationError::InvalidCommandId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommandInvocationError::InvalidCommandId,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(GetCommandInvocationError::InvalidInstanceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommandInvocationError::InvalidInstanceId,
+                    )
                 }
                 "InvalidPluginName" => {
-                    return RusotoError::Service(GetCommandInvocationError::InvalidPluginName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommandInvocationError::InvalidPluginName,
+                    )
                 }
                 "InvocationDoesNotExist" => {
-                    return RusotoError::Service(GetCommandInvocationError::InvocationDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommandInvocationError::InvocationDoesNotExist,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11974,11 +12623,14 @@ impl GetConnectionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetConnectionStatusError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetConnectionStatusError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12006,11 +12658,14 @@ impl GetDefaultPatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetDefaultPatchBaselineError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDefaultPatchBaselineError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12044,25 +12699,30 @@ impl GetDeployablePatchSnapshotForInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        GetDeployablePatchSnapshotForInstanceError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeployablePatchSnapshotForInstanceError::InternalServerError,
                     )
                 }
                 "UnsupportedFeatureRequiredException" => {
-                    return RusotoError::Service(
-                        GetDeployablePatchSnapshotForInstanceError::UnsupportedFeatureRequired(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeployablePatchSnapshotForInstanceError::UnsupportedFeatureRequired,
                     )
                 }
                 "UnsupportedOperatingSystem" => {
-                    return RusotoError::Service(
-                        GetDeployablePatchSnapshotForInstanceError::UnsupportedOperatingSystem(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeployablePatchSnapshotForInstanceError::UnsupportedOperatingSystem,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12102,15 +12762,30 @@ impl GetDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(GetDocumentError::InvalidDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentError::InvalidDocument,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(GetDocumentError::InvalidDocumentVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentError::InvalidDocumentVersion,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12152,27 +12827,62 @@ impl GetInventoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetInventoryError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInventoryError::InternalServerError,
+                    )
                 }
                 "InvalidAggregatorException" => {
-                    return RusotoError::Service(GetInventoryError::InvalidAggregator(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInventoryError::InvalidAggregator,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(GetInventoryError::InvalidFilter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInventoryError::InvalidFilter,
+                    )
                 }
                 "InvalidInventoryGroupException" => {
-                    return RusotoError::Service(GetInventoryError::InvalidInventoryGroup(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInventoryError::InvalidInventoryGroup,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetInventoryError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInventoryError::InvalidNextToken,
+                    )
                 }
                 "InvalidResultAttributeException" => {
-                    return RusotoError::Service(GetInventoryError::InvalidResultAttribute(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInventoryError::InvalidResultAttribute,
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(GetInventoryError::InvalidTypeName(err.msg))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInventoryError::InvalidTypeName,
+                    )
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12210,17 +12920,30 @@ impl GetInventorySchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetInventorySchemaError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInventorySchemaError::InternalServerError,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetInventorySchemaError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInventorySchemaError::InvalidNextToken,
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(GetInventorySchemaError::InvalidTypeName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInventorySchemaError::InvalidTypeName,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12252,14 +12975,22 @@ impl GetMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(GetMaintenanceWindowError::DoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMaintenanceWindowError::DoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetMaintenanceWindowError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMaintenanceWindowError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12292,16 +13023,22 @@ impl GetMaintenanceWindowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(GetMaintenanceWindowExecutionError::DoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMaintenanceWindowExecutionError::DoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        GetMaintenanceWindowExecutionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMaintenanceWindowExecutionError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12336,16 +13073,22 @@ impl GetMaintenanceWindowExecutionTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetMaintenanceWindowExecutionTaskError::DoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMaintenanceWindowExecutionTaskError::DoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        GetMaintenanceWindowExecutionTaskError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMaintenanceWindowExecutionTaskError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12382,18 +13125,22 @@ impl GetMaintenanceWindowExecutionTaskInvocationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetMaintenanceWindowExecutionTaskInvocationError::DoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMaintenanceWindowExecutionTaskInvocationError::DoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        GetMaintenanceWindowExecutionTaskInvocationError::InternalServerError(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMaintenanceWindowExecutionTaskInvocationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12428,16 +13175,22 @@ impl GetMaintenanceWindowTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(GetMaintenanceWindowTaskError::DoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMaintenanceWindowTaskError::DoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        GetMaintenanceWindowTaskError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMaintenanceWindowTaskError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12468,12 +13221,22 @@ impl GetOpsItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetOpsItemError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpsItemError::InternalServerError,
+                    )
                 }
                 "OpsItemNotFoundException" => {
-                    return RusotoError::Service(GetOpsItemError::OpsItemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpsItemError::OpsItemNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12506,17 +13269,30 @@ impl GetOpsMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetOpsMetadataError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpsMetadataError::InternalServerError,
+                    )
                 }
                 "OpsMetadataInvalidArgumentException" => {
-                    return RusotoError::Service(GetOpsMetadataError::OpsMetadataInvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetOpsMetadataError::OpsMetadataInvalidArgument,
+                    )
                 }
                 "OpsMetadataNotFoundException" => {
-                    return RusotoError::Service(GetOpsMetadataError::OpsMetadataNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpsMetadataError::OpsMetadataNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12556,26 +13332,54 @@ impl GetOpsSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetOpsSummaryError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpsSummaryError::InternalServerError,
+                    )
                 }
                 "InvalidAggregatorException" => {
-                    return RusotoError::Service(GetOpsSummaryError::InvalidAggregator(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpsSummaryError::InvalidAggregator,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(GetOpsSummaryError::InvalidFilter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpsSummaryError::InvalidFilter,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetOpsSummaryError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpsSummaryError::InvalidNextToken,
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(GetOpsSummaryError::InvalidTypeName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpsSummaryError::InvalidTypeName,
+                    )
                 }
                 "ResourceDataSyncNotFoundException" => {
-                    return RusotoError::Service(GetOpsSummaryError::ResourceDataSyncNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetOpsSummaryError::ResourceDataSyncNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12614,20 +13418,38 @@ impl GetParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetParameterError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParameterError::InternalServerError,
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(GetParameterError::InvalidKeyId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParameterError::InvalidKeyId,
+                    )
                 }
                 "ParameterNotFound" => {
-                    return RusotoError::Service(GetParameterError::ParameterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParameterError::ParameterNotFound,
+                    )
                 }
                 "ParameterVersionNotFound" => {
-                    return RusotoError::Service(GetParameterError::ParameterVersionNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParameterError::ParameterVersionNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12664,24 +13486,38 @@ impl GetParameterHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetParameterHistoryError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParameterHistoryError::InternalServerError,
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(GetParameterHistoryError::InvalidKeyId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParameterHistoryError::InvalidKeyId,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetParameterHistoryError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParameterHistoryError::InvalidNextToken,
+                    )
                 }
                 "ParameterNotFound" => {
-                    return RusotoError::Service(GetParameterHistoryError::ParameterNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParameterHistoryError::ParameterNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12714,12 +13550,22 @@ impl GetParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetParametersError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParametersError::InternalServerError,
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(GetParametersError::InvalidKeyId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParametersError::InvalidKeyId,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12758,34 +13604,54 @@ impl GetParametersByPathError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetParametersByPathError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParametersByPathError::InternalServerError,
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidFilterKey(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParametersByPathError::InvalidFilterKey,
+                    )
                 }
                 "InvalidFilterOption" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidFilterOption(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParametersByPathError::InvalidFilterOption,
+                    )
                 }
                 "InvalidFilterValue" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidFilterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParametersByPathError::InvalidFilterValue,
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidKeyId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParametersByPathError::InvalidKeyId,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParametersByPathError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12822,17 +13688,30 @@ impl GetPatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(GetPatchBaselineError::DoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPatchBaselineError::DoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetPatchBaselineError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPatchBaselineError::InternalServerError,
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(GetPatchBaselineError::InvalidResourceId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPatchBaselineError::InvalidResourceId,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12864,11 +13743,14 @@ impl GetPatchBaselineForPatchGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        GetPatchBaselineForPatchGroupError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPatchBaselineForPatchGroupError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12900,16 +13782,22 @@ impl GetServiceSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetServiceSettingError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetServiceSettingError::InternalServerError,
+                    )
                 }
                 "ServiceSettingNotFound" => {
-                    return RusotoError::Service(GetServiceSettingError::ServiceSettingNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetServiceSettingError::ServiceSettingNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12946,31 +13834,46 @@ impl LabelParameterVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(LabelParameterVersionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LabelParameterVersionError::InternalServerError,
+                    )
                 }
                 "ParameterNotFound" => {
-                    return RusotoError::Service(LabelParameterVersionError::ParameterNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LabelParameterVersionError::ParameterNotFound,
+                    )
                 }
                 "ParameterVersionLabelLimitExceeded" => {
-                    return RusotoError::Service(
-                        LabelParameterVersionError::ParameterVersionLabelLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LabelParameterVersionError::ParameterVersionLabelLimitExceeded,
                     )
                 }
                 "ParameterVersionNotFound" => {
-                    return RusotoError::Service(
-                        LabelParameterVersionError::ParameterVersionNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LabelParameterVersionError::ParameterVersionNotFound,
                     )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(LabelParameterVersionError::TooManyUpdates(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LabelParameterVersionError::TooManyUpdates,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13010,21 +13913,30 @@ impl ListAssociationVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
-                        ListAssociationVersionsError::AssociationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssociationVersionsError::AssociationDoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListAssociationVersionsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAssociationVersionsError::InternalServerError,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListAssociationVersionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAssociationVersionsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13058,14 +13970,22 @@ impl ListAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListAssociationsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAssociationsError::InternalServerError,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListAssociationsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssociationsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13102,31 +14022,46 @@ impl ListCommandInvocationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCommandInvocationsError::InternalServerError,
+                    )
                 }
                 "InvalidCommandId" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InvalidCommandId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCommandInvocationsError::InvalidCommandId,
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InvalidFilterKey(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCommandInvocationsError::InvalidFilterKey,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InvalidInstanceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCommandInvocationsError::InvalidInstanceId,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCommandInvocationsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13166,21 +14101,46 @@ impl ListCommandsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListCommandsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCommandsError::InternalServerError,
+                    )
                 }
                 "InvalidCommandId" => {
-                    return RusotoError::Service(ListCommandsError::InvalidCommandId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCommandsError::InvalidCommandId,
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(ListCommandsError::InvalidFilterKey(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCommandsError::InvalidFilterKey,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(ListCommandsError::InvalidInstanceId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCommandsError::InvalidInstanceId,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListCommandsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCommandsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13220,29 +14180,46 @@ impl ListComplianceItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListComplianceItemsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListComplianceItemsError::InternalServerError,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(ListComplianceItemsError::InvalidFilter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComplianceItemsError::InvalidFilter,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListComplianceItemsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListComplianceItemsError::InvalidNextToken,
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(ListComplianceItemsError::InvalidResourceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListComplianceItemsError::InvalidResourceId,
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(ListComplianceItemsError::InvalidResourceType(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListComplianceItemsError::InvalidResourceType,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13278,21 +14255,30 @@ impl ListComplianceSummariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListComplianceSummariesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListComplianceSummariesError::InternalServerError,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(ListComplianceSummariesError::InvalidFilter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListComplianceSummariesError::InvalidFilter,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListComplianceSummariesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListComplianceSummariesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13330,26 +14316,38 @@ impl ListDocumentMetadataHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        ListDocumentMetadataHistoryError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDocumentMetadataHistoryError::InternalServerError,
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(ListDocumentMetadataHistoryError::InvalidDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDocumentMetadataHistoryError::InvalidDocument,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(
-                        ListDocumentMetadataHistoryError::InvalidDocumentVersion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDocumentMetadataHistoryError::InvalidDocumentVersion,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
-                        ListDocumentMetadataHistoryError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDocumentMetadataHistoryError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13388,21 +14386,30 @@ impl ListDocumentVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListDocumentVersionsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDocumentVersionsError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(ListDocumentVersionsError::InvalidDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDocumentVersionsError::InvalidDocument,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListDocumentVersionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDocumentVersionsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13436,15 +14443,30 @@ impl ListDocumentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListDocumentsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDocumentsError::InternalServerError,
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(ListDocumentsError::InvalidFilterKey(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDocumentsError::InvalidFilterKey,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListDocumentsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDocumentsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13482,29 +14504,46 @@ impl ListInventoryEntriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInventoryEntriesError::InternalServerError,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InvalidFilter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInventoryEntriesError::InvalidFilter,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InvalidInstanceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInventoryEntriesError::InvalidInstanceId,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInventoryEntriesError::InvalidNextToken,
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InvalidTypeName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInventoryEntriesError::InvalidTypeName,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13542,24 +14581,38 @@ impl ListOpsItemEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListOpsItemEventsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOpsItemEventsError::InternalServerError,
+                    )
                 }
                 "OpsItemInvalidParameterException" => {
-                    return RusotoError::Service(ListOpsItemEventsError::OpsItemInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOpsItemEventsError::OpsItemInvalidParameter,
+                    )
                 }
                 "OpsItemLimitExceededException" => {
-                    return RusotoError::Service(ListOpsItemEventsError::OpsItemLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOpsItemEventsError::OpsItemLimitExceeded,
+                    )
                 }
                 "OpsItemNotFoundException" => {
-                    return RusotoError::Service(ListOpsItemEventsError::OpsItemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOpsItemEventsError::OpsItemNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13592,14 +14645,22 @@ impl ListOpsMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListOpsMetadataError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOpsMetadataError::InternalServerError,
+                    )
                 }
                 "OpsMetadataInvalidArgumentException" => {
-                    return RusotoError::Service(ListOpsMetadataError::OpsMetadataInvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOpsMetadataError::OpsMetadataInvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13634,21 +14695,30 @@ impl ListResourceComplianceSummariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        ListResourceComplianceSummariesError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceComplianceSummariesError::InternalServerError,
                     )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(
-                        ListResourceComplianceSummariesError::InvalidFilter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceComplianceSummariesError::InvalidFilter,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
-                        ListResourceComplianceSummariesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceComplianceSummariesError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13688,21 +14758,30 @@ impl ListResourceDataSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListResourceDataSyncError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceDataSyncError::InternalServerError,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListResourceDataSyncError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceDataSyncError::InvalidNextToken,
+                    )
                 }
                 "ResourceDataSyncInvalidConfigurationException" => {
-                    return RusotoError::Service(
-                        ListResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceDataSyncError::ResourceDataSyncInvalidConfiguration,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13738,21 +14817,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServerError,
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidResourceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidResourceId,
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidResourceType(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidResourceType,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13790,31 +14878,46 @@ impl ModifyDocumentPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DocumentLimitExceeded" => {
-                    return RusotoError::Service(
-                        ModifyDocumentPermissionError::DocumentLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyDocumentPermissionError::DocumentLimitExceeded,
                     )
                 }
                 "DocumentPermissionLimit" => {
-                    return RusotoError::Service(
-                        ModifyDocumentPermissionError::DocumentPermissionLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyDocumentPermissionError::DocumentPermissionLimit,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        ModifyDocumentPermissionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyDocumentPermissionError::InternalServerError,
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(ModifyDocumentPermissionError::InvalidDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyDocumentPermissionError::InvalidDocument,
+                    )
                 }
                 "InvalidPermissionType" => {
-                    return RusotoError::Service(
-                        ModifyDocumentPermissionError::InvalidPermissionType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyDocumentPermissionError::InvalidPermissionType,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13864,41 +14967,62 @@ impl PutComplianceItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ComplianceTypeCountLimitExceededException" => {
-                    return RusotoError::Service(
-                        PutComplianceItemsError::ComplianceTypeCountLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutComplianceItemsError::ComplianceTypeCountLimitExceeded,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutComplianceItemsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutComplianceItemsError::InternalServerError,
+                    )
                 }
                 "InvalidItemContentException" => {
-                    return RusotoError::Service(PutComplianceItemsError::InvalidItemContent(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutComplianceItemsError::InvalidItemContent,
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(PutComplianceItemsError::InvalidResourceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutComplianceItemsError::InvalidResourceId,
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(PutComplianceItemsError::InvalidResourceType(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutComplianceItemsError::InvalidResourceType,
+                    )
                 }
                 "ItemSizeLimitExceededException" => {
-                    return RusotoError::Service(PutComplianceItemsError::ItemSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutComplianceItemsError::ItemSizeLimitExceeded,
+                    )
                 }
                 "TotalSizeLimitExceededException" => {
-                    return RusotoError::Service(PutComplianceItemsError::TotalSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutComplianceItemsError::TotalSizeLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13956,52 +15080,102 @@ impl PutInventoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomSchemaCountLimitExceededException" => {
-                    return RusotoError::Service(PutInventoryError::CustomSchemaCountLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutInventoryError::CustomSchemaCountLimitExceeded,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutInventoryError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInventoryError::InternalServerError,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(PutInventoryError::InvalidInstanceId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInventoryError::InvalidInstanceId,
+                    )
                 }
                 "InvalidInventoryItemContextException" => {
-                    return RusotoError::Service(PutInventoryError::InvalidInventoryItemContext(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutInventoryError::InvalidInventoryItemContext,
+                    )
                 }
                 "InvalidItemContentException" => {
-                    return RusotoError::Service(PutInventoryError::InvalidItemContent(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInventoryError::InvalidItemContent,
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(PutInventoryError::InvalidTypeName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInventoryError::InvalidTypeName,
+                    )
                 }
                 "ItemContentMismatchException" => {
-                    return RusotoError::Service(PutInventoryError::ItemContentMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInventoryError::ItemContentMismatch,
+                    )
                 }
                 "ItemSizeLimitExceededException" => {
-                    return RusotoError::Service(PutInventoryError::ItemSizeLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInventoryError::ItemSizeLimitExceeded,
+                    )
                 }
                 "SubTypeCountLimitExceededException" => {
-                    return RusotoError::Service(PutInventoryError::SubTypeCountLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutInventoryError::SubTypeCountLimitExceeded,
+                    )
                 }
                 "TotalSizeLimitExceededException" => {
-                    return RusotoError::Service(PutInventoryError::TotalSizeLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInventoryError::TotalSizeLimitExceeded,
+                    )
                 }
                 "UnsupportedInventoryItemContextException" => {
-                    return RusotoError::Service(
-                        PutInventoryError::UnsupportedInventoryItemContext(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInventoryError::UnsupportedInventoryItemContext,
                     )
                 }
                 "UnsupportedInventorySchemaVersionException" => {
-                    return RusotoError::Service(
-                        PutInventoryError::UnsupportedInventorySchemaVersion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInventoryError::UnsupportedInventorySchemaVersion,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14070,59 +15244,126 @@ impl PutParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "HierarchyLevelLimitExceededException" => {
-                    return RusotoError::Service(PutParameterError::HierarchyLevelLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutParameterError::HierarchyLevelLimitExceeded,
+                    )
                 }
                 "HierarchyTypeMismatchException" => {
-                    return RusotoError::Service(PutParameterError::HierarchyTypeMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutParameterError::HierarchyTypeMismatch,
+                    )
                 }
                 "IncompatiblePolicyException" => {
-                    return RusotoError::Service(PutParameterError::IncompatiblePolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutParameterError::IncompatiblePolicy,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutParameterError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutParameterError::InternalServerError,
+                    )
                 }
                 "InvalidAllowedPatternException" => {
-                    return RusotoError::Service(PutParameterError::InvalidAllowedPattern(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutParameterError::InvalidAllowedPattern,
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(PutParameterError::InvalidKeyId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutParameterError::InvalidKeyId,
+                    )
                 }
                 "InvalidPolicyAttributeException" => {
-                    return RusotoError::Service(PutParameterError::InvalidPolicyAttribute(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutParameterError::InvalidPolicyAttribute,
+                    )
                 }
                 "InvalidPolicyTypeException" => {
-                    return RusotoError::Service(PutParameterError::InvalidPolicyType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutParameterError::InvalidPolicyType,
+                    )
                 }
                 "ParameterAlreadyExists" => {
-                    return RusotoError::Service(PutParameterError::ParameterAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutParameterError::ParameterAlreadyExists,
+                    )
                 }
                 "ParameterLimitExceeded" => {
-                    return RusotoError::Service(PutParameterError::ParameterLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutParameterError::ParameterLimitExceeded,
+                    )
                 }
                 "ParameterMaxVersionLimitExceeded" => {
-                    return RusotoError::Service(
-                        PutParameterError::ParameterMaxVersionLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutParameterError::ParameterMaxVersionLimitExceeded,
                     )
                 }
                 "ParameterPatternMismatchException" => {
-                    return RusotoError::Service(PutParameterError::ParameterPatternMismatch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutParameterError::ParameterPatternMismatch,
+                    )
                 }
                 "PoliciesLimitExceededException" => {
-                    return RusotoError::Service(PutParameterError::PoliciesLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutParameterError::PoliciesLimitExceeded,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(PutParameterError::TooManyUpdates(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutParameterError::TooManyUpdates,
+                    )
                 }
                 "UnsupportedParameterType" => {
-                    return RusotoError::Service(PutParameterError::UnsupportedParameterType(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutParameterError::UnsupportedParameterType,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14172,21 +15413,30 @@ impl RegisterDefaultPatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(RegisterDefaultPatchBaselineError::DoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterDefaultPatchBaselineError::DoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        RegisterDefaultPatchBaselineError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDefaultPatchBaselineError::InternalServerError,
                     )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(
-                        RegisterDefaultPatchBaselineError::InvalidResourceId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDefaultPatchBaselineError::InvalidResourceId,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14230,31 +15480,46 @@ impl RegisterPatchBaselineForPatchGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(
-                        RegisterPatchBaselineForPatchGroupError::AlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterPatchBaselineForPatchGroupError::AlreadyExists,
                     )
                 }
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        RegisterPatchBaselineForPatchGroupError::DoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterPatchBaselineForPatchGroupError::DoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        RegisterPatchBaselineForPatchGroupError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterPatchBaselineForPatchGroupError::InternalServerError,
                     )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(
-                        RegisterPatchBaselineForPatchGroupError::InvalidResourceId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterPatchBaselineForPatchGroupError::InvalidResourceId,
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        RegisterPatchBaselineForPatchGroupError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterPatchBaselineForPatchGroupError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14304,28 +15569,38 @@ impl RegisterTargetWithMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        RegisterTargetWithMaintenanceWindowError::DoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTargetWithMaintenanceWindowError::DoesNotExist,
                     )
                 }
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
-                        RegisterTargetWithMaintenanceWindowError::IdempotentParameterMismatch(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTargetWithMaintenanceWindowError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        RegisterTargetWithMaintenanceWindowError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTargetWithMaintenanceWindowError::InternalServerError,
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        RegisterTargetWithMaintenanceWindowError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTargetWithMaintenanceWindowError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14374,33 +15649,46 @@ impl RegisterTaskWithMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
-                        RegisterTaskWithMaintenanceWindowError::DoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTaskWithMaintenanceWindowError::DoesNotExist,
                     )
                 }
                 "FeatureNotAvailableException" => {
-                    return RusotoError::Service(
-                        RegisterTaskWithMaintenanceWindowError::FeatureNotAvailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTaskWithMaintenanceWindowError::FeatureNotAvailable,
                     )
                 }
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
-                        RegisterTaskWithMaintenanceWindowError::IdempotentParameterMismatch(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTaskWithMaintenanceWindowError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        RegisterTaskWithMaintenanceWindowError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTaskWithMaintenanceWindowError::InternalServerError,
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        RegisterTaskWithMaintenanceWindowError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTaskWithMaintenanceWindowError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14448,26 +15736,38 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromResourceError::InternalServerError,
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InvalidResourceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromResourceError::InvalidResourceId,
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InvalidResourceType(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromResourceError::InvalidResourceType,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::TooManyUpdates(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromResourceError::TooManyUpdates,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14502,19 +15802,30 @@ impl ResetServiceSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ResetServiceSettingError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResetServiceSettingError::InternalServerError,
+                    )
                 }
                 "ServiceSettingNotFound" => {
-                    return RusotoError::Service(ResetServiceSettingError::ServiceSettingNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResetServiceSettingError::ServiceSettingNotFound,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(ResetServiceSettingError::TooManyUpdates(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetServiceSettingError::TooManyUpdates,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14546,12 +15857,22 @@ impl ResumeSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(ResumeSessionError::DoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResumeSessionError::DoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ResumeSessionError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResumeSessionError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14586,26 +15907,38 @@ impl SendAutomationSignalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationExecutionNotFoundException" => {
-                    return RusotoError::Service(
-                        SendAutomationSignalError::AutomationExecutionNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendAutomationSignalError::AutomationExecutionNotFound,
                     )
                 }
                 "AutomationStepNotFoundException" => {
-                    return RusotoError::Service(SendAutomationSignalError::AutomationStepNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendAutomationSignalError::AutomationStepNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(SendAutomationSignalError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendAutomationSignalError::InternalServerError,
+                    )
                 }
                 "InvalidAutomationSignalException" => {
-                    return RusotoError::Service(
-                        SendAutomationSignalError::InvalidAutomationSignal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendAutomationSignalError::InvalidAutomationSignal,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14658,41 +15991,94 @@ impl SendCommandError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateInstanceId" => {
-                    return RusotoError::Service(SendCommandError::DuplicateInstanceId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::DuplicateInstanceId,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(SendCommandError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(SendCommandError::InvalidDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::InvalidDocument,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(SendCommandError::InvalidDocumentVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::InvalidDocumentVersion,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(SendCommandError::InvalidInstanceId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::InvalidInstanceId,
+                    )
                 }
                 "InvalidNotificationConfig" => {
-                    return RusotoError::Service(SendCommandError::InvalidNotificationConfig(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendCommandError::InvalidNotificationConfig,
+                    )
                 }
                 "InvalidOutputFolder" => {
-                    return RusotoError::Service(SendCommandError::InvalidOutputFolder(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::InvalidOutputFolder,
+                    )
                 }
                 "InvalidParameters" => {
-                    return RusotoError::Service(SendCommandError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::InvalidParameters,
+                    )
                 }
                 "InvalidRole" => {
-                    return RusotoError::Service(SendCommandError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::InvalidRole,
+                    )
                 }
                 "MaxDocumentSizeExceeded" => {
-                    return RusotoError::Service(SendCommandError::MaxDocumentSizeExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::MaxDocumentSizeExceeded,
+                    )
                 }
                 "UnsupportedPlatformType" => {
-                    return RusotoError::Service(SendCommandError::UnsupportedPlatformType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::UnsupportedPlatformType,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14732,16 +16118,22 @@ impl StartAssociationsOnceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
-                        StartAssociationsOnceError::AssociationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAssociationsOnceError::AssociationDoesNotExist,
                     )
                 }
                 "InvalidAssociation" => {
-                    return RusotoError::Service(StartAssociationsOnceError::InvalidAssociation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartAssociationsOnceError::InvalidAssociation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14784,43 +16176,62 @@ impl StartAutomationExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationDefinitionNotFoundException" => {
-                    return RusotoError::Service(
-                        StartAutomationExecutionError::AutomationDefinitionNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAutomationExecutionError::AutomationDefinitionNotFound,
                     )
                 }
                 "AutomationDefinitionVersionNotFoundException" => {
-                    return RusotoError::Service(
-                        StartAutomationExecutionError::AutomationDefinitionVersionNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAutomationExecutionError::AutomationDefinitionVersionNotFound,
                     )
                 }
                 "AutomationExecutionLimitExceededException" => {
-                    return RusotoError::Service(
-                        StartAutomationExecutionError::AutomationExecutionLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAutomationExecutionError::AutomationExecutionLimitExceeded,
                     )
                 }
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
-                        StartAutomationExecutionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAutomationExecutionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        StartAutomationExecutionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAutomationExecutionError::InternalServerError,
                     )
                 }
                 "InvalidAutomationExecutionParametersException" => {
-                    return RusotoError::Service(
-                        StartAutomationExecutionError::InvalidAutomationExecutionParameters(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAutomationExecutionError::InvalidAutomationExecutionParameters,
                     )
                 }
                 "InvalidTarget" => {
-                    return RusotoError::Service(StartAutomationExecutionError::InvalidTarget(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartAutomationExecutionError::InvalidTarget,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14878,45 +16289,62 @@ impl StartChangeRequestExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationDefinitionNotApprovedException" => {
-                    return RusotoError::Service(
-                        StartChangeRequestExecutionError::AutomationDefinitionNotApproved(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeRequestExecutionError::AutomationDefinitionNotApproved,
                     )
                 }
                 "AutomationDefinitionNotFoundException" => {
-                    return RusotoError::Service(
-                        StartChangeRequestExecutionError::AutomationDefinitionNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeRequestExecutionError::AutomationDefinitionNotFound,
                     )
                 }
                 "AutomationDefinitionVersionNotFoundException" => {
-                    return RusotoError::Service(
-                        StartChangeRequestExecutionError::AutomationDefinitionVersionNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeRequestExecutionError::AutomationDefinitionVersionNotFound,
                     )
                 }
                 "AutomationExecutionLimitExceededException" => {
-                    return RusotoError::Service(
-                        StartChangeRequestExecutionError::AutomationExecutionLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeRequestExecutionError::AutomationExecutionLimitExceeded,
                     )
                 }
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
-                        StartChangeRequestExecutionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeRequestExecutionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        StartChangeRequestExecutionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeRequestExecutionError::InternalServerError,
                     )
                 }
                 "InvalidAutomationExecutionParametersException" => {
-                    return RusotoError::Service(
-                        StartChangeRequestExecutionError::InvalidAutomationExecutionParameters(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeRequestExecutionError::InvalidAutomationExecutionParameters,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14968,15 +16396,30 @@ impl StartSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(StartSessionError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSessionError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(StartSessionError::InvalidDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSessionError::InvalidDocument,
+                    )
                 }
                 "TargetNotConnected" => {
-                    return RusotoError::Service(StartSessionError::TargetNotConnected(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSessionError::TargetNotConnected,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15010,21 +16453,30 @@ impl StopAutomationExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationExecutionNotFoundException" => {
-                    return RusotoError::Service(
-                        StopAutomationExecutionError::AutomationExecutionNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopAutomationExecutionError::AutomationExecutionNotFound,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StopAutomationExecutionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopAutomationExecutionError::InternalServerError,
+                    )
                 }
                 "InvalidAutomationStatusUpdateException" => {
-                    return RusotoError::Service(
-                        StopAutomationExecutionError::InvalidAutomationStatusUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopAutomationExecutionError::InvalidAutomationStatusUpdate,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15060,14 +16512,22 @@ impl TerminateSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(TerminateSessionError::DoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TerminateSessionError::DoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(TerminateSessionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TerminateSessionError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15118,54 +16578,102 @@ impl UpdateAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(UpdateAssociationError::AssociationDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAssociationError::AssociationDoesNotExist,
+                    )
                 }
                 "AssociationVersionLimitExceeded" => {
-                    return RusotoError::Service(
-                        UpdateAssociationError::AssociationVersionLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssociationError::AssociationVersionLimitExceeded,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateAssociationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAssociationError::InternalServerError,
+                    )
                 }
                 "InvalidAssociationVersion" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidAssociationVersion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAssociationError::InvalidAssociationVersion,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssociationError::InvalidDocument,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidDocumentVersion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAssociationError::InvalidDocumentVersion,
+                    )
                 }
                 "InvalidOutputLocation" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidOutputLocation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAssociationError::InvalidOutputLocation,
+                    )
                 }
                 "InvalidParameters" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidParameters(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssociationError::InvalidParameters,
+                    )
                 }
                 "InvalidSchedule" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidSchedule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssociationError::InvalidSchedule,
+                    )
                 }
                 "InvalidTarget" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidTarget(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssociationError::InvalidTarget,
+                    )
                 }
                 "InvalidUpdate" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidUpdate(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssociationError::InvalidUpdate,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(UpdateAssociationError::TooManyUpdates(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssociationError::TooManyUpdates,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15216,36 +16724,54 @@ impl UpdateAssociationStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
-                        UpdateAssociationStatusError::AssociationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssociationStatusError::AssociationDoesNotExist,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAssociationStatusError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::InvalidDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAssociationStatusError::InvalidDocument,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::InvalidInstanceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAssociationStatusError::InvalidInstanceId,
+                    )
                 }
                 "StatusUnchanged" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::StatusUnchanged(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAssociationStatusError::StatusUnchanged,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::TooManyUpdates(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAssociationStatusError::TooManyUpdates,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15298,52 +16824,86 @@ impl UpdateDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DocumentVersionLimitExceeded" => {
-                    return RusotoError::Service(UpdateDocumentError::DocumentVersionLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentError::DocumentVersionLimitExceeded,
+                    )
                 }
                 "DuplicateDocumentContent" => {
-                    return RusotoError::Service(UpdateDocumentError::DuplicateDocumentContent(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentError::DuplicateDocumentContent,
+                    )
                 }
                 "DuplicateDocumentVersionName" => {
-                    return RusotoError::Service(UpdateDocumentError::DuplicateDocumentVersionName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentError::DuplicateDocumentVersionName,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentError::InvalidDocument,
+                    )
                 }
                 "InvalidDocumentContent" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocumentContent(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentError::InvalidDocumentContent,
+                    )
                 }
                 "InvalidDocumentOperation" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocumentOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentError::InvalidDocumentOperation,
+                    )
                 }
                 "InvalidDocumentSchemaVersion" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocumentSchemaVersion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentError::InvalidDocumentSchemaVersion,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocumentVersion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentError::InvalidDocumentVersion,
+                    )
                 }
                 "MaxDocumentSizeExceeded" => {
-                    return RusotoError::Service(UpdateDocumentError::MaxDocumentSizeExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentError::MaxDocumentSizeExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15388,26 +16948,38 @@ impl UpdateDocumentDefaultVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateDocumentDefaultVersionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentDefaultVersionError::InternalServerError,
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(
-                        UpdateDocumentDefaultVersionError::InvalidDocument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentDefaultVersionError::InvalidDocument,
                     )
                 }
                 "InvalidDocumentSchemaVersion" => {
-                    return RusotoError::Service(
-                        UpdateDocumentDefaultVersionError::InvalidDocumentSchemaVersion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentDefaultVersionError::InvalidDocumentSchemaVersion,
                     )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(
-                        UpdateDocumentDefaultVersionError::InvalidDocumentVersion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentDefaultVersionError::InvalidDocumentVersion,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15450,26 +17022,38 @@ impl UpdateDocumentMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateDocumentMetadataError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentMetadataError::InternalServerError,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(UpdateDocumentMetadataError::InvalidDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentMetadataError::InvalidDocument,
+                    )
                 }
                 "InvalidDocumentOperation" => {
-                    return RusotoError::Service(
-                        UpdateDocumentMetadataError::InvalidDocumentOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentMetadataError::InvalidDocumentOperation,
                     )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(
-                        UpdateDocumentMetadataError::InvalidDocumentVersion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentMetadataError::InvalidDocumentVersion,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15506,16 +17090,22 @@ impl UpdateMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(UpdateMaintenanceWindowError::DoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMaintenanceWindowError::DoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateMaintenanceWindowError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMaintenanceWindowError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15548,16 +17138,22 @@ impl UpdateMaintenanceWindowTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(UpdateMaintenanceWindowTargetError::DoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMaintenanceWindowTargetError::DoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateMaintenanceWindowTargetError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMaintenanceWindowTargetError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15592,16 +17188,22 @@ impl UpdateMaintenanceWindowTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(UpdateMaintenanceWindowTaskError::DoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMaintenanceWindowTaskError::DoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateMaintenanceWindowTaskError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMaintenanceWindowTaskError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15634,16 +17236,22 @@ impl UpdateManagedInstanceRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateManagedInstanceRoleError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateManagedInstanceRoleError::InternalServerError,
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(UpdateManagedInstanceRoleError::InvalidInstanceId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateManagedInstanceRoleError::InvalidInstanceId,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15682,23 +17290,46 @@ impl UpdateOpsItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateOpsItemError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOpsItemError::InternalServerError,
+                    )
                 }
                 "OpsItemAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateOpsItemError::OpsItemAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOpsItemError::OpsItemAlreadyExists,
+                    )
                 }
                 "OpsItemInvalidParameterException" => {
-                    return RusotoError::Service(UpdateOpsItemError::OpsItemInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateOpsItemError::OpsItemInvalidParameter,
+                    )
                 }
                 "OpsItemLimitExceededException" => {
-                    return RusotoError::Service(UpdateOpsItemError::OpsItemLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOpsItemError::OpsItemLimitExceeded,
+                    )
                 }
                 "OpsItemNotFoundException" => {
-                    return RusotoError::Service(UpdateOpsItemError::OpsItemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOpsItemError::OpsItemNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15738,31 +17369,46 @@ impl UpdateOpsMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateOpsMetadataError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateOpsMetadataError::InternalServerError,
+                    )
                 }
                 "OpsMetadataInvalidArgumentException" => {
-                    return RusotoError::Service(
-                        UpdateOpsMetadataError::OpsMetadataInvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOpsMetadataError::OpsMetadataInvalidArgument,
                     )
                 }
                 "OpsMetadataKeyLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateOpsMetadataError::OpsMetadataKeyLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOpsMetadataError::OpsMetadataKeyLimitExceeded,
                     )
                 }
                 "OpsMetadataNotFoundException" => {
-                    return RusotoError::Service(UpdateOpsMetadataError::OpsMetadataNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateOpsMetadataError::OpsMetadataNotFound,
+                    )
                 }
                 "OpsMetadataTooManyUpdatesException" => {
-                    return RusotoError::Service(UpdateOpsMetadataError::OpsMetadataTooManyUpdates(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateOpsMetadataError::OpsMetadataTooManyUpdates,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15798,14 +17444,22 @@ impl UpdatePatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(UpdatePatchBaselineError::DoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePatchBaselineError::DoesNotExist,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdatePatchBaselineError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePatchBaselineError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15840,26 +17494,38 @@ impl UpdateResourceDataSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateResourceDataSyncError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResourceDataSyncError::InternalServerError,
+                    )
                 }
                 "ResourceDataSyncConflictException" => {
-                    return RusotoError::Service(
-                        UpdateResourceDataSyncError::ResourceDataSyncConflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceDataSyncError::ResourceDataSyncConflict,
                     )
                 }
                 "ResourceDataSyncInvalidConfigurationException" => {
-                    return RusotoError::Service(
-                        UpdateResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceDataSyncError::ResourceDataSyncInvalidConfiguration,
                     )
                 }
                 "ResourceDataSyncNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateResourceDataSyncError::ResourceDataSyncNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceDataSyncError::ResourceDataSyncNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15900,19 +17566,30 @@ impl UpdateServiceSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateServiceSettingError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateServiceSettingError::InternalServerError,
+                    )
                 }
                 "ServiceSettingNotFound" => {
-                    return RusotoError::Service(UpdateServiceSettingError::ServiceSettingNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateServiceSettingError::ServiceSettingNotFound,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(UpdateServiceSettingError::TooManyUpdates(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceSettingError::TooManyUpdates,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16801,11 +18478,12 @@ pub struct SsmClient {
 impl SsmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SsmClient {
         SsmClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SSM"),
         }
     }
 
@@ -16820,12 +18498,32 @@ impl SsmClient {
     {
         SsmClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SSM"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SsmClient {
-        SsmClient { client, region }
+        SsmClient {
+            client,
+            region: region_for_service(region, "SSM"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SsmClient {
+        SsmClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SSM"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SsmClient {
+        SsmClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -16837,6 +18535,7 @@ impl Ssm for SsmClient {
         input: AddTagsToResourceRequest,
     ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTagsToResource");
         request.add_header("x-amz-target", "AmazonSSM.AddTagsToResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16855,6 +18554,7 @@ impl Ssm for SsmClient {
         input: CancelCommandRequest,
     ) -> Result<CancelCommandResult, RusotoError<CancelCommandError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelCommand");
         request.add_header("x-amz-target", "AmazonSSM.CancelCommand");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16876,6 +18576,7 @@ impl Ssm for SsmClient {
         RusotoError<CancelMaintenanceWindowExecutionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelMaintenanceWindowExecution");
         request.add_header("x-amz-target", "AmazonSSM.CancelMaintenanceWindowExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16898,6 +18599,7 @@ impl Ssm for SsmClient {
         input: CreateActivationRequest,
     ) -> Result<CreateActivationResult, RusotoError<CreateActivationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateActivation");
         request.add_header("x-amz-target", "AmazonSSM.CreateActivation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16916,6 +18618,7 @@ impl Ssm for SsmClient {
         input: CreateAssociationRequest,
     ) -> Result<CreateAssociationResult, RusotoError<CreateAssociationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAssociation");
         request.add_header("x-amz-target", "AmazonSSM.CreateAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16934,6 +18637,7 @@ impl Ssm for SsmClient {
         input: CreateAssociationBatchRequest,
     ) -> Result<CreateAssociationBatchResult, RusotoError<CreateAssociationBatchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAssociationBatch");
         request.add_header("x-amz-target", "AmazonSSM.CreateAssociationBatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16953,6 +18657,7 @@ impl Ssm for SsmClient {
         input: CreateDocumentRequest,
     ) -> Result<CreateDocumentResult, RusotoError<CreateDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDocument");
         request.add_header("x-amz-target", "AmazonSSM.CreateDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16971,6 +18676,7 @@ impl Ssm for SsmClient {
         input: CreateMaintenanceWindowRequest,
     ) -> Result<CreateMaintenanceWindowResult, RusotoError<CreateMaintenanceWindowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateMaintenanceWindow");
         request.add_header("x-amz-target", "AmazonSSM.CreateMaintenanceWindow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16990,6 +18696,7 @@ impl Ssm for SsmClient {
         input: CreateOpsItemRequest,
     ) -> Result<CreateOpsItemResponse, RusotoError<CreateOpsItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateOpsItem");
         request.add_header("x-amz-target", "AmazonSSM.CreateOpsItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17008,6 +18715,7 @@ impl Ssm for SsmClient {
         input: CreateOpsMetadataRequest,
     ) -> Result<CreateOpsMetadataResult, RusotoError<CreateOpsMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateOpsMetadata");
         request.add_header("x-amz-target", "AmazonSSM.CreateOpsMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17026,6 +18734,7 @@ impl Ssm for SsmClient {
         input: CreatePatchBaselineRequest,
     ) -> Result<CreatePatchBaselineResult, RusotoError<CreatePatchBaselineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePatchBaseline");
         request.add_header("x-amz-target", "AmazonSSM.CreatePatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17044,6 +18753,7 @@ impl Ssm for SsmClient {
         input: CreateResourceDataSyncRequest,
     ) -> Result<CreateResourceDataSyncResult, RusotoError<CreateResourceDataSyncError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateResourceDataSync");
         request.add_header("x-amz-target", "AmazonSSM.CreateResourceDataSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17063,6 +18773,7 @@ impl Ssm for SsmClient {
         input: DeleteActivationRequest,
     ) -> Result<DeleteActivationResult, RusotoError<DeleteActivationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteActivation");
         request.add_header("x-amz-target", "AmazonSSM.DeleteActivation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17081,6 +18792,7 @@ impl Ssm for SsmClient {
         input: DeleteAssociationRequest,
     ) -> Result<DeleteAssociationResult, RusotoError<DeleteAssociationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAssociation");
         request.add_header("x-amz-target", "AmazonSSM.DeleteAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17099,6 +18811,7 @@ impl Ssm for SsmClient {
         input: DeleteDocumentRequest,
     ) -> Result<DeleteDocumentResult, RusotoError<DeleteDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDocument");
         request.add_header("x-amz-target", "AmazonSSM.DeleteDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17117,6 +18830,7 @@ impl Ssm for SsmClient {
         input: DeleteInventoryRequest,
     ) -> Result<DeleteInventoryResult, RusotoError<DeleteInventoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteInventory");
         request.add_header("x-amz-target", "AmazonSSM.DeleteInventory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17135,6 +18849,7 @@ impl Ssm for SsmClient {
         input: DeleteMaintenanceWindowRequest,
     ) -> Result<DeleteMaintenanceWindowResult, RusotoError<DeleteMaintenanceWindowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteMaintenanceWindow");
         request.add_header("x-amz-target", "AmazonSSM.DeleteMaintenanceWindow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17154,6 +18869,7 @@ impl Ssm for SsmClient {
         input: DeleteOpsMetadataRequest,
     ) -> Result<DeleteOpsMetadataResult, RusotoError<DeleteOpsMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteOpsMetadata");
         request.add_header("x-amz-target", "AmazonSSM.DeleteOpsMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17172,6 +18888,7 @@ impl Ssm for SsmClient {
         input: DeleteParameterRequest,
     ) -> Result<DeleteParameterResult, RusotoError<DeleteParameterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteParameter");
         request.add_header("x-amz-target", "AmazonSSM.DeleteParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17190,6 +18907,7 @@ impl Ssm for SsmClient {
         input: DeleteParametersRequest,
     ) -> Result<DeleteParametersResult, RusotoError<DeleteParametersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteParameters");
         request.add_header("x-amz-target", "AmazonSSM.DeleteParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17208,6 +18926,7 @@ impl Ssm for SsmClient {
         input: DeletePatchBaselineRequest,
     ) -> Result<DeletePatchBaselineResult, RusotoError<DeletePatchBaselineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePatchBaseline");
         request.add_header("x-amz-target", "AmazonSSM.DeletePatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17226,6 +18945,7 @@ impl Ssm for SsmClient {
         input: DeleteResourceDataSyncRequest,
     ) -> Result<DeleteResourceDataSyncResult, RusotoError<DeleteResourceDataSyncError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteResourceDataSync");
         request.add_header("x-amz-target", "AmazonSSM.DeleteResourceDataSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17245,6 +18965,7 @@ impl Ssm for SsmClient {
         input: DeregisterManagedInstanceRequest,
     ) -> Result<DeregisterManagedInstanceResult, RusotoError<DeregisterManagedInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterManagedInstance");
         request.add_header("x-amz-target", "AmazonSSM.DeregisterManagedInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17267,6 +18988,7 @@ impl Ssm for SsmClient {
         RusotoError<DeregisterPatchBaselineForPatchGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterPatchBaselineForPatchGroup");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DeregisterPatchBaselineForPatchGroup",
@@ -17295,6 +19017,7 @@ impl Ssm for SsmClient {
         RusotoError<DeregisterTargetFromMaintenanceWindowError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterTargetFromMaintenanceWindow");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DeregisterTargetFromMaintenanceWindow",
@@ -17323,6 +19046,7 @@ impl Ssm for SsmClient {
         RusotoError<DeregisterTaskFromMaintenanceWindowError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterTaskFromMaintenanceWindow");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DeregisterTaskFromMaintenanceWindow",
@@ -17348,6 +19072,7 @@ impl Ssm for SsmClient {
         input: DescribeActivationsRequest,
     ) -> Result<DescribeActivationsResult, RusotoError<DescribeActivationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeActivations");
         request.add_header("x-amz-target", "AmazonSSM.DescribeActivations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17366,6 +19091,7 @@ impl Ssm for SsmClient {
         input: DescribeAssociationRequest,
     ) -> Result<DescribeAssociationResult, RusotoError<DescribeAssociationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAssociation");
         request.add_header("x-amz-target", "AmazonSSM.DescribeAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17387,6 +19113,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeAssociationExecutionTargetsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAssociationExecutionTargets");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeAssociationExecutionTargets",
@@ -17413,6 +19140,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeAssociationExecutionsResult, RusotoError<DescribeAssociationExecutionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAssociationExecutions");
         request.add_header("x-amz-target", "AmazonSSM.DescribeAssociationExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17433,6 +19161,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeAutomationExecutionsResult, RusotoError<DescribeAutomationExecutionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAutomationExecutions");
         request.add_header("x-amz-target", "AmazonSSM.DescribeAutomationExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17455,6 +19184,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeAutomationStepExecutionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAutomationStepExecutions");
         request.add_header("x-amz-target", "AmazonSSM.DescribeAutomationStepExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17477,6 +19207,7 @@ impl Ssm for SsmClient {
         input: DescribeAvailablePatchesRequest,
     ) -> Result<DescribeAvailablePatchesResult, RusotoError<DescribeAvailablePatchesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAvailablePatches");
         request.add_header("x-amz-target", "AmazonSSM.DescribeAvailablePatches");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17496,6 +19227,7 @@ impl Ssm for SsmClient {
         input: DescribeDocumentRequest,
     ) -> Result<DescribeDocumentResult, RusotoError<DescribeDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDocument");
         request.add_header("x-amz-target", "AmazonSSM.DescribeDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17515,6 +19247,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeDocumentPermissionResponse, RusotoError<DescribeDocumentPermissionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDocumentPermission");
         request.add_header("x-amz-target", "AmazonSSM.DescribeDocumentPermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17537,6 +19270,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeEffectiveInstanceAssociationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEffectiveInstanceAssociations");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeEffectiveInstanceAssociations",
@@ -17565,6 +19299,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeEffectivePatchesForPatchBaselineError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEffectivePatchesForPatchBaseline");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeEffectivePatchesForPatchBaseline",
@@ -17593,6 +19328,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeInstanceAssociationsStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeInstanceAssociationsStatus");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeInstanceAssociationsStatus",
@@ -17619,6 +19355,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeInstanceInformationResult, RusotoError<DescribeInstanceInformationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeInstanceInformation");
         request.add_header("x-amz-target", "AmazonSSM.DescribeInstanceInformation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17639,6 +19376,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeInstancePatchStatesResult, RusotoError<DescribeInstancePatchStatesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeInstancePatchStates");
         request.add_header("x-amz-target", "AmazonSSM.DescribeInstancePatchStates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17661,6 +19399,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeInstancePatchStatesForPatchGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeInstancePatchStatesForPatchGroup");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeInstancePatchStatesForPatchGroup",
@@ -17686,6 +19425,7 @@ impl Ssm for SsmClient {
         input: DescribeInstancePatchesRequest,
     ) -> Result<DescribeInstancePatchesResult, RusotoError<DescribeInstancePatchesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeInstancePatches");
         request.add_header("x-amz-target", "AmazonSSM.DescribeInstancePatches");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17706,6 +19446,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeInventoryDeletionsResult, RusotoError<DescribeInventoryDeletionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeInventoryDeletions");
         request.add_header("x-amz-target", "AmazonSSM.DescribeInventoryDeletions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17728,6 +19469,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowExecutionTaskInvocationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMaintenanceWindowExecutionTaskInvocations");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeMaintenanceWindowExecutionTaskInvocations",
@@ -17756,6 +19498,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowExecutionTasksError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMaintenanceWindowExecutionTasks");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeMaintenanceWindowExecutionTasks",
@@ -17784,6 +19527,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowExecutionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMaintenanceWindowExecutions");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeMaintenanceWindowExecutions",
@@ -17812,6 +19556,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowScheduleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMaintenanceWindowSchedule");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeMaintenanceWindowSchedule",
@@ -17840,6 +19585,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowTargetsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMaintenanceWindowTargets");
         request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindowTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17865,6 +19611,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowTasksError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMaintenanceWindowTasks");
         request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindowTasks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17885,6 +19632,7 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeMaintenanceWindowsResult, RusotoError<DescribeMaintenanceWindowsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMaintenanceWindows");
         request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17907,6 +19655,7 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowsForTargetError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMaintenanceWindowsForTarget");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeMaintenanceWindowsForTarget",
@@ -17932,6 +19681,7 @@ impl Ssm for SsmClient {
         input: DescribeOpsItemsRequest,
     ) -> Result<DescribeOpsItemsResponse, RusotoError<DescribeOpsItemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeOpsItems");
         request.add_header("x-amz-target", "AmazonSSM.DescribeOpsItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17950,6 +19700,7 @@ impl Ssm for SsmClient {
         input: DescribeParametersRequest,
     ) -> Result<DescribeParametersResult, RusotoError<DescribeParametersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeParameters");
         request.add_header("x-amz-target", "AmazonSSM.DescribeParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17968,6 +19719,7 @@ impl Ssm for SsmClient {
         input: DescribePatchBaselinesRequest,
     ) -> Result<DescribePatchBaselinesResult, RusotoError<DescribePatchBaselinesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePatchBaselines");
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchBaselines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17987,6 +19739,7 @@ impl Ssm for SsmClient {
         input: DescribePatchGroupStateRequest,
     ) -> Result<DescribePatchGroupStateResult, RusotoError<DescribePatchGroupStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePatchGroupState");
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchGroupState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18006,6 +19759,7 @@ impl Ssm for SsmClient {
         input: DescribePatchGroupsRequest,
     ) -> Result<DescribePatchGroupsResult, RusotoError<DescribePatchGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePatchGroups");
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18024,6 +19778,7 @@ impl Ssm for SsmClient {
         input: DescribePatchPropertiesRequest,
     ) -> Result<DescribePatchPropertiesResult, RusotoError<DescribePatchPropertiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePatchProperties");
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18043,6 +19798,7 @@ impl Ssm for SsmClient {
         input: DescribeSessionsRequest,
     ) -> Result<DescribeSessionsResponse, RusotoError<DescribeSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSessions");
         request.add_header("x-amz-target", "AmazonSSM.DescribeSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18061,6 +19817,7 @@ impl Ssm for SsmClient {
         input: GetAutomationExecutionRequest,
     ) -> Result<GetAutomationExecutionResult, RusotoError<GetAutomationExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAutomationExecution");
         request.add_header("x-amz-target", "AmazonSSM.GetAutomationExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18080,6 +19837,7 @@ impl Ssm for SsmClient {
         input: GetCalendarStateRequest,
     ) -> Result<GetCalendarStateResponse, RusotoError<GetCalendarStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCalendarState");
         request.add_header("x-amz-target", "AmazonSSM.GetCalendarState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18098,6 +19856,7 @@ impl Ssm for SsmClient {
         input: GetCommandInvocationRequest,
     ) -> Result<GetCommandInvocationResult, RusotoError<GetCommandInvocationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCommandInvocation");
         request.add_header("x-amz-target", "AmazonSSM.GetCommandInvocation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18116,6 +19875,7 @@ impl Ssm for SsmClient {
         input: GetConnectionStatusRequest,
     ) -> Result<GetConnectionStatusResponse, RusotoError<GetConnectionStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetConnectionStatus");
         request.add_header("x-amz-target", "AmazonSSM.GetConnectionStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18134,6 +19894,7 @@ impl Ssm for SsmClient {
         input: GetDefaultPatchBaselineRequest,
     ) -> Result<GetDefaultPatchBaselineResult, RusotoError<GetDefaultPatchBaselineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDefaultPatchBaseline");
         request.add_header("x-amz-target", "AmazonSSM.GetDefaultPatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18156,6 +19917,7 @@ impl Ssm for SsmClient {
         RusotoError<GetDeployablePatchSnapshotForInstanceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDeployablePatchSnapshotForInstance");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.GetDeployablePatchSnapshotForInstance",
@@ -18181,6 +19943,7 @@ impl Ssm for SsmClient {
         input: GetDocumentRequest,
     ) -> Result<GetDocumentResult, RusotoError<GetDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDocument");
         request.add_header("x-amz-target", "AmazonSSM.GetDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18199,6 +19962,7 @@ impl Ssm for SsmClient {
         input: GetInventoryRequest,
     ) -> Result<GetInventoryResult, RusotoError<GetInventoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInventory");
         request.add_header("x-amz-target", "AmazonSSM.GetInventory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18217,6 +19981,7 @@ impl Ssm for SsmClient {
         input: GetInventorySchemaRequest,
     ) -> Result<GetInventorySchemaResult, RusotoError<GetInventorySchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInventorySchema");
         request.add_header("x-amz-target", "AmazonSSM.GetInventorySchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18235,6 +20000,7 @@ impl Ssm for SsmClient {
         input: GetMaintenanceWindowRequest,
     ) -> Result<GetMaintenanceWindowResult, RusotoError<GetMaintenanceWindowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMaintenanceWindow");
         request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18254,6 +20020,7 @@ impl Ssm for SsmClient {
     ) -> Result<GetMaintenanceWindowExecutionResult, RusotoError<GetMaintenanceWindowExecutionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMaintenanceWindowExecution");
         request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindowExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18276,6 +20043,7 @@ impl Ssm for SsmClient {
         RusotoError<GetMaintenanceWindowExecutionTaskError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMaintenanceWindowExecutionTask");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.GetMaintenanceWindowExecutionTask",
@@ -18304,6 +20072,7 @@ impl Ssm for SsmClient {
         RusotoError<GetMaintenanceWindowExecutionTaskInvocationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMaintenanceWindowExecutionTaskInvocation");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.GetMaintenanceWindowExecutionTaskInvocation",
@@ -18329,6 +20098,7 @@ impl Ssm for SsmClient {
         input: GetMaintenanceWindowTaskRequest,
     ) -> Result<GetMaintenanceWindowTaskResult, RusotoError<GetMaintenanceWindowTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMaintenanceWindowTask");
         request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindowTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18348,6 +20118,7 @@ impl Ssm for SsmClient {
         input: GetOpsItemRequest,
     ) -> Result<GetOpsItemResponse, RusotoError<GetOpsItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOpsItem");
         request.add_header("x-amz-target", "AmazonSSM.GetOpsItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18366,6 +20137,7 @@ impl Ssm for SsmClient {
         input: GetOpsMetadataRequest,
     ) -> Result<GetOpsMetadataResult, RusotoError<GetOpsMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOpsMetadata");
         request.add_header("x-amz-target", "AmazonSSM.GetOpsMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18384,6 +20156,7 @@ impl Ssm for SsmClient {
         input: GetOpsSummaryRequest,
     ) -> Result<GetOpsSummaryResult, RusotoError<GetOpsSummaryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOpsSummary");
         request.add_header("x-amz-target", "AmazonSSM.GetOpsSummary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18402,6 +20175,7 @@ impl Ssm for SsmClient {
         input: GetParameterRequest,
     ) -> Result<GetParameterResult, RusotoError<GetParameterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetParameter");
         request.add_header("x-amz-target", "AmazonSSM.GetParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18420,6 +20194,7 @@ impl Ssm for SsmClient {
         input: GetParameterHistoryRequest,
     ) -> Result<GetParameterHistoryResult, RusotoError<GetParameterHistoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetParameterHistory");
         request.add_header("x-amz-target", "AmazonSSM.GetParameterHistory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18438,6 +20213,7 @@ impl Ssm for SsmClient {
         input: GetParametersRequest,
     ) -> Result<GetParametersResult, RusotoError<GetParametersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetParameters");
         request.add_header("x-amz-target", "AmazonSSM.GetParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18456,6 +20232,7 @@ impl Ssm for SsmClient {
         input: GetParametersByPathRequest,
     ) -> Result<GetParametersByPathResult, RusotoError<GetParametersByPathError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetParametersByPath");
         request.add_header("x-amz-target", "AmazonSSM.GetParametersByPath");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18474,6 +20251,7 @@ impl Ssm for SsmClient {
         input: GetPatchBaselineRequest,
     ) -> Result<GetPatchBaselineResult, RusotoError<GetPatchBaselineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPatchBaseline");
         request.add_header("x-amz-target", "AmazonSSM.GetPatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18493,6 +20271,7 @@ impl Ssm for SsmClient {
     ) -> Result<GetPatchBaselineForPatchGroupResult, RusotoError<GetPatchBaselineForPatchGroupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPatchBaselineForPatchGroup");
         request.add_header("x-amz-target", "AmazonSSM.GetPatchBaselineForPatchGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18512,6 +20291,7 @@ impl Ssm for SsmClient {
         input: GetServiceSettingRequest,
     ) -> Result<GetServiceSettingResult, RusotoError<GetServiceSettingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetServiceSetting");
         request.add_header("x-amz-target", "AmazonSSM.GetServiceSetting");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18530,6 +20310,7 @@ impl Ssm for SsmClient {
         input: LabelParameterVersionRequest,
     ) -> Result<LabelParameterVersionResult, RusotoError<LabelParameterVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("LabelParameterVersion");
         request.add_header("x-amz-target", "AmazonSSM.LabelParameterVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18548,6 +20329,7 @@ impl Ssm for SsmClient {
         input: ListAssociationVersionsRequest,
     ) -> Result<ListAssociationVersionsResult, RusotoError<ListAssociationVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAssociationVersions");
         request.add_header("x-amz-target", "AmazonSSM.ListAssociationVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18567,6 +20349,7 @@ impl Ssm for SsmClient {
         input: ListAssociationsRequest,
     ) -> Result<ListAssociationsResult, RusotoError<ListAssociationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAssociations");
         request.add_header("x-amz-target", "AmazonSSM.ListAssociations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18585,6 +20368,7 @@ impl Ssm for SsmClient {
         input: ListCommandInvocationsRequest,
     ) -> Result<ListCommandInvocationsResult, RusotoError<ListCommandInvocationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCommandInvocations");
         request.add_header("x-amz-target", "AmazonSSM.ListCommandInvocations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18604,6 +20388,7 @@ impl Ssm for SsmClient {
         input: ListCommandsRequest,
     ) -> Result<ListCommandsResult, RusotoError<ListCommandsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCommands");
         request.add_header("x-amz-target", "AmazonSSM.ListCommands");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18622,6 +20407,7 @@ impl Ssm for SsmClient {
         input: ListComplianceItemsRequest,
     ) -> Result<ListComplianceItemsResult, RusotoError<ListComplianceItemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListComplianceItems");
         request.add_header("x-amz-target", "AmazonSSM.ListComplianceItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18640,6 +20426,7 @@ impl Ssm for SsmClient {
         input: ListComplianceSummariesRequest,
     ) -> Result<ListComplianceSummariesResult, RusotoError<ListComplianceSummariesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListComplianceSummaries");
         request.add_header("x-amz-target", "AmazonSSM.ListComplianceSummaries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18660,6 +20447,7 @@ impl Ssm for SsmClient {
     ) -> Result<ListDocumentMetadataHistoryResponse, RusotoError<ListDocumentMetadataHistoryError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDocumentMetadataHistory");
         request.add_header("x-amz-target", "AmazonSSM.ListDocumentMetadataHistory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18679,6 +20467,7 @@ impl Ssm for SsmClient {
         input: ListDocumentVersionsRequest,
     ) -> Result<ListDocumentVersionsResult, RusotoError<ListDocumentVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDocumentVersions");
         request.add_header("x-amz-target", "AmazonSSM.ListDocumentVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18697,6 +20486,7 @@ impl Ssm for SsmClient {
         input: ListDocumentsRequest,
     ) -> Result<ListDocumentsResult, RusotoError<ListDocumentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDocuments");
         request.add_header("x-amz-target", "AmazonSSM.ListDocuments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18715,6 +20505,7 @@ impl Ssm for SsmClient {
         input: ListInventoryEntriesRequest,
     ) -> Result<ListInventoryEntriesResult, RusotoError<ListInventoryEntriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListInventoryEntries");
         request.add_header("x-amz-target", "AmazonSSM.ListInventoryEntries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18733,6 +20524,7 @@ impl Ssm for SsmClient {
         input: ListOpsItemEventsRequest,
     ) -> Result<ListOpsItemEventsResponse, RusotoError<ListOpsItemEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListOpsItemEvents");
         request.add_header("x-amz-target", "AmazonSSM.ListOpsItemEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18751,6 +20543,7 @@ impl Ssm for SsmClient {
         input: ListOpsMetadataRequest,
     ) -> Result<ListOpsMetadataResult, RusotoError<ListOpsMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListOpsMetadata");
         request.add_header("x-amz-target", "AmazonSSM.ListOpsMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18772,6 +20565,7 @@ impl Ssm for SsmClient {
         RusotoError<ListResourceComplianceSummariesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResourceComplianceSummaries");
         request.add_header("x-amz-target", "AmazonSSM.ListResourceComplianceSummaries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18791,6 +20585,7 @@ impl Ssm for SsmClient {
         input: ListResourceDataSyncRequest,
     ) -> Result<ListResourceDataSyncResult, RusotoError<ListResourceDataSyncError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResourceDataSync");
         request.add_header("x-amz-target", "AmazonSSM.ListResourceDataSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18809,6 +20604,7 @@ impl Ssm for SsmClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "AmazonSSM.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18827,6 +20623,7 @@ impl Ssm for SsmClient {
         input: ModifyDocumentPermissionRequest,
     ) -> Result<ModifyDocumentPermissionResponse, RusotoError<ModifyDocumentPermissionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyDocumentPermission");
         request.add_header("x-amz-target", "AmazonSSM.ModifyDocumentPermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18846,6 +20643,7 @@ impl Ssm for SsmClient {
         input: PutComplianceItemsRequest,
     ) -> Result<PutComplianceItemsResult, RusotoError<PutComplianceItemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutComplianceItems");
         request.add_header("x-amz-target", "AmazonSSM.PutComplianceItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18864,6 +20662,7 @@ impl Ssm for SsmClient {
         input: PutInventoryRequest,
     ) -> Result<PutInventoryResult, RusotoError<PutInventoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutInventory");
         request.add_header("x-amz-target", "AmazonSSM.PutInventory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18882,6 +20681,7 @@ impl Ssm for SsmClient {
         input: PutParameterRequest,
     ) -> Result<PutParameterResult, RusotoError<PutParameterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutParameter");
         request.add_header("x-amz-target", "AmazonSSM.PutParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18901,6 +20701,7 @@ impl Ssm for SsmClient {
     ) -> Result<RegisterDefaultPatchBaselineResult, RusotoError<RegisterDefaultPatchBaselineError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterDefaultPatchBaseline");
         request.add_header("x-amz-target", "AmazonSSM.RegisterDefaultPatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18923,6 +20724,7 @@ impl Ssm for SsmClient {
         RusotoError<RegisterPatchBaselineForPatchGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterPatchBaselineForPatchGroup");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.RegisterPatchBaselineForPatchGroup",
@@ -18951,6 +20753,7 @@ impl Ssm for SsmClient {
         RusotoError<RegisterTargetWithMaintenanceWindowError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterTargetWithMaintenanceWindow");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.RegisterTargetWithMaintenanceWindow",
@@ -18979,6 +20782,7 @@ impl Ssm for SsmClient {
         RusotoError<RegisterTaskWithMaintenanceWindowError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterTaskWithMaintenanceWindow");
         request.add_header(
             "x-amz-target",
             "AmazonSSM.RegisterTaskWithMaintenanceWindow",
@@ -19004,6 +20808,7 @@ impl Ssm for SsmClient {
         input: RemoveTagsFromResourceRequest,
     ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveTagsFromResource");
         request.add_header("x-amz-target", "AmazonSSM.RemoveTagsFromResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19023,6 +20828,7 @@ impl Ssm for SsmClient {
         input: ResetServiceSettingRequest,
     ) -> Result<ResetServiceSettingResult, RusotoError<ResetServiceSettingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResetServiceSetting");
         request.add_header("x-amz-target", "AmazonSSM.ResetServiceSetting");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19041,6 +20847,7 @@ impl Ssm for SsmClient {
         input: ResumeSessionRequest,
     ) -> Result<ResumeSessionResponse, RusotoError<ResumeSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResumeSession");
         request.add_header("x-amz-target", "AmazonSSM.ResumeSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19059,6 +20866,7 @@ impl Ssm for SsmClient {
         input: SendAutomationSignalRequest,
     ) -> Result<SendAutomationSignalResult, RusotoError<SendAutomationSignalError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SendAutomationSignal");
         request.add_header("x-amz-target", "AmazonSSM.SendAutomationSignal");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19077,6 +20885,7 @@ impl Ssm for SsmClient {
         input: SendCommandRequest,
     ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SendCommand");
         request.add_header("x-amz-target", "AmazonSSM.SendCommand");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19095,6 +20904,7 @@ impl Ssm for SsmClient {
         input: StartAssociationsOnceRequest,
     ) -> Result<StartAssociationsOnceResult, RusotoError<StartAssociationsOnceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartAssociationsOnce");
         request.add_header("x-amz-target", "AmazonSSM.StartAssociationsOnce");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19113,6 +20923,7 @@ impl Ssm for SsmClient {
         input: StartAutomationExecutionRequest,
     ) -> Result<StartAutomationExecutionResult, RusotoError<StartAutomationExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartAutomationExecution");
         request.add_header("x-amz-target", "AmazonSSM.StartAutomationExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19133,6 +20944,7 @@ impl Ssm for SsmClient {
     ) -> Result<StartChangeRequestExecutionResult, RusotoError<StartChangeRequestExecutionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartChangeRequestExecution");
         request.add_header("x-amz-target", "AmazonSSM.StartChangeRequestExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19152,6 +20964,7 @@ impl Ssm for SsmClient {
         input: StartSessionRequest,
     ) -> Result<StartSessionResponse, RusotoError<StartSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartSession");
         request.add_header("x-amz-target", "AmazonSSM.StartSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19170,6 +20983,7 @@ impl Ssm for SsmClient {
         input: StopAutomationExecutionRequest,
     ) -> Result<StopAutomationExecutionResult, RusotoError<StopAutomationExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopAutomationExecution");
         request.add_header("x-amz-target", "AmazonSSM.StopAutomationExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19189,6 +21003,7 @@ impl Ssm for SsmClient {
         input: TerminateSessionRequest,
     ) -> Result<TerminateSessionResponse, RusotoError<TerminateSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TerminateSession");
         request.add_header("x-amz-target", "AmazonSSM.TerminateSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19207,6 +21022,7 @@ impl Ssm for SsmClient {
         input: UpdateAssociationRequest,
     ) -> Result<UpdateAssociationResult, RusotoError<UpdateAssociationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAssociation");
         request.add_header("x-amz-target", "AmazonSSM.UpdateAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19225,6 +21041,7 @@ impl Ssm for SsmClient {
         input: UpdateAssociationStatusRequest,
     ) -> Result<UpdateAssociationStatusResult, RusotoError<UpdateAssociationStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAssociationStatus");
         request.add_header("x-amz-target", "AmazonSSM.UpdateAssociationStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19244,6 +21061,7 @@ impl Ssm for SsmClient {
         input: UpdateDocumentRequest,
     ) -> Result<UpdateDocumentResult, RusotoError<UpdateDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDocument");
         request.add_header("x-amz-target", "AmazonSSM.UpdateDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19263,6 +21081,7 @@ impl Ssm for SsmClient {
     ) -> Result<UpdateDocumentDefaultVersionResult, RusotoError<UpdateDocumentDefaultVersionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDocumentDefaultVersion");
         request.add_header("x-amz-target", "AmazonSSM.UpdateDocumentDefaultVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19282,6 +21101,7 @@ impl Ssm for SsmClient {
         input: UpdateDocumentMetadataRequest,
     ) -> Result<UpdateDocumentMetadataResponse, RusotoError<UpdateDocumentMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDocumentMetadata");
         request.add_header("x-amz-target", "AmazonSSM.UpdateDocumentMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19301,6 +21121,7 @@ impl Ssm for SsmClient {
         input: UpdateMaintenanceWindowRequest,
     ) -> Result<UpdateMaintenanceWindowResult, RusotoError<UpdateMaintenanceWindowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateMaintenanceWindow");
         request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19321,6 +21142,7 @@ impl Ssm for SsmClient {
     ) -> Result<UpdateMaintenanceWindowTargetResult, RusotoError<UpdateMaintenanceWindowTargetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateMaintenanceWindowTarget");
         request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindowTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19341,6 +21163,7 @@ impl Ssm for SsmClient {
     ) -> Result<UpdateMaintenanceWindowTaskResult, RusotoError<UpdateMaintenanceWindowTaskError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateMaintenanceWindowTask");
         request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindowTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19360,6 +21183,7 @@ impl Ssm for SsmClient {
         input: UpdateManagedInstanceRoleRequest,
     ) -> Result<UpdateManagedInstanceRoleResult, RusotoError<UpdateManagedInstanceRoleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateManagedInstanceRole");
         request.add_header("x-amz-target", "AmazonSSM.UpdateManagedInstanceRole");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19379,6 +21203,7 @@ impl Ssm for SsmClient {
         input: UpdateOpsItemRequest,
     ) -> Result<UpdateOpsItemResponse, RusotoError<UpdateOpsItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateOpsItem");
         request.add_header("x-amz-target", "AmazonSSM.UpdateOpsItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19397,6 +21222,7 @@ impl Ssm for SsmClient {
         input: UpdateOpsMetadataRequest,
     ) -> Result<UpdateOpsMetadataResult, RusotoError<UpdateOpsMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateOpsMetadata");
         request.add_header("x-amz-target", "AmazonSSM.UpdateOpsMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19415,6 +21241,7 @@ impl Ssm for SsmClient {
         input: UpdatePatchBaselineRequest,
     ) -> Result<UpdatePatchBaselineResult, RusotoError<UpdatePatchBaselineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePatchBaseline");
         request.add_header("x-amz-target", "AmazonSSM.UpdatePatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19433,6 +21260,7 @@ impl Ssm for SsmClient {
         input: UpdateResourceDataSyncRequest,
     ) -> Result<UpdateResourceDataSyncResult, RusotoError<UpdateResourceDataSyncError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateResourceDataSync");
         request.add_header("x-amz-target", "AmazonSSM.UpdateResourceDataSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19452,6 +21280,7 @@ impl Ssm for SsmClient {
         input: UpdateServiceSettingRequest,
     ) -> Result<UpdateServiceSettingResult, RusotoError<UpdateServiceSettingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateServiceSetting");
         request.add_header("x-amz-target", "AmazonSSM.UpdateServiceSetting");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19464,3 +21293,1320 @@ impl Ssm for SsmClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateServiceSettingResult, _>()
     }
 }
+
+/// A blocking client for the Amazon SSM API, running the calls of a `SsmClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SsmClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SsmBlockingClient {
+    client: SsmClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SsmBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SsmBlockingClient {
+        SsmBlockingClient::from_client(SsmClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SsmBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SsmBlockingClient::from_client(SsmClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> SsmBlockingClient {
+        SsmBlockingClient::from_client(SsmClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SsmBlockingClient {
+        SsmBlockingClient::from_client(SsmClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SsmClient) -> SsmBlockingClient {
+        SsmBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> SsmBlockingClient {
+        SsmBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SsmBlockingClient {
+        SsmBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Ssm::add_tags_to_resource`].
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// Blocking version of [`Ssm::cancel_command`].
+    pub fn cancel_command(
+        &self,
+        input: CancelCommandRequest,
+    ) -> Result<CancelCommandResult, RusotoError<CancelCommandError>> {
+        self.runtime.block_on(self.client.cancel_command(input))
+    }
+
+    /// Blocking version of [`Ssm::cancel_maintenance_window_execution`].
+    pub fn cancel_maintenance_window_execution(
+        &self,
+        input: CancelMaintenanceWindowExecutionRequest,
+    ) -> Result<
+        CancelMaintenanceWindowExecutionResult,
+        RusotoError<CancelMaintenanceWindowExecutionError>,
+    > {
+        self.runtime
+            .block_on(self.client.cancel_maintenance_window_execution(input))
+    }
+
+    /// Blocking version of [`Ssm::create_activation`].
+    pub fn create_activation(
+        &self,
+        input: CreateActivationRequest,
+    ) -> Result<CreateActivationResult, RusotoError<CreateActivationError>> {
+        self.runtime.block_on(self.client.create_activation(input))
+    }
+
+    /// Blocking version of [`Ssm::create_association`].
+    pub fn create_association(
+        &self,
+        input: CreateAssociationRequest,
+    ) -> Result<CreateAssociationResult, RusotoError<CreateAssociationError>> {
+        self.runtime.block_on(self.client.create_association(input))
+    }
+
+    /// Blocking version of [`Ssm::create_association_batch`].
+    pub fn create_association_batch(
+        &self,
+        input: CreateAssociationBatchRequest,
+    ) -> Result<CreateAssociationBatchResult, RusotoError<CreateAssociationBatchError>> {
+        self.runtime
+            .block_on(self.client.create_association_batch(input))
+    }
+
+    /// Blocking version of [`Ssm::create_document`].
+    pub fn create_document(
+        &self,
+        input: CreateDocumentRequest,
+    ) -> Result<CreateDocumentResult, RusotoError<CreateDocumentError>> {
+        self.runtime.block_on(self.client.create_document(input))
+    }
+
+    /// Blocking version of [`Ssm::create_maintenance_window`].
+    pub fn create_maintenance_window(
+        &self,
+        input: CreateMaintenanceWindowRequest,
+    ) -> Result<CreateMaintenanceWindowResult, RusotoError<CreateMaintenanceWindowError>> {
+        self.runtime
+            .block_on(self.client.create_maintenance_window(input))
+    }
+
+    /// Blocking version of [`Ssm::create_ops_item`].
+    pub fn create_ops_item(
+        &self,
+        input: CreateOpsItemRequest,
+    ) -> Result<CreateOpsItemResponse, RusotoError<CreateOpsItemError>> {
+        self.runtime.block_on(self.client.create_ops_item(input))
+    }
+
+    /// Blocking version of [`Ssm::create_ops_metadata`].
+    pub fn create_ops_metadata(
+        &self,
+        input: CreateOpsMetadataRequest,
+    ) -> Result<CreateOpsMetadataResult, RusotoError<CreateOpsMetadataError>> {
+        self.runtime
+            .block_on(self.client.create_ops_metadata(input))
+    }
+
+    /// Blocking version of [`Ssm::create_patch_baseline`].
+    pub fn create_patch_baseline(
+        &self,
+        input: CreatePatchBaselineRequest,
+    ) -> Result<CreatePatchBaselineResult, RusotoError<CreatePatchBaselineError>> {
+        self.runtime
+            .block_on(self.client.create_patch_baseline(input))
+    }
+
+    /// Blocking version of [`Ssm::create_resource_data_sync`].
+    pub fn create_resource_data_sync(
+        &self,
+        input: CreateResourceDataSyncRequest,
+    ) -> Result<CreateResourceDataSyncResult, RusotoError<CreateResourceDataSyncError>> {
+        self.runtime
+            .block_on(self.client.create_resource_data_sync(input))
+    }
+
+    /// Blocking version of [`Ssm::delete_activation`].
+    pub fn delete_activation(
+        &self,
+        input: DeleteActivationRequest,
+    ) -> Result<DeleteActivationResult, RusotoError<DeleteActivationError>> {
+        self.runtime.block_on(self.client.delete_activation(input))
+    }
+
+    /// Blocking version of [`Ssm::delete_association`].
+    pub fn delete_association(
+        &self,
+        input: DeleteAssociationRequest,
+    ) -> Result<DeleteAssociationResult, RusotoError<DeleteAssociationError>> {
+        self.runtime.block_on(self.client.delete_association(input))
+    }
+
+    /// Blocking version of [`Ssm::delete_document`].
+    pub fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> Result<DeleteDocumentResult, RusotoError<DeleteDocumentError>> {
+        self.runtime.block_on(self.client.delete_document(input))
+    }
+
+    /// Blocking version of [`Ssm::delete_inventory`].
+    pub fn delete_inventory(
+        &self,
+        input: DeleteInventoryRequest,
+    ) -> Result<DeleteInventoryResult, RusotoError<DeleteInventoryError>> {
+        self.runtime.block_on(self.client.delete_inventory(input))
+    }
+
+    /// Blocking version of [`Ssm::delete_maintenance_window`].
+    pub fn delete_maintenance_window(
+        &self,
+        input: DeleteMaintenanceWindowRequest,
+    ) -> Result<DeleteMaintenanceWindowResult, RusotoError<DeleteMaintenanceWindowError>> {
+        self.runtime
+            .block_on(self.client.delete_maintenance_window(input))
+    }
+
+    /// Blocking version of [`Ssm::delete_ops_metadata`].
+    pub fn delete_ops_metadata(
+        &self,
+        input: DeleteOpsMetadataRequest,
+    ) -> Result<DeleteOpsMetadataResult, RusotoError<DeleteOpsMetadataError>> {
+        self.runtime
+            .block_on(self.client.delete_ops_metadata(input))
+    }
+
+    /// Blocking version of [`Ssm::delete_parameter`].
+    pub fn delete_parameter(
+        &self,
+        input: DeleteParameterRequest,
+    ) -> Result<DeleteParameterResult, RusotoError<DeleteParameterError>> {
+        self.runtime.block_on(self.client.delete_parameter(input))
+    }
+
+    /// Blocking version of [`Ssm::delete_parameters`].
+    pub fn delete_parameters(
+        &self,
+        input: DeleteParametersRequest,
+    ) -> Result<DeleteParametersResult, RusotoError<DeleteParametersError>> {
+        self.runtime.block_on(self.client.delete_parameters(input))
+    }
+
+    /// Blocking version of [`Ssm::delete_patch_baseline`].
+    pub fn delete_patch_baseline(
+        &self,
+        input: DeletePatchBaselineRequest,
+    ) -> Result<DeletePatchBaselineResult, RusotoError<DeletePatchBaselineError>> {
+        self.runtime
+            .block_on(self.client.delete_patch_baseline(input))
+    }
+
+    /// Blocking version of [`Ssm::delete_resource_data_sync`].
+    pub fn delete_resource_data_sync(
+        &self,
+        input: DeleteResourceDataSyncRequest,
+    ) -> Result<DeleteResourceDataSyncResult, RusotoError<DeleteResourceDataSyncError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_data_sync(input))
+    }
+
+    /// Blocking version of [`Ssm::deregister_managed_instance`].
+    pub fn deregister_managed_instance(
+        &self,
+        input: DeregisterManagedInstanceRequest,
+    ) -> Result<DeregisterManagedInstanceResult, RusotoError<DeregisterManagedInstanceError>> {
+        self.runtime
+            .block_on(self.client.deregister_managed_instance(input))
+    }
+
+    /// Blocking version of [`Ssm::deregister_patch_baseline_for_patch_group`].
+    pub fn deregister_patch_baseline_for_patch_group(
+        &self,
+        input: DeregisterPatchBaselineForPatchGroupRequest,
+    ) -> Result<
+        DeregisterPatchBaselineForPatchGroupResult,
+        RusotoError<DeregisterPatchBaselineForPatchGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.deregister_patch_baseline_for_patch_group(input))
+    }
+
+    /// Blocking version of [`Ssm::deregister_target_from_maintenance_window`].
+    pub fn deregister_target_from_maintenance_window(
+        &self,
+        input: DeregisterTargetFromMaintenanceWindowRequest,
+    ) -> Result<
+        DeregisterTargetFromMaintenanceWindowResult,
+        RusotoError<DeregisterTargetFromMaintenanceWindowError>,
+    > {
+        self.runtime
+            .block_on(self.client.deregister_target_from_maintenance_window(input))
+    }
+
+    /// Blocking version of [`Ssm::deregister_task_from_maintenance_window`].
+    pub fn deregister_task_from_maintenance_window(
+        &self,
+        input: DeregisterTaskFromMaintenanceWindowRequest,
+    ) -> Result<
+        DeregisterTaskFromMaintenanceWindowResult,
+        RusotoError<DeregisterTaskFromMaintenanceWindowError>,
+    > {
+        self.runtime
+            .block_on(self.client.deregister_task_from_maintenance_window(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_activations`].
+    pub fn describe_activations(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> Result<DescribeActivationsResult, RusotoError<DescribeActivationsError>> {
+        self.runtime
+            .block_on(self.client.describe_activations(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_association`].
+    pub fn describe_association(
+        &self,
+        input: DescribeAssociationRequest,
+    ) -> Result<DescribeAssociationResult, RusotoError<DescribeAssociationError>> {
+        self.runtime
+            .block_on(self.client.describe_association(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_association_execution_targets`].
+    pub fn describe_association_execution_targets(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> Result<
+        DescribeAssociationExecutionTargetsResult,
+        RusotoError<DescribeAssociationExecutionTargetsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_association_execution_targets(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_association_executions`].
+    pub fn describe_association_executions(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> Result<DescribeAssociationExecutionsResult, RusotoError<DescribeAssociationExecutionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_association_executions(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_automation_executions`].
+    pub fn describe_automation_executions(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> Result<DescribeAutomationExecutionsResult, RusotoError<DescribeAutomationExecutionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_automation_executions(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_automation_step_executions`].
+    pub fn describe_automation_step_executions(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> Result<
+        DescribeAutomationStepExecutionsResult,
+        RusotoError<DescribeAutomationStepExecutionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_automation_step_executions(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_available_patches`].
+    pub fn describe_available_patches(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> Result<DescribeAvailablePatchesResult, RusotoError<DescribeAvailablePatchesError>> {
+        self.runtime
+            .block_on(self.client.describe_available_patches(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_document`].
+    pub fn describe_document(
+        &self,
+        input: DescribeDocumentRequest,
+    ) -> Result<DescribeDocumentResult, RusotoError<DescribeDocumentError>> {
+        self.runtime.block_on(self.client.describe_document(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_document_permission`].
+    pub fn describe_document_permission(
+        &self,
+        input: DescribeDocumentPermissionRequest,
+    ) -> Result<DescribeDocumentPermissionResponse, RusotoError<DescribeDocumentPermissionError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_document_permission(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_effective_instance_associations`].
+    pub fn describe_effective_instance_associations(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> Result<
+        DescribeEffectiveInstanceAssociationsResult,
+        RusotoError<DescribeEffectiveInstanceAssociationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_effective_instance_associations(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_effective_patches_for_patch_baseline`].
+    pub fn describe_effective_patches_for_patch_baseline(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> Result<
+        DescribeEffectivePatchesForPatchBaselineResult,
+        RusotoError<DescribeEffectivePatchesForPatchBaselineError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_effective_patches_for_patch_baseline(input),
+        )
+    }
+
+    /// Blocking version of [`Ssm::describe_instance_associations_status`].
+    pub fn describe_instance_associations_status(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> Result<
+        DescribeInstanceAssociationsStatusResult,
+        RusotoError<DescribeInstanceAssociationsStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_instance_associations_status(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_instance_information`].
+    pub fn describe_instance_information(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> Result<DescribeInstanceInformationResult, RusotoError<DescribeInstanceInformationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_instance_information(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_instance_patch_states`].
+    pub fn describe_instance_patch_states(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> Result<DescribeInstancePatchStatesResult, RusotoError<DescribeInstancePatchStatesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_instance_patch_states(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_instance_patch_states_for_patch_group`].
+    pub fn describe_instance_patch_states_for_patch_group(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> Result<
+        DescribeInstancePatchStatesForPatchGroupResult,
+        RusotoError<DescribeInstancePatchStatesForPatchGroupError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_instance_patch_states_for_patch_group(input),
+        )
+    }
+
+    /// Blocking version of [`Ssm::describe_instance_patches`].
+    pub fn describe_instance_patches(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> Result<DescribeInstancePatchesResult, RusotoError<DescribeInstancePatchesError>> {
+        self.runtime
+            .block_on(self.client.describe_instance_patches(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_inventory_deletions`].
+    pub fn describe_inventory_deletions(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> Result<DescribeInventoryDeletionsResult, RusotoError<DescribeInventoryDeletionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_inventory_deletions(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_maintenance_window_execution_task_invocations`].
+    pub fn describe_maintenance_window_execution_task_invocations(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+        RusotoError<DescribeMaintenanceWindowExecutionTaskInvocationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_maintenance_window_execution_task_invocations(input),
+        )
+    }
+
+    /// Blocking version of [`Ssm::describe_maintenance_window_execution_tasks`].
+    pub fn describe_maintenance_window_execution_tasks(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowExecutionTasksResult,
+        RusotoError<DescribeMaintenanceWindowExecutionTasksError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_maintenance_window_execution_tasks(input),
+        )
+    }
+
+    /// Blocking version of [`Ssm::describe_maintenance_window_executions`].
+    pub fn describe_maintenance_window_executions(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowExecutionsResult,
+        RusotoError<DescribeMaintenanceWindowExecutionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_maintenance_window_executions(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_maintenance_window_schedule`].
+    pub fn describe_maintenance_window_schedule(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowScheduleResult,
+        RusotoError<DescribeMaintenanceWindowScheduleError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_maintenance_window_schedule(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_maintenance_window_targets`].
+    pub fn describe_maintenance_window_targets(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowTargetsResult,
+        RusotoError<DescribeMaintenanceWindowTargetsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_maintenance_window_targets(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_maintenance_window_tasks`].
+    pub fn describe_maintenance_window_tasks(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowTasksResult,
+        RusotoError<DescribeMaintenanceWindowTasksError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_maintenance_window_tasks(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_maintenance_windows`].
+    pub fn describe_maintenance_windows(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> Result<DescribeMaintenanceWindowsResult, RusotoError<DescribeMaintenanceWindowsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_maintenance_windows(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_maintenance_windows_for_target`].
+    pub fn describe_maintenance_windows_for_target(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowsForTargetResult,
+        RusotoError<DescribeMaintenanceWindowsForTargetError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_maintenance_windows_for_target(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_ops_items`].
+    pub fn describe_ops_items(
+        &self,
+        input: DescribeOpsItemsRequest,
+    ) -> Result<DescribeOpsItemsResponse, RusotoError<DescribeOpsItemsError>> {
+        self.runtime.block_on(self.client.describe_ops_items(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_parameters`].
+    pub fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> Result<DescribeParametersResult, RusotoError<DescribeParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_parameters(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_patch_baselines`].
+    pub fn describe_patch_baselines(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> Result<DescribePatchBaselinesResult, RusotoError<DescribePatchBaselinesError>> {
+        self.runtime
+            .block_on(self.client.describe_patch_baselines(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_patch_group_state`].
+    pub fn describe_patch_group_state(
+        &self,
+        input: DescribePatchGroupStateRequest,
+    ) -> Result<DescribePatchGroupStateResult, RusotoError<DescribePatchGroupStateError>> {
+        self.runtime
+            .block_on(self.client.describe_patch_group_state(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_patch_groups`].
+    pub fn describe_patch_groups(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> Result<DescribePatchGroupsResult, RusotoError<DescribePatchGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_patch_groups(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_patch_properties`].
+    pub fn describe_patch_properties(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> Result<DescribePatchPropertiesResult, RusotoError<DescribePatchPropertiesError>> {
+        self.runtime
+            .block_on(self.client.describe_patch_properties(input))
+    }
+
+    /// Blocking version of [`Ssm::describe_sessions`].
+    pub fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> Result<DescribeSessionsResponse, RusotoError<DescribeSessionsError>> {
+        self.runtime.block_on(self.client.describe_sessions(input))
+    }
+
+    /// Blocking version of [`Ssm::get_automation_execution`].
+    pub fn get_automation_execution(
+        &self,
+        input: GetAutomationExecutionRequest,
+    ) -> Result<GetAutomationExecutionResult, RusotoError<GetAutomationExecutionError>> {
+        self.runtime
+            .block_on(self.client.get_automation_execution(input))
+    }
+
+    /// Blocking version of [`Ssm::get_calendar_state`].
+    pub fn get_calendar_state(
+        &self,
+        input: GetCalendarStateRequest,
+    ) -> Result<GetCalendarStateResponse, RusotoError<GetCalendarStateError>> {
+        self.runtime.block_on(self.client.get_calendar_state(input))
+    }
+
+    /// Blocking version of [`Ssm::get_command_invocation`].
+    pub fn get_command_invocation(
+        &self,
+        input: GetCommandInvocationRequest,
+    ) -> Result<GetCommandInvocationResult, RusotoError<GetCommandInvocationError>> {
+        self.runtime
+            .block_on(self.client.get_command_invocation(input))
+    }
+
+    /// Blocking version of [`Ssm::get_connection_status`].
+    pub fn get_connection_status(
+        &self,
+        input: GetConnectionStatusRequest,
+    ) -> Result<GetConnectionStatusResponse, RusotoError<GetConnectionStatusError>> {
+        self.runtime
+            .block_on(self.client.get_connection_status(input))
+    }
+
+    /// Blocking version of [`Ssm::get_default_patch_baseline`].
+    pub fn get_default_patch_baseline(
+        &self,
+        input: GetDefaultPatchBaselineRequest,
+    ) -> Result<GetDefaultPatchBaselineResult, RusotoError<GetDefaultPatchBaselineError>> {
+        self.runtime
+            .block_on(self.client.get_default_patch_baseline(input))
+    }
+
+    /// Blocking version of [`Ssm::get_deployable_patch_snapshot_for_instance`].
+    pub fn get_deployable_patch_snapshot_for_instance(
+        &self,
+        input: GetDeployablePatchSnapshotForInstanceRequest,
+    ) -> Result<
+        GetDeployablePatchSnapshotForInstanceResult,
+        RusotoError<GetDeployablePatchSnapshotForInstanceError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_deployable_patch_snapshot_for_instance(input),
+        )
+    }
+
+    /// Blocking version of [`Ssm::get_document`].
+    pub fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> Result<GetDocumentResult, RusotoError<GetDocumentError>> {
+        self.runtime.block_on(self.client.get_document(input))
+    }
+
+    /// Blocking version of [`Ssm::get_inventory`].
+    pub fn get_inventory(
+        &self,
+        input: GetInventoryRequest,
+    ) -> Result<GetInventoryResult, RusotoError<GetInventoryError>> {
+        self.runtime.block_on(self.client.get_inventory(input))
+    }
+
+    /// Blocking version of [`Ssm::get_inventory_schema`].
+    pub fn get_inventory_schema(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> Result<GetInventorySchemaResult, RusotoError<GetInventorySchemaError>> {
+        self.runtime
+            .block_on(self.client.get_inventory_schema(input))
+    }
+
+    /// Blocking version of [`Ssm::get_maintenance_window`].
+    pub fn get_maintenance_window(
+        &self,
+        input: GetMaintenanceWindowRequest,
+    ) -> Result<GetMaintenanceWindowResult, RusotoError<GetMaintenanceWindowError>> {
+        self.runtime
+            .block_on(self.client.get_maintenance_window(input))
+    }
+
+    /// Blocking version of [`Ssm::get_maintenance_window_execution`].
+    pub fn get_maintenance_window_execution(
+        &self,
+        input: GetMaintenanceWindowExecutionRequest,
+    ) -> Result<GetMaintenanceWindowExecutionResult, RusotoError<GetMaintenanceWindowExecutionError>>
+    {
+        self.runtime
+            .block_on(self.client.get_maintenance_window_execution(input))
+    }
+
+    /// Blocking version of [`Ssm::get_maintenance_window_execution_task`].
+    pub fn get_maintenance_window_execution_task(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskRequest,
+    ) -> Result<
+        GetMaintenanceWindowExecutionTaskResult,
+        RusotoError<GetMaintenanceWindowExecutionTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_maintenance_window_execution_task(input))
+    }
+
+    /// Blocking version of [`Ssm::get_maintenance_window_execution_task_invocation`].
+    pub fn get_maintenance_window_execution_task_invocation(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskInvocationRequest,
+    ) -> Result<
+        GetMaintenanceWindowExecutionTaskInvocationResult,
+        RusotoError<GetMaintenanceWindowExecutionTaskInvocationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_maintenance_window_execution_task_invocation(input),
+        )
+    }
+
+    /// Blocking version of [`Ssm::get_maintenance_window_task`].
+    pub fn get_maintenance_window_task(
+        &self,
+        input: GetMaintenanceWindowTaskRequest,
+    ) -> Result<GetMaintenanceWindowTaskResult, RusotoError<GetMaintenanceWindowTaskError>> {
+        self.runtime
+            .block_on(self.client.get_maintenance_window_task(input))
+    }
+
+    /// Blocking version of [`Ssm::get_ops_item`].
+    pub fn get_ops_item(
+        &self,
+        input: GetOpsItemRequest,
+    ) -> Result<GetOpsItemResponse, RusotoError<GetOpsItemError>> {
+        self.runtime.block_on(self.client.get_ops_item(input))
+    }
+
+    /// Blocking version of [`Ssm::get_ops_metadata`].
+    pub fn get_ops_metadata(
+        &self,
+        input: GetOpsMetadataRequest,
+    ) -> Result<GetOpsMetadataResult, RusotoError<GetOpsMetadataError>> {
+        self.runtime.block_on(self.client.get_ops_metadata(input))
+    }
+
+    /// Blocking version of [`Ssm::get_ops_summary`].
+    pub fn get_ops_summary(
+        &self,
+        input: GetOpsSummaryRequest,
+    ) -> Result<GetOpsSummaryResult, RusotoError<GetOpsSummaryError>> {
+        self.runtime.block_on(self.client.get_ops_summary(input))
+    }
+
+    /// Blocking version of [`Ssm::get_parameter`].
+    pub fn get_parameter(
+        &self,
+        input: GetParameterRequest,
+    ) -> Result<GetParameterResult, RusotoError<GetParameterError>> {
+        self.runtime.block_on(self.client.get_parameter(input))
+    }
+
+    /// Blocking version of [`Ssm::get_parameter_history`].
+    pub fn get_parameter_history(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> Result<GetParameterHistoryResult, RusotoError<GetParameterHistoryError>> {
+        self.runtime
+            .block_on(self.client.get_parameter_history(input))
+    }
+
+    /// Blocking version of [`Ssm::get_parameters`].
+    pub fn get_parameters(
+        &self,
+        input: GetParametersRequest,
+    ) -> Result<GetParametersResult, RusotoError<GetParametersError>> {
+        self.runtime.block_on(self.client.get_parameters(input))
+    }
+
+    /// Blocking version of [`Ssm::get_parameters_by_path`].
+    pub fn get_parameters_by_path(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> Result<GetParametersByPathResult, RusotoError<GetParametersByPathError>> {
+        self.runtime
+            .block_on(self.client.get_parameters_by_path(input))
+    }
+
+    /// Blocking version of [`Ssm::get_patch_baseline`].
+    pub fn get_patch_baseline(
+        &self,
+        input: GetPatchBaselineRequest,
+    ) -> Result<GetPatchBaselineResult, RusotoError<GetPatchBaselineError>> {
+        self.runtime.block_on(self.client.get_patch_baseline(input))
+    }
+
+    /// Blocking version of [`Ssm::get_patch_baseline_for_patch_group`].
+    pub fn get_patch_baseline_for_patch_group(
+        &self,
+        input: GetPatchBaselineForPatchGroupRequest,
+    ) -> Result<GetPatchBaselineForPatchGroupResult, RusotoError<GetPatchBaselineForPatchGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.get_patch_baseline_for_patch_group(input))
+    }
+
+    /// Blocking version of [`Ssm::get_service_setting`].
+    pub fn get_service_setting(
+        &self,
+        input: GetServiceSettingRequest,
+    ) -> Result<GetServiceSettingResult, RusotoError<GetServiceSettingError>> {
+        self.runtime
+            .block_on(self.client.get_service_setting(input))
+    }
+
+    /// Blocking version of [`Ssm::label_parameter_version`].
+    pub fn label_parameter_version(
+        &self,
+        input: LabelParameterVersionRequest,
+    ) -> Result<LabelParameterVersionResult, RusotoError<LabelParameterVersionError>> {
+        self.runtime
+            .block_on(self.client.label_parameter_version(input))
+    }
+
+    /// Blocking version of [`Ssm::list_association_versions`].
+    pub fn list_association_versions(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> Result<ListAssociationVersionsResult, RusotoError<ListAssociationVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_association_versions(input))
+    }
+
+    /// Blocking version of [`Ssm::list_associations`].
+    pub fn list_associations(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> Result<ListAssociationsResult, RusotoError<ListAssociationsError>> {
+        self.runtime.block_on(self.client.list_associations(input))
+    }
+
+    /// Blocking version of [`Ssm::list_command_invocations`].
+    pub fn list_command_invocations(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> Result<ListCommandInvocationsResult, RusotoError<ListCommandInvocationsError>> {
+        self.runtime
+            .block_on(self.client.list_command_invocations(input))
+    }
+
+    /// Blocking version of [`Ssm::list_commands`].
+    pub fn list_commands(
+        &self,
+        input: ListCommandsRequest,
+    ) -> Result<ListCommandsResult, RusotoError<ListCommandsError>> {
+        self.runtime.block_on(self.client.list_commands(input))
+    }
+
+    /// Blocking version of [`Ssm::list_compliance_items`].
+    pub fn list_compliance_items(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> Result<ListComplianceItemsResult, RusotoError<ListComplianceItemsError>> {
+        self.runtime
+            .block_on(self.client.list_compliance_items(input))
+    }
+
+    /// Blocking version of [`Ssm::list_compliance_summaries`].
+    pub fn list_compliance_summaries(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> Result<ListComplianceSummariesResult, RusotoError<ListComplianceSummariesError>> {
+        self.runtime
+            .block_on(self.client.list_compliance_summaries(input))
+    }
+
+    /// Blocking version of [`Ssm::list_document_metadata_history`].
+    pub fn list_document_metadata_history(
+        &self,
+        input: ListDocumentMetadataHistoryRequest,
+    ) -> Result<ListDocumentMetadataHistoryResponse, RusotoError<ListDocumentMetadataHistoryError>>
+    {
+        self.runtime
+            .block_on(self.client.list_document_metadata_history(input))
+    }
+
+    /// Blocking version of [`Ssm::list_document_versions`].
+    pub fn list_document_versions(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> Result<ListDocumentVersionsResult, RusotoError<ListDocumentVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_document_versions(input))
+    }
+
+    /// Blocking version of [`Ssm::list_documents`].
+    pub fn list_documents(
+        &self,
+        input: ListDocumentsRequest,
+    ) -> Result<ListDocumentsResult, RusotoError<ListDocumentsError>> {
+        self.runtime.block_on(self.client.list_documents(input))
+    }
+
+    /// Blocking version of [`Ssm::list_inventory_entries`].
+    pub fn list_inventory_entries(
+        &self,
+        input: ListInventoryEntriesRequest,
+    ) -> Result<ListInventoryEntriesResult, RusotoError<ListInventoryEntriesError>> {
+        self.runtime
+            .block_on(self.client.list_inventory_entries(input))
+    }
+
+    /// Blocking version of [`Ssm::list_ops_item_events`].
+    pub fn list_ops_item_events(
+        &self,
+        input: ListOpsItemEventsRequest,
+    ) -> Result<ListOpsItemEventsResponse, RusotoError<ListOpsItemEventsError>> {
+        self.runtime
+            .block_on(self.client.list_ops_item_events(input))
+    }
+
+    /// Blocking version of [`Ssm::list_ops_metadata`].
+    pub fn list_ops_metadata(
+        &self,
+        input: ListOpsMetadataRequest,
+    ) -> Result<ListOpsMetadataResult, RusotoError<ListOpsMetadataError>> {
+        self.runtime.block_on(self.client.list_ops_metadata(input))
+    }
+
+    /// Blocking version of [`Ssm::list_resource_compliance_summaries`].
+    pub fn list_resource_compliance_summaries(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> Result<
+        ListResourceComplianceSummariesResult,
+        RusotoError<ListResourceComplianceSummariesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_resource_compliance_summaries(input))
+    }
+
+    /// Blocking version of [`Ssm::list_resource_data_sync`].
+    pub fn list_resource_data_sync(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> Result<ListResourceDataSyncResult, RusotoError<ListResourceDataSyncError>> {
+        self.runtime
+            .block_on(self.client.list_resource_data_sync(input))
+    }
+
+    /// Blocking version of [`Ssm::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Ssm::modify_document_permission`].
+    pub fn modify_document_permission(
+        &self,
+        input: ModifyDocumentPermissionRequest,
+    ) -> Result<ModifyDocumentPermissionResponse, RusotoError<ModifyDocumentPermissionError>> {
+        self.runtime
+            .block_on(self.client.modify_document_permission(input))
+    }
+
+    /// Blocking version of [`Ssm::put_compliance_items`].
+    pub fn put_compliance_items(
+        &self,
+        input: PutComplianceItemsRequest,
+    ) -> Result<PutComplianceItemsResult, RusotoError<PutComplianceItemsError>> {
+        self.runtime
+            .block_on(self.client.put_compliance_items(input))
+    }
+
+    /// Blocking version of [`Ssm::put_inventory`].
+    pub fn put_inventory(
+        &self,
+        input: PutInventoryRequest,
+    ) -> Result<PutInventoryResult, RusotoError<PutInventoryError>> {
+        self.runtime.block_on(self.client.put_inventory(input))
+    }
+
+    /// Blocking version of [`Ssm::put_parameter`].
+    pub fn put_parameter(
+        &self,
+        input: PutParameterRequest,
+    ) -> Result<PutParameterResult, RusotoError<PutParameterError>> {
+        self.runtime.block_on(self.client.put_parameter(input))
+    }
+
+    /// Blocking version of [`Ssm::register_default_patch_baseline`].
+    pub fn register_default_patch_baseline(
+        &self,
+        input: RegisterDefaultPatchBaselineRequest,
+    ) -> Result<RegisterDefaultPatchBaselineResult, RusotoError<RegisterDefaultPatchBaselineError>>
+    {
+        self.runtime
+            .block_on(self.client.register_default_patch_baseline(input))
+    }
+
+    /// Blocking version of [`Ssm::register_patch_baseline_for_patch_group`].
+    pub fn register_patch_baseline_for_patch_group(
+        &self,
+        input: RegisterPatchBaselineForPatchGroupRequest,
+    ) -> Result<
+        RegisterPatchBaselineForPatchGroupResult,
+        RusotoError<RegisterPatchBaselineForPatchGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.register_patch_baseline_for_patch_group(input))
+    }
+
+    /// Blocking version of [`Ssm::register_target_with_maintenance_window`].
+    pub fn register_target_with_maintenance_window(
+        &self,
+        input: RegisterTargetWithMaintenanceWindowRequest,
+    ) -> Result<
+        RegisterTargetWithMaintenanceWindowResult,
+        RusotoError<RegisterTargetWithMaintenanceWindowError>,
+    > {
+        self.runtime
+            .block_on(self.client.register_target_with_maintenance_window(input))
+    }
+
+    /// Blocking version of [`Ssm::register_task_with_maintenance_window`].
+    pub fn register_task_with_maintenance_window(
+        &self,
+        input: RegisterTaskWithMaintenanceWindowRequest,
+    ) -> Result<
+        RegisterTaskWithMaintenanceWindowResult,
+        RusotoError<RegisterTaskWithMaintenanceWindowError>,
+    > {
+        self.runtime
+            .block_on(self.client.register_task_with_maintenance_window(input))
+    }
+
+    /// Blocking version of [`Ssm::remove_tags_from_resource`].
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// Blocking version of [`Ssm::reset_service_setting`].
+    pub fn reset_service_setting(
+        &self,
+        input: ResetServiceSettingRequest,
+    ) -> Result<ResetServiceSettingResult, RusotoError<ResetServiceSettingError>> {
+        self.runtime
+            .block_on(self.client.reset_service_setting(input))
+    }
+
+    /// Blocking version of [`Ssm::resume_session`].
+    pub fn resume_session(
+        &self,
+        input: ResumeSessionRequest,
+    ) -> Result<ResumeSessionResponse, RusotoError<ResumeSessionError>> {
+        self.runtime.block_on(self.client.resume_session(input))
+    }
+
+    /// Blocking version of [`Ssm::send_automation_signal`].
+    pub fn send_automation_signal(
+        &self,
+        input: SendAutomationSignalRequest,
+    ) -> Result<SendAutomationSignalResult, RusotoError<SendAutomationSignalError>> {
+        self.runtime
+            .block_on(self.client.send_automation_signal(input))
+    }
+
+    /// Blocking version of [`Ssm::send_command`].
+    pub fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
+        self.runtime.block_on(self.client.send_command(input))
+    }
+
+    /// Blocking version of [`Ssm::start_associations_once`].
+    pub fn start_associations_once(
+        &self,
+        input: StartAssociationsOnceRequest,
+    ) -> Result<StartAssociationsOnceResult, RusotoError<StartAssociationsOnceError>> {
+        self.runtime
+            .block_on(self.client.start_associations_once(input))
+    }
+
+    /// Blocking version of [`Ssm::start_automation_execution`].
+    pub fn start_automation_execution(
+        &self,
+        input: StartAutomationExecutionRequest,
+    ) -> Result<StartAutomationExecutionResult, RusotoError<StartAutomationExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_automation_execution(input))
+    }
+
+    /// Blocking version of [`Ssm::start_change_request_execution`].
+    pub fn start_change_request_execution(
+        &self,
+        input: StartChangeRequestExecutionRequest,
+    ) -> Result<StartChangeRequestExecutionResult, RusotoError<StartChangeRequestExecutionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_change_request_execution(input))
+    }
+
+    /// Blocking version of [`Ssm::start_session`].
+    pub fn start_session(
+        &self,
+        input: StartSessionRequest,
+    ) -> Result<StartSessionResponse, RusotoError<StartSessionError>> {
+        self.runtime.block_on(self.client.start_session(input))
+    }
+
+    /// Blocking version of [`Ssm::stop_automation_execution`].
+    pub fn stop_automation_execution(
+        &self,
+        input: StopAutomationExecutionRequest,
+    ) -> Result<StopAutomationExecutionResult, RusotoError<StopAutomationExecutionError>> {
+        self.runtime
+            .block_on(self.client.stop_automation_execution(input))
+    }
+
+    /// Blocking version of [`Ssm::terminate_session`].
+    pub fn terminate_session(
+        &self,
+        input: TerminateSessionRequest,
+    ) -> Result<TerminateSessionResponse, RusotoError<TerminateSessionError>> {
+        self.runtime.block_on(self.client.terminate_session(input))
+    }
+
+    /// Blocking version of [`Ssm::update_association`].
+    pub fn update_association(
+        &self,
+        input: UpdateAssociationRequest,
+    ) -> Result<UpdateAssociationResult, RusotoError<UpdateAssociationError>> {
+        self.runtime.block_on(self.client.update_association(input))
+    }
+
+    /// Blocking version of [`Ssm::update_association_status`].
+    pub fn update_association_status(
+        &self,
+        input: UpdateAssociationStatusRequest,
+    ) -> Result<UpdateAssociationStatusResult, RusotoError<UpdateAssociationStatusError>> {
+        self.runtime
+            .block_on(self.client.update_association_status(input))
+    }
+
+    /// Blocking version of [`Ssm::update_document`].
+    pub fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> Result<UpdateDocumentResult, RusotoError<UpdateDocumentError>> {
+        self.runtime.block_on(self.client.update_document(input))
+    }
+
+    /// Blocking version of [`Ssm::update_document_default_version`].
+    pub fn update_document_default_version(
+        &self,
+        input: UpdateDocumentDefaultVersionRequest,
+    ) -> Result<UpdateDocumentDefaultVersionResult, RusotoError<UpdateDocumentDefaultVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_document_default_version(input))
+    }
+
+    /// Blocking version of [`Ssm::update_document_metadata`].
+    pub fn update_document_metadata(
+        &self,
+        input: UpdateDocumentMetadataRequest,
+    ) -> Result<UpdateDocumentMetadataResponse, RusotoError<UpdateDocumentMetadataError>> {
+        self.runtime
+            .block_on(self.client.update_document_metadata(input))
+    }
+
+    /// Blocking version of [`Ssm::update_maintenance_window`].
+    pub fn update_maintenance_window(
+        &self,
+        input: UpdateMaintenanceWindowRequest,
+    ) -> Result<UpdateMaintenanceWindowResult, RusotoError<UpdateMaintenanceWindowError>> {
+        self.runtime
+            .block_on(self.client.update_maintenance_window(input))
+    }
+
+    /// Blocking version of [`Ssm::update_maintenance_window_target`].
+    pub fn update_maintenance_window_target(
+        &self,
+        input: UpdateMaintenanceWindowTargetRequest,
+    ) -> Result<UpdateMaintenanceWindowTargetResult, RusotoError<UpdateMaintenanceWindowTargetError>>
+    {
+        self.runtime
+            .block_on(self.client.update_maintenance_window_target(input))
+    }
+
+    /// Blocking version of [`Ssm::update_maintenance_window_task`].
+    pub fn update_maintenance_window_task(
+        &self,
+        input: UpdateMaintenanceWindowTaskRequest,
+    ) -> Result<UpdateMaintenanceWindowTaskResult, RusotoError<UpdateMaintenanceWindowTaskError>>
+    {
+        self.runtime
+            .block_on(self.client.update_maintenance_window_task(input))
+    }
+
+    /// Blocking version of [`Ssm::update_managed_instance_role`].
+    pub fn update_managed_instance_role(
+        &self,
+        input: UpdateManagedInstanceRoleRequest,
+    ) -> Result<UpdateManagedInstanceRoleResult, RusotoError<UpdateManagedInstanceRoleError>> {
+        self.runtime
+            .block_on(self.client.update_managed_instance_role(input))
+    }
+
+    /// Blocking version of [`Ssm::update_ops_item`].
+    pub fn update_ops_item(
+        &self,
+        input: UpdateOpsItemRequest,
+    ) -> Result<UpdateOpsItemResponse, RusotoError<UpdateOpsItemError>> {
+        self.runtime.block_on(self.client.update_ops_item(input))
+    }
+
+    /// Blocking version of [`Ssm::update_ops_metadata`].
+    pub fn update_ops_metadata(
+        &self,
+        input: UpdateOpsMetadataRequest,
+    ) -> Result<UpdateOpsMetadataResult, RusotoError<UpdateOpsMetadataError>> {
+        self.runtime
+            .block_on(self.client.update_ops_metadata(input))
+    }
+
+    /// Blocking version of [`Ssm::update_patch_baseline`].
+    pub fn update_patch_baseline(
+        &self,
+        input: UpdatePatchBaselineRequest,
+    ) -> Result<UpdatePatchBaselineResult, RusotoError<UpdatePatchBaselineError>> {
+        self.runtime
+            .block_on(self.client.update_patch_baseline(input))
+    }
+
+    /// Blocking version of [`Ssm::update_resource_data_sync`].
+    pub fn update_resource_data_sync(
+        &self,
+        input: UpdateResourceDataSyncRequest,
+    ) -> Result<UpdateResourceDataSyncResult, RusotoError<UpdateResourceDataSyncError>> {
+        self.runtime
+            .block_on(self.client.update_resource_data_sync(input))
+    }
+
+    /// Blocking version of [`Ssm::update_service_setting`].
+    pub fn update_service_setting(
+        &self,
+        input: UpdateServiceSettingRequest,
+    ) -> Result<UpdateServiceSettingResult, RusotoError<UpdateServiceSettingError>> {
+        self.runtime
+            .block_on(self.client.update_service_setting(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -201,39 +202,94 @@ impl CreateTokenError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTokenError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::AccessDenied,
+                    )
                 }
                 "AuthorizationPendingException" => {
-                    return RusotoError::Service(CreateTokenError::AuthorizationPending(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::AuthorizationPending,
+                    )
                 }
                 "ExpiredTokenException" => {
-                    return RusotoError::Service(CreateTokenError::ExpiredToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::ExpiredToken,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateTokenError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::InternalServer,
+                    )
                 }
                 "InvalidClientException" => {
-                    return RusotoError::Service(CreateTokenError::InvalidClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::InvalidClient,
+                    )
                 }
                 "InvalidGrantException" => {
-                    return RusotoError::Service(CreateTokenError::InvalidGrant(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::InvalidGrant,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateTokenError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::InvalidRequest,
+                    )
                 }
                 "InvalidScopeException" => {
-                    return RusotoError::Service(CreateTokenError::InvalidScope(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::InvalidScope,
+                    )
                 }
                 "SlowDownException" => {
-                    return RusotoError::Service(CreateTokenError::SlowDown(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::SlowDown,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateTokenError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::UnauthorizedClient,
+                    )
                 }
                 "UnsupportedGrantTypeException" => {
-                    return RusotoError::Service(CreateTokenError::UnsupportedGrantType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::UnsupportedGrantType,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -277,20 +333,38 @@ impl RegisterClientError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RegisterClientError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterClientError::InternalServer,
+                    )
                 }
                 "InvalidClientMetadataException" => {
-                    return RusotoError::Service(RegisterClientError::InvalidClientMetadata(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterClientError::InvalidClientMetadata,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterClientError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterClientError::InvalidRequest,
+                    )
                 }
                 "InvalidScopeException" => {
-                    return RusotoError::Service(RegisterClientError::InvalidScope(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterClientError::InvalidScope,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -329,29 +403,46 @@ impl StartDeviceAuthorizationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDeviceAuthorizationError::InternalServer,
+                    )
                 }
                 "InvalidClientException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::InvalidClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDeviceAuthorizationError::InvalidClient,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDeviceAuthorizationError::InvalidRequest,
+                    )
                 }
                 "SlowDownException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::SlowDown(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeviceAuthorizationError::SlowDown,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDeviceAuthorizationError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -402,11 +493,12 @@ pub struct SsoOidcClient {
 impl SsoOidcClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SsoOidcClient {
         SsoOidcClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SSO OIDC"),
         }
     }
 
@@ -421,12 +513,32 @@ impl SsoOidcClient {
     {
         SsoOidcClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SSO OIDC"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SsoOidcClient {
-        SsoOidcClient { client, region }
+        SsoOidcClient {
+            client,
+            region: region_for_service(region, "SSO OIDC"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SsoOidcClient {
+        SsoOidcClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SSO OIDC"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SsoOidcClient {
+        SsoOidcClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -441,6 +553,7 @@ impl SsoOidc for SsoOidcClient {
         let request_uri = "/token";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation("CreateToken");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
@@ -473,6 +586,7 @@ impl SsoOidc for SsoOidcClient {
         let request_uri = "/client/register";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation("RegisterClient");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
@@ -505,6 +619,7 @@ impl SsoOidc for SsoOidcClient {
         let request_uri = "/device_authorization";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation("StartDeviceAuthorization");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
@@ -528,3 +643,103 @@ impl SsoOidc for SsoOidcClient {
         }
     }
 }
+
+/// A blocking client for the SSO OIDC API, running the calls of a `SsoOidcClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SsoOidcClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SsoOidcBlockingClient {
+    client: SsoOidcClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SsoOidcBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SsoOidcBlockingClient {
+        SsoOidcBlockingClient::from_client(SsoOidcClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SsoOidcBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SsoOidcBlockingClient::from_client(SsoOidcClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> SsoOidcBlockingClient {
+        SsoOidcBlockingClient::from_client(SsoOidcClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SsoOidcBlockingClient {
+        SsoOidcBlockingClient::from_client(SsoOidcClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SsoOidcClient) -> SsoOidcBlockingClient {
+        SsoOidcBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> SsoOidcBlockingClient {
+        SsoOidcBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SsoOidcBlockingClient {
+        SsoOidcBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`SsoOidc::create_token`].
+    pub fn create_token(
+        &self,
+        input: CreateTokenRequest,
+    ) -> Result<CreateTokenResponse, RusotoError<CreateTokenError>> {
+        self.runtime.block_on(self.client.create_token(input))
+    }
+
+    /// Blocking version of [`SsoOidc::register_client`].
+    pub fn register_client(
+        &self,
+        input: RegisterClientRequest,
+    ) -> Result<RegisterClientResponse, RusotoError<RegisterClientError>> {
+        self.runtime.block_on(self.client.register_client(input))
+    }
+
+    /// Blocking version of [`SsoOidc::start_device_authorization`].
+    pub fn start_device_authorization(
+        &self,
+        input: StartDeviceAuthorizationRequest,
+    ) -> Result<StartDeviceAuthorizationResponse, RusotoError<StartDeviceAuthorizationError>> {
+        self.runtime
+            .block_on(self.client.start_device_authorization(input))
+    }
+}
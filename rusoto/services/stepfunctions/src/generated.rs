@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1455,17 +1456,30 @@ impl CreateActivityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActivityLimitExceeded" => {
-                    return RusotoError::Service(CreateActivityError::ActivityLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateActivityError::ActivityLimitExceeded,
+                    )
                 }
                 "InvalidName" => {
-                    return RusotoError::Service(CreateActivityError::InvalidName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateActivityError::InvalidName,
+                    )
                 }
                 "TooManyTags" => {
-                    return RusotoError::Service(CreateActivityError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateActivityError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1513,50 +1527,86 @@ impl CreateStateMachineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(CreateStateMachineError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStateMachineError::InvalidArn,
+                    )
                 }
                 "InvalidDefinition" => {
-                    return RusotoError::Service(CreateStateMachineError::InvalidDefinition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateStateMachineError::InvalidDefinition,
+                    )
                 }
                 "InvalidLoggingConfiguration" => {
-                    return RusotoError::Service(
-                        CreateStateMachineError::InvalidLoggingConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStateMachineError::InvalidLoggingConfiguration,
                     )
                 }
                 "InvalidName" => {
-                    return RusotoError::Service(CreateStateMachineError::InvalidName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStateMachineError::InvalidName,
+                    )
                 }
                 "InvalidTracingConfiguration" => {
-                    return RusotoError::Service(
-                        CreateStateMachineError::InvalidTracingConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStateMachineError::InvalidTracingConfiguration,
                     )
                 }
                 "StateMachineAlreadyExists" => {
-                    return RusotoError::Service(
-                        CreateStateMachineError::StateMachineAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStateMachineError::StateMachineAlreadyExists,
                     )
                 }
                 "StateMachineDeleting" => {
-                    return RusotoError::Service(CreateStateMachineError::StateMachineDeleting(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateStateMachineError::StateMachineDeleting,
+                    )
                 }
                 "StateMachineLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateStateMachineError::StateMachineLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStateMachineError::StateMachineLimitExceeded,
                     )
                 }
                 "StateMachineTypeNotSupported" => {
-                    return RusotoError::Service(
-                        CreateStateMachineError::StateMachineTypeNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStateMachineError::StateMachineTypeNotSupported,
                     )
                 }
                 "TooManyTags" => {
-                    return RusotoError::Service(CreateStateMachineError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStateMachineError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1599,9 +1649,14 @@ impl DeleteActivityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(DeleteActivityError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteActivityError::InvalidArn,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1629,9 +1684,14 @@ impl DeleteStateMachineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(DeleteStateMachineError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStateMachineError::InvalidArn,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1661,14 +1721,22 @@ impl DescribeActivityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActivityDoesNotExist" => {
-                    return RusotoError::Service(DescribeActivityError::ActivityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeActivityError::ActivityDoesNotExist,
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(DescribeActivityError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeActivityError::InvalidArn,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1699,14 +1767,22 @@ impl DescribeExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionDoesNotExist" => {
-                    return RusotoError::Service(DescribeExecutionError::ExecutionDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeExecutionError::ExecutionDoesNotExist,
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(DescribeExecutionError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExecutionError::InvalidArn,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1737,14 +1813,22 @@ impl DescribeStateMachineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(DescribeStateMachineError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStateMachineError::InvalidArn,
+                    )
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(
-                        DescribeStateMachineError::StateMachineDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStateMachineError::StateMachineDoesNotExist,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1779,16 +1863,22 @@ impl DescribeStateMachineForExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionDoesNotExist" => {
-                    return RusotoError::Service(
-                        DescribeStateMachineForExecutionError::ExecutionDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStateMachineForExecutionError::ExecutionDoesNotExist,
                     )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(DescribeStateMachineForExecutionError::InvalidArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStateMachineForExecutionError::InvalidArn,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1823,19 +1913,30 @@ impl GetActivityTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActivityDoesNotExist" => {
-                    return RusotoError::Service(GetActivityTaskError::ActivityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetActivityTaskError::ActivityDoesNotExist,
+                    )
                 }
                 "ActivityWorkerLimitExceeded" => {
-                    return RusotoError::Service(GetActivityTaskError::ActivityWorkerLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetActivityTaskError::ActivityWorkerLimitExceeded,
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(GetActivityTaskError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetActivityTaskError::InvalidArn,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1869,17 +1970,30 @@ impl GetExecutionHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionDoesNotExist" => {
-                    return RusotoError::Service(GetExecutionHistoryError::ExecutionDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetExecutionHistoryError::ExecutionDoesNotExist,
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(GetExecutionHistoryError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExecutionHistoryError::InvalidArn,
+                    )
                 }
                 "InvalidToken" => {
-                    return RusotoError::Service(GetExecutionHistoryError::InvalidToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExecutionHistoryError::InvalidToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1909,9 +2023,14 @@ impl ListActivitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidToken" => {
-                    return RusotoError::Service(ListActivitiesError::InvalidToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActivitiesError::InvalidToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1945,22 +2064,38 @@ impl ListExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(ListExecutionsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListExecutionsError::InvalidArn,
+                    )
                 }
                 "InvalidToken" => {
-                    return RusotoError::Service(ListExecutionsError::InvalidToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListExecutionsError::InvalidToken,
+                    )
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(ListExecutionsError::StateMachineDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListExecutionsError::StateMachineDoesNotExist,
+                    )
                 }
                 "StateMachineTypeNotSupported" => {
-                    return RusotoError::Service(ListExecutionsError::StateMachineTypeNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListExecutionsError::StateMachineTypeNotSupported,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1991,9 +2126,14 @@ impl ListStateMachinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidToken" => {
-                    return RusotoError::Service(ListStateMachinesError::InvalidToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStateMachinesError::InvalidToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2023,14 +2163,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidArn,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2063,15 +2211,30 @@ impl SendTaskFailureError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidToken" => {
-                    return RusotoError::Service(SendTaskFailureError::InvalidToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendTaskFailureError::InvalidToken,
+                    )
                 }
                 "TaskDoesNotExist" => {
-                    return RusotoError::Service(SendTaskFailureError::TaskDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendTaskFailureError::TaskDoesNotExist,
+                    )
                 }
                 "TaskTimedOut" => {
-                    return RusotoError::Service(SendTaskFailureError::TaskTimedOut(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendTaskFailureError::TaskTimedOut,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2105,15 +2268,30 @@ impl SendTaskHeartbeatError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidToken" => {
-                    return RusotoError::Service(SendTaskHeartbeatError::InvalidToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendTaskHeartbeatError::InvalidToken,
+                    )
                 }
                 "TaskDoesNotExist" => {
-                    return RusotoError::Service(SendTaskHeartbeatError::TaskDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendTaskHeartbeatError::TaskDoesNotExist,
+                    )
                 }
                 "TaskTimedOut" => {
-                    return RusotoError::Service(SendTaskHeartbeatError::TaskTimedOut(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendTaskHeartbeatError::TaskTimedOut,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2149,18 +2327,38 @@ impl SendTaskSuccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidOutput" => {
-                    return RusotoError::Service(SendTaskSuccessError::InvalidOutput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendTaskSuccessError::InvalidOutput,
+                    )
                 }
                 "InvalidToken" => {
-                    return RusotoError::Service(SendTaskSuccessError::InvalidToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendTaskSuccessError::InvalidToken,
+                    )
                 }
                 "TaskDoesNotExist" => {
-                    return RusotoError::Service(SendTaskSuccessError::TaskDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendTaskSuccessError::TaskDoesNotExist,
+                    )
                 }
                 "TaskTimedOut" => {
-                    return RusotoError::Service(SendTaskSuccessError::TaskTimedOut(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendTaskSuccessError::TaskTimedOut,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2203,35 +2401,62 @@ impl StartExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionAlreadyExists" => {
-                    return RusotoError::Service(StartExecutionError::ExecutionAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartExecutionError::ExecutionAlreadyExists,
+                    )
                 }
                 "ExecutionLimitExceeded" => {
-                    return RusotoError::Service(StartExecutionError::ExecutionLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartExecutionError::ExecutionLimitExceeded,
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(StartExecutionError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartExecutionError::InvalidArn,
+                    )
                 }
                 "InvalidExecutionInput" => {
-                    return RusotoError::Service(StartExecutionError::InvalidExecutionInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartExecutionError::InvalidExecutionInput,
+                    )
                 }
                 "InvalidName" => {
-                    return RusotoError::Service(StartExecutionError::InvalidName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartExecutionError::InvalidName,
+                    )
                 }
                 "StateMachineDeleting" => {
-                    return RusotoError::Service(StartExecutionError::StateMachineDeleting(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartExecutionError::StateMachineDeleting,
+                    )
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(StartExecutionError::StateMachineDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartExecutionError::StateMachineDoesNotExist,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2275,32 +2500,54 @@ impl StartSyncExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(StartSyncExecutionError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSyncExecutionError::InvalidArn,
+                    )
                 }
                 "InvalidExecutionInput" => {
-                    return RusotoError::Service(StartSyncExecutionError::InvalidExecutionInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSyncExecutionError::InvalidExecutionInput,
+                    )
                 }
                 "InvalidName" => {
-                    return RusotoError::Service(StartSyncExecutionError::InvalidName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSyncExecutionError::InvalidName,
+                    )
                 }
                 "StateMachineDeleting" => {
-                    return RusotoError::Service(StartSyncExecutionError::StateMachineDeleting(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSyncExecutionError::StateMachineDeleting,
+                    )
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(StartSyncExecutionError::StateMachineDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSyncExecutionError::StateMachineDoesNotExist,
+                    )
                 }
                 "StateMachineTypeNotSupported" => {
-                    return RusotoError::Service(
-                        StartSyncExecutionError::StateMachineTypeNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSyncExecutionError::StateMachineTypeNotSupported,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2337,12 +2584,22 @@ impl StopExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionDoesNotExist" => {
-                    return RusotoError::Service(StopExecutionError::ExecutionDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopExecutionError::ExecutionDoesNotExist,
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(StopExecutionError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopExecutionError::InvalidArn,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2374,14 +2631,31 @@ impl TagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagResourceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "InvalidArn" => return RusotoError::Service(TagResourceError::InvalidArn(err.msg)),
+                "InvalidArn" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidArn,
+                    )
+                }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyTags" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2413,12 +2687,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidArn,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2459,39 +2743,62 @@ impl UpdateStateMachineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(UpdateStateMachineError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStateMachineError::InvalidArn,
+                    )
                 }
                 "InvalidDefinition" => {
-                    return RusotoError::Service(UpdateStateMachineError::InvalidDefinition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateStateMachineError::InvalidDefinition,
+                    )
                 }
                 "InvalidLoggingConfiguration" => {
-                    return RusotoError::Service(
-                        UpdateStateMachineError::InvalidLoggingConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStateMachineError::InvalidLoggingConfiguration,
                     )
                 }
                 "InvalidTracingConfiguration" => {
-                    return RusotoError::Service(
-                        UpdateStateMachineError::InvalidTracingConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStateMachineError::InvalidTracingConfiguration,
                     )
                 }
                 "MissingRequiredParameter" => {
-                    return RusotoError::Service(UpdateStateMachineError::MissingRequiredParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateStateMachineError::MissingRequiredParameter,
+                    )
                 }
                 "StateMachineDeleting" => {
-                    return RusotoError::Service(UpdateStateMachineError::StateMachineDeleting(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateStateMachineError::StateMachineDeleting,
+                    )
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(UpdateStateMachineError::StateMachineDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateStateMachineError::StateMachineDoesNotExist,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2671,11 +2978,12 @@ pub struct StepFunctionsClient {
 impl StepFunctionsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> StepFunctionsClient {
         StepFunctionsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SFN"),
         }
     }
 
@@ -2690,12 +2998,32 @@ impl StepFunctionsClient {
     {
         StepFunctionsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SFN"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> StepFunctionsClient {
-        StepFunctionsClient { client, region }
+        StepFunctionsClient {
+            client,
+            region: region_for_service(region, "SFN"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> StepFunctionsClient {
+        StepFunctionsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SFN"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> StepFunctionsClient {
+        StepFunctionsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2707,6 +3035,7 @@ impl StepFunctions for StepFunctionsClient {
         input: CreateActivityInput,
     ) -> Result<CreateActivityOutput, RusotoError<CreateActivityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateActivity");
         request.add_header("x-amz-target", "AWSStepFunctions.CreateActivity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2725,6 +3054,7 @@ impl StepFunctions for StepFunctionsClient {
         input: CreateStateMachineInput,
     ) -> Result<CreateStateMachineOutput, RusotoError<CreateStateMachineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateStateMachine");
         request.add_header("x-amz-target", "AWSStepFunctions.CreateStateMachine");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2743,6 +3073,7 @@ impl StepFunctions for StepFunctionsClient {
         input: DeleteActivityInput,
     ) -> Result<DeleteActivityOutput, RusotoError<DeleteActivityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteActivity");
         request.add_header("x-amz-target", "AWSStepFunctions.DeleteActivity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2761,6 +3092,7 @@ impl StepFunctions for StepFunctionsClient {
         input: DeleteStateMachineInput,
     ) -> Result<DeleteStateMachineOutput, RusotoError<DeleteStateMachineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteStateMachine");
         request.add_header("x-amz-target", "AWSStepFunctions.DeleteStateMachine");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2779,6 +3111,7 @@ impl StepFunctions for StepFunctionsClient {
         input: DescribeActivityInput,
     ) -> Result<DescribeActivityOutput, RusotoError<DescribeActivityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeActivity");
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeActivity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2797,6 +3130,7 @@ impl StepFunctions for StepFunctionsClient {
         input: DescribeExecutionInput,
     ) -> Result<DescribeExecutionOutput, RusotoError<DescribeExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeExecution");
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2815,6 +3149,7 @@ impl StepFunctions for StepFunctionsClient {
         input: DescribeStateMachineInput,
     ) -> Result<DescribeStateMachineOutput, RusotoError<DescribeStateMachineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStateMachine");
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeStateMachine");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2836,6 +3171,7 @@ impl StepFunctions for StepFunctionsClient {
         RusotoError<DescribeStateMachineForExecutionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStateMachineForExecution");
         request.add_header(
             "x-amz-target",
             "AWSStepFunctions.DescribeStateMachineForExecution",
@@ -2861,6 +3197,7 @@ impl StepFunctions for StepFunctionsClient {
         input: GetActivityTaskInput,
     ) -> Result<GetActivityTaskOutput, RusotoError<GetActivityTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetActivityTask");
         request.add_header("x-amz-target", "AWSStepFunctions.GetActivityTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2879,6 +3216,7 @@ impl StepFunctions for StepFunctionsClient {
         input: GetExecutionHistoryInput,
     ) -> Result<GetExecutionHistoryOutput, RusotoError<GetExecutionHistoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetExecutionHistory");
         request.add_header("x-amz-target", "AWSStepFunctions.GetExecutionHistory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2897,6 +3235,7 @@ impl StepFunctions for StepFunctionsClient {
         input: ListActivitiesInput,
     ) -> Result<ListActivitiesOutput, RusotoError<ListActivitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListActivities");
         request.add_header("x-amz-target", "AWSStepFunctions.ListActivities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2915,6 +3254,7 @@ impl StepFunctions for StepFunctionsClient {
         input: ListExecutionsInput,
     ) -> Result<ListExecutionsOutput, RusotoError<ListExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListExecutions");
         request.add_header("x-amz-target", "AWSStepFunctions.ListExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2933,6 +3273,7 @@ impl StepFunctions for StepFunctionsClient {
         input: ListStateMachinesInput,
     ) -> Result<ListStateMachinesOutput, RusotoError<ListStateMachinesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListStateMachines");
         request.add_header("x-amz-target", "AWSStepFunctions.ListStateMachines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2951,6 +3292,7 @@ impl StepFunctions for StepFunctionsClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "AWSStepFunctions.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2969,6 +3311,7 @@ impl StepFunctions for StepFunctionsClient {
         input: SendTaskFailureInput,
     ) -> Result<SendTaskFailureOutput, RusotoError<SendTaskFailureError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SendTaskFailure");
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskFailure");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2987,6 +3330,7 @@ impl StepFunctions for StepFunctionsClient {
         input: SendTaskHeartbeatInput,
     ) -> Result<SendTaskHeartbeatOutput, RusotoError<SendTaskHeartbeatError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SendTaskHeartbeat");
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskHeartbeat");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3005,6 +3349,7 @@ impl StepFunctions for StepFunctionsClient {
         input: SendTaskSuccessInput,
     ) -> Result<SendTaskSuccessOutput, RusotoError<SendTaskSuccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SendTaskSuccess");
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskSuccess");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3023,6 +3368,7 @@ impl StepFunctions for StepFunctionsClient {
         input: StartExecutionInput,
     ) -> Result<StartExecutionOutput, RusotoError<StartExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartExecution");
         request.add_header("x-amz-target", "AWSStepFunctions.StartExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3041,6 +3387,7 @@ impl StepFunctions for StepFunctionsClient {
         input: StartSyncExecutionInput,
     ) -> Result<StartSyncExecutionOutput, RusotoError<StartSyncExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartSyncExecution");
         request.add_header("x-amz-target", "AWSStepFunctions.StartSyncExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3059,6 +3406,7 @@ impl StepFunctions for StepFunctionsClient {
         input: StopExecutionInput,
     ) -> Result<StopExecutionOutput, RusotoError<StopExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopExecution");
         request.add_header("x-amz-target", "AWSStepFunctions.StopExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3077,6 +3425,7 @@ impl StepFunctions for StepFunctionsClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AWSStepFunctions.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3095,6 +3444,7 @@ impl StepFunctions for StepFunctionsClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AWSStepFunctions.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3113,6 +3463,7 @@ impl StepFunctions for StepFunctionsClient {
         input: UpdateStateMachineInput,
     ) -> Result<UpdateStateMachineOutput, RusotoError<UpdateStateMachineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateStateMachine");
         request.add_header("x-amz-target", "AWSStepFunctions.UpdateStateMachine");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3125,3 +3476,280 @@ impl StepFunctions for StepFunctionsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateStateMachineOutput, _>()
     }
 }
+
+/// A blocking client for the AWS SFN API, running the calls of a `StepFunctionsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `StepFunctionsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct StepFunctionsBlockingClient {
+    client: StepFunctionsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl StepFunctionsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> StepFunctionsBlockingClient {
+        StepFunctionsBlockingClient::from_client(StepFunctionsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> StepFunctionsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        StepFunctionsBlockingClient::from_client(StepFunctionsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> StepFunctionsBlockingClient {
+        StepFunctionsBlockingClient::from_client(StepFunctionsClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> StepFunctionsBlockingClient {
+        StepFunctionsBlockingClient::from_client(StepFunctionsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: StepFunctionsClient) -> StepFunctionsBlockingClient {
+        StepFunctionsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> StepFunctionsBlockingClient {
+        StepFunctionsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> StepFunctionsBlockingClient {
+        StepFunctionsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`StepFunctions::create_activity`].
+    pub fn create_activity(
+        &self,
+        input: CreateActivityInput,
+    ) -> Result<CreateActivityOutput, RusotoError<CreateActivityError>> {
+        self.runtime.block_on(self.client.create_activity(input))
+    }
+
+    /// Blocking version of [`StepFunctions::create_state_machine`].
+    pub fn create_state_machine(
+        &self,
+        input: CreateStateMachineInput,
+    ) -> Result<CreateStateMachineOutput, RusotoError<CreateStateMachineError>> {
+        self.runtime
+            .block_on(self.client.create_state_machine(input))
+    }
+
+    /// Blocking version of [`StepFunctions::delete_activity`].
+    pub fn delete_activity(
+        &self,
+        input: DeleteActivityInput,
+    ) -> Result<DeleteActivityOutput, RusotoError<DeleteActivityError>> {
+        self.runtime.block_on(self.client.delete_activity(input))
+    }
+
+    /// Blocking version of [`StepFunctions::delete_state_machine`].
+    pub fn delete_state_machine(
+        &self,
+        input: DeleteStateMachineInput,
+    ) -> Result<DeleteStateMachineOutput, RusotoError<DeleteStateMachineError>> {
+        self.runtime
+            .block_on(self.client.delete_state_machine(input))
+    }
+
+    /// Blocking version of [`StepFunctions::describe_activity`].
+    pub fn describe_activity(
+        &self,
+        input: DescribeActivityInput,
+    ) -> Result<DescribeActivityOutput, RusotoError<DescribeActivityError>> {
+        self.runtime.block_on(self.client.describe_activity(input))
+    }
+
+    /// Blocking version of [`StepFunctions::describe_execution`].
+    pub fn describe_execution(
+        &self,
+        input: DescribeExecutionInput,
+    ) -> Result<DescribeExecutionOutput, RusotoError<DescribeExecutionError>> {
+        self.runtime.block_on(self.client.describe_execution(input))
+    }
+
+    /// Blocking version of [`StepFunctions::describe_state_machine`].
+    pub fn describe_state_machine(
+        &self,
+        input: DescribeStateMachineInput,
+    ) -> Result<DescribeStateMachineOutput, RusotoError<DescribeStateMachineError>> {
+        self.runtime
+            .block_on(self.client.describe_state_machine(input))
+    }
+
+    /// Blocking version of [`StepFunctions::describe_state_machine_for_execution`].
+    pub fn describe_state_machine_for_execution(
+        &self,
+        input: DescribeStateMachineForExecutionInput,
+    ) -> Result<
+        DescribeStateMachineForExecutionOutput,
+        RusotoError<DescribeStateMachineForExecutionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_state_machine_for_execution(input))
+    }
+
+    /// Blocking version of [`StepFunctions::get_activity_task`].
+    pub fn get_activity_task(
+        &self,
+        input: GetActivityTaskInput,
+    ) -> Result<GetActivityTaskOutput, RusotoError<GetActivityTaskError>> {
+        self.runtime.block_on(self.client.get_activity_task(input))
+    }
+
+    /// Blocking version of [`StepFunctions::get_execution_history`].
+    pub fn get_execution_history(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> Result<GetExecutionHistoryOutput, RusotoError<GetExecutionHistoryError>> {
+        self.runtime
+            .block_on(self.client.get_execution_history(input))
+    }
+
+    /// Blocking version of [`StepFunctions::list_activities`].
+    pub fn list_activities(
+        &self,
+        input: ListActivitiesInput,
+    ) -> Result<ListActivitiesOutput, RusotoError<ListActivitiesError>> {
+        self.runtime.block_on(self.client.list_activities(input))
+    }
+
+    /// Blocking version of [`StepFunctions::list_executions`].
+    pub fn list_executions(
+        &self,
+        input: ListExecutionsInput,
+    ) -> Result<ListExecutionsOutput, RusotoError<ListExecutionsError>> {
+        self.runtime.block_on(self.client.list_executions(input))
+    }
+
+    /// Blocking version of [`StepFunctions::list_state_machines`].
+    pub fn list_state_machines(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> Result<ListStateMachinesOutput, RusotoError<ListStateMachinesError>> {
+        self.runtime
+            .block_on(self.client.list_state_machines(input))
+    }
+
+    /// Blocking version of [`StepFunctions::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`StepFunctions::send_task_failure`].
+    pub fn send_task_failure(
+        &self,
+        input: SendTaskFailureInput,
+    ) -> Result<SendTaskFailureOutput, RusotoError<SendTaskFailureError>> {
+        self.runtime.block_on(self.client.send_task_failure(input))
+    }
+
+    /// Blocking version of [`StepFunctions::send_task_heartbeat`].
+    pub fn send_task_heartbeat(
+        &self,
+        input: SendTaskHeartbeatInput,
+    ) -> Result<SendTaskHeartbeatOutput, RusotoError<SendTaskHeartbeatError>> {
+        self.runtime
+            .block_on(self.client.send_task_heartbeat(input))
+    }
+
+    /// Blocking version of [`StepFunctions::send_task_success`].
+    pub fn send_task_success(
+        &self,
+        input: SendTaskSuccessInput,
+    ) -> Result<SendTaskSuccessOutput, RusotoError<SendTaskSuccessError>> {
+        self.runtime.block_on(self.client.send_task_success(input))
+    }
+
+    /// Blocking version of [`StepFunctions::start_execution`].
+    pub fn start_execution(
+        &self,
+        input: StartExecutionInput,
+    ) -> Result<StartExecutionOutput, RusotoError<StartExecutionError>> {
+        self.runtime.block_on(self.client.start_execution(input))
+    }
+
+    /// Blocking version of [`StepFunctions::start_sync_execution`].
+    pub fn start_sync_execution(
+        &self,
+        input: StartSyncExecutionInput,
+    ) -> Result<StartSyncExecutionOutput, RusotoError<StartSyncExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_sync_execution(input))
+    }
+
+    /// Blocking version of [`StepFunctions::stop_execution`].
+    pub fn stop_execution(
+        &self,
+        input: StopExecutionInput,
+    ) -> Result<StopExecutionOutput, RusotoError<StopExecutionError>> {
+        self.runtime.block_on(self.client.stop_execution(input))
+    }
+
+    /// Blocking version of [`StepFunctions::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`StepFunctions::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`StepFunctions::update_state_machine`].
+    pub fn update_state_machine(
+        &self,
+        input: UpdateStateMachineInput,
+    ) -> Result<UpdateStateMachineOutput, RusotoError<UpdateStateMachineError>> {
+        self.runtime
+            .block_on(self.client.update_state_machine(input))
+    }
+}
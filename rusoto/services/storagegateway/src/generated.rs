@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3427,14 +3428,22 @@ impl ActivateGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ActivateGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ActivateGatewayError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ActivateGatewayError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ActivateGatewayError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3465,12 +3474,22 @@ impl AddCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddCacheError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddCacheError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AddCacheError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddCacheError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3501,16 +3520,22 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddTagsToResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsToResourceError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsToResourceError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3541,14 +3566,22 @@ impl AddUploadBufferError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddUploadBufferError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddUploadBufferError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AddUploadBufferError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddUploadBufferError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3579,16 +3612,22 @@ impl AddWorkingStorageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddWorkingStorageError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddWorkingStorageError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AddWorkingStorageError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddWorkingStorageError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3619,14 +3658,22 @@ impl AssignTapePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AssignTapePoolError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssignTapePoolError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AssignTapePoolError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssignTapePoolError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3657,12 +3704,22 @@ impl AttachVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AttachVolumeError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachVolumeError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AttachVolumeError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachVolumeError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3693,14 +3750,22 @@ impl CancelArchivalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CancelArchivalError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelArchivalError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CancelArchivalError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelArchivalError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3731,14 +3796,22 @@ impl CancelRetrievalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CancelRetrievalError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelRetrievalError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CancelRetrievalError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelRetrievalError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3769,16 +3842,22 @@ impl CreateCachediSCSIVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateCachediSCSIVolumeError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCachediSCSIVolumeError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        CreateCachediSCSIVolumeError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCachediSCSIVolumeError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3811,16 +3890,22 @@ impl CreateNFSFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateNFSFileShareError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateNFSFileShareError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateNFSFileShareError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateNFSFileShareError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3851,16 +3936,22 @@ impl CreateSMBFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateSMBFileShareError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSMBFileShareError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateSMBFileShareError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSMBFileShareError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3893,19 +3984,30 @@ impl CreateSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateSnapshotError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSnapshotError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateSnapshotError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSnapshotError::InvalidGatewayRequest,
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(CreateSnapshotError::ServiceUnavailableError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSnapshotError::ServiceUnavailableError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3941,23 +4043,30 @@ impl CreateSnapshotFromVolumeRecoveryPointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        CreateSnapshotFromVolumeRecoveryPointError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSnapshotFromVolumeRecoveryPointError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        CreateSnapshotFromVolumeRecoveryPointError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSnapshotFromVolumeRecoveryPointError::InvalidGatewayRequest,
                     )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(
-                        CreateSnapshotFromVolumeRecoveryPointError::ServiceUnavailableError(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSnapshotFromVolumeRecoveryPointError::ServiceUnavailableError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3995,16 +4104,22 @@ impl CreateStorediSCSIVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateStorediSCSIVolumeError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateStorediSCSIVolumeError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        CreateStorediSCSIVolumeError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStorediSCSIVolumeError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4037,14 +4152,22 @@ impl CreateTapePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTapePoolError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTapePoolError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateTapePoolError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTapePoolError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4075,16 +4198,22 @@ impl CreateTapeWithBarcodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTapeWithBarcodeError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTapeWithBarcodeError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateTapeWithBarcodeError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTapeWithBarcodeError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4115,12 +4244,22 @@ impl CreateTapesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTapesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTapesError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateTapesError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTapesError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4153,16 +4292,22 @@ impl DeleteAutomaticTapeCreationPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DeleteAutomaticTapeCreationPolicyError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAutomaticTapeCreationPolicyError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DeleteAutomaticTapeCreationPolicyError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAutomaticTapeCreationPolicyError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4197,16 +4342,22 @@ impl DeleteBandwidthRateLimitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DeleteBandwidthRateLimitError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBandwidthRateLimitError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DeleteBandwidthRateLimitError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBandwidthRateLimitError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4239,16 +4390,22 @@ impl DeleteChapCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteChapCredentialsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChapCredentialsError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteChapCredentialsError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChapCredentialsError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4279,14 +4436,22 @@ impl DeleteFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFileShareError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileShareError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteFileShareError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFileShareError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4317,12 +4482,22 @@ impl DeleteGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGatewayError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteGatewayError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGatewayError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4353,16 +4528,22 @@ impl DeleteSnapshotScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteSnapshotScheduleError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSnapshotScheduleError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DeleteSnapshotScheduleError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSnapshotScheduleError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4393,12 +4574,22 @@ impl DeleteTapeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTapeError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTapeError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteTapeError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTapeError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4429,16 +4620,22 @@ impl DeleteTapeArchiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTapeArchiveError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTapeArchiveError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteTapeArchiveError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTapeArchiveError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4469,14 +4666,22 @@ impl DeleteTapePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTapePoolError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTapePoolError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteTapePoolError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTapePoolError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4507,12 +4712,22 @@ impl DeleteVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteVolumeError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVolumeError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteVolumeError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVolumeError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4545,16 +4760,22 @@ impl DescribeAvailabilityMonitorTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeAvailabilityMonitorTestError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAvailabilityMonitorTestError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DescribeAvailabilityMonitorTestError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAvailabilityMonitorTestError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4591,16 +4812,22 @@ impl DescribeBandwidthRateLimitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeBandwidthRateLimitError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBandwidthRateLimitError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DescribeBandwidthRateLimitError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBandwidthRateLimitError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4637,16 +4864,22 @@ impl DescribeBandwidthRateLimitScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeBandwidthRateLimitScheduleError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBandwidthRateLimitScheduleError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DescribeBandwidthRateLimitScheduleError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBandwidthRateLimitScheduleError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4681,12 +4914,22 @@ impl DescribeCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeCacheError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCacheError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeCacheError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCacheError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4719,16 +4962,22 @@ impl DescribeCachediSCSIVolumesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeCachediSCSIVolumesError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCachediSCSIVolumesError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DescribeCachediSCSIVolumesError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCachediSCSIVolumesError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4763,16 +5012,22 @@ impl DescribeChapCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeChapCredentialsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeChapCredentialsError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DescribeChapCredentialsError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChapCredentialsError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4807,16 +5062,22 @@ impl DescribeGatewayInformationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeGatewayInformationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGatewayInformationError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DescribeGatewayInformationError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGatewayInformationError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4853,16 +5114,22 @@ impl DescribeMaintenanceStartTimeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceStartTimeError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceStartTimeError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DescribeMaintenanceStartTimeError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMaintenanceStartTimeError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4897,16 +5164,22 @@ impl DescribeNFSFileSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeNFSFileSharesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeNFSFileSharesError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeNFSFileSharesError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeNFSFileSharesError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4937,16 +5210,22 @@ impl DescribeSMBFileSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeSMBFileSharesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSMBFileSharesError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeSMBFileSharesError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSMBFileSharesError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4977,16 +5256,22 @@ impl DescribeSMBSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeSMBSettingsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSMBSettingsError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeSMBSettingsError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSMBSettingsError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5017,16 +5302,22 @@ impl DescribeSnapshotScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeSnapshotScheduleError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSnapshotScheduleError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DescribeSnapshotScheduleError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSnapshotScheduleError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5061,16 +5352,22 @@ impl DescribeStorediSCSIVolumesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeStorediSCSIVolumesError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStorediSCSIVolumesError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DescribeStorediSCSIVolumesError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStorediSCSIVolumesError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5105,16 +5402,22 @@ impl DescribeTapeArchivesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTapeArchivesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTapeArchivesError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeTapeArchivesError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTapeArchivesError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5147,16 +5450,22 @@ impl DescribeTapeRecoveryPointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeTapeRecoveryPointsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTapeRecoveryPointsError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DescribeTapeRecoveryPointsError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTapeRecoveryPointsError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5191,12 +5500,22 @@ impl DescribeTapesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTapesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTapesError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeTapesError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTapesError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5227,16 +5546,22 @@ impl DescribeUploadBufferError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeUploadBufferError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUploadBufferError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeUploadBufferError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUploadBufferError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5267,16 +5592,22 @@ impl DescribeVTLDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeVTLDevicesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVTLDevicesError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeVTLDevicesError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVTLDevicesError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5307,16 +5638,22 @@ impl DescribeWorkingStorageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeWorkingStorageError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorkingStorageError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        DescribeWorkingStorageError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorkingStorageError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5347,12 +5684,22 @@ impl DetachVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DetachVolumeError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachVolumeError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DetachVolumeError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachVolumeError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5383,14 +5730,22 @@ impl DisableGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DisableGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableGatewayError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DisableGatewayError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableGatewayError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5421,12 +5776,22 @@ impl JoinDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(JoinDomainError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        JoinDomainError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(JoinDomainError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        JoinDomainError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5459,16 +5824,22 @@ impl ListAutomaticTapeCreationPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        ListAutomaticTapeCreationPoliciesError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAutomaticTapeCreationPoliciesError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        ListAutomaticTapeCreationPoliciesError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAutomaticTapeCreationPoliciesError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5503,14 +5874,22 @@ impl ListFileSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListFileSharesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFileSharesError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListFileSharesError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFileSharesError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5541,12 +5920,22 @@ impl ListGatewaysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListGatewaysError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGatewaysError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListGatewaysError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGatewaysError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5577,14 +5966,22 @@ impl ListLocalDisksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListLocalDisksError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLocalDisksError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListLocalDisksError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLocalDisksError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5615,16 +6012,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5655,12 +6058,22 @@ impl ListTapePoolsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTapePoolsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTapePoolsError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListTapePoolsError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTapePoolsError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5691,12 +6104,22 @@ impl ListTapesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTapesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTapesError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListTapesError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTapesError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5727,16 +6150,22 @@ impl ListVolumeInitiatorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListVolumeInitiatorsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVolumeInitiatorsError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListVolumeInitiatorsError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVolumeInitiatorsError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5767,16 +6196,22 @@ impl ListVolumeRecoveryPointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        ListVolumeRecoveryPointsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVolumeRecoveryPointsError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        ListVolumeRecoveryPointsError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVolumeRecoveryPointsError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5809,12 +6244,22 @@ impl ListVolumesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListVolumesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVolumesError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListVolumesError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVolumesError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5845,16 +6290,22 @@ impl NotifyWhenUploadedError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(NotifyWhenUploadedError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyWhenUploadedError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(NotifyWhenUploadedError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyWhenUploadedError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5885,12 +6336,22 @@ impl RefreshCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RefreshCacheError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RefreshCacheError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(RefreshCacheError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RefreshCacheError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5921,16 +6382,22 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromResourceError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        RemoveTagsFromResourceError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromResourceError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5961,12 +6428,22 @@ impl ResetCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ResetCacheError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetCacheError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ResetCacheError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetCacheError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5997,16 +6474,22 @@ impl RetrieveTapeArchiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RetrieveTapeArchiveError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RetrieveTapeArchiveError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(RetrieveTapeArchiveError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RetrieveTapeArchiveError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6037,16 +6520,22 @@ impl RetrieveTapeRecoveryPointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        RetrieveTapeRecoveryPointError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetrieveTapeRecoveryPointError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        RetrieveTapeRecoveryPointError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetrieveTapeRecoveryPointError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6081,16 +6570,22 @@ impl SetLocalConsolePasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(SetLocalConsolePasswordError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetLocalConsolePasswordError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        SetLocalConsolePasswordError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetLocalConsolePasswordError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6123,16 +6618,22 @@ impl SetSMBGuestPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(SetSMBGuestPasswordError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetSMBGuestPasswordError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(SetSMBGuestPasswordError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetSMBGuestPasswordError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6163,14 +6664,22 @@ impl ShutdownGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ShutdownGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ShutdownGatewayError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ShutdownGatewayError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ShutdownGatewayError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6203,16 +6712,22 @@ impl StartAvailabilityMonitorTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        StartAvailabilityMonitorTestError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAvailabilityMonitorTestError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        StartAvailabilityMonitorTestError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAvailabilityMonitorTestError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6247,12 +6762,22 @@ impl StartGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(StartGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartGatewayError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(StartGatewayError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartGatewayError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6285,16 +6810,22 @@ impl UpdateAutomaticTapeCreationPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateAutomaticTapeCreationPolicyError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAutomaticTapeCreationPolicyError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        UpdateAutomaticTapeCreationPolicyError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAutomaticTapeCreationPolicyError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6329,16 +6860,22 @@ impl UpdateBandwidthRateLimitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateBandwidthRateLimitError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBandwidthRateLimitError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        UpdateBandwidthRateLimitError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBandwidthRateLimitError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6373,16 +6910,22 @@ impl UpdateBandwidthRateLimitScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateBandwidthRateLimitScheduleError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBandwidthRateLimitScheduleError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        UpdateBandwidthRateLimitScheduleError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBandwidthRateLimitScheduleError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6417,16 +6960,22 @@ impl UpdateChapCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateChapCredentialsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateChapCredentialsError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(UpdateChapCredentialsError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateChapCredentialsError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6457,16 +7006,22 @@ impl UpdateGatewayInformationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateGatewayInformationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayInformationError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        UpdateGatewayInformationError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayInformationError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6499,16 +7054,22 @@ impl UpdateGatewaySoftwareNowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateGatewaySoftwareNowError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewaySoftwareNowError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        UpdateGatewaySoftwareNowError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewaySoftwareNowError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6543,16 +7104,22 @@ impl UpdateMaintenanceStartTimeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateMaintenanceStartTimeError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMaintenanceStartTimeError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        UpdateMaintenanceStartTimeError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMaintenanceStartTimeError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6587,16 +7154,22 @@ impl UpdateNFSFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateNFSFileShareError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNFSFileShareError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(UpdateNFSFileShareError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNFSFileShareError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6627,16 +7200,22 @@ impl UpdateSMBFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateSMBFileShareError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSMBFileShareError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(UpdateSMBFileShareError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSMBFileShareError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6669,16 +7248,22 @@ impl UpdateSMBFileShareVisibilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateSMBFileShareVisibilityError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSMBFileShareVisibilityError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        UpdateSMBFileShareVisibilityError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSMBFileShareVisibilityError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6713,16 +7298,22 @@ impl UpdateSMBSecurityStrategyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateSMBSecurityStrategyError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSMBSecurityStrategyError::InternalServerError,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        UpdateSMBSecurityStrategyError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSMBSecurityStrategyError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6757,16 +7348,22 @@ impl UpdateSnapshotScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateSnapshotScheduleError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSnapshotScheduleError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
-                        UpdateSnapshotScheduleError::InvalidGatewayRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSnapshotScheduleError::InvalidGatewayRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6797,16 +7394,22 @@ impl UpdateVTLDeviceTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateVTLDeviceTypeError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVTLDeviceTypeError::InternalServerError,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(UpdateVTLDeviceTypeError::InvalidGatewayRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVTLDeviceTypeError::InvalidGatewayRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7361,11 +7964,12 @@ pub struct StorageGatewayClient {
 impl StorageGatewayClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> StorageGatewayClient {
         StorageGatewayClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Storage Gateway"),
         }
     }
 
@@ -7380,12 +7984,32 @@ impl StorageGatewayClient {
     {
         StorageGatewayClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Storage Gateway"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> StorageGatewayClient {
-        StorageGatewayClient { client, region }
+        StorageGatewayClient {
+            client,
+            region: region_for_service(region, "Storage Gateway"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> StorageGatewayClient {
+        StorageGatewayClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Storage Gateway"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> StorageGatewayClient {
+        StorageGatewayClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -7397,6 +8021,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ActivateGatewayInput,
     ) -> Result<ActivateGatewayOutput, RusotoError<ActivateGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ActivateGateway");
         request.add_header("x-amz-target", "StorageGateway_20130630.ActivateGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7415,6 +8040,7 @@ impl StorageGateway for StorageGatewayClient {
         input: AddCacheInput,
     ) -> Result<AddCacheOutput, RusotoError<AddCacheError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddCache");
         request.add_header("x-amz-target", "StorageGateway_20130630.AddCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7433,6 +8059,7 @@ impl StorageGateway for StorageGatewayClient {
         input: AddTagsToResourceInput,
     ) -> Result<AddTagsToResourceOutput, RusotoError<AddTagsToResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTagsToResource");
         request.add_header("x-amz-target", "StorageGateway_20130630.AddTagsToResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7451,6 +8078,7 @@ impl StorageGateway for StorageGatewayClient {
         input: AddUploadBufferInput,
     ) -> Result<AddUploadBufferOutput, RusotoError<AddUploadBufferError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddUploadBuffer");
         request.add_header("x-amz-target", "StorageGateway_20130630.AddUploadBuffer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7469,6 +8097,7 @@ impl StorageGateway for StorageGatewayClient {
         input: AddWorkingStorageInput,
     ) -> Result<AddWorkingStorageOutput, RusotoError<AddWorkingStorageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddWorkingStorage");
         request.add_header("x-amz-target", "StorageGateway_20130630.AddWorkingStorage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7487,6 +8116,7 @@ impl StorageGateway for StorageGatewayClient {
         input: AssignTapePoolInput,
     ) -> Result<AssignTapePoolOutput, RusotoError<AssignTapePoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssignTapePool");
         request.add_header("x-amz-target", "StorageGateway_20130630.AssignTapePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7505,6 +8135,7 @@ impl StorageGateway for StorageGatewayClient {
         input: AttachVolumeInput,
     ) -> Result<AttachVolumeOutput, RusotoError<AttachVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AttachVolume");
         request.add_header("x-amz-target", "StorageGateway_20130630.AttachVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7523,6 +8154,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CancelArchivalInput,
     ) -> Result<CancelArchivalOutput, RusotoError<CancelArchivalError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelArchival");
         request.add_header("x-amz-target", "StorageGateway_20130630.CancelArchival");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7541,6 +8173,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CancelRetrievalInput,
     ) -> Result<CancelRetrievalOutput, RusotoError<CancelRetrievalError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelRetrieval");
         request.add_header("x-amz-target", "StorageGateway_20130630.CancelRetrieval");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7559,6 +8192,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateCachediSCSIVolumeInput,
     ) -> Result<CreateCachediSCSIVolumeOutput, RusotoError<CreateCachediSCSIVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCachediSCSIVolume");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.CreateCachediSCSIVolume",
@@ -7581,6 +8215,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateNFSFileShareInput,
     ) -> Result<CreateNFSFileShareOutput, RusotoError<CreateNFSFileShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateNFSFileShare");
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateNFSFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7599,6 +8234,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateSMBFileShareInput,
     ) -> Result<CreateSMBFileShareOutput, RusotoError<CreateSMBFileShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSMBFileShare");
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateSMBFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7617,6 +8253,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateSnapshotInput,
     ) -> Result<CreateSnapshotOutput, RusotoError<CreateSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSnapshot");
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7638,6 +8275,7 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<CreateSnapshotFromVolumeRecoveryPointError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSnapshotFromVolumeRecoveryPoint");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.CreateSnapshotFromVolumeRecoveryPoint",
@@ -7663,6 +8301,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateStorediSCSIVolumeInput,
     ) -> Result<CreateStorediSCSIVolumeOutput, RusotoError<CreateStorediSCSIVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateStorediSCSIVolume");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.CreateStorediSCSIVolume",
@@ -7685,6 +8324,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateTapePoolInput,
     ) -> Result<CreateTapePoolOutput, RusotoError<CreateTapePoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTapePool");
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateTapePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7703,6 +8343,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateTapeWithBarcodeInput,
     ) -> Result<CreateTapeWithBarcodeOutput, RusotoError<CreateTapeWithBarcodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTapeWithBarcode");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.CreateTapeWithBarcode",
@@ -7724,6 +8365,7 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateTapesInput,
     ) -> Result<CreateTapesOutput, RusotoError<CreateTapesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTapes");
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateTapes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7745,6 +8387,7 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<DeleteAutomaticTapeCreationPolicyError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAutomaticTapeCreationPolicy");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DeleteAutomaticTapeCreationPolicy",
@@ -7770,6 +8413,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteBandwidthRateLimitInput,
     ) -> Result<DeleteBandwidthRateLimitOutput, RusotoError<DeleteBandwidthRateLimitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBandwidthRateLimit");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DeleteBandwidthRateLimit",
@@ -7792,6 +8436,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteChapCredentialsInput,
     ) -> Result<DeleteChapCredentialsOutput, RusotoError<DeleteChapCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteChapCredentials");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DeleteChapCredentials",
@@ -7813,6 +8458,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteFileShareInput,
     ) -> Result<DeleteFileShareOutput, RusotoError<DeleteFileShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteFileShare");
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7831,6 +8477,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteGatewayInput,
     ) -> Result<DeleteGatewayOutput, RusotoError<DeleteGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteGateway");
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7849,6 +8496,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteSnapshotScheduleInput,
     ) -> Result<DeleteSnapshotScheduleOutput, RusotoError<DeleteSnapshotScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSnapshotSchedule");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DeleteSnapshotSchedule",
@@ -7871,6 +8519,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteTapeInput,
     ) -> Result<DeleteTapeOutput, RusotoError<DeleteTapeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTape");
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTape");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7889,6 +8538,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteTapeArchiveInput,
     ) -> Result<DeleteTapeArchiveOutput, RusotoError<DeleteTapeArchiveError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTapeArchive");
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTapeArchive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7907,6 +8557,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteTapePoolInput,
     ) -> Result<DeleteTapePoolOutput, RusotoError<DeleteTapePoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTapePool");
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTapePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7925,6 +8576,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteVolumeInput,
     ) -> Result<DeleteVolumeOutput, RusotoError<DeleteVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteVolume");
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7946,6 +8598,7 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<DescribeAvailabilityMonitorTestError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAvailabilityMonitorTest");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeAvailabilityMonitorTest",
@@ -7969,6 +8622,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeBandwidthRateLimitOutput, RusotoError<DescribeBandwidthRateLimitError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBandwidthRateLimit");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeBandwidthRateLimit",
@@ -7994,6 +8648,7 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<DescribeBandwidthRateLimitScheduleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBandwidthRateLimitSchedule");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeBandwidthRateLimitSchedule",
@@ -8019,6 +8674,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeCacheInput,
     ) -> Result<DescribeCacheOutput, RusotoError<DescribeCacheError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCache");
         request.add_header("x-amz-target", "StorageGateway_20130630.DescribeCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8038,6 +8694,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeCachediSCSIVolumesOutput, RusotoError<DescribeCachediSCSIVolumesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCachediSCSIVolumes");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeCachediSCSIVolumes",
@@ -8060,6 +8717,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeChapCredentialsInput,
     ) -> Result<DescribeChapCredentialsOutput, RusotoError<DescribeChapCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeChapCredentials");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeChapCredentials",
@@ -8083,6 +8741,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeGatewayInformationOutput, RusotoError<DescribeGatewayInformationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeGatewayInformation");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeGatewayInformation",
@@ -8106,6 +8765,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeMaintenanceStartTimeOutput, RusotoError<DescribeMaintenanceStartTimeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMaintenanceStartTime");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeMaintenanceStartTime",
@@ -8128,6 +8788,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeNFSFileSharesInput,
     ) -> Result<DescribeNFSFileSharesOutput, RusotoError<DescribeNFSFileSharesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeNFSFileShares");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeNFSFileShares",
@@ -8149,6 +8810,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeSMBFileSharesInput,
     ) -> Result<DescribeSMBFileSharesOutput, RusotoError<DescribeSMBFileSharesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSMBFileShares");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeSMBFileShares",
@@ -8170,6 +8832,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeSMBSettingsInput,
     ) -> Result<DescribeSMBSettingsOutput, RusotoError<DescribeSMBSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSMBSettings");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeSMBSettings",
@@ -8191,6 +8854,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeSnapshotScheduleInput,
     ) -> Result<DescribeSnapshotScheduleOutput, RusotoError<DescribeSnapshotScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSnapshotSchedule");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeSnapshotSchedule",
@@ -8214,6 +8878,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeStorediSCSIVolumesOutput, RusotoError<DescribeStorediSCSIVolumesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStorediSCSIVolumes");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeStorediSCSIVolumes",
@@ -8236,6 +8901,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeTapeArchivesInput,
     ) -> Result<DescribeTapeArchivesOutput, RusotoError<DescribeTapeArchivesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTapeArchives");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeTapeArchives",
@@ -8258,6 +8924,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeTapeRecoveryPointsOutput, RusotoError<DescribeTapeRecoveryPointsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTapeRecoveryPoints");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeTapeRecoveryPoints",
@@ -8280,6 +8947,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeTapesInput,
     ) -> Result<DescribeTapesOutput, RusotoError<DescribeTapesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTapes");
         request.add_header("x-amz-target", "StorageGateway_20130630.DescribeTapes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8298,6 +8966,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeUploadBufferInput,
     ) -> Result<DescribeUploadBufferOutput, RusotoError<DescribeUploadBufferError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeUploadBuffer");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeUploadBuffer",
@@ -8319,6 +8988,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeVTLDevicesInput,
     ) -> Result<DescribeVTLDevicesOutput, RusotoError<DescribeVTLDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeVTLDevices");
         request.add_header("x-amz-target", "StorageGateway_20130630.DescribeVTLDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8337,6 +9007,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeWorkingStorageInput,
     ) -> Result<DescribeWorkingStorageOutput, RusotoError<DescribeWorkingStorageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeWorkingStorage");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeWorkingStorage",
@@ -8359,6 +9030,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DetachVolumeInput,
     ) -> Result<DetachVolumeOutput, RusotoError<DetachVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetachVolume");
         request.add_header("x-amz-target", "StorageGateway_20130630.DetachVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8377,6 +9049,7 @@ impl StorageGateway for StorageGatewayClient {
         input: DisableGatewayInput,
     ) -> Result<DisableGatewayOutput, RusotoError<DisableGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableGateway");
         request.add_header("x-amz-target", "StorageGateway_20130630.DisableGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8395,6 +9068,7 @@ impl StorageGateway for StorageGatewayClient {
         input: JoinDomainInput,
     ) -> Result<JoinDomainOutput, RusotoError<JoinDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("JoinDomain");
         request.add_header("x-amz-target", "StorageGateway_20130630.JoinDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8416,6 +9090,7 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<ListAutomaticTapeCreationPoliciesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAutomaticTapeCreationPolicies");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.ListAutomaticTapeCreationPolicies",
@@ -8441,6 +9116,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListFileSharesInput,
     ) -> Result<ListFileSharesOutput, RusotoError<ListFileSharesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListFileShares");
         request.add_header("x-amz-target", "StorageGateway_20130630.ListFileShares");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8459,6 +9135,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListGatewaysInput,
     ) -> Result<ListGatewaysOutput, RusotoError<ListGatewaysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGateways");
         request.add_header("x-amz-target", "StorageGateway_20130630.ListGateways");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8477,6 +9154,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListLocalDisksInput,
     ) -> Result<ListLocalDisksOutput, RusotoError<ListLocalDisksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLocalDisks");
         request.add_header("x-amz-target", "StorageGateway_20130630.ListLocalDisks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8495,6 +9173,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.ListTagsForResource",
@@ -8516,6 +9195,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListTapePoolsInput,
     ) -> Result<ListTapePoolsOutput, RusotoError<ListTapePoolsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTapePools");
         request.add_header("x-amz-target", "StorageGateway_20130630.ListTapePools");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8534,6 +9214,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListTapesInput,
     ) -> Result<ListTapesOutput, RusotoError<ListTapesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTapes");
         request.add_header("x-amz-target", "StorageGateway_20130630.ListTapes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8552,6 +9233,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListVolumeInitiatorsInput,
     ) -> Result<ListVolumeInitiatorsOutput, RusotoError<ListVolumeInitiatorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListVolumeInitiators");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.ListVolumeInitiators",
@@ -8573,6 +9255,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListVolumeRecoveryPointsInput,
     ) -> Result<ListVolumeRecoveryPointsOutput, RusotoError<ListVolumeRecoveryPointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListVolumeRecoveryPoints");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.ListVolumeRecoveryPoints",
@@ -8595,6 +9278,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ListVolumesInput,
     ) -> Result<ListVolumesOutput, RusotoError<ListVolumesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListVolumes");
         request.add_header("x-amz-target", "StorageGateway_20130630.ListVolumes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8613,6 +9297,7 @@ impl StorageGateway for StorageGatewayClient {
         input: NotifyWhenUploadedInput,
     ) -> Result<NotifyWhenUploadedOutput, RusotoError<NotifyWhenUploadedError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("NotifyWhenUploaded");
         request.add_header("x-amz-target", "StorageGateway_20130630.NotifyWhenUploaded");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8631,6 +9316,7 @@ impl StorageGateway for StorageGatewayClient {
         input: RefreshCacheInput,
     ) -> Result<RefreshCacheOutput, RusotoError<RefreshCacheError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RefreshCache");
         request.add_header("x-amz-target", "StorageGateway_20130630.RefreshCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8649,6 +9335,7 @@ impl StorageGateway for StorageGatewayClient {
         input: RemoveTagsFromResourceInput,
     ) -> Result<RemoveTagsFromResourceOutput, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveTagsFromResource");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.RemoveTagsFromResource",
@@ -8671,6 +9358,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ResetCacheInput,
     ) -> Result<ResetCacheOutput, RusotoError<ResetCacheError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResetCache");
         request.add_header("x-amz-target", "StorageGateway_20130630.ResetCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8689,6 +9377,7 @@ impl StorageGateway for StorageGatewayClient {
         input: RetrieveTapeArchiveInput,
     ) -> Result<RetrieveTapeArchiveOutput, RusotoError<RetrieveTapeArchiveError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RetrieveTapeArchive");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.RetrieveTapeArchive",
@@ -8710,6 +9399,7 @@ impl StorageGateway for StorageGatewayClient {
         input: RetrieveTapeRecoveryPointInput,
     ) -> Result<RetrieveTapeRecoveryPointOutput, RusotoError<RetrieveTapeRecoveryPointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RetrieveTapeRecoveryPoint");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.RetrieveTapeRecoveryPoint",
@@ -8732,6 +9422,7 @@ impl StorageGateway for StorageGatewayClient {
         input: SetLocalConsolePasswordInput,
     ) -> Result<SetLocalConsolePasswordOutput, RusotoError<SetLocalConsolePasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetLocalConsolePassword");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.SetLocalConsolePassword",
@@ -8754,6 +9445,7 @@ impl StorageGateway for StorageGatewayClient {
         input: SetSMBGuestPasswordInput,
     ) -> Result<SetSMBGuestPasswordOutput, RusotoError<SetSMBGuestPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetSMBGuestPassword");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.SetSMBGuestPassword",
@@ -8775,6 +9467,7 @@ impl StorageGateway for StorageGatewayClient {
         input: ShutdownGatewayInput,
     ) -> Result<ShutdownGatewayOutput, RusotoError<ShutdownGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ShutdownGateway");
         request.add_header("x-amz-target", "StorageGateway_20130630.ShutdownGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8794,6 +9487,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<StartAvailabilityMonitorTestOutput, RusotoError<StartAvailabilityMonitorTestError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartAvailabilityMonitorTest");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.StartAvailabilityMonitorTest",
@@ -8816,6 +9510,7 @@ impl StorageGateway for StorageGatewayClient {
         input: StartGatewayInput,
     ) -> Result<StartGatewayOutput, RusotoError<StartGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartGateway");
         request.add_header("x-amz-target", "StorageGateway_20130630.StartGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8837,6 +9532,7 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<UpdateAutomaticTapeCreationPolicyError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAutomaticTapeCreationPolicy");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateAutomaticTapeCreationPolicy",
@@ -8862,6 +9558,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateBandwidthRateLimitInput,
     ) -> Result<UpdateBandwidthRateLimitOutput, RusotoError<UpdateBandwidthRateLimitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateBandwidthRateLimit");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateBandwidthRateLimit",
@@ -8887,6 +9584,7 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<UpdateBandwidthRateLimitScheduleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateBandwidthRateLimitSchedule");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateBandwidthRateLimitSchedule",
@@ -8912,6 +9610,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateChapCredentialsInput,
     ) -> Result<UpdateChapCredentialsOutput, RusotoError<UpdateChapCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateChapCredentials");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateChapCredentials",
@@ -8933,6 +9632,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateGatewayInformationInput,
     ) -> Result<UpdateGatewayInformationOutput, RusotoError<UpdateGatewayInformationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGatewayInformation");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateGatewayInformation",
@@ -8955,6 +9655,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateGatewaySoftwareNowInput,
     ) -> Result<UpdateGatewaySoftwareNowOutput, RusotoError<UpdateGatewaySoftwareNowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGatewaySoftwareNow");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateGatewaySoftwareNow",
@@ -8978,6 +9679,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<UpdateMaintenanceStartTimeOutput, RusotoError<UpdateMaintenanceStartTimeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateMaintenanceStartTime");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateMaintenanceStartTime",
@@ -9000,6 +9702,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateNFSFileShareInput,
     ) -> Result<UpdateNFSFileShareOutput, RusotoError<UpdateNFSFileShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateNFSFileShare");
         request.add_header("x-amz-target", "StorageGateway_20130630.UpdateNFSFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9018,6 +9721,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateSMBFileShareInput,
     ) -> Result<UpdateSMBFileShareOutput, RusotoError<UpdateSMBFileShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSMBFileShare");
         request.add_header("x-amz-target", "StorageGateway_20130630.UpdateSMBFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9037,6 +9741,7 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<UpdateSMBFileShareVisibilityOutput, RusotoError<UpdateSMBFileShareVisibilityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSMBFileShareVisibility");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateSMBFileShareVisibility",
@@ -9059,6 +9764,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateSMBSecurityStrategyInput,
     ) -> Result<UpdateSMBSecurityStrategyOutput, RusotoError<UpdateSMBSecurityStrategyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSMBSecurityStrategy");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateSMBSecurityStrategy",
@@ -9081,6 +9787,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateSnapshotScheduleInput,
     ) -> Result<UpdateSnapshotScheduleOutput, RusotoError<UpdateSnapshotScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSnapshotSchedule");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateSnapshotSchedule",
@@ -9103,6 +9810,7 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateVTLDeviceTypeInput,
     ) -> Result<UpdateVTLDeviceTypeOutput, RusotoError<UpdateVTLDeviceTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateVTLDeviceType");
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateVTLDeviceType",
@@ -9118,3 +9826,841 @@ impl StorageGateway for StorageGatewayClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateVTLDeviceTypeOutput, _>()
     }
 }
+
+/// A blocking client for the AWS Storage Gateway API, running the calls of a `StorageGatewayClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `StorageGatewayClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct StorageGatewayBlockingClient {
+    client: StorageGatewayClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl StorageGatewayBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> StorageGatewayBlockingClient {
+        StorageGatewayBlockingClient::from_client(StorageGatewayClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> StorageGatewayBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        StorageGatewayBlockingClient::from_client(StorageGatewayClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> StorageGatewayBlockingClient {
+        StorageGatewayBlockingClient::from_client(StorageGatewayClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> StorageGatewayBlockingClient {
+        StorageGatewayBlockingClient::from_client(StorageGatewayClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: StorageGatewayClient) -> StorageGatewayBlockingClient {
+        StorageGatewayBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> StorageGatewayBlockingClient {
+        StorageGatewayBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> StorageGatewayBlockingClient {
+        StorageGatewayBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`StorageGateway::activate_gateway`].
+    pub fn activate_gateway(
+        &self,
+        input: ActivateGatewayInput,
+    ) -> Result<ActivateGatewayOutput, RusotoError<ActivateGatewayError>> {
+        self.runtime.block_on(self.client.activate_gateway(input))
+    }
+
+    /// Blocking version of [`StorageGateway::add_cache`].
+    pub fn add_cache(
+        &self,
+        input: AddCacheInput,
+    ) -> Result<AddCacheOutput, RusotoError<AddCacheError>> {
+        self.runtime.block_on(self.client.add_cache(input))
+    }
+
+    /// Blocking version of [`StorageGateway::add_tags_to_resource`].
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceInput,
+    ) -> Result<AddTagsToResourceOutput, RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// Blocking version of [`StorageGateway::add_upload_buffer`].
+    pub fn add_upload_buffer(
+        &self,
+        input: AddUploadBufferInput,
+    ) -> Result<AddUploadBufferOutput, RusotoError<AddUploadBufferError>> {
+        self.runtime.block_on(self.client.add_upload_buffer(input))
+    }
+
+    /// Blocking version of [`StorageGateway::add_working_storage`].
+    pub fn add_working_storage(
+        &self,
+        input: AddWorkingStorageInput,
+    ) -> Result<AddWorkingStorageOutput, RusotoError<AddWorkingStorageError>> {
+        self.runtime
+            .block_on(self.client.add_working_storage(input))
+    }
+
+    /// Blocking version of [`StorageGateway::assign_tape_pool`].
+    pub fn assign_tape_pool(
+        &self,
+        input: AssignTapePoolInput,
+    ) -> Result<AssignTapePoolOutput, RusotoError<AssignTapePoolError>> {
+        self.runtime.block_on(self.client.assign_tape_pool(input))
+    }
+
+    /// Blocking version of [`StorageGateway::attach_volume`].
+    pub fn attach_volume(
+        &self,
+        input: AttachVolumeInput,
+    ) -> Result<AttachVolumeOutput, RusotoError<AttachVolumeError>> {
+        self.runtime.block_on(self.client.attach_volume(input))
+    }
+
+    /// Blocking version of [`StorageGateway::cancel_archival`].
+    pub fn cancel_archival(
+        &self,
+        input: CancelArchivalInput,
+    ) -> Result<CancelArchivalOutput, RusotoError<CancelArchivalError>> {
+        self.runtime.block_on(self.client.cancel_archival(input))
+    }
+
+    /// Blocking version of [`StorageGateway::cancel_retrieval`].
+    pub fn cancel_retrieval(
+        &self,
+        input: CancelRetrievalInput,
+    ) -> Result<CancelRetrievalOutput, RusotoError<CancelRetrievalError>> {
+        self.runtime.block_on(self.client.cancel_retrieval(input))
+    }
+
+    /// Blocking version of [`StorageGateway::create_cachedi_scsi_volume`].
+    pub fn create_cachedi_scsi_volume(
+        &self,
+        input: CreateCachediSCSIVolumeInput,
+    ) -> Result<CreateCachediSCSIVolumeOutput, RusotoError<CreateCachediSCSIVolumeError>> {
+        self.runtime
+            .block_on(self.client.create_cachedi_scsi_volume(input))
+    }
+
+    /// Blocking version of [`StorageGateway::create_nfs_file_share`].
+    pub fn create_nfs_file_share(
+        &self,
+        input: CreateNFSFileShareInput,
+    ) -> Result<CreateNFSFileShareOutput, RusotoError<CreateNFSFileShareError>> {
+        self.runtime
+            .block_on(self.client.create_nfs_file_share(input))
+    }
+
+    /// Blocking version of [`StorageGateway::create_smb_file_share`].
+    pub fn create_smb_file_share(
+        &self,
+        input: CreateSMBFileShareInput,
+    ) -> Result<CreateSMBFileShareOutput, RusotoError<CreateSMBFileShareError>> {
+        self.runtime
+            .block_on(self.client.create_smb_file_share(input))
+    }
+
+    /// Blocking version of [`StorageGateway::create_snapshot`].
+    pub fn create_snapshot(
+        &self,
+        input: CreateSnapshotInput,
+    ) -> Result<CreateSnapshotOutput, RusotoError<CreateSnapshotError>> {
+        self.runtime.block_on(self.client.create_snapshot(input))
+    }
+
+    /// Blocking version of [`StorageGateway::create_snapshot_from_volume_recovery_point`].
+    pub fn create_snapshot_from_volume_recovery_point(
+        &self,
+        input: CreateSnapshotFromVolumeRecoveryPointInput,
+    ) -> Result<
+        CreateSnapshotFromVolumeRecoveryPointOutput,
+        RusotoError<CreateSnapshotFromVolumeRecoveryPointError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_snapshot_from_volume_recovery_point(input),
+        )
+    }
+
+    /// Blocking version of [`StorageGateway::create_storedi_scsi_volume`].
+    pub fn create_storedi_scsi_volume(
+        &self,
+        input: CreateStorediSCSIVolumeInput,
+    ) -> Result<CreateStorediSCSIVolumeOutput, RusotoError<CreateStorediSCSIVolumeError>> {
+        self.runtime
+            .block_on(self.client.create_storedi_scsi_volume(input))
+    }
+
+    /// Blocking version of [`StorageGateway::create_tape_pool`].
+    pub fn create_tape_pool(
+        &self,
+        input: CreateTapePoolInput,
+    ) -> Result<CreateTapePoolOutput, RusotoError<CreateTapePoolError>> {
+        self.runtime.block_on(self.client.create_tape_pool(input))
+    }
+
+    /// Blocking version of [`StorageGateway::create_tape_with_barcode`].
+    pub fn create_tape_with_barcode(
+        &self,
+        input: CreateTapeWithBarcodeInput,
+    ) -> Result<CreateTapeWithBarcodeOutput, RusotoError<CreateTapeWithBarcodeError>> {
+        self.runtime
+            .block_on(self.client.create_tape_with_barcode(input))
+    }
+
+    /// Blocking version of [`StorageGateway::create_tapes`].
+    pub fn create_tapes(
+        &self,
+        input: CreateTapesInput,
+    ) -> Result<CreateTapesOutput, RusotoError<CreateTapesError>> {
+        self.runtime.block_on(self.client.create_tapes(input))
+    }
+
+    /// Blocking version of [`StorageGateway::delete_automatic_tape_creation_policy`].
+    pub fn delete_automatic_tape_creation_policy(
+        &self,
+        input: DeleteAutomaticTapeCreationPolicyInput,
+    ) -> Result<
+        DeleteAutomaticTapeCreationPolicyOutput,
+        RusotoError<DeleteAutomaticTapeCreationPolicyError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_automatic_tape_creation_policy(input))
+    }
+
+    /// Blocking version of [`StorageGateway::delete_bandwidth_rate_limit`].
+    pub fn delete_bandwidth_rate_limit(
+        &self,
+        input: DeleteBandwidthRateLimitInput,
+    ) -> Result<DeleteBandwidthRateLimitOutput, RusotoError<DeleteBandwidthRateLimitError>> {
+        self.runtime
+            .block_on(self.client.delete_bandwidth_rate_limit(input))
+    }
+
+    /// Blocking version of [`StorageGateway::delete_chap_credentials`].
+    pub fn delete_chap_credentials(
+        &self,
+        input: DeleteChapCredentialsInput,
+    ) -> Result<DeleteChapCredentialsOutput, RusotoError<DeleteChapCredentialsError>> {
+        self.runtime
+            .block_on(self.client.delete_chap_credentials(input))
+    }
+
+    /// Blocking version of [`StorageGateway::delete_file_share`].
+    pub fn delete_file_share(
+        &self,
+        input: DeleteFileShareInput,
+    ) -> Result<DeleteFileShareOutput, RusotoError<DeleteFileShareError>> {
+        self.runtime.block_on(self.client.delete_file_share(input))
+    }
+
+    /// Blocking version of [`StorageGateway::delete_gateway`].
+    pub fn delete_gateway(
+        &self,
+        input: DeleteGatewayInput,
+    ) -> Result<DeleteGatewayOutput, RusotoError<DeleteGatewayError>> {
+        self.runtime.block_on(self.client.delete_gateway(input))
+    }
+
+    /// Blocking version of [`StorageGateway::delete_snapshot_schedule`].
+    pub fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleInput,
+    ) -> Result<DeleteSnapshotScheduleOutput, RusotoError<DeleteSnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.delete_snapshot_schedule(input))
+    }
+
+    /// Blocking version of [`StorageGateway::delete_tape`].
+    pub fn delete_tape(
+        &self,
+        input: DeleteTapeInput,
+    ) -> Result<DeleteTapeOutput, RusotoError<DeleteTapeError>> {
+        self.runtime.block_on(self.client.delete_tape(input))
+    }
+
+    /// Blocking version of [`StorageGateway::delete_tape_archive`].
+    pub fn delete_tape_archive(
+        &self,
+        input: DeleteTapeArchiveInput,
+    ) -> Result<DeleteTapeArchiveOutput, RusotoError<DeleteTapeArchiveError>> {
+        self.runtime
+            .block_on(self.client.delete_tape_archive(input))
+    }
+
+    /// Blocking version of [`StorageGateway::delete_tape_pool`].
+    pub fn delete_tape_pool(
+        &self,
+        input: DeleteTapePoolInput,
+    ) -> Result<DeleteTapePoolOutput, RusotoError<DeleteTapePoolError>> {
+        self.runtime.block_on(self.client.delete_tape_pool(input))
+    }
+
+    /// Blocking version of [`StorageGateway::delete_volume`].
+    pub fn delete_volume(
+        &self,
+        input: DeleteVolumeInput,
+    ) -> Result<DeleteVolumeOutput, RusotoError<DeleteVolumeError>> {
+        self.runtime.block_on(self.client.delete_volume(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_availability_monitor_test`].
+    pub fn describe_availability_monitor_test(
+        &self,
+        input: DescribeAvailabilityMonitorTestInput,
+    ) -> Result<
+        DescribeAvailabilityMonitorTestOutput,
+        RusotoError<DescribeAvailabilityMonitorTestError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_availability_monitor_test(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_bandwidth_rate_limit`].
+    pub fn describe_bandwidth_rate_limit(
+        &self,
+        input: DescribeBandwidthRateLimitInput,
+    ) -> Result<DescribeBandwidthRateLimitOutput, RusotoError<DescribeBandwidthRateLimitError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_bandwidth_rate_limit(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_bandwidth_rate_limit_schedule`].
+    pub fn describe_bandwidth_rate_limit_schedule(
+        &self,
+        input: DescribeBandwidthRateLimitScheduleInput,
+    ) -> Result<
+        DescribeBandwidthRateLimitScheduleOutput,
+        RusotoError<DescribeBandwidthRateLimitScheduleError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_bandwidth_rate_limit_schedule(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_cache`].
+    pub fn describe_cache(
+        &self,
+        input: DescribeCacheInput,
+    ) -> Result<DescribeCacheOutput, RusotoError<DescribeCacheError>> {
+        self.runtime.block_on(self.client.describe_cache(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_cachedi_scsi_volumes`].
+    pub fn describe_cachedi_scsi_volumes(
+        &self,
+        input: DescribeCachediSCSIVolumesInput,
+    ) -> Result<DescribeCachediSCSIVolumesOutput, RusotoError<DescribeCachediSCSIVolumesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_cachedi_scsi_volumes(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_chap_credentials`].
+    pub fn describe_chap_credentials(
+        &self,
+        input: DescribeChapCredentialsInput,
+    ) -> Result<DescribeChapCredentialsOutput, RusotoError<DescribeChapCredentialsError>> {
+        self.runtime
+            .block_on(self.client.describe_chap_credentials(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_gateway_information`].
+    pub fn describe_gateway_information(
+        &self,
+        input: DescribeGatewayInformationInput,
+    ) -> Result<DescribeGatewayInformationOutput, RusotoError<DescribeGatewayInformationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_gateway_information(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_maintenance_start_time`].
+    pub fn describe_maintenance_start_time(
+        &self,
+        input: DescribeMaintenanceStartTimeInput,
+    ) -> Result<DescribeMaintenanceStartTimeOutput, RusotoError<DescribeMaintenanceStartTimeError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_maintenance_start_time(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_nfs_file_shares`].
+    pub fn describe_nfs_file_shares(
+        &self,
+        input: DescribeNFSFileSharesInput,
+    ) -> Result<DescribeNFSFileSharesOutput, RusotoError<DescribeNFSFileSharesError>> {
+        self.runtime
+            .block_on(self.client.describe_nfs_file_shares(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_smb_file_shares`].
+    pub fn describe_smb_file_shares(
+        &self,
+        input: DescribeSMBFileSharesInput,
+    ) -> Result<DescribeSMBFileSharesOutput, RusotoError<DescribeSMBFileSharesError>> {
+        self.runtime
+            .block_on(self.client.describe_smb_file_shares(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_smb_settings`].
+    pub fn describe_smb_settings(
+        &self,
+        input: DescribeSMBSettingsInput,
+    ) -> Result<DescribeSMBSettingsOutput, RusotoError<DescribeSMBSettingsError>> {
+        self.runtime
+            .block_on(self.client.describe_smb_settings(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_snapshot_schedule`].
+    pub fn describe_snapshot_schedule(
+        &self,
+        input: DescribeSnapshotScheduleInput,
+    ) -> Result<DescribeSnapshotScheduleOutput, RusotoError<DescribeSnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.describe_snapshot_schedule(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_storedi_scsi_volumes`].
+    pub fn describe_storedi_scsi_volumes(
+        &self,
+        input: DescribeStorediSCSIVolumesInput,
+    ) -> Result<DescribeStorediSCSIVolumesOutput, RusotoError<DescribeStorediSCSIVolumesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_storedi_scsi_volumes(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_tape_archives`].
+    pub fn describe_tape_archives(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> Result<DescribeTapeArchivesOutput, RusotoError<DescribeTapeArchivesError>> {
+        self.runtime
+            .block_on(self.client.describe_tape_archives(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_tape_recovery_points`].
+    pub fn describe_tape_recovery_points(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> Result<DescribeTapeRecoveryPointsOutput, RusotoError<DescribeTapeRecoveryPointsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_tape_recovery_points(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_tapes`].
+    pub fn describe_tapes(
+        &self,
+        input: DescribeTapesInput,
+    ) -> Result<DescribeTapesOutput, RusotoError<DescribeTapesError>> {
+        self.runtime.block_on(self.client.describe_tapes(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_upload_buffer`].
+    pub fn describe_upload_buffer(
+        &self,
+        input: DescribeUploadBufferInput,
+    ) -> Result<DescribeUploadBufferOutput, RusotoError<DescribeUploadBufferError>> {
+        self.runtime
+            .block_on(self.client.describe_upload_buffer(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_vtl_devices`].
+    pub fn describe_vtl_devices(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> Result<DescribeVTLDevicesOutput, RusotoError<DescribeVTLDevicesError>> {
+        self.runtime
+            .block_on(self.client.describe_vtl_devices(input))
+    }
+
+    /// Blocking version of [`StorageGateway::describe_working_storage`].
+    pub fn describe_working_storage(
+        &self,
+        input: DescribeWorkingStorageInput,
+    ) -> Result<DescribeWorkingStorageOutput, RusotoError<DescribeWorkingStorageError>> {
+        self.runtime
+            .block_on(self.client.describe_working_storage(input))
+    }
+
+    /// Blocking version of [`StorageGateway::detach_volume`].
+    pub fn detach_volume(
+        &self,
+        input: DetachVolumeInput,
+    ) -> Result<DetachVolumeOutput, RusotoError<DetachVolumeError>> {
+        self.runtime.block_on(self.client.detach_volume(input))
+    }
+
+    /// Blocking version of [`StorageGateway::disable_gateway`].
+    pub fn disable_gateway(
+        &self,
+        input: DisableGatewayInput,
+    ) -> Result<DisableGatewayOutput, RusotoError<DisableGatewayError>> {
+        self.runtime.block_on(self.client.disable_gateway(input))
+    }
+
+    /// Blocking version of [`StorageGateway::join_domain`].
+    pub fn join_domain(
+        &self,
+        input: JoinDomainInput,
+    ) -> Result<JoinDomainOutput, RusotoError<JoinDomainError>> {
+        self.runtime.block_on(self.client.join_domain(input))
+    }
+
+    /// Blocking version of [`StorageGateway::list_automatic_tape_creation_policies`].
+    pub fn list_automatic_tape_creation_policies(
+        &self,
+        input: ListAutomaticTapeCreationPoliciesInput,
+    ) -> Result<
+        ListAutomaticTapeCreationPoliciesOutput,
+        RusotoError<ListAutomaticTapeCreationPoliciesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_automatic_tape_creation_policies(input))
+    }
+
+    /// Blocking version of [`StorageGateway::list_file_shares`].
+    pub fn list_file_shares(
+        &self,
+        input: ListFileSharesInput,
+    ) -> Result<ListFileSharesOutput, RusotoError<ListFileSharesError>> {
+        self.runtime.block_on(self.client.list_file_shares(input))
+    }
+
+    /// Blocking version of [`StorageGateway::list_gateways`].
+    pub fn list_gateways(
+        &self,
+        input: ListGatewaysInput,
+    ) -> Result<ListGatewaysOutput, RusotoError<ListGatewaysError>> {
+        self.runtime.block_on(self.client.list_gateways(input))
+    }
+
+    /// Blocking version of [`StorageGateway::list_local_disks`].
+    pub fn list_local_disks(
+        &self,
+        input: ListLocalDisksInput,
+    ) -> Result<ListLocalDisksOutput, RusotoError<ListLocalDisksError>> {
+        self.runtime.block_on(self.client.list_local_disks(input))
+    }
+
+    /// Blocking version of [`StorageGateway::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`StorageGateway::list_tape_pools`].
+    pub fn list_tape_pools(
+        &self,
+        input: ListTapePoolsInput,
+    ) -> Result<ListTapePoolsOutput, RusotoError<ListTapePoolsError>> {
+        self.runtime.block_on(self.client.list_tape_pools(input))
+    }
+
+    /// Blocking version of [`StorageGateway::list_tapes`].
+    pub fn list_tapes(
+        &self,
+        input: ListTapesInput,
+    ) -> Result<ListTapesOutput, RusotoError<ListTapesError>> {
+        self.runtime.block_on(self.client.list_tapes(input))
+    }
+
+    /// Blocking version of [`StorageGateway::list_volume_initiators`].
+    pub fn list_volume_initiators(
+        &self,
+        input: ListVolumeInitiatorsInput,
+    ) -> Result<ListVolumeInitiatorsOutput, RusotoError<ListVolumeInitiatorsError>> {
+        self.runtime
+            .block_on(self.client.list_volume_initiators(input))
+    }
+
+    /// Blocking version of [`StorageGateway::list_volume_recovery_points`].
+    pub fn list_volume_recovery_points(
+        &self,
+        input: ListVolumeRecoveryPointsInput,
+    ) -> Result<ListVolumeRecoveryPointsOutput, RusotoError<ListVolumeRecoveryPointsError>> {
+        self.runtime
+            .block_on(self.client.list_volume_recovery_points(input))
+    }
+
+    /// Blocking version of [`StorageGateway::list_volumes`].
+    pub fn list_volumes(
+        &self,
+        input: ListVolumesInput,
+    ) -> Result<ListVolumesOutput, RusotoError<ListVolumesError>> {
+        self.runtime.block_on(self.client.list_volumes(input))
+    }
+
+    /// Blocking version of [`StorageGateway::notify_when_uploaded`].
+    pub fn notify_when_uploaded(
+        &self,
+        input: NotifyWhenUploadedInput,
+    ) -> Result<NotifyWhenUploadedOutput, RusotoError<NotifyWhenUploadedError>> {
+        self.runtime
+            .block_on(self.client.notify_when_uploaded(input))
+    }
+
+    /// Blocking version of [`StorageGateway::refresh_cache`].
+    pub fn refresh_cache(
+        &self,
+        input: RefreshCacheInput,
+    ) -> Result<RefreshCacheOutput, RusotoError<RefreshCacheError>> {
+        self.runtime.block_on(self.client.refresh_cache(input))
+    }
+
+    /// Blocking version of [`StorageGateway::remove_tags_from_resource`].
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceInput,
+    ) -> Result<RemoveTagsFromResourceOutput, RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// Blocking version of [`StorageGateway::reset_cache`].
+    pub fn reset_cache(
+        &self,
+        input: ResetCacheInput,
+    ) -> Result<ResetCacheOutput, RusotoError<ResetCacheError>> {
+        self.runtime.block_on(self.client.reset_cache(input))
+    }
+
+    /// Blocking version of [`StorageGateway::retrieve_tape_archive`].
+    pub fn retrieve_tape_archive(
+        &self,
+        input: RetrieveTapeArchiveInput,
+    ) -> Result<RetrieveTapeArchiveOutput, RusotoError<RetrieveTapeArchiveError>> {
+        self.runtime
+            .block_on(self.client.retrieve_tape_archive(input))
+    }
+
+    /// Blocking version of [`StorageGateway::retrieve_tape_recovery_point`].
+    pub fn retrieve_tape_recovery_point(
+        &self,
+        input: RetrieveTapeRecoveryPointInput,
+    ) -> Result<RetrieveTapeRecoveryPointOutput, RusotoError<RetrieveTapeRecoveryPointError>> {
+        self.runtime
+            .block_on(self.client.retrieve_tape_recovery_point(input))
+    }
+
+    /// Blocking version of [`StorageGateway::set_local_console_password`].
+    pub fn set_local_console_password(
+        &self,
+        input: SetLocalConsolePasswordInput,
+    ) -> Result<SetLocalConsolePasswordOutput, RusotoError<SetLocalConsolePasswordError>> {
+        self.runtime
+            .block_on(self.client.set_local_console_password(input))
+    }
+
+    /// Blocking version of [`StorageGateway::set_smb_guest_password`].
+    pub fn set_smb_guest_password(
+        &self,
+        input: SetSMBGuestPasswordInput,
+    ) -> Result<SetSMBGuestPasswordOutput, RusotoError<SetSMBGuestPasswordError>> {
+        self.runtime
+            .block_on(self.client.set_smb_guest_password(input))
+    }
+
+    /// Blocking version of [`StorageGateway::shutdown_gateway`].
+    pub fn shutdown_gateway(
+        &self,
+        input: ShutdownGatewayInput,
+    ) -> Result<ShutdownGatewayOutput, RusotoError<ShutdownGatewayError>> {
+        self.runtime.block_on(self.client.shutdown_gateway(input))
+    }
+
+    /// Blocking version of [`StorageGateway::start_availability_monitor_test`].
+    pub fn start_availability_monitor_test(
+        &self,
+        input: StartAvailabilityMonitorTestInput,
+    ) -> Result<StartAvailabilityMonitorTestOutput, RusotoError<StartAvailabilityMonitorTestError>>
+    {
+        self.runtime
+            .block_on(self.client.start_availability_monitor_test(input))
+    }
+
+    /// Blocking version of [`StorageGateway::start_gateway`].
+    pub fn start_gateway(
+        &self,
+        input: StartGatewayInput,
+    ) -> Result<StartGatewayOutput, RusotoError<StartGatewayError>> {
+        self.runtime.block_on(self.client.start_gateway(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_automatic_tape_creation_policy`].
+    pub fn update_automatic_tape_creation_policy(
+        &self,
+        input: UpdateAutomaticTapeCreationPolicyInput,
+    ) -> Result<
+        UpdateAutomaticTapeCreationPolicyOutput,
+        RusotoError<UpdateAutomaticTapeCreationPolicyError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_automatic_tape_creation_policy(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_bandwidth_rate_limit`].
+    pub fn update_bandwidth_rate_limit(
+        &self,
+        input: UpdateBandwidthRateLimitInput,
+    ) -> Result<UpdateBandwidthRateLimitOutput, RusotoError<UpdateBandwidthRateLimitError>> {
+        self.runtime
+            .block_on(self.client.update_bandwidth_rate_limit(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_bandwidth_rate_limit_schedule`].
+    pub fn update_bandwidth_rate_limit_schedule(
+        &self,
+        input: UpdateBandwidthRateLimitScheduleInput,
+    ) -> Result<
+        UpdateBandwidthRateLimitScheduleOutput,
+        RusotoError<UpdateBandwidthRateLimitScheduleError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_bandwidth_rate_limit_schedule(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_chap_credentials`].
+    pub fn update_chap_credentials(
+        &self,
+        input: UpdateChapCredentialsInput,
+    ) -> Result<UpdateChapCredentialsOutput, RusotoError<UpdateChapCredentialsError>> {
+        self.runtime
+            .block_on(self.client.update_chap_credentials(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_gateway_information`].
+    pub fn update_gateway_information(
+        &self,
+        input: UpdateGatewayInformationInput,
+    ) -> Result<UpdateGatewayInformationOutput, RusotoError<UpdateGatewayInformationError>> {
+        self.runtime
+            .block_on(self.client.update_gateway_information(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_gateway_software_now`].
+    pub fn update_gateway_software_now(
+        &self,
+        input: UpdateGatewaySoftwareNowInput,
+    ) -> Result<UpdateGatewaySoftwareNowOutput, RusotoError<UpdateGatewaySoftwareNowError>> {
+        self.runtime
+            .block_on(self.client.update_gateway_software_now(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_maintenance_start_time`].
+    pub fn update_maintenance_start_time(
+        &self,
+        input: UpdateMaintenanceStartTimeInput,
+    ) -> Result<UpdateMaintenanceStartTimeOutput, RusotoError<UpdateMaintenanceStartTimeError>>
+    {
+        self.runtime
+            .block_on(self.client.update_maintenance_start_time(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_nfs_file_share`].
+    pub fn update_nfs_file_share(
+        &self,
+        input: UpdateNFSFileShareInput,
+    ) -> Result<UpdateNFSFileShareOutput, RusotoError<UpdateNFSFileShareError>> {
+        self.runtime
+            .block_on(self.client.update_nfs_file_share(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_smb_file_share`].
+    pub fn update_smb_file_share(
+        &self,
+        input: UpdateSMBFileShareInput,
+    ) -> Result<UpdateSMBFileShareOutput, RusotoError<UpdateSMBFileShareError>> {
+        self.runtime
+            .block_on(self.client.update_smb_file_share(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_smb_file_share_visibility`].
+    pub fn update_smb_file_share_visibility(
+        &self,
+        input: UpdateSMBFileShareVisibilityInput,
+    ) -> Result<UpdateSMBFileShareVisibilityOutput, RusotoError<UpdateSMBFileShareVisibilityError>>
+    {
+        self.runtime
+            .block_on(self.client.update_smb_file_share_visibility(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_smb_security_strategy`].
+    pub fn update_smb_security_strategy(
+        &self,
+        input: UpdateSMBSecurityStrategyInput,
+    ) -> Result<UpdateSMBSecurityStrategyOutput, RusotoError<UpdateSMBSecurityStrategyError>> {
+        self.runtime
+            .block_on(self.client.update_smb_security_strategy(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_snapshot_schedule`].
+    pub fn update_snapshot_schedule(
+        &self,
+        input: UpdateSnapshotScheduleInput,
+    ) -> Result<UpdateSnapshotScheduleOutput, RusotoError<UpdateSnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.update_snapshot_schedule(input))
+    }
+
+    /// Blocking version of [`StorageGateway::update_vtl_device_type`].
+    pub fn update_vtl_device_type(
+        &self,
+        input: UpdateVTLDeviceTypeInput,
+    ) -> Result<UpdateVTLDeviceTypeOutput, RusotoError<UpdateVTLDeviceTypeError>> {
+        self.runtime
+            .block_on(self.client.update_vtl_device_type(input))
+    }
+}
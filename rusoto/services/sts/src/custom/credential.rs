@@ -3,7 +3,7 @@ use chrono::prelude::*;
 use chrono::Duration;
 
 use rusoto_core;
-use rusoto_core::RusotoError;
+use rusoto_core::{AwsConfig, RusotoError};
 
 use crate::{
     AssumeRoleError, AssumeRoleRequest, AssumeRoleResponse, AssumeRoleWithSAMLError,
@@ -15,7 +15,9 @@ use crate::{
     GetFederationTokenResponse, GetSessionTokenError, GetSessionTokenRequest,
     GetSessionTokenResponse, Sts, StsClient,
 };
-use rusoto_core::credential::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+use rusoto_core::credential::{
+    AwsCredentials, CredentialsError, ProfileProvider, ProvideAwsCredentials,
+};
 
 pub const DEFAULT_DURATION_SECONDS: i32 = 3600;
 pub const DEFAULT_ROLE_DURATION_SECONDS: i32 = 900;
@@ -270,6 +272,48 @@ impl StsAssumeRoleSessionCredentialsProvider {
         }
     }
 
+    /// Creates a provider assuming the role of the `role_arn` setting of the profile of the
+    /// configuration, with its `role_session_name`, `external_id`, `mfa_serial` and
+    /// `duration_seconds` settings.
+    ///
+    /// The role is assumed with the credentials of the profile named by the `source_profile`
+    /// setting, or with the credentials provider of the configuration. Fails if the profile
+    /// has no `role_arn` setting.
+    pub fn from_config(
+        config: &AwsConfig,
+    ) -> Result<StsAssumeRoleSessionCredentialsProvider, CredentialsError> {
+        let setting = |name: &str| config.get_setting(name).map(str::to_owned);
+        let role_arn = setting("role_arn").ok_or_else(|| {
+            CredentialsError::new(format!(
+                "The profile {} has no role_arn",
+                config.get_profile()
+            ))
+        })?;
+        let mut sts_config = config.clone();
+        if let Some(source_profile) = config.get_setting("source_profile") {
+            sts_config
+                .credentials_provider(ProfileProvider::with_default_credentials(source_profile)?);
+        }
+        let session_duration = match config.get_role_session_duration() {
+            Some(duration) => Some(
+                Duration::from_std(duration)
+                    .map_err(|_| CredentialsError::new("Invalid duration_seconds"))?,
+            ),
+            None => None,
+        };
+        let session_name = setting("role_session_name")
+            .unwrap_or_else(|| format!("rusoto-session-{}", Utc::now().timestamp()));
+        Ok(StsAssumeRoleSessionCredentialsProvider::new(
+            StsClient::new_with_config(&sts_config),
+            role_arn,
+            session_name,
+            setting("external_id"),
+            session_duration,
+            None,
+            setting("mfa_serial"),
+        ))
+    }
+
     /// Set the MFA code for use when acquiring session tokens.
     pub fn set_mfa_code<S>(&mut self, code: S)
     where
@@ -416,3 +460,26 @@ fn sts_futures_are_send() {
     is_send::<StsAssumeRoleSessionCredentialsProvider>();
     is_send::<StsWebIdentityFederationSessionCredentialsProvider>();
 }
+
+#[test]
+fn assumes_the_role_of_the_profile() {
+    use std::io::Write;
+
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    write!(
+        file,
+        "[profile admin]\nrole_arn = arn:aws:iam::123456789012:role/admin\n\
+         role_session_name = session\nduration_seconds = 1800\n"
+    )
+    .unwrap();
+    std::env::set_var("AWS_CONFIG_FILE", file.path());
+
+    let config = AwsConfig::load_profile("admin").unwrap();
+    let provider = StsAssumeRoleSessionCredentialsProvider::from_config(&config).unwrap();
+    assert_eq!(provider.role_arn, "arn:aws:iam::123456789012:role/admin");
+    assert_eq!(provider.session_name, "session");
+    assert_eq!(provider.session_duration, Duration::seconds(1800));
+
+    let config = AwsConfig::load_profile("missing").unwrap();
+    assert!(StsAssumeRoleSessionCredentialsProvider::from_config(&config).is_err());
+}
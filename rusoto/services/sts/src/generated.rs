@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -1097,24 +1098,36 @@ impl AssumeRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ExpiredTokenException" => {
-                        return RusotoError::Service(AssumeRoleError::ExpiredToken(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AssumeRoleError::ExpiredToken,
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(AssumeRoleError::MalformedPolicyDocument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AssumeRoleError::MalformedPolicyDocument,
+                        )
                     }
                     "PackedPolicyTooLarge" => {
-                        return RusotoError::Service(AssumeRoleError::PackedPolicyTooLarge(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AssumeRoleError::PackedPolicyTooLarge,
+                        )
                     }
                     "RegionDisabledException" => {
-                        return RusotoError::Service(AssumeRoleError::RegionDisabled(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AssumeRoleError::RegionDisabled,
+                        )
                     }
                     _ => {}
                 }
@@ -1169,34 +1182,52 @@ impl AssumeRoleWithSAMLError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ExpiredTokenException" => {
-                        return RusotoError::Service(AssumeRoleWithSAMLError::ExpiredToken(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AssumeRoleWithSAMLError::ExpiredToken,
+                        )
                     }
                     "IDPRejectedClaim" => {
-                        return RusotoError::Service(AssumeRoleWithSAMLError::IDPRejectedClaim(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AssumeRoleWithSAMLError::IDPRejectedClaim,
+                        )
                     }
                     "InvalidIdentityToken" => {
-                        return RusotoError::Service(AssumeRoleWithSAMLError::InvalidIdentityToken(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AssumeRoleWithSAMLError::InvalidIdentityToken,
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(
-                            AssumeRoleWithSAMLError::MalformedPolicyDocument(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssumeRoleWithSAMLError::MalformedPolicyDocument,
                         )
                     }
                     "PackedPolicyTooLarge" => {
-                        return RusotoError::Service(AssumeRoleWithSAMLError::PackedPolicyTooLarge(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AssumeRoleWithSAMLError::PackedPolicyTooLarge,
+                        )
                     }
                     "RegionDisabledException" => {
-                        return RusotoError::Service(AssumeRoleWithSAMLError::RegionDisabled(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AssumeRoleWithSAMLError::RegionDisabled,
+                        )
                     }
                     _ => {}
                 }
@@ -1255,46 +1286,59 @@ impl AssumeRoleWithWebIdentityError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ExpiredTokenException" => {
-                        return RusotoError::Service(AssumeRoleWithWebIdentityError::ExpiredToken(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AssumeRoleWithWebIdentityError::ExpiredToken,
+                        )
                     }
                     "IDPCommunicationError" => {
-                        return RusotoError::Service(
-                            AssumeRoleWithWebIdentityError::IDPCommunicationError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssumeRoleWithWebIdentityError::IDPCommunicationError,
                         )
                     }
                     "IDPRejectedClaim" => {
-                        return RusotoError::Service(
-                            AssumeRoleWithWebIdentityError::IDPRejectedClaim(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssumeRoleWithWebIdentityError::IDPRejectedClaim,
                         )
                     }
                     "InvalidIdentityToken" => {
-                        return RusotoError::Service(
-                            AssumeRoleWithWebIdentityError::InvalidIdentityToken(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssumeRoleWithWebIdentityError::InvalidIdentityToken,
                         )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(
-                            AssumeRoleWithWebIdentityError::MalformedPolicyDocument(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssumeRoleWithWebIdentityError::MalformedPolicyDocument,
                         )
                     }
                     "PackedPolicyTooLarge" => {
-                        return RusotoError::Service(
-                            AssumeRoleWithWebIdentityError::PackedPolicyTooLarge(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssumeRoleWithWebIdentityError::PackedPolicyTooLarge,
                         )
                     }
                     "RegionDisabledException" => {
-                        return RusotoError::Service(
-                            AssumeRoleWithWebIdentityError::RegionDisabled(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssumeRoleWithWebIdentityError::RegionDisabled,
                         )
                     }
                     _ => {}
@@ -1353,10 +1397,11 @@ impl DecodeAuthorizationMessageError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidAuthorizationMessageException" => {
-                        return RusotoError::Service(
-                            DecodeAuthorizationMessageError::InvalidAuthorizationMessage(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DecodeAuthorizationMessageError::InvalidAuthorizationMessage,
                         )
                     }
                     _ => {}
@@ -1473,19 +1518,28 @@ impl GetFederationTokenError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(
-                            GetFederationTokenError::MalformedPolicyDocument(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetFederationTokenError::MalformedPolicyDocument,
                         )
                     }
                     "PackedPolicyTooLarge" => {
-                        return RusotoError::Service(GetFederationTokenError::PackedPolicyTooLarge(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetFederationTokenError::PackedPolicyTooLarge,
+                        )
                     }
                     "RegionDisabledException" => {
-                        return RusotoError::Service(GetFederationTokenError::RegionDisabled(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetFederationTokenError::RegionDisabled,
+                        )
                     }
                     _ => {}
                 }
@@ -1529,9 +1583,12 @@ impl GetSessionTokenError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RegionDisabledException" => {
-                        return RusotoError::Service(GetSessionTokenError::RegionDisabled(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetSessionTokenError::RegionDisabled,
+                        )
                     }
                     _ => {}
                 }
@@ -1618,11 +1675,12 @@ pub struct StsClient {
 impl StsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> StsClient {
         StsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "STS"),
         }
     }
 
@@ -1637,12 +1695,32 @@ impl StsClient {
     {
         StsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "STS"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> StsClient {
-        StsClient { client, region }
+        StsClient {
+            client,
+            region: region_for_service(region, "STS"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> StsClient {
+        StsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("STS"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> StsClient {
+        StsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1654,6 +1732,7 @@ impl Sts for StsClient {
         input: AssumeRoleRequest,
     ) -> Result<AssumeRoleResponse, RusotoError<AssumeRoleError>> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("AssumeRole");
         let params = self.new_params("AssumeRole");
         let mut params = params;
         AssumeRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -1684,6 +1763,7 @@ impl Sts for StsClient {
         input: AssumeRoleWithSAMLRequest,
     ) -> Result<AssumeRoleWithSAMLResponse, RusotoError<AssumeRoleWithSAMLError>> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("AssumeRoleWithSAML");
         let params = self.new_params("AssumeRoleWithSAML");
         let mut params = params;
         AssumeRoleWithSAMLRequestSerializer::serialize(&mut params, "", &input);
@@ -1718,6 +1798,7 @@ impl Sts for StsClient {
     ) -> Result<AssumeRoleWithWebIdentityResponse, RusotoError<AssumeRoleWithWebIdentityError>>
     {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("AssumeRoleWithWebIdentity");
         let params = self.new_params("AssumeRoleWithWebIdentity");
         let mut params = params;
         AssumeRoleWithWebIdentityRequestSerializer::serialize(&mut params, "", &input);
@@ -1752,6 +1833,7 @@ impl Sts for StsClient {
     ) -> Result<DecodeAuthorizationMessageResponse, RusotoError<DecodeAuthorizationMessageError>>
     {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("DecodeAuthorizationMessage");
         let params = self.new_params("DecodeAuthorizationMessage");
         let mut params = params;
         DecodeAuthorizationMessageRequestSerializer::serialize(&mut params, "", &input);
@@ -1785,6 +1867,7 @@ impl Sts for StsClient {
         input: GetAccessKeyInfoRequest,
     ) -> Result<GetAccessKeyInfoResponse, RusotoError<GetAccessKeyInfoError>> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("GetAccessKeyInfo");
         let params = self.new_params("GetAccessKeyInfo");
         let mut params = params;
         GetAccessKeyInfoRequestSerializer::serialize(&mut params, "", &input);
@@ -1816,6 +1899,7 @@ impl Sts for StsClient {
         input: GetCallerIdentityRequest,
     ) -> Result<GetCallerIdentityResponse, RusotoError<GetCallerIdentityError>> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("GetCallerIdentity");
         let params = self.new_params("GetCallerIdentity");
         let mut params = params;
         GetCallerIdentityRequestSerializer::serialize(&mut params, "", &input);
@@ -1849,6 +1933,7 @@ impl Sts for StsClient {
         input: GetFederationTokenRequest,
     ) -> Result<GetFederationTokenResponse, RusotoError<GetFederationTokenError>> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("GetFederationToken");
         let params = self.new_params("GetFederationToken");
         let mut params = params;
         GetFederationTokenRequestSerializer::serialize(&mut params, "", &input);
@@ -1882,6 +1967,7 @@ impl Sts for StsClient {
         input: GetSessionTokenRequest,
     ) -> Result<GetSessionTokenResponse, RusotoError<GetSessionTokenError>> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("GetSessionToken");
         let params = self.new_params("GetSessionToken");
         let mut params = params;
         GetSessionTokenRequestSerializer::serialize(&mut params, "", &input);
@@ -1908,6 +1994,153 @@ impl Sts for StsClient {
     }
 }
 
+/// A blocking client for the AWS STS API, running the calls of a `StsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `StsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct StsBlockingClient {
+    client: StsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl StsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> StsBlockingClient {
+        StsBlockingClient::from_client(StsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> StsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        StsBlockingClient::from_client(StsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> StsBlockingClient {
+        StsBlockingClient::from_client(StsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> StsBlockingClient {
+        StsBlockingClient::from_client(StsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: StsClient) -> StsBlockingClient {
+        StsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> StsBlockingClient {
+        StsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> StsBlockingClient {
+        StsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Sts::assume_role`].
+    pub fn assume_role(
+        &self,
+        input: AssumeRoleRequest,
+    ) -> Result<AssumeRoleResponse, RusotoError<AssumeRoleError>> {
+        self.runtime.block_on(self.client.assume_role(input))
+    }
+
+    /// Blocking version of [`Sts::assume_role_with_saml`].
+    pub fn assume_role_with_saml(
+        &self,
+        input: AssumeRoleWithSAMLRequest,
+    ) -> Result<AssumeRoleWithSAMLResponse, RusotoError<AssumeRoleWithSAMLError>> {
+        self.runtime
+            .block_on(self.client.assume_role_with_saml(input))
+    }
+
+    /// Blocking version of [`Sts::assume_role_with_web_identity`].
+    pub fn assume_role_with_web_identity(
+        &self,
+        input: AssumeRoleWithWebIdentityRequest,
+    ) -> Result<AssumeRoleWithWebIdentityResponse, RusotoError<AssumeRoleWithWebIdentityError>>
+    {
+        self.runtime
+            .block_on(self.client.assume_role_with_web_identity(input))
+    }
+
+    /// Blocking version of [`Sts::decode_authorization_message`].
+    pub fn decode_authorization_message(
+        &self,
+        input: DecodeAuthorizationMessageRequest,
+    ) -> Result<DecodeAuthorizationMessageResponse, RusotoError<DecodeAuthorizationMessageError>>
+    {
+        self.runtime
+            .block_on(self.client.decode_authorization_message(input))
+    }
+
+    /// Blocking version of [`Sts::get_access_key_info`].
+    pub fn get_access_key_info(
+        &self,
+        input: GetAccessKeyInfoRequest,
+    ) -> Result<GetAccessKeyInfoResponse, RusotoError<GetAccessKeyInfoError>> {
+        self.runtime
+            .block_on(self.client.get_access_key_info(input))
+    }
+
+    /// Blocking version of [`Sts::get_caller_identity`].
+    pub fn get_caller_identity(
+        &self,
+        input: GetCallerIdentityRequest,
+    ) -> Result<GetCallerIdentityResponse, RusotoError<GetCallerIdentityError>> {
+        self.runtime
+            .block_on(self.client.get_caller_identity(input))
+    }
+
+    /// Blocking version of [`Sts::get_federation_token`].
+    pub fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> Result<GetFederationTokenResponse, RusotoError<GetFederationTokenError>> {
+        self.runtime
+            .block_on(self.client.get_federation_token(input))
+    }
+
+    /// Blocking version of [`Sts::get_session_token`].
+    pub fn get_session_token(
+        &self,
+        input: GetSessionTokenRequest,
+    ) -> Result<GetSessionTokenResponse, RusotoError<GetSessionTokenError>> {
+        self.runtime.block_on(self.client.get_session_token(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
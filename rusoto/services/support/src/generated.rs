@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -761,31 +762,46 @@ impl AddAttachmentsToSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttachmentLimitExceeded" => {
-                    return RusotoError::Service(AddAttachmentsToSetError::AttachmentLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddAttachmentsToSetError::AttachmentLimitExceeded,
+                    )
                 }
                 "AttachmentSetExpired" => {
-                    return RusotoError::Service(AddAttachmentsToSetError::AttachmentSetExpired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddAttachmentsToSetError::AttachmentSetExpired,
+                    )
                 }
                 "AttachmentSetIdNotFound" => {
-                    return RusotoError::Service(AddAttachmentsToSetError::AttachmentSetIdNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddAttachmentsToSetError::AttachmentSetIdNotFound,
+                    )
                 }
                 "AttachmentSetSizeLimitExceeded" => {
-                    return RusotoError::Service(
-                        AddAttachmentsToSetError::AttachmentSetSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddAttachmentsToSetError::AttachmentSetSizeLimitExceeded,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(AddAttachmentsToSetError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddAttachmentsToSetError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -825,26 +841,38 @@ impl AddCommunicationToCaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttachmentSetExpired" => {
-                    return RusotoError::Service(AddCommunicationToCaseError::AttachmentSetExpired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddCommunicationToCaseError::AttachmentSetExpired,
+                    )
                 }
                 "AttachmentSetIdNotFound" => {
-                    return RusotoError::Service(
-                        AddCommunicationToCaseError::AttachmentSetIdNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddCommunicationToCaseError::AttachmentSetIdNotFound,
                     )
                 }
                 "CaseIdNotFound" => {
-                    return RusotoError::Service(AddCommunicationToCaseError::CaseIdNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddCommunicationToCaseError::CaseIdNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(AddCommunicationToCaseError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddCommunicationToCaseError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -883,20 +911,38 @@ impl CreateCaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttachmentSetExpired" => {
-                    return RusotoError::Service(CreateCaseError::AttachmentSetExpired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCaseError::AttachmentSetExpired,
+                    )
                 }
                 "AttachmentSetIdNotFound" => {
-                    return RusotoError::Service(CreateCaseError::AttachmentSetIdNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCaseError::AttachmentSetIdNotFound,
+                    )
                 }
                 "CaseCreationLimitExceeded" => {
-                    return RusotoError::Service(CreateCaseError::CaseCreationLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCaseError::CaseCreationLimitExceeded,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateCaseError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCaseError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -931,21 +977,30 @@ impl DescribeAttachmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttachmentIdNotFound" => {
-                    return RusotoError::Service(DescribeAttachmentError::AttachmentIdNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAttachmentError::AttachmentIdNotFound,
+                    )
                 }
                 "DescribeAttachmentLimitExceeded" => {
-                    return RusotoError::Service(
-                        DescribeAttachmentError::DescribeAttachmentLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAttachmentError::DescribeAttachmentLimitExceeded,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeAttachmentError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAttachmentError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -979,12 +1034,22 @@ impl DescribeCasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CaseIdNotFound" => {
-                    return RusotoError::Service(DescribeCasesError::CaseIdNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCasesError::CaseIdNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeCasesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCasesError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1015,16 +1080,22 @@ impl DescribeCommunicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CaseIdNotFound" => {
-                    return RusotoError::Service(DescribeCommunicationsError::CaseIdNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCommunicationsError::CaseIdNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeCommunicationsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCommunicationsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1053,11 +1124,14 @@ impl DescribeServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeServicesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeServicesError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1085,11 +1159,14 @@ impl DescribeSeverityLevelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeSeverityLevelsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSeverityLevelsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1119,13 +1196,14 @@ impl DescribeTrustedAdvisorCheckRefreshStatusesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeTrustedAdvisorCheckRefreshStatusesError::InternalServerError(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrustedAdvisorCheckRefreshStatusesError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1157,11 +1235,14 @@ impl DescribeTrustedAdvisorCheckResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeTrustedAdvisorCheckResultError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrustedAdvisorCheckResultError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1193,11 +1274,14 @@ impl DescribeTrustedAdvisorCheckSummariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeTrustedAdvisorCheckSummariesError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrustedAdvisorCheckSummariesError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1229,11 +1313,14 @@ impl DescribeTrustedAdvisorChecksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeTrustedAdvisorChecksError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrustedAdvisorChecksError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1265,11 +1352,14 @@ impl RefreshTrustedAdvisorCheckError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        RefreshTrustedAdvisorCheckError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RefreshTrustedAdvisorCheckError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1301,12 +1391,22 @@ impl ResolveCaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CaseIdNotFound" => {
-                    return RusotoError::Service(ResolveCaseError::CaseIdNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResolveCaseError::CaseIdNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ResolveCaseError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResolveCaseError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1429,11 +1529,12 @@ pub struct AWSSupportClient {
 impl AWSSupportClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AWSSupportClient {
         AWSSupportClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Support"),
         }
     }
 
@@ -1448,12 +1549,32 @@ impl AWSSupportClient {
     {
         AWSSupportClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Support"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AWSSupportClient {
-        AWSSupportClient { client, region }
+        AWSSupportClient {
+            client,
+            region: region_for_service(region, "Support"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AWSSupportClient {
+        AWSSupportClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Support"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AWSSupportClient {
+        AWSSupportClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1465,6 +1586,7 @@ impl AWSSupport for AWSSupportClient {
         input: AddAttachmentsToSetRequest,
     ) -> Result<AddAttachmentsToSetResponse, RusotoError<AddAttachmentsToSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddAttachmentsToSet");
         request.add_header("x-amz-target", "AWSSupport_20130415.AddAttachmentsToSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1483,6 +1605,7 @@ impl AWSSupport for AWSSupportClient {
         input: AddCommunicationToCaseRequest,
     ) -> Result<AddCommunicationToCaseResponse, RusotoError<AddCommunicationToCaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddCommunicationToCase");
         request.add_header("x-amz-target", "AWSSupport_20130415.AddCommunicationToCase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1502,6 +1625,7 @@ impl AWSSupport for AWSSupportClient {
         input: CreateCaseRequest,
     ) -> Result<CreateCaseResponse, RusotoError<CreateCaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCase");
         request.add_header("x-amz-target", "AWSSupport_20130415.CreateCase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1520,6 +1644,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeAttachmentRequest,
     ) -> Result<DescribeAttachmentResponse, RusotoError<DescribeAttachmentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAttachment");
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeAttachment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1538,6 +1663,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeCasesRequest,
     ) -> Result<DescribeCasesResponse, RusotoError<DescribeCasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCases");
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeCases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1556,6 +1682,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeCommunicationsRequest,
     ) -> Result<DescribeCommunicationsResponse, RusotoError<DescribeCommunicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCommunications");
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeCommunications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1575,6 +1702,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeServicesRequest,
     ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeServices");
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1593,6 +1721,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeSeverityLevelsRequest,
     ) -> Result<DescribeSeverityLevelsResponse, RusotoError<DescribeSeverityLevelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSeverityLevels");
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeSeverityLevels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1615,6 +1744,7 @@ impl AWSSupport for AWSSupportClient {
         RusotoError<DescribeTrustedAdvisorCheckRefreshStatusesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTrustedAdvisorCheckRefreshStatuses");
         request.add_header(
             "x-amz-target",
             "AWSSupport_20130415.DescribeTrustedAdvisorCheckRefreshStatuses",
@@ -1643,6 +1773,7 @@ impl AWSSupport for AWSSupportClient {
         RusotoError<DescribeTrustedAdvisorCheckResultError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTrustedAdvisorCheckResult");
         request.add_header(
             "x-amz-target",
             "AWSSupport_20130415.DescribeTrustedAdvisorCheckResult",
@@ -1671,6 +1802,7 @@ impl AWSSupport for AWSSupportClient {
         RusotoError<DescribeTrustedAdvisorCheckSummariesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTrustedAdvisorCheckSummaries");
         request.add_header(
             "x-amz-target",
             "AWSSupport_20130415.DescribeTrustedAdvisorCheckSummaries",
@@ -1697,6 +1829,7 @@ impl AWSSupport for AWSSupportClient {
     ) -> Result<DescribeTrustedAdvisorChecksResponse, RusotoError<DescribeTrustedAdvisorChecksError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTrustedAdvisorChecks");
         request.add_header(
             "x-amz-target",
             "AWSSupport_20130415.DescribeTrustedAdvisorChecks",
@@ -1720,6 +1853,7 @@ impl AWSSupport for AWSSupportClient {
     ) -> Result<RefreshTrustedAdvisorCheckResponse, RusotoError<RefreshTrustedAdvisorCheckError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RefreshTrustedAdvisorCheck");
         request.add_header(
             "x-amz-target",
             "AWSSupport_20130415.RefreshTrustedAdvisorCheck",
@@ -1742,6 +1876,7 @@ impl AWSSupport for AWSSupportClient {
         input: ResolveCaseRequest,
     ) -> Result<ResolveCaseResponse, RusotoError<ResolveCaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResolveCase");
         request.add_header("x-amz-target", "AWSSupport_20130415.ResolveCase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1754,3 +1889,213 @@ impl AWSSupport for AWSSupportClient {
         proto::json::ResponsePayload::new(&response).deserialize::<ResolveCaseResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Support API, running the calls of a `AWSSupportClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AWSSupportClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AWSSupportBlockingClient {
+    client: AWSSupportClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AWSSupportBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AWSSupportBlockingClient {
+        AWSSupportBlockingClient::from_client(AWSSupportClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AWSSupportBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AWSSupportBlockingClient::from_client(AWSSupportClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> AWSSupportBlockingClient {
+        AWSSupportBlockingClient::from_client(AWSSupportClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AWSSupportBlockingClient {
+        AWSSupportBlockingClient::from_client(AWSSupportClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AWSSupportClient) -> AWSSupportBlockingClient {
+        AWSSupportBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> AWSSupportBlockingClient {
+        AWSSupportBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AWSSupportBlockingClient {
+        AWSSupportBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`AWSSupport::add_attachments_to_set`].
+    pub fn add_attachments_to_set(
+        &self,
+        input: AddAttachmentsToSetRequest,
+    ) -> Result<AddAttachmentsToSetResponse, RusotoError<AddAttachmentsToSetError>> {
+        self.runtime
+            .block_on(self.client.add_attachments_to_set(input))
+    }
+
+    /// Blocking version of [`AWSSupport::add_communication_to_case`].
+    pub fn add_communication_to_case(
+        &self,
+        input: AddCommunicationToCaseRequest,
+    ) -> Result<AddCommunicationToCaseResponse, RusotoError<AddCommunicationToCaseError>> {
+        self.runtime
+            .block_on(self.client.add_communication_to_case(input))
+    }
+
+    /// Blocking version of [`AWSSupport::create_case`].
+    pub fn create_case(
+        &self,
+        input: CreateCaseRequest,
+    ) -> Result<CreateCaseResponse, RusotoError<CreateCaseError>> {
+        self.runtime.block_on(self.client.create_case(input))
+    }
+
+    /// Blocking version of [`AWSSupport::describe_attachment`].
+    pub fn describe_attachment(
+        &self,
+        input: DescribeAttachmentRequest,
+    ) -> Result<DescribeAttachmentResponse, RusotoError<DescribeAttachmentError>> {
+        self.runtime
+            .block_on(self.client.describe_attachment(input))
+    }
+
+    /// Blocking version of [`AWSSupport::describe_cases`].
+    pub fn describe_cases(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> Result<DescribeCasesResponse, RusotoError<DescribeCasesError>> {
+        self.runtime.block_on(self.client.describe_cases(input))
+    }
+
+    /// Blocking version of [`AWSSupport::describe_communications`].
+    pub fn describe_communications(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> Result<DescribeCommunicationsResponse, RusotoError<DescribeCommunicationsError>> {
+        self.runtime
+            .block_on(self.client.describe_communications(input))
+    }
+
+    /// Blocking version of [`AWSSupport::describe_services`].
+    pub fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
+        self.runtime.block_on(self.client.describe_services(input))
+    }
+
+    /// Blocking version of [`AWSSupport::describe_severity_levels`].
+    pub fn describe_severity_levels(
+        &self,
+        input: DescribeSeverityLevelsRequest,
+    ) -> Result<DescribeSeverityLevelsResponse, RusotoError<DescribeSeverityLevelsError>> {
+        self.runtime
+            .block_on(self.client.describe_severity_levels(input))
+    }
+
+    /// Blocking version of [`AWSSupport::describe_trusted_advisor_check_refresh_statuses`].
+    pub fn describe_trusted_advisor_check_refresh_statuses(
+        &self,
+        input: DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+    ) -> Result<
+        DescribeTrustedAdvisorCheckRefreshStatusesResponse,
+        RusotoError<DescribeTrustedAdvisorCheckRefreshStatusesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_trusted_advisor_check_refresh_statuses(input),
+        )
+    }
+
+    /// Blocking version of [`AWSSupport::describe_trusted_advisor_check_result`].
+    pub fn describe_trusted_advisor_check_result(
+        &self,
+        input: DescribeTrustedAdvisorCheckResultRequest,
+    ) -> Result<
+        DescribeTrustedAdvisorCheckResultResponse,
+        RusotoError<DescribeTrustedAdvisorCheckResultError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_trusted_advisor_check_result(input))
+    }
+
+    /// Blocking version of [`AWSSupport::describe_trusted_advisor_check_summaries`].
+    pub fn describe_trusted_advisor_check_summaries(
+        &self,
+        input: DescribeTrustedAdvisorCheckSummariesRequest,
+    ) -> Result<
+        DescribeTrustedAdvisorCheckSummariesResponse,
+        RusotoError<DescribeTrustedAdvisorCheckSummariesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_trusted_advisor_check_summaries(input))
+    }
+
+    /// Blocking version of [`AWSSupport::describe_trusted_advisor_checks`].
+    pub fn describe_trusted_advisor_checks(
+        &self,
+        input: DescribeTrustedAdvisorChecksRequest,
+    ) -> Result<DescribeTrustedAdvisorChecksResponse, RusotoError<DescribeTrustedAdvisorChecksError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_trusted_advisor_checks(input))
+    }
+
+    /// Blocking version of [`AWSSupport::refresh_trusted_advisor_check`].
+    pub fn refresh_trusted_advisor_check(
+        &self,
+        input: RefreshTrustedAdvisorCheckRequest,
+    ) -> Result<RefreshTrustedAdvisorCheckResponse, RusotoError<RefreshTrustedAdvisorCheckError>>
+    {
+        self.runtime
+            .block_on(self.client.refresh_trusted_advisor_check(input))
+    }
+
+    /// Blocking version of [`AWSSupport::resolve_case`].
+    pub fn resolve_case(
+        &self,
+        input: ResolveCaseRequest,
+    ) -> Result<ResolveCaseResponse, RusotoError<ResolveCaseError>> {
+        self.runtime.block_on(self.client.resolve_case(input))
+    }
+}
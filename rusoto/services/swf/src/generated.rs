@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2996,16 +2997,22 @@ impl CountClosedWorkflowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        CountClosedWorkflowExecutionsError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CountClosedWorkflowExecutionsError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        CountClosedWorkflowExecutionsError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CountClosedWorkflowExecutionsError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3042,16 +3049,22 @@ impl CountOpenWorkflowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        CountOpenWorkflowExecutionsError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CountOpenWorkflowExecutionsError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        CountOpenWorkflowExecutionsError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CountOpenWorkflowExecutionsError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3086,16 +3099,22 @@ impl CountPendingActivityTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        CountPendingActivityTasksError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CountPendingActivityTasksError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        CountPendingActivityTasksError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CountPendingActivityTasksError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3130,16 +3149,22 @@ impl CountPendingDecisionTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        CountPendingDecisionTasksError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CountPendingDecisionTasksError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        CountPendingDecisionTasksError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CountPendingDecisionTasksError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3176,21 +3201,30 @@ impl DeprecateActivityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        DeprecateActivityTypeError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeprecateActivityTypeError::OperationNotPermittedFault,
                     )
                 }
                 "TypeDeprecatedFault" => {
-                    return RusotoError::Service(DeprecateActivityTypeError::TypeDeprecatedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateActivityTypeError::TypeDeprecatedFault,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DeprecateActivityTypeError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateActivityTypeError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3226,21 +3260,30 @@ impl DeprecateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainDeprecatedFault" => {
-                    return RusotoError::Service(DeprecateDomainError::DomainDeprecatedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateDomainError::DomainDeprecatedFault,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(DeprecateDomainError::OperationNotPermittedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateDomainError::OperationNotPermittedFault,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DeprecateDomainError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateDomainError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3274,21 +3317,30 @@ impl DeprecateWorkflowTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        DeprecateWorkflowTypeError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeprecateWorkflowTypeError::OperationNotPermittedFault,
                     )
                 }
                 "TypeDeprecatedFault" => {
-                    return RusotoError::Service(DeprecateWorkflowTypeError::TypeDeprecatedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateWorkflowTypeError::TypeDeprecatedFault,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DeprecateWorkflowTypeError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateWorkflowTypeError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3322,16 +3374,22 @@ impl DescribeActivityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        DescribeActivityTypeError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeActivityTypeError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DescribeActivityTypeError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeActivityTypeError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3364,14 +3422,22 @@ impl DescribeDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(DescribeDomainError::OperationNotPermittedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDomainError::OperationNotPermittedFault,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DescribeDomainError::UnknownResourceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3402,16 +3468,22 @@ impl DescribeWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        DescribeWorkflowExecutionError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorkflowExecutionError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        DescribeWorkflowExecutionError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorkflowExecutionError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3446,16 +3518,22 @@ impl DescribeWorkflowTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        DescribeWorkflowTypeError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorkflowTypeError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DescribeWorkflowTypeError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorkflowTypeError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3490,16 +3568,22 @@ impl GetWorkflowExecutionHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        GetWorkflowExecutionHistoryError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowExecutionHistoryError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        GetWorkflowExecutionHistoryError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowExecutionHistoryError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3534,16 +3618,22 @@ impl ListActivityTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        ListActivityTypesError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActivityTypesError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(ListActivityTypesError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListActivityTypesError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3576,16 +3666,22 @@ impl ListClosedWorkflowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        ListClosedWorkflowExecutionsError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClosedWorkflowExecutionsError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        ListClosedWorkflowExecutionsError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClosedWorkflowExecutionsError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3618,11 +3714,14 @@ impl ListDomainsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(ListDomainsError::OperationNotPermittedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDomainsError::OperationNotPermittedFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3654,16 +3753,22 @@ impl ListOpenWorkflowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        ListOpenWorkflowExecutionsError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOpenWorkflowExecutionsError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        ListOpenWorkflowExecutionsError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOpenWorkflowExecutionsError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3700,21 +3805,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceededFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::LimitExceededFault,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        ListTagsForResourceError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(ListTagsForResourceError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3748,16 +3862,22 @@ impl ListWorkflowTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        ListWorkflowTypesError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorkflowTypesError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(ListWorkflowTypesError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListWorkflowTypesError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3790,21 +3910,30 @@ impl PollForActivityTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(PollForActivityTaskError::LimitExceededFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PollForActivityTaskError::LimitExceededFault,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        PollForActivityTaskError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PollForActivityTaskError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(PollForActivityTaskError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PollForActivityTaskError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3840,21 +3969,30 @@ impl PollForDecisionTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(PollForDecisionTaskError::LimitExceededFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PollForDecisionTaskError::LimitExceededFault,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        PollForDecisionTaskError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PollForDecisionTaskError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(PollForDecisionTaskError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PollForDecisionTaskError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3890,16 +4028,22 @@ impl RecordActivityTaskHeartbeatError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        RecordActivityTaskHeartbeatError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RecordActivityTaskHeartbeatError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        RecordActivityTaskHeartbeatError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RecordActivityTaskHeartbeatError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3938,26 +4082,38 @@ impl RegisterActivityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(RegisterActivityTypeError::LimitExceededFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterActivityTypeError::LimitExceededFault,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        RegisterActivityTypeError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterActivityTypeError::OperationNotPermittedFault,
                     )
                 }
                 "TypeAlreadyExistsFault" => {
-                    return RusotoError::Service(RegisterActivityTypeError::TypeAlreadyExistsFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterActivityTypeError::TypeAlreadyExistsFault,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(RegisterActivityTypeError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterActivityTypeError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3996,22 +4152,38 @@ impl RegisterDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainAlreadyExistsFault" => {
-                    return RusotoError::Service(RegisterDomainError::DomainAlreadyExistsFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterDomainError::DomainAlreadyExistsFault,
+                    )
                 }
                 "LimitExceededFault" => {
-                    return RusotoError::Service(RegisterDomainError::LimitExceededFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDomainError::LimitExceededFault,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(RegisterDomainError::OperationNotPermittedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterDomainError::OperationNotPermittedFault,
+                    )
                 }
                 "TooManyTagsFault" => {
-                    return RusotoError::Service(RegisterDomainError::TooManyTagsFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDomainError::TooManyTagsFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4048,26 +4220,38 @@ impl RegisterWorkflowTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(RegisterWorkflowTypeError::LimitExceededFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterWorkflowTypeError::LimitExceededFault,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        RegisterWorkflowTypeError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterWorkflowTypeError::OperationNotPermittedFault,
                     )
                 }
                 "TypeAlreadyExistsFault" => {
-                    return RusotoError::Service(RegisterWorkflowTypeError::TypeAlreadyExistsFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterWorkflowTypeError::TypeAlreadyExistsFault,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(RegisterWorkflowTypeError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterWorkflowTypeError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4104,16 +4288,22 @@ impl RequestCancelWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        RequestCancelWorkflowExecutionError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestCancelWorkflowExecutionError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        RequestCancelWorkflowExecutionError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestCancelWorkflowExecutionError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4150,16 +4340,22 @@ impl RespondActivityTaskCanceledError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        RespondActivityTaskCanceledError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondActivityTaskCanceledError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        RespondActivityTaskCanceledError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondActivityTaskCanceledError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4196,16 +4392,22 @@ impl RespondActivityTaskCompletedError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        RespondActivityTaskCompletedError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondActivityTaskCompletedError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        RespondActivityTaskCompletedError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondActivityTaskCompletedError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4240,16 +4442,22 @@ impl RespondActivityTaskFailedError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        RespondActivityTaskFailedError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondActivityTaskFailedError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        RespondActivityTaskFailedError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondActivityTaskFailedError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4286,16 +4494,22 @@ impl RespondDecisionTaskCompletedError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        RespondDecisionTaskCompletedError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondDecisionTaskCompletedError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        RespondDecisionTaskCompletedError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondDecisionTaskCompletedError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4330,16 +4544,22 @@ impl SignalWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        SignalWorkflowExecutionError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignalWorkflowExecutionError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        SignalWorkflowExecutionError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignalWorkflowExecutionError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4380,36 +4600,54 @@ impl StartWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DefaultUndefinedFault" => {
-                    return RusotoError::Service(
-                        StartWorkflowExecutionError::DefaultUndefinedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartWorkflowExecutionError::DefaultUndefinedFault,
                     )
                 }
                 "LimitExceededFault" => {
-                    return RusotoError::Service(StartWorkflowExecutionError::LimitExceededFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartWorkflowExecutionError::LimitExceededFault,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        StartWorkflowExecutionError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartWorkflowExecutionError::OperationNotPermittedFault,
                     )
                 }
                 "TypeDeprecatedFault" => {
-                    return RusotoError::Service(StartWorkflowExecutionError::TypeDeprecatedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartWorkflowExecutionError::TypeDeprecatedFault,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(StartWorkflowExecutionError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartWorkflowExecutionError::UnknownResourceFault,
+                    )
                 }
                 "WorkflowExecutionAlreadyStartedFault" => {
-                    return RusotoError::Service(
-                        StartWorkflowExecutionError::WorkflowExecutionAlreadyStartedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartWorkflowExecutionError::WorkflowExecutionAlreadyStartedFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4452,20 +4690,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(TagResourceError::LimitExceededFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceededFault,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(TagResourceError::OperationNotPermittedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::OperationNotPermittedFault,
+                    )
                 }
                 "TooManyTagsFault" => {
-                    return RusotoError::Service(TagResourceError::TooManyTagsFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTagsFault,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(TagResourceError::UnknownResourceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4500,16 +4756,22 @@ impl TerminateWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        TerminateWorkflowExecutionError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TerminateWorkflowExecutionError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        TerminateWorkflowExecutionError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TerminateWorkflowExecutionError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4546,21 +4808,30 @@ impl UndeprecateActivityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        UndeprecateActivityTypeError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UndeprecateActivityTypeError::OperationNotPermittedFault,
                     )
                 }
                 "TypeAlreadyExistsFault" => {
-                    return RusotoError::Service(
-                        UndeprecateActivityTypeError::TypeAlreadyExistsFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UndeprecateActivityTypeError::TypeAlreadyExistsFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        UndeprecateActivityTypeError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UndeprecateActivityTypeError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4598,21 +4869,30 @@ impl UndeprecateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainAlreadyExistsFault" => {
-                    return RusotoError::Service(UndeprecateDomainError::DomainAlreadyExistsFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UndeprecateDomainError::DomainAlreadyExistsFault,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        UndeprecateDomainError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UndeprecateDomainError::OperationNotPermittedFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(UndeprecateDomainError::UnknownResourceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UndeprecateDomainError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4646,21 +4926,30 @@ impl UndeprecateWorkflowTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
-                        UndeprecateWorkflowTypeError::OperationNotPermittedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UndeprecateWorkflowTypeError::OperationNotPermittedFault,
                     )
                 }
                 "TypeAlreadyExistsFault" => {
-                    return RusotoError::Service(
-                        UndeprecateWorkflowTypeError::TypeAlreadyExistsFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UndeprecateWorkflowTypeError::TypeAlreadyExistsFault,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
-                        UndeprecateWorkflowTypeError::UnknownResourceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UndeprecateWorkflowTypeError::UnknownResourceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4698,17 +4987,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceededFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::LimitExceededFault,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(UntagResourceError::OperationNotPermittedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::OperationNotPermittedFault,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(UntagResourceError::UnknownResourceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::UnknownResourceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4961,11 +5263,12 @@ pub struct SwfClient {
 impl SwfClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SwfClient {
         SwfClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SWF"),
         }
     }
 
@@ -4980,12 +5283,32 @@ impl SwfClient {
     {
         SwfClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SWF"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SwfClient {
-        SwfClient { client, region }
+        SwfClient {
+            client,
+            region: region_for_service(region, "SWF"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SwfClient {
+        SwfClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SWF"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SwfClient {
+        SwfClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4997,6 +5320,7 @@ impl Swf for SwfClient {
         input: CountClosedWorkflowExecutionsInput,
     ) -> Result<WorkflowExecutionCount, RusotoError<CountClosedWorkflowExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CountClosedWorkflowExecutions");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.CountClosedWorkflowExecutions",
@@ -5018,6 +5342,7 @@ impl Swf for SwfClient {
         input: CountOpenWorkflowExecutionsInput,
     ) -> Result<WorkflowExecutionCount, RusotoError<CountOpenWorkflowExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CountOpenWorkflowExecutions");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.CountOpenWorkflowExecutions",
@@ -5039,6 +5364,7 @@ impl Swf for SwfClient {
         input: CountPendingActivityTasksInput,
     ) -> Result<PendingTaskCount, RusotoError<CountPendingActivityTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CountPendingActivityTasks");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.CountPendingActivityTasks",
@@ -5060,6 +5386,7 @@ impl Swf for SwfClient {
         input: CountPendingDecisionTasksInput,
     ) -> Result<PendingTaskCount, RusotoError<CountPendingDecisionTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CountPendingDecisionTasks");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.CountPendingDecisionTasks",
@@ -5081,6 +5408,7 @@ impl Swf for SwfClient {
         input: DeprecateActivityTypeInput,
     ) -> Result<(), RusotoError<DeprecateActivityTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeprecateActivityType");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.DeprecateActivityType",
@@ -5101,6 +5429,7 @@ impl Swf for SwfClient {
         input: DeprecateDomainInput,
     ) -> Result<(), RusotoError<DeprecateDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeprecateDomain");
         request.add_header("x-amz-target", "SimpleWorkflowService.DeprecateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5118,6 +5447,7 @@ impl Swf for SwfClient {
         input: DeprecateWorkflowTypeInput,
     ) -> Result<(), RusotoError<DeprecateWorkflowTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeprecateWorkflowType");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.DeprecateWorkflowType",
@@ -5138,6 +5468,7 @@ impl Swf for SwfClient {
         input: DescribeActivityTypeInput,
     ) -> Result<ActivityTypeDetail, RusotoError<DescribeActivityTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeActivityType");
         request.add_header("x-amz-target", "SimpleWorkflowService.DescribeActivityType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5156,6 +5487,7 @@ impl Swf for SwfClient {
         input: DescribeDomainInput,
     ) -> Result<DomainDetail, RusotoError<DescribeDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDomain");
         request.add_header("x-amz-target", "SimpleWorkflowService.DescribeDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5174,6 +5506,7 @@ impl Swf for SwfClient {
         input: DescribeWorkflowExecutionInput,
     ) -> Result<WorkflowExecutionDetail, RusotoError<DescribeWorkflowExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeWorkflowExecution");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.DescribeWorkflowExecution",
@@ -5195,6 +5528,7 @@ impl Swf for SwfClient {
         input: DescribeWorkflowTypeInput,
     ) -> Result<WorkflowTypeDetail, RusotoError<DescribeWorkflowTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeWorkflowType");
         request.add_header("x-amz-target", "SimpleWorkflowService.DescribeWorkflowType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5213,6 +5547,7 @@ impl Swf for SwfClient {
         input: GetWorkflowExecutionHistoryInput,
     ) -> Result<History, RusotoError<GetWorkflowExecutionHistoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetWorkflowExecutionHistory");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.GetWorkflowExecutionHistory",
@@ -5234,6 +5569,7 @@ impl Swf for SwfClient {
         input: ListActivityTypesInput,
     ) -> Result<ActivityTypeInfos, RusotoError<ListActivityTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListActivityTypes");
         request.add_header("x-amz-target", "SimpleWorkflowService.ListActivityTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5252,6 +5588,7 @@ impl Swf for SwfClient {
         input: ListClosedWorkflowExecutionsInput,
     ) -> Result<WorkflowExecutionInfos, RusotoError<ListClosedWorkflowExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListClosedWorkflowExecutions");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.ListClosedWorkflowExecutions",
@@ -5273,6 +5610,7 @@ impl Swf for SwfClient {
         input: ListDomainsInput,
     ) -> Result<DomainInfos, RusotoError<ListDomainsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDomains");
         request.add_header("x-amz-target", "SimpleWorkflowService.ListDomains");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5291,6 +5629,7 @@ impl Swf for SwfClient {
         input: ListOpenWorkflowExecutionsInput,
     ) -> Result<WorkflowExecutionInfos, RusotoError<ListOpenWorkflowExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListOpenWorkflowExecutions");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.ListOpenWorkflowExecutions",
@@ -5312,6 +5651,7 @@ impl Swf for SwfClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "SimpleWorkflowService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5330,6 +5670,7 @@ impl Swf for SwfClient {
         input: ListWorkflowTypesInput,
     ) -> Result<WorkflowTypeInfos, RusotoError<ListWorkflowTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListWorkflowTypes");
         request.add_header("x-amz-target", "SimpleWorkflowService.ListWorkflowTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5348,6 +5689,7 @@ impl Swf for SwfClient {
         input: PollForActivityTaskInput,
     ) -> Result<ActivityTask, RusotoError<PollForActivityTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PollForActivityTask");
         request.add_header("x-amz-target", "SimpleWorkflowService.PollForActivityTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5366,6 +5708,7 @@ impl Swf for SwfClient {
         input: PollForDecisionTaskInput,
     ) -> Result<DecisionTask, RusotoError<PollForDecisionTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PollForDecisionTask");
         request.add_header("x-amz-target", "SimpleWorkflowService.PollForDecisionTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5384,6 +5727,7 @@ impl Swf for SwfClient {
         input: RecordActivityTaskHeartbeatInput,
     ) -> Result<ActivityTaskStatus, RusotoError<RecordActivityTaskHeartbeatError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RecordActivityTaskHeartbeat");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.RecordActivityTaskHeartbeat",
@@ -5405,6 +5749,7 @@ impl Swf for SwfClient {
         input: RegisterActivityTypeInput,
     ) -> Result<(), RusotoError<RegisterActivityTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterActivityType");
         request.add_header("x-amz-target", "SimpleWorkflowService.RegisterActivityType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5422,6 +5767,7 @@ impl Swf for SwfClient {
         input: RegisterDomainInput,
     ) -> Result<(), RusotoError<RegisterDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterDomain");
         request.add_header("x-amz-target", "SimpleWorkflowService.RegisterDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5439,6 +5785,7 @@ impl Swf for SwfClient {
         input: RegisterWorkflowTypeInput,
     ) -> Result<(), RusotoError<RegisterWorkflowTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterWorkflowType");
         request.add_header("x-amz-target", "SimpleWorkflowService.RegisterWorkflowType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5456,6 +5803,7 @@ impl Swf for SwfClient {
         input: RequestCancelWorkflowExecutionInput,
     ) -> Result<(), RusotoError<RequestCancelWorkflowExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RequestCancelWorkflowExecution");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.RequestCancelWorkflowExecution",
@@ -5476,6 +5824,7 @@ impl Swf for SwfClient {
         input: RespondActivityTaskCanceledInput,
     ) -> Result<(), RusotoError<RespondActivityTaskCanceledError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RespondActivityTaskCanceled");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.RespondActivityTaskCanceled",
@@ -5496,6 +5845,7 @@ impl Swf for SwfClient {
         input: RespondActivityTaskCompletedInput,
     ) -> Result<(), RusotoError<RespondActivityTaskCompletedError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RespondActivityTaskCompleted");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.RespondActivityTaskCompleted",
@@ -5516,6 +5866,7 @@ impl Swf for SwfClient {
         input: RespondActivityTaskFailedInput,
     ) -> Result<(), RusotoError<RespondActivityTaskFailedError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RespondActivityTaskFailed");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.RespondActivityTaskFailed",
@@ -5536,6 +5887,7 @@ impl Swf for SwfClient {
         input: RespondDecisionTaskCompletedInput,
     ) -> Result<(), RusotoError<RespondDecisionTaskCompletedError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RespondDecisionTaskCompleted");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.RespondDecisionTaskCompleted",
@@ -5556,6 +5908,7 @@ impl Swf for SwfClient {
         input: SignalWorkflowExecutionInput,
     ) -> Result<(), RusotoError<SignalWorkflowExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SignalWorkflowExecution");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.SignalWorkflowExecution",
@@ -5576,6 +5929,7 @@ impl Swf for SwfClient {
         input: StartWorkflowExecutionInput,
     ) -> Result<Run, RusotoError<StartWorkflowExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartWorkflowExecution");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.StartWorkflowExecution",
@@ -5597,6 +5951,7 @@ impl Swf for SwfClient {
         input: TagResourceInput,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "SimpleWorkflowService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5614,6 +5969,7 @@ impl Swf for SwfClient {
         input: TerminateWorkflowExecutionInput,
     ) -> Result<(), RusotoError<TerminateWorkflowExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TerminateWorkflowExecution");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.TerminateWorkflowExecution",
@@ -5634,6 +5990,7 @@ impl Swf for SwfClient {
         input: UndeprecateActivityTypeInput,
     ) -> Result<(), RusotoError<UndeprecateActivityTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UndeprecateActivityType");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.UndeprecateActivityType",
@@ -5654,6 +6011,7 @@ impl Swf for SwfClient {
         input: UndeprecateDomainInput,
     ) -> Result<(), RusotoError<UndeprecateDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UndeprecateDomain");
         request.add_header("x-amz-target", "SimpleWorkflowService.UndeprecateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5671,6 +6029,7 @@ impl Swf for SwfClient {
         input: UndeprecateWorkflowTypeInput,
     ) -> Result<(), RusotoError<UndeprecateWorkflowTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UndeprecateWorkflowType");
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.UndeprecateWorkflowType",
@@ -5691,6 +6050,7 @@ impl Swf for SwfClient {
         input: UntagResourceInput,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "SimpleWorkflowService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5702,3 +6062,404 @@ impl Swf for SwfClient {
         Ok(())
     }
 }
+
+/// A blocking client for the Amazon SWF API, running the calls of a `SwfClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SwfClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SwfBlockingClient {
+    client: SwfClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SwfBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SwfBlockingClient {
+        SwfBlockingClient::from_client(SwfClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SwfBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SwfBlockingClient::from_client(SwfClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> SwfBlockingClient {
+        SwfBlockingClient::from_client(SwfClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SwfBlockingClient {
+        SwfBlockingClient::from_client(SwfClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SwfClient) -> SwfBlockingClient {
+        SwfBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> SwfBlockingClient {
+        SwfBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SwfBlockingClient {
+        SwfBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Swf::count_closed_workflow_executions`].
+    pub fn count_closed_workflow_executions(
+        &self,
+        input: CountClosedWorkflowExecutionsInput,
+    ) -> Result<WorkflowExecutionCount, RusotoError<CountClosedWorkflowExecutionsError>> {
+        self.runtime
+            .block_on(self.client.count_closed_workflow_executions(input))
+    }
+
+    /// Blocking version of [`Swf::count_open_workflow_executions`].
+    pub fn count_open_workflow_executions(
+        &self,
+        input: CountOpenWorkflowExecutionsInput,
+    ) -> Result<WorkflowExecutionCount, RusotoError<CountOpenWorkflowExecutionsError>> {
+        self.runtime
+            .block_on(self.client.count_open_workflow_executions(input))
+    }
+
+    /// Blocking version of [`Swf::count_pending_activity_tasks`].
+    pub fn count_pending_activity_tasks(
+        &self,
+        input: CountPendingActivityTasksInput,
+    ) -> Result<PendingTaskCount, RusotoError<CountPendingActivityTasksError>> {
+        self.runtime
+            .block_on(self.client.count_pending_activity_tasks(input))
+    }
+
+    /// Blocking version of [`Swf::count_pending_decision_tasks`].
+    pub fn count_pending_decision_tasks(
+        &self,
+        input: CountPendingDecisionTasksInput,
+    ) -> Result<PendingTaskCount, RusotoError<CountPendingDecisionTasksError>> {
+        self.runtime
+            .block_on(self.client.count_pending_decision_tasks(input))
+    }
+
+    /// Blocking version of [`Swf::deprecate_activity_type`].
+    pub fn deprecate_activity_type(
+        &self,
+        input: DeprecateActivityTypeInput,
+    ) -> Result<(), RusotoError<DeprecateActivityTypeError>> {
+        self.runtime
+            .block_on(self.client.deprecate_activity_type(input))
+    }
+
+    /// Blocking version of [`Swf::deprecate_domain`].
+    pub fn deprecate_domain(
+        &self,
+        input: DeprecateDomainInput,
+    ) -> Result<(), RusotoError<DeprecateDomainError>> {
+        self.runtime.block_on(self.client.deprecate_domain(input))
+    }
+
+    /// Blocking version of [`Swf::deprecate_workflow_type`].
+    pub fn deprecate_workflow_type(
+        &self,
+        input: DeprecateWorkflowTypeInput,
+    ) -> Result<(), RusotoError<DeprecateWorkflowTypeError>> {
+        self.runtime
+            .block_on(self.client.deprecate_workflow_type(input))
+    }
+
+    /// Blocking version of [`Swf::describe_activity_type`].
+    pub fn describe_activity_type(
+        &self,
+        input: DescribeActivityTypeInput,
+    ) -> Result<ActivityTypeDetail, RusotoError<DescribeActivityTypeError>> {
+        self.runtime
+            .block_on(self.client.describe_activity_type(input))
+    }
+
+    /// Blocking version of [`Swf::describe_domain`].
+    pub fn describe_domain(
+        &self,
+        input: DescribeDomainInput,
+    ) -> Result<DomainDetail, RusotoError<DescribeDomainError>> {
+        self.runtime.block_on(self.client.describe_domain(input))
+    }
+
+    /// Blocking version of [`Swf::describe_workflow_execution`].
+    pub fn describe_workflow_execution(
+        &self,
+        input: DescribeWorkflowExecutionInput,
+    ) -> Result<WorkflowExecutionDetail, RusotoError<DescribeWorkflowExecutionError>> {
+        self.runtime
+            .block_on(self.client.describe_workflow_execution(input))
+    }
+
+    /// Blocking version of [`Swf::describe_workflow_type`].
+    pub fn describe_workflow_type(
+        &self,
+        input: DescribeWorkflowTypeInput,
+    ) -> Result<WorkflowTypeDetail, RusotoError<DescribeWorkflowTypeError>> {
+        self.runtime
+            .block_on(self.client.describe_workflow_type(input))
+    }
+
+    /// Blocking version of [`Swf::get_workflow_execution_history`].
+    pub fn get_workflow_execution_history(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> Result<History, RusotoError<GetWorkflowExecutionHistoryError>> {
+        self.runtime
+            .block_on(self.client.get_workflow_execution_history(input))
+    }
+
+    /// Blocking version of [`Swf::list_activity_types`].
+    pub fn list_activity_types(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> Result<ActivityTypeInfos, RusotoError<ListActivityTypesError>> {
+        self.runtime
+            .block_on(self.client.list_activity_types(input))
+    }
+
+    /// Blocking version of [`Swf::list_closed_workflow_executions`].
+    pub fn list_closed_workflow_executions(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> Result<WorkflowExecutionInfos, RusotoError<ListClosedWorkflowExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_closed_workflow_executions(input))
+    }
+
+    /// Blocking version of [`Swf::list_domains`].
+    pub fn list_domains(
+        &self,
+        input: ListDomainsInput,
+    ) -> Result<DomainInfos, RusotoError<ListDomainsError>> {
+        self.runtime.block_on(self.client.list_domains(input))
+    }
+
+    /// Blocking version of [`Swf::list_open_workflow_executions`].
+    pub fn list_open_workflow_executions(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> Result<WorkflowExecutionInfos, RusotoError<ListOpenWorkflowExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_open_workflow_executions(input))
+    }
+
+    /// Blocking version of [`Swf::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Swf::list_workflow_types`].
+    pub fn list_workflow_types(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> Result<WorkflowTypeInfos, RusotoError<ListWorkflowTypesError>> {
+        self.runtime
+            .block_on(self.client.list_workflow_types(input))
+    }
+
+    /// Blocking version of [`Swf::poll_for_activity_task`].
+    pub fn poll_for_activity_task(
+        &self,
+        input: PollForActivityTaskInput,
+    ) -> Result<ActivityTask, RusotoError<PollForActivityTaskError>> {
+        self.runtime
+            .block_on(self.client.poll_for_activity_task(input))
+    }
+
+    /// Blocking version of [`Swf::poll_for_decision_task`].
+    pub fn poll_for_decision_task(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> Result<DecisionTask, RusotoError<PollForDecisionTaskError>> {
+        self.runtime
+            .block_on(self.client.poll_for_decision_task(input))
+    }
+
+    /// Blocking version of [`Swf::record_activity_task_heartbeat`].
+    pub fn record_activity_task_heartbeat(
+        &self,
+        input: RecordActivityTaskHeartbeatInput,
+    ) -> Result<ActivityTaskStatus, RusotoError<RecordActivityTaskHeartbeatError>> {
+        self.runtime
+            .block_on(self.client.record_activity_task_heartbeat(input))
+    }
+
+    /// Blocking version of [`Swf::register_activity_type`].
+    pub fn register_activity_type(
+        &self,
+        input: RegisterActivityTypeInput,
+    ) -> Result<(), RusotoError<RegisterActivityTypeError>> {
+        self.runtime
+            .block_on(self.client.register_activity_type(input))
+    }
+
+    /// Blocking version of [`Swf::register_domain`].
+    pub fn register_domain(
+        &self,
+        input: RegisterDomainInput,
+    ) -> Result<(), RusotoError<RegisterDomainError>> {
+        self.runtime.block_on(self.client.register_domain(input))
+    }
+
+    /// Blocking version of [`Swf::register_workflow_type`].
+    pub fn register_workflow_type(
+        &self,
+        input: RegisterWorkflowTypeInput,
+    ) -> Result<(), RusotoError<RegisterWorkflowTypeError>> {
+        self.runtime
+            .block_on(self.client.register_workflow_type(input))
+    }
+
+    /// Blocking version of [`Swf::request_cancel_workflow_execution`].
+    pub fn request_cancel_workflow_execution(
+        &self,
+        input: RequestCancelWorkflowExecutionInput,
+    ) -> Result<(), RusotoError<RequestCancelWorkflowExecutionError>> {
+        self.runtime
+            .block_on(self.client.request_cancel_workflow_execution(input))
+    }
+
+    /// Blocking version of [`Swf::respond_activity_task_canceled`].
+    pub fn respond_activity_task_canceled(
+        &self,
+        input: RespondActivityTaskCanceledInput,
+    ) -> Result<(), RusotoError<RespondActivityTaskCanceledError>> {
+        self.runtime
+            .block_on(self.client.respond_activity_task_canceled(input))
+    }
+
+    /// Blocking version of [`Swf::respond_activity_task_completed`].
+    pub fn respond_activity_task_completed(
+        &self,
+        input: RespondActivityTaskCompletedInput,
+    ) -> Result<(), RusotoError<RespondActivityTaskCompletedError>> {
+        self.runtime
+            .block_on(self.client.respond_activity_task_completed(input))
+    }
+
+    /// Blocking version of [`Swf::respond_activity_task_failed`].
+    pub fn respond_activity_task_failed(
+        &self,
+        input: RespondActivityTaskFailedInput,
+    ) -> Result<(), RusotoError<RespondActivityTaskFailedError>> {
+        self.runtime
+            .block_on(self.client.respond_activity_task_failed(input))
+    }
+
+    /// Blocking version of [`Swf::respond_decision_task_completed`].
+    pub fn respond_decision_task_completed(
+        &self,
+        input: RespondDecisionTaskCompletedInput,
+    ) -> Result<(), RusotoError<RespondDecisionTaskCompletedError>> {
+        self.runtime
+            .block_on(self.client.respond_decision_task_completed(input))
+    }
+
+    /// Blocking version of [`Swf::signal_workflow_execution`].
+    pub fn signal_workflow_execution(
+        &self,
+        input: SignalWorkflowExecutionInput,
+    ) -> Result<(), RusotoError<SignalWorkflowExecutionError>> {
+        self.runtime
+            .block_on(self.client.signal_workflow_execution(input))
+    }
+
+    /// Blocking version of [`Swf::start_workflow_execution`].
+    pub fn start_workflow_execution(
+        &self,
+        input: StartWorkflowExecutionInput,
+    ) -> Result<Run, RusotoError<StartWorkflowExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_workflow_execution(input))
+    }
+
+    /// Blocking version of [`Swf::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Swf::terminate_workflow_execution`].
+    pub fn terminate_workflow_execution(
+        &self,
+        input: TerminateWorkflowExecutionInput,
+    ) -> Result<(), RusotoError<TerminateWorkflowExecutionError>> {
+        self.runtime
+            .block_on(self.client.terminate_workflow_execution(input))
+    }
+
+    /// Blocking version of [`Swf::undeprecate_activity_type`].
+    pub fn undeprecate_activity_type(
+        &self,
+        input: UndeprecateActivityTypeInput,
+    ) -> Result<(), RusotoError<UndeprecateActivityTypeError>> {
+        self.runtime
+            .block_on(self.client.undeprecate_activity_type(input))
+    }
+
+    /// Blocking version of [`Swf::undeprecate_domain`].
+    pub fn undeprecate_domain(
+        &self,
+        input: UndeprecateDomainInput,
+    ) -> Result<(), RusotoError<UndeprecateDomainError>> {
+        self.runtime.block_on(self.client.undeprecate_domain(input))
+    }
+
+    /// Blocking version of [`Swf::undeprecate_workflow_type`].
+    pub fn undeprecate_workflow_type(
+        &self,
+        input: UndeprecateWorkflowTypeInput,
+    ) -> Result<(), RusotoError<UndeprecateWorkflowTypeError>> {
+        self.runtime
+            .block_on(self.client.undeprecate_workflow_type(input))
+    }
+
+    /// Blocking version of [`Swf::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -582,40 +583,86 @@ impl AnalyzeDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AnalyzeDocumentError::AccessDenied,
+                    )
                 }
                 "BadDocumentException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::BadDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AnalyzeDocumentError::BadDocument,
+                    )
                 }
                 "DocumentTooLargeException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::DocumentTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AnalyzeDocumentError::DocumentTooLarge,
+                    )
                 }
                 "HumanLoopQuotaExceededException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::HumanLoopQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AnalyzeDocumentError::HumanLoopQuotaExceeded,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(AnalyzeDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AnalyzeDocumentError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AnalyzeDocumentError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AnalyzeDocumentError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        AnalyzeDocumentError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AnalyzeDocumentError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AnalyzeDocumentError::Throttling,
+                    )
                 }
                 "UnsupportedDocumentException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::UnsupportedDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AnalyzeDocumentError::UnsupportedDocument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -670,39 +717,78 @@ impl DetectDocumentTextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectDocumentTextError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectDocumentTextError::AccessDenied,
+                    )
                 }
                 "BadDocumentException" => {
-                    return RusotoError::Service(DetectDocumentTextError::BadDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectDocumentTextError::BadDocument,
+                    )
                 }
                 "DocumentTooLargeException" => {
-                    return RusotoError::Service(DetectDocumentTextError::DocumentTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectDocumentTextError::DocumentTooLarge,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectDocumentTextError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectDocumentTextError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectDocumentTextError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectDocumentTextError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectDocumentTextError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectDocumentTextError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DetectDocumentTextError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectDocumentTextError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectDocumentTextError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectDocumentTextError::Throttling,
+                    )
                 }
                 "UnsupportedDocumentException" => {
-                    return RusotoError::Service(DetectDocumentTextError::UnsupportedDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectDocumentTextError::UnsupportedDocument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -752,33 +838,62 @@ impl GetDocumentAnalysisError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentAnalysisError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentAnalysisError::InternalServerError,
+                    )
                 }
                 "InvalidJobIdException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::InvalidJobId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentAnalysisError::InvalidJobId,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentAnalysisError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentAnalysisError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        GetDocumentAnalysisError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentAnalysisError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentAnalysisError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -826,39 +941,62 @@ impl GetDocumentTextDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentTextDetectionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        GetDocumentTextDetectionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentTextDetectionError::InternalServerError,
                     )
                 }
                 "InvalidJobIdException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::InvalidJobId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentTextDetectionError::InvalidJobId,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentTextDetectionError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::InvalidS3Object(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentTextDetectionError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        GetDocumentTextDetectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentTextDetectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentTextDetectionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -916,56 +1054,102 @@ impl StartDocumentAnalysisError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentAnalysisError::AccessDenied,
+                    )
                 }
                 "BadDocumentException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::BadDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentAnalysisError::BadDocument,
+                    )
                 }
                 "DocumentTooLargeException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::DocumentTooLarge(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentAnalysisError::DocumentTooLarge,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        StartDocumentAnalysisError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentAnalysisError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentAnalysisError::InternalServerError,
+                    )
                 }
                 "InvalidKMSKeyException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::InvalidKMSKey(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentAnalysisError::InvalidKMSKey,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentAnalysisError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::InvalidS3Object(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentAnalysisError::InvalidS3Object,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentAnalysisError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StartDocumentAnalysisError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentAnalysisError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentAnalysisError::Throttling,
+                    )
                 }
                 "UnsupportedDocumentException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::UnsupportedDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentAnalysisError::UnsupportedDocument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1032,66 +1216,102 @@ impl StartDocumentTextDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentTextDetectionError::AccessDenied,
+                    )
                 }
                 "BadDocumentException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::BadDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentTextDetectionError::BadDocument,
+                    )
                 }
                 "DocumentTooLargeException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::DocumentTooLarge(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentTextDetectionError::DocumentTooLarge,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        StartDocumentTextDetectionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentTextDetectionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        StartDocumentTextDetectionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentTextDetectionError::InternalServerError,
                     )
                 }
                 "InvalidKMSKeyException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::InvalidKMSKey(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentTextDetectionError::InvalidKMSKey,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentTextDetectionError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::InvalidS3Object(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentTextDetectionError::InvalidS3Object,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentTextDetectionError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StartDocumentTextDetectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentTextDetectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDocumentTextDetectionError::Throttling,
+                    )
                 }
                 "UnsupportedDocumentException" => {
-                    return RusotoError::Service(
-                        StartDocumentTextDetectionError::UnsupportedDocument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentTextDetectionError::UnsupportedDocument,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1175,11 +1395,12 @@ pub struct TextractClient {
 impl TextractClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> TextractClient {
         TextractClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Textract"),
         }
     }
 
@@ -1194,12 +1415,32 @@ impl TextractClient {
     {
         TextractClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Textract"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> TextractClient {
-        TextractClient { client, region }
+        TextractClient {
+            client,
+            region: region_for_service(region, "Textract"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> TextractClient {
+        TextractClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Textract"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> TextractClient {
+        TextractClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1211,6 +1452,7 @@ impl Textract for TextractClient {
         input: AnalyzeDocumentRequest,
     ) -> Result<AnalyzeDocumentResponse, RusotoError<AnalyzeDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AnalyzeDocument");
         request.add_header("x-amz-target", "Textract.AnalyzeDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1229,6 +1471,7 @@ impl Textract for TextractClient {
         input: DetectDocumentTextRequest,
     ) -> Result<DetectDocumentTextResponse, RusotoError<DetectDocumentTextError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectDocumentText");
         request.add_header("x-amz-target", "Textract.DetectDocumentText");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1247,6 +1490,7 @@ impl Textract for TextractClient {
         input: GetDocumentAnalysisRequest,
     ) -> Result<GetDocumentAnalysisResponse, RusotoError<GetDocumentAnalysisError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDocumentAnalysis");
         request.add_header("x-amz-target", "Textract.GetDocumentAnalysis");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1265,6 +1509,7 @@ impl Textract for TextractClient {
         input: GetDocumentTextDetectionRequest,
     ) -> Result<GetDocumentTextDetectionResponse, RusotoError<GetDocumentTextDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDocumentTextDetection");
         request.add_header("x-amz-target", "Textract.GetDocumentTextDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1284,6 +1529,7 @@ impl Textract for TextractClient {
         input: StartDocumentAnalysisRequest,
     ) -> Result<StartDocumentAnalysisResponse, RusotoError<StartDocumentAnalysisError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartDocumentAnalysis");
         request.add_header("x-amz-target", "Textract.StartDocumentAnalysis");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1304,6 +1550,7 @@ impl Textract for TextractClient {
     ) -> Result<StartDocumentTextDetectionResponse, RusotoError<StartDocumentTextDetectionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartDocumentTextDetection");
         request.add_header("x-amz-target", "Textract.StartDocumentTextDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1317,3 +1564,132 @@ impl Textract for TextractClient {
             .deserialize::<StartDocumentTextDetectionResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Textract API, running the calls of a `TextractClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `TextractClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct TextractBlockingClient {
+    client: TextractClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl TextractBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> TextractBlockingClient {
+        TextractBlockingClient::from_client(TextractClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> TextractBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        TextractBlockingClient::from_client(TextractClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> TextractBlockingClient {
+        TextractBlockingClient::from_client(TextractClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> TextractBlockingClient {
+        TextractBlockingClient::from_client(TextractClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: TextractClient) -> TextractBlockingClient {
+        TextractBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> TextractBlockingClient {
+        TextractBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> TextractBlockingClient {
+        TextractBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Textract::analyze_document`].
+    pub fn analyze_document(
+        &self,
+        input: AnalyzeDocumentRequest,
+    ) -> Result<AnalyzeDocumentResponse, RusotoError<AnalyzeDocumentError>> {
+        self.runtime.block_on(self.client.analyze_document(input))
+    }
+
+    /// Blocking version of [`Textract::detect_document_text`].
+    pub fn detect_document_text(
+        &self,
+        input: DetectDocumentTextRequest,
+    ) -> Result<DetectDocumentTextResponse, RusotoError<DetectDocumentTextError>> {
+        self.runtime
+            .block_on(self.client.detect_document_text(input))
+    }
+
+    /// Blocking version of [`Textract::get_document_analysis`].
+    pub fn get_document_analysis(
+        &self,
+        input: GetDocumentAnalysisRequest,
+    ) -> Result<GetDocumentAnalysisResponse, RusotoError<GetDocumentAnalysisError>> {
+        self.runtime
+            .block_on(self.client.get_document_analysis(input))
+    }
+
+    /// Blocking version of [`Textract::get_document_text_detection`].
+    pub fn get_document_text_detection(
+        &self,
+        input: GetDocumentTextDetectionRequest,
+    ) -> Result<GetDocumentTextDetectionResponse, RusotoError<GetDocumentTextDetectionError>> {
+        self.runtime
+            .block_on(self.client.get_document_text_detection(input))
+    }
+
+    /// Blocking version of [`Textract::start_document_analysis`].
+    pub fn start_document_analysis(
+        &self,
+        input: StartDocumentAnalysisRequest,
+    ) -> Result<StartDocumentAnalysisResponse, RusotoError<StartDocumentAnalysisError>> {
+        self.runtime
+            .block_on(self.client.start_document_analysis(input))
+    }
+
+    /// Blocking version of [`Textract::start_document_text_detection`].
+    pub fn start_document_text_detection(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+    ) -> Result<StartDocumentTextDetectionResponse, RusotoError<StartDocumentTextDetectionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_document_text_detection(input))
+    }
+}
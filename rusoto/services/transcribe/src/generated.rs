@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1342,18 +1343,38 @@ impl CreateLanguageModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateLanguageModelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLanguageModelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateLanguageModelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLanguageModelError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateLanguageModelError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLanguageModelError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateLanguageModelError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLanguageModelError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1390,22 +1411,38 @@ impl CreateMedicalVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMedicalVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMedicalVocabularyError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateMedicalVocabularyError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMedicalVocabularyError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateMedicalVocabularyError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMedicalVocabularyError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateMedicalVocabularyError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMedicalVocabularyError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1442,18 +1479,38 @@ impl CreateVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVocabularyError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVocabularyError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVocabularyError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateVocabularyError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVocabularyError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVocabularyError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1490,22 +1547,38 @@ impl CreateVocabularyFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVocabularyFilterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVocabularyFilterError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVocabularyFilterError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVocabularyFilterError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateVocabularyFilterError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVocabularyFilterError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVocabularyFilterError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVocabularyFilterError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1540,15 +1613,30 @@ impl DeleteLanguageModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteLanguageModelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLanguageModelError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteLanguageModelError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLanguageModelError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteLanguageModelError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLanguageModelError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1584,21 +1672,30 @@ impl DeleteMedicalTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMedicalTranscriptionJobError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMedicalTranscriptionJobError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        DeleteMedicalTranscriptionJobError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMedicalTranscriptionJobError::InternalFailure,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteMedicalTranscriptionJobError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMedicalTranscriptionJobError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1636,22 +1733,38 @@ impl DeleteMedicalVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMedicalVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMedicalVocabularyError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteMedicalVocabularyError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMedicalVocabularyError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteMedicalVocabularyError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMedicalVocabularyError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMedicalVocabularyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMedicalVocabularyError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1686,19 +1799,30 @@ impl DeleteTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteTranscriptionJobError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTranscriptionJobError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteTranscriptionJobError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTranscriptionJobError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteTranscriptionJobError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTranscriptionJobError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1734,18 +1858,38 @@ impl DeleteVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVocabularyError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteVocabularyError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVocabularyError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVocabularyError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVocabularyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVocabularyError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1782,22 +1926,38 @@ impl DeleteVocabularyFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVocabularyFilterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVocabularyFilterError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteVocabularyFilterError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVocabularyFilterError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteVocabularyFilterError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVocabularyFilterError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVocabularyFilterError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVocabularyFilterError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1834,20 +1994,38 @@ impl DescribeLanguageModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeLanguageModelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLanguageModelError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeLanguageModelError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLanguageModelError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeLanguageModelError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLanguageModelError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeLanguageModelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLanguageModelError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1886,24 +2064,38 @@ impl GetMedicalTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMedicalTranscriptionJobError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMedicalTranscriptionJobError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetMedicalTranscriptionJobError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMedicalTranscriptionJobError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetMedicalTranscriptionJobError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMedicalTranscriptionJobError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMedicalTranscriptionJobError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMedicalTranscriptionJobError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1940,20 +2132,38 @@ impl GetMedicalVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMedicalVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMedicalVocabularyError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetMedicalVocabularyError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMedicalVocabularyError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetMedicalVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMedicalVocabularyError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMedicalVocabularyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMedicalVocabularyError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1990,18 +2200,38 @@ impl GetTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTranscriptionJobError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTranscriptionJobError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetTranscriptionJobError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTranscriptionJobError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTranscriptionJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTranscriptionJobError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTranscriptionJobError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTranscriptionJobError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2038,18 +2268,38 @@ impl GetVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVocabularyError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetVocabularyError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVocabularyError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVocabularyError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVocabularyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVocabularyError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2086,18 +2336,38 @@ impl GetVocabularyFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVocabularyFilterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVocabularyFilterError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetVocabularyFilterError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVocabularyFilterError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetVocabularyFilterError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVocabularyFilterError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVocabularyFilterError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVocabularyFilterError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2132,15 +2402,30 @@ impl ListLanguageModelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListLanguageModelsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLanguageModelsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListLanguageModelsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLanguageModelsError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListLanguageModelsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLanguageModelsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2176,21 +2461,30 @@ impl ListMedicalTranscriptionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMedicalTranscriptionJobsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMedicalTranscriptionJobsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        ListMedicalTranscriptionJobsError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMedicalTranscriptionJobsError::InternalFailure,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListMedicalTranscriptionJobsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMedicalTranscriptionJobsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2224,19 +2518,30 @@ impl ListMedicalVocabulariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMedicalVocabulariesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMedicalVocabulariesError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListMedicalVocabulariesError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMedicalVocabulariesError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListMedicalVocabulariesError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMedicalVocabulariesError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2270,17 +2575,30 @@ impl ListTranscriptionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTranscriptionJobsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTranscriptionJobsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTranscriptionJobsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTranscriptionJobsError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTranscriptionJobsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTranscriptionJobsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2314,15 +2632,30 @@ impl ListVocabulariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVocabulariesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVocabulariesError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListVocabulariesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVocabulariesError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListVocabulariesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVocabulariesError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2356,17 +2689,30 @@ impl ListVocabularyFiltersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVocabularyFiltersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVocabularyFiltersError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListVocabularyFiltersError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVocabularyFiltersError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListVocabularyFiltersError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVocabularyFiltersError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2404,26 +2750,38 @@ impl StartMedicalTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartMedicalTranscriptionJobError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMedicalTranscriptionJobError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartMedicalTranscriptionJobError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMedicalTranscriptionJobError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        StartMedicalTranscriptionJobError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMedicalTranscriptionJobError::InternalFailure,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartMedicalTranscriptionJobError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMedicalTranscriptionJobError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2460,20 +2818,38 @@ impl StartTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartTranscriptionJobError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTranscriptionJobError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartTranscriptionJobError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTranscriptionJobError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartTranscriptionJobError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartTranscriptionJobError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartTranscriptionJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTranscriptionJobError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2512,25 +2888,46 @@ impl UpdateMedicalVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMedicalVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMedicalVocabularyError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMedicalVocabularyError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMedicalVocabularyError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateMedicalVocabularyError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMedicalVocabularyError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateMedicalVocabularyError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMedicalVocabularyError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMedicalVocabularyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMedicalVocabularyError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2570,21 +2967,46 @@ impl UpdateVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVocabularyError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVocabularyError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVocabularyError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateVocabularyError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVocabularyError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVocabularyError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVocabularyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVocabularyError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2622,22 +3044,38 @@ impl UpdateVocabularyFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVocabularyFilterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVocabularyFilterError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateVocabularyFilterError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVocabularyFilterError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVocabularyFilterError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVocabularyFilterError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVocabularyFilterError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVocabularyFilterError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2831,11 +3269,12 @@ pub struct TranscribeClient {
 impl TranscribeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> TranscribeClient {
         TranscribeClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Transcribe"),
         }
     }
 
@@ -2850,12 +3289,32 @@ impl TranscribeClient {
     {
         TranscribeClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Transcribe"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> TranscribeClient {
-        TranscribeClient { client, region }
+        TranscribeClient {
+            client,
+            region: region_for_service(region, "Transcribe"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> TranscribeClient {
+        TranscribeClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Transcribe"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> TranscribeClient {
+        TranscribeClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2867,6 +3326,7 @@ impl Transcribe for TranscribeClient {
         input: CreateLanguageModelRequest,
     ) -> Result<CreateLanguageModelResponse, RusotoError<CreateLanguageModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLanguageModel");
         request.add_header("x-amz-target", "Transcribe.CreateLanguageModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2885,6 +3345,7 @@ impl Transcribe for TranscribeClient {
         input: CreateMedicalVocabularyRequest,
     ) -> Result<CreateMedicalVocabularyResponse, RusotoError<CreateMedicalVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateMedicalVocabulary");
         request.add_header("x-amz-target", "Transcribe.CreateMedicalVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2904,6 +3365,7 @@ impl Transcribe for TranscribeClient {
         input: CreateVocabularyRequest,
     ) -> Result<CreateVocabularyResponse, RusotoError<CreateVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateVocabulary");
         request.add_header("x-amz-target", "Transcribe.CreateVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2922,6 +3384,7 @@ impl Transcribe for TranscribeClient {
         input: CreateVocabularyFilterRequest,
     ) -> Result<CreateVocabularyFilterResponse, RusotoError<CreateVocabularyFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateVocabularyFilter");
         request.add_header("x-amz-target", "Transcribe.CreateVocabularyFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2941,6 +3404,7 @@ impl Transcribe for TranscribeClient {
         input: DeleteLanguageModelRequest,
     ) -> Result<(), RusotoError<DeleteLanguageModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLanguageModel");
         request.add_header("x-amz-target", "Transcribe.DeleteLanguageModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2958,6 +3422,7 @@ impl Transcribe for TranscribeClient {
         input: DeleteMedicalTranscriptionJobRequest,
     ) -> Result<(), RusotoError<DeleteMedicalTranscriptionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteMedicalTranscriptionJob");
         request.add_header("x-amz-target", "Transcribe.DeleteMedicalTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2975,6 +3440,7 @@ impl Transcribe for TranscribeClient {
         input: DeleteMedicalVocabularyRequest,
     ) -> Result<(), RusotoError<DeleteMedicalVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteMedicalVocabulary");
         request.add_header("x-amz-target", "Transcribe.DeleteMedicalVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2992,6 +3458,7 @@ impl Transcribe for TranscribeClient {
         input: DeleteTranscriptionJobRequest,
     ) -> Result<(), RusotoError<DeleteTranscriptionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTranscriptionJob");
         request.add_header("x-amz-target", "Transcribe.DeleteTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3009,6 +3476,7 @@ impl Transcribe for TranscribeClient {
         input: DeleteVocabularyRequest,
     ) -> Result<(), RusotoError<DeleteVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteVocabulary");
         request.add_header("x-amz-target", "Transcribe.DeleteVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3026,6 +3494,7 @@ impl Transcribe for TranscribeClient {
         input: DeleteVocabularyFilterRequest,
     ) -> Result<(), RusotoError<DeleteVocabularyFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteVocabularyFilter");
         request.add_header("x-amz-target", "Transcribe.DeleteVocabularyFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3043,6 +3512,7 @@ impl Transcribe for TranscribeClient {
         input: DescribeLanguageModelRequest,
     ) -> Result<DescribeLanguageModelResponse, RusotoError<DescribeLanguageModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLanguageModel");
         request.add_header("x-amz-target", "Transcribe.DescribeLanguageModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3063,6 +3533,7 @@ impl Transcribe for TranscribeClient {
     ) -> Result<GetMedicalTranscriptionJobResponse, RusotoError<GetMedicalTranscriptionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMedicalTranscriptionJob");
         request.add_header("x-amz-target", "Transcribe.GetMedicalTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3082,6 +3553,7 @@ impl Transcribe for TranscribeClient {
         input: GetMedicalVocabularyRequest,
     ) -> Result<GetMedicalVocabularyResponse, RusotoError<GetMedicalVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMedicalVocabulary");
         request.add_header("x-amz-target", "Transcribe.GetMedicalVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3101,6 +3573,7 @@ impl Transcribe for TranscribeClient {
         input: GetTranscriptionJobRequest,
     ) -> Result<GetTranscriptionJobResponse, RusotoError<GetTranscriptionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTranscriptionJob");
         request.add_header("x-amz-target", "Transcribe.GetTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3119,6 +3592,7 @@ impl Transcribe for TranscribeClient {
         input: GetVocabularyRequest,
     ) -> Result<GetVocabularyResponse, RusotoError<GetVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetVocabulary");
         request.add_header("x-amz-target", "Transcribe.GetVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3137,6 +3611,7 @@ impl Transcribe for TranscribeClient {
         input: GetVocabularyFilterRequest,
     ) -> Result<GetVocabularyFilterResponse, RusotoError<GetVocabularyFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetVocabularyFilter");
         request.add_header("x-amz-target", "Transcribe.GetVocabularyFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3155,6 +3630,7 @@ impl Transcribe for TranscribeClient {
         input: ListLanguageModelsRequest,
     ) -> Result<ListLanguageModelsResponse, RusotoError<ListLanguageModelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLanguageModels");
         request.add_header("x-amz-target", "Transcribe.ListLanguageModels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3174,6 +3650,7 @@ impl Transcribe for TranscribeClient {
     ) -> Result<ListMedicalTranscriptionJobsResponse, RusotoError<ListMedicalTranscriptionJobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListMedicalTranscriptionJobs");
         request.add_header("x-amz-target", "Transcribe.ListMedicalTranscriptionJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3193,6 +3670,7 @@ impl Transcribe for TranscribeClient {
         input: ListMedicalVocabulariesRequest,
     ) -> Result<ListMedicalVocabulariesResponse, RusotoError<ListMedicalVocabulariesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListMedicalVocabularies");
         request.add_header("x-amz-target", "Transcribe.ListMedicalVocabularies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3212,6 +3690,7 @@ impl Transcribe for TranscribeClient {
         input: ListTranscriptionJobsRequest,
     ) -> Result<ListTranscriptionJobsResponse, RusotoError<ListTranscriptionJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTranscriptionJobs");
         request.add_header("x-amz-target", "Transcribe.ListTranscriptionJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3231,6 +3710,7 @@ impl Transcribe for TranscribeClient {
         input: ListVocabulariesRequest,
     ) -> Result<ListVocabulariesResponse, RusotoError<ListVocabulariesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListVocabularies");
         request.add_header("x-amz-target", "Transcribe.ListVocabularies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3249,6 +3729,7 @@ impl Transcribe for TranscribeClient {
         input: ListVocabularyFiltersRequest,
     ) -> Result<ListVocabularyFiltersResponse, RusotoError<ListVocabularyFiltersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListVocabularyFilters");
         request.add_header("x-amz-target", "Transcribe.ListVocabularyFilters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3269,6 +3750,7 @@ impl Transcribe for TranscribeClient {
     ) -> Result<StartMedicalTranscriptionJobResponse, RusotoError<StartMedicalTranscriptionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartMedicalTranscriptionJob");
         request.add_header("x-amz-target", "Transcribe.StartMedicalTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3288,6 +3770,7 @@ impl Transcribe for TranscribeClient {
         input: StartTranscriptionJobRequest,
     ) -> Result<StartTranscriptionJobResponse, RusotoError<StartTranscriptionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartTranscriptionJob");
         request.add_header("x-amz-target", "Transcribe.StartTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3307,6 +3790,7 @@ impl Transcribe for TranscribeClient {
         input: UpdateMedicalVocabularyRequest,
     ) -> Result<UpdateMedicalVocabularyResponse, RusotoError<UpdateMedicalVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateMedicalVocabulary");
         request.add_header("x-amz-target", "Transcribe.UpdateMedicalVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3326,6 +3810,7 @@ impl Transcribe for TranscribeClient {
         input: UpdateVocabularyRequest,
     ) -> Result<UpdateVocabularyResponse, RusotoError<UpdateVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateVocabulary");
         request.add_header("x-amz-target", "Transcribe.UpdateVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3344,6 +3829,7 @@ impl Transcribe for TranscribeClient {
         input: UpdateVocabularyFilterRequest,
     ) -> Result<UpdateVocabularyFilterResponse, RusotoError<UpdateVocabularyFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateVocabularyFilter");
         request.add_header("x-amz-target", "Transcribe.UpdateVocabularyFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3357,3 +3843,319 @@ impl Transcribe for TranscribeClient {
             .deserialize::<UpdateVocabularyFilterResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Transcribe Service API, running the calls of a `TranscribeClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `TranscribeClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct TranscribeBlockingClient {
+    client: TranscribeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl TranscribeBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> TranscribeBlockingClient {
+        TranscribeBlockingClient::from_client(TranscribeClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> TranscribeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        TranscribeBlockingClient::from_client(TranscribeClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> TranscribeBlockingClient {
+        TranscribeBlockingClient::from_client(TranscribeClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> TranscribeBlockingClient {
+        TranscribeBlockingClient::from_client(TranscribeClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: TranscribeClient) -> TranscribeBlockingClient {
+        TranscribeBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> TranscribeBlockingClient {
+        TranscribeBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> TranscribeBlockingClient {
+        TranscribeBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Transcribe::create_language_model`].
+    pub fn create_language_model(
+        &self,
+        input: CreateLanguageModelRequest,
+    ) -> Result<CreateLanguageModelResponse, RusotoError<CreateLanguageModelError>> {
+        self.runtime
+            .block_on(self.client.create_language_model(input))
+    }
+
+    /// Blocking version of [`Transcribe::create_medical_vocabulary`].
+    pub fn create_medical_vocabulary(
+        &self,
+        input: CreateMedicalVocabularyRequest,
+    ) -> Result<CreateMedicalVocabularyResponse, RusotoError<CreateMedicalVocabularyError>> {
+        self.runtime
+            .block_on(self.client.create_medical_vocabulary(input))
+    }
+
+    /// Blocking version of [`Transcribe::create_vocabulary`].
+    pub fn create_vocabulary(
+        &self,
+        input: CreateVocabularyRequest,
+    ) -> Result<CreateVocabularyResponse, RusotoError<CreateVocabularyError>> {
+        self.runtime.block_on(self.client.create_vocabulary(input))
+    }
+
+    /// Blocking version of [`Transcribe::create_vocabulary_filter`].
+    pub fn create_vocabulary_filter(
+        &self,
+        input: CreateVocabularyFilterRequest,
+    ) -> Result<CreateVocabularyFilterResponse, RusotoError<CreateVocabularyFilterError>> {
+        self.runtime
+            .block_on(self.client.create_vocabulary_filter(input))
+    }
+
+    /// Blocking version of [`Transcribe::delete_language_model`].
+    pub fn delete_language_model(
+        &self,
+        input: DeleteLanguageModelRequest,
+    ) -> Result<(), RusotoError<DeleteLanguageModelError>> {
+        self.runtime
+            .block_on(self.client.delete_language_model(input))
+    }
+
+    /// Blocking version of [`Transcribe::delete_medical_transcription_job`].
+    pub fn delete_medical_transcription_job(
+        &self,
+        input: DeleteMedicalTranscriptionJobRequest,
+    ) -> Result<(), RusotoError<DeleteMedicalTranscriptionJobError>> {
+        self.runtime
+            .block_on(self.client.delete_medical_transcription_job(input))
+    }
+
+    /// Blocking version of [`Transcribe::delete_medical_vocabulary`].
+    pub fn delete_medical_vocabulary(
+        &self,
+        input: DeleteMedicalVocabularyRequest,
+    ) -> Result<(), RusotoError<DeleteMedicalVocabularyError>> {
+        self.runtime
+            .block_on(self.client.delete_medical_vocabulary(input))
+    }
+
+    /// Blocking version of [`Transcribe::delete_transcription_job`].
+    pub fn delete_transcription_job(
+        &self,
+        input: DeleteTranscriptionJobRequest,
+    ) -> Result<(), RusotoError<DeleteTranscriptionJobError>> {
+        self.runtime
+            .block_on(self.client.delete_transcription_job(input))
+    }
+
+    /// Blocking version of [`Transcribe::delete_vocabulary`].
+    pub fn delete_vocabulary(
+        &self,
+        input: DeleteVocabularyRequest,
+    ) -> Result<(), RusotoError<DeleteVocabularyError>> {
+        self.runtime.block_on(self.client.delete_vocabulary(input))
+    }
+
+    /// Blocking version of [`Transcribe::delete_vocabulary_filter`].
+    pub fn delete_vocabulary_filter(
+        &self,
+        input: DeleteVocabularyFilterRequest,
+    ) -> Result<(), RusotoError<DeleteVocabularyFilterError>> {
+        self.runtime
+            .block_on(self.client.delete_vocabulary_filter(input))
+    }
+
+    /// Blocking version of [`Transcribe::describe_language_model`].
+    pub fn describe_language_model(
+        &self,
+        input: DescribeLanguageModelRequest,
+    ) -> Result<DescribeLanguageModelResponse, RusotoError<DescribeLanguageModelError>> {
+        self.runtime
+            .block_on(self.client.describe_language_model(input))
+    }
+
+    /// Blocking version of [`Transcribe::get_medical_transcription_job`].
+    pub fn get_medical_transcription_job(
+        &self,
+        input: GetMedicalTranscriptionJobRequest,
+    ) -> Result<GetMedicalTranscriptionJobResponse, RusotoError<GetMedicalTranscriptionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.get_medical_transcription_job(input))
+    }
+
+    /// Blocking version of [`Transcribe::get_medical_vocabulary`].
+    pub fn get_medical_vocabulary(
+        &self,
+        input: GetMedicalVocabularyRequest,
+    ) -> Result<GetMedicalVocabularyResponse, RusotoError<GetMedicalVocabularyError>> {
+        self.runtime
+            .block_on(self.client.get_medical_vocabulary(input))
+    }
+
+    /// Blocking version of [`Transcribe::get_transcription_job`].
+    pub fn get_transcription_job(
+        &self,
+        input: GetTranscriptionJobRequest,
+    ) -> Result<GetTranscriptionJobResponse, RusotoError<GetTranscriptionJobError>> {
+        self.runtime
+            .block_on(self.client.get_transcription_job(input))
+    }
+
+    /// Blocking version of [`Transcribe::get_vocabulary`].
+    pub fn get_vocabulary(
+        &self,
+        input: GetVocabularyRequest,
+    ) -> Result<GetVocabularyResponse, RusotoError<GetVocabularyError>> {
+        self.runtime.block_on(self.client.get_vocabulary(input))
+    }
+
+    /// Blocking version of [`Transcribe::get_vocabulary_filter`].
+    pub fn get_vocabulary_filter(
+        &self,
+        input: GetVocabularyFilterRequest,
+    ) -> Result<GetVocabularyFilterResponse, RusotoError<GetVocabularyFilterError>> {
+        self.runtime
+            .block_on(self.client.get_vocabulary_filter(input))
+    }
+
+    /// Blocking version of [`Transcribe::list_language_models`].
+    pub fn list_language_models(
+        &self,
+        input: ListLanguageModelsRequest,
+    ) -> Result<ListLanguageModelsResponse, RusotoError<ListLanguageModelsError>> {
+        self.runtime
+            .block_on(self.client.list_language_models(input))
+    }
+
+    /// Blocking version of [`Transcribe::list_medical_transcription_jobs`].
+    pub fn list_medical_transcription_jobs(
+        &self,
+        input: ListMedicalTranscriptionJobsRequest,
+    ) -> Result<ListMedicalTranscriptionJobsResponse, RusotoError<ListMedicalTranscriptionJobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_medical_transcription_jobs(input))
+    }
+
+    /// Blocking version of [`Transcribe::list_medical_vocabularies`].
+    pub fn list_medical_vocabularies(
+        &self,
+        input: ListMedicalVocabulariesRequest,
+    ) -> Result<ListMedicalVocabulariesResponse, RusotoError<ListMedicalVocabulariesError>> {
+        self.runtime
+            .block_on(self.client.list_medical_vocabularies(input))
+    }
+
+    /// Blocking version of [`Transcribe::list_transcription_jobs`].
+    pub fn list_transcription_jobs(
+        &self,
+        input: ListTranscriptionJobsRequest,
+    ) -> Result<ListTranscriptionJobsResponse, RusotoError<ListTranscriptionJobsError>> {
+        self.runtime
+            .block_on(self.client.list_transcription_jobs(input))
+    }
+
+    /// Blocking version of [`Transcribe::list_vocabularies`].
+    pub fn list_vocabularies(
+        &self,
+        input: ListVocabulariesRequest,
+    ) -> Result<ListVocabulariesResponse, RusotoError<ListVocabulariesError>> {
+        self.runtime.block_on(self.client.list_vocabularies(input))
+    }
+
+    /// Blocking version of [`Transcribe::list_vocabulary_filters`].
+    pub fn list_vocabulary_filters(
+        &self,
+        input: ListVocabularyFiltersRequest,
+    ) -> Result<ListVocabularyFiltersResponse, RusotoError<ListVocabularyFiltersError>> {
+        self.runtime
+            .block_on(self.client.list_vocabulary_filters(input))
+    }
+
+    /// Blocking version of [`Transcribe::start_medical_transcription_job`].
+    pub fn start_medical_transcription_job(
+        &self,
+        input: StartMedicalTranscriptionJobRequest,
+    ) -> Result<StartMedicalTranscriptionJobResponse, RusotoError<StartMedicalTranscriptionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.start_medical_transcription_job(input))
+    }
+
+    /// Blocking version of [`Transcribe::start_transcription_job`].
+    pub fn start_transcription_job(
+        &self,
+        input: StartTranscriptionJobRequest,
+    ) -> Result<StartTranscriptionJobResponse, RusotoError<StartTranscriptionJobError>> {
+        self.runtime
+            .block_on(self.client.start_transcription_job(input))
+    }
+
+    /// Blocking version of [`Transcribe::update_medical_vocabulary`].
+    pub fn update_medical_vocabulary(
+        &self,
+        input: UpdateMedicalVocabularyRequest,
+    ) -> Result<UpdateMedicalVocabularyResponse, RusotoError<UpdateMedicalVocabularyError>> {
+        self.runtime
+            .block_on(self.client.update_medical_vocabulary(input))
+    }
+
+    /// Blocking version of [`Transcribe::update_vocabulary`].
+    pub fn update_vocabulary(
+        &self,
+        input: UpdateVocabularyRequest,
+    ) -> Result<UpdateVocabularyResponse, RusotoError<UpdateVocabularyError>> {
+        self.runtime.block_on(self.client.update_vocabulary(input))
+    }
+
+    /// Blocking version of [`Transcribe::update_vocabulary_filter`].
+    pub fn update_vocabulary_filter(
+        &self,
+        input: UpdateVocabularyFilterRequest,
+    ) -> Result<UpdateVocabularyFilterResponse, RusotoError<UpdateVocabularyFilterError>> {
+        self.runtime
+            .block_on(self.client.update_vocabulary_filter(input))
+    }
+}
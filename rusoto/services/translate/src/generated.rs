@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -793,26 +794,54 @@ impl CreateParallelDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateParallelDataError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateParallelDataError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateParallelDataError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateParallelDataError::InternalServer,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateParallelDataError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateParallelDataError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateParallelDataError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateParallelDataError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateParallelDataError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateParallelDataError::LimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateParallelDataError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateParallelDataError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -851,20 +880,38 @@ impl DeleteParallelDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteParallelDataError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteParallelDataError::ConcurrentModification,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteParallelDataError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteParallelDataError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteParallelDataError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteParallelDataError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteParallelDataError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteParallelDataError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -901,20 +948,38 @@ impl DeleteTerminologyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteTerminologyError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTerminologyError::InternalServer,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteTerminologyError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTerminologyError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTerminologyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTerminologyError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteTerminologyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTerminologyError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -951,21 +1016,30 @@ impl DescribeTextTranslationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeTextTranslationJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTextTranslationJobError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTextTranslationJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTextTranslationJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeTextTranslationJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTextTranslationJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1001,20 +1075,38 @@ impl GetParallelDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetParallelDataError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParallelDataError::InternalServer,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetParallelDataError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParallelDataError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetParallelDataError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParallelDataError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetParallelDataError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParallelDataError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1051,20 +1143,38 @@ impl GetTerminologyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetTerminologyError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTerminologyError::InternalServer,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetTerminologyError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTerminologyError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetTerminologyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTerminologyError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetTerminologyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTerminologyError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1101,20 +1211,38 @@ impl ImportTerminologyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ImportTerminologyError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportTerminologyError::InternalServer,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ImportTerminologyError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportTerminologyError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportTerminologyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportTerminologyError::LimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportTerminologyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportTerminologyError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1149,17 +1277,30 @@ impl ListParallelDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListParallelDataError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListParallelDataError::InternalServer,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListParallelDataError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListParallelDataError::InvalidParameterValue,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListParallelDataError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListParallelDataError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1193,17 +1334,30 @@ impl ListTerminologiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTerminologiesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTerminologiesError::InternalServer,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTerminologiesError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTerminologiesError::InvalidParameterValue,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTerminologiesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTerminologiesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1239,26 +1393,38 @@ impl ListTextTranslationJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTextTranslationJobsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTextTranslationJobsError::InternalServer,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListTextTranslationJobsError::InvalidFilter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTextTranslationJobsError::InvalidFilter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTextTranslationJobsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTextTranslationJobsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTextTranslationJobsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTextTranslationJobsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1297,31 +1463,46 @@ impl StartTextTranslationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartTextTranslationJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartTextTranslationJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartTextTranslationJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartTextTranslationJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartTextTranslationJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartTextTranslationJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartTextTranslationJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartTextTranslationJobError::TooManyRequests,
+                    )
                 }
                 "UnsupportedLanguagePairException" => {
-                    return RusotoError::Service(
-                        StartTextTranslationJobError::UnsupportedLanguagePair(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTextTranslationJobError::UnsupportedLanguagePair,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1359,21 +1540,30 @@ impl StopTextTranslationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopTextTranslationJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopTextTranslationJobError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopTextTranslationJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopTextTranslationJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopTextTranslationJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopTextTranslationJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1417,34 +1607,70 @@ impl TranslateTextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DetectedLanguageLowConfidenceException" => {
-                    return RusotoError::Service(TranslateTextError::DetectedLanguageLowConfidence(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TranslateTextError::DetectedLanguageLowConfidence,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TranslateTextError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TranslateTextError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TranslateTextError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TranslateTextError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TranslateTextError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TranslateTextError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TranslateTextError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TranslateTextError::ServiceUnavailable,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(TranslateTextError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TranslateTextError::TextSizeLimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TranslateTextError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TranslateTextError::TooManyRequests,
+                    )
                 }
                 "UnsupportedLanguagePairException" => {
-                    return RusotoError::Service(TranslateTextError::UnsupportedLanguagePair(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TranslateTextError::UnsupportedLanguagePair,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1493,34 +1719,70 @@ impl UpdateParallelDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateParallelDataError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateParallelDataError::ConcurrentModification,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateParallelDataError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateParallelDataError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateParallelDataError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateParallelDataError::InternalServer,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateParallelDataError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateParallelDataError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateParallelDataError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateParallelDataError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateParallelDataError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateParallelDataError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateParallelDataError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateParallelDataError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateParallelDataError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateParallelDataError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1640,11 +1902,12 @@ pub struct TranslateClient {
 impl TranslateClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> TranslateClient {
         TranslateClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Translate"),
         }
     }
 
@@ -1659,12 +1922,32 @@ impl TranslateClient {
     {
         TranslateClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Translate"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> TranslateClient {
-        TranslateClient { client, region }
+        TranslateClient {
+            client,
+            region: region_for_service(region, "Translate"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> TranslateClient {
+        TranslateClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Translate"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> TranslateClient {
+        TranslateClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1676,6 +1959,7 @@ impl Translate for TranslateClient {
         input: CreateParallelDataRequest,
     ) -> Result<CreateParallelDataResponse, RusotoError<CreateParallelDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateParallelData");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.CreateParallelData",
@@ -1697,6 +1981,7 @@ impl Translate for TranslateClient {
         input: DeleteParallelDataRequest,
     ) -> Result<DeleteParallelDataResponse, RusotoError<DeleteParallelDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteParallelData");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.DeleteParallelData",
@@ -1718,6 +2003,7 @@ impl Translate for TranslateClient {
         input: DeleteTerminologyRequest,
     ) -> Result<(), RusotoError<DeleteTerminologyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTerminology");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.DeleteTerminology",
@@ -1739,6 +2025,7 @@ impl Translate for TranslateClient {
     ) -> Result<DescribeTextTranslationJobResponse, RusotoError<DescribeTextTranslationJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTextTranslationJob");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.DescribeTextTranslationJob",
@@ -1761,6 +2048,7 @@ impl Translate for TranslateClient {
         input: GetParallelDataRequest,
     ) -> Result<GetParallelDataResponse, RusotoError<GetParallelDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetParallelData");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.GetParallelData",
@@ -1782,6 +2070,7 @@ impl Translate for TranslateClient {
         input: GetTerminologyRequest,
     ) -> Result<GetTerminologyResponse, RusotoError<GetTerminologyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTerminology");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.GetTerminology",
@@ -1803,6 +2092,7 @@ impl Translate for TranslateClient {
         input: ImportTerminologyRequest,
     ) -> Result<ImportTerminologyResponse, RusotoError<ImportTerminologyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportTerminology");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.ImportTerminology",
@@ -1824,6 +2114,7 @@ impl Translate for TranslateClient {
         input: ListParallelDataRequest,
     ) -> Result<ListParallelDataResponse, RusotoError<ListParallelDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListParallelData");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.ListParallelData",
@@ -1845,6 +2136,7 @@ impl Translate for TranslateClient {
         input: ListTerminologiesRequest,
     ) -> Result<ListTerminologiesResponse, RusotoError<ListTerminologiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTerminologies");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.ListTerminologies",
@@ -1866,6 +2158,7 @@ impl Translate for TranslateClient {
         input: ListTextTranslationJobsRequest,
     ) -> Result<ListTextTranslationJobsResponse, RusotoError<ListTextTranslationJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTextTranslationJobs");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.ListTextTranslationJobs",
@@ -1888,6 +2181,7 @@ impl Translate for TranslateClient {
         input: StartTextTranslationJobRequest,
     ) -> Result<StartTextTranslationJobResponse, RusotoError<StartTextTranslationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartTextTranslationJob");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.StartTextTranslationJob",
@@ -1910,6 +2204,7 @@ impl Translate for TranslateClient {
         input: StopTextTranslationJobRequest,
     ) -> Result<StopTextTranslationJobResponse, RusotoError<StopTextTranslationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopTextTranslationJob");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.StopTextTranslationJob",
@@ -1932,6 +2227,7 @@ impl Translate for TranslateClient {
         input: TranslateTextRequest,
     ) -> Result<TranslateTextResponse, RusotoError<TranslateTextError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TranslateText");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.TranslateText",
@@ -1953,6 +2249,7 @@ impl Translate for TranslateClient {
         input: UpdateParallelDataRequest,
     ) -> Result<UpdateParallelDataResponse, RusotoError<UpdateParallelDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateParallelData");
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.UpdateParallelData",
@@ -1968,3 +2265,198 @@ impl Translate for TranslateClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateParallelDataResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Translate API, running the calls of a `TranslateClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `TranslateClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct TranslateBlockingClient {
+    client: TranslateClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl TranslateBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> TranslateBlockingClient {
+        TranslateBlockingClient::from_client(TranslateClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> TranslateBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        TranslateBlockingClient::from_client(TranslateClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> TranslateBlockingClient {
+        TranslateBlockingClient::from_client(TranslateClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> TranslateBlockingClient {
+        TranslateBlockingClient::from_client(TranslateClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: TranslateClient) -> TranslateBlockingClient {
+        TranslateBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> TranslateBlockingClient {
+        TranslateBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> TranslateBlockingClient {
+        TranslateBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Translate::create_parallel_data`].
+    pub fn create_parallel_data(
+        &self,
+        input: CreateParallelDataRequest,
+    ) -> Result<CreateParallelDataResponse, RusotoError<CreateParallelDataError>> {
+        self.runtime
+            .block_on(self.client.create_parallel_data(input))
+    }
+
+    /// Blocking version of [`Translate::delete_parallel_data`].
+    pub fn delete_parallel_data(
+        &self,
+        input: DeleteParallelDataRequest,
+    ) -> Result<DeleteParallelDataResponse, RusotoError<DeleteParallelDataError>> {
+        self.runtime
+            .block_on(self.client.delete_parallel_data(input))
+    }
+
+    /// Blocking version of [`Translate::delete_terminology`].
+    pub fn delete_terminology(
+        &self,
+        input: DeleteTerminologyRequest,
+    ) -> Result<(), RusotoError<DeleteTerminologyError>> {
+        self.runtime.block_on(self.client.delete_terminology(input))
+    }
+
+    /// Blocking version of [`Translate::describe_text_translation_job`].
+    pub fn describe_text_translation_job(
+        &self,
+        input: DescribeTextTranslationJobRequest,
+    ) -> Result<DescribeTextTranslationJobResponse, RusotoError<DescribeTextTranslationJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_text_translation_job(input))
+    }
+
+    /// Blocking version of [`Translate::get_parallel_data`].
+    pub fn get_parallel_data(
+        &self,
+        input: GetParallelDataRequest,
+    ) -> Result<GetParallelDataResponse, RusotoError<GetParallelDataError>> {
+        self.runtime.block_on(self.client.get_parallel_data(input))
+    }
+
+    /// Blocking version of [`Translate::get_terminology`].
+    pub fn get_terminology(
+        &self,
+        input: GetTerminologyRequest,
+    ) -> Result<GetTerminologyResponse, RusotoError<GetTerminologyError>> {
+        self.runtime.block_on(self.client.get_terminology(input))
+    }
+
+    /// Blocking version of [`Translate::import_terminology`].
+    pub fn import_terminology(
+        &self,
+        input: ImportTerminologyRequest,
+    ) -> Result<ImportTerminologyResponse, RusotoError<ImportTerminologyError>> {
+        self.runtime.block_on(self.client.import_terminology(input))
+    }
+
+    /// Blocking version of [`Translate::list_parallel_data`].
+    pub fn list_parallel_data(
+        &self,
+        input: ListParallelDataRequest,
+    ) -> Result<ListParallelDataResponse, RusotoError<ListParallelDataError>> {
+        self.runtime.block_on(self.client.list_parallel_data(input))
+    }
+
+    /// Blocking version of [`Translate::list_terminologies`].
+    pub fn list_terminologies(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> Result<ListTerminologiesResponse, RusotoError<ListTerminologiesError>> {
+        self.runtime.block_on(self.client.list_terminologies(input))
+    }
+
+    /// Blocking version of [`Translate::list_text_translation_jobs`].
+    pub fn list_text_translation_jobs(
+        &self,
+        input: ListTextTranslationJobsRequest,
+    ) -> Result<ListTextTranslationJobsResponse, RusotoError<ListTextTranslationJobsError>> {
+        self.runtime
+            .block_on(self.client.list_text_translation_jobs(input))
+    }
+
+    /// Blocking version of [`Translate::start_text_translation_job`].
+    pub fn start_text_translation_job(
+        &self,
+        input: StartTextTranslationJobRequest,
+    ) -> Result<StartTextTranslationJobResponse, RusotoError<StartTextTranslationJobError>> {
+        self.runtime
+            .block_on(self.client.start_text_translation_job(input))
+    }
+
+    /// Blocking version of [`Translate::stop_text_translation_job`].
+    pub fn stop_text_translation_job(
+        &self,
+        input: StopTextTranslationJobRequest,
+    ) -> Result<StopTextTranslationJobResponse, RusotoError<StopTextTranslationJobError>> {
+        self.runtime
+            .block_on(self.client.stop_text_translation_job(input))
+    }
+
+    /// Blocking version of [`Translate::translate_text`].
+    pub fn translate_text(
+        &self,
+        input: TranslateTextRequest,
+    ) -> Result<TranslateTextResponse, RusotoError<TranslateTextError>> {
+        self.runtime.block_on(self.client.translate_text(input))
+    }
+
+    /// Blocking version of [`Translate::update_parallel_data`].
+    pub fn update_parallel_data(
+        &self,
+        input: UpdateParallelDataRequest,
+    ) -> Result<UpdateParallelDataResponse, RusotoError<UpdateParallelDataError>> {
+        self.runtime
+            .block_on(self.client.update_parallel_data(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2655,23 +2656,46 @@ impl AssociateWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(AssociateWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebACLError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(AssociateWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebACLError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(AssociateWebACLError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebACLError::WAFInvalidParameter,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(AssociateWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebACLError::WAFNonexistentItem,
+                    )
                 }
                 "WAFUnavailableEntityException" => {
-                    return RusotoError::Service(AssociateWebACLError::WAFUnavailableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateWebACLError::WAFUnavailableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2713,32 +2737,54 @@ impl CreateByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFDisallowedName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateByteMatchSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateByteMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateByteMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateByteMatchSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateByteMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateByteMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2781,26 +2827,54 @@ impl CreateGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFDisallowedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGeoMatchSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGeoMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGeoMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGeoMatchSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGeoMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGeoMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2843,24 +2917,54 @@ impl CreateIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFDisallowedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2907,40 +3011,70 @@ impl CreateRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRateBasedRuleError::WAFBadRequest,
+                    )
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFDisallowedName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRateBasedRuleError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRateBasedRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRateBasedRuleError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRateBasedRuleError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRateBasedRuleError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRateBasedRuleError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
-                        CreateRateBasedRuleError::WAFTagOperationInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRateBasedRuleError::WAFTagOperationInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2983,24 +3117,38 @@ impl CreateRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFDisallowedName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegexMatchSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegexMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegexMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRegexMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3037,24 +3185,38 @@ impl CreateRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFDisallowedName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegexPatternSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegexPatternSetError::WAFInternalError,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegexPatternSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRegexPatternSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3099,32 +3261,70 @@ impl CreateRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateRuleError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFBadRequest,
+                    )
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRuleError::WAFDisallowedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateRuleError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRuleError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(CreateRuleError::WAFTagOperationInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRuleError::WAFTagOperationInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3171,29 +3371,62 @@ impl CreateRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFBadRequest,
+                    )
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFDisallowedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFInternalError,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
-                        CreateRuleGroupError::WAFTagOperationInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFTagOperationInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3237,36 +3470,54 @@ impl CreateSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFDisallowedName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSizeConstraintSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSizeConstraintSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSizeConstraintSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSizeConstraintSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSizeConstraintSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSizeConstraintSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3311,36 +3562,54 @@ impl CreateSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(
-                        CreateSqlInjectionMatchSetError::WAFDisallowedName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSqlInjectionMatchSetError::WAFDisallowedName,
                     )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateSqlInjectionMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSqlInjectionMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
-                        CreateSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSqlInjectionMatchSetError::WAFInvalidAccount,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
-                        CreateSqlInjectionMatchSetError::WAFInvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSqlInjectionMatchSetError::WAFInvalidParameter,
                     )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(
-                        CreateSqlInjectionMatchSetError::WAFLimitsExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSqlInjectionMatchSetError::WAFLimitsExceeded,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateSqlInjectionMatchSetError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSqlInjectionMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3391,35 +3660,78 @@ impl CreateWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFBadRequest,
+                    )
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFDisallowedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFTagOperationInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWebACLError::WAFTagOperationInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3465,31 +3777,46 @@ impl CreateWebACLMigrationStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFEntityMigrationException" => {
-                    return RusotoError::Service(
-                        CreateWebACLMigrationStackError::WAFEntityMigration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLMigrationStackError::WAFEntityMigration,
                     )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateWebACLMigrationStackError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWebACLMigrationStackError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(
-                        CreateWebACLMigrationStackError::WAFInvalidOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLMigrationStackError::WAFInvalidOperation,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
-                        CreateWebACLMigrationStackError::WAFInvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLMigrationStackError::WAFInvalidParameter,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        CreateWebACLMigrationStackError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLMigrationStackError::WAFNonexistentItem,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3539,26 +3866,54 @@ impl CreateXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFDisallowedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateXssMatchSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateXssMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateXssMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateXssMatchSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateXssMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateXssMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3601,32 +3956,54 @@ impl DeleteByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteByteMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteByteMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFNonEmptyEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteByteMatchSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteByteMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteByteMatchSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteByteMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3669,26 +4046,54 @@ impl DeleteGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGeoMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGeoMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGeoMatchSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGeoMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGeoMatchSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGeoMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3731,24 +4136,54 @@ impl DeleteIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3787,21 +4222,30 @@ impl DeleteLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteLoggingConfigurationError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLoggingConfigurationError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        DeleteLoggingConfigurationError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoggingConfigurationError::WAFNonexistentItem,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteLoggingConfigurationError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLoggingConfigurationError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3837,19 +4281,30 @@ impl DeletePermissionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeletePermissionPolicyError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePermissionPolicyError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeletePermissionPolicyError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePermissionPolicyError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeletePermissionPolicyError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePermissionPolicyError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3893,42 +4348,70 @@ impl DeleteRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRateBasedRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRateBasedRuleError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFNonEmptyEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRateBasedRuleError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRateBasedRuleError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRateBasedRuleError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRateBasedRuleError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRateBasedRuleError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
-                        DeleteRateBasedRuleError::WAFTagOperationInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRateBasedRuleError::WAFTagOperationInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3975,34 +4458,54 @@ impl DeleteRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFNonEmptyEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexMatchSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexMatchSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRegexMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4045,34 +4548,54 @@ impl DeleteRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexPatternSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexPatternSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFNonEmptyEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexPatternSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexPatternSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexPatternSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRegexPatternSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4119,32 +4642,70 @@ impl DeleteRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFTagOperationInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRuleError::WAFTagOperationInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4193,32 +4754,70 @@ impl DeleteRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFInvalidOperation,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
-                        DeleteRuleGroupError::WAFTagOperationInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFTagOperationInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4263,36 +4862,54 @@ impl DeleteSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSizeConstraintSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSizeConstraintSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFNonEmptyEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSizeConstraintSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSizeConstraintSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSizeConstraintSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSizeConstraintSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4337,36 +4954,54 @@ impl DeleteSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteSqlInjectionMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSqlInjectionMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
-                        DeleteSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSqlInjectionMatchSetError::WAFInvalidAccount,
                     )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(
-                        DeleteSqlInjectionMatchSetError::WAFNonEmptyEntity(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSqlInjectionMatchSetError::WAFNonEmptyEntity,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        DeleteSqlInjectionMatchSetError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSqlInjectionMatchSetError::WAFNonexistentItem,
                     )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(
-                        DeleteSqlInjectionMatchSetError::WAFReferencedItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSqlInjectionMatchSetError::WAFReferencedItem,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteSqlInjectionMatchSetError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSqlInjectionMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4415,32 +5050,70 @@ impl DeleteWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFTagOperationInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteWebACLError::WAFTagOperationInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4485,26 +5158,54 @@ impl DeleteXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteXssMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFInvalidAccount(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteXssMatchSetError::WAFInvalidAccount,
+                    )
+                }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteXssMatchSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteXssMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteXssMatchSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteXssMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4543,24 +5244,38 @@ impl DisassociateWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DisassociateWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateWebACLError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DisassociateWebACLError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateWebACLError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(DisassociateWebACLError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateWebACLError::WAFInvalidParameter,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DisassociateWebACLError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateWebACLError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4595,15 +5310,30 @@ impl GetByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetByteMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetByteMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetByteMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetByteMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetByteMatchSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4633,9 +5363,14 @@ impl GetChangeTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetChangeTokenError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetChangeTokenError::WAFInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4665,16 +5400,22 @@ impl GetChangeTokenStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetChangeTokenStatusError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetChangeTokenStatusError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetChangeTokenStatusError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetChangeTokenStatusError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4707,15 +5448,30 @@ impl GetGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGeoMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGeoMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetGeoMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGeoMatchSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4749,15 +5505,30 @@ impl GetIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIPSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIPSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetIPSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIPSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4789,16 +5560,22 @@ impl GetLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetLoggingConfigurationError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoggingConfigurationError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetLoggingConfigurationError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoggingConfigurationError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4829,16 +5606,22 @@ impl GetPermissionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetPermissionPolicyError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPermissionPolicyError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetPermissionPolicyError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPermissionPolicyError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4871,15 +5654,30 @@ impl GetRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRateBasedRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRateBasedRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRateBasedRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4917,26 +5715,38 @@ impl GetRateBasedRuleManagedKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(
-                        GetRateBasedRuleManagedKeysError::WAFInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleManagedKeysError::WAFInternalError,
                     )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
-                        GetRateBasedRuleManagedKeysError::WAFInvalidAccount(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleManagedKeysError::WAFInvalidAccount,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
-                        GetRateBasedRuleManagedKeysError::WAFInvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleManagedKeysError::WAFInvalidParameter,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        GetRateBasedRuleManagedKeysError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleManagedKeysError::WAFNonexistentItem,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4977,15 +5787,30 @@ impl GetRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRegexMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegexMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRegexMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegexMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRegexMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegexMatchSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5019,19 +5844,30 @@ impl GetRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRegexPatternSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegexPatternSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRegexPatternSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRegexPatternSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRegexPatternSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRegexPatternSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5065,15 +5901,30 @@ impl GetRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRuleError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRuleError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5105,12 +5956,22 @@ impl GetRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRuleGroupError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRuleGroupError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRuleGroupError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5141,14 +6002,22 @@ impl GetSampledRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetSampledRequestsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSampledRequestsError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetSampledRequestsError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSampledRequestsError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5181,21 +6050,30 @@ impl GetSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetSizeConstraintSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSizeConstraintSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetSizeConstraintSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSizeConstraintSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetSizeConstraintSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSizeConstraintSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5229,21 +6107,30 @@ impl GetSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetSqlInjectionMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSqlInjectionMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetSqlInjectionMatchSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSqlInjectionMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetSqlInjectionMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSqlInjectionMatchSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5277,15 +6164,30 @@ impl GetWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWebACLError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWebACLError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWebACLError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5323,31 +6225,46 @@ impl GetWebACLForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetWebACLForResourceError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetWebACLForResourceError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetWebACLForResourceError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetWebACLForResourceError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(GetWebACLForResourceError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetWebACLForResourceError::WAFInvalidParameter,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetWebACLForResourceError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetWebACLForResourceError::WAFNonexistentItem,
+                    )
                 }
                 "WAFUnavailableEntityException" => {
-                    return RusotoError::Service(GetWebACLForResourceError::WAFUnavailableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetWebACLForResourceError::WAFUnavailableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5383,15 +6300,30 @@ impl GetXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetXssMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetXssMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetXssMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetXssMatchSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5427,21 +6359,30 @@ impl ListActivatedRulesInRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(
-                        ListActivatedRulesInRuleGroupError::WAFInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActivatedRulesInRuleGroupError::WAFInternalError,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
-                        ListActivatedRulesInRuleGroupError::WAFInvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActivatedRulesInRuleGroupError::WAFInvalidParameter,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        ListActivatedRulesInRuleGroupError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActivatedRulesInRuleGroupError::WAFNonexistentItem,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5479,12 +6420,22 @@ impl ListByteMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListByteMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListByteMatchSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListByteMatchSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListByteMatchSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5515,12 +6466,22 @@ impl ListGeoMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListGeoMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGeoMatchSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListGeoMatchSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGeoMatchSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5551,12 +6512,22 @@ impl ListIPSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListIPSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIPSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListIPSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIPSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5589,21 +6560,30 @@ impl ListLoggingConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListLoggingConfigurationsError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLoggingConfigurationsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
-                        ListLoggingConfigurationsError::WAFInvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLoggingConfigurationsError::WAFInvalidParameter,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        ListLoggingConfigurationsError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLoggingConfigurationsError::WAFNonexistentItem,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5637,14 +6617,22 @@ impl ListRateBasedRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRateBasedRulesError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRateBasedRulesError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRateBasedRulesError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRateBasedRulesError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5675,14 +6663,22 @@ impl ListRegexMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRegexMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRegexMatchSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRegexMatchSetsError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRegexMatchSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5713,16 +6709,22 @@ impl ListRegexPatternSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRegexPatternSetsError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRegexPatternSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRegexPatternSetsError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRegexPatternSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5757,26 +6759,38 @@ impl ListResourcesForWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListResourcesForWebACLError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourcesForWebACLError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListResourcesForWebACLError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourcesForWebACLError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(ListResourcesForWebACLError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourcesForWebACLError::WAFInvalidParameter,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(ListResourcesForWebACLError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourcesForWebACLError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5807,9 +6821,14 @@ impl ListRuleGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRuleGroupsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRuleGroupsError::WAFInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5839,12 +6858,22 @@ impl ListRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRulesError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRulesError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRulesError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRulesError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5875,16 +6904,22 @@ impl ListSizeConstraintSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListSizeConstraintSetsError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSizeConstraintSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListSizeConstraintSetsError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSizeConstraintSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5915,16 +6950,22 @@ impl ListSqlInjectionMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListSqlInjectionMatchSetsError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSqlInjectionMatchSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListSqlInjectionMatchSetsError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSqlInjectionMatchSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5955,16 +6996,22 @@ impl ListSubscribedRuleGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListSubscribedRuleGroupsError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSubscribedRuleGroupsError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(ListSubscribedRuleGroupsError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSubscribedRuleGroupsError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6003,32 +7050,54 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::WAFBadRequest,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::WAFInvalidParameter,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::WAFNonexistentItem,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
-                        ListTagsForResourceError::WAFTagOperationInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::WAFTagOperationInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6065,12 +7134,22 @@ impl ListWebACLsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListWebACLsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebACLsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListWebACLsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebACLsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6101,12 +7180,22 @@ impl ListXssMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListXssMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListXssMatchSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListXssMatchSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListXssMatchSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6141,26 +7230,38 @@ impl PutLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(PutLoggingConfigurationError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutLoggingConfigurationError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(PutLoggingConfigurationError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutLoggingConfigurationError::WAFNonexistentItem,
+                    )
                 }
                 "WAFServiceLinkedRoleErrorException" => {
-                    return RusotoError::Service(
-                        PutLoggingConfigurationError::WAFServiceLinkedRoleError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLoggingConfigurationError::WAFServiceLinkedRoleError,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(PutLoggingConfigurationError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutLoggingConfigurationError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6199,24 +7300,38 @@ impl PutPermissionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(PutPermissionPolicyError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutPermissionPolicyError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidPermissionPolicyException" => {
-                    return RusotoError::Service(
-                        PutPermissionPolicyError::WAFInvalidPermissionPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPermissionPolicyError::WAFInvalidPermissionPolicy,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(PutPermissionPolicyError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutPermissionPolicyError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(PutPermissionPolicyError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPermissionPolicyError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6261,29 +7376,62 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(TagResourceError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::WAFBadRequest,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(TagResourceError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(TagResourceError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::WAFNonexistentItem,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(TagResourceError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::WAFTagOperationInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::WAFTagOperationInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6327,26 +7475,54 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::WAFBadRequest,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::WAFInvalidParameter,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UntagResourceError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::WAFNonexistentItem,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(UntagResourceError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::WAFTagOperationInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::WAFTagOperationInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6393,42 +7569,70 @@ impl UpdateByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateByteMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateByteMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateByteMatchSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateByteMatchSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateByteMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateByteMatchSetError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateByteMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateByteMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6479,41 +7683,78 @@ impl UpdateGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGeoMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGeoMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGeoMatchSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGeoMatchSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGeoMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGeoMatchSetError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGeoMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGeoMatchSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGeoMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6565,33 +7806,78 @@ impl UpdateIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFNonexistentContainer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6643,49 +7929,78 @@ impl UpdateRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRateBasedRuleError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6735,44 +8050,70 @@ impl UpdateRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFDisallowedName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRegexMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6821,44 +8162,70 @@ impl UpdateRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexPatternSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexPatternSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexPatternSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidRegexPatternException" => {
-                    return RusotoError::Service(
-                        UpdateRegexPatternSetError::WAFInvalidRegexPattern(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRegexPatternSetError::WAFInvalidRegexPattern,
                     )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexPatternSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(
-                        UpdateRegexPatternSetError::WAFNonexistentContainer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRegexPatternSetError::WAFNonexistentContainer,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexPatternSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRegexPatternSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6911,33 +8278,78 @@ impl UpdateRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFNonexistentContainer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6985,29 +8397,62 @@ impl UpdateRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleGroupError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleGroupError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleGroupError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleGroupError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRuleGroupError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleGroupError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleGroupError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7057,51 +8502,78 @@ impl UpdateSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(
-                        UpdateSizeConstraintSetError::WAFNonexistentContainer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSizeConstraintSetError::WAFNonexistentContainer,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7155,46 +8627,70 @@ impl UpdateSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateSqlInjectionMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSqlInjectionMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
-                        UpdateSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSqlInjectionMatchSetError::WAFInvalidAccount,
                     )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(
-                        UpdateSqlInjectionMatchSetError::WAFInvalidOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSqlInjectionMatchSetError::WAFInvalidOperation,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
-                        UpdateSqlInjectionMatchSetError::WAFInvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSqlInjectionMatchSetError::WAFInvalidParameter,
                     )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(
-                        UpdateSqlInjectionMatchSetError::WAFLimitsExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSqlInjectionMatchSetError::WAFLimitsExceeded,
                     )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(
-                        UpdateSqlInjectionMatchSetError::WAFNonexistentContainer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSqlInjectionMatchSetError::WAFNonexistentContainer,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        UpdateSqlInjectionMatchSetError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSqlInjectionMatchSetError::WAFNonexistentItem,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateSqlInjectionMatchSetError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSqlInjectionMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7255,40 +8751,86 @@ impl UpdateWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateWebACLError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFStaleData,
+                    )
                 }
                 "WAFSubscriptionNotFoundException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFSubscriptionNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateWebACLError::WAFSubscriptionNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7339,38 +8881,70 @@ impl UpdateXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateXssMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateXssMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateXssMatchSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateXssMatchSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateXssMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateXssMatchSetError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateXssMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateXssMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7894,11 +9468,12 @@ pub struct WAFRegionalClient {
 impl WAFRegionalClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> WAFRegionalClient {
         WAFRegionalClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "WAF Regional"),
         }
     }
 
@@ -7913,12 +9488,32 @@ impl WAFRegionalClient {
     {
         WAFRegionalClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "WAF Regional"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> WAFRegionalClient {
-        WAFRegionalClient { client, region }
+        WAFRegionalClient {
+            client,
+            region: region_for_service(region, "WAF Regional"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> WAFRegionalClient {
+        WAFRegionalClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("WAF Regional"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WAFRegionalClient {
+        WAFRegionalClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -7930,6 +9525,7 @@ impl WAFRegional for WAFRegionalClient {
         input: AssociateWebACLRequest,
     ) -> Result<AssociateWebACLResponse, RusotoError<AssociateWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateWebACL");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.AssociateWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7948,6 +9544,7 @@ impl WAFRegional for WAFRegionalClient {
         input: CreateByteMatchSetRequest,
     ) -> Result<CreateByteMatchSetResponse, RusotoError<CreateByteMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateByteMatchSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.CreateByteMatchSet",
@@ -7969,6 +9566,7 @@ impl WAFRegional for WAFRegionalClient {
         input: CreateGeoMatchSetRequest,
     ) -> Result<CreateGeoMatchSetResponse, RusotoError<CreateGeoMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGeoMatchSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.CreateGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7987,6 +9585,7 @@ impl WAFRegional for WAFRegionalClient {
         input: CreateIPSetRequest,
     ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateIPSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.CreateIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8005,6 +9604,7 @@ impl WAFRegional for WAFRegionalClient {
         input: CreateRateBasedRuleRequest,
     ) -> Result<CreateRateBasedRuleResponse, RusotoError<CreateRateBasedRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRateBasedRule");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.CreateRateBasedRule",
@@ -8026,6 +9626,7 @@ impl WAFRegional for WAFRegionalClient {
         input: CreateRegexMatchSetRequest,
     ) -> Result<CreateRegexMatchSetResponse, RusotoError<CreateRegexMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRegexMatchSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.CreateRegexMatchSet",
@@ -8047,6 +9648,7 @@ impl WAFRegional for WAFRegionalClient {
         input: CreateRegexPatternSetRequest,
     ) -> Result<CreateRegexPatternSetResponse, RusotoError<CreateRegexPatternSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRegexPatternSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.CreateRegexPatternSet",
@@ -8069,6 +9671,7 @@ impl WAFRegional for WAFRegionalClient {
         input: CreateRuleRequest,
     ) -> Result<CreateRuleResponse, RusotoError<CreateRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRule");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.CreateRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8087,6 +9690,7 @@ impl WAFRegional for WAFRegionalClient {
         input: CreateRuleGroupRequest,
     ) -> Result<CreateRuleGroupResponse, RusotoError<CreateRuleGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRuleGroup");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.CreateRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8105,6 +9709,7 @@ impl WAFRegional for WAFRegionalClient {
         input: CreateSizeConstraintSetRequest,
     ) -> Result<CreateSizeConstraintSetResponse, RusotoError<CreateSizeConstraintSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSizeConstraintSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.CreateSizeConstraintSet",
@@ -8128,6 +9733,7 @@ impl WAFRegional for WAFRegionalClient {
     ) -> Result<CreateSqlInjectionMatchSetResponse, RusotoError<CreateSqlInjectionMatchSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSqlInjectionMatchSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.CreateSqlInjectionMatchSet",
@@ -8150,6 +9756,7 @@ impl WAFRegional for WAFRegionalClient {
         input: CreateWebACLRequest,
     ) -> Result<CreateWebACLResponse, RusotoError<CreateWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateWebACL");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.CreateWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8169,6 +9776,7 @@ impl WAFRegional for WAFRegionalClient {
     ) -> Result<CreateWebACLMigrationStackResponse, RusotoError<CreateWebACLMigrationStackError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateWebACLMigrationStack");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.CreateWebACLMigrationStack",
@@ -8191,6 +9799,7 @@ impl WAFRegional for WAFRegionalClient {
         input: CreateXssMatchSetRequest,
     ) -> Result<CreateXssMatchSetResponse, RusotoError<CreateXssMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateXssMatchSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.CreateXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8209,6 +9818,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DeleteByteMatchSetRequest,
     ) -> Result<DeleteByteMatchSetResponse, RusotoError<DeleteByteMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteByteMatchSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.DeleteByteMatchSet",
@@ -8230,6 +9840,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DeleteGeoMatchSetRequest,
     ) -> Result<DeleteGeoMatchSetResponse, RusotoError<DeleteGeoMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteGeoMatchSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.DeleteGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8248,6 +9859,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DeleteIPSetRequest,
     ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteIPSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.DeleteIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8267,6 +9879,7 @@ impl WAFRegional for WAFRegionalClient {
     ) -> Result<DeleteLoggingConfigurationResponse, RusotoError<DeleteLoggingConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLoggingConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.DeleteLoggingConfiguration",
@@ -8289,6 +9902,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DeletePermissionPolicyRequest,
     ) -> Result<DeletePermissionPolicyResponse, RusotoError<DeletePermissionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePermissionPolicy");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.DeletePermissionPolicy",
@@ -8311,6 +9925,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DeleteRateBasedRuleRequest,
     ) -> Result<DeleteRateBasedRuleResponse, RusotoError<DeleteRateBasedRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRateBasedRule");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.DeleteRateBasedRule",
@@ -8332,6 +9947,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DeleteRegexMatchSetRequest,
     ) -> Result<DeleteRegexMatchSetResponse, RusotoError<DeleteRegexMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRegexMatchSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.DeleteRegexMatchSet",
@@ -8353,6 +9969,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DeleteRegexPatternSetRequest,
     ) -> Result<DeleteRegexPatternSetResponse, RusotoError<DeleteRegexPatternSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRegexPatternSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.DeleteRegexPatternSet",
@@ -8375,6 +9992,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DeleteRuleRequest,
     ) -> Result<DeleteRuleResponse, RusotoError<DeleteRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRule");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.DeleteRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8393,6 +10011,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DeleteRuleGroupRequest,
     ) -> Result<DeleteRuleGroupResponse, RusotoError<DeleteRuleGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRuleGroup");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.DeleteRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8411,6 +10030,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DeleteSizeConstraintSetRequest,
     ) -> Result<DeleteSizeConstraintSetResponse, RusotoError<DeleteSizeConstraintSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSizeConstraintSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.DeleteSizeConstraintSet",
@@ -8434,6 +10054,7 @@ impl WAFRegional for WAFRegionalClient {
     ) -> Result<DeleteSqlInjectionMatchSetResponse, RusotoError<DeleteSqlInjectionMatchSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSqlInjectionMatchSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.DeleteSqlInjectionMatchSet",
@@ -8456,6 +10077,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DeleteWebACLRequest,
     ) -> Result<DeleteWebACLResponse, RusotoError<DeleteWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteWebACL");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.DeleteWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8474,6 +10096,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DeleteXssMatchSetRequest,
     ) -> Result<DeleteXssMatchSetResponse, RusotoError<DeleteXssMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteXssMatchSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.DeleteXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8492,6 +10115,7 @@ impl WAFRegional for WAFRegionalClient {
         input: DisassociateWebACLRequest,
     ) -> Result<DisassociateWebACLResponse, RusotoError<DisassociateWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateWebACL");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.DisassociateWebACL",
@@ -8513,6 +10137,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetByteMatchSetRequest,
     ) -> Result<GetByteMatchSetResponse, RusotoError<GetByteMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetByteMatchSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetByteMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8530,6 +10155,7 @@ impl WAFRegional for WAFRegionalClient {
         &self,
     ) -> Result<GetChangeTokenResponse, RusotoError<GetChangeTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetChangeToken");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetChangeToken");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -8547,6 +10173,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetChangeTokenStatusRequest,
     ) -> Result<GetChangeTokenStatusResponse, RusotoError<GetChangeTokenStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetChangeTokenStatus");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.GetChangeTokenStatus",
@@ -8569,6 +10196,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetGeoMatchSetRequest,
     ) -> Result<GetGeoMatchSetResponse, RusotoError<GetGeoMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetGeoMatchSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8587,6 +10215,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetIPSetRequest,
     ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetIPSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8605,6 +10234,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetLoggingConfigurationRequest,
     ) -> Result<GetLoggingConfigurationResponse, RusotoError<GetLoggingConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLoggingConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.GetLoggingConfiguration",
@@ -8627,6 +10257,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetPermissionPolicyRequest,
     ) -> Result<GetPermissionPolicyResponse, RusotoError<GetPermissionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPermissionPolicy");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.GetPermissionPolicy",
@@ -8648,6 +10279,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetRateBasedRuleRequest,
     ) -> Result<GetRateBasedRuleResponse, RusotoError<GetRateBasedRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRateBasedRule");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetRateBasedRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8667,6 +10299,7 @@ impl WAFRegional for WAFRegionalClient {
     ) -> Result<GetRateBasedRuleManagedKeysResponse, RusotoError<GetRateBasedRuleManagedKeysError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRateBasedRuleManagedKeys");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.GetRateBasedRuleManagedKeys",
@@ -8689,6 +10322,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetRegexMatchSetRequest,
     ) -> Result<GetRegexMatchSetResponse, RusotoError<GetRegexMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRegexMatchSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetRegexMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8707,6 +10341,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetRegexPatternSetRequest,
     ) -> Result<GetRegexPatternSetResponse, RusotoError<GetRegexPatternSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRegexPatternSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.GetRegexPatternSet",
@@ -8728,6 +10363,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetRuleRequest,
     ) -> Result<GetRuleResponse, RusotoError<GetRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRule");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8746,6 +10382,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetRuleGroupRequest,
     ) -> Result<GetRuleGroupResponse, RusotoError<GetRuleGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRuleGroup");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8764,6 +10401,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetSampledRequestsRequest,
     ) -> Result<GetSampledRequestsResponse, RusotoError<GetSampledRequestsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSampledRequests");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.GetSampledRequests",
@@ -8785,6 +10423,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetSizeConstraintSetRequest,
     ) -> Result<GetSizeConstraintSetResponse, RusotoError<GetSizeConstraintSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSizeConstraintSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.GetSizeConstraintSet",
@@ -8807,6 +10446,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetSqlInjectionMatchSetRequest,
     ) -> Result<GetSqlInjectionMatchSetResponse, RusotoError<GetSqlInjectionMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSqlInjectionMatchSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.GetSqlInjectionMatchSet",
@@ -8829,6 +10469,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetWebACLRequest,
     ) -> Result<GetWebACLResponse, RusotoError<GetWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetWebACL");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8847,6 +10488,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetWebACLForResourceRequest,
     ) -> Result<GetWebACLForResourceResponse, RusotoError<GetWebACLForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetWebACLForResource");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.GetWebACLForResource",
@@ -8869,6 +10511,7 @@ impl WAFRegional for WAFRegionalClient {
         input: GetXssMatchSetRequest,
     ) -> Result<GetXssMatchSetResponse, RusotoError<GetXssMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetXssMatchSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8890,6 +10533,7 @@ impl WAFRegional for WAFRegionalClient {
         RusotoError<ListActivatedRulesInRuleGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListActivatedRulesInRuleGroup");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.ListActivatedRulesInRuleGroup",
@@ -8912,6 +10556,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListByteMatchSetsRequest,
     ) -> Result<ListByteMatchSetsResponse, RusotoError<ListByteMatchSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListByteMatchSets");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListByteMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8930,6 +10575,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListGeoMatchSetsRequest,
     ) -> Result<ListGeoMatchSetsResponse, RusotoError<ListGeoMatchSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGeoMatchSets");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListGeoMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8948,6 +10594,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListIPSetsRequest,
     ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListIPSets");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListIPSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8967,6 +10614,7 @@ impl WAFRegional for WAFRegionalClient {
     ) -> Result<ListLoggingConfigurationsResponse, RusotoError<ListLoggingConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLoggingConfigurations");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.ListLoggingConfigurations",
@@ -8989,6 +10637,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListRateBasedRulesRequest,
     ) -> Result<ListRateBasedRulesResponse, RusotoError<ListRateBasedRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRateBasedRules");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.ListRateBasedRules",
@@ -9010,6 +10659,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListRegexMatchSetsRequest,
     ) -> Result<ListRegexMatchSetsResponse, RusotoError<ListRegexMatchSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRegexMatchSets");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.ListRegexMatchSets",
@@ -9031,6 +10681,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListRegexPatternSetsRequest,
     ) -> Result<ListRegexPatternSetsResponse, RusotoError<ListRegexPatternSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRegexPatternSets");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.ListRegexPatternSets",
@@ -9053,6 +10704,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListResourcesForWebACLRequest,
     ) -> Result<ListResourcesForWebACLResponse, RusotoError<ListResourcesForWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResourcesForWebACL");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.ListResourcesForWebACL",
@@ -9075,6 +10727,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListRuleGroupsRequest,
     ) -> Result<ListRuleGroupsResponse, RusotoError<ListRuleGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRuleGroups");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListRuleGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9093,6 +10746,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListRulesRequest,
     ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRules");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9111,6 +10765,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListSizeConstraintSetsRequest,
     ) -> Result<ListSizeConstraintSetsResponse, RusotoError<ListSizeConstraintSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSizeConstraintSets");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.ListSizeConstraintSets",
@@ -9134,6 +10789,7 @@ impl WAFRegional for WAFRegionalClient {
     ) -> Result<ListSqlInjectionMatchSetsResponse, RusotoError<ListSqlInjectionMatchSetsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSqlInjectionMatchSets");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.ListSqlInjectionMatchSets",
@@ -9156,6 +10812,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListSubscribedRuleGroupsRequest,
     ) -> Result<ListSubscribedRuleGroupsResponse, RusotoError<ListSubscribedRuleGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSubscribedRuleGroups");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.ListSubscribedRuleGroups",
@@ -9178,6 +10835,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.ListTagsForResource",
@@ -9199,6 +10857,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListWebACLsRequest,
     ) -> Result<ListWebACLsResponse, RusotoError<ListWebACLsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListWebACLs");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListWebACLs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9217,6 +10876,7 @@ impl WAFRegional for WAFRegionalClient {
         input: ListXssMatchSetsRequest,
     ) -> Result<ListXssMatchSetsResponse, RusotoError<ListXssMatchSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListXssMatchSets");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListXssMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9235,6 +10895,7 @@ impl WAFRegional for WAFRegionalClient {
         input: PutLoggingConfigurationRequest,
     ) -> Result<PutLoggingConfigurationResponse, RusotoError<PutLoggingConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutLoggingConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.PutLoggingConfiguration",
@@ -9257,6 +10918,7 @@ impl WAFRegional for WAFRegionalClient {
         input: PutPermissionPolicyRequest,
     ) -> Result<PutPermissionPolicyResponse, RusotoError<PutPermissionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutPermissionPolicy");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.PutPermissionPolicy",
@@ -9278,6 +10940,7 @@ impl WAFRegional for WAFRegionalClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9296,6 +10959,7 @@ impl WAFRegional for WAFRegionalClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9314,6 +10978,7 @@ impl WAFRegional for WAFRegionalClient {
         input: UpdateByteMatchSetRequest,
     ) -> Result<UpdateByteMatchSetResponse, RusotoError<UpdateByteMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateByteMatchSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.UpdateByteMatchSet",
@@ -9335,6 +11000,7 @@ impl WAFRegional for WAFRegionalClient {
         input: UpdateGeoMatchSetRequest,
     ) -> Result<UpdateGeoMatchSetResponse, RusotoError<UpdateGeoMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGeoMatchSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UpdateGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9353,6 +11019,7 @@ impl WAFRegional for WAFRegionalClient {
         input: UpdateIPSetRequest,
     ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateIPSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UpdateIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9371,6 +11038,7 @@ impl WAFRegional for WAFRegionalClient {
         input: UpdateRateBasedRuleRequest,
     ) -> Result<UpdateRateBasedRuleResponse, RusotoError<UpdateRateBasedRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRateBasedRule");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.UpdateRateBasedRule",
@@ -9392,6 +11060,7 @@ impl WAFRegional for WAFRegionalClient {
         input: UpdateRegexMatchSetRequest,
     ) -> Result<UpdateRegexMatchSetResponse, RusotoError<UpdateRegexMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRegexMatchSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.UpdateRegexMatchSet",
@@ -9413,6 +11082,7 @@ impl WAFRegional for WAFRegionalClient {
         input: UpdateRegexPatternSetRequest,
     ) -> Result<UpdateRegexPatternSetResponse, RusotoError<UpdateRegexPatternSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRegexPatternSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.UpdateRegexPatternSet",
@@ -9435,6 +11105,7 @@ impl WAFRegional for WAFRegionalClient {
         input: UpdateRuleRequest,
     ) -> Result<UpdateRuleResponse, RusotoError<UpdateRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRule");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UpdateRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9453,6 +11124,7 @@ impl WAFRegional for WAFRegionalClient {
         input: UpdateRuleGroupRequest,
     ) -> Result<UpdateRuleGroupResponse, RusotoError<UpdateRuleGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRuleGroup");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UpdateRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9471,6 +11143,7 @@ impl WAFRegional for WAFRegionalClient {
         input: UpdateSizeConstraintSetRequest,
     ) -> Result<UpdateSizeConstraintSetResponse, RusotoError<UpdateSizeConstraintSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSizeConstraintSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.UpdateSizeConstraintSet",
@@ -9494,6 +11167,7 @@ impl WAFRegional for WAFRegionalClient {
     ) -> Result<UpdateSqlInjectionMatchSetResponse, RusotoError<UpdateSqlInjectionMatchSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSqlInjectionMatchSet");
         request.add_header(
             "x-amz-target",
             "AWSWAF_Regional_20161128.UpdateSqlInjectionMatchSet",
@@ -9516,6 +11190,7 @@ impl WAFRegional for WAFRegionalClient {
         input: UpdateWebACLRequest,
     ) -> Result<UpdateWebACLResponse, RusotoError<UpdateWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateWebACL");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UpdateWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9534,6 +11209,7 @@ impl WAFRegional for WAFRegionalClient {
         input: UpdateXssMatchSetRequest,
     ) -> Result<UpdateXssMatchSetResponse, RusotoError<UpdateXssMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateXssMatchSet");
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UpdateXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9546,3 +11222,790 @@ impl WAFRegional for WAFRegionalClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateXssMatchSetResponse, _>()
     }
 }
+
+/// A blocking client for the WAF Regional API, running the calls of a `WAFRegionalClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `WAFRegionalClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WAFRegionalBlockingClient {
+    client: WAFRegionalClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WAFRegionalBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> WAFRegionalBlockingClient {
+        WAFRegionalBlockingClient::from_client(WAFRegionalClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WAFRegionalBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WAFRegionalBlockingClient::from_client(WAFRegionalClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> WAFRegionalBlockingClient {
+        WAFRegionalBlockingClient::from_client(WAFRegionalClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> WAFRegionalBlockingClient {
+        WAFRegionalBlockingClient::from_client(WAFRegionalClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: WAFRegionalClient) -> WAFRegionalBlockingClient {
+        WAFRegionalBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> WAFRegionalBlockingClient {
+        WAFRegionalBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WAFRegionalBlockingClient {
+        WAFRegionalBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`WAFRegional::associate_web_acl`].
+    pub fn associate_web_acl(
+        &self,
+        input: AssociateWebACLRequest,
+    ) -> Result<AssociateWebACLResponse, RusotoError<AssociateWebACLError>> {
+        self.runtime.block_on(self.client.associate_web_acl(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_byte_match_set`].
+    pub fn create_byte_match_set(
+        &self,
+        input: CreateByteMatchSetRequest,
+    ) -> Result<CreateByteMatchSetResponse, RusotoError<CreateByteMatchSetError>> {
+        self.runtime
+            .block_on(self.client.create_byte_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_geo_match_set`].
+    pub fn create_geo_match_set(
+        &self,
+        input: CreateGeoMatchSetRequest,
+    ) -> Result<CreateGeoMatchSetResponse, RusotoError<CreateGeoMatchSetError>> {
+        self.runtime
+            .block_on(self.client.create_geo_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_ip_set`].
+    pub fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
+        self.runtime.block_on(self.client.create_ip_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_rate_based_rule`].
+    pub fn create_rate_based_rule(
+        &self,
+        input: CreateRateBasedRuleRequest,
+    ) -> Result<CreateRateBasedRuleResponse, RusotoError<CreateRateBasedRuleError>> {
+        self.runtime
+            .block_on(self.client.create_rate_based_rule(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_regex_match_set`].
+    pub fn create_regex_match_set(
+        &self,
+        input: CreateRegexMatchSetRequest,
+    ) -> Result<CreateRegexMatchSetResponse, RusotoError<CreateRegexMatchSetError>> {
+        self.runtime
+            .block_on(self.client.create_regex_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_regex_pattern_set`].
+    pub fn create_regex_pattern_set(
+        &self,
+        input: CreateRegexPatternSetRequest,
+    ) -> Result<CreateRegexPatternSetResponse, RusotoError<CreateRegexPatternSetError>> {
+        self.runtime
+            .block_on(self.client.create_regex_pattern_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_rule`].
+    pub fn create_rule(
+        &self,
+        input: CreateRuleRequest,
+    ) -> Result<CreateRuleResponse, RusotoError<CreateRuleError>> {
+        self.runtime.block_on(self.client.create_rule(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_rule_group`].
+    pub fn create_rule_group(
+        &self,
+        input: CreateRuleGroupRequest,
+    ) -> Result<CreateRuleGroupResponse, RusotoError<CreateRuleGroupError>> {
+        self.runtime.block_on(self.client.create_rule_group(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_size_constraint_set`].
+    pub fn create_size_constraint_set(
+        &self,
+        input: CreateSizeConstraintSetRequest,
+    ) -> Result<CreateSizeConstraintSetResponse, RusotoError<CreateSizeConstraintSetError>> {
+        self.runtime
+            .block_on(self.client.create_size_constraint_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_sql_injection_match_set`].
+    pub fn create_sql_injection_match_set(
+        &self,
+        input: CreateSqlInjectionMatchSetRequest,
+    ) -> Result<CreateSqlInjectionMatchSetResponse, RusotoError<CreateSqlInjectionMatchSetError>>
+    {
+        self.runtime
+            .block_on(self.client.create_sql_injection_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_web_acl`].
+    pub fn create_web_acl(
+        &self,
+        input: CreateWebACLRequest,
+    ) -> Result<CreateWebACLResponse, RusotoError<CreateWebACLError>> {
+        self.runtime.block_on(self.client.create_web_acl(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_web_acl_migration_stack`].
+    pub fn create_web_acl_migration_stack(
+        &self,
+        input: CreateWebACLMigrationStackRequest,
+    ) -> Result<CreateWebACLMigrationStackResponse, RusotoError<CreateWebACLMigrationStackError>>
+    {
+        self.runtime
+            .block_on(self.client.create_web_acl_migration_stack(input))
+    }
+
+    /// Blocking version of [`WAFRegional::create_xss_match_set`].
+    pub fn create_xss_match_set(
+        &self,
+        input: CreateXssMatchSetRequest,
+    ) -> Result<CreateXssMatchSetResponse, RusotoError<CreateXssMatchSetError>> {
+        self.runtime
+            .block_on(self.client.create_xss_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_byte_match_set`].
+    pub fn delete_byte_match_set(
+        &self,
+        input: DeleteByteMatchSetRequest,
+    ) -> Result<DeleteByteMatchSetResponse, RusotoError<DeleteByteMatchSetError>> {
+        self.runtime
+            .block_on(self.client.delete_byte_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_geo_match_set`].
+    pub fn delete_geo_match_set(
+        &self,
+        input: DeleteGeoMatchSetRequest,
+    ) -> Result<DeleteGeoMatchSetResponse, RusotoError<DeleteGeoMatchSetError>> {
+        self.runtime
+            .block_on(self.client.delete_geo_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_ip_set`].
+    pub fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
+        self.runtime.block_on(self.client.delete_ip_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_logging_configuration`].
+    pub fn delete_logging_configuration(
+        &self,
+        input: DeleteLoggingConfigurationRequest,
+    ) -> Result<DeleteLoggingConfigurationResponse, RusotoError<DeleteLoggingConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_logging_configuration(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_permission_policy`].
+    pub fn delete_permission_policy(
+        &self,
+        input: DeletePermissionPolicyRequest,
+    ) -> Result<DeletePermissionPolicyResponse, RusotoError<DeletePermissionPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_permission_policy(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_rate_based_rule`].
+    pub fn delete_rate_based_rule(
+        &self,
+        input: DeleteRateBasedRuleRequest,
+    ) -> Result<DeleteRateBasedRuleResponse, RusotoError<DeleteRateBasedRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_rate_based_rule(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_regex_match_set`].
+    pub fn delete_regex_match_set(
+        &self,
+        input: DeleteRegexMatchSetRequest,
+    ) -> Result<DeleteRegexMatchSetResponse, RusotoError<DeleteRegexMatchSetError>> {
+        self.runtime
+            .block_on(self.client.delete_regex_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_regex_pattern_set`].
+    pub fn delete_regex_pattern_set(
+        &self,
+        input: DeleteRegexPatternSetRequest,
+    ) -> Result<DeleteRegexPatternSetResponse, RusotoError<DeleteRegexPatternSetError>> {
+        self.runtime
+            .block_on(self.client.delete_regex_pattern_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_rule`].
+    pub fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> Result<DeleteRuleResponse, RusotoError<DeleteRuleError>> {
+        self.runtime.block_on(self.client.delete_rule(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_rule_group`].
+    pub fn delete_rule_group(
+        &self,
+        input: DeleteRuleGroupRequest,
+    ) -> Result<DeleteRuleGroupResponse, RusotoError<DeleteRuleGroupError>> {
+        self.runtime.block_on(self.client.delete_rule_group(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_size_constraint_set`].
+    pub fn delete_size_constraint_set(
+        &self,
+        input: DeleteSizeConstraintSetRequest,
+    ) -> Result<DeleteSizeConstraintSetResponse, RusotoError<DeleteSizeConstraintSetError>> {
+        self.runtime
+            .block_on(self.client.delete_size_constraint_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_sql_injection_match_set`].
+    pub fn delete_sql_injection_match_set(
+        &self,
+        input: DeleteSqlInjectionMatchSetRequest,
+    ) -> Result<DeleteSqlInjectionMatchSetResponse, RusotoError<DeleteSqlInjectionMatchSetError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_sql_injection_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_web_acl`].
+    pub fn delete_web_acl(
+        &self,
+        input: DeleteWebACLRequest,
+    ) -> Result<DeleteWebACLResponse, RusotoError<DeleteWebACLError>> {
+        self.runtime.block_on(self.client.delete_web_acl(input))
+    }
+
+    /// Blocking version of [`WAFRegional::delete_xss_match_set`].
+    pub fn delete_xss_match_set(
+        &self,
+        input: DeleteXssMatchSetRequest,
+    ) -> Result<DeleteXssMatchSetResponse, RusotoError<DeleteXssMatchSetError>> {
+        self.runtime
+            .block_on(self.client.delete_xss_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::disassociate_web_acl`].
+    pub fn disassociate_web_acl(
+        &self,
+        input: DisassociateWebACLRequest,
+    ) -> Result<DisassociateWebACLResponse, RusotoError<DisassociateWebACLError>> {
+        self.runtime
+            .block_on(self.client.disassociate_web_acl(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_byte_match_set`].
+    pub fn get_byte_match_set(
+        &self,
+        input: GetByteMatchSetRequest,
+    ) -> Result<GetByteMatchSetResponse, RusotoError<GetByteMatchSetError>> {
+        self.runtime.block_on(self.client.get_byte_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_change_token`].
+    pub fn get_change_token(
+        &self,
+    ) -> Result<GetChangeTokenResponse, RusotoError<GetChangeTokenError>> {
+        self.runtime.block_on(self.client.get_change_token())
+    }
+
+    /// Blocking version of [`WAFRegional::get_change_token_status`].
+    pub fn get_change_token_status(
+        &self,
+        input: GetChangeTokenStatusRequest,
+    ) -> Result<GetChangeTokenStatusResponse, RusotoError<GetChangeTokenStatusError>> {
+        self.runtime
+            .block_on(self.client.get_change_token_status(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_geo_match_set`].
+    pub fn get_geo_match_set(
+        &self,
+        input: GetGeoMatchSetRequest,
+    ) -> Result<GetGeoMatchSetResponse, RusotoError<GetGeoMatchSetError>> {
+        self.runtime.block_on(self.client.get_geo_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_ip_set`].
+    pub fn get_ip_set(
+        &self,
+        input: GetIPSetRequest,
+    ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
+        self.runtime.block_on(self.client.get_ip_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_logging_configuration`].
+    pub fn get_logging_configuration(
+        &self,
+        input: GetLoggingConfigurationRequest,
+    ) -> Result<GetLoggingConfigurationResponse, RusotoError<GetLoggingConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_logging_configuration(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_permission_policy`].
+    pub fn get_permission_policy(
+        &self,
+        input: GetPermissionPolicyRequest,
+    ) -> Result<GetPermissionPolicyResponse, RusotoError<GetPermissionPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_permission_policy(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_rate_based_rule`].
+    pub fn get_rate_based_rule(
+        &self,
+        input: GetRateBasedRuleRequest,
+    ) -> Result<GetRateBasedRuleResponse, RusotoError<GetRateBasedRuleError>> {
+        self.runtime
+            .block_on(self.client.get_rate_based_rule(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_rate_based_rule_managed_keys`].
+    pub fn get_rate_based_rule_managed_keys(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> Result<GetRateBasedRuleManagedKeysResponse, RusotoError<GetRateBasedRuleManagedKeysError>>
+    {
+        self.runtime
+            .block_on(self.client.get_rate_based_rule_managed_keys(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_regex_match_set`].
+    pub fn get_regex_match_set(
+        &self,
+        input: GetRegexMatchSetRequest,
+    ) -> Result<GetRegexMatchSetResponse, RusotoError<GetRegexMatchSetError>> {
+        self.runtime
+            .block_on(self.client.get_regex_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_regex_pattern_set`].
+    pub fn get_regex_pattern_set(
+        &self,
+        input: GetRegexPatternSetRequest,
+    ) -> Result<GetRegexPatternSetResponse, RusotoError<GetRegexPatternSetError>> {
+        self.runtime
+            .block_on(self.client.get_regex_pattern_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_rule`].
+    pub fn get_rule(
+        &self,
+        input: GetRuleRequest,
+    ) -> Result<GetRuleResponse, RusotoError<GetRuleError>> {
+        self.runtime.block_on(self.client.get_rule(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_rule_group`].
+    pub fn get_rule_group(
+        &self,
+        input: GetRuleGroupRequest,
+    ) -> Result<GetRuleGroupResponse, RusotoError<GetRuleGroupError>> {
+        self.runtime.block_on(self.client.get_rule_group(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_sampled_requests`].
+    pub fn get_sampled_requests(
+        &self,
+        input: GetSampledRequestsRequest,
+    ) -> Result<GetSampledRequestsResponse, RusotoError<GetSampledRequestsError>> {
+        self.runtime
+            .block_on(self.client.get_sampled_requests(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_size_constraint_set`].
+    pub fn get_size_constraint_set(
+        &self,
+        input: GetSizeConstraintSetRequest,
+    ) -> Result<GetSizeConstraintSetResponse, RusotoError<GetSizeConstraintSetError>> {
+        self.runtime
+            .block_on(self.client.get_size_constraint_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_sql_injection_match_set`].
+    pub fn get_sql_injection_match_set(
+        &self,
+        input: GetSqlInjectionMatchSetRequest,
+    ) -> Result<GetSqlInjectionMatchSetResponse, RusotoError<GetSqlInjectionMatchSetError>> {
+        self.runtime
+            .block_on(self.client.get_sql_injection_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_web_acl`].
+    pub fn get_web_acl(
+        &self,
+        input: GetWebACLRequest,
+    ) -> Result<GetWebACLResponse, RusotoError<GetWebACLError>> {
+        self.runtime.block_on(self.client.get_web_acl(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_web_acl_for_resource`].
+    pub fn get_web_acl_for_resource(
+        &self,
+        input: GetWebACLForResourceRequest,
+    ) -> Result<GetWebACLForResourceResponse, RusotoError<GetWebACLForResourceError>> {
+        self.runtime
+            .block_on(self.client.get_web_acl_for_resource(input))
+    }
+
+    /// Blocking version of [`WAFRegional::get_xss_match_set`].
+    pub fn get_xss_match_set(
+        &self,
+        input: GetXssMatchSetRequest,
+    ) -> Result<GetXssMatchSetResponse, RusotoError<GetXssMatchSetError>> {
+        self.runtime.block_on(self.client.get_xss_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_activated_rules_in_rule_group`].
+    pub fn list_activated_rules_in_rule_group(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> Result<
+        ListActivatedRulesInRuleGroupResponse,
+        RusotoError<ListActivatedRulesInRuleGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_activated_rules_in_rule_group(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_byte_match_sets`].
+    pub fn list_byte_match_sets(
+        &self,
+        input: ListByteMatchSetsRequest,
+    ) -> Result<ListByteMatchSetsResponse, RusotoError<ListByteMatchSetsError>> {
+        self.runtime
+            .block_on(self.client.list_byte_match_sets(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_geo_match_sets`].
+    pub fn list_geo_match_sets(
+        &self,
+        input: ListGeoMatchSetsRequest,
+    ) -> Result<ListGeoMatchSetsResponse, RusotoError<ListGeoMatchSetsError>> {
+        self.runtime
+            .block_on(self.client.list_geo_match_sets(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_ip_sets`].
+    pub fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
+        self.runtime.block_on(self.client.list_ip_sets(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_logging_configurations`].
+    pub fn list_logging_configurations(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> Result<ListLoggingConfigurationsResponse, RusotoError<ListLoggingConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_logging_configurations(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_rate_based_rules`].
+    pub fn list_rate_based_rules(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> Result<ListRateBasedRulesResponse, RusotoError<ListRateBasedRulesError>> {
+        self.runtime
+            .block_on(self.client.list_rate_based_rules(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_regex_match_sets`].
+    pub fn list_regex_match_sets(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> Result<ListRegexMatchSetsResponse, RusotoError<ListRegexMatchSetsError>> {
+        self.runtime
+            .block_on(self.client.list_regex_match_sets(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_regex_pattern_sets`].
+    pub fn list_regex_pattern_sets(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> Result<ListRegexPatternSetsResponse, RusotoError<ListRegexPatternSetsError>> {
+        self.runtime
+            .block_on(self.client.list_regex_pattern_sets(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_resources_for_web_acl`].
+    pub fn list_resources_for_web_acl(
+        &self,
+        input: ListResourcesForWebACLRequest,
+    ) -> Result<ListResourcesForWebACLResponse, RusotoError<ListResourcesForWebACLError>> {
+        self.runtime
+            .block_on(self.client.list_resources_for_web_acl(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_rule_groups`].
+    pub fn list_rule_groups(
+        &self,
+        input: ListRuleGroupsRequest,
+    ) -> Result<ListRuleGroupsResponse, RusotoError<ListRuleGroupsError>> {
+        self.runtime.block_on(self.client.list_rule_groups(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_rules`].
+    pub fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
+        self.runtime.block_on(self.client.list_rules(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_size_constraint_sets`].
+    pub fn list_size_constraint_sets(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> Result<ListSizeConstraintSetsResponse, RusotoError<ListSizeConstraintSetsError>> {
+        self.runtime
+            .block_on(self.client.list_size_constraint_sets(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_sql_injection_match_sets`].
+    pub fn list_sql_injection_match_sets(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> Result<ListSqlInjectionMatchSetsResponse, RusotoError<ListSqlInjectionMatchSetsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_sql_injection_match_sets(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_subscribed_rule_groups`].
+    pub fn list_subscribed_rule_groups(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> Result<ListSubscribedRuleGroupsResponse, RusotoError<ListSubscribedRuleGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_subscribed_rule_groups(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_web_ac_ls`].
+    pub fn list_web_ac_ls(
+        &self,
+        input: ListWebACLsRequest,
+    ) -> Result<ListWebACLsResponse, RusotoError<ListWebACLsError>> {
+        self.runtime.block_on(self.client.list_web_ac_ls(input))
+    }
+
+    /// Blocking version of [`WAFRegional::list_xss_match_sets`].
+    pub fn list_xss_match_sets(
+        &self,
+        input: ListXssMatchSetsRequest,
+    ) -> Result<ListXssMatchSetsResponse, RusotoError<ListXssMatchSetsError>> {
+        self.runtime
+            .block_on(self.client.list_xss_match_sets(input))
+    }
+
+    /// Blocking version of [`WAFRegional::put_logging_configuration`].
+    pub fn put_logging_configuration(
+        &self,
+        input: PutLoggingConfigurationRequest,
+    ) -> Result<PutLoggingConfigurationResponse, RusotoError<PutLoggingConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_logging_configuration(input))
+    }
+
+    /// Blocking version of [`WAFRegional::put_permission_policy`].
+    pub fn put_permission_policy(
+        &self,
+        input: PutPermissionPolicyRequest,
+    ) -> Result<PutPermissionPolicyResponse, RusotoError<PutPermissionPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_permission_policy(input))
+    }
+
+    /// Blocking version of [`WAFRegional::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`WAFRegional::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`WAFRegional::update_byte_match_set`].
+    pub fn update_byte_match_set(
+        &self,
+        input: UpdateByteMatchSetRequest,
+    ) -> Result<UpdateByteMatchSetResponse, RusotoError<UpdateByteMatchSetError>> {
+        self.runtime
+            .block_on(self.client.update_byte_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::update_geo_match_set`].
+    pub fn update_geo_match_set(
+        &self,
+        input: UpdateGeoMatchSetRequest,
+    ) -> Result<UpdateGeoMatchSetResponse, RusotoError<UpdateGeoMatchSetError>> {
+        self.runtime
+            .block_on(self.client.update_geo_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::update_ip_set`].
+    pub fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
+        self.runtime.block_on(self.client.update_ip_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::update_rate_based_rule`].
+    pub fn update_rate_based_rule(
+        &self,
+        input: UpdateRateBasedRuleRequest,
+    ) -> Result<UpdateRateBasedRuleResponse, RusotoError<UpdateRateBasedRuleError>> {
+        self.runtime
+            .block_on(self.client.update_rate_based_rule(input))
+    }
+
+    /// Blocking version of [`WAFRegional::update_regex_match_set`].
+    pub fn update_regex_match_set(
+        &self,
+        input: UpdateRegexMatchSetRequest,
+    ) -> Result<UpdateRegexMatchSetResponse, RusotoError<UpdateRegexMatchSetError>> {
+        self.runtime
+            .block_on(self.client.update_regex_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::update_regex_pattern_set`].
+    pub fn update_regex_pattern_set(
+        &self,
+        input: UpdateRegexPatternSetRequest,
+    ) -> Result<UpdateRegexPatternSetResponse, RusotoError<UpdateRegexPatternSetError>> {
+        self.runtime
+            .block_on(self.client.update_regex_pattern_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::update_rule`].
+    pub fn update_rule(
+        &self,
+        input: UpdateRuleRequest,
+    ) -> Result<UpdateRuleResponse, RusotoError<UpdateRuleError>> {
+        self.runtime.block_on(self.client.update_rule(input))
+    }
+
+    /// Blocking version of [`WAFRegional::update_rule_group`].
+    pub fn update_rule_group(
+        &self,
+        input: UpdateRuleGroupRequest,
+    ) -> Result<UpdateRuleGroupResponse, RusotoError<UpdateRuleGroupError>> {
+        self.runtime.block_on(self.client.update_rule_group(input))
+    }
+
+    /// Blocking version of [`WAFRegional::update_size_constraint_set`].
+    pub fn update_size_constraint_set(
+        &self,
+        input: UpdateSizeConstraintSetRequest,
+    ) -> Result<UpdateSizeConstraintSetResponse, RusotoError<UpdateSizeConstraintSetError>> {
+        self.runtime
+            .block_on(self.client.update_size_constraint_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::update_sql_injection_match_set`].
+    pub fn update_sql_injection_match_set(
+        &self,
+        input: UpdateSqlInjectionMatchSetRequest,
+    ) -> Result<UpdateSqlInjectionMatchSetResponse, RusotoError<UpdateSqlInjectionMatchSetError>>
+    {
+        self.runtime
+            .block_on(self.client.update_sql_injection_match_set(input))
+    }
+
+    /// Blocking version of [`WAFRegional::update_web_acl`].
+    pub fn update_web_acl(
+        &self,
+        input: UpdateWebACLRequest,
+    ) -> Result<UpdateWebACLResponse, RusotoError<UpdateWebACLError>> {
+        self.runtime.block_on(self.client.update_web_acl(input))
+    }
+
+    /// Blocking version of [`WAFRegional::update_xss_match_set`].
+    pub fn update_xss_match_set(
+        &self,
+        input: UpdateXssMatchSetRequest,
+    ) -> Result<UpdateXssMatchSetResponse, RusotoError<UpdateXssMatchSetError>> {
+        self.runtime
+            .block_on(self.client.update_xss_match_set(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2591,32 +2592,54 @@ impl CreateByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFDisallowedName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateByteMatchSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateByteMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateByteMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateByteMatchSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateByteMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateByteMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2659,26 +2682,54 @@ impl CreateGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFDisallowedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGeoMatchSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGeoMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGeoMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGeoMatchSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGeoMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGeoMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2721,24 +2772,54 @@ impl CreateIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFDisallowedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2785,40 +2866,70 @@ impl CreateRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRateBasedRuleError::WAFBadRequest,
+                    )
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFDisallowedName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRateBasedRuleError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRateBasedRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRateBasedRuleError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRateBasedRuleError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRateBasedRuleError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRateBasedRuleError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
-                        CreateRateBasedRuleError::WAFTagOperationInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRateBasedRuleError::WAFTagOperationInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2861,24 +2972,38 @@ impl CreateRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFDisallowedName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegexMatchSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegexMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegexMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRegexMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2915,24 +3040,38 @@ impl CreateRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFDisallowedName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegexPatternSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegexPatternSetError::WAFInternalError,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegexPatternSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRegexPatternSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2977,32 +3116,70 @@ impl CreateRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateRuleError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFBadRequest,
+                    )
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRuleError::WAFDisallowedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateRuleError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRuleError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(CreateRuleError::WAFTagOperationInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRuleError::WAFTagOperationInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3049,29 +3226,62 @@ impl CreateRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFBadRequest,
+                    )
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFDisallowedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFInternalError,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
-                        CreateRuleGroupError::WAFTagOperationInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleGroupError::WAFTagOperationInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3115,36 +3325,54 @@ impl CreateSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFDisallowedName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSizeConstraintSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSizeConstraintSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSizeConstraintSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSizeConstraintSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSizeConstraintSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSizeConstraintSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3189,36 +3417,54 @@ impl CreateSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(
-                        CreateSqlInjectionMatchSetError::WAFDisallowedName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSqlInjectionMatchSetError::WAFDisallowedName,
                     )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateSqlInjectionMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSqlInjectionMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
-                        CreateSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSqlInjectionMatchSetError::WAFInvalidAccount,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
-                        CreateSqlInjectionMatchSetError::WAFInvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSqlInjectionMatchSetError::WAFInvalidParameter,
                     )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(
-                        CreateSqlInjectionMatchSetError::WAFLimitsExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSqlInjectionMatchSetError::WAFLimitsExceeded,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateSqlInjectionMatchSetError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSqlInjectionMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3269,35 +3515,78 @@ impl CreateWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFBadRequest,
+                    )
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFDisallowedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFTagOperationInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWebACLError::WAFTagOperationInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3343,31 +3632,46 @@ impl CreateWebACLMigrationStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFEntityMigrationException" => {
-                    return RusotoError::Service(
-                        CreateWebACLMigrationStackError::WAFEntityMigration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLMigrationStackError::WAFEntityMigration,
                     )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateWebACLMigrationStackError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWebACLMigrationStackError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(
-                        CreateWebACLMigrationStackError::WAFInvalidOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLMigrationStackError::WAFInvalidOperation,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
-                        CreateWebACLMigrationStackError::WAFInvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLMigrationStackError::WAFInvalidParameter,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        CreateWebACLMigrationStackError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebACLMigrationStackError::WAFNonexistentItem,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3417,26 +3721,54 @@ impl CreateXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFDisallowedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateXssMatchSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateXssMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateXssMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateXssMatchSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateXssMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateXssMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3479,32 +3811,54 @@ impl DeleteByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteByteMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteByteMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFNonEmptyEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteByteMatchSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteByteMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteByteMatchSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteByteMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3547,26 +3901,54 @@ impl DeleteGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGeoMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGeoMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGeoMatchSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGeoMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGeoMatchSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGeoMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3609,24 +3991,54 @@ impl DeleteIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3665,21 +4077,30 @@ impl DeleteLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteLoggingConfigurationError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLoggingConfigurationError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        DeleteLoggingConfigurationError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoggingConfigurationError::WAFNonexistentItem,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteLoggingConfigurationError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLoggingConfigurationError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3715,19 +4136,30 @@ impl DeletePermissionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeletePermissionPolicyError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePermissionPolicyError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeletePermissionPolicyError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePermissionPolicyError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeletePermissionPolicyError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePermissionPolicyError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3771,42 +4203,70 @@ impl DeleteRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRateBasedRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRateBasedRuleError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFNonEmptyEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRateBasedRuleError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRateBasedRuleError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRateBasedRuleError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRateBasedRuleError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRateBasedRuleError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
-                        DeleteRateBasedRuleError::WAFTagOperationInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRateBasedRuleError::WAFTagOperationInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3853,34 +4313,54 @@ impl DeleteRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFNonEmptyEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexMatchSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexMatchSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRegexMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3923,34 +4403,54 @@ impl DeleteRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexPatternSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexPatternSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFNonEmptyEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexPatternSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexPatternSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegexPatternSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRegexPatternSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3997,32 +4497,70 @@ impl DeleteRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFTagOperationInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRuleError::WAFTagOperationInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4071,32 +4609,70 @@ impl DeleteRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFInvalidOperation,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
-                        DeleteRuleGroupError::WAFTagOperationInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleGroupError::WAFTagOperationInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4141,36 +4717,54 @@ impl DeleteSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSizeConstraintSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSizeConstraintSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFNonEmptyEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSizeConstraintSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSizeConstraintSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSizeConstraintSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSizeConstraintSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4215,36 +4809,54 @@ impl DeleteSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteSqlInjectionMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSqlInjectionMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
-                        DeleteSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSqlInjectionMatchSetError::WAFInvalidAccount,
                     )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(
-                        DeleteSqlInjectionMatchSetError::WAFNonEmptyEntity(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSqlInjectionMatchSetError::WAFNonEmptyEntity,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        DeleteSqlInjectionMatchSetError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSqlInjectionMatchSetError::WAFNonexistentItem,
                     )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(
-                        DeleteSqlInjectionMatchSetError::WAFReferencedItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSqlInjectionMatchSetError::WAFReferencedItem,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteSqlInjectionMatchSetError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSqlInjectionMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4293,32 +4905,70 @@ impl DeleteWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFStaleData,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebACLError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFTagOperationInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteWebACLError::WAFTagOperationInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4363,26 +5013,54 @@ impl DeleteXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteXssMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteXssMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteXssMatchSetError::WAFNonEmptyEntity,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteXssMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteXssMatchSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteXssMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4419,15 +5097,30 @@ impl GetByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetByteMatchSetError::WAFInternalError(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetByteMatchSetError::WAFInternalError,
+                    )
+                }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetByteMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetByteMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetByteMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetByteMatchSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4457,9 +5150,14 @@ impl GetChangeTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetChangeTokenError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetChangeTokenError::WAFInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4489,16 +5187,22 @@ impl GetChangeTokenStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetChangeTokenStatusError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetChangeTokenStatusError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetChangeTokenStatusError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetChangeTokenStatusError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4531,15 +5235,30 @@ impl GetGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGeoMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGeoMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetGeoMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGeoMatchSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4573,15 +5292,30 @@ impl GetIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIPSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIPSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetIPSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIPSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4613,16 +5347,22 @@ impl GetLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetLoggingConfigurationError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoggingConfigurationError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetLoggingConfigurationError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoggingConfigurationError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4653,16 +5393,22 @@ impl GetPermissionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetPermissionPolicyError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPermissionPolicyError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetPermissionPolicyError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPermissionPolicyError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4695,15 +5441,30 @@ impl GetRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRateBasedRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRateBasedRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRateBasedRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4741,26 +5502,38 @@ impl GetRateBasedRuleManagedKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(
-                        GetRateBasedRuleManagedKeysError::WAFInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleManagedKeysError::WAFInternalError,
                     )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
-                        GetRateBasedRuleManagedKeysError::WAFInvalidAccount(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleManagedKeysError::WAFInvalidAccount,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
-                        GetRateBasedRuleManagedKeysError::WAFInvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleManagedKeysError::WAFInvalidParameter,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        GetRateBasedRuleManagedKeysError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRateBasedRuleManagedKeysError::WAFNonexistentItem,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4801,15 +5574,30 @@ impl GetRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRegexMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegexMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRegexMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegexMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRegexMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegexMatchSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4843,19 +5631,30 @@ impl GetRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRegexPatternSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegexPatternSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRegexPatternSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRegexPatternSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRegexPatternSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRegexPatternSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4889,15 +5688,30 @@ impl GetRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRuleError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRuleError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4929,12 +5743,22 @@ impl GetRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRuleGroupError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRuleGroupError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRuleGroupError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4965,14 +5789,22 @@ impl GetSampledRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetSampledRequestsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSampledRequestsError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetSampledRequestsError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSampledRequestsError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5005,21 +5837,30 @@ impl GetSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetSizeConstraintSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSizeConstraintSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetSizeConstraintSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSizeConstraintSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetSizeConstraintSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSizeConstraintSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5053,21 +5894,30 @@ impl GetSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetSqlInjectionMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSqlInjectionMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetSqlInjectionMatchSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSqlInjectionMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetSqlInjectionMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSqlInjectionMatchSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5101,15 +5951,30 @@ impl GetWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWebACLError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWebACLError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWebACLError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5143,15 +6008,30 @@ impl GetXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetXssMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetXssMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetXssMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetXssMatchSetError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5187,21 +6067,30 @@ impl ListActivatedRulesInRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(
-                        ListActivatedRulesInRuleGroupError::WAFInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActivatedRulesInRuleGroupError::WAFInternalError,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
-                        ListActivatedRulesInRuleGroupError::WAFInvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActivatedRulesInRuleGroupError::WAFInvalidParameter,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        ListActivatedRulesInRuleGroupError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActivatedRulesInRuleGroupError::WAFNonexistentItem,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5239,12 +6128,22 @@ impl ListByteMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListByteMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListByteMatchSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListByteMatchSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListByteMatchSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5275,12 +6174,22 @@ impl ListGeoMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListGeoMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGeoMatchSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListGeoMatchSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGeoMatchSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5311,12 +6220,22 @@ impl ListIPSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListIPSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIPSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListIPSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIPSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5349,21 +6268,30 @@ impl ListLoggingConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListLoggingConfigurationsError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLoggingConfigurationsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
-                        ListLoggingConfigurationsError::WAFInvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLoggingConfigurationsError::WAFInvalidParameter,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        ListLoggingConfigurationsError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLoggingConfigurationsError::WAFNonexistentItem,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5397,14 +6325,22 @@ impl ListRateBasedRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRateBasedRulesError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRateBasedRulesError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRateBasedRulesError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRateBasedRulesError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5435,14 +6371,22 @@ impl ListRegexMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRegexMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRegexMatchSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRegexMatchSetsError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRegexMatchSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5473,16 +6417,22 @@ impl ListRegexPatternSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRegexPatternSetsError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRegexPatternSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRegexPatternSetsError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRegexPatternSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5511,9 +6461,14 @@ impl ListRuleGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRuleGroupsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRuleGroupsError::WAFInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5543,12 +6498,22 @@ impl ListRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRulesError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRulesError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRulesError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRulesError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5579,16 +6544,22 @@ impl ListSizeConstraintSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListSizeConstraintSetsError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSizeConstraintSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListSizeConstraintSetsError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSizeConstraintSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5619,16 +6590,22 @@ impl ListSqlInjectionMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListSqlInjectionMatchSetsError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSqlInjectionMatchSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListSqlInjectionMatchSetsError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSqlInjectionMatchSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5659,16 +6636,22 @@ impl ListSubscribedRuleGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListSubscribedRuleGroupsError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSubscribedRuleGroupsError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(ListSubscribedRuleGroupsError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSubscribedRuleGroupsError::WAFNonexistentItem,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5707,32 +6690,54 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::WAFBadRequest,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::WAFInvalidParameter,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::WAFNonexistentItem,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
-                        ListTagsForResourceError::WAFTagOperationInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::WAFTagOperationInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5769,12 +6774,22 @@ impl ListWebACLsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListWebACLsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebACLsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListWebACLsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebACLsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5805,12 +6820,22 @@ impl ListXssMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListXssMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListXssMatchSetsError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListXssMatchSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListXssMatchSetsError::WAFInvalidAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5845,26 +6870,38 @@ impl PutLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(PutLoggingConfigurationError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutLoggingConfigurationError::WAFInternalError,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(PutLoggingConfigurationError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutLoggingConfigurationError::WAFNonexistentItem,
+                    )
                 }
                 "WAFServiceLinkedRoleErrorException" => {
-                    return RusotoError::Service(
-                        PutLoggingConfigurationError::WAFServiceLinkedRoleError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLoggingConfigurationError::WAFServiceLinkedRoleError,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(PutLoggingConfigurationError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutLoggingConfigurationError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5903,24 +6940,38 @@ impl PutPermissionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(PutPermissionPolicyError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutPermissionPolicyError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidPermissionPolicyException" => {
-                    return RusotoError::Service(
-                        PutPermissionPolicyError::WAFInvalidPermissionPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPermissionPolicyError::WAFInvalidPermissionPolicy,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(PutPermissionPolicyError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutPermissionPolicyError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(PutPermissionPolicyError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPermissionPolicyError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5965,29 +7016,62 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(TagResourceError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::WAFBadRequest,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(TagResourceError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(TagResourceError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::WAFNonexistentItem,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(TagResourceError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::WAFTagOperationInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::WAFTagOperationInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6031,26 +7115,54 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::WAFBadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::WAFBadRequest,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::WAFInvalidParameter,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UntagResourceError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::WAFNonexistentItem,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(UntagResourceError::WAFTagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::WAFTagOperation,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::WAFTagOperationInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::WAFTagOperationInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6097,42 +7209,70 @@ impl UpdateByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateByteMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateByteMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateByteMatchSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateByteMatchSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateByteMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateByteMatchSetError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateByteMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateByteMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6183,41 +7323,78 @@ impl UpdateGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGeoMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGeoMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGeoMatchSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGeoMatchSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGeoMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGeoMatchSetError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGeoMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGeoMatchSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGeoMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6269,33 +7446,78 @@ impl UpdateIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFNonexistentContainer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6347,49 +7569,78 @@ impl UpdateRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRateBasedRuleError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRateBasedRuleError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6439,44 +7690,70 @@ impl UpdateRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFDisallowedName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFDisallowedName,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRegexMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6525,44 +7802,70 @@ impl UpdateRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexPatternSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexPatternSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexPatternSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidRegexPatternException" => {
-                    return RusotoError::Service(
-                        UpdateRegexPatternSetError::WAFInvalidRegexPattern(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRegexPatternSetError::WAFInvalidRegexPattern,
                     )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexPatternSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(
-                        UpdateRegexPatternSetError::WAFNonexistentContainer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRegexPatternSetError::WAFNonexistentContainer,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegexPatternSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRegexPatternSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6615,33 +7918,78 @@ impl UpdateRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFNonexistentContainer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6689,29 +8037,62 @@ impl UpdateRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleGroupError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFInvalidOperation(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleGroupError::WAFInvalidOperation,
+                    )
+                }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleGroupError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleGroupError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRuleGroupError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleGroupError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleGroupError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6761,51 +8142,78 @@ impl UpdateSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInvalidAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFLimitsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(
-                        UpdateSizeConstraintSetError::WAFNonexistentContainer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSizeConstraintSetError::WAFNonexistentContainer,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFReferencedItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSizeConstraintSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6859,46 +8267,70 @@ impl UpdateSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateSqlInjectionMatchSetError::WAFInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSqlInjectionMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
-                        UpdateSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSqlInjectionMatchSetError::WAFInvalidAccount,
                     )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(
-                        UpdateSqlInjectionMatchSetError::WAFInvalidOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSqlInjectionMatchSetError::WAFInvalidOperation,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
-                        UpdateSqlInjectionMatchSetError::WAFInvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSqlInjectionMatchSetError::WAFInvalidParameter,
                     )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(
-                        UpdateSqlInjectionMatchSetError::WAFLimitsExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSqlInjectionMatchSetError::WAFLimitsExceeded,
                     )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(
-                        UpdateSqlInjectionMatchSetError::WAFNonexistentContainer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSqlInjectionMatchSetError::WAFNonexistentContainer,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
-                        UpdateSqlInjectionMatchSetError::WAFNonexistentItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSqlInjectionMatchSetError::WAFNonexistentItem,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateSqlInjectionMatchSetError::WAFStaleData(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSqlInjectionMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6959,40 +8391,86 @@ impl UpdateWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateWebACLError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFNonexistentItem,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFReferencedItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFReferencedItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebACLError::WAFStaleData,
+                    )
                 }
                 "WAFSubscriptionNotFoundException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFSubscriptionNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateWebACLError::WAFSubscriptionNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7043,38 +8521,70 @@ impl UpdateXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateXssMatchSetError::WAFInternalError,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateXssMatchSetError::WAFInvalidAccount,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateXssMatchSetError::WAFInvalidOperation,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateXssMatchSetError::WAFInvalidParameter,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateXssMatchSetError::WAFLimitsExceeded,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFNonexistentContainer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateXssMatchSetError::WAFNonexistentContainer,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFNonexistentItem(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateXssMatchSetError::WAFNonexistentItem,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateXssMatchSetError::WAFStaleData,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7574,11 +9084,12 @@ pub struct WafClient {
 impl WafClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> WafClient {
         WafClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "WAF"),
         }
     }
 
@@ -7593,12 +9104,32 @@ impl WafClient {
     {
         WafClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "WAF"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> WafClient {
-        WafClient { client, region }
+        WafClient {
+            client,
+            region: region_for_service(region, "WAF"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> WafClient {
+        WafClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("WAF"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WafClient {
+        WafClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -7610,6 +9141,7 @@ impl Waf for WafClient {
         input: CreateByteMatchSetRequest,
     ) -> Result<CreateByteMatchSetResponse, RusotoError<CreateByteMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateByteMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateByteMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7628,6 +9160,7 @@ impl Waf for WafClient {
         input: CreateGeoMatchSetRequest,
     ) -> Result<CreateGeoMatchSetResponse, RusotoError<CreateGeoMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGeoMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7646,6 +9179,7 @@ impl Waf for WafClient {
         input: CreateIPSetRequest,
     ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateIPSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7664,6 +9198,7 @@ impl Waf for WafClient {
         input: CreateRateBasedRuleRequest,
     ) -> Result<CreateRateBasedRuleResponse, RusotoError<CreateRateBasedRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRateBasedRule");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRateBasedRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7682,6 +9217,7 @@ impl Waf for WafClient {
         input: CreateRegexMatchSetRequest,
     ) -> Result<CreateRegexMatchSetResponse, RusotoError<CreateRegexMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRegexMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRegexMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7700,6 +9236,7 @@ impl Waf for WafClient {
         input: CreateRegexPatternSetRequest,
     ) -> Result<CreateRegexPatternSetResponse, RusotoError<CreateRegexPatternSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRegexPatternSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRegexPatternSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7719,6 +9256,7 @@ impl Waf for WafClient {
         input: CreateRuleRequest,
     ) -> Result<CreateRuleResponse, RusotoError<CreateRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRule");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7737,6 +9275,7 @@ impl Waf for WafClient {
         input: CreateRuleGroupRequest,
     ) -> Result<CreateRuleGroupResponse, RusotoError<CreateRuleGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRuleGroup");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7755,6 +9294,7 @@ impl Waf for WafClient {
         input: CreateSizeConstraintSetRequest,
     ) -> Result<CreateSizeConstraintSetResponse, RusotoError<CreateSizeConstraintSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSizeConstraintSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateSizeConstraintSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7775,6 +9315,7 @@ impl Waf for WafClient {
     ) -> Result<CreateSqlInjectionMatchSetResponse, RusotoError<CreateSqlInjectionMatchSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSqlInjectionMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateSqlInjectionMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7794,6 +9335,7 @@ impl Waf for WafClient {
         input: CreateWebACLRequest,
     ) -> Result<CreateWebACLResponse, RusotoError<CreateWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateWebACL");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7813,6 +9355,7 @@ impl Waf for WafClient {
     ) -> Result<CreateWebACLMigrationStackResponse, RusotoError<CreateWebACLMigrationStackError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateWebACLMigrationStack");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateWebACLMigrationStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7832,6 +9375,7 @@ impl Waf for WafClient {
         input: CreateXssMatchSetRequest,
     ) -> Result<CreateXssMatchSetResponse, RusotoError<CreateXssMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateXssMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7850,6 +9394,7 @@ impl Waf for WafClient {
         input: DeleteByteMatchSetRequest,
     ) -> Result<DeleteByteMatchSetResponse, RusotoError<DeleteByteMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteByteMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteByteMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7868,6 +9413,7 @@ impl Waf for WafClient {
         input: DeleteGeoMatchSetRequest,
     ) -> Result<DeleteGeoMatchSetResponse, RusotoError<DeleteGeoMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteGeoMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7886,6 +9432,7 @@ impl Waf for WafClient {
         input: DeleteIPSetRequest,
     ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteIPSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7905,6 +9452,7 @@ impl Waf for WafClient {
     ) -> Result<DeleteLoggingConfigurationResponse, RusotoError<DeleteLoggingConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLoggingConfiguration");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteLoggingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7924,6 +9472,7 @@ impl Waf for WafClient {
         input: DeletePermissionPolicyRequest,
     ) -> Result<DeletePermissionPolicyResponse, RusotoError<DeletePermissionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePermissionPolicy");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeletePermissionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7943,6 +9492,7 @@ impl Waf for WafClient {
         input: DeleteRateBasedRuleRequest,
     ) -> Result<DeleteRateBasedRuleResponse, RusotoError<DeleteRateBasedRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRateBasedRule");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRateBasedRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7961,6 +9511,7 @@ impl Waf for WafClient {
         input: DeleteRegexMatchSetRequest,
     ) -> Result<DeleteRegexMatchSetResponse, RusotoError<DeleteRegexMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRegexMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRegexMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7979,6 +9530,7 @@ impl Waf for WafClient {
         input: DeleteRegexPatternSetRequest,
     ) -> Result<DeleteRegexPatternSetResponse, RusotoError<DeleteRegexPatternSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRegexPatternSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRegexPatternSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7998,6 +9550,7 @@ impl Waf for WafClient {
         input: DeleteRuleRequest,
     ) -> Result<DeleteRuleResponse, RusotoError<DeleteRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRule");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8016,6 +9569,7 @@ impl Waf for WafClient {
         input: DeleteRuleGroupRequest,
     ) -> Result<DeleteRuleGroupResponse, RusotoError<DeleteRuleGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRuleGroup");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8034,6 +9588,7 @@ impl Waf for WafClient {
         input: DeleteSizeConstraintSetRequest,
     ) -> Result<DeleteSizeConstraintSetResponse, RusotoError<DeleteSizeConstraintSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSizeConstraintSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteSizeConstraintSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8054,6 +9609,7 @@ impl Waf for WafClient {
     ) -> Result<DeleteSqlInjectionMatchSetResponse, RusotoError<DeleteSqlInjectionMatchSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSqlInjectionMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteSqlInjectionMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8073,6 +9629,7 @@ impl Waf for WafClient {
         input: DeleteWebACLRequest,
     ) -> Result<DeleteWebACLResponse, RusotoError<DeleteWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteWebACL");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8091,6 +9648,7 @@ impl Waf for WafClient {
         input: DeleteXssMatchSetRequest,
     ) -> Result<DeleteXssMatchSetResponse, RusotoError<DeleteXssMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteXssMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8109,6 +9667,7 @@ impl Waf for WafClient {
         input: GetByteMatchSetRequest,
     ) -> Result<GetByteMatchSetResponse, RusotoError<GetByteMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetByteMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetByteMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8126,6 +9685,7 @@ impl Waf for WafClient {
         &self,
     ) -> Result<GetChangeTokenResponse, RusotoError<GetChangeTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetChangeToken");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetChangeToken");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -8143,6 +9703,7 @@ impl Waf for WafClient {
         input: GetChangeTokenStatusRequest,
     ) -> Result<GetChangeTokenStatusResponse, RusotoError<GetChangeTokenStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetChangeTokenStatus");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetChangeTokenStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8162,6 +9723,7 @@ impl Waf for WafClient {
         input: GetGeoMatchSetRequest,
     ) -> Result<GetGeoMatchSetResponse, RusotoError<GetGeoMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetGeoMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8180,6 +9742,7 @@ impl Waf for WafClient {
         input: GetIPSetRequest,
     ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetIPSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8198,6 +9761,7 @@ impl Waf for WafClient {
         input: GetLoggingConfigurationRequest,
     ) -> Result<GetLoggingConfigurationResponse, RusotoError<GetLoggingConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLoggingConfiguration");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetLoggingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8217,6 +9781,7 @@ impl Waf for WafClient {
         input: GetPermissionPolicyRequest,
     ) -> Result<GetPermissionPolicyResponse, RusotoError<GetPermissionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPermissionPolicy");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetPermissionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8235,6 +9800,7 @@ impl Waf for WafClient {
         input: GetRateBasedRuleRequest,
     ) -> Result<GetRateBasedRuleResponse, RusotoError<GetRateBasedRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRateBasedRule");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRateBasedRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8254,6 +9820,7 @@ impl Waf for WafClient {
     ) -> Result<GetRateBasedRuleManagedKeysResponse, RusotoError<GetRateBasedRuleManagedKeysError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRateBasedRuleManagedKeys");
         request.add_header(
             "x-amz-target",
             "AWSWAF_20150824.GetRateBasedRuleManagedKeys",
@@ -8276,6 +9843,7 @@ impl Waf for WafClient {
         input: GetRegexMatchSetRequest,
     ) -> Result<GetRegexMatchSetResponse, RusotoError<GetRegexMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRegexMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRegexMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8294,6 +9862,7 @@ impl Waf for WafClient {
         input: GetRegexPatternSetRequest,
     ) -> Result<GetRegexPatternSetResponse, RusotoError<GetRegexPatternSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRegexPatternSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRegexPatternSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8312,6 +9881,7 @@ impl Waf for WafClient {
         input: GetRuleRequest,
     ) -> Result<GetRuleResponse, RusotoError<GetRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRule");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8330,6 +9900,7 @@ impl Waf for WafClient {
         input: GetRuleGroupRequest,
     ) -> Result<GetRuleGroupResponse, RusotoError<GetRuleGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRuleGroup");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8348,6 +9919,7 @@ impl Waf for WafClient {
         input: GetSampledRequestsRequest,
     ) -> Result<GetSampledRequestsResponse, RusotoError<GetSampledRequestsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSampledRequests");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetSampledRequests");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8366,6 +9938,7 @@ impl Waf for WafClient {
         input: GetSizeConstraintSetRequest,
     ) -> Result<GetSizeConstraintSetResponse, RusotoError<GetSizeConstraintSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSizeConstraintSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetSizeConstraintSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8385,6 +9958,7 @@ impl Waf for WafClient {
         input: GetSqlInjectionMatchSetRequest,
     ) -> Result<GetSqlInjectionMatchSetResponse, RusotoError<GetSqlInjectionMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSqlInjectionMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetSqlInjectionMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8404,6 +9978,7 @@ impl Waf for WafClient {
         input: GetWebACLRequest,
     ) -> Result<GetWebACLResponse, RusotoError<GetWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetWebACL");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8422,6 +9997,7 @@ impl Waf for WafClient {
         input: GetXssMatchSetRequest,
     ) -> Result<GetXssMatchSetResponse, RusotoError<GetXssMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetXssMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.GetXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8443,6 +10019,7 @@ impl Waf for WafClient {
         RusotoError<ListActivatedRulesInRuleGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListActivatedRulesInRuleGroup");
         request.add_header(
             "x-amz-target",
             "AWSWAF_20150824.ListActivatedRulesInRuleGroup",
@@ -8465,6 +10042,7 @@ impl Waf for WafClient {
         input: ListByteMatchSetsRequest,
     ) -> Result<ListByteMatchSetsResponse, RusotoError<ListByteMatchSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListByteMatchSets");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListByteMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8483,6 +10061,7 @@ impl Waf for WafClient {
         input: ListGeoMatchSetsRequest,
     ) -> Result<ListGeoMatchSetsResponse, RusotoError<ListGeoMatchSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGeoMatchSets");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListGeoMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8501,6 +10080,7 @@ impl Waf for WafClient {
         input: ListIPSetsRequest,
     ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListIPSets");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListIPSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8520,6 +10100,7 @@ impl Waf for WafClient {
     ) -> Result<ListLoggingConfigurationsResponse, RusotoError<ListLoggingConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLoggingConfigurations");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListLoggingConfigurations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8539,6 +10120,7 @@ impl Waf for WafClient {
         input: ListRateBasedRulesRequest,
     ) -> Result<ListRateBasedRulesResponse, RusotoError<ListRateBasedRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRateBasedRules");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRateBasedRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8557,6 +10139,7 @@ impl Waf for WafClient {
         input: ListRegexMatchSetsRequest,
     ) -> Result<ListRegexMatchSetsResponse, RusotoError<ListRegexMatchSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRegexMatchSets");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRegexMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8575,6 +10158,7 @@ impl Waf for WafClient {
         input: ListRegexPatternSetsRequest,
     ) -> Result<ListRegexPatternSetsResponse, RusotoError<ListRegexPatternSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRegexPatternSets");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRegexPatternSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8594,6 +10178,7 @@ impl Waf for WafClient {
         input: ListRuleGroupsRequest,
     ) -> Result<ListRuleGroupsResponse, RusotoError<ListRuleGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRuleGroups");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRuleGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8612,6 +10197,7 @@ impl Waf for WafClient {
         input: ListRulesRequest,
     ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRules");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8630,6 +10216,7 @@ impl Waf for WafClient {
         input: ListSizeConstraintSetsRequest,
     ) -> Result<ListSizeConstraintSetsResponse, RusotoError<ListSizeConstraintSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSizeConstraintSets");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListSizeConstraintSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8650,6 +10237,7 @@ impl Waf for WafClient {
     ) -> Result<ListSqlInjectionMatchSetsResponse, RusotoError<ListSqlInjectionMatchSetsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSqlInjectionMatchSets");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListSqlInjectionMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8669,6 +10257,7 @@ impl Waf for WafClient {
         input: ListSubscribedRuleGroupsRequest,
     ) -> Result<ListSubscribedRuleGroupsResponse, RusotoError<ListSubscribedRuleGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSubscribedRuleGroups");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListSubscribedRuleGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8688,6 +10277,7 @@ impl Waf for WafClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8706,6 +10296,7 @@ impl Waf for WafClient {
         input: ListWebACLsRequest,
     ) -> Result<ListWebACLsResponse, RusotoError<ListWebACLsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListWebACLs");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListWebACLs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8724,6 +10315,7 @@ impl Waf for WafClient {
         input: ListXssMatchSetsRequest,
     ) -> Result<ListXssMatchSetsResponse, RusotoError<ListXssMatchSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListXssMatchSets");
         request.add_header("x-amz-target", "AWSWAF_20150824.ListXssMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8742,6 +10334,7 @@ impl Waf for WafClient {
         input: PutLoggingConfigurationRequest,
     ) -> Result<PutLoggingConfigurationResponse, RusotoError<PutLoggingConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutLoggingConfiguration");
         request.add_header("x-amz-target", "AWSWAF_20150824.PutLoggingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8761,6 +10354,7 @@ impl Waf for WafClient {
         input: PutPermissionPolicyRequest,
     ) -> Result<PutPermissionPolicyResponse, RusotoError<PutPermissionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutPermissionPolicy");
         request.add_header("x-amz-target", "AWSWAF_20150824.PutPermissionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8779,6 +10373,7 @@ impl Waf for WafClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AWSWAF_20150824.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8797,6 +10392,7 @@ impl Waf for WafClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AWSWAF_20150824.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8815,6 +10411,7 @@ impl Waf for WafClient {
         input: UpdateByteMatchSetRequest,
     ) -> Result<UpdateByteMatchSetResponse, RusotoError<UpdateByteMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateByteMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateByteMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8833,6 +10430,7 @@ impl Waf for WafClient {
         input: UpdateGeoMatchSetRequest,
     ) -> Result<UpdateGeoMatchSetResponse, RusotoError<UpdateGeoMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGeoMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8851,6 +10449,7 @@ impl Waf for WafClient {
         input: UpdateIPSetRequest,
     ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateIPSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8869,6 +10468,7 @@ impl Waf for WafClient {
         input: UpdateRateBasedRuleRequest,
     ) -> Result<UpdateRateBasedRuleResponse, RusotoError<UpdateRateBasedRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRateBasedRule");
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRateBasedRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8887,6 +10487,7 @@ impl Waf for WafClient {
         input: UpdateRegexMatchSetRequest,
     ) -> Result<UpdateRegexMatchSetResponse, RusotoError<UpdateRegexMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRegexMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRegexMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8905,6 +10506,7 @@ impl Waf for WafClient {
         input: UpdateRegexPatternSetRequest,
     ) -> Result<UpdateRegexPatternSetResponse, RusotoError<UpdateRegexPatternSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRegexPatternSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRegexPatternSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8924,6 +10526,7 @@ impl Waf for WafClient {
         input: UpdateRuleRequest,
     ) -> Result<UpdateRuleResponse, RusotoError<UpdateRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRule");
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8942,6 +10545,7 @@ impl Waf for WafClient {
         input: UpdateRuleGroupRequest,
     ) -> Result<UpdateRuleGroupResponse, RusotoError<UpdateRuleGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRuleGroup");
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8960,6 +10564,7 @@ impl Waf for WafClient {
         input: UpdateSizeConstraintSetRequest,
     ) -> Result<UpdateSizeConstraintSetResponse, RusotoError<UpdateSizeConstraintSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSizeConstraintSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateSizeConstraintSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8980,6 +10585,7 @@ impl Waf for WafClient {
     ) -> Result<UpdateSqlInjectionMatchSetResponse, RusotoError<UpdateSqlInjectionMatchSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSqlInjectionMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateSqlInjectionMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8999,6 +10605,7 @@ impl Waf for WafClient {
         input: UpdateWebACLRequest,
     ) -> Result<UpdateWebACLResponse, RusotoError<UpdateWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateWebACL");
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9017,6 +10624,7 @@ impl Waf for WafClient {
         input: UpdateXssMatchSetRequest,
     ) -> Result<UpdateXssMatchSetResponse, RusotoError<UpdateXssMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateXssMatchSet");
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9029,3 +10637,755 @@ impl Waf for WafClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateXssMatchSetResponse, _>()
     }
 }
+
+/// A blocking client for the WAF API, running the calls of a `WafClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `WafClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WafBlockingClient {
+    client: WafClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WafBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> WafBlockingClient {
+        WafBlockingClient::from_client(WafClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WafBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WafBlockingClient::from_client(WafClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> WafBlockingClient {
+        WafBlockingClient::from_client(WafClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> WafBlockingClient {
+        WafBlockingClient::from_client(WafClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: WafClient) -> WafBlockingClient {
+        WafBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> WafBlockingClient {
+        WafBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WafBlockingClient {
+        WafBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Waf::create_byte_match_set`].
+    pub fn create_byte_match_set(
+        &self,
+        input: CreateByteMatchSetRequest,
+    ) -> Result<CreateByteMatchSetResponse, RusotoError<CreateByteMatchSetError>> {
+        self.runtime
+            .block_on(self.client.create_byte_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::create_geo_match_set`].
+    pub fn create_geo_match_set(
+        &self,
+        input: CreateGeoMatchSetRequest,
+    ) -> Result<CreateGeoMatchSetResponse, RusotoError<CreateGeoMatchSetError>> {
+        self.runtime
+            .block_on(self.client.create_geo_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::create_ip_set`].
+    pub fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
+        self.runtime.block_on(self.client.create_ip_set(input))
+    }
+
+    /// Blocking version of [`Waf::create_rate_based_rule`].
+    pub fn create_rate_based_rule(
+        &self,
+        input: CreateRateBasedRuleRequest,
+    ) -> Result<CreateRateBasedRuleResponse, RusotoError<CreateRateBasedRuleError>> {
+        self.runtime
+            .block_on(self.client.create_rate_based_rule(input))
+    }
+
+    /// Blocking version of [`Waf::create_regex_match_set`].
+    pub fn create_regex_match_set(
+        &self,
+        input: CreateRegexMatchSetRequest,
+    ) -> Result<CreateRegexMatchSetResponse, RusotoError<CreateRegexMatchSetError>> {
+        self.runtime
+            .block_on(self.client.create_regex_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::create_regex_pattern_set`].
+    pub fn create_regex_pattern_set(
+        &self,
+        input: CreateRegexPatternSetRequest,
+    ) -> Result<CreateRegexPatternSetResponse, RusotoError<CreateRegexPatternSetError>> {
+        self.runtime
+            .block_on(self.client.create_regex_pattern_set(input))
+    }
+
+    /// Blocking version of [`Waf::create_rule`].
+    pub fn create_rule(
+        &self,
+        input: CreateRuleRequest,
+    ) -> Result<CreateRuleResponse, RusotoError<CreateRuleError>> {
+        self.runtime.block_on(self.client.create_rule(input))
+    }
+
+    /// Blocking version of [`Waf::create_rule_group`].
+    pub fn create_rule_group(
+        &self,
+        input: CreateRuleGroupRequest,
+    ) -> Result<CreateRuleGroupResponse, RusotoError<CreateRuleGroupError>> {
+        self.runtime.block_on(self.client.create_rule_group(input))
+    }
+
+    /// Blocking version of [`Waf::create_size_constraint_set`].
+    pub fn create_size_constraint_set(
+        &self,
+        input: CreateSizeConstraintSetRequest,
+    ) -> Result<CreateSizeConstraintSetResponse, RusotoError<CreateSizeConstraintSetError>> {
+        self.runtime
+            .block_on(self.client.create_size_constraint_set(input))
+    }
+
+    /// Blocking version of [`Waf::create_sql_injection_match_set`].
+    pub fn create_sql_injection_match_set(
+        &self,
+        input: CreateSqlInjectionMatchSetRequest,
+    ) -> Result<CreateSqlInjectionMatchSetResponse, RusotoError<CreateSqlInjectionMatchSetError>>
+    {
+        self.runtime
+            .block_on(self.client.create_sql_injection_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::create_web_acl`].
+    pub fn create_web_acl(
+        &self,
+        input: CreateWebACLRequest,
+    ) -> Result<CreateWebACLResponse, RusotoError<CreateWebACLError>> {
+        self.runtime.block_on(self.client.create_web_acl(input))
+    }
+
+    /// Blocking version of [`Waf::create_web_acl_migration_stack`].
+    pub fn create_web_acl_migration_stack(
+        &self,
+        input: CreateWebACLMigrationStackRequest,
+    ) -> Result<CreateWebACLMigrationStackResponse, RusotoError<CreateWebACLMigrationStackError>>
+    {
+        self.runtime
+            .block_on(self.client.create_web_acl_migration_stack(input))
+    }
+
+    /// Blocking version of [`Waf::create_xss_match_set`].
+    pub fn create_xss_match_set(
+        &self,
+        input: CreateXssMatchSetRequest,
+    ) -> Result<CreateXssMatchSetResponse, RusotoError<CreateXssMatchSetError>> {
+        self.runtime
+            .block_on(self.client.create_xss_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::delete_byte_match_set`].
+    pub fn delete_byte_match_set(
+        &self,
+        input: DeleteByteMatchSetRequest,
+    ) -> Result<DeleteByteMatchSetResponse, RusotoError<DeleteByteMatchSetError>> {
+        self.runtime
+            .block_on(self.client.delete_byte_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::delete_geo_match_set`].
+    pub fn delete_geo_match_set(
+        &self,
+        input: DeleteGeoMatchSetRequest,
+    ) -> Result<DeleteGeoMatchSetResponse, RusotoError<DeleteGeoMatchSetError>> {
+        self.runtime
+            .block_on(self.client.delete_geo_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::delete_ip_set`].
+    pub fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
+        self.runtime.block_on(self.client.delete_ip_set(input))
+    }
+
+    /// Blocking version of [`Waf::delete_logging_configuration`].
+    pub fn delete_logging_configuration(
+        &self,
+        input: DeleteLoggingConfigurationRequest,
+    ) -> Result<DeleteLoggingConfigurationResponse, RusotoError<DeleteLoggingConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_logging_configuration(input))
+    }
+
+    /// Blocking version of [`Waf::delete_permission_policy`].
+    pub fn delete_permission_policy(
+        &self,
+        input: DeletePermissionPolicyRequest,
+    ) -> Result<DeletePermissionPolicyResponse, RusotoError<DeletePermissionPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_permission_policy(input))
+    }
+
+    /// Blocking version of [`Waf::delete_rate_based_rule`].
+    pub fn delete_rate_based_rule(
+        &self,
+        input: DeleteRateBasedRuleRequest,
+    ) -> Result<DeleteRateBasedRuleResponse, RusotoError<DeleteRateBasedRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_rate_based_rule(input))
+    }
+
+    /// Blocking version of [`Waf::delete_regex_match_set`].
+    pub fn delete_regex_match_set(
+        &self,
+        input: DeleteRegexMatchSetRequest,
+    ) -> Result<DeleteRegexMatchSetResponse, RusotoError<DeleteRegexMatchSetError>> {
+        self.runtime
+            .block_on(self.client.delete_regex_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::delete_regex_pattern_set`].
+    pub fn delete_regex_pattern_set(
+        &self,
+        input: DeleteRegexPatternSetRequest,
+    ) -> Result<DeleteRegexPatternSetResponse, RusotoError<DeleteRegexPatternSetError>> {
+        self.runtime
+            .block_on(self.client.delete_regex_pattern_set(input))
+    }
+
+    /// Blocking version of [`Waf::delete_rule`].
+    pub fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> Result<DeleteRuleResponse, RusotoError<DeleteRuleError>> {
+        self.runtime.block_on(self.client.delete_rule(input))
+    }
+
+    /// Blocking version of [`Waf::delete_rule_group`].
+    pub fn delete_rule_group(
+        &self,
+        input: DeleteRuleGroupRequest,
+    ) -> Result<DeleteRuleGroupResponse, RusotoError<DeleteRuleGroupError>> {
+        self.runtime.block_on(self.client.delete_rule_group(input))
+    }
+
+    /// Blocking version of [`Waf::delete_size_constraint_set`].
+    pub fn delete_size_constraint_set(
+        &self,
+        input: DeleteSizeConstraintSetRequest,
+    ) -> Result<DeleteSizeConstraintSetResponse, RusotoError<DeleteSizeConstraintSetError>> {
+        self.runtime
+            .block_on(self.client.delete_size_constraint_set(input))
+    }
+
+    /// Blocking version of [`Waf::delete_sql_injection_match_set`].
+    pub fn delete_sql_injection_match_set(
+        &self,
+        input: DeleteSqlInjectionMatchSetRequest,
+    ) -> Result<DeleteSqlInjectionMatchSetResponse, RusotoError<DeleteSqlInjectionMatchSetError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_sql_injection_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::delete_web_acl`].
+    pub fn delete_web_acl(
+        &self,
+        input: DeleteWebACLRequest,
+    ) -> Result<DeleteWebACLResponse, RusotoError<DeleteWebACLError>> {
+        self.runtime.block_on(self.client.delete_web_acl(input))
+    }
+
+    /// Blocking version of [`Waf::delete_xss_match_set`].
+    pub fn delete_xss_match_set(
+        &self,
+        input: DeleteXssMatchSetRequest,
+    ) -> Result<DeleteXssMatchSetResponse, RusotoError<DeleteXssMatchSetError>> {
+        self.runtime
+            .block_on(self.client.delete_xss_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::get_byte_match_set`].
+    pub fn get_byte_match_set(
+        &self,
+        input: GetByteMatchSetRequest,
+    ) -> Result<GetByteMatchSetResponse, RusotoError<GetByteMatchSetError>> {
+        self.runtime.block_on(self.client.get_byte_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::get_change_token`].
+    pub fn get_change_token(
+        &self,
+    ) -> Result<GetChangeTokenResponse, RusotoError<GetChangeTokenError>> {
+        self.runtime.block_on(self.client.get_change_token())
+    }
+
+    /// Blocking version of [`Waf::get_change_token_status`].
+    pub fn get_change_token_status(
+        &self,
+        input: GetChangeTokenStatusRequest,
+    ) -> Result<GetChangeTokenStatusResponse, RusotoError<GetChangeTokenStatusError>> {
+        self.runtime
+            .block_on(self.client.get_change_token_status(input))
+    }
+
+    /// Blocking version of [`Waf::get_geo_match_set`].
+    pub fn get_geo_match_set(
+        &self,
+        input: GetGeoMatchSetRequest,
+    ) -> Result<GetGeoMatchSetResponse, RusotoError<GetGeoMatchSetError>> {
+        self.runtime.block_on(self.client.get_geo_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::get_ip_set`].
+    pub fn get_ip_set(
+        &self,
+        input: GetIPSetRequest,
+    ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
+        self.runtime.block_on(self.client.get_ip_set(input))
+    }
+
+    /// Blocking version of [`Waf::get_logging_configuration`].
+    pub fn get_logging_configuration(
+        &self,
+        input: GetLoggingConfigurationRequest,
+    ) -> Result<GetLoggingConfigurationResponse, RusotoError<GetLoggingConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_logging_configuration(input))
+    }
+
+    /// Blocking version of [`Waf::get_permission_policy`].
+    pub fn get_permission_policy(
+        &self,
+        input: GetPermissionPolicyRequest,
+    ) -> Result<GetPermissionPolicyResponse, RusotoError<GetPermissionPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_permission_policy(input))
+    }
+
+    /// Blocking version of [`Waf::get_rate_based_rule`].
+    pub fn get_rate_based_rule(
+        &self,
+        input: GetRateBasedRuleRequest,
+    ) -> Result<GetRateBasedRuleResponse, RusotoError<GetRateBasedRuleError>> {
+        self.runtime
+            .block_on(self.client.get_rate_based_rule(input))
+    }
+
+    /// Blocking version of [`Waf::get_rate_based_rule_managed_keys`].
+    pub fn get_rate_based_rule_managed_keys(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> Result<GetRateBasedRuleManagedKeysResponse, RusotoError<GetRateBasedRuleManagedKeysError>>
+    {
+        self.runtime
+            .block_on(self.client.get_rate_based_rule_managed_keys(input))
+    }
+
+    /// Blocking version of [`Waf::get_regex_match_set`].
+    pub fn get_regex_match_set(
+        &self,
+        input: GetRegexMatchSetRequest,
+    ) -> Result<GetRegexMatchSetResponse, RusotoError<GetRegexMatchSetError>> {
+        self.runtime
+            .block_on(self.client.get_regex_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::get_regex_pattern_set`].
+    pub fn get_regex_pattern_set(
+        &self,
+        input: GetRegexPatternSetRequest,
+    ) -> Result<GetRegexPatternSetResponse, RusotoError<GetRegexPatternSetError>> {
+        self.runtime
+            .block_on(self.client.get_regex_pattern_set(input))
+    }
+
+    /// Blocking version of [`Waf::get_rule`].
+    pub fn get_rule(
+        &self,
+        input: GetRuleRequest,
+    ) -> Result<GetRuleResponse, RusotoError<GetRuleError>> {
+        self.runtime.block_on(self.client.get_rule(input))
+    }
+
+    /// Blocking version of [`Waf::get_rule_group`].
+    pub fn get_rule_group(
+        &self,
+        input: GetRuleGroupRequest,
+    ) -> Result<GetRuleGroupResponse, RusotoError<GetRuleGroupError>> {
+        self.runtime.block_on(self.client.get_rule_group(input))
+    }
+
+    /// Blocking version of [`Waf::get_sampled_requests`].
+    pub fn get_sampled_requests(
+        &self,
+        input: GetSampledRequestsRequest,
+    ) -> Result<GetSampledRequestsResponse, RusotoError<GetSampledRequestsError>> {
+        self.runtime
+            .block_on(self.client.get_sampled_requests(input))
+    }
+
+    /// Blocking version of [`Waf::get_size_constraint_set`].
+    pub fn get_size_constraint_set(
+        &self,
+        input: GetSizeConstraintSetRequest,
+    ) -> Result<GetSizeConstraintSetResponse, RusotoError<GetSizeConstraintSetError>> {
+        self.runtime
+            .block_on(self.client.get_size_constraint_set(input))
+    }
+
+    /// Blocking version of [`Waf::get_sql_injection_match_set`].
+    pub fn get_sql_injection_match_set(
+        &self,
+        input: GetSqlInjectionMatchSetRequest,
+    ) -> Result<GetSqlInjectionMatchSetResponse, RusotoError<GetSqlInjectionMatchSetError>> {
+        self.runtime
+            .block_on(self.client.get_sql_injection_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::get_web_acl`].
+    pub fn get_web_acl(
+        &self,
+        input: GetWebACLRequest,
+    ) -> Result<GetWebACLResponse, RusotoError<GetWebACLError>> {
+        self.runtime.block_on(self.client.get_web_acl(input))
+    }
+
+    /// Blocking version of [`Waf::get_xss_match_set`].
+    pub fn get_xss_match_set(
+        &self,
+        input: GetXssMatchSetRequest,
+    ) -> Result<GetXssMatchSetResponse, RusotoError<GetXssMatchSetError>> {
+        self.runtime.block_on(self.client.get_xss_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::list_activated_rules_in_rule_group`].
+    pub fn list_activated_rules_in_rule_group(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> Result<
+        ListActivatedRulesInRuleGroupResponse,
+        RusotoError<ListActivatedRulesInRuleGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_activated_rules_in_rule_group(input))
+    }
+
+    /// Blocking version of [`Waf::list_byte_match_sets`].
+    pub fn list_byte_match_sets(
+        &self,
+        input: ListByteMatchSetsRequest,
+    ) -> Result<ListByteMatchSetsResponse, RusotoError<ListByteMatchSetsError>> {
+        self.runtime
+            .block_on(self.client.list_byte_match_sets(input))
+    }
+
+    /// Blocking version of [`Waf::list_geo_match_sets`].
+    pub fn list_geo_match_sets(
+        &self,
+        input: ListGeoMatchSetsRequest,
+    ) -> Result<ListGeoMatchSetsResponse, RusotoError<ListGeoMatchSetsError>> {
+        self.runtime
+            .block_on(self.client.list_geo_match_sets(input))
+    }
+
+    /// Blocking version of [`Waf::list_ip_sets`].
+    pub fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
+        self.runtime.block_on(self.client.list_ip_sets(input))
+    }
+
+    /// Blocking version of [`Waf::list_logging_configurations`].
+    pub fn list_logging_configurations(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> Result<ListLoggingConfigurationsResponse, RusotoError<ListLoggingConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_logging_configurations(input))
+    }
+
+    /// Blocking version of [`Waf::list_rate_based_rules`].
+    pub fn list_rate_based_rules(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> Result<ListRateBasedRulesResponse, RusotoError<ListRateBasedRulesError>> {
+        self.runtime
+            .block_on(self.client.list_rate_based_rules(input))
+    }
+
+    /// Blocking version of [`Waf::list_regex_match_sets`].
+    pub fn list_regex_match_sets(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> Result<ListRegexMatchSetsResponse, RusotoError<ListRegexMatchSetsError>> {
+        self.runtime
+            .block_on(self.client.list_regex_match_sets(input))
+    }
+
+    /// Blocking version of [`Waf::list_regex_pattern_sets`].
+    pub fn list_regex_pattern_sets(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> Result<ListRegexPatternSetsResponse, RusotoError<ListRegexPatternSetsError>> {
+        self.runtime
+            .block_on(self.client.list_regex_pattern_sets(input))
+    }
+
+    /// Blocking version of [`Waf::list_rule_groups`].
+    pub fn list_rule_groups(
+        &self,
+        input: ListRuleGroupsRequest,
+    ) -> Result<ListRuleGroupsResponse, RusotoError<ListRuleGroupsError>> {
+        self.runtime.block_on(self.client.list_rule_groups(input))
+    }
+
+    /// Blocking version of [`Waf::list_rules`].
+    pub fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
+        self.runtime.block_on(self.client.list_rules(input))
+    }
+
+    /// Blocking version of [`Waf::list_size_constraint_sets`].
+    pub fn list_size_constraint_sets(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> Result<ListSizeConstraintSetsResponse, RusotoError<ListSizeConstraintSetsError>> {
+        self.runtime
+            .block_on(self.client.list_size_constraint_sets(input))
+    }
+
+    /// Blocking version of [`Waf::list_sql_injection_match_sets`].
+    pub fn list_sql_injection_match_sets(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> Result<ListSqlInjectionMatchSetsResponse, RusotoError<ListSqlInjectionMatchSetsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_sql_injection_match_sets(input))
+    }
+
+    /// Blocking version of [`Waf::list_subscribed_rule_groups`].
+    pub fn list_subscribed_rule_groups(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> Result<ListSubscribedRuleGroupsResponse, RusotoError<ListSubscribedRuleGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_subscribed_rule_groups(input))
+    }
+
+    /// Blocking version of [`Waf::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Waf::list_web_ac_ls`].
+    pub fn list_web_ac_ls(
+        &self,
+        input: ListWebACLsRequest,
+    ) -> Result<ListWebACLsResponse, RusotoError<ListWebACLsError>> {
+        self.runtime.block_on(self.client.list_web_ac_ls(input))
+    }
+
+    /// Blocking version of [`Waf::list_xss_match_sets`].
+    pub fn list_xss_match_sets(
+        &self,
+        input: ListXssMatchSetsRequest,
+    ) -> Result<ListXssMatchSetsResponse, RusotoError<ListXssMatchSetsError>> {
+        self.runtime
+            .block_on(self.client.list_xss_match_sets(input))
+    }
+
+    /// Blocking version of [`Waf::put_logging_configuration`].
+    pub fn put_logging_configuration(
+        &self,
+        input: PutLoggingConfigurationRequest,
+    ) -> Result<PutLoggingConfigurationResponse, RusotoError<PutLoggingConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_logging_configuration(input))
+    }
+
+    /// Blocking version of [`Waf::put_permission_policy`].
+    pub fn put_permission_policy(
+        &self,
+        input: PutPermissionPolicyRequest,
+    ) -> Result<PutPermissionPolicyResponse, RusotoError<PutPermissionPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_permission_policy(input))
+    }
+
+    /// Blocking version of [`Waf::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Waf::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Waf::update_byte_match_set`].
+    pub fn update_byte_match_set(
+        &self,
+        input: UpdateByteMatchSetRequest,
+    ) -> Result<UpdateByteMatchSetResponse, RusotoError<UpdateByteMatchSetError>> {
+        self.runtime
+            .block_on(self.client.update_byte_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::update_geo_match_set`].
+    pub fn update_geo_match_set(
+        &self,
+        input: UpdateGeoMatchSetRequest,
+    ) -> Result<UpdateGeoMatchSetResponse, RusotoError<UpdateGeoMatchSetError>> {
+        self.runtime
+            .block_on(self.client.update_geo_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::update_ip_set`].
+    pub fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
+        self.runtime.block_on(self.client.update_ip_set(input))
+    }
+
+    /// Blocking version of [`Waf::update_rate_based_rule`].
+    pub fn update_rate_based_rule(
+        &self,
+        input: UpdateRateBasedRuleRequest,
+    ) -> Result<UpdateRateBasedRuleResponse, RusotoError<UpdateRateBasedRuleError>> {
+        self.runtime
+            .block_on(self.client.update_rate_based_rule(input))
+    }
+
+    /// Blocking version of [`Waf::update_regex_match_set`].
+    pub fn update_regex_match_set(
+        &self,
+        input: UpdateRegexMatchSetRequest,
+    ) -> Result<UpdateRegexMatchSetResponse, RusotoError<UpdateRegexMatchSetError>> {
+        self.runtime
+            .block_on(self.client.update_regex_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::update_regex_pattern_set`].
+    pub fn update_regex_pattern_set(
+        &self,
+        input: UpdateRegexPatternSetRequest,
+    ) -> Result<UpdateRegexPatternSetResponse, RusotoError<UpdateRegexPatternSetError>> {
+        self.runtime
+            .block_on(self.client.update_regex_pattern_set(input))
+    }
+
+    /// Blocking version of [`Waf::update_rule`].
+    pub fn update_rule(
+        &self,
+        input: UpdateRuleRequest,
+    ) -> Result<UpdateRuleResponse, RusotoError<UpdateRuleError>> {
+        self.runtime.block_on(self.client.update_rule(input))
+    }
+
+    /// Blocking version of [`Waf::update_rule_group`].
+    pub fn update_rule_group(
+        &self,
+        input: UpdateRuleGroupRequest,
+    ) -> Result<UpdateRuleGroupResponse, RusotoError<UpdateRuleGroupError>> {
+        self.runtime.block_on(self.client.update_rule_group(input))
+    }
+
+    /// Blocking version of [`Waf::update_size_constraint_set`].
+    pub fn update_size_constraint_set(
+        &self,
+        input: UpdateSizeConstraintSetRequest,
+    ) -> Result<UpdateSizeConstraintSetResponse, RusotoError<UpdateSizeConstraintSetError>> {
+        self.runtime
+            .block_on(self.client.update_size_constraint_set(input))
+    }
+
+    /// Blocking version of [`Waf::update_sql_injection_match_set`].
+    pub fn update_sql_injection_match_set(
+        &self,
+        input: UpdateSqlInjectionMatchSetRequest,
+    ) -> Result<UpdateSqlInjectionMatchSetResponse, RusotoError<UpdateSqlInjectionMatchSetError>>
+    {
+        self.runtime
+            .block_on(self.client.update_sql_injection_match_set(input))
+    }
+
+    /// Blocking version of [`Waf::update_web_acl`].
+    pub fn update_web_acl(
+        &self,
+        input: UpdateWebACLRequest,
+    ) -> Result<UpdateWebACLResponse, RusotoError<UpdateWebACLError>> {
+        self.runtime.block_on(self.client.update_web_acl(input))
+    }
+
+    /// Blocking version of [`Waf::update_xss_match_set`].
+    pub fn update_xss_match_set(
+        &self,
+        input: UpdateXssMatchSetRequest,
+    ) -> Result<UpdateXssMatchSetResponse, RusotoError<UpdateXssMatchSetError>> {
+        self.runtime
+            .block_on(self.client.update_xss_match_set(input))
+    }
+}
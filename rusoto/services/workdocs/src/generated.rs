@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1812,36 +1813,54 @@ impl AbortDocumentVersionUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(AbortDocumentVersionUploadError::EntityNotExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AbortDocumentVersionUploadError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(AbortDocumentVersionUploadError::FailedDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AbortDocumentVersionUploadError::FailedDependency,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(AbortDocumentVersionUploadError::ProhibitedState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AbortDocumentVersionUploadError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        AbortDocumentVersionUploadError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AbortDocumentVersionUploadError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        AbortDocumentVersionUploadError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AbortDocumentVersionUploadError::UnauthorizedOperation,
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        AbortDocumentVersionUploadError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AbortDocumentVersionUploadError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1888,23 +1907,46 @@ impl ActivateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(ActivateUserError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ActivateUserError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(ActivateUserError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ActivateUserError::FailedDependency,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ActivateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ActivateUserError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(ActivateUserError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ActivateUserError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(ActivateUserError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ActivateUserError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1942,26 +1984,38 @@ impl AddResourcePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(AddResourcePermissionsError::FailedDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddResourcePermissionsError::FailedDependency,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AddResourcePermissionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddResourcePermissionsError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        AddResourcePermissionsError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddResourcePermissionsError::UnauthorizedOperation,
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        AddResourcePermissionsError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddResourcePermissionsError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2008,36 +2062,70 @@ impl CreateCommentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DocumentLockedForCommentsException" => {
-                    return RusotoError::Service(CreateCommentError::DocumentLockedForComments(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCommentError::DocumentLockedForComments,
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(CreateCommentError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommentError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(CreateCommentError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommentError::FailedDependency,
+                    )
                 }
                 "InvalidCommentOperationException" => {
-                    return RusotoError::Service(CreateCommentError::InvalidCommentOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCommentError::InvalidCommentOperation,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(CreateCommentError::ProhibitedState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommentError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateCommentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommentError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateCommentError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommentError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(CreateCommentError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCommentError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2084,41 +2172,62 @@ impl CreateCustomMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CustomMetadataLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateCustomMetadataError::CustomMetadataLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomMetadataError::CustomMetadataLimitExceeded,
                     )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(CreateCustomMetadataError::EntityNotExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCustomMetadataError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(CreateCustomMetadataError::FailedDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCustomMetadataError::FailedDependency,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(CreateCustomMetadataError::ProhibitedState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCustomMetadataError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateCustomMetadataError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCustomMetadataError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateCustomMetadataError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCustomMetadataError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        CreateCustomMetadataError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomMetadataError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2172,35 +2281,78 @@ impl CreateFolderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingOperationException" => {
-                    return RusotoError::Service(CreateFolderError::ConflictingOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFolderError::ConflictingOperation,
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFolderError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFolderError::EntityAlreadyExists,
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(CreateFolderError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFolderError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(CreateFolderError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFolderError::FailedDependency,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFolderError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFolderError::LimitExceeded,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(CreateFolderError::ProhibitedState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFolderError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateFolderError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFolderError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateFolderError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFolderError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(CreateFolderError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFolderError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2246,26 +2398,54 @@ impl CreateLabelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(CreateLabelsError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLabelsError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(CreateLabelsError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLabelsError::FailedDependency,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateLabelsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLabelsError::ServiceUnavailable,
+                    )
                 }
                 "TooManyLabelsException" => {
-                    return RusotoError::Service(CreateLabelsError::TooManyLabels(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLabelsError::TooManyLabels,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateLabelsError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLabelsError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(CreateLabelsError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLabelsError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2304,21 +2484,30 @@ impl CreateNotificationSubscriptionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateNotificationSubscriptionError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotificationSubscriptionError::ServiceUnavailable,
                     )
                 }
                 "TooManySubscriptionsException" => {
-                    return RusotoError::Service(
-                        CreateNotificationSubscriptionError::TooManySubscriptions(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotificationSubscriptionError::TooManySubscriptions,
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        CreateNotificationSubscriptionError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotificationSubscriptionError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2362,23 +2551,46 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateUserError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::EntityAlreadyExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(CreateUserError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::FailedDependency,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateUserError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(CreateUserError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2418,25 +2630,46 @@ impl DeactivateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeactivateUserError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeactivateUserError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeactivateUserError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeactivateUserError::FailedDependency,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeactivateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeactivateUserError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeactivateUserError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeactivateUserError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DeactivateUserError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeactivateUserError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2480,31 +2713,62 @@ impl DeleteCommentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DocumentLockedForCommentsException" => {
-                    return RusotoError::Service(DeleteCommentError::DocumentLockedForComments(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCommentError::DocumentLockedForComments,
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteCommentError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCommentError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteCommentError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCommentError::FailedDependency,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DeleteCommentError::ProhibitedState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCommentError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteCommentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCommentError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteCommentError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCommentError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DeleteCommentError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCommentError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2548,36 +2812,54 @@ impl DeleteCustomMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteCustomMetadataError::EntityNotExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCustomMetadataError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteCustomMetadataError::FailedDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCustomMetadataError::FailedDependency,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DeleteCustomMetadataError::ProhibitedState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCustomMetadataError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteCustomMetadataError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCustomMetadataError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteCustomMetadataError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCustomMetadataError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        DeleteCustomMetadataError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomMetadataError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2626,36 +2908,70 @@ impl DeleteDocumentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteDocumentError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentError::ConcurrentModification,
+                    )
                 }
                 "ConflictingOperationException" => {
-                    return RusotoError::Service(DeleteDocumentError::ConflictingOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentError::ConflictingOperation,
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteDocumentError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteDocumentError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentError::FailedDependency,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DeleteDocumentError::ProhibitedState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDocumentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteDocumentError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DeleteDocumentError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2704,32 +3020,70 @@ impl DeleteFolderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteFolderError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFolderError::ConcurrentModification,
+                    )
                 }
                 "ConflictingOperationException" => {
-                    return RusotoError::Service(DeleteFolderError::ConflictingOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFolderError::ConflictingOperation,
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteFolderError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFolderError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteFolderError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFolderError::FailedDependency,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DeleteFolderError::ProhibitedState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFolderError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteFolderError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFolderError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteFolderError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFolderError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DeleteFolderError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFolderError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2776,41 +3130,62 @@ impl DeleteFolderContentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingOperationException" => {
-                    return RusotoError::Service(DeleteFolderContentsError::ConflictingOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFolderContentsError::ConflictingOperation,
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteFolderContentsError::EntityNotExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFolderContentsError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteFolderContentsError::FailedDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFolderContentsError::FailedDependency,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DeleteFolderContentsError::ProhibitedState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFolderContentsError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteFolderContentsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFolderContentsError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteFolderContentsError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFolderContentsError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        DeleteFolderContentsError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFolderContentsError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2854,23 +3229,46 @@ impl DeleteLabelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteLabelsError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLabelsError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteLabelsError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLabelsError::FailedDependency,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteLabelsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLabelsError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteLabelsError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLabelsError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DeleteLabelsError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLabelsError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2910,26 +3308,38 @@ impl DeleteNotificationSubscriptionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(
-                        DeleteNotificationSubscriptionError::EntityNotExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNotificationSubscriptionError::EntityNotExists,
                     )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(
-                        DeleteNotificationSubscriptionError::ProhibitedState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNotificationSubscriptionError::ProhibitedState,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteNotificationSubscriptionError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNotificationSubscriptionError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        DeleteNotificationSubscriptionError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNotificationSubscriptionError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2976,23 +3386,46 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteUserError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteUserError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::FailedDependency,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteUserError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DeleteUserError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3032,27 +3465,46 @@ impl DescribeActivitiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeActivitiesError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeActivitiesError::FailedDependency,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeActivitiesError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeActivitiesError::InvalidArgument,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeActivitiesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeActivitiesError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DescribeActivitiesError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeActivitiesError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        DescribeActivitiesError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeActivitiesError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3096,28 +3548,54 @@ impl DescribeCommentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DescribeCommentsError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCommentsError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeCommentsError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCommentsError::FailedDependency,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DescribeCommentsError::ProhibitedState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCommentsError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeCommentsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCommentsError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DescribeCommentsError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCommentsError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DescribeCommentsError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCommentsError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3162,41 +3640,62 @@ impl DescribeDocumentVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DescribeDocumentVersionsError::EntityNotExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDocumentVersionsError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeDocumentVersionsError::FailedDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDocumentVersionsError::FailedDependency,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeDocumentVersionsError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDocumentVersionsError::InvalidArgument,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DescribeDocumentVersionsError::ProhibitedState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDocumentVersionsError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDocumentVersionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDocumentVersionsError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        DescribeDocumentVersionsError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDocumentVersionsError::UnauthorizedOperation,
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        DescribeDocumentVersionsError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDocumentVersionsError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3244,36 +3743,54 @@ impl DescribeFolderContentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DescribeFolderContentsError::EntityNotExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFolderContentsError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeFolderContentsError::FailedDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFolderContentsError::FailedDependency,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeFolderContentsError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFolderContentsError::InvalidArgument,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DescribeFolderContentsError::ProhibitedState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFolderContentsError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeFolderContentsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFolderContentsError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        DescribeFolderContentsError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFolderContentsError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3314,22 +3831,38 @@ impl DescribeGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeGroupsError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupsError::FailedDependency,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeGroupsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupsError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DescribeGroupsError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGroupsError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DescribeGroupsError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGroupsError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3366,21 +3899,30 @@ impl DescribeNotificationSubscriptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(
-                        DescribeNotificationSubscriptionsError::EntityNotExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNotificationSubscriptionsError::EntityNotExists,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeNotificationSubscriptionsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNotificationSubscriptionsError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        DescribeNotificationSubscriptionsError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNotificationSubscriptionsError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3424,26 +3966,38 @@ impl DescribeResourcePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(
-                        DescribeResourcePermissionsError::FailedDependency(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourcePermissionsError::FailedDependency,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeResourcePermissionsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourcePermissionsError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        DescribeResourcePermissionsError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourcePermissionsError::UnauthorizedOperation,
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        DescribeResourcePermissionsError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourcePermissionsError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3488,29 +4042,46 @@ impl DescribeRootFoldersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeRootFoldersError::FailedDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRootFoldersError::FailedDependency,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeRootFoldersError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRootFoldersError::InvalidArgument,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRootFoldersError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRootFoldersError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DescribeRootFoldersError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRootFoldersError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        DescribeRootFoldersError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRootFoldersError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3556,31 +4127,62 @@ impl DescribeUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DescribeUsersError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUsersError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeUsersError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUsersError::FailedDependency,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeUsersError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUsersError::InvalidArgument,
+                    )
                 }
                 "RequestedEntityTooLargeException" => {
-                    return RusotoError::Service(DescribeUsersError::RequestedEntityTooLarge(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUsersError::RequestedEntityTooLarge,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeUsersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUsersError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DescribeUsersError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUsersError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DescribeUsersError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUsersError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3622,25 +4224,46 @@ impl GetCurrentUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(GetCurrentUserError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCurrentUserError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetCurrentUserError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCurrentUserError::FailedDependency,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetCurrentUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCurrentUserError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetCurrentUserError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCurrentUserError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(GetCurrentUserError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCurrentUserError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3684,29 +4307,62 @@ impl GetDocumentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(GetDocumentError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetDocumentError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentError::FailedDependency,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetDocumentError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentError::InvalidArgument,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(GetDocumentError::InvalidPassword(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentError::InvalidPassword,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDocumentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetDocumentError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(GetDocumentError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3748,25 +4404,46 @@ impl GetDocumentPathError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(GetDocumentPathError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentPathError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetDocumentPathError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentPathError::FailedDependency,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDocumentPathError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentPathError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetDocumentPathError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentPathError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(GetDocumentPathError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentPathError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3810,33 +4487,62 @@ impl GetDocumentVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(GetDocumentVersionError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentVersionError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetDocumentVersionError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentVersionError::FailedDependency,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(GetDocumentVersionError::InvalidPassword(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentVersionError::InvalidPassword,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(GetDocumentVersionError::ProhibitedState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentVersionError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDocumentVersionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentVersionError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetDocumentVersionError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentVersionError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        GetDocumentVersionError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentVersionError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3884,29 +4590,62 @@ impl GetFolderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(GetFolderError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetFolderError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::FailedDependency,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetFolderError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::InvalidArgument,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(GetFolderError::ProhibitedState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetFolderError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetFolderError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(GetFolderError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFolderError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3948,23 +4687,46 @@ impl GetFolderPathError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(GetFolderPathError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderPathError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetFolderPathError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderPathError::FailedDependency,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetFolderPathError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderPathError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetFolderPathError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderPathError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(GetFolderPathError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFolderPathError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4004,23 +4766,46 @@ impl GetResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetResourcesError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcesError::FailedDependency,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetResourcesError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcesError::InvalidArgument,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetResourcesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcesError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetResourcesError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcesError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(GetResourcesError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourcesError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4074,61 +4859,94 @@ impl InitiateDocumentVersionUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DraftUploadOutOfSyncException" => {
-                    return RusotoError::Service(
-                        InitiateDocumentVersionUploadError::DraftUploadOutOfSync(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDocumentVersionUploadError::DraftUploadOutOfSync,
                     )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        InitiateDocumentVersionUploadError::EntityAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDocumentVersionUploadError::EntityAlreadyExists,
                     )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(
-                        InitiateDocumentVersionUploadError::EntityNotExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDocumentVersionUploadError::EntityNotExists,
                     )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(
-                        InitiateDocumentVersionUploadError::FailedDependency(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDocumentVersionUploadError::FailedDependency,
                     )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(
-                        InitiateDocumentVersionUploadError::ProhibitedState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDocumentVersionUploadError::ProhibitedState,
                     )
                 }
                 "ResourceAlreadyCheckedOutException" => {
-                    return RusotoError::Service(
-                        InitiateDocumentVersionUploadError::ResourceAlreadyCheckedOut(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDocumentVersionUploadError::ResourceAlreadyCheckedOut,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        InitiateDocumentVersionUploadError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDocumentVersionUploadError::ServiceUnavailable,
                     )
                 }
                 "StorageLimitExceededException" => {
-                    return RusotoError::Service(
-                        InitiateDocumentVersionUploadError::StorageLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDocumentVersionUploadError::StorageLimitExceeded,
                     )
                 }
                 "StorageLimitWillExceedException" => {
-                    return RusotoError::Service(
-                        InitiateDocumentVersionUploadError::StorageLimitWillExceed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDocumentVersionUploadError::StorageLimitWillExceed,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        InitiateDocumentVersionUploadError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDocumentVersionUploadError::UnauthorizedOperation,
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        InitiateDocumentVersionUploadError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDocumentVersionUploadError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4196,26 +5014,38 @@ impl RemoveAllResourcePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(
-                        RemoveAllResourcePermissionsError::FailedDependency(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveAllResourcePermissionsError::FailedDependency,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        RemoveAllResourcePermissionsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveAllResourcePermissionsError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        RemoveAllResourcePermissionsError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveAllResourcePermissionsError::UnauthorizedOperation,
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        RemoveAllResourcePermissionsError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveAllResourcePermissionsError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4260,26 +5090,38 @@ impl RemoveResourcePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(RemoveResourcePermissionError::FailedDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveResourcePermissionError::FailedDependency,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RemoveResourcePermissionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveResourcePermissionError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
-                        RemoveResourcePermissionError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveResourcePermissionError::UnauthorizedOperation,
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        RemoveResourcePermissionError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveResourcePermissionError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4332,42 +5174,86 @@ impl UpdateDocumentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateDocumentError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentError::ConcurrentModification,
+                    )
                 }
                 "ConflictingOperationException" => {
-                    return RusotoError::Service(UpdateDocumentError::ConflictingOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentError::ConflictingOperation,
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateDocumentError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentError::EntityAlreadyExists,
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(UpdateDocumentError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(UpdateDocumentError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentError::FailedDependency,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDocumentError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentError::LimitExceeded,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(UpdateDocumentError::ProhibitedState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDocumentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateDocumentError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(UpdateDocumentError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4418,46 +5304,70 @@ impl UpdateDocumentVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        UpdateDocumentVersionError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentVersionError::ConcurrentModification,
                     )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(UpdateDocumentVersionError::EntityNotExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentVersionError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(UpdateDocumentVersionError::FailedDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentVersionError::FailedDependency,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(UpdateDocumentVersionError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentVersionError::InvalidOperation,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(UpdateDocumentVersionError::ProhibitedState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentVersionError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDocumentVersionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentVersionError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateDocumentVersionError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentVersionError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
-                        UpdateDocumentVersionError::UnauthorizedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentVersionError::UnauthorizedResourceAccess,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4512,38 +5422,86 @@ impl UpdateFolderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateFolderError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFolderError::ConcurrentModification,
+                    )
                 }
                 "ConflictingOperationException" => {
-                    return RusotoError::Service(UpdateFolderError::ConflictingOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFolderError::ConflictingOperation,
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateFolderError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFolderError::EntityAlreadyExists,
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(UpdateFolderError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFolderError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(UpdateFolderError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFolderError::FailedDependency,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFolderError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFolderError::LimitExceeded,
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(UpdateFolderError::ProhibitedState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFolderError::ProhibitedState,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFolderError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFolderError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateFolderError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFolderError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(UpdateFolderError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFolderError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4594,34 +5552,70 @@ impl UpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeactivatingLastSystemUserException" => {
-                    return RusotoError::Service(UpdateUserError::DeactivatingLastSystemUser(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserError::DeactivatingLastSystemUser,
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(UpdateUserError::EntityNotExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::EntityNotExists,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(UpdateUserError::FailedDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::FailedDependency,
+                    )
                 }
                 "IllegalUserStateException" => {
-                    return RusotoError::Service(UpdateUserError::IllegalUserState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::IllegalUserState,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateUserError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::InvalidArgument,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateUserError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::UnauthorizedOperation,
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(UpdateUserError::UnauthorizedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserError::UnauthorizedResourceAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4912,11 +5906,12 @@ pub struct WorkdocsClient {
 impl WorkdocsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> WorkdocsClient {
         WorkdocsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "WorkDocs"),
         }
     }
 
@@ -4931,12 +5926,32 @@ impl WorkdocsClient {
     {
         WorkdocsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "WorkDocs"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> WorkdocsClient {
-        WorkdocsClient { client, region }
+        WorkdocsClient {
+            client,
+            region: region_for_service(region, "WorkDocs"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> WorkdocsClient {
+        WorkdocsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("WorkDocs"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WorkdocsClient {
+        WorkdocsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4955,6 +5970,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("AbortDocumentVersionUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -4987,6 +6003,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("ActivateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5020,6 +6037,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("AddResourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5056,6 +6074,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateComment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5091,6 +6110,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateCustomMetadata");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5128,6 +6148,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/folders";
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateFolder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5163,6 +6184,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateLabels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5201,6 +6223,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateNotificationSubscription");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5232,6 +6255,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/users";
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5267,6 +6291,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeactivateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5301,6 +6326,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteComment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5333,6 +6359,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteCustomMetadata");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5379,6 +6406,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteDocument");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5408,6 +6436,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteFolder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5440,6 +6469,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteFolderContents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5472,6 +6502,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteLabels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5516,6 +6547,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteNotificationSubscription");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5543,6 +6575,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/users/{user_id}", user_id = input.user_id);
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5572,6 +6605,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/activities";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeActivities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5635,6 +6669,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeComments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5676,6 +6711,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeDocumentVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5723,6 +6759,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeFolderContents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5773,6 +6810,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/groups";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5821,6 +6859,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeNotificationSubscriptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5864,6 +6903,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeResourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5905,6 +6945,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/me/root";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeRootFolders");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("Authentication", &input.authentication_token.to_string());
@@ -5943,6 +6984,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/users";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6002,6 +7044,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/me";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetCurrentUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("Authentication", &input.authentication_token.to_string());
@@ -6035,6 +7078,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetDocument");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6073,6 +7117,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetDocumentPath");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6118,6 +7163,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetDocumentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6156,6 +7202,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetFolder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6194,6 +7241,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetFolderPath");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6235,6 +7283,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/resources";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6282,6 +7331,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/documents";
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("InitiateDocumentVersionUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6317,6 +7367,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("RemoveAllResourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6350,6 +7401,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("RemoveResourcePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6387,6 +7439,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateDocument");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6422,6 +7475,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6453,6 +7507,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateFolder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6484,6 +7539,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/users/{user_id}", user_id = input.user_id);
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6507,3 +7563,434 @@ impl Workdocs for WorkdocsClient {
         }
     }
 }
+
+/// A blocking client for the Amazon WorkDocs API, running the calls of a `WorkdocsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `WorkdocsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WorkdocsBlockingClient {
+    client: WorkdocsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WorkdocsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> WorkdocsBlockingClient {
+        WorkdocsBlockingClient::from_client(WorkdocsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorkdocsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WorkdocsBlockingClient::from_client(WorkdocsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> WorkdocsBlockingClient {
+        WorkdocsBlockingClient::from_client(WorkdocsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> WorkdocsBlockingClient {
+        WorkdocsBlockingClient::from_client(WorkdocsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: WorkdocsClient) -> WorkdocsBlockingClient {
+        WorkdocsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> WorkdocsBlockingClient {
+        WorkdocsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WorkdocsBlockingClient {
+        WorkdocsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Workdocs::abort_document_version_upload`].
+    pub fn abort_document_version_upload(
+        &self,
+        input: AbortDocumentVersionUploadRequest,
+    ) -> Result<(), RusotoError<AbortDocumentVersionUploadError>> {
+        self.runtime
+            .block_on(self.client.abort_document_version_upload(input))
+    }
+
+    /// Blocking version of [`Workdocs::activate_user`].
+    pub fn activate_user(
+        &self,
+        input: ActivateUserRequest,
+    ) -> Result<ActivateUserResponse, RusotoError<ActivateUserError>> {
+        self.runtime.block_on(self.client.activate_user(input))
+    }
+
+    /// Blocking version of [`Workdocs::add_resource_permissions`].
+    pub fn add_resource_permissions(
+        &self,
+        input: AddResourcePermissionsRequest,
+    ) -> Result<AddResourcePermissionsResponse, RusotoError<AddResourcePermissionsError>> {
+        self.runtime
+            .block_on(self.client.add_resource_permissions(input))
+    }
+
+    /// Blocking version of [`Workdocs::create_comment`].
+    pub fn create_comment(
+        &self,
+        input: CreateCommentRequest,
+    ) -> Result<CreateCommentResponse, RusotoError<CreateCommentError>> {
+        self.runtime.block_on(self.client.create_comment(input))
+    }
+
+    /// Blocking version of [`Workdocs::create_custom_metadata`].
+    pub fn create_custom_metadata(
+        &self,
+        input: CreateCustomMetadataRequest,
+    ) -> Result<CreateCustomMetadataResponse, RusotoError<CreateCustomMetadataError>> {
+        self.runtime
+            .block_on(self.client.create_custom_metadata(input))
+    }
+
+    /// Blocking version of [`Workdocs::create_folder`].
+    pub fn create_folder(
+        &self,
+        input: CreateFolderRequest,
+    ) -> Result<CreateFolderResponse, RusotoError<CreateFolderError>> {
+        self.runtime.block_on(self.client.create_folder(input))
+    }
+
+    /// Blocking version of [`Workdocs::create_labels`].
+    pub fn create_labels(
+        &self,
+        input: CreateLabelsRequest,
+    ) -> Result<CreateLabelsResponse, RusotoError<CreateLabelsError>> {
+        self.runtime.block_on(self.client.create_labels(input))
+    }
+
+    /// Blocking version of [`Workdocs::create_notification_subscription`].
+    pub fn create_notification_subscription(
+        &self,
+        input: CreateNotificationSubscriptionRequest,
+    ) -> Result<
+        CreateNotificationSubscriptionResponse,
+        RusotoError<CreateNotificationSubscriptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_notification_subscription(input))
+    }
+
+    /// Blocking version of [`Workdocs::create_user`].
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// Blocking version of [`Workdocs::deactivate_user`].
+    pub fn deactivate_user(
+        &self,
+        input: DeactivateUserRequest,
+    ) -> Result<(), RusotoError<DeactivateUserError>> {
+        self.runtime.block_on(self.client.deactivate_user(input))
+    }
+
+    /// Blocking version of [`Workdocs::delete_comment`].
+    pub fn delete_comment(
+        &self,
+        input: DeleteCommentRequest,
+    ) -> Result<(), RusotoError<DeleteCommentError>> {
+        self.runtime.block_on(self.client.delete_comment(input))
+    }
+
+    /// Blocking version of [`Workdocs::delete_custom_metadata`].
+    pub fn delete_custom_metadata(
+        &self,
+        input: DeleteCustomMetadataRequest,
+    ) -> Result<DeleteCustomMetadataResponse, RusotoError<DeleteCustomMetadataError>> {
+        self.runtime
+            .block_on(self.client.delete_custom_metadata(input))
+    }
+
+    /// Blocking version of [`Workdocs::delete_document`].
+    pub fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> Result<(), RusotoError<DeleteDocumentError>> {
+        self.runtime.block_on(self.client.delete_document(input))
+    }
+
+    /// Blocking version of [`Workdocs::delete_folder`].
+    pub fn delete_folder(
+        &self,
+        input: DeleteFolderRequest,
+    ) -> Result<(), RusotoError<DeleteFolderError>> {
+        self.runtime.block_on(self.client.delete_folder(input))
+    }
+
+    /// Blocking version of [`Workdocs::delete_folder_contents`].
+    pub fn delete_folder_contents(
+        &self,
+        input: DeleteFolderContentsRequest,
+    ) -> Result<(), RusotoError<DeleteFolderContentsError>> {
+        self.runtime
+            .block_on(self.client.delete_folder_contents(input))
+    }
+
+    /// Blocking version of [`Workdocs::delete_labels`].
+    pub fn delete_labels(
+        &self,
+        input: DeleteLabelsRequest,
+    ) -> Result<DeleteLabelsResponse, RusotoError<DeleteLabelsError>> {
+        self.runtime.block_on(self.client.delete_labels(input))
+    }
+
+    /// Blocking version of [`Workdocs::delete_notification_subscription`].
+    pub fn delete_notification_subscription(
+        &self,
+        input: DeleteNotificationSubscriptionRequest,
+    ) -> Result<(), RusotoError<DeleteNotificationSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_notification_subscription(input))
+    }
+
+    /// Blocking version of [`Workdocs::delete_user`].
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(), RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// Blocking version of [`Workdocs::describe_activities`].
+    pub fn describe_activities(
+        &self,
+        input: DescribeActivitiesRequest,
+    ) -> Result<DescribeActivitiesResponse, RusotoError<DescribeActivitiesError>> {
+        self.runtime
+            .block_on(self.client.describe_activities(input))
+    }
+
+    /// Blocking version of [`Workdocs::describe_comments`].
+    pub fn describe_comments(
+        &self,
+        input: DescribeCommentsRequest,
+    ) -> Result<DescribeCommentsResponse, RusotoError<DescribeCommentsError>> {
+        self.runtime.block_on(self.client.describe_comments(input))
+    }
+
+    /// Blocking version of [`Workdocs::describe_document_versions`].
+    pub fn describe_document_versions(
+        &self,
+        input: DescribeDocumentVersionsRequest,
+    ) -> Result<DescribeDocumentVersionsResponse, RusotoError<DescribeDocumentVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_document_versions(input))
+    }
+
+    /// Blocking version of [`Workdocs::describe_folder_contents`].
+    pub fn describe_folder_contents(
+        &self,
+        input: DescribeFolderContentsRequest,
+    ) -> Result<DescribeFolderContentsResponse, RusotoError<DescribeFolderContentsError>> {
+        self.runtime
+            .block_on(self.client.describe_folder_contents(input))
+    }
+
+    /// Blocking version of [`Workdocs::describe_groups`].
+    pub fn describe_groups(
+        &self,
+        input: DescribeGroupsRequest,
+    ) -> Result<DescribeGroupsResponse, RusotoError<DescribeGroupsError>> {
+        self.runtime.block_on(self.client.describe_groups(input))
+    }
+
+    /// Blocking version of [`Workdocs::describe_notification_subscriptions`].
+    pub fn describe_notification_subscriptions(
+        &self,
+        input: DescribeNotificationSubscriptionsRequest,
+    ) -> Result<
+        DescribeNotificationSubscriptionsResponse,
+        RusotoError<DescribeNotificationSubscriptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_notification_subscriptions(input))
+    }
+
+    /// Blocking version of [`Workdocs::describe_resource_permissions`].
+    pub fn describe_resource_permissions(
+        &self,
+        input: DescribeResourcePermissionsRequest,
+    ) -> Result<DescribeResourcePermissionsResponse, RusotoError<DescribeResourcePermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_resource_permissions(input))
+    }
+
+    /// Blocking version of [`Workdocs::describe_root_folders`].
+    pub fn describe_root_folders(
+        &self,
+        input: DescribeRootFoldersRequest,
+    ) -> Result<DescribeRootFoldersResponse, RusotoError<DescribeRootFoldersError>> {
+        self.runtime
+            .block_on(self.client.describe_root_folders(input))
+    }
+
+    /// Blocking version of [`Workdocs::describe_users`].
+    pub fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> Result<DescribeUsersResponse, RusotoError<DescribeUsersError>> {
+        self.runtime.block_on(self.client.describe_users(input))
+    }
+
+    /// Blocking version of [`Workdocs::get_current_user`].
+    pub fn get_current_user(
+        &self,
+        input: GetCurrentUserRequest,
+    ) -> Result<GetCurrentUserResponse, RusotoError<GetCurrentUserError>> {
+        self.runtime.block_on(self.client.get_current_user(input))
+    }
+
+    /// Blocking version of [`Workdocs::get_document`].
+    pub fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> Result<GetDocumentResponse, RusotoError<GetDocumentError>> {
+        self.runtime.block_on(self.client.get_document(input))
+    }
+
+    /// Blocking version of [`Workdocs::get_document_path`].
+    pub fn get_document_path(
+        &self,
+        input: GetDocumentPathRequest,
+    ) -> Result<GetDocumentPathResponse, RusotoError<GetDocumentPathError>> {
+        self.runtime.block_on(self.client.get_document_path(input))
+    }
+
+    /// Blocking version of [`Workdocs::get_document_version`].
+    pub fn get_document_version(
+        &self,
+        input: GetDocumentVersionRequest,
+    ) -> Result<GetDocumentVersionResponse, RusotoError<GetDocumentVersionError>> {
+        self.runtime
+            .block_on(self.client.get_document_version(input))
+    }
+
+    /// Blocking version of [`Workdocs::get_folder`].
+    pub fn get_folder(
+        &self,
+        input: GetFolderRequest,
+    ) -> Result<GetFolderResponse, RusotoError<GetFolderError>> {
+        self.runtime.block_on(self.client.get_folder(input))
+    }
+
+    /// Blocking version of [`Workdocs::get_folder_path`].
+    pub fn get_folder_path(
+        &self,
+        input: GetFolderPathRequest,
+    ) -> Result<GetFolderPathResponse, RusotoError<GetFolderPathError>> {
+        self.runtime.block_on(self.client.get_folder_path(input))
+    }
+
+    /// Blocking version of [`Workdocs::get_resources`].
+    pub fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> Result<GetResourcesResponse, RusotoError<GetResourcesError>> {
+        self.runtime.block_on(self.client.get_resources(input))
+    }
+
+    /// Blocking version of [`Workdocs::initiate_document_version_upload`].
+    pub fn initiate_document_version_upload(
+        &self,
+        input: InitiateDocumentVersionUploadRequest,
+    ) -> Result<
+        InitiateDocumentVersionUploadResponse,
+        RusotoError<InitiateDocumentVersionUploadError>,
+    > {
+        self.runtime
+            .block_on(self.client.initiate_document_version_upload(input))
+    }
+
+    /// Blocking version of [`Workdocs::remove_all_resource_permissions`].
+    pub fn remove_all_resource_permissions(
+        &self,
+        input: RemoveAllResourcePermissionsRequest,
+    ) -> Result<(), RusotoError<RemoveAllResourcePermissionsError>> {
+        self.runtime
+            .block_on(self.client.remove_all_resource_permissions(input))
+    }
+
+    /// Blocking version of [`Workdocs::remove_resource_permission`].
+    pub fn remove_resource_permission(
+        &self,
+        input: RemoveResourcePermissionRequest,
+    ) -> Result<(), RusotoError<RemoveResourcePermissionError>> {
+        self.runtime
+            .block_on(self.client.remove_resource_permission(input))
+    }
+
+    /// Blocking version of [`Workdocs::update_document`].
+    pub fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> Result<(), RusotoError<UpdateDocumentError>> {
+        self.runtime.block_on(self.client.update_document(input))
+    }
+
+    /// Blocking version of [`Workdocs::update_document_version`].
+    pub fn update_document_version(
+        &self,
+        input: UpdateDocumentVersionRequest,
+    ) -> Result<(), RusotoError<UpdateDocumentVersionError>> {
+        self.runtime
+            .block_on(self.client.update_document_version(input))
+    }
+
+    /// Blocking version of [`Workdocs::update_folder`].
+    pub fn update_folder(
+        &self,
+        input: UpdateFolderRequest,
+    ) -> Result<(), RusotoError<UpdateFolderError>> {
+        self.runtime.block_on(self.client.update_folder(input))
+    }
+
+    /// Blocking version of [`Workdocs::update_user`].
+    pub fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+        self.runtime.block_on(self.client.update_user(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -903,26 +904,54 @@ impl AssociateDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AssociateDomainError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDomainError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateDomainError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDomainError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(AssociateDomainError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDomainError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDomainError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AssociateDomainError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDomainError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(AssociateDomainError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDomainError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -967,36 +996,54 @@ impl AssociateWebsiteAuthorizationProviderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        AssociateWebsiteAuthorizationProviderError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebsiteAuthorizationProviderError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        AssociateWebsiteAuthorizationProviderError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebsiteAuthorizationProviderError::InvalidRequest,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        AssociateWebsiteAuthorizationProviderError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebsiteAuthorizationProviderError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateWebsiteAuthorizationProviderError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebsiteAuthorizationProviderError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        AssociateWebsiteAuthorizationProviderError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebsiteAuthorizationProviderError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        AssociateWebsiteAuthorizationProviderError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebsiteAuthorizationProviderError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1053,36 +1100,54 @@ impl AssociateWebsiteCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        AssociateWebsiteCertificateAuthorityError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebsiteCertificateAuthorityError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        AssociateWebsiteCertificateAuthorityError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebsiteCertificateAuthorityError::InvalidRequest,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        AssociateWebsiteCertificateAuthorityError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebsiteCertificateAuthorityError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateWebsiteCertificateAuthorityError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebsiteCertificateAuthorityError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        AssociateWebsiteCertificateAuthorityError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebsiteCertificateAuthorityError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        AssociateWebsiteCertificateAuthorityError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateWebsiteCertificateAuthorityError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1137,24 +1202,54 @@ impl CreateFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateFleetError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateFleetError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateFleetError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1195,21 +1290,46 @@ impl DeleteFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteFleetError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteFleetError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteFleetError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1251,31 +1371,46 @@ impl DescribeAuditStreamConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeAuditStreamConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditStreamConfigurationError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeAuditStreamConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditStreamConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeAuditStreamConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditStreamConfigurationError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeAuditStreamConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditStreamConfigurationError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        DescribeAuditStreamConfigurationError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditStreamConfigurationError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1327,31 +1462,46 @@ impl DescribeCompanyNetworkConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeCompanyNetworkConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCompanyNetworkConfigurationError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeCompanyNetworkConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCompanyNetworkConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeCompanyNetworkConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCompanyNetworkConfigurationError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeCompanyNetworkConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCompanyNetworkConfigurationError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        DescribeCompanyNetworkConfigurationError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCompanyNetworkConfigurationError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1401,21 +1551,46 @@ impl DescribeDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeDeviceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDeviceError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDeviceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDeviceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDeviceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDeviceError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeDeviceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDeviceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1457,31 +1632,46 @@ impl DescribeDevicePolicyConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeDevicePolicyConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDevicePolicyConfigurationError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeDevicePolicyConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDevicePolicyConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeDevicePolicyConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDevicePolicyConfigurationError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeDevicePolicyConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDevicePolicyConfigurationError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        DescribeDevicePolicyConfigurationError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDevicePolicyConfigurationError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1531,21 +1721,46 @@ impl DescribeDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeDomainError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDomainError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeDomainError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeDomainError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1585,29 +1800,46 @@ impl DescribeFleetMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeFleetMetadataError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetMetadataError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetMetadataError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetMetadataError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFleetMetadataError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetMetadataError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeFleetMetadataError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetMetadataError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetMetadataError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetMetadataError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1649,31 +1881,46 @@ impl DescribeIdentityProviderConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeIdentityProviderConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityProviderConfigurationError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeIdentityProviderConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityProviderConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeIdentityProviderConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityProviderConfigurationError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeIdentityProviderConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityProviderConfigurationError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        DescribeIdentityProviderConfigurationError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityProviderConfigurationError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1725,31 +1972,46 @@ impl DescribeWebsiteCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeWebsiteCertificateAuthorityError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWebsiteCertificateAuthorityError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeWebsiteCertificateAuthorityError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWebsiteCertificateAuthorityError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeWebsiteCertificateAuthorityError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWebsiteCertificateAuthorityError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeWebsiteCertificateAuthorityError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWebsiteCertificateAuthorityError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        DescribeWebsiteCertificateAuthorityError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWebsiteCertificateAuthorityError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1799,23 +2061,46 @@ impl DisassociateDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DisassociateDomainError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateDomainError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DisassociateDomainError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDomainError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDomainError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DisassociateDomainError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDomainError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DisassociateDomainError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDomainError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1859,38 +2144,54 @@ impl DisassociateWebsiteAuthorizationProviderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DisassociateWebsiteAuthorizationProviderError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateWebsiteAuthorizationProviderError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DisassociateWebsiteAuthorizationProviderError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateWebsiteAuthorizationProviderError::InvalidRequest,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        DisassociateWebsiteAuthorizationProviderError::ResourceAlreadyExists(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateWebsiteAuthorizationProviderError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateWebsiteAuthorizationProviderError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateWebsiteAuthorizationProviderError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DisassociateWebsiteAuthorizationProviderError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateWebsiteAuthorizationProviderError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        DisassociateWebsiteAuthorizationProviderError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateWebsiteAuthorizationProviderError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1945,31 +2246,46 @@ impl DisassociateWebsiteCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DisassociateWebsiteCertificateAuthorityError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateWebsiteCertificateAuthorityError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DisassociateWebsiteCertificateAuthorityError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateWebsiteCertificateAuthorityError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateWebsiteCertificateAuthorityError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateWebsiteCertificateAuthorityError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DisassociateWebsiteCertificateAuthorityError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateWebsiteCertificateAuthorityError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        DisassociateWebsiteCertificateAuthorityError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateWebsiteCertificateAuthorityError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2019,21 +2335,46 @@ impl ListDevicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListDevicesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDevicesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDevicesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDevicesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDevicesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2073,21 +2414,46 @@ impl ListDomainsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListDomainsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainsError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDomainsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDomainsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainsError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDomainsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainsError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDomainsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2125,18 +2491,38 @@ impl ListFleetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListFleetsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFleetsError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListFleetsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFleetsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListFleetsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFleetsError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListFleetsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFleetsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2167,9 +2553,14 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2207,31 +2598,46 @@ impl ListWebsiteAuthorizationProvidersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        ListWebsiteAuthorizationProvidersError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebsiteAuthorizationProvidersError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListWebsiteAuthorizationProvidersError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebsiteAuthorizationProvidersError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListWebsiteAuthorizationProvidersError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebsiteAuthorizationProvidersError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListWebsiteAuthorizationProvidersError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebsiteAuthorizationProvidersError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        ListWebsiteAuthorizationProvidersError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebsiteAuthorizationProvidersError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2281,26 +2687,38 @@ impl ListWebsiteCertificateAuthoritiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        ListWebsiteCertificateAuthoritiesError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebsiteCertificateAuthoritiesError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListWebsiteCertificateAuthoritiesError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebsiteCertificateAuthoritiesError::InvalidRequest,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListWebsiteCertificateAuthoritiesError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebsiteCertificateAuthoritiesError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        ListWebsiteCertificateAuthoritiesError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebsiteCertificateAuthoritiesError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2347,25 +2765,46 @@ impl RestoreDomainAccessError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RestoreDomainAccessError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreDomainAccessError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RestoreDomainAccessError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreDomainAccessError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreDomainAccessError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreDomainAccessError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RestoreDomainAccessError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreDomainAccessError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RestoreDomainAccessError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreDomainAccessError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2405,23 +2844,46 @@ impl RevokeDomainAccessError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RevokeDomainAccessError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokeDomainAccessError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RevokeDomainAccessError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeDomainAccessError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RevokeDomainAccessError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeDomainAccessError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RevokeDomainAccessError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeDomainAccessError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RevokeDomainAccessError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeDomainAccessError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2461,21 +2923,46 @@ impl SignOutUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(SignOutUserError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignOutUserError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SignOutUserError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignOutUserError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SignOutUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignOutUserError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SignOutUserError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignOutUserError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SignOutUserError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignOutUserError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2507,9 +2994,14 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2537,9 +3029,14 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2577,31 +3074,46 @@ impl UpdateAuditStreamConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        UpdateAuditStreamConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuditStreamConfigurationError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        UpdateAuditStreamConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuditStreamConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateAuditStreamConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuditStreamConfigurationError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        UpdateAuditStreamConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuditStreamConfigurationError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAuditStreamConfigurationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAuditStreamConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2651,31 +3163,46 @@ impl UpdateCompanyNetworkConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        UpdateCompanyNetworkConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCompanyNetworkConfigurationError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        UpdateCompanyNetworkConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCompanyNetworkConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateCompanyNetworkConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCompanyNetworkConfigurationError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        UpdateCompanyNetworkConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCompanyNetworkConfigurationError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        UpdateCompanyNetworkConfigurationError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCompanyNetworkConfigurationError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2727,31 +3254,46 @@ impl UpdateDevicePolicyConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        UpdateDevicePolicyConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevicePolicyConfigurationError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        UpdateDevicePolicyConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevicePolicyConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateDevicePolicyConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevicePolicyConfigurationError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        UpdateDevicePolicyConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevicePolicyConfigurationError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        UpdateDevicePolicyConfigurationError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevicePolicyConfigurationError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2799,27 +3341,46 @@ impl UpdateDomainMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateDomainMetadataError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainMetadataError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDomainMetadataError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainMetadataError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDomainMetadataError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainMetadataError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDomainMetadataError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainMetadataError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDomainMetadataError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainMetadataError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2859,25 +3420,46 @@ impl UpdateFleetMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFleetMetadataError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFleetMetadataError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFleetMetadataError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetMetadataError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFleetMetadataError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFleetMetadataError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFleetMetadataError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetMetadataError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFleetMetadataError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetMetadataError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2919,31 +3501,46 @@ impl UpdateIdentityProviderConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        UpdateIdentityProviderConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityProviderConfigurationError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        UpdateIdentityProviderConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityProviderConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateIdentityProviderConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityProviderConfigurationError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        UpdateIdentityProviderConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityProviderConfigurationError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        UpdateIdentityProviderConfigurationError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityProviderConfigurationError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3229,11 +3826,12 @@ pub struct WorklinkClient {
 impl WorklinkClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> WorklinkClient {
         WorklinkClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "WorkLink"),
         }
     }
 
@@ -3248,12 +3846,32 @@ impl WorklinkClient {
     {
         WorklinkClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "WorkLink"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> WorklinkClient {
-        WorklinkClient { client, region }
+        WorklinkClient {
+            client,
+            region: region_for_service(region, "WorkLink"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> WorklinkClient {
+        WorklinkClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("WorkLink"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WorklinkClient {
+        WorklinkClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3268,6 +3886,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/associateDomain";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("AssociateDomain");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3302,6 +3921,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/associateWebsiteAuthorizationProvider";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("AssociateWebsiteAuthorizationProvider");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3338,6 +3958,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/associateWebsiteCertificateAuthority";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("AssociateWebsiteCertificateAuthority");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3371,6 +3992,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/createFleet";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("CreateFleet");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3402,6 +4024,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/deleteFleet";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DeleteFleet");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3436,6 +4059,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeAuditStreamConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeAuditStreamConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3472,6 +4096,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeCompanyNetworkConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeCompanyNetworkConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3505,6 +4130,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeDevice";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeDevice");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3539,6 +4165,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeDevicePolicyConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeDevicePolicyConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3572,6 +4199,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeDomain";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeDomain");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3603,6 +4231,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeFleetMetadata";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeFleetMetadata");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3637,6 +4266,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeIdentityProviderConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityProviderConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3673,6 +4303,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeWebsiteCertificateAuthority";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeWebsiteCertificateAuthority");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3706,6 +4337,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/disassociateDomain";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DisassociateDomain");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3740,6 +4372,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/disassociateWebsiteAuthorizationProvider";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DisassociateWebsiteAuthorizationProvider");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3774,6 +4407,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/disassociateWebsiteCertificateAuthority";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DisassociateWebsiteCertificateAuthority");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3807,6 +4441,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listDevices";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListDevices");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3838,6 +4473,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listDomains";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListDomains");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3869,6 +4505,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listFleets";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListFleets");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3900,6 +4537,7 @@ impl Worklink for WorklinkClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "worklink", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/json".to_owned());
 
         let mut response = self
@@ -3931,6 +4569,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listWebsiteAuthorizationProviders";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListWebsiteAuthorizationProviders");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3967,6 +4606,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listWebsiteCertificateAuthorities";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListWebsiteCertificateAuthorities");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4000,6 +4640,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/restoreDomainAccess";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("RestoreDomainAccess");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4031,6 +4672,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/revokeDomainAccess";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("RevokeDomainAccess");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4062,6 +4704,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/signOutUser";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("SignOutUser");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4093,6 +4736,7 @@ impl Worklink for WorklinkClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4124,6 +4768,7 @@ impl Worklink for WorklinkClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "worklink", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/json".to_owned());
 
         let mut params = Params::new();
@@ -4161,6 +4806,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateAuditStreamConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateAuditStreamConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4195,6 +4841,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateCompanyNetworkConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateCompanyNetworkConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4231,6 +4878,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateDevicePolicyConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateDevicePolicyConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4264,6 +4912,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateDomainMetadata";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateDomainMetadata");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4295,6 +4944,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/UpdateFleetMetadata";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateFleetMetadata");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4329,6 +4979,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateIdentityProviderConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateIdentityProviderConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4353,3 +5004,413 @@ impl Worklink for WorklinkClient {
         }
     }
 }
+
+/// A blocking client for the WorkLink API, running the calls of a `WorklinkClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `WorklinkClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WorklinkBlockingClient {
+    client: WorklinkClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WorklinkBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> WorklinkBlockingClient {
+        WorklinkBlockingClient::from_client(WorklinkClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorklinkBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WorklinkBlockingClient::from_client(WorklinkClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> WorklinkBlockingClient {
+        WorklinkBlockingClient::from_client(WorklinkClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> WorklinkBlockingClient {
+        WorklinkBlockingClient::from_client(WorklinkClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: WorklinkClient) -> WorklinkBlockingClient {
+        WorklinkBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> WorklinkBlockingClient {
+        WorklinkBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WorklinkBlockingClient {
+        WorklinkBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Worklink::associate_domain`].
+    pub fn associate_domain(
+        &self,
+        input: AssociateDomainRequest,
+    ) -> Result<AssociateDomainResponse, RusotoError<AssociateDomainError>> {
+        self.runtime.block_on(self.client.associate_domain(input))
+    }
+
+    /// Blocking version of [`Worklink::associate_website_authorization_provider`].
+    pub fn associate_website_authorization_provider(
+        &self,
+        input: AssociateWebsiteAuthorizationProviderRequest,
+    ) -> Result<
+        AssociateWebsiteAuthorizationProviderResponse,
+        RusotoError<AssociateWebsiteAuthorizationProviderError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_website_authorization_provider(input))
+    }
+
+    /// Blocking version of [`Worklink::associate_website_certificate_authority`].
+    pub fn associate_website_certificate_authority(
+        &self,
+        input: AssociateWebsiteCertificateAuthorityRequest,
+    ) -> Result<
+        AssociateWebsiteCertificateAuthorityResponse,
+        RusotoError<AssociateWebsiteCertificateAuthorityError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_website_certificate_authority(input))
+    }
+
+    /// Blocking version of [`Worklink::create_fleet`].
+    pub fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> Result<CreateFleetResponse, RusotoError<CreateFleetError>> {
+        self.runtime.block_on(self.client.create_fleet(input))
+    }
+
+    /// Blocking version of [`Worklink::delete_fleet`].
+    pub fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> Result<DeleteFleetResponse, RusotoError<DeleteFleetError>> {
+        self.runtime.block_on(self.client.delete_fleet(input))
+    }
+
+    /// Blocking version of [`Worklink::describe_audit_stream_configuration`].
+    pub fn describe_audit_stream_configuration(
+        &self,
+        input: DescribeAuditStreamConfigurationRequest,
+    ) -> Result<
+        DescribeAuditStreamConfigurationResponse,
+        RusotoError<DescribeAuditStreamConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_audit_stream_configuration(input))
+    }
+
+    /// Blocking version of [`Worklink::describe_company_network_configuration`].
+    pub fn describe_company_network_configuration(
+        &self,
+        input: DescribeCompanyNetworkConfigurationRequest,
+    ) -> Result<
+        DescribeCompanyNetworkConfigurationResponse,
+        RusotoError<DescribeCompanyNetworkConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_company_network_configuration(input))
+    }
+
+    /// Blocking version of [`Worklink::describe_device`].
+    pub fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> Result<DescribeDeviceResponse, RusotoError<DescribeDeviceError>> {
+        self.runtime.block_on(self.client.describe_device(input))
+    }
+
+    /// Blocking version of [`Worklink::describe_device_policy_configuration`].
+    pub fn describe_device_policy_configuration(
+        &self,
+        input: DescribeDevicePolicyConfigurationRequest,
+    ) -> Result<
+        DescribeDevicePolicyConfigurationResponse,
+        RusotoError<DescribeDevicePolicyConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_device_policy_configuration(input))
+    }
+
+    /// Blocking version of [`Worklink::describe_domain`].
+    pub fn describe_domain(
+        &self,
+        input: DescribeDomainRequest,
+    ) -> Result<DescribeDomainResponse, RusotoError<DescribeDomainError>> {
+        self.runtime.block_on(self.client.describe_domain(input))
+    }
+
+    /// Blocking version of [`Worklink::describe_fleet_metadata`].
+    pub fn describe_fleet_metadata(
+        &self,
+        input: DescribeFleetMetadataRequest,
+    ) -> Result<DescribeFleetMetadataResponse, RusotoError<DescribeFleetMetadataError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_metadata(input))
+    }
+
+    /// Blocking version of [`Worklink::describe_identity_provider_configuration`].
+    pub fn describe_identity_provider_configuration(
+        &self,
+        input: DescribeIdentityProviderConfigurationRequest,
+    ) -> Result<
+        DescribeIdentityProviderConfigurationResponse,
+        RusotoError<DescribeIdentityProviderConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_identity_provider_configuration(input))
+    }
+
+    /// Blocking version of [`Worklink::describe_website_certificate_authority`].
+    pub fn describe_website_certificate_authority(
+        &self,
+        input: DescribeWebsiteCertificateAuthorityRequest,
+    ) -> Result<
+        DescribeWebsiteCertificateAuthorityResponse,
+        RusotoError<DescribeWebsiteCertificateAuthorityError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_website_certificate_authority(input))
+    }
+
+    /// Blocking version of [`Worklink::disassociate_domain`].
+    pub fn disassociate_domain(
+        &self,
+        input: DisassociateDomainRequest,
+    ) -> Result<DisassociateDomainResponse, RusotoError<DisassociateDomainError>> {
+        self.runtime
+            .block_on(self.client.disassociate_domain(input))
+    }
+
+    /// Blocking version of [`Worklink::disassociate_website_authorization_provider`].
+    pub fn disassociate_website_authorization_provider(
+        &self,
+        input: DisassociateWebsiteAuthorizationProviderRequest,
+    ) -> Result<
+        DisassociateWebsiteAuthorizationProviderResponse,
+        RusotoError<DisassociateWebsiteAuthorizationProviderError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_website_authorization_provider(input),
+        )
+    }
+
+    /// Blocking version of [`Worklink::disassociate_website_certificate_authority`].
+    pub fn disassociate_website_certificate_authority(
+        &self,
+        input: DisassociateWebsiteCertificateAuthorityRequest,
+    ) -> Result<
+        DisassociateWebsiteCertificateAuthorityResponse,
+        RusotoError<DisassociateWebsiteCertificateAuthorityError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_website_certificate_authority(input),
+        )
+    }
+
+    /// Blocking version of [`Worklink::list_devices`].
+    pub fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
+        self.runtime.block_on(self.client.list_devices(input))
+    }
+
+    /// Blocking version of [`Worklink::list_domains`].
+    pub fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
+        self.runtime.block_on(self.client.list_domains(input))
+    }
+
+    /// Blocking version of [`Worklink::list_fleets`].
+    pub fn list_fleets(
+        &self,
+        input: ListFleetsRequest,
+    ) -> Result<ListFleetsResponse, RusotoError<ListFleetsError>> {
+        self.runtime.block_on(self.client.list_fleets(input))
+    }
+
+    /// Blocking version of [`Worklink::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Worklink::list_website_authorization_providers`].
+    pub fn list_website_authorization_providers(
+        &self,
+        input: ListWebsiteAuthorizationProvidersRequest,
+    ) -> Result<
+        ListWebsiteAuthorizationProvidersResponse,
+        RusotoError<ListWebsiteAuthorizationProvidersError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_website_authorization_providers(input))
+    }
+
+    /// Blocking version of [`Worklink::list_website_certificate_authorities`].
+    pub fn list_website_certificate_authorities(
+        &self,
+        input: ListWebsiteCertificateAuthoritiesRequest,
+    ) -> Result<
+        ListWebsiteCertificateAuthoritiesResponse,
+        RusotoError<ListWebsiteCertificateAuthoritiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_website_certificate_authorities(input))
+    }
+
+    /// Blocking version of [`Worklink::restore_domain_access`].
+    pub fn restore_domain_access(
+        &self,
+        input: RestoreDomainAccessRequest,
+    ) -> Result<RestoreDomainAccessResponse, RusotoError<RestoreDomainAccessError>> {
+        self.runtime
+            .block_on(self.client.restore_domain_access(input))
+    }
+
+    /// Blocking version of [`Worklink::revoke_domain_access`].
+    pub fn revoke_domain_access(
+        &self,
+        input: RevokeDomainAccessRequest,
+    ) -> Result<RevokeDomainAccessResponse, RusotoError<RevokeDomainAccessError>> {
+        self.runtime
+            .block_on(self.client.revoke_domain_access(input))
+    }
+
+    /// Blocking version of [`Worklink::sign_out_user`].
+    pub fn sign_out_user(
+        &self,
+        input: SignOutUserRequest,
+    ) -> Result<SignOutUserResponse, RusotoError<SignOutUserError>> {
+        self.runtime.block_on(self.client.sign_out_user(input))
+    }
+
+    /// Blocking version of [`Worklink::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Worklink::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Worklink::update_audit_stream_configuration`].
+    pub fn update_audit_stream_configuration(
+        &self,
+        input: UpdateAuditStreamConfigurationRequest,
+    ) -> Result<
+        UpdateAuditStreamConfigurationResponse,
+        RusotoError<UpdateAuditStreamConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_audit_stream_configuration(input))
+    }
+
+    /// Blocking version of [`Worklink::update_company_network_configuration`].
+    pub fn update_company_network_configuration(
+        &self,
+        input: UpdateCompanyNetworkConfigurationRequest,
+    ) -> Result<
+        UpdateCompanyNetworkConfigurationResponse,
+        RusotoError<UpdateCompanyNetworkConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_company_network_configuration(input))
+    }
+
+    /// Blocking version of [`Worklink::update_device_policy_configuration`].
+    pub fn update_device_policy_configuration(
+        &self,
+        input: UpdateDevicePolicyConfigurationRequest,
+    ) -> Result<
+        UpdateDevicePolicyConfigurationResponse,
+        RusotoError<UpdateDevicePolicyConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_device_policy_configuration(input))
+    }
+
+    /// Blocking version of [`Worklink::update_domain_metadata`].
+    pub fn update_domain_metadata(
+        &self,
+        input: UpdateDomainMetadataRequest,
+    ) -> Result<UpdateDomainMetadataResponse, RusotoError<UpdateDomainMetadataError>> {
+        self.runtime
+            .block_on(self.client.update_domain_metadata(input))
+    }
+
+    /// Blocking version of [`Worklink::update_fleet_metadata`].
+    pub fn update_fleet_metadata(
+        &self,
+        input: UpdateFleetMetadataRequest,
+    ) -> Result<UpdateFleetMetadataResponse, RusotoError<UpdateFleetMetadataError>> {
+        self.runtime
+            .block_on(self.client.update_fleet_metadata(input))
+    }
+
+    /// Blocking version of [`Worklink::update_identity_provider_configuration`].
+    pub fn update_identity_provider_configuration(
+        &self,
+        input: UpdateIdentityProviderConfigurationRequest,
+    ) -> Result<
+        UpdateIdentityProviderConfigurationResponse,
+        RusotoError<UpdateIdentityProviderConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_identity_provider_configuration(input))
+    }
+}
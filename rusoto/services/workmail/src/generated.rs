@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1666,31 +1667,46 @@ impl AssociateDelegateToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(AssociateDelegateToResourceError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDelegateToResourceError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(AssociateDelegateToResourceError::EntityState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDelegateToResourceError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        AssociateDelegateToResourceError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDelegateToResourceError::InvalidParameter,
                     )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateDelegateToResourceError::OrganizationNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDelegateToResourceError::OrganizationNotFound,
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(
-                        AssociateDelegateToResourceError::OrganizationState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDelegateToResourceError::OrganizationState,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1740,44 +1756,70 @@ impl AssociateMemberToGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
-                        AssociateMemberToGroupError::DirectoryServiceAuthenticationFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateMemberToGroupError::DirectoryServiceAuthenticationFailed,
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::DirectoryUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateMemberToGroupError::DirectoryUnavailable,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateMemberToGroupError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateMemberToGroupError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateMemberToGroupError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateMemberToGroupError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateMemberToGroupError::OrganizationState,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateMemberToGroupError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1820,26 +1862,38 @@ impl CancelMailboxExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CancelMailboxExportJobError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelMailboxExportJobError::EntityNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelMailboxExportJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelMailboxExportJobError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(CancelMailboxExportJobError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelMailboxExportJobError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(CancelMailboxExportJobError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelMailboxExportJobError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1886,33 +1940,78 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EmailAddressInUseException" => {
-                    return RusotoError::Service(CreateAliasError::EmailAddressInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::EmailAddressInUse,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreateAliasError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(CreateAliasError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::LimitExceeded,
+                    )
                 }
                 "MailDomainNotFoundException" => {
-                    return RusotoError::Service(CreateAliasError::MailDomainNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::MailDomainNotFound,
+                    )
                 }
                 "MailDomainStateException" => {
-                    return RusotoError::Service(CreateAliasError::MailDomainState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::MailDomainState,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(CreateAliasError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(CreateAliasError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1962,32 +2061,70 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
-                        CreateGroupError::DirectoryServiceAuthenticationFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::DirectoryServiceAuthenticationFailed,
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(CreateGroupError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::DirectoryUnavailable,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::InvalidParameter,
+                    )
                 }
                 "NameAvailabilityException" => {
-                    return RusotoError::Service(CreateGroupError::NameAvailability(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::NameAvailability,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(CreateGroupError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(CreateGroupError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::OrganizationState,
+                    )
                 }
                 "ReservedNameException" => {
-                    return RusotoError::Service(CreateGroupError::ReservedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::ReservedName,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateGroupError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2032,23 +2169,46 @@ impl CreateOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryInUseException" => {
-                    return RusotoError::Service(CreateOrganizationError::DirectoryInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationError::DirectoryInUse,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(CreateOrganizationError::DirectoryUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOrganizationError::DirectoryUnavailable,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateOrganizationError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateOrganizationError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationError::LimitExceeded,
+                    )
                 }
                 "NameAvailabilityException" => {
-                    return RusotoError::Service(CreateOrganizationError::NameAvailability(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationError::NameAvailability,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2092,29 +2252,62 @@ impl CreateResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
-                        CreateResourceError::DirectoryServiceAuthenticationFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::DirectoryServiceAuthenticationFailed,
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(CreateResourceError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::DirectoryUnavailable,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::InvalidParameter,
+                    )
                 }
                 "NameAvailabilityException" => {
-                    return RusotoError::Service(CreateResourceError::NameAvailability(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::NameAvailability,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(CreateResourceError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(CreateResourceError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::OrganizationState,
+                    )
                 }
                 "ReservedNameException" => {
-                    return RusotoError::Service(CreateResourceError::ReservedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::ReservedName,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2166,35 +2359,78 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
-                        CreateUserError::DirectoryServiceAuthenticationFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::DirectoryServiceAuthenticationFailed,
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(CreateUserError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::DirectoryUnavailable,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::InvalidParameter,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(CreateUserError::InvalidPassword(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::InvalidPassword,
+                    )
                 }
                 "NameAvailabilityException" => {
-                    return RusotoError::Service(CreateUserError::NameAvailability(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::NameAvailability,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(CreateUserError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(CreateUserError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::OrganizationState,
+                    )
                 }
                 "ReservedNameException" => {
-                    return RusotoError::Service(CreateUserError::ReservedName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::ReservedName,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateUserError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2234,16 +2470,22 @@ impl DeleteAccessControlRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteAccessControlRuleError::OrganizationNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccessControlRuleError::OrganizationNotFound,
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteAccessControlRuleError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAccessControlRuleError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2280,21 +2522,46 @@ impl DeleteAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteAliasError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(DeleteAliasError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteAliasError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeleteAliasError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteAliasError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2338,29 +2605,62 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
-                        DeleteGroupError::DirectoryServiceAuthenticationFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::DirectoryServiceAuthenticationFailed,
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(DeleteGroupError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::DirectoryUnavailable,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(DeleteGroupError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeleteGroupError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteGroupError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::OrganizationState,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteGroupError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2404,31 +2704,46 @@ impl DeleteMailboxPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteMailboxPermissionsError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMailboxPermissionsError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(DeleteMailboxPermissionsError::EntityState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMailboxPermissionsError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteMailboxPermissionsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMailboxPermissionsError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteMailboxPermissionsError::OrganizationNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMailboxPermissionsError::OrganizationNotFound,
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteMailboxPermissionsError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMailboxPermissionsError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2466,19 +2781,30 @@ impl DeleteOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteOrganizationError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeleteOrganizationError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteOrganizationError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteOrganizationError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteOrganizationError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2514,18 +2840,38 @@ impl DeleteResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityStateException" => {
-                    return RusotoError::Service(DeleteResourceError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeleteResourceError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteResourceError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2560,21 +2906,30 @@ impl DeleteRetentionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRetentionPolicyError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRetentionPolicyError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRetentionPolicyError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2616,29 +2971,62 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
-                        DeleteUserError::DirectoryServiceAuthenticationFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::DirectoryServiceAuthenticationFailed,
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(DeleteUserError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::DirectoryUnavailable,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(DeleteUserError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteUserError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::OrganizationState,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteUserError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2682,29 +3070,46 @@ impl DeregisterFromWorkMailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeregisterFromWorkMailError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterFromWorkMailError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(DeregisterFromWorkMailError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterFromWorkMailError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterFromWorkMailError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterFromWorkMailError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeregisterFromWorkMailError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterFromWorkMailError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeregisterFromWorkMailError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterFromWorkMailError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2742,18 +3147,38 @@ impl DescribeGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DescribeGroupError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupError::EntityNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DescribeGroupError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DescribeGroupError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2790,26 +3215,38 @@ impl DescribeMailboxExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DescribeMailboxExportJobError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMailboxExportJobError::EntityNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeMailboxExportJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMailboxExportJobError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeMailboxExportJobError::OrganizationNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMailboxExportJobError::OrganizationNotFound,
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DescribeMailboxExportJobError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMailboxExportJobError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2844,16 +3281,22 @@ impl DescribeOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeOrganizationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeOrganizationError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DescribeOrganizationError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeOrganizationError::OrganizationNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2888,20 +3331,38 @@ impl DescribeResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DescribeResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourceError::EntityNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourceError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DescribeResourceError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeResourceError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DescribeResourceError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourceError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2938,18 +3399,38 @@ impl DescribeUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::EntityNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DescribeUserError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2990,31 +3471,46 @@ impl DisassociateDelegateFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateDelegateFromResourceError::EntityNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDelegateFromResourceError::EntityNotFound,
                     )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(
-                        DisassociateDelegateFromResourceError::EntityState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDelegateFromResourceError::EntityState,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DisassociateDelegateFromResourceError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDelegateFromResourceError::InvalidParameter,
                     )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateDelegateFromResourceError::OrganizationNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDelegateFromResourceError::OrganizationNotFound,
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(
-                        DisassociateDelegateFromResourceError::OrganizationState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDelegateFromResourceError::OrganizationState,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3070,48 +3566,70 @@ impl DisassociateMemberFromGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
-                        DisassociateMemberFromGroupError::DirectoryServiceAuthenticationFailed(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateMemberFromGroupError::DirectoryServiceAuthenticationFailed,
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
-                        DisassociateMemberFromGroupError::DirectoryUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateMemberFromGroupError::DirectoryUnavailable,
                     )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DisassociateMemberFromGroupError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateMemberFromGroupError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(DisassociateMemberFromGroupError::EntityState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateMemberFromGroupError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DisassociateMemberFromGroupError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateMemberFromGroupError::InvalidParameter,
                     )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateMemberFromGroupError::OrganizationNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateMemberFromGroupError::OrganizationNotFound,
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(
-                        DisassociateMemberFromGroupError::OrganizationState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateMemberFromGroupError::OrganizationState,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DisassociateMemberFromGroupError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateMemberFromGroupError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3162,26 +3680,38 @@ impl GetAccessControlEffectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetAccessControlEffectError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAccessControlEffectError::EntityNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAccessControlEffectError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAccessControlEffectError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(GetAccessControlEffectError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAccessControlEffectError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(GetAccessControlEffectError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAccessControlEffectError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3218,26 +3748,38 @@ impl GetDefaultRetentionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDefaultRetentionPolicyError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDefaultRetentionPolicyError::EntityNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDefaultRetentionPolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDefaultRetentionPolicyError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
-                        GetDefaultRetentionPolicyError::OrganizationNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDefaultRetentionPolicyError::OrganizationNotFound,
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(GetDefaultRetentionPolicyError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDefaultRetentionPolicyError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3274,17 +3816,30 @@ impl GetMailboxDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMailboxDetailsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMailboxDetailsError::EntityNotFound,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(GetMailboxDetailsError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMailboxDetailsError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(GetMailboxDetailsError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMailboxDetailsError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3316,16 +3871,22 @@ impl ListAccessControlRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListAccessControlRulesError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAccessControlRulesError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListAccessControlRulesError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAccessControlRulesError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3362,21 +3923,46 @@ impl ListAliasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListAliasesError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(ListAliasesError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListAliasesError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListAliasesError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3416,23 +4002,46 @@ impl ListGroupMembersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListGroupMembersError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupMembersError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(ListGroupMembersError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupMembersError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListGroupMembersError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupMembersError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListGroupMembersError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGroupMembersError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListGroupMembersError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupMembersError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3470,18 +4079,38 @@ impl ListGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListGroupsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::EntityNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListGroupsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListGroupsError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListGroupsError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3516,21 +4145,30 @@ impl ListMailboxExportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListMailboxExportJobsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMailboxExportJobsError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListMailboxExportJobsError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMailboxExportJobsError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListMailboxExportJobsError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMailboxExportJobsError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3566,26 +4204,38 @@ impl ListMailboxPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListMailboxPermissionsError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMailboxPermissionsError::EntityNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListMailboxPermissionsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMailboxPermissionsError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListMailboxPermissionsError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMailboxPermissionsError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListMailboxPermissionsError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMailboxPermissionsError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3616,9 +4266,14 @@ impl ListOrganizationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListOrganizationsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOrganizationsError::InvalidParameter,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3654,29 +4309,46 @@ impl ListResourceDelegatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListResourceDelegatesError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceDelegatesError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(ListResourceDelegatesError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceDelegatesError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResourceDelegatesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceDelegatesError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListResourceDelegatesError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceDelegatesError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListResourceDelegatesError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceDelegatesError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3712,15 +4384,30 @@ impl ListResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListResourcesError::OrganizationNotFound(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::OrganizationNotFound,
+                    )
+                }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListResourcesError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3750,11 +4437,14 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3786,15 +4476,30 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUsersError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListUsersError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3832,27 +4537,46 @@ impl PutAccessControlRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(PutAccessControlRuleError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAccessControlRuleError::EntityNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAccessControlRuleError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAccessControlRuleError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutAccessControlRuleError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAccessControlRuleError::LimitExceeded,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(PutAccessControlRuleError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAccessControlRuleError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(PutAccessControlRuleError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAccessControlRuleError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3892,29 +4616,46 @@ impl PutMailboxPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(PutMailboxPermissionsError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutMailboxPermissionsError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(PutMailboxPermissionsError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMailboxPermissionsError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutMailboxPermissionsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutMailboxPermissionsError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(PutMailboxPermissionsError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutMailboxPermissionsError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(PutMailboxPermissionsError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutMailboxPermissionsError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3952,22 +4693,38 @@ impl PutRetentionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRetentionPolicyError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRetentionPolicyError::LimitExceeded,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRetentionPolicyError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRetentionPolicyError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4018,53 +4775,94 @@ impl RegisterToWorkMailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
-                        RegisterToWorkMailError::DirectoryServiceAuthenticationFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterToWorkMailError::DirectoryServiceAuthenticationFailed,
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::DirectoryUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterToWorkMailError::DirectoryUnavailable,
+                    )
                 }
                 "EmailAddressInUseException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::EmailAddressInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterToWorkMailError::EmailAddressInUse,
+                    )
                 }
                 "EntityAlreadyRegisteredException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::EntityAlreadyRegistered(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterToWorkMailError::EntityAlreadyRegistered,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterToWorkMailError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterToWorkMailError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterToWorkMailError::InvalidParameter,
+                    )
                 }
                 "MailDomainNotFoundException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::MailDomainNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterToWorkMailError::MailDomainNotFound,
+                    )
                 }
                 "MailDomainStateException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::MailDomainState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterToWorkMailError::MailDomainState,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterToWorkMailError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterToWorkMailError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4120,35 +4918,78 @@ impl ResetPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
-                        ResetPasswordError::DirectoryServiceAuthenticationFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPasswordError::DirectoryServiceAuthenticationFailed,
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(ResetPasswordError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPasswordError::DirectoryUnavailable,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ResetPasswordError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPasswordError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(ResetPasswordError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPasswordError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ResetPasswordError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPasswordError::InvalidParameter,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ResetPasswordError::InvalidPassword(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPasswordError::InvalidPassword,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ResetPasswordError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPasswordError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ResetPasswordError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPasswordError::OrganizationState,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ResetPasswordError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPasswordError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4194,29 +5035,46 @@ impl StartMailboxExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartMailboxExportJobError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMailboxExportJobError::EntityNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartMailboxExportJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMailboxExportJobError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartMailboxExportJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMailboxExportJobError::LimitExceeded,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(StartMailboxExportJobError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMailboxExportJobError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(StartMailboxExportJobError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMailboxExportJobError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4252,15 +5110,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OrganizationStateException" => {
-                    return RusotoError::Service(TagResourceError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::OrganizationState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4290,9 +5163,14 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4328,25 +5206,46 @@ impl UpdateMailboxQuotaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateMailboxQuotaError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMailboxQuotaError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(UpdateMailboxQuotaError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMailboxQuotaError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateMailboxQuotaError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMailboxQuotaError::InvalidParameter,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(UpdateMailboxQuotaError::OrganizationNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMailboxQuotaError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(UpdateMailboxQuotaError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMailboxQuotaError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4398,63 +5297,94 @@ impl UpdatePrimaryEmailAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
-                        UpdatePrimaryEmailAddressError::DirectoryServiceAuthenticationFailed(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePrimaryEmailAddressError::DirectoryServiceAuthenticationFailed,
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdatePrimaryEmailAddressError::DirectoryUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePrimaryEmailAddressError::DirectoryUnavailable,
                     )
                 }
                 "EmailAddressInUseException" => {
-                    return RusotoError::Service(UpdatePrimaryEmailAddressError::EmailAddressInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePrimaryEmailAddressError::EmailAddressInUse,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdatePrimaryEmailAddressError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePrimaryEmailAddressError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(UpdatePrimaryEmailAddressError::EntityState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePrimaryEmailAddressError::EntityState,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdatePrimaryEmailAddressError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePrimaryEmailAddressError::InvalidParameter,
+                    )
                 }
                 "MailDomainNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdatePrimaryEmailAddressError::MailDomainNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePrimaryEmailAddressError::MailDomainNotFound,
                     )
                 }
                 "MailDomainStateException" => {
-                    return RusotoError::Service(UpdatePrimaryEmailAddressError::MailDomainState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePrimaryEmailAddressError::MailDomainState,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdatePrimaryEmailAddressError::OrganizationNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePrimaryEmailAddressError::OrganizationNotFound,
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(UpdatePrimaryEmailAddressError::OrganizationState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePrimaryEmailAddressError::OrganizationState,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        UpdatePrimaryEmailAddressError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePrimaryEmailAddressError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4518,36 +5448,86 @@ impl UpdateResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(UpdateResourceError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::DirectoryUnavailable,
+                    )
                 }
                 "EmailAddressInUseException" => {
-                    return RusotoError::Service(UpdateResourceError::EmailAddressInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::EmailAddressInUse,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::EntityNotFound,
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(UpdateResourceError::EntityState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::EntityState,
+                    )
                 }
                 "InvalidConfigurationException" => {
-                    return RusotoError::Service(UpdateResourceError::InvalidConfiguration(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::InvalidConfiguration,
+                    )
                 }
                 "MailDomainNotFoundException" => {
-                    return RusotoError::Service(UpdateResourceError::MailDomainNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::MailDomainNotFound,
+                    )
                 }
                 "MailDomainStateException" => {
-                    return RusotoError::Service(UpdateResourceError::MailDomainState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::MailDomainState,
+                    )
                 }
                 "NameAvailabilityException" => {
-                    return RusotoError::Service(UpdateResourceError::NameAvailability(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::NameAvailability,
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(UpdateResourceError::OrganizationNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::OrganizationNotFound,
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(UpdateResourceError::OrganizationState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::OrganizationState,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4882,11 +5862,12 @@ pub struct WorkmailClient {
 impl WorkmailClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> WorkmailClient {
         WorkmailClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "WorkMail"),
         }
     }
 
@@ -4901,12 +5882,32 @@ impl WorkmailClient {
     {
         WorkmailClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "WorkMail"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> WorkmailClient {
-        WorkmailClient { client, region }
+        WorkmailClient {
+            client,
+            region: region_for_service(region, "WorkMail"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> WorkmailClient {
+        WorkmailClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("WorkMail"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WorkmailClient {
+        WorkmailClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4919,6 +5920,7 @@ impl Workmail for WorkmailClient {
     ) -> Result<AssociateDelegateToResourceResponse, RusotoError<AssociateDelegateToResourceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateDelegateToResource");
         request.add_header(
             "x-amz-target",
             "WorkMailService.AssociateDelegateToResource",
@@ -4941,6 +5943,7 @@ impl Workmail for WorkmailClient {
         input: AssociateMemberToGroupRequest,
     ) -> Result<AssociateMemberToGroupResponse, RusotoError<AssociateMemberToGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateMemberToGroup");
         request.add_header("x-amz-target", "WorkMailService.AssociateMemberToGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4960,6 +5963,7 @@ impl Workmail for WorkmailClient {
         input: CancelMailboxExportJobRequest,
     ) -> Result<CancelMailboxExportJobResponse, RusotoError<CancelMailboxExportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelMailboxExportJob");
         request.add_header("x-amz-target", "WorkMailService.CancelMailboxExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4979,6 +5983,7 @@ impl Workmail for WorkmailClient {
         input: CreateAliasRequest,
     ) -> Result<CreateAliasResponse, RusotoError<CreateAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAlias");
         request.add_header("x-amz-target", "WorkMailService.CreateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4997,6 +6002,7 @@ impl Workmail for WorkmailClient {
         input: CreateGroupRequest,
     ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGroup");
         request.add_header("x-amz-target", "WorkMailService.CreateGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5015,6 +6021,7 @@ impl Workmail for WorkmailClient {
         input: CreateOrganizationRequest,
     ) -> Result<CreateOrganizationResponse, RusotoError<CreateOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateOrganization");
         request.add_header("x-amz-target", "WorkMailService.CreateOrganization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5033,6 +6040,7 @@ impl Workmail for WorkmailClient {
         input: CreateResourceRequest,
     ) -> Result<CreateResourceResponse, RusotoError<CreateResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateResource");
         request.add_header("x-amz-target", "WorkMailService.CreateResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5051,6 +6059,7 @@ impl Workmail for WorkmailClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUser");
         request.add_header("x-amz-target", "WorkMailService.CreateUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5069,6 +6078,7 @@ impl Workmail for WorkmailClient {
         input: DeleteAccessControlRuleRequest,
     ) -> Result<DeleteAccessControlRuleResponse, RusotoError<DeleteAccessControlRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAccessControlRule");
         request.add_header("x-amz-target", "WorkMailService.DeleteAccessControlRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5088,6 +6098,7 @@ impl Workmail for WorkmailClient {
         input: DeleteAliasRequest,
     ) -> Result<DeleteAliasResponse, RusotoError<DeleteAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAlias");
         request.add_header("x-amz-target", "WorkMailService.DeleteAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5106,6 +6117,7 @@ impl Workmail for WorkmailClient {
         input: DeleteGroupRequest,
     ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteGroup");
         request.add_header("x-amz-target", "WorkMailService.DeleteGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5124,6 +6136,7 @@ impl Workmail for WorkmailClient {
         input: DeleteMailboxPermissionsRequest,
     ) -> Result<DeleteMailboxPermissionsResponse, RusotoError<DeleteMailboxPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteMailboxPermissions");
         request.add_header("x-amz-target", "WorkMailService.DeleteMailboxPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5143,6 +6156,7 @@ impl Workmail for WorkmailClient {
         input: DeleteOrganizationRequest,
     ) -> Result<DeleteOrganizationResponse, RusotoError<DeleteOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteOrganization");
         request.add_header("x-amz-target", "WorkMailService.DeleteOrganization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5161,6 +6175,7 @@ impl Workmail for WorkmailClient {
         input: DeleteResourceRequest,
     ) -> Result<DeleteResourceResponse, RusotoError<DeleteResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteResource");
         request.add_header("x-amz-target", "WorkMailService.DeleteResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5179,6 +6194,7 @@ impl Workmail for WorkmailClient {
         input: DeleteRetentionPolicyRequest,
     ) -> Result<DeleteRetentionPolicyResponse, RusotoError<DeleteRetentionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRetentionPolicy");
         request.add_header("x-amz-target", "WorkMailService.DeleteRetentionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5198,6 +6214,7 @@ impl Workmail for WorkmailClient {
         input: DeleteUserRequest,
     ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUser");
         request.add_header("x-amz-target", "WorkMailService.DeleteUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5216,6 +6233,7 @@ impl Workmail for WorkmailClient {
         input: DeregisterFromWorkMailRequest,
     ) -> Result<DeregisterFromWorkMailResponse, RusotoError<DeregisterFromWorkMailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterFromWorkMail");
         request.add_header("x-amz-target", "WorkMailService.DeregisterFromWorkMail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5235,6 +6253,7 @@ impl Workmail for WorkmailClient {
         input: DescribeGroupRequest,
     ) -> Result<DescribeGroupResponse, RusotoError<DescribeGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeGroup");
         request.add_header("x-amz-target", "WorkMailService.DescribeGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5253,6 +6272,7 @@ impl Workmail for WorkmailClient {
         input: DescribeMailboxExportJobRequest,
     ) -> Result<DescribeMailboxExportJobResponse, RusotoError<DescribeMailboxExportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMailboxExportJob");
         request.add_header("x-amz-target", "WorkMailService.DescribeMailboxExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5272,6 +6292,7 @@ impl Workmail for WorkmailClient {
         input: DescribeOrganizationRequest,
     ) -> Result<DescribeOrganizationResponse, RusotoError<DescribeOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeOrganization");
         request.add_header("x-amz-target", "WorkMailService.DescribeOrganization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5291,6 +6312,7 @@ impl Workmail for WorkmailClient {
         input: DescribeResourceRequest,
     ) -> Result<DescribeResourceResponse, RusotoError<DescribeResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeResource");
         request.add_header("x-amz-target", "WorkMailService.DescribeResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5309,6 +6331,7 @@ impl Workmail for WorkmailClient {
         input: DescribeUserRequest,
     ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeUser");
         request.add_header("x-amz-target", "WorkMailService.DescribeUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5330,6 +6353,7 @@ impl Workmail for WorkmailClient {
         RusotoError<DisassociateDelegateFromResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateDelegateFromResource");
         request.add_header(
             "x-amz-target",
             "WorkMailService.DisassociateDelegateFromResource",
@@ -5356,6 +6380,7 @@ impl Workmail for WorkmailClient {
     ) -> Result<DisassociateMemberFromGroupResponse, RusotoError<DisassociateMemberFromGroupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateMemberFromGroup");
         request.add_header(
             "x-amz-target",
             "WorkMailService.DisassociateMemberFromGroup",
@@ -5378,6 +6403,7 @@ impl Workmail for WorkmailClient {
         input: GetAccessControlEffectRequest,
     ) -> Result<GetAccessControlEffectResponse, RusotoError<GetAccessControlEffectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAccessControlEffect");
         request.add_header("x-amz-target", "WorkMailService.GetAccessControlEffect");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5398,6 +6424,7 @@ impl Workmail for WorkmailClient {
     ) -> Result<GetDefaultRetentionPolicyResponse, RusotoError<GetDefaultRetentionPolicyError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDefaultRetentionPolicy");
         request.add_header("x-amz-target", "WorkMailService.GetDefaultRetentionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5417,6 +6444,7 @@ impl Workmail for WorkmailClient {
         input: GetMailboxDetailsRequest,
     ) -> Result<GetMailboxDetailsResponse, RusotoError<GetMailboxDetailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMailboxDetails");
         request.add_header("x-amz-target", "WorkMailService.GetMailboxDetails");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5435,6 +6463,7 @@ impl Workmail for WorkmailClient {
         input: ListAccessControlRulesRequest,
     ) -> Result<ListAccessControlRulesResponse, RusotoError<ListAccessControlRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAccessControlRules");
         request.add_header("x-amz-target", "WorkMailService.ListAccessControlRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5454,6 +6483,7 @@ impl Workmail for WorkmailClient {
         input: ListAliasesRequest,
     ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAliases");
         request.add_header("x-amz-target", "WorkMailService.ListAliases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5472,6 +6502,7 @@ impl Workmail for WorkmailClient {
         input: ListGroupMembersRequest,
     ) -> Result<ListGroupMembersResponse, RusotoError<ListGroupMembersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGroupMembers");
         request.add_header("x-amz-target", "WorkMailService.ListGroupMembers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5490,6 +6521,7 @@ impl Workmail for WorkmailClient {
         input: ListGroupsRequest,
     ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGroups");
         request.add_header("x-amz-target", "WorkMailService.ListGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5508,6 +6540,7 @@ impl Workmail for WorkmailClient {
         input: ListMailboxExportJobsRequest,
     ) -> Result<ListMailboxExportJobsResponse, RusotoError<ListMailboxExportJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListMailboxExportJobs");
         request.add_header("x-amz-target", "WorkMailService.ListMailboxExportJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5527,6 +6560,7 @@ impl Workmail for WorkmailClient {
         input: ListMailboxPermissionsRequest,
     ) -> Result<ListMailboxPermissionsResponse, RusotoError<ListMailboxPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListMailboxPermissions");
         request.add_header("x-amz-target", "WorkMailService.ListMailboxPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5546,6 +6580,7 @@ impl Workmail for WorkmailClient {
         input: ListOrganizationsRequest,
     ) -> Result<ListOrganizationsResponse, RusotoError<ListOrganizationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListOrganizations");
         request.add_header("x-amz-target", "WorkMailService.ListOrganizations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5564,6 +6599,7 @@ impl Workmail for WorkmailClient {
         input: ListResourceDelegatesRequest,
     ) -> Result<ListResourceDelegatesResponse, RusotoError<ListResourceDelegatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResourceDelegates");
         request.add_header("x-amz-target", "WorkMailService.ListResourceDelegates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5583,6 +6619,7 @@ impl Workmail for WorkmailClient {
         input: ListResourcesRequest,
     ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResources");
         request.add_header("x-amz-target", "WorkMailService.ListResources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5601,6 +6638,7 @@ impl Workmail for WorkmailClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "WorkMailService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5619,6 +6657,7 @@ impl Workmail for WorkmailClient {
         input: ListUsersRequest,
     ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListUsers");
         request.add_header("x-amz-target", "WorkMailService.ListUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5637,6 +6676,7 @@ impl Workmail for WorkmailClient {
         input: PutAccessControlRuleRequest,
     ) -> Result<PutAccessControlRuleResponse, RusotoError<PutAccessControlRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutAccessControlRule");
         request.add_header("x-amz-target", "WorkMailService.PutAccessControlRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5656,6 +6696,7 @@ impl Workmail for WorkmailClient {
         input: PutMailboxPermissionsRequest,
     ) -> Result<PutMailboxPermissionsResponse, RusotoError<PutMailboxPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutMailboxPermissions");
         request.add_header("x-amz-target", "WorkMailService.PutMailboxPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5675,6 +6716,7 @@ impl Workmail for WorkmailClient {
         input: PutRetentionPolicyRequest,
     ) -> Result<PutRetentionPolicyResponse, RusotoError<PutRetentionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRetentionPolicy");
         request.add_header("x-amz-target", "WorkMailService.PutRetentionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5693,6 +6735,7 @@ impl Workmail for WorkmailClient {
         input: RegisterToWorkMailRequest,
     ) -> Result<RegisterToWorkMailResponse, RusotoError<RegisterToWorkMailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterToWorkMail");
         request.add_header("x-amz-target", "WorkMailService.RegisterToWorkMail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5711,6 +6754,7 @@ impl Workmail for WorkmailClient {
         input: ResetPasswordRequest,
     ) -> Result<ResetPasswordResponse, RusotoError<ResetPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResetPassword");
         request.add_header("x-amz-target", "WorkMailService.ResetPassword");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5729,6 +6773,7 @@ impl Workmail for WorkmailClient {
         input: StartMailboxExportJobRequest,
     ) -> Result<StartMailboxExportJobResponse, RusotoError<StartMailboxExportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartMailboxExportJob");
         request.add_header("x-amz-target", "WorkMailService.StartMailboxExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5748,6 +6793,7 @@ impl Workmail for WorkmailClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "WorkMailService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5766,6 +6812,7 @@ impl Workmail for WorkmailClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "WorkMailService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5784,6 +6831,7 @@ impl Workmail for WorkmailClient {
         input: UpdateMailboxQuotaRequest,
     ) -> Result<UpdateMailboxQuotaResponse, RusotoError<UpdateMailboxQuotaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateMailboxQuota");
         request.add_header("x-amz-target", "WorkMailService.UpdateMailboxQuota");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5803,6 +6851,7 @@ impl Workmail for WorkmailClient {
     ) -> Result<UpdatePrimaryEmailAddressResponse, RusotoError<UpdatePrimaryEmailAddressError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePrimaryEmailAddress");
         request.add_header("x-amz-target", "WorkMailService.UpdatePrimaryEmailAddress");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5822,6 +6871,7 @@ impl Workmail for WorkmailClient {
         input: UpdateResourceRequest,
     ) -> Result<UpdateResourceResponse, RusotoError<UpdateResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateResource");
         request.add_header("x-amz-target", "WorkMailService.UpdateResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5834,3 +6884,505 @@ impl Workmail for WorkmailClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateResourceResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon WorkMail API, running the calls of a `WorkmailClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `WorkmailClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WorkmailBlockingClient {
+    client: WorkmailClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WorkmailBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> WorkmailBlockingClient {
+        WorkmailBlockingClient::from_client(WorkmailClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorkmailBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WorkmailBlockingClient::from_client(WorkmailClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> WorkmailBlockingClient {
+        WorkmailBlockingClient::from_client(WorkmailClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> WorkmailBlockingClient {
+        WorkmailBlockingClient::from_client(WorkmailClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: WorkmailClient) -> WorkmailBlockingClient {
+        WorkmailBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> WorkmailBlockingClient {
+        WorkmailBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WorkmailBlockingClient {
+        WorkmailBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Workmail::associate_delegate_to_resource`].
+    pub fn associate_delegate_to_resource(
+        &self,
+        input: AssociateDelegateToResourceRequest,
+    ) -> Result<AssociateDelegateToResourceResponse, RusotoError<AssociateDelegateToResourceError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_delegate_to_resource(input))
+    }
+
+    /// Blocking version of [`Workmail::associate_member_to_group`].
+    pub fn associate_member_to_group(
+        &self,
+        input: AssociateMemberToGroupRequest,
+    ) -> Result<AssociateMemberToGroupResponse, RusotoError<AssociateMemberToGroupError>> {
+        self.runtime
+            .block_on(self.client.associate_member_to_group(input))
+    }
+
+    /// Blocking version of [`Workmail::cancel_mailbox_export_job`].
+    pub fn cancel_mailbox_export_job(
+        &self,
+        input: CancelMailboxExportJobRequest,
+    ) -> Result<CancelMailboxExportJobResponse, RusotoError<CancelMailboxExportJobError>> {
+        self.runtime
+            .block_on(self.client.cancel_mailbox_export_job(input))
+    }
+
+    /// Blocking version of [`Workmail::create_alias`].
+    pub fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<CreateAliasResponse, RusotoError<CreateAliasError>> {
+        self.runtime.block_on(self.client.create_alias(input))
+    }
+
+    /// Blocking version of [`Workmail::create_group`].
+    pub fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// Blocking version of [`Workmail::create_organization`].
+    pub fn create_organization(
+        &self,
+        input: CreateOrganizationRequest,
+    ) -> Result<CreateOrganizationResponse, RusotoError<CreateOrganizationError>> {
+        self.runtime
+            .block_on(self.client.create_organization(input))
+    }
+
+    /// Blocking version of [`Workmail::create_resource`].
+    pub fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> Result<CreateResourceResponse, RusotoError<CreateResourceError>> {
+        self.runtime.block_on(self.client.create_resource(input))
+    }
+
+    /// Blocking version of [`Workmail::create_user`].
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// Blocking version of [`Workmail::delete_access_control_rule`].
+    pub fn delete_access_control_rule(
+        &self,
+        input: DeleteAccessControlRuleRequest,
+    ) -> Result<DeleteAccessControlRuleResponse, RusotoError<DeleteAccessControlRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_access_control_rule(input))
+    }
+
+    /// Blocking version of [`Workmail::delete_alias`].
+    pub fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> Result<DeleteAliasResponse, RusotoError<DeleteAliasError>> {
+        self.runtime.block_on(self.client.delete_alias(input))
+    }
+
+    /// Blocking version of [`Workmail::delete_group`].
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// Blocking version of [`Workmail::delete_mailbox_permissions`].
+    pub fn delete_mailbox_permissions(
+        &self,
+        input: DeleteMailboxPermissionsRequest,
+    ) -> Result<DeleteMailboxPermissionsResponse, RusotoError<DeleteMailboxPermissionsError>> {
+        self.runtime
+            .block_on(self.client.delete_mailbox_permissions(input))
+    }
+
+    /// Blocking version of [`Workmail::delete_organization`].
+    pub fn delete_organization(
+        &self,
+        input: DeleteOrganizationRequest,
+    ) -> Result<DeleteOrganizationResponse, RusotoError<DeleteOrganizationError>> {
+        self.runtime
+            .block_on(self.client.delete_organization(input))
+    }
+
+    /// Blocking version of [`Workmail::delete_resource`].
+    pub fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> Result<DeleteResourceResponse, RusotoError<DeleteResourceError>> {
+        self.runtime.block_on(self.client.delete_resource(input))
+    }
+
+    /// Blocking version of [`Workmail::delete_retention_policy`].
+    pub fn delete_retention_policy(
+        &self,
+        input: DeleteRetentionPolicyRequest,
+    ) -> Result<DeleteRetentionPolicyResponse, RusotoError<DeleteRetentionPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_retention_policy(input))
+    }
+
+    /// Blocking version of [`Workmail::delete_user`].
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// Blocking version of [`Workmail::deregister_from_work_mail`].
+    pub fn deregister_from_work_mail(
+        &self,
+        input: DeregisterFromWorkMailRequest,
+    ) -> Result<DeregisterFromWorkMailResponse, RusotoError<DeregisterFromWorkMailError>> {
+        self.runtime
+            .block_on(self.client.deregister_from_work_mail(input))
+    }
+
+    /// Blocking version of [`Workmail::describe_group`].
+    pub fn describe_group(
+        &self,
+        input: DescribeGroupRequest,
+    ) -> Result<DescribeGroupResponse, RusotoError<DescribeGroupError>> {
+        self.runtime.block_on(self.client.describe_group(input))
+    }
+
+    /// Blocking version of [`Workmail::describe_mailbox_export_job`].
+    pub fn describe_mailbox_export_job(
+        &self,
+        input: DescribeMailboxExportJobRequest,
+    ) -> Result<DescribeMailboxExportJobResponse, RusotoError<DescribeMailboxExportJobError>> {
+        self.runtime
+            .block_on(self.client.describe_mailbox_export_job(input))
+    }
+
+    /// Blocking version of [`Workmail::describe_organization`].
+    pub fn describe_organization(
+        &self,
+        input: DescribeOrganizationRequest,
+    ) -> Result<DescribeOrganizationResponse, RusotoError<DescribeOrganizationError>> {
+        self.runtime
+            .block_on(self.client.describe_organization(input))
+    }
+
+    /// Blocking version of [`Workmail::describe_resource`].
+    pub fn describe_resource(
+        &self,
+        input: DescribeResourceRequest,
+    ) -> Result<DescribeResourceResponse, RusotoError<DescribeResourceError>> {
+        self.runtime.block_on(self.client.describe_resource(input))
+    }
+
+    /// Blocking version of [`Workmail::describe_user`].
+    pub fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+        self.runtime.block_on(self.client.describe_user(input))
+    }
+
+    /// Blocking version of [`Workmail::disassociate_delegate_from_resource`].
+    pub fn disassociate_delegate_from_resource(
+        &self,
+        input: DisassociateDelegateFromResourceRequest,
+    ) -> Result<
+        DisassociateDelegateFromResourceResponse,
+        RusotoError<DisassociateDelegateFromResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_delegate_from_resource(input))
+    }
+
+    /// Blocking version of [`Workmail::disassociate_member_from_group`].
+    pub fn disassociate_member_from_group(
+        &self,
+        input: DisassociateMemberFromGroupRequest,
+    ) -> Result<DisassociateMemberFromGroupResponse, RusotoError<DisassociateMemberFromGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_member_from_group(input))
+    }
+
+    /// Blocking version of [`Workmail::get_access_control_effect`].
+    pub fn get_access_control_effect(
+        &self,
+        input: GetAccessControlEffectRequest,
+    ) -> Result<GetAccessControlEffectResponse, RusotoError<GetAccessControlEffectError>> {
+        self.runtime
+            .block_on(self.client.get_access_control_effect(input))
+    }
+
+    /// Blocking version of [`Workmail::get_default_retention_policy`].
+    pub fn get_default_retention_policy(
+        &self,
+        input: GetDefaultRetentionPolicyRequest,
+    ) -> Result<GetDefaultRetentionPolicyResponse, RusotoError<GetDefaultRetentionPolicyError>>
+    {
+        self.runtime
+            .block_on(self.client.get_default_retention_policy(input))
+    }
+
+    /// Blocking version of [`Workmail::get_mailbox_details`].
+    pub fn get_mailbox_details(
+        &self,
+        input: GetMailboxDetailsRequest,
+    ) -> Result<GetMailboxDetailsResponse, RusotoError<GetMailboxDetailsError>> {
+        self.runtime
+            .block_on(self.client.get_mailbox_details(input))
+    }
+
+    /// Blocking version of [`Workmail::list_access_control_rules`].
+    pub fn list_access_control_rules(
+        &self,
+        input: ListAccessControlRulesRequest,
+    ) -> Result<ListAccessControlRulesResponse, RusotoError<ListAccessControlRulesError>> {
+        self.runtime
+            .block_on(self.client.list_access_control_rules(input))
+    }
+
+    /// Blocking version of [`Workmail::list_aliases`].
+    pub fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
+        self.runtime.block_on(self.client.list_aliases(input))
+    }
+
+    /// Blocking version of [`Workmail::list_group_members`].
+    pub fn list_group_members(
+        &self,
+        input: ListGroupMembersRequest,
+    ) -> Result<ListGroupMembersResponse, RusotoError<ListGroupMembersError>> {
+        self.runtime.block_on(self.client.list_group_members(input))
+    }
+
+    /// Blocking version of [`Workmail::list_groups`].
+    pub fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.runtime.block_on(self.client.list_groups(input))
+    }
+
+    /// Blocking version of [`Workmail::list_mailbox_export_jobs`].
+    pub fn list_mailbox_export_jobs(
+        &self,
+        input: ListMailboxExportJobsRequest,
+    ) -> Result<ListMailboxExportJobsResponse, RusotoError<ListMailboxExportJobsError>> {
+        self.runtime
+            .block_on(self.client.list_mailbox_export_jobs(input))
+    }
+
+    /// Blocking version of [`Workmail::list_mailbox_permissions`].
+    pub fn list_mailbox_permissions(
+        &self,
+        input: ListMailboxPermissionsRequest,
+    ) -> Result<ListMailboxPermissionsResponse, RusotoError<ListMailboxPermissionsError>> {
+        self.runtime
+            .block_on(self.client.list_mailbox_permissions(input))
+    }
+
+    /// Blocking version of [`Workmail::list_organizations`].
+    pub fn list_organizations(
+        &self,
+        input: ListOrganizationsRequest,
+    ) -> Result<ListOrganizationsResponse, RusotoError<ListOrganizationsError>> {
+        self.runtime.block_on(self.client.list_organizations(input))
+    }
+
+    /// Blocking version of [`Workmail::list_resource_delegates`].
+    pub fn list_resource_delegates(
+        &self,
+        input: ListResourceDelegatesRequest,
+    ) -> Result<ListResourceDelegatesResponse, RusotoError<ListResourceDelegatesError>> {
+        self.runtime
+            .block_on(self.client.list_resource_delegates(input))
+    }
+
+    /// Blocking version of [`Workmail::list_resources`].
+    pub fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
+        self.runtime.block_on(self.client.list_resources(input))
+    }
+
+    /// Blocking version of [`Workmail::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Workmail::list_users`].
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// Blocking version of [`Workmail::put_access_control_rule`].
+    pub fn put_access_control_rule(
+        &self,
+        input: PutAccessControlRuleRequest,
+    ) -> Result<PutAccessControlRuleResponse, RusotoError<PutAccessControlRuleError>> {
+        self.runtime
+            .block_on(self.client.put_access_control_rule(input))
+    }
+
+    /// Blocking version of [`Workmail::put_mailbox_permissions`].
+    pub fn put_mailbox_permissions(
+        &self,
+        input: PutMailboxPermissionsRequest,
+    ) -> Result<PutMailboxPermissionsResponse, RusotoError<PutMailboxPermissionsError>> {
+        self.runtime
+            .block_on(self.client.put_mailbox_permissions(input))
+    }
+
+    /// Blocking version of [`Workmail::put_retention_policy`].
+    pub fn put_retention_policy(
+        &self,
+        input: PutRetentionPolicyRequest,
+    ) -> Result<PutRetentionPolicyResponse, RusotoError<PutRetentionPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_retention_policy(input))
+    }
+
+    /// Blocking version of [`Workmail::register_to_work_mail`].
+    pub fn register_to_work_mail(
+        &self,
+        input: RegisterToWorkMailRequest,
+    ) -> Result<RegisterToWorkMailResponse, RusotoError<RegisterToWorkMailError>> {
+        self.runtime
+            .block_on(self.client.register_to_work_mail(input))
+    }
+
+    /// Blocking version of [`Workmail::reset_password`].
+    pub fn reset_password(
+        &self,
+        input: ResetPasswordRequest,
+    ) -> Result<ResetPasswordResponse, RusotoError<ResetPasswordError>> {
+        self.runtime.block_on(self.client.reset_password(input))
+    }
+
+    /// Blocking version of [`Workmail::start_mailbox_export_job`].
+    pub fn start_mailbox_export_job(
+        &self,
+        input: StartMailboxExportJobRequest,
+    ) -> Result<StartMailboxExportJobResponse, RusotoError<StartMailboxExportJobError>> {
+        self.runtime
+            .block_on(self.client.start_mailbox_export_job(input))
+    }
+
+    /// Blocking version of [`Workmail::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Workmail::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Workmail::update_mailbox_quota`].
+    pub fn update_mailbox_quota(
+        &self,
+        input: UpdateMailboxQuotaRequest,
+    ) -> Result<UpdateMailboxQuotaResponse, RusotoError<UpdateMailboxQuotaError>> {
+        self.runtime
+            .block_on(self.client.update_mailbox_quota(input))
+    }
+
+    /// Blocking version of [`Workmail::update_primary_email_address`].
+    pub fn update_primary_email_address(
+        &self,
+        input: UpdatePrimaryEmailAddressRequest,
+    ) -> Result<UpdatePrimaryEmailAddressResponse, RusotoError<UpdatePrimaryEmailAddressError>>
+    {
+        self.runtime
+            .block_on(self.client.update_primary_email_address(input))
+    }
+
+    /// Blocking version of [`Workmail::update_resource`].
+    pub fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> Result<UpdateResourceResponse, RusotoError<UpdateResourceError>> {
+        self.runtime.block_on(self.client.update_resource(input))
+    }
+}
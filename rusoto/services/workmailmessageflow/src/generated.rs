@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -49,11 +50,14 @@ impl GetRawMessageContentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRawMessageContentError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRawMessageContentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -88,11 +92,12 @@ pub struct WorkmailMessageFlowClient {
 impl WorkmailMessageFlowClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> WorkmailMessageFlowClient {
         WorkmailMessageFlowClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "WorkMailMessageFlow"),
         }
     }
 
@@ -107,12 +112,32 @@ impl WorkmailMessageFlowClient {
     {
         WorkmailMessageFlowClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "WorkMailMessageFlow"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> WorkmailMessageFlowClient {
-        WorkmailMessageFlowClient { client, region }
+        WorkmailMessageFlowClient {
+            client,
+            region: region_for_service(region, "WorkMailMessageFlow"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> WorkmailMessageFlowClient {
+        WorkmailMessageFlowClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("WorkMailMessageFlow"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WorkmailMessageFlowClient {
+        WorkmailMessageFlowClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -128,6 +153,8 @@ impl WorkmailMessageFlow for WorkmailMessageFlowClient {
 
         let mut request =
             SignedRequest::new("GET", "workmailmessageflow", &self.region, &request_uri);
+        request.set_operation("GetRawMessageContent");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -148,3 +175,97 @@ impl WorkmailMessageFlow for WorkmailMessageFlowClient {
         }
     }
 }
+
+/// A blocking client for the Amazon WorkMail Message Flow API, running the calls of a `WorkmailMessageFlowClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `WorkmailMessageFlowClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WorkmailMessageFlowBlockingClient {
+    client: WorkmailMessageFlowClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WorkmailMessageFlowBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> WorkmailMessageFlowBlockingClient {
+        WorkmailMessageFlowBlockingClient::from_client(WorkmailMessageFlowClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorkmailMessageFlowBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WorkmailMessageFlowBlockingClient::from_client(WorkmailMessageFlowClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> WorkmailMessageFlowBlockingClient {
+        WorkmailMessageFlowBlockingClient::from_client(WorkmailMessageFlowClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> WorkmailMessageFlowBlockingClient {
+        WorkmailMessageFlowBlockingClient::from_client(WorkmailMessageFlowClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: WorkmailMessageFlowClient) -> WorkmailMessageFlowBlockingClient {
+        WorkmailMessageFlowBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> WorkmailMessageFlowBlockingClient {
+        WorkmailMessageFlowBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WorkmailMessageFlowBlockingClient {
+        WorkmailMessageFlowBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`WorkmailMessageFlow::get_raw_message_content`].
+    pub fn get_raw_message_content(
+        &self,
+        input: GetRawMessageContentRequest,
+    ) -> Result<GetRawMessageContentResponse, RusotoError<GetRawMessageContentError>> {
+        self.runtime
+            .block_on(self.client.get_raw_message_content(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1839,36 +1840,54 @@ impl AssociateConnectionAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateConnectionAliasError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateConnectionAliasError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        AssociateConnectionAliasError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateConnectionAliasError::InvalidParameterValues,
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
-                        AssociateConnectionAliasError::InvalidResourceState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateConnectionAliasError::InvalidResourceState,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        AssociateConnectionAliasError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateConnectionAliasError::OperationNotSupported,
                     )
                 }
                 "ResourceAssociatedException" => {
-                    return RusotoError::Service(AssociateConnectionAliasError::ResourceAssociated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateConnectionAliasError::ResourceAssociated,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateConnectionAliasError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateConnectionAliasError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1917,32 +1936,54 @@ impl AssociateIpGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateIpGroupsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateIpGroupsError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(AssociateIpGroupsError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateIpGroupsError::InvalidParameterValues,
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(AssociateIpGroupsError::InvalidResourceState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateIpGroupsError::InvalidResourceState,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(AssociateIpGroupsError::OperationNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateIpGroupsError::OperationNotSupported,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(AssociateIpGroupsError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateIpGroupsError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateIpGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateIpGroupsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1983,27 +2024,46 @@ impl AuthorizeIpRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AuthorizeIpRulesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AuthorizeIpRulesError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(AuthorizeIpRulesError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AuthorizeIpRulesError::InvalidParameterValues,
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(AuthorizeIpRulesError::InvalidResourceState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AuthorizeIpRulesError::InvalidResourceState,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(AuthorizeIpRulesError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AuthorizeIpRulesError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AuthorizeIpRulesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AuthorizeIpRulesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2047,37 +2107,62 @@ impl CopyWorkspaceImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopyWorkspaceImageError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CopyWorkspaceImageError::InvalidParameterValues,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::OperationNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CopyWorkspaceImageError::OperationNotSupported,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CopyWorkspaceImageError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CopyWorkspaceImageError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopyWorkspaceImageError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CopyWorkspaceImageError::ResourceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2121,34 +2206,54 @@ impl CreateConnectionAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateConnectionAliasError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionAliasError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        CreateConnectionAliasError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionAliasError::InvalidParameterValues,
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(CreateConnectionAliasError::InvalidResourceState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConnectionAliasError::InvalidResourceState,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(CreateConnectionAliasError::OperationNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConnectionAliasError::OperationNotSupported,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateConnectionAliasError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConnectionAliasError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateConnectionAliasError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConnectionAliasError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2189,25 +2294,46 @@ impl CreateIpGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIpGroupError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIpGroupError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(CreateIpGroupError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIpGroupError::InvalidParameterValues,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateIpGroupError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIpGroupError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceCreationFailedException" => {
-                    return RusotoError::Service(CreateIpGroupError::ResourceCreationFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIpGroupError::ResourceCreationFailed,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateIpGroupError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIpGroupError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2243,15 +2369,30 @@ impl CreateTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(CreateTagsError::InvalidParameterValues(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::InvalidParameterValues,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateTagsError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2283,16 +2424,22 @@ impl CreateWorkspacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(CreateWorkspacesError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorkspacesError::InvalidParameterValues,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateWorkspacesError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorkspacesError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2331,34 +2478,54 @@ impl DeleteConnectionAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteConnectionAliasError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionAliasError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        DeleteConnectionAliasError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionAliasError::InvalidParameterValues,
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(DeleteConnectionAliasError::InvalidResourceState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConnectionAliasError::InvalidResourceState,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(DeleteConnectionAliasError::OperationNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConnectionAliasError::OperationNotSupported,
+                    )
                 }
                 "ResourceAssociatedException" => {
-                    return RusotoError::Service(DeleteConnectionAliasError::ResourceAssociated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConnectionAliasError::ResourceAssociated,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConnectionAliasError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConnectionAliasError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2397,20 +2564,38 @@ impl DeleteIpGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteIpGroupError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIpGroupError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(DeleteIpGroupError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIpGroupError::InvalidParameterValues,
+                    )
                 }
                 "ResourceAssociatedException" => {
-                    return RusotoError::Service(DeleteIpGroupError::ResourceAssociated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIpGroupError::ResourceAssociated,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIpGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIpGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2443,12 +2628,22 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidParameterValues(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::InvalidParameterValues,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2481,19 +2676,30 @@ impl DeleteWorkspaceImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteWorkspaceImageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWorkspaceImageError::AccessDenied,
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(DeleteWorkspaceImageError::InvalidResourceState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteWorkspaceImageError::InvalidResourceState,
+                    )
                 }
                 "ResourceAssociatedException" => {
-                    return RusotoError::Service(DeleteWorkspaceImageError::ResourceAssociated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteWorkspaceImageError::ResourceAssociated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2533,31 +2739,46 @@ impl DeregisterWorkspaceDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeregisterWorkspaceDirectoryError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterWorkspaceDirectoryError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        DeregisterWorkspaceDirectoryError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterWorkspaceDirectoryError::InvalidParameterValues,
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
-                        DeregisterWorkspaceDirectoryError::InvalidResourceState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterWorkspaceDirectoryError::InvalidResourceState,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        DeregisterWorkspaceDirectoryError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterWorkspaceDirectoryError::OperationNotSupported,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeregisterWorkspaceDirectoryError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterWorkspaceDirectoryError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2597,9 +2818,14 @@ impl DescribeAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAccountError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountError::AccessDenied,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2629,11 +2855,14 @@ impl DescribeAccountModificationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAccountModificationsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAccountModificationsError::AccessDenied,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2665,21 +2894,30 @@ impl DescribeClientPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeClientPropertiesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeClientPropertiesError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        DescribeClientPropertiesError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClientPropertiesError::InvalidParameterValues,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeClientPropertiesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeClientPropertiesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2719,26 +2957,38 @@ impl DescribeConnectionAliasPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DescribeConnectionAliasPermissionsError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConnectionAliasPermissionsError::AccessDenied,
                     )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        DescribeConnectionAliasPermissionsError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConnectionAliasPermissionsError::InvalidParameterValues,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        DescribeConnectionAliasPermissionsError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConnectionAliasPermissionsError::OperationNotSupported,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeConnectionAliasPermissionsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConnectionAliasPermissionsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2781,21 +3031,30 @@ impl DescribeConnectionAliasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeConnectionAliasesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConnectionAliasesError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        DescribeConnectionAliasesError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConnectionAliasesError::InvalidParameterValues,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        DescribeConnectionAliasesError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConnectionAliasesError::OperationNotSupported,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2831,14 +3090,22 @@ impl DescribeIpGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeIpGroupsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIpGroupsError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(DescribeIpGroupsError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIpGroupsError::InvalidParameterValues,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2867,9 +3134,14 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2897,11 +3169,14 @@ impl DescribeWorkspaceBundlesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        DescribeWorkspaceBundlesError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorkspaceBundlesError::InvalidParameterValues,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2933,11 +3208,14 @@ impl DescribeWorkspaceDirectoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        DescribeWorkspaceDirectoriesError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorkspaceDirectoriesError::InvalidParameterValues,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2973,21 +3251,30 @@ impl DescribeWorkspaceImagePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DescribeWorkspaceImagePermissionsError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorkspaceImagePermissionsError::AccessDenied,
                     )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        DescribeWorkspaceImagePermissionsError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorkspaceImagePermissionsError::InvalidParameterValues,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeWorkspaceImagePermissionsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorkspaceImagePermissionsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3023,11 +3310,14 @@ impl DescribeWorkspaceImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeWorkspaceImagesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorkspaceImagesError::AccessDenied,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3061,21 +3351,30 @@ impl DescribeWorkspaceSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeWorkspaceSnapshotsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorkspaceSnapshotsError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        DescribeWorkspaceSnapshotsError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorkspaceSnapshotsError::InvalidParameterValues,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeWorkspaceSnapshotsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorkspaceSnapshotsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3109,16 +3408,22 @@ impl DescribeWorkspacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(DescribeWorkspacesError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorkspacesError::InvalidParameterValues,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DescribeWorkspacesError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorkspacesError::ResourceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3149,11 +3454,14 @@ impl DescribeWorkspacesConnectionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        DescribeWorkspacesConnectionStatusError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorkspacesConnectionStatusError::InvalidParameterValues,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3193,31 +3501,46 @@ impl DisassociateConnectionAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateConnectionAliasError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateConnectionAliasError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        DisassociateConnectionAliasError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateConnectionAliasError::InvalidParameterValues,
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
-                        DisassociateConnectionAliasError::InvalidResourceState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateConnectionAliasError::InvalidResourceState,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        DisassociateConnectionAliasError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateConnectionAliasError::OperationNotSupported,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateConnectionAliasError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateConnectionAliasError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3261,24 +3584,38 @@ impl DisassociateIpGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateIpGroupsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateIpGroupsError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(DisassociateIpGroupsError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateIpGroupsError::InvalidParameterValues,
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(DisassociateIpGroupsError::InvalidResourceState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateIpGroupsError::InvalidResourceState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateIpGroupsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateIpGroupsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3319,34 +3656,54 @@ impl ImportWorkspaceImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ImportWorkspaceImageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportWorkspaceImageError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(ImportWorkspaceImageError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportWorkspaceImageError::InvalidParameterValues,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(ImportWorkspaceImageError::OperationNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportWorkspaceImageError::OperationNotSupported,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(ImportWorkspaceImageError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportWorkspaceImageError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(ImportWorkspaceImageError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportWorkspaceImageError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ImportWorkspaceImageError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportWorkspaceImageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3383,16 +3740,22 @@ impl ListAvailableManagementCidrRangesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListAvailableManagementCidrRangesError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAvailableManagementCidrRangesError::AccessDenied,
                     )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        ListAvailableManagementCidrRangesError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAvailableManagementCidrRangesError::InvalidParameterValues,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3435,32 +3798,54 @@ impl MigrateWorkspaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(MigrateWorkspaceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MigrateWorkspaceError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(MigrateWorkspaceError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MigrateWorkspaceError::InvalidParameterValues,
+                    )
                 }
                 "OperationInProgressException" => {
-                    return RusotoError::Service(MigrateWorkspaceError::OperationInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MigrateWorkspaceError::OperationInProgress,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(MigrateWorkspaceError::OperationNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MigrateWorkspaceError::OperationNotSupported,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(MigrateWorkspaceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MigrateWorkspaceError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(MigrateWorkspaceError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MigrateWorkspaceError::ResourceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3501,23 +3886,46 @@ impl ModifyAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ModifyAccountError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyAccountError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(ModifyAccountError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyAccountError::InvalidParameterValues,
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(ModifyAccountError::InvalidResourceState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyAccountError::InvalidResourceState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ModifyAccountError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyAccountError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ModifyAccountError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyAccountError::ResourceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3553,19 +3961,30 @@ impl ModifyClientPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ModifyClientPropertiesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyClientPropertiesError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        ModifyClientPropertiesError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyClientPropertiesError::InvalidParameterValues,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ModifyClientPropertiesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyClientPropertiesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3603,21 +4022,30 @@ impl ModifySelfservicePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ModifySelfservicePermissionsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifySelfservicePermissionsError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        ModifySelfservicePermissionsError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifySelfservicePermissionsError::InvalidParameterValues,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ModifySelfservicePermissionsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifySelfservicePermissionsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3655,16 +4083,22 @@ impl ModifyWorkspaceAccessPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ModifyWorkspaceAccessPropertiesError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyWorkspaceAccessPropertiesError::AccessDenied,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ModifyWorkspaceAccessPropertiesError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyWorkspaceAccessPropertiesError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3703,26 +4137,38 @@ impl ModifyWorkspaceCreationPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ModifyWorkspaceCreationPropertiesError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyWorkspaceCreationPropertiesError::AccessDenied,
                     )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        ModifyWorkspaceCreationPropertiesError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyWorkspaceCreationPropertiesError::InvalidParameterValues,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        ModifyWorkspaceCreationPropertiesError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyWorkspaceCreationPropertiesError::OperationNotSupported,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ModifyWorkspaceCreationPropertiesError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyWorkspaceCreationPropertiesError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3773,41 +4219,62 @@ impl ModifyWorkspacePropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ModifyWorkspacePropertiesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyWorkspacePropertiesError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        ModifyWorkspacePropertiesError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyWorkspacePropertiesError::InvalidParameterValues,
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
-                        ModifyWorkspacePropertiesError::InvalidResourceState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyWorkspacePropertiesError::InvalidResourceState,
                     )
                 }
                 "OperationInProgressException" => {
-                    return RusotoError::Service(
-                        ModifyWorkspacePropertiesError::OperationInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyWorkspacePropertiesError::OperationInProgress,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ModifyWorkspacePropertiesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyWorkspacePropertiesError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
-                        ModifyWorkspacePropertiesError::ResourceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyWorkspacePropertiesError::ResourceUnavailable,
                     )
                 }
                 "UnsupportedWorkspaceConfigurationException" => {
-                    return RusotoError::Service(
-                        ModifyWorkspacePropertiesError::UnsupportedWorkspaceConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyWorkspacePropertiesError::UnsupportedWorkspaceConfiguration,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3855,21 +4322,30 @@ impl ModifyWorkspaceStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(ModifyWorkspaceStateError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyWorkspaceStateError::InvalidParameterValues,
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(ModifyWorkspaceStateError::InvalidResourceState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyWorkspaceStateError::InvalidResourceState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ModifyWorkspaceStateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyWorkspaceStateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3895,7 +4371,7 @@ impl RebootWorkspacesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RebootWorkspacesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3917,7 +4393,7 @@ impl RebuildWorkspacesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RebuildWorkspacesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3959,46 +4435,70 @@ impl RegisterWorkspaceDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterWorkspaceDirectoryError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterWorkspaceDirectoryError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        RegisterWorkspaceDirectoryError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterWorkspaceDirectoryError::InvalidParameterValues,
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
-                        RegisterWorkspaceDirectoryError::InvalidResourceState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterWorkspaceDirectoryError::InvalidResourceState,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        RegisterWorkspaceDirectoryError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterWorkspaceDirectoryError::OperationNotSupported,
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        RegisterWorkspaceDirectoryError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterWorkspaceDirectoryError::ResourceLimitExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterWorkspaceDirectoryError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterWorkspaceDirectoryError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedNetworkConfigurationException" => {
-                    return RusotoError::Service(
-                        RegisterWorkspaceDirectoryError::UnsupportedNetworkConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterWorkspaceDirectoryError::UnsupportedNetworkConfiguration,
                     )
                 }
                 "WorkspacesDefaultRoleNotFoundException" => {
-                    return RusotoError::Service(
-                        RegisterWorkspaceDirectoryError::WorkspacesDefaultRoleNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterWorkspaceDirectoryError::WorkspacesDefaultRoleNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4049,17 +4549,30 @@ impl RestoreWorkspaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RestoreWorkspaceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreWorkspaceError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(RestoreWorkspaceError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreWorkspaceError::InvalidParameterValues,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreWorkspaceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreWorkspaceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4095,20 +4608,38 @@ impl RevokeIpRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RevokeIpRulesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeIpRulesError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(RevokeIpRulesError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokeIpRulesError::InvalidParameterValues,
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(RevokeIpRulesError::InvalidResourceState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeIpRulesError::InvalidResourceState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RevokeIpRulesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeIpRulesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4135,7 +4666,7 @@ impl StartWorkspacesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StartWorkspacesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4157,7 +4688,7 @@ impl StopWorkspacesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StopWorkspacesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4179,7 +4710,7 @@ impl TerminateWorkspacesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TerminateWorkspacesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4219,41 +4750,62 @@ impl UpdateConnectionAliasPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        UpdateConnectionAliasPermissionError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionAliasPermissionError::AccessDenied,
                     )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        UpdateConnectionAliasPermissionError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionAliasPermissionError::InvalidParameterValues,
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
-                        UpdateConnectionAliasPermissionError::InvalidResourceState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionAliasPermissionError::InvalidResourceState,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        UpdateConnectionAliasPermissionError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionAliasPermissionError::OperationNotSupported,
                     )
                 }
                 "ResourceAssociatedException" => {
-                    return RusotoError::Service(
-                        UpdateConnectionAliasPermissionError::ResourceAssociated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionAliasPermissionError::ResourceAssociated,
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateConnectionAliasPermissionError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionAliasPermissionError::ResourceLimitExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateConnectionAliasPermissionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionAliasPermissionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4307,29 +4859,46 @@ impl UpdateRulesOfIpGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateRulesOfIpGroupError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRulesOfIpGroupError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(UpdateRulesOfIpGroupError::InvalidParameterValues(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRulesOfIpGroupError::InvalidParameterValues,
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(UpdateRulesOfIpGroupError::InvalidResourceState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRulesOfIpGroupError::InvalidResourceState,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(UpdateRulesOfIpGroupError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRulesOfIpGroupError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRulesOfIpGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRulesOfIpGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4371,31 +4940,46 @@ impl UpdateWorkspaceImagePermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateWorkspaceImagePermissionError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateWorkspaceImagePermissionError::AccessDenied,
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
-                        UpdateWorkspaceImagePermissionError::InvalidParameterValues(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWorkspaceImagePermissionError::InvalidParameterValues,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        UpdateWorkspaceImagePermissionError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWorkspaceImagePermissionError::OperationNotSupported,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateWorkspaceImagePermissionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWorkspaceImagePermissionError::ResourceNotFound,
                     )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateWorkspaceImagePermissionError::ResourceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWorkspaceImagePermissionError::ResourceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4759,11 +5343,12 @@ pub struct WorkspacesClient {
 impl WorkspacesClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> WorkspacesClient {
         WorkspacesClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "WorkSpaces"),
         }
     }
 
@@ -4778,12 +5363,32 @@ impl WorkspacesClient {
     {
         WorkspacesClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "WorkSpaces"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> WorkspacesClient {
-        WorkspacesClient { client, region }
+        WorkspacesClient {
+            client,
+            region: region_for_service(region, "WorkSpaces"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> WorkspacesClient {
+        WorkspacesClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("WorkSpaces"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WorkspacesClient {
+        WorkspacesClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4795,6 +5400,7 @@ impl Workspaces for WorkspacesClient {
         input: AssociateConnectionAliasRequest,
     ) -> Result<AssociateConnectionAliasResult, RusotoError<AssociateConnectionAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateConnectionAlias");
         request.add_header("x-amz-target", "WorkspacesService.AssociateConnectionAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4814,6 +5420,7 @@ impl Workspaces for WorkspacesClient {
         input: AssociateIpGroupsRequest,
     ) -> Result<AssociateIpGroupsResult, RusotoError<AssociateIpGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateIpGroups");
         request.add_header("x-amz-target", "WorkspacesService.AssociateIpGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4832,6 +5439,7 @@ impl Workspaces for WorkspacesClient {
         input: AuthorizeIpRulesRequest,
     ) -> Result<AuthorizeIpRulesResult, RusotoError<AuthorizeIpRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AuthorizeIpRules");
         request.add_header("x-amz-target", "WorkspacesService.AuthorizeIpRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4850,6 +5458,7 @@ impl Workspaces for WorkspacesClient {
         input: CopyWorkspaceImageRequest,
     ) -> Result<CopyWorkspaceImageResult, RusotoError<CopyWorkspaceImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CopyWorkspaceImage");
         request.add_header("x-amz-target", "WorkspacesService.CopyWorkspaceImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4868,6 +5477,7 @@ impl Workspaces for WorkspacesClient {
         input: CreateConnectionAliasRequest,
     ) -> Result<CreateConnectionAliasResult, RusotoError<CreateConnectionAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateConnectionAlias");
         request.add_header("x-amz-target", "WorkspacesService.CreateConnectionAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4886,6 +5496,7 @@ impl Workspaces for WorkspacesClient {
         input: CreateIpGroupRequest,
     ) -> Result<CreateIpGroupResult, RusotoError<CreateIpGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateIpGroup");
         request.add_header("x-amz-target", "WorkspacesService.CreateIpGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4904,6 +5515,7 @@ impl Workspaces for WorkspacesClient {
         input: CreateTagsRequest,
     ) -> Result<CreateTagsResult, RusotoError<CreateTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTags");
         request.add_header("x-amz-target", "WorkspacesService.CreateTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4922,6 +5534,7 @@ impl Workspaces for WorkspacesClient {
         input: CreateWorkspacesRequest,
     ) -> Result<CreateWorkspacesResult, RusotoError<CreateWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateWorkspaces");
         request.add_header("x-amz-target", "WorkspacesService.CreateWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4940,6 +5553,7 @@ impl Workspaces for WorkspacesClient {
         input: DeleteConnectionAliasRequest,
     ) -> Result<DeleteConnectionAliasResult, RusotoError<DeleteConnectionAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteConnectionAlias");
         request.add_header("x-amz-target", "WorkspacesService.DeleteConnectionAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4958,6 +5572,7 @@ impl Workspaces for WorkspacesClient {
         input: DeleteIpGroupRequest,
     ) -> Result<DeleteIpGroupResult, RusotoError<DeleteIpGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteIpGroup");
         request.add_header("x-amz-target", "WorkspacesService.DeleteIpGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4976,6 +5591,7 @@ impl Workspaces for WorkspacesClient {
         input: DeleteTagsRequest,
     ) -> Result<DeleteTagsResult, RusotoError<DeleteTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTags");
         request.add_header("x-amz-target", "WorkspacesService.DeleteTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4994,6 +5610,7 @@ impl Workspaces for WorkspacesClient {
         input: DeleteWorkspaceImageRequest,
     ) -> Result<DeleteWorkspaceImageResult, RusotoError<DeleteWorkspaceImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteWorkspaceImage");
         request.add_header("x-amz-target", "WorkspacesService.DeleteWorkspaceImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5013,6 +5630,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DeregisterWorkspaceDirectoryResult, RusotoError<DeregisterWorkspaceDirectoryError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterWorkspaceDirectory");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DeregisterWorkspaceDirectory",
@@ -5034,6 +5652,7 @@ impl Workspaces for WorkspacesClient {
         &self,
     ) -> Result<DescribeAccountResult, RusotoError<DescribeAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAccount");
         request.add_header("x-amz-target", "WorkspacesService.DescribeAccount");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -5052,6 +5671,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DescribeAccountModificationsResult, RusotoError<DescribeAccountModificationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAccountModifications");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DescribeAccountModifications",
@@ -5074,6 +5694,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeClientPropertiesRequest,
     ) -> Result<DescribeClientPropertiesResult, RusotoError<DescribeClientPropertiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeClientProperties");
         request.add_header("x-amz-target", "WorkspacesService.DescribeClientProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5096,6 +5717,7 @@ impl Workspaces for WorkspacesClient {
         RusotoError<DescribeConnectionAliasPermissionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConnectionAliasPermissions");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DescribeConnectionAliasPermissions",
@@ -5121,6 +5743,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeConnectionAliasesRequest,
     ) -> Result<DescribeConnectionAliasesResult, RusotoError<DescribeConnectionAliasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConnectionAliases");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DescribeConnectionAliases",
@@ -5143,6 +5766,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeIpGroupsRequest,
     ) -> Result<DescribeIpGroupsResult, RusotoError<DescribeIpGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeIpGroups");
         request.add_header("x-amz-target", "WorkspacesService.DescribeIpGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5161,6 +5785,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeTagsRequest,
     ) -> Result<DescribeTagsResult, RusotoError<DescribeTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTags");
         request.add_header("x-amz-target", "WorkspacesService.DescribeTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5179,6 +5804,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeWorkspaceBundlesRequest,
     ) -> Result<DescribeWorkspaceBundlesResult, RusotoError<DescribeWorkspaceBundlesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeWorkspaceBundles");
         request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaceBundles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5199,6 +5825,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DescribeWorkspaceDirectoriesResult, RusotoError<DescribeWorkspaceDirectoriesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeWorkspaceDirectories");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DescribeWorkspaceDirectories",
@@ -5224,6 +5851,7 @@ impl Workspaces for WorkspacesClient {
         RusotoError<DescribeWorkspaceImagePermissionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeWorkspaceImagePermissions");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DescribeWorkspaceImagePermissions",
@@ -5249,6 +5877,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeWorkspaceImagesRequest,
     ) -> Result<DescribeWorkspaceImagesResult, RusotoError<DescribeWorkspaceImagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeWorkspaceImages");
         request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaceImages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5269,6 +5898,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DescribeWorkspaceSnapshotsResult, RusotoError<DescribeWorkspaceSnapshotsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeWorkspaceSnapshots");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DescribeWorkspaceSnapshots",
@@ -5291,6 +5921,7 @@ impl Workspaces for WorkspacesClient {
         input: DescribeWorkspacesRequest,
     ) -> Result<DescribeWorkspacesResult, RusotoError<DescribeWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeWorkspaces");
         request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5312,6 +5943,7 @@ impl Workspaces for WorkspacesClient {
         RusotoError<DescribeWorkspacesConnectionStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeWorkspacesConnectionStatus");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DescribeWorkspacesConnectionStatus",
@@ -5338,6 +5970,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DisassociateConnectionAliasResult, RusotoError<DisassociateConnectionAliasError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateConnectionAlias");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DisassociateConnectionAlias",
@@ -5360,6 +5993,7 @@ impl Workspaces for WorkspacesClient {
         input: DisassociateIpGroupsRequest,
     ) -> Result<DisassociateIpGroupsResult, RusotoError<DisassociateIpGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateIpGroups");
         request.add_header("x-amz-target", "WorkspacesService.DisassociateIpGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5378,6 +6012,7 @@ impl Workspaces for WorkspacesClient {
         input: ImportWorkspaceImageRequest,
     ) -> Result<ImportWorkspaceImageResult, RusotoError<ImportWorkspaceImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportWorkspaceImage");
         request.add_header("x-amz-target", "WorkspacesService.ImportWorkspaceImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5399,6 +6034,7 @@ impl Workspaces for WorkspacesClient {
         RusotoError<ListAvailableManagementCidrRangesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAvailableManagementCidrRanges");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.ListAvailableManagementCidrRanges",
@@ -5424,6 +6060,7 @@ impl Workspaces for WorkspacesClient {
         input: MigrateWorkspaceRequest,
     ) -> Result<MigrateWorkspaceResult, RusotoError<MigrateWorkspaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("MigrateWorkspace");
         request.add_header("x-amz-target", "WorkspacesService.MigrateWorkspace");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5442,6 +6079,7 @@ impl Workspaces for WorkspacesClient {
         input: ModifyAccountRequest,
     ) -> Result<ModifyAccountResult, RusotoError<ModifyAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyAccount");
         request.add_header("x-amz-target", "WorkspacesService.ModifyAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5460,6 +6098,7 @@ impl Workspaces for WorkspacesClient {
         input: ModifyClientPropertiesRequest,
     ) -> Result<ModifyClientPropertiesResult, RusotoError<ModifyClientPropertiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyClientProperties");
         request.add_header("x-amz-target", "WorkspacesService.ModifyClientProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5480,6 +6119,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<ModifySelfservicePermissionsResult, RusotoError<ModifySelfservicePermissionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifySelfservicePermissions");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.ModifySelfservicePermissions",
@@ -5505,6 +6145,7 @@ impl Workspaces for WorkspacesClient {
         RusotoError<ModifyWorkspaceAccessPropertiesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyWorkspaceAccessProperties");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.ModifyWorkspaceAccessProperties",
@@ -5530,6 +6171,7 @@ impl Workspaces for WorkspacesClient {
         RusotoError<ModifyWorkspaceCreationPropertiesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyWorkspaceCreationProperties");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.ModifyWorkspaceCreationProperties",
@@ -5555,6 +6197,7 @@ impl Workspaces for WorkspacesClient {
         input: ModifyWorkspacePropertiesRequest,
     ) -> Result<ModifyWorkspacePropertiesResult, RusotoError<ModifyWorkspacePropertiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyWorkspaceProperties");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.ModifyWorkspaceProperties",
@@ -5577,6 +6220,7 @@ impl Workspaces for WorkspacesClient {
         input: ModifyWorkspaceStateRequest,
     ) -> Result<ModifyWorkspaceStateResult, RusotoError<ModifyWorkspaceStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyWorkspaceState");
         request.add_header("x-amz-target", "WorkspacesService.ModifyWorkspaceState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5595,6 +6239,7 @@ impl Workspaces for WorkspacesClient {
         input: RebootWorkspacesRequest,
     ) -> Result<RebootWorkspacesResult, RusotoError<RebootWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RebootWorkspaces");
         request.add_header("x-amz-target", "WorkspacesService.RebootWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5613,6 +6258,7 @@ impl Workspaces for WorkspacesClient {
         input: RebuildWorkspacesRequest,
     ) -> Result<RebuildWorkspacesResult, RusotoError<RebuildWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RebuildWorkspaces");
         request.add_header("x-amz-target", "WorkspacesService.RebuildWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5632,6 +6278,7 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<RegisterWorkspaceDirectoryResult, RusotoError<RegisterWorkspaceDirectoryError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterWorkspaceDirectory");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.RegisterWorkspaceDirectory",
@@ -5654,6 +6301,7 @@ impl Workspaces for WorkspacesClient {
         input: RestoreWorkspaceRequest,
     ) -> Result<RestoreWorkspaceResult, RusotoError<RestoreWorkspaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RestoreWorkspace");
         request.add_header("x-amz-target", "WorkspacesService.RestoreWorkspace");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5672,6 +6320,7 @@ impl Workspaces for WorkspacesClient {
         input: RevokeIpRulesRequest,
     ) -> Result<RevokeIpRulesResult, RusotoError<RevokeIpRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RevokeIpRules");
         request.add_header("x-amz-target", "WorkspacesService.RevokeIpRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5690,6 +6339,7 @@ impl Workspaces for WorkspacesClient {
         input: StartWorkspacesRequest,
     ) -> Result<StartWorkspacesResult, RusotoError<StartWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartWorkspaces");
         request.add_header("x-amz-target", "WorkspacesService.StartWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5708,6 +6358,7 @@ impl Workspaces for WorkspacesClient {
         input: StopWorkspacesRequest,
     ) -> Result<StopWorkspacesResult, RusotoError<StopWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopWorkspaces");
         request.add_header("x-amz-target", "WorkspacesService.StopWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5726,6 +6377,7 @@ impl Workspaces for WorkspacesClient {
         input: TerminateWorkspacesRequest,
     ) -> Result<TerminateWorkspacesResult, RusotoError<TerminateWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TerminateWorkspaces");
         request.add_header("x-amz-target", "WorkspacesService.TerminateWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5747,6 +6399,7 @@ impl Workspaces for WorkspacesClient {
         RusotoError<UpdateConnectionAliasPermissionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateConnectionAliasPermission");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.UpdateConnectionAliasPermission",
@@ -5769,6 +6422,7 @@ impl Workspaces for WorkspacesClient {
         input: UpdateRulesOfIpGroupRequest,
     ) -> Result<UpdateRulesOfIpGroupResult, RusotoError<UpdateRulesOfIpGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRulesOfIpGroup");
         request.add_header("x-amz-target", "WorkspacesService.UpdateRulesOfIpGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5790,6 +6444,7 @@ impl Workspaces for WorkspacesClient {
         RusotoError<UpdateWorkspaceImagePermissionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateWorkspaceImagePermission");
         request.add_header(
             "x-amz-target",
             "WorkspacesService.UpdateWorkspaceImagePermission",
@@ -5806,3 +6461,541 @@ impl Workspaces for WorkspacesClient {
             .deserialize::<UpdateWorkspaceImagePermissionResult, _>()
     }
 }
+
+/// A blocking client for the Amazon WorkSpaces API, running the calls of a `WorkspacesClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `WorkspacesClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WorkspacesBlockingClient {
+    client: WorkspacesClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WorkspacesBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> WorkspacesBlockingClient {
+        WorkspacesBlockingClient::from_client(WorkspacesClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorkspacesBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WorkspacesBlockingClient::from_client(WorkspacesClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> WorkspacesBlockingClient {
+        WorkspacesBlockingClient::from_client(WorkspacesClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> WorkspacesBlockingClient {
+        WorkspacesBlockingClient::from_client(WorkspacesClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: WorkspacesClient) -> WorkspacesBlockingClient {
+        WorkspacesBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> WorkspacesBlockingClient {
+        WorkspacesBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> WorkspacesBlockingClient {
+        WorkspacesBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Workspaces::associate_connection_alias`].
+    pub fn associate_connection_alias(
+        &self,
+        input: AssociateConnectionAliasRequest,
+    ) -> Result<AssociateConnectionAliasResult, RusotoError<AssociateConnectionAliasError>> {
+        self.runtime
+            .block_on(self.client.associate_connection_alias(input))
+    }
+
+    /// Blocking version of [`Workspaces::associate_ip_groups`].
+    pub fn associate_ip_groups(
+        &self,
+        input: AssociateIpGroupsRequest,
+    ) -> Result<AssociateIpGroupsResult, RusotoError<AssociateIpGroupsError>> {
+        self.runtime
+            .block_on(self.client.associate_ip_groups(input))
+    }
+
+    /// Blocking version of [`Workspaces::authorize_ip_rules`].
+    pub fn authorize_ip_rules(
+        &self,
+        input: AuthorizeIpRulesRequest,
+    ) -> Result<AuthorizeIpRulesResult, RusotoError<AuthorizeIpRulesError>> {
+        self.runtime.block_on(self.client.authorize_ip_rules(input))
+    }
+
+    /// Blocking version of [`Workspaces::copy_workspace_image`].
+    pub fn copy_workspace_image(
+        &self,
+        input: CopyWorkspaceImageRequest,
+    ) -> Result<CopyWorkspaceImageResult, RusotoError<CopyWorkspaceImageError>> {
+        self.runtime
+            .block_on(self.client.copy_workspace_image(input))
+    }
+
+    /// Blocking version of [`Workspaces::create_connection_alias`].
+    pub fn create_connection_alias(
+        &self,
+        input: CreateConnectionAliasRequest,
+    ) -> Result<CreateConnectionAliasResult, RusotoError<CreateConnectionAliasError>> {
+        self.runtime
+            .block_on(self.client.create_connection_alias(input))
+    }
+
+    /// Blocking version of [`Workspaces::create_ip_group`].
+    pub fn create_ip_group(
+        &self,
+        input: CreateIpGroupRequest,
+    ) -> Result<CreateIpGroupResult, RusotoError<CreateIpGroupError>> {
+        self.runtime.block_on(self.client.create_ip_group(input))
+    }
+
+    /// Blocking version of [`Workspaces::create_tags`].
+    pub fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<CreateTagsResult, RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// Blocking version of [`Workspaces::create_workspaces`].
+    pub fn create_workspaces(
+        &self,
+        input: CreateWorkspacesRequest,
+    ) -> Result<CreateWorkspacesResult, RusotoError<CreateWorkspacesError>> {
+        self.runtime.block_on(self.client.create_workspaces(input))
+    }
+
+    /// Blocking version of [`Workspaces::delete_connection_alias`].
+    pub fn delete_connection_alias(
+        &self,
+        input: DeleteConnectionAliasRequest,
+    ) -> Result<DeleteConnectionAliasResult, RusotoError<DeleteConnectionAliasError>> {
+        self.runtime
+            .block_on(self.client.delete_connection_alias(input))
+    }
+
+    /// Blocking version of [`Workspaces::delete_ip_group`].
+    pub fn delete_ip_group(
+        &self,
+        input: DeleteIpGroupRequest,
+    ) -> Result<DeleteIpGroupResult, RusotoError<DeleteIpGroupError>> {
+        self.runtime.block_on(self.client.delete_ip_group(input))
+    }
+
+    /// Blocking version of [`Workspaces::delete_tags`].
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<DeleteTagsResult, RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// Blocking version of [`Workspaces::delete_workspace_image`].
+    pub fn delete_workspace_image(
+        &self,
+        input: DeleteWorkspaceImageRequest,
+    ) -> Result<DeleteWorkspaceImageResult, RusotoError<DeleteWorkspaceImageError>> {
+        self.runtime
+            .block_on(self.client.delete_workspace_image(input))
+    }
+
+    /// Blocking version of [`Workspaces::deregister_workspace_directory`].
+    pub fn deregister_workspace_directory(
+        &self,
+        input: DeregisterWorkspaceDirectoryRequest,
+    ) -> Result<DeregisterWorkspaceDirectoryResult, RusotoError<DeregisterWorkspaceDirectoryError>>
+    {
+        self.runtime
+            .block_on(self.client.deregister_workspace_directory(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_account`].
+    pub fn describe_account(
+        &self,
+    ) -> Result<DescribeAccountResult, RusotoError<DescribeAccountError>> {
+        self.runtime.block_on(self.client.describe_account())
+    }
+
+    /// Blocking version of [`Workspaces::describe_account_modifications`].
+    pub fn describe_account_modifications(
+        &self,
+        input: DescribeAccountModificationsRequest,
+    ) -> Result<DescribeAccountModificationsResult, RusotoError<DescribeAccountModificationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_account_modifications(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_client_properties`].
+    pub fn describe_client_properties(
+        &self,
+        input: DescribeClientPropertiesRequest,
+    ) -> Result<DescribeClientPropertiesResult, RusotoError<DescribeClientPropertiesError>> {
+        self.runtime
+            .block_on(self.client.describe_client_properties(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_connection_alias_permissions`].
+    pub fn describe_connection_alias_permissions(
+        &self,
+        input: DescribeConnectionAliasPermissionsRequest,
+    ) -> Result<
+        DescribeConnectionAliasPermissionsResult,
+        RusotoError<DescribeConnectionAliasPermissionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_connection_alias_permissions(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_connection_aliases`].
+    pub fn describe_connection_aliases(
+        &self,
+        input: DescribeConnectionAliasesRequest,
+    ) -> Result<DescribeConnectionAliasesResult, RusotoError<DescribeConnectionAliasesError>> {
+        self.runtime
+            .block_on(self.client.describe_connection_aliases(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_ip_groups`].
+    pub fn describe_ip_groups(
+        &self,
+        input: DescribeIpGroupsRequest,
+    ) -> Result<DescribeIpGroupsResult, RusotoError<DescribeIpGroupsError>> {
+        self.runtime.block_on(self.client.describe_ip_groups(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_tags`].
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResult, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_workspace_bundles`].
+    pub fn describe_workspace_bundles(
+        &self,
+        input: DescribeWorkspaceBundlesRequest,
+    ) -> Result<DescribeWorkspaceBundlesResult, RusotoError<DescribeWorkspaceBundlesError>> {
+        self.runtime
+            .block_on(self.client.describe_workspace_bundles(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_workspace_directories`].
+    pub fn describe_workspace_directories(
+        &self,
+        input: DescribeWorkspaceDirectoriesRequest,
+    ) -> Result<DescribeWorkspaceDirectoriesResult, RusotoError<DescribeWorkspaceDirectoriesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_workspace_directories(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_workspace_image_permissions`].
+    pub fn describe_workspace_image_permissions(
+        &self,
+        input: DescribeWorkspaceImagePermissionsRequest,
+    ) -> Result<
+        DescribeWorkspaceImagePermissionsResult,
+        RusotoError<DescribeWorkspaceImagePermissionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_workspace_image_permissions(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_workspace_images`].
+    pub fn describe_workspace_images(
+        &self,
+        input: DescribeWorkspaceImagesRequest,
+    ) -> Result<DescribeWorkspaceImagesResult, RusotoError<DescribeWorkspaceImagesError>> {
+        self.runtime
+            .block_on(self.client.describe_workspace_images(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_workspace_snapshots`].
+    pub fn describe_workspace_snapshots(
+        &self,
+        input: DescribeWorkspaceSnapshotsRequest,
+    ) -> Result<DescribeWorkspaceSnapshotsResult, RusotoError<DescribeWorkspaceSnapshotsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_workspace_snapshots(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_workspaces`].
+    pub fn describe_workspaces(
+        &self,
+        input: DescribeWorkspacesRequest,
+    ) -> Result<DescribeWorkspacesResult, RusotoError<DescribeWorkspacesError>> {
+        self.runtime
+            .block_on(self.client.describe_workspaces(input))
+    }
+
+    /// Blocking version of [`Workspaces::describe_workspaces_connection_status`].
+    pub fn describe_workspaces_connection_status(
+        &self,
+        input: DescribeWorkspacesConnectionStatusRequest,
+    ) -> Result<
+        DescribeWorkspacesConnectionStatusResult,
+        RusotoError<DescribeWorkspacesConnectionStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_workspaces_connection_status(input))
+    }
+
+    /// Blocking version of [`Workspaces::disassociate_connection_alias`].
+    pub fn disassociate_connection_alias(
+        &self,
+        input: DisassociateConnectionAliasRequest,
+    ) -> Result<DisassociateConnectionAliasResult, RusotoError<DisassociateConnectionAliasError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_connection_alias(input))
+    }
+
+    /// Blocking version of [`Workspaces::disassociate_ip_groups`].
+    pub fn disassociate_ip_groups(
+        &self,
+        input: DisassociateIpGroupsRequest,
+    ) -> Result<DisassociateIpGroupsResult, RusotoError<DisassociateIpGroupsError>> {
+        self.runtime
+            .block_on(self.client.disassociate_ip_groups(input))
+    }
+
+    /// Blocking version of [`Workspaces::import_workspace_image`].
+    pub fn import_workspace_image(
+        &self,
+        input: ImportWorkspaceImageRequest,
+    ) -> Result<ImportWorkspaceImageResult, RusotoError<ImportWorkspaceImageError>> {
+        self.runtime
+            .block_on(self.client.import_workspace_image(input))
+    }
+
+    /// Blocking version of [`Workspaces::list_available_management_cidr_ranges`].
+    pub fn list_available_management_cidr_ranges(
+        &self,
+        input: ListAvailableManagementCidrRangesRequest,
+    ) -> Result<
+        ListAvailableManagementCidrRangesResult,
+        RusotoError<ListAvailableManagementCidrRangesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_available_management_cidr_ranges(input))
+    }
+
+    /// Blocking version of [`Workspaces::migrate_workspace`].
+    pub fn migrate_workspace(
+        &self,
+        input: MigrateWorkspaceRequest,
+    ) -> Result<MigrateWorkspaceResult, RusotoError<MigrateWorkspaceError>> {
+        self.runtime.block_on(self.client.migrate_workspace(input))
+    }
+
+    /// Blocking version of [`Workspaces::modify_account`].
+    pub fn modify_account(
+        &self,
+        input: ModifyAccountRequest,
+    ) -> Result<ModifyAccountResult, RusotoError<ModifyAccountError>> {
+        self.runtime.block_on(self.client.modify_account(input))
+    }
+
+    /// Blocking version of [`Workspaces::modify_client_properties`].
+    pub fn modify_client_properties(
+        &self,
+        input: ModifyClientPropertiesRequest,
+    ) -> Result<ModifyClientPropertiesResult, RusotoError<ModifyClientPropertiesError>> {
+        self.runtime
+            .block_on(self.client.modify_client_properties(input))
+    }
+
+    /// Blocking version of [`Workspaces::modify_selfservice_permissions`].
+    pub fn modify_selfservice_permissions(
+        &self,
+        input: ModifySelfservicePermissionsRequest,
+    ) -> Result<ModifySelfservicePermissionsResult, RusotoError<ModifySelfservicePermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_selfservice_permissions(input))
+    }
+
+    /// Blocking version of [`Workspaces::modify_workspace_access_properties`].
+    pub fn modify_workspace_access_properties(
+        &self,
+        input: ModifyWorkspaceAccessPropertiesRequest,
+    ) -> Result<
+        ModifyWorkspaceAccessPropertiesResult,
+        RusotoError<ModifyWorkspaceAccessPropertiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_workspace_access_properties(input))
+    }
+
+    /// Blocking version of [`Workspaces::modify_workspace_creation_properties`].
+    pub fn modify_workspace_creation_properties(
+        &self,
+        input: ModifyWorkspaceCreationPropertiesRequest,
+    ) -> Result<
+        ModifyWorkspaceCreationPropertiesResult,
+        RusotoError<ModifyWorkspaceCreationPropertiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_workspace_creation_properties(input))
+    }
+
+    /// Blocking version of [`Workspaces::modify_workspace_properties`].
+    pub fn modify_workspace_properties(
+        &self,
+        input: ModifyWorkspacePropertiesRequest,
+    ) -> Result<ModifyWorkspacePropertiesResult, RusotoError<ModifyWorkspacePropertiesError>> {
+        self.runtime
+            .block_on(self.client.modify_workspace_properties(input))
+    }
+
+    /// Blocking version of [`Workspaces::modify_workspace_state`].
+    pub fn modify_workspace_state(
+        &self,
+        input: ModifyWorkspaceStateRequest,
+    ) -> Result<ModifyWorkspaceStateResult, RusotoError<ModifyWorkspaceStateError>> {
+        self.runtime
+            .block_on(self.client.modify_workspace_state(input))
+    }
+
+    /// Blocking version of [`Workspaces::reboot_workspaces`].
+    pub fn reboot_workspaces(
+        &self,
+        input: RebootWorkspacesRequest,
+    ) -> Result<RebootWorkspacesResult, RusotoError<RebootWorkspacesError>> {
+        self.runtime.block_on(self.client.reboot_workspaces(input))
+    }
+
+    /// Blocking version of [`Workspaces::rebuild_workspaces`].
+    pub fn rebuild_workspaces(
+        &self,
+        input: RebuildWorkspacesRequest,
+    ) -> Result<RebuildWorkspacesResult, RusotoError<RebuildWorkspacesError>> {
+        self.runtime.block_on(self.client.rebuild_workspaces(input))
+    }
+
+    /// Blocking version of [`Workspaces::register_workspace_directory`].
+    pub fn register_workspace_directory(
+        &self,
+        input: RegisterWorkspaceDirectoryRequest,
+    ) -> Result<RegisterWorkspaceDirectoryResult, RusotoError<RegisterWorkspaceDirectoryError>>
+    {
+        self.runtime
+            .block_on(self.client.register_workspace_directory(input))
+    }
+
+    /// Blocking version of [`Workspaces::restore_workspace`].
+    pub fn restore_workspace(
+        &self,
+        input: RestoreWorkspaceRequest,
+    ) -> Result<RestoreWorkspaceResult, RusotoError<RestoreWorkspaceError>> {
+        self.runtime.block_on(self.client.restore_workspace(input))
+    }
+
+    /// Blocking version of [`Workspaces::revoke_ip_rules`].
+    pub fn revoke_ip_rules(
+        &self,
+        input: RevokeIpRulesRequest,
+    ) -> Result<RevokeIpRulesResult, RusotoError<RevokeIpRulesError>> {
+        self.runtime.block_on(self.client.revoke_ip_rules(input))
+    }
+
+    /// Blocking version of [`Workspaces::start_workspaces`].
+    pub fn start_workspaces(
+        &self,
+        input: StartWorkspacesRequest,
+    ) -> Result<StartWorkspacesResult, RusotoError<StartWorkspacesError>> {
+        self.runtime.block_on(self.client.start_workspaces(input))
+    }
+
+    /// Blocking version of [`Workspaces::stop_workspaces`].
+    pub fn stop_workspaces(
+        &self,
+        input: StopWorkspacesRequest,
+    ) -> Result<StopWorkspacesResult, RusotoError<StopWorkspacesError>> {
+        self.runtime.block_on(self.client.stop_workspaces(input))
+    }
+
+    /// Blocking version of [`Workspaces::terminate_workspaces`].
+    pub fn terminate_workspaces(
+        &self,
+        input: TerminateWorkspacesRequest,
+    ) -> Result<TerminateWorkspacesResult, RusotoError<TerminateWorkspacesError>> {
+        self.runtime
+            .block_on(self.client.terminate_workspaces(input))
+    }
+
+    /// Blocking version of [`Workspaces::update_connection_alias_permission`].
+    pub fn update_connection_alias_permission(
+        &self,
+        input: UpdateConnectionAliasPermissionRequest,
+    ) -> Result<
+        UpdateConnectionAliasPermissionResult,
+        RusotoError<UpdateConnectionAliasPermissionError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_connection_alias_permission(input))
+    }
+
+    /// Blocking version of [`Workspaces::update_rules_of_ip_group`].
+    pub fn update_rules_of_ip_group(
+        &self,
+        input: UpdateRulesOfIpGroupRequest,
+    ) -> Result<UpdateRulesOfIpGroupResult, RusotoError<UpdateRulesOfIpGroupError>> {
+        self.runtime
+            .block_on(self.client.update_rules_of_ip_group(input))
+    }
+
+    /// Blocking version of [`Workspaces::update_workspace_image_permission`].
+    pub fn update_workspace_image_permission(
+        &self,
+        input: UpdateWorkspaceImagePermissionRequest,
+    ) -> Result<
+        UpdateWorkspaceImagePermissionResult,
+        RusotoError<UpdateWorkspaceImagePermissionError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_workspace_image_permission(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2046,12 +2047,22 @@ impl BatchGetTracesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchGetTracesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetTracesError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(BatchGetTracesError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetTracesError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2082,12 +2093,22 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(CreateGroupError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2120,17 +2141,30 @@ impl CreateSamplingRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSamplingRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSamplingRuleError::InvalidRequest,
+                    )
                 }
                 "RuleLimitExceededException" => {
-                    return RusotoError::Service(CreateSamplingRuleError::RuleLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSamplingRuleError::RuleLimitExceeded,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(CreateSamplingRuleError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSamplingRuleError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2162,12 +2196,22 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(DeleteGroupError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2198,12 +2242,22 @@ impl DeleteSamplingRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSamplingRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSamplingRuleError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(DeleteSamplingRuleError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSamplingRuleError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2234,12 +2288,22 @@ impl GetEncryptionConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetEncryptionConfigError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEncryptionConfigError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetEncryptionConfigError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEncryptionConfigError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2270,12 +2334,22 @@ impl GetGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetGroupError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2306,12 +2380,22 @@ impl GetGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupsError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetGroupsError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupsError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2342,12 +2426,22 @@ impl GetInsightError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetInsightError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetInsightError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2378,12 +2472,22 @@ impl GetInsightEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetInsightEventsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightEventsError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetInsightEventsError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightEventsError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2414,14 +2518,22 @@ impl GetInsightImpactGraphError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetInsightImpactGraphError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInsightImpactGraphError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetInsightImpactGraphError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightImpactGraphError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2452,12 +2564,22 @@ impl GetInsightSummariesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetInsightSummariesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightSummariesError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetInsightSummariesError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightSummariesError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2488,12 +2610,22 @@ impl GetSamplingRulesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSamplingRulesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSamplingRulesError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetSamplingRulesError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSamplingRulesError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2526,16 +2658,22 @@ impl GetSamplingStatisticSummariesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        GetSamplingStatisticSummariesError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSamplingStatisticSummariesError::InvalidRequest,
                     )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetSamplingStatisticSummariesError::Throttled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSamplingStatisticSummariesError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2566,12 +2704,22 @@ impl GetSamplingTargetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSamplingTargetsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSamplingTargetsError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetSamplingTargetsError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSamplingTargetsError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2602,12 +2750,22 @@ impl GetServiceGraphError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetServiceGraphError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceGraphError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetServiceGraphError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceGraphError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2640,16 +2798,22 @@ impl GetTimeSeriesServiceStatisticsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        GetTimeSeriesServiceStatisticsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTimeSeriesServiceStatisticsError::InvalidRequest,
                     )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetTimeSeriesServiceStatisticsError::Throttled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTimeSeriesServiceStatisticsError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2682,12 +2846,22 @@ impl GetTraceGraphError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetTraceGraphError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTraceGraphError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetTraceGraphError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTraceGraphError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2718,12 +2892,22 @@ impl GetTraceSummariesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetTraceSummariesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTraceSummariesError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetTraceSummariesError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTraceSummariesError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2756,17 +2940,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2798,12 +2995,22 @@ impl PutEncryptionConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutEncryptionConfigError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEncryptionConfigError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(PutEncryptionConfigError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEncryptionConfigError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2834,12 +3041,22 @@ impl PutTelemetryRecordsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutTelemetryRecordsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutTelemetryRecordsError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(PutTelemetryRecordsError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutTelemetryRecordsError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2870,12 +3087,22 @@ impl PutTraceSegmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutTraceSegmentsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutTraceSegmentsError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(PutTraceSegmentsError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutTraceSegmentsError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2910,18 +3137,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(TagResourceError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Throttled,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2956,15 +3203,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(UntagResourceError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2996,12 +3258,22 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(UpdateGroupError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3032,12 +3304,22 @@ impl UpdateSamplingRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSamplingRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSamplingRuleError::InvalidRequest,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(UpdateSamplingRuleError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSamplingRuleError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3231,11 +3513,12 @@ pub struct XRayClient {
 impl XRayClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> XRayClient {
         XRayClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "XRay"),
         }
     }
 
@@ -3250,12 +3533,32 @@ impl XRayClient {
     {
         XRayClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "XRay"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> XRayClient {
-        XRayClient { client, region }
+        XRayClient {
+            client,
+            region: region_for_service(region, "XRay"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> XRayClient {
+        XRayClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("XRay"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> XRayClient {
+        XRayClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3270,6 +3573,7 @@ impl XRay for XRayClient {
         let request_uri = "/Traces";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("BatchGetTraces");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3301,6 +3605,7 @@ impl XRay for XRayClient {
         let request_uri = "/CreateGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3332,6 +3637,7 @@ impl XRay for XRayClient {
         let request_uri = "/CreateSamplingRule";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("CreateSamplingRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3363,6 +3669,7 @@ impl XRay for XRayClient {
         let request_uri = "/DeleteGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3394,6 +3701,7 @@ impl XRay for XRayClient {
         let request_uri = "/DeleteSamplingRule";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("DeleteSamplingRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3424,6 +3732,7 @@ impl XRay for XRayClient {
         let request_uri = "/EncryptionConfig";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetEncryptionConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3452,6 +3761,7 @@ impl XRay for XRayClient {
         let request_uri = "/GetGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3483,6 +3793,7 @@ impl XRay for XRayClient {
         let request_uri = "/Groups";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3514,6 +3825,7 @@ impl XRay for XRayClient {
         let request_uri = "/Insight";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetInsight");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3545,6 +3857,7 @@ impl XRay for XRayClient {
         let request_uri = "/InsightEvents";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetInsightEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3576,6 +3889,7 @@ impl XRay for XRayClient {
         let request_uri = "/InsightImpactGraph";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetInsightImpactGraph");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3607,6 +3921,7 @@ impl XRay for XRayClient {
         let request_uri = "/InsightSummaries";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetInsightSummaries");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3638,6 +3953,7 @@ impl XRay for XRayClient {
         let request_uri = "/GetSamplingRules";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetSamplingRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3670,6 +3986,7 @@ impl XRay for XRayClient {
         let request_uri = "/SamplingStatisticSummaries";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetSamplingStatisticSummaries");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3701,6 +4018,7 @@ impl XRay for XRayClient {
         let request_uri = "/SamplingTargets";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetSamplingTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3732,6 +4050,7 @@ impl XRay for XRayClient {
         let request_uri = "/ServiceGraph";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetServiceGraph");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3766,6 +4085,7 @@ impl XRay for XRayClient {
         let request_uri = "/TimeSeriesServiceStatistics";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetTimeSeriesServiceStatistics");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3797,6 +4117,7 @@ impl XRay for XRayClient {
         let request_uri = "/TraceGraph";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetTraceGraph");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3828,6 +4149,7 @@ impl XRay for XRayClient {
         let request_uri = "/TraceSummaries";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetTraceSummaries");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3859,6 +4181,7 @@ impl XRay for XRayClient {
         let request_uri = "/ListTagsForResource";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3890,6 +4213,7 @@ impl XRay for XRayClient {
         let request_uri = "/PutEncryptionConfig";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("PutEncryptionConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3921,6 +4245,7 @@ impl XRay for XRayClient {
         let request_uri = "/TelemetryRecords";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("PutTelemetryRecords");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3952,6 +4277,7 @@ impl XRay for XRayClient {
         let request_uri = "/TraceSegments";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("PutTraceSegments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3983,6 +4309,7 @@ impl XRay for XRayClient {
         let request_uri = "/TagResource";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4014,6 +4341,7 @@ impl XRay for XRayClient {
         let request_uri = "/UntagResource";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4045,6 +4373,7 @@ impl XRay for XRayClient {
         let request_uri = "/UpdateGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4076,6 +4405,7 @@ impl XRay for XRayClient {
         let request_uri = "/UpdateSamplingRule";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("UpdateSamplingRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4098,3 +4428,309 @@ impl XRay for XRayClient {
         }
     }
 }
+
+/// A blocking client for the AWS X-Ray API, running the calls of a `XRayClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `XRayClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct XRayBlockingClient {
+    client: XRayClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl XRayBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> XRayBlockingClient {
+        XRayBlockingClient::from_client(XRayClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> XRayBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        XRayBlockingClient::from_client(XRayClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> XRayBlockingClient {
+        XRayBlockingClient::from_client(XRayClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> XRayBlockingClient {
+        XRayBlockingClient::from_client(XRayClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: XRayClient) -> XRayBlockingClient {
+        XRayBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> XRayBlockingClient {
+        XRayBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> XRayBlockingClient {
+        XRayBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`XRay::batch_get_traces`].
+    pub fn batch_get_traces(
+        &self,
+        input: BatchGetTracesRequest,
+    ) -> Result<BatchGetTracesResult, RusotoError<BatchGetTracesError>> {
+        self.runtime.block_on(self.client.batch_get_traces(input))
+    }
+
+    /// Blocking version of [`XRay::create_group`].
+    pub fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResult, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// Blocking version of [`XRay::create_sampling_rule`].
+    pub fn create_sampling_rule(
+        &self,
+        input: CreateSamplingRuleRequest,
+    ) -> Result<CreateSamplingRuleResult, RusotoError<CreateSamplingRuleError>> {
+        self.runtime
+            .block_on(self.client.create_sampling_rule(input))
+    }
+
+    /// Blocking version of [`XRay::delete_group`].
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<DeleteGroupResult, RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// Blocking version of [`XRay::delete_sampling_rule`].
+    pub fn delete_sampling_rule(
+        &self,
+        input: DeleteSamplingRuleRequest,
+    ) -> Result<DeleteSamplingRuleResult, RusotoError<DeleteSamplingRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_sampling_rule(input))
+    }
+
+    /// Blocking version of [`XRay::get_encryption_config`].
+    pub fn get_encryption_config(
+        &self,
+    ) -> Result<GetEncryptionConfigResult, RusotoError<GetEncryptionConfigError>> {
+        self.runtime.block_on(self.client.get_encryption_config())
+    }
+
+    /// Blocking version of [`XRay::get_group`].
+    pub fn get_group(
+        &self,
+        input: GetGroupRequest,
+    ) -> Result<GetGroupResult, RusotoError<GetGroupError>> {
+        self.runtime.block_on(self.client.get_group(input))
+    }
+
+    /// Blocking version of [`XRay::get_groups`].
+    pub fn get_groups(
+        &self,
+        input: GetGroupsRequest,
+    ) -> Result<GetGroupsResult, RusotoError<GetGroupsError>> {
+        self.runtime.block_on(self.client.get_groups(input))
+    }
+
+    /// Blocking version of [`XRay::get_insight`].
+    pub fn get_insight(
+        &self,
+        input: GetInsightRequest,
+    ) -> Result<GetInsightResult, RusotoError<GetInsightError>> {
+        self.runtime.block_on(self.client.get_insight(input))
+    }
+
+    /// Blocking version of [`XRay::get_insight_events`].
+    pub fn get_insight_events(
+        &self,
+        input: GetInsightEventsRequest,
+    ) -> Result<GetInsightEventsResult, RusotoError<GetInsightEventsError>> {
+        self.runtime.block_on(self.client.get_insight_events(input))
+    }
+
+    /// Blocking version of [`XRay::get_insight_impact_graph`].
+    pub fn get_insight_impact_graph(
+        &self,
+        input: GetInsightImpactGraphRequest,
+    ) -> Result<GetInsightImpactGraphResult, RusotoError<GetInsightImpactGraphError>> {
+        self.runtime
+            .block_on(self.client.get_insight_impact_graph(input))
+    }
+
+    /// Blocking version of [`XRay::get_insight_summaries`].
+    pub fn get_insight_summaries(
+        &self,
+        input: GetInsightSummariesRequest,
+    ) -> Result<GetInsightSummariesResult, RusotoError<GetInsightSummariesError>> {
+        self.runtime
+            .block_on(self.client.get_insight_summaries(input))
+    }
+
+    /// Blocking version of [`XRay::get_sampling_rules`].
+    pub fn get_sampling_rules(
+        &self,
+        input: GetSamplingRulesRequest,
+    ) -> Result<GetSamplingRulesResult, RusotoError<GetSamplingRulesError>> {
+        self.runtime.block_on(self.client.get_sampling_rules(input))
+    }
+
+    /// Blocking version of [`XRay::get_sampling_statistic_summaries`].
+    pub fn get_sampling_statistic_summaries(
+        &self,
+        input: GetSamplingStatisticSummariesRequest,
+    ) -> Result<GetSamplingStatisticSummariesResult, RusotoError<GetSamplingStatisticSummariesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_sampling_statistic_summaries(input))
+    }
+
+    /// Blocking version of [`XRay::get_sampling_targets`].
+    pub fn get_sampling_targets(
+        &self,
+        input: GetSamplingTargetsRequest,
+    ) -> Result<GetSamplingTargetsResult, RusotoError<GetSamplingTargetsError>> {
+        self.runtime
+            .block_on(self.client.get_sampling_targets(input))
+    }
+
+    /// Blocking version of [`XRay::get_service_graph`].
+    pub fn get_service_graph(
+        &self,
+        input: GetServiceGraphRequest,
+    ) -> Result<GetServiceGraphResult, RusotoError<GetServiceGraphError>> {
+        self.runtime.block_on(self.client.get_service_graph(input))
+    }
+
+    /// Blocking version of [`XRay::get_time_series_service_statistics`].
+    pub fn get_time_series_service_statistics(
+        &self,
+        input: GetTimeSeriesServiceStatisticsRequest,
+    ) -> Result<
+        GetTimeSeriesServiceStatisticsResult,
+        RusotoError<GetTimeSeriesServiceStatisticsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_time_series_service_statistics(input))
+    }
+
+    /// Blocking version of [`XRay::get_trace_graph`].
+    pub fn get_trace_graph(
+        &self,
+        input: GetTraceGraphRequest,
+    ) -> Result<GetTraceGraphResult, RusotoError<GetTraceGraphError>> {
+        self.runtime.block_on(self.client.get_trace_graph(input))
+    }
+
+    /// Blocking version of [`XRay::get_trace_summaries`].
+    pub fn get_trace_summaries(
+        &self,
+        input: GetTraceSummariesRequest,
+    ) -> Result<GetTraceSummariesResult, RusotoError<GetTraceSummariesError>> {
+        self.runtime
+            .block_on(self.client.get_trace_summaries(input))
+    }
+
+    /// Blocking version of [`XRay::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`XRay::put_encryption_config`].
+    pub fn put_encryption_config(
+        &self,
+        input: PutEncryptionConfigRequest,
+    ) -> Result<PutEncryptionConfigResult, RusotoError<PutEncryptionConfigError>> {
+        self.runtime
+            .block_on(self.client.put_encryption_config(input))
+    }
+
+    /// Blocking version of [`XRay::put_telemetry_records`].
+    pub fn put_telemetry_records(
+        &self,
+        input: PutTelemetryRecordsRequest,
+    ) -> Result<PutTelemetryRecordsResult, RusotoError<PutTelemetryRecordsError>> {
+        self.runtime
+            .block_on(self.client.put_telemetry_records(input))
+    }
+
+    /// Blocking version of [`XRay::put_trace_segments`].
+    pub fn put_trace_segments(
+        &self,
+        input: PutTraceSegmentsRequest,
+    ) -> Result<PutTraceSegmentsResult, RusotoError<PutTraceSegmentsError>> {
+        self.runtime.block_on(self.client.put_trace_segments(input))
+    }
+
+    /// Blocking version of [`XRay::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`XRay::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`XRay::update_group`].
+    pub fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<UpdateGroupResult, RusotoError<UpdateGroupError>> {
+        self.runtime.block_on(self.client.update_group(input))
+    }
+
+    /// Blocking version of [`XRay::update_sampling_rule`].
+    pub fn update_sampling_rule(
+        &self,
+        input: UpdateSamplingRuleRequest,
+    ) -> Result<UpdateSamplingRuleResult, RusotoError<UpdateSamplingRuleError>> {
+        self.runtime
+            .block_on(self.client.update_sampling_rule(input))
+    }
+}
@@ -0,0 +1,350 @@
+//! The `aws-chunked` content encoding, signing each chunk of a streaming payload with a
+//! signature chained to the signature of the previous chunk.
+//!
+//! See [Transferring Payload in Multiple Chunks](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming.html)
+//! and [Including Trailing Headers](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming-trailers.html).
+
+use std::io;
+
+use bytes::{Bytes, BytesMut};
+use futures::stream::{self, Fuse, StreamExt};
+use sha2::{Digest, Sha256};
+
+use crate::signature::{ChecksumAlgorithm, EMPTY_SHA256_HASH};
+use crate::stream::ByteStream;
+
+/// The `x-amz-content-sha256` of a payload signed chunk by chunk.
+pub(crate) const STREAMING_PAYLOAD: &str = "STREAMING-AWS4-HMAC-SHA256-PAYLOAD";
+/// The `x-amz-content-sha256` of a payload signed chunk by chunk, followed by a signed checksum.
+pub(crate) const STREAMING_PAYLOAD_TRAILER: &str = "STREAMING-AWS4-HMAC-SHA256-PAYLOAD-TRAILER";
+
+const CHUNK_SIGNATURE: &str = ";chunk-signature=";
+const TRAILER_SIGNATURE: &str = "x-amz-trailer-signature:";
+const SIGNATURE_LENGTH: usize = 64;
+const CRLF: &str = "\r\n";
+
+/// Signs the chunks of a payload, starting from the signature of the request headers.
+pub(crate) struct ChunkSigner {
+    key: Vec<u8>,
+    date_time: String,
+    scope: String,
+    previous_signature: String,
+}
+
+impl ChunkSigner {
+    pub(crate) fn new(key: Vec<u8>, date_time: String, scope: String, seed: String) -> Self {
+        ChunkSigner {
+            key,
+            date_time,
+            scope,
+            previous_signature: seed,
+        }
+    }
+
+    /// Returns the signature of a chunk, the final chunk being empty.
+    pub(crate) fn sign_chunk(&mut self, chunk: &[u8]) -> &str {
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256-PAYLOAD\n{}\n{}\n{}\n{}\n{}",
+            self.date_time,
+            self.scope,
+            self.previous_signature,
+            EMPTY_SHA256_HASH,
+            hex::encode(Sha256::digest(chunk))
+        );
+        self.chain(&string_to_sign)
+    }
+
+    /// Returns the signature of the trailing headers, each of them followed by a newline.
+    pub(crate) fn sign_trailer(&mut self, trailer: &str) -> &str {
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256-TRAILER\n{}\n{}\n{}\n{}",
+            self.date_time,
+            self.scope,
+            self.previous_signature,
+            hex::encode(Sha256::digest(trailer.as_bytes()))
+        );
+        self.chain(&string_to_sign)
+    }
+
+    fn chain(&mut self, string_to_sign: &str) -> &str {
+        self.previous_signature = crate::signature::sign_with_key(&self.key, string_to_sign);
+        &self.previous_signature
+    }
+}
+
+/// Returns the length of a payload once encoded in chunks of the given size.
+pub(crate) fn encoded_length(
+    decoded_length: usize,
+    chunk_size: usize,
+    checksum: Option<ChecksumAlgorithm>,
+) -> usize {
+    let chunk_length = |size: usize| {
+        format!("{:x}", size).len()
+            + CHUNK_SIGNATURE.len()
+            + SIGNATURE_LENGTH
+            + size
+            + 2 * CRLF.len()
+    };
+    let remainder = decoded_length % chunk_size;
+    let mut length = decoded_length / chunk_size * chunk_length(chunk_size);
+    if remainder > 0 {
+        length += chunk_length(remainder);
+    }
+    // The final chunk has no data, nor the newline after it when headers follow.
+    length += chunk_length(0) - CRLF.len();
+    if let Some(checksum) = checksum {
+        length += checksum.header_name().len() + 1 + checksum.encoded_length() + CRLF.len();
+        length += TRAILER_SIGNATURE.len() + SIGNATURE_LENGTH + CRLF.len();
+    }
+    length + CRLF.len()
+}
+
+/// Encodes a stream of the given length in signed chunks of the given size, ending it with
+/// a signed checksum of the payload if one is given.
+pub(crate) fn sign_stream(
+    stream: ByteStream,
+    decoded_length: usize,
+    chunk_size: usize,
+    checksum: Option<ChecksumAlgorithm>,
+    signer: ChunkSigner,
+) -> ByteStream {
+    let encoder = ChunkEncoder {
+        stream: stream.fuse(),
+        buffer: BytesMut::new(),
+        chunk_size,
+        remaining: decoded_length,
+        checksum: checksum.map(Checksum::new),
+        signer,
+        done: false,
+    };
+    ByteStream::new_with_size(
+        stream::unfold(encoder, ChunkEncoder::next_chunk),
+        encoded_length(decoded_length, chunk_size, checksum),
+    )
+}
+
+struct ChunkEncoder {
+    stream: Fuse<ByteStream>,
+    buffer: BytesMut,
+    chunk_size: usize,
+    remaining: usize,
+    checksum: Option<Checksum>,
+    signer: ChunkSigner,
+    done: bool,
+}
+
+impl ChunkEncoder {
+    async fn next_chunk(mut self) -> Option<(Result<Bytes, io::Error>, Self)> {
+        if self.done {
+            return None;
+        }
+        while self.buffer.len() < self.chunk_size {
+            match self.stream.next().await {
+                Some(Ok(bytes)) => self.buffer.extend_from_slice(&bytes),
+                Some(Err(err)) => return self.fail(err),
+                None => break,
+            }
+        }
+        let size = self.buffer.len().min(self.chunk_size);
+        if size > self.remaining {
+            return self.fail(length_mismatch("longer"));
+        }
+        self.remaining -= size;
+        let data = self.buffer.split_to(size);
+        if data.is_empty() {
+            if self.remaining > 0 {
+                return self.fail(length_mismatch("shorter"));
+            }
+            self.done = true;
+            let chunk = self.final_chunk();
+            return Some((Ok(chunk), self));
+        }
+        if let Some(ref mut checksum) = self.checksum {
+            checksum.update(&data);
+        }
+        let signature = self.signer.sign_chunk(&data);
+        let mut chunk = BytesMut::with_capacity(size + 128);
+        chunk.extend_from_slice(
+            format!("{:x}{}{}{}", size, CHUNK_SIGNATURE, signature, CRLF).as_bytes(),
+        );
+        chunk.extend_from_slice(&data);
+        chunk.extend_from_slice(CRLF.as_bytes());
+        Some((Ok(chunk.freeze()), self))
+    }
+
+    fn final_chunk(&mut self) -> Bytes {
+        let mut chunk = format!(
+            "0{}{}{}",
+            CHUNK_SIGNATURE,
+            self.signer.sign_chunk(&[]),
+            CRLF
+        );
+        if let Some(checksum) = self.checksum.take() {
+            let trailer = format!(
+                "{}:{}\n",
+                checksum.algorithm.header_name(),
+                checksum.finish()
+            );
+            let signature = self.signer.sign_trailer(&trailer);
+            chunk.push_str(&trailer.replace('\n', CRLF));
+            chunk.push_str(&format!("{}{}{}", TRAILER_SIGNATURE, signature, CRLF));
+        }
+        chunk.push_str(CRLF);
+        Bytes::from(chunk)
+    }
+
+    fn fail(mut self, err: io::Error) -> Option<(Result<Bytes, io::Error>, Self)> {
+        self.done = true;
+        Some((Err(err), self))
+    }
+}
+
+fn length_mismatch(comparison: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("stream is {} than its declared length", comparison),
+    )
+}
+
+/// A checksum being computed over the chunks of a payload.
+struct Checksum {
+    algorithm: ChecksumAlgorithm,
+    hasher: Hasher,
+}
+
+enum Hasher {
+    Crc32(crc32fast::Hasher),
+    Sha256(Sha256),
+}
+
+impl Checksum {
+    fn new(algorithm: ChecksumAlgorithm) -> Self {
+        let hasher = match algorithm {
+            ChecksumAlgorithm::Crc32 => Hasher::Crc32(crc32fast::Hasher::new()),
+            ChecksumAlgorithm::Sha256 => Hasher::Sha256(Sha256::new()),
+        };
+        Checksum { algorithm, hasher }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self.hasher {
+            Hasher::Crc32(ref mut hasher) => hasher.update(data),
+            Hasher::Sha256(ref mut hasher) => hasher.update(data),
+        }
+    }
+
+    /// Returns the base64 encoded checksum.
+    fn finish(self) -> String {
+        match self.hasher {
+            Hasher::Crc32(hasher) => base64::encode(hasher.finalize().to_be_bytes()),
+            Hasher::Sha256(hasher) => base64::encode(hasher.finalize()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signature::signing_key;
+    use chrono::NaiveDate;
+    use futures::TryStreamExt;
+
+    // The example of the S3 documentation, uploading 66560 bytes in chunks of 64 KiB.
+    const SEED_SIGNATURE: &str = "4f232c4386841ef735655705268965c44a0e4690baa4adea153f7db9fa80a0a9";
+    const CHUNK_SIGNATURES: [&str; 3] = [
+        "ad80c730a21e5b8d04586a2213dd63b9a0e99e0e2307b0ade35a65485a288648",
+        "0055627c9e194cb4542bae2aa5492e3c1575bbb81b612b7d234b86a503ef5497",
+        "b6c6ea8a5354eaf15b3cb7646744f4275b71ea724fed81ceb9323e279d449df9",
+    ];
+
+    fn signer() -> ChunkSigner {
+        let key = signing_key(
+            "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY",
+            NaiveDate::from_ymd(2013, 5, 24),
+            "us-east-1",
+            "s3",
+        );
+        ChunkSigner::new(
+            key,
+            "20130524T000000Z".to_owned(),
+            "20130524/us-east-1/s3/aws4_request".to_owned(),
+            SEED_SIGNATURE.to_owned(),
+        )
+    }
+
+    async fn encode(payload: Vec<u8>, checksum: Option<ChecksumAlgorithm>) -> (Bytes, usize) {
+        let length = payload.len();
+        // Split the payload so that chunks have to be buffered.
+        let parts: Vec<Result<Bytes, io::Error>> = payload
+            .chunks(1000)
+            .map(|part| Ok(Bytes::copy_from_slice(part)))
+            .collect();
+        let stream = ByteStream::new(stream::iter(parts));
+        let encoded = sign_stream(stream, length, 64 * 1024, checksum, signer());
+        let size_hint = encoded.size_hint().unwrap();
+        let bytes = encoded
+            .map_ok(|bytes| BytesMut::from(&bytes[..]))
+            .try_concat()
+            .await
+            .unwrap();
+        (bytes.freeze(), size_hint)
+    }
+
+    #[tokio::test]
+    async fn signs_chunks() {
+        let (encoded, size_hint) = encode(vec![b'a'; 66560], None).await;
+        assert_eq!(encoded.len(), 66824);
+        assert_eq!(size_hint, 66824);
+
+        let first = format!("10000;chunk-signature={}\r\n", CHUNK_SIGNATURES[0]);
+        assert!(encoded.starts_with(first.as_bytes()));
+        let second = format!("\r\n400;chunk-signature={}\r\n", CHUNK_SIGNATURES[1]);
+        assert_eq!(
+            &encoded[first.len() + 65536..first.len() + 65536 + second.len()],
+            second.as_bytes()
+        );
+        let last = format!("\r\n0;chunk-signature={}\r\n\r\n", CHUNK_SIGNATURES[2]);
+        assert!(encoded.ends_with(last.as_bytes()));
+    }
+
+    #[tokio::test]
+    async fn signs_trailing_checksums() {
+        let (encoded, size_hint) = encode(vec![b'a'; 66560], Some(ChecksumAlgorithm::Crc32)).await;
+        assert_eq!(encoded.len(), size_hint);
+
+        let mut signer = signer();
+        signer.previous_signature = CHUNK_SIGNATURES[2].to_owned();
+        let checksum = base64::encode(crc32fast::hash(&[b'a'; 66560]).to_be_bytes());
+        let trailer = format!("x-amz-checksum-crc32:{}\n", checksum);
+        let end = format!(
+            "\r\n0;chunk-signature={}\r\nx-amz-checksum-crc32:{}\r\nx-amz-trailer-signature:{}\r\n\r\n",
+            CHUNK_SIGNATURES[2],
+            checksum,
+            signer.sign_trailer(&trailer)
+        );
+        assert!(encoded.ends_with(end.as_bytes()));
+
+        let (encoded, size_hint) = encode(vec![], Some(ChecksumAlgorithm::Sha256)).await;
+        assert_eq!(encoded.len(), size_hint);
+        let trailer = format!(
+            "\r\nx-amz-checksum-sha256:{}\r\n",
+            base64::encode(Sha256::digest(&[]))
+        );
+        assert!(std::str::from_utf8(&encoded).unwrap().contains(&trailer));
+    }
+
+    #[tokio::test]
+    async fn fails_on_length_mismatch() {
+        let stream = ByteStream::from(vec![b'a'; 10]);
+        let result: Result<Vec<Bytes>, io::Error> = sign_stream(stream, 20, 8, None, signer())
+            .try_collect()
+            .await;
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let stream = ByteStream::from(vec![b'a'; 10]);
+        let result: Result<Vec<Bytes>, io::Error> = sign_stream(stream, 5, 8, None, signer())
+            .try_collect()
+            .await;
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,389 @@
+//! Endpoint resolution of the AWS services.
+//!
+//! The hostname of a service in a region, and the region its requests are signed for, are
+//! resolved from a table generated from the botocore `endpoints.json`. The region is looked
+//! up in the partitions, like `aws`, `aws-cn` or `aws-us-gov`, by name or, for the regions
+//! they don't list yet, by the prefixes of their region names. Then the service endpoints of
+//! the partition are searched for the region, falling back to the global endpoint of the
+//! services which are not regionalized and to the hostname template of the partition.
+//!
+//! The FIPS and dual-stack variants of the endpoints are resolved from the variants of the
+//! endpoint, then of the service, then of the partition. Their requests are signed for the
+//! same region as the standard endpoints.
+//!
+//! ```
+//! use rusoto_signature::endpoints;
+//!
+//! let endpoint = endpoints::resolve("iam", "us-gov-west-1");
+//! assert_eq!(endpoint.hostname(), "iam.us-gov.amazonaws.com");
+//! assert_eq!(endpoint.signing_region(), "us-gov-west-1");
+//! assert_eq!(endpoint.partition(), "aws-us-gov");
+//!
+//! let variant = endpoints::EndpointVariant {
+//!     fips: false,
+//!     dualstack: true,
+//! };
+//! let endpoint = endpoints::resolve_variant("sqs", "eu-west-1", variant).unwrap();
+//! assert_eq!(endpoint.hostname(), "sqs.eu-west-1.api.aws");
+//! ```
+
+mod generated;
+
+use self::generated::PARTITIONS;
+
+/// An AWS partition, a group of regions sharing their DNS suffix.
+#[derive(Debug)]
+pub struct Partition {
+    id: &'static str,
+    dns_suffix: &'static str,
+    hostname: &'static str,
+    region_prefixes: &'static [&'static str],
+    regions: &'static [&'static str],
+    variants: &'static [Variant],
+    services: &'static [(&'static str, ServiceEndpoints)],
+}
+
+#[derive(Debug)]
+struct ServiceEndpoints {
+    partition_endpoint: Option<&'static str>,
+    is_regionalized: bool,
+    defaults: EndpointData,
+    endpoints: &'static [(&'static str, EndpointData)],
+}
+
+#[derive(Clone, Copy, Debug)]
+struct EndpointData {
+    hostname: Option<&'static str>,
+    credential_scope_region: Option<&'static str>,
+    variants: &'static [Variant],
+}
+
+impl EndpointData {
+    const DEFAULT: EndpointData = EndpointData {
+        hostname: None,
+        credential_scope_region: None,
+        variants: &[],
+    };
+}
+
+#[derive(Debug)]
+struct Variant {
+    fips: bool,
+    dualstack: bool,
+    hostname: Option<&'static str>,
+    dns_suffix: Option<&'static str>,
+}
+
+fn find_variant(
+    variants: &'static [Variant],
+    variant: EndpointVariant,
+) -> Option<&'static Variant> {
+    variants
+        .iter()
+        .find(|v| v.fips == variant.fips && v.dualstack == variant.dualstack)
+}
+
+/// The variant of an endpoint to resolve.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EndpointVariant {
+    /// Use an endpoint validated under FIPS 140-2.
+    pub fips: bool,
+    /// Use an endpoint reachable over both IPv4 and IPv6.
+    pub dualstack: bool,
+}
+
+/// The endpoint of a service in a region.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Endpoint {
+    hostname: String,
+    signing_region: String,
+    partition: &'static str,
+}
+
+impl Endpoint {
+    /// The hostname requests are sent to.
+    pub fn hostname(&self) -> &str {
+        &self.hostname
+    }
+
+    /// The region requests are signed for, which differs from the region of the client for
+    /// global endpoints like the one of IAM.
+    pub fn signing_region(&self) -> &str {
+        &self.signing_region
+    }
+
+    /// The id of the partition of the endpoint, for instance `aws-cn`.
+    pub fn partition(&self) -> &'static str {
+        self.partition
+    }
+}
+
+/// Resolves the endpoint of a service, given its endpoint prefix, in a region.
+pub fn resolve(service: &str, region: &str) -> Endpoint {
+    Partition::for_region(region).resolve(service, region)
+}
+
+/// Resolves a variant of the endpoint of a service, given its endpoint prefix, in a region.
+/// Returns `None` when the partition of the region has no such variant.
+pub fn resolve_variant(service: &str, region: &str, variant: EndpointVariant) -> Option<Endpoint> {
+    Partition::for_region(region).resolve_variant(service, region, variant)
+}
+
+impl Partition {
+    /// Returns the partition of a region, falling back to the `aws` partition for
+    /// unknown regions.
+    pub fn for_region(region: &str) -> &'static Partition {
+        PARTITIONS
+            .iter()
+            .find(|partition| partition.regions.contains(&region))
+            .or_else(|| {
+                PARTITIONS
+                    .iter()
+                    .find(|partition| partition.matches_region(region))
+            })
+            .unwrap_or(&PARTITIONS[0])
+    }
+
+    /// The id of the partition, for instance `aws-us-gov`.
+    pub fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// The DNS suffix of the endpoints of the partition, for instance `amazonaws.com.cn`.
+    pub fn dns_suffix(&self) -> &'static str {
+        self.dns_suffix
+    }
+
+    /// Resolves the endpoint of a service, given its endpoint prefix, in a region of the
+    /// partition.
+    pub fn resolve(&self, service: &str, region: &str) -> Endpoint {
+        self.resolve_variant(service, region, EndpointVariant::default())
+            .expect("the standard variant is always resolved")
+    }
+
+    /// Resolves a variant of the endpoint of a service, given its endpoint prefix, in a region
+    /// of the partition.
+    pub fn resolve_variant(
+        &self,
+        service: &str,
+        region: &str,
+        variant: EndpointVariant,
+    ) -> Option<Endpoint> {
+        let (endpoint, defaults) = match self
+            .services
+            .binary_search_by_key(&service, |&(name, _)| name)
+        {
+            Ok(index) => {
+                let service = &self.services[index].1;
+                (service.endpoint(region), service.defaults)
+            }
+            Err(_) => (EndpointData::DEFAULT, EndpointData::DEFAULT),
+        };
+        let (hostname, dns_suffix) = if variant == EndpointVariant::default() {
+            let hostname = endpoint.hostname.or(defaults.hostname);
+            (hostname.unwrap_or(self.hostname), self.dns_suffix)
+        } else {
+            // The templates of the partition don't apply to endpoints with their own hostname.
+            let partition_variant = match endpoint.hostname {
+                Some(_) => None,
+                None => find_variant(self.variants, variant),
+            };
+            let variants = [
+                find_variant(endpoint.variants, variant),
+                find_variant(defaults.variants, variant),
+                partition_variant,
+            ];
+            let hostname = variants.iter().flatten().find_map(|v| v.hostname)?;
+            let dns_suffix = variants.iter().flatten().find_map(|v| v.dns_suffix);
+            (hostname, dns_suffix.unwrap_or(self.dns_suffix))
+        };
+        let hostname = hostname
+            .replace("{service}", service)
+            .replace("{region}", region)
+            .replace("{dnsSuffix}", dns_suffix);
+        let signing_region = endpoint
+            .credential_scope_region
+            .or(defaults.credential_scope_region)
+            .unwrap_or(region);
+        Some(Endpoint {
+            hostname,
+            signing_region: signing_region.to_owned(),
+            partition: self.id,
+        })
+    }
+
+    /// Whether the region matches the region regex of the partition, which is made of one of
+    /// its prefixes followed by `-{name}-{number}`.
+    fn matches_region(&self, region: &str) -> bool {
+        self.region_prefixes.iter().any(|prefix| {
+            let rest = match region
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_prefix('-'))
+            {
+                Some(rest) => rest,
+                None => return false,
+            };
+            match rest.split_once('-') {
+                Some((name, number)) => {
+                    !name.is_empty()
+                        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                        && !number.is_empty()
+                        && number.chars().all(|c| c.is_ascii_digit())
+                }
+                None => false,
+            }
+        })
+    }
+}
+
+impl ServiceEndpoints {
+    fn endpoint(&self, region: &str) -> EndpointData {
+        let find = |region: &str| {
+            self.endpoints
+                .binary_search_by_key(&region, |&(name, _)| name)
+                .ok()
+                .map(|index| self.endpoints[index].1)
+        };
+        let endpoint = match find(region) {
+            Some(endpoint) => Some(endpoint),
+            None if !self.is_regionalized => self.partition_endpoint.and_then(find),
+            None => None,
+        };
+        endpoint.unwrap_or(EndpointData::DEFAULT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_are_sorted() {
+        for partition in PARTITIONS {
+            assert!(partition.services.windows(2).all(|w| w[0].0 < w[1].0));
+            for (_, service) in partition.services {
+                assert!(service.endpoints.windows(2).all(|w| w[0].0 < w[1].0));
+            }
+        }
+    }
+
+    #[test]
+    fn finds_partitions() {
+        assert_eq!(Partition::for_region("us-east-1").id(), "aws");
+        assert_eq!(Partition::for_region("eu-west-9").id(), "aws");
+        assert_eq!(Partition::for_region("cn-northwest-1").id(), "aws-cn");
+        assert_eq!(Partition::for_region("cn-south-2").id(), "aws-cn");
+        assert_eq!(Partition::for_region("us-gov-west-1").id(), "aws-us-gov");
+        assert_eq!(Partition::for_region("us-gov-north-2").id(), "aws-us-gov");
+        assert_eq!(Partition::for_region("us-iso-east-1").id(), "aws-iso");
+        assert_eq!(Partition::for_region("us-isob-east-1").id(), "aws-iso-b");
+        assert_eq!(Partition::for_region("localhost").id(), "aws");
+        assert_eq!(
+            Partition::for_region("cn-north-1").dns_suffix(),
+            "amazonaws.com.cn"
+        );
+    }
+
+    #[test]
+    fn resolves_regional_endpoints() {
+        let endpoint = resolve("sqs", "eu-west-1");
+        assert_eq!(endpoint.hostname(), "sqs.eu-west-1.amazonaws.com");
+        assert_eq!(endpoint.signing_region(), "eu-west-1");
+        assert_eq!(
+            resolve("sqs", "cn-north-1").hostname(),
+            "sqs.cn-north-1.amazonaws.com.cn"
+        );
+        assert_eq!(
+            resolve("ec2", "us-isob-east-1").hostname(),
+            "ec2.us-isob-east-1.sc2s.sgov.gov"
+        );
+    }
+
+    #[test]
+    fn resolves_global_endpoints() {
+        let endpoint = resolve("iam", "eu-west-1");
+        assert_eq!(endpoint.hostname(), "iam.amazonaws.com");
+        assert_eq!(endpoint.signing_region(), "us-east-1");
+
+        let endpoint = resolve("iam", "cn-northwest-1");
+        assert_eq!(endpoint.hostname(), "iam.cn-north-1.amazonaws.com.cn");
+        assert_eq!(endpoint.signing_region(), "cn-north-1");
+
+        let endpoint = resolve("organizations", "us-gov-east-1");
+        assert_eq!(
+            endpoint.hostname(),
+            "organizations.us-gov-west-1.amazonaws.com"
+        );
+        assert_eq!(endpoint.signing_region(), "us-gov-west-1");
+
+        let endpoint = resolve("chime", "eu-central-1");
+        assert_eq!(endpoint.hostname(), "chime.us-east-1.amazonaws.com");
+        assert_eq!(endpoint.signing_region(), "us-east-1");
+    }
+
+    #[test]
+    fn resolves_fips_and_dualstack_variants() {
+        let fips = EndpointVariant {
+            fips: true,
+            dualstack: false,
+        };
+        let dualstack = EndpointVariant {
+            fips: false,
+            dualstack: true,
+        };
+        let both = EndpointVariant {
+            fips: true,
+            dualstack: true,
+        };
+        let hostname = |service, region, variant| {
+            resolve_variant(service, region, variant).map(|endpoint| endpoint.hostname)
+        };
+
+        let endpoint = resolve_variant("sqs", "us-east-2", fips).unwrap();
+        assert_eq!(endpoint.hostname(), "sqs-fips.us-east-2.amazonaws.com");
+        assert_eq!(endpoint.signing_region(), "us-east-2");
+        assert_eq!(
+            hostname("sqs", "us-east-2", dualstack).as_deref(),
+            Some("sqs.us-east-2.api.aws")
+        );
+        assert_eq!(
+            hostname("sqs", "us-east-2", both).as_deref(),
+            Some("sqs-fips.us-east-2.api.aws")
+        );
+        assert_eq!(
+            hostname("sqs", "cn-north-1", dualstack).as_deref(),
+            Some("sqs.cn-north-1.api.amazonwebservices.com.cn")
+        );
+        assert_eq!(
+            hostname("s3", "eu-west-1", dualstack).as_deref(),
+            Some("s3.dualstack.eu-west-1.amazonaws.com")
+        );
+        assert_eq!(
+            hostname("s3", "us-east-1", fips).as_deref(),
+            Some("s3-fips.us-east-1.amazonaws.com")
+        );
+        assert_eq!(hostname("sqs", "us-iso-east-1", dualstack), None);
+
+        let endpoint = resolve_variant("iam", "us-gov-east-1", fips).unwrap();
+        assert_eq!(endpoint.hostname(), "iam.us-gov.amazonaws.com");
+        assert_eq!(endpoint.signing_region(), "us-gov-west-1");
+        assert_eq!(
+            hostname("iam", "us-east-1", dualstack).as_deref(),
+            Some("iam.global.api.aws")
+        );
+        assert_eq!(hostname("iam", "us-east-1", both), None);
+    }
+
+    #[test]
+    fn resolves_region_overrides() {
+        assert_eq!(resolve("sdb", "us-east-1").hostname(), "sdb.amazonaws.com");
+        assert_eq!(
+            resolve("sdb", "eu-west-1").hostname(),
+            "sdb.eu-west-1.amazonaws.com"
+        );
+        assert_eq!(
+            resolve("s3", "us-east-1").hostname(),
+            "s3.us-east-1.amazonaws.com"
+        );
+    }
+}
@@ -11,9 +11,19 @@
 #![cfg_attr(not(feature = "unstable"), deny(warnings))]
 #![cfg_attr(not(feature = "unstable"), allow(clippy::type_complexity))]
 pub extern crate rusoto_credential as credential;
+mod chunked;
+pub mod endpoints;
 pub mod region;
 pub mod signature;
 pub mod stream;
+pub mod verify;
+mod v2;
+mod v4a;
+pub use endpoints::EndpointVariant;
 pub use region::Region;
-pub use signature::{SignedRequest, SignedRequestPayload};
+pub use signature::{
+    sign_http_request, ChecksumAlgorithm, PayloadSigning, RegionSet, SignedRequest,
+    SignedRequestPayload, SigningAlgorithm, SigningOptions,
+};
 pub use stream::ByteStream;
+pub use verify::SignatureVerifier;
@@ -5,6 +5,7 @@
 //! For example: `UsEast1` to "us-east-1"
 
 use crate::credential::ProfileProvider;
+use crate::endpoints::Partition;
 use serde::ser::SerializeTuple;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std;
@@ -21,6 +22,20 @@ use std::str::FromStr;
 /// If it is not present it will fallback on the value associated with the current profile in `~/.aws/config` or the file
 /// specified by the `AWS_CONFIG_FILE` environment variable. If that is malformed of absent it will fall back on `Region::UsEast1`
 ///
+/// # Other regions
+///
+/// The regions without a variant of their own, like `il-central-1`, are parsed as
+/// `Region::Other`. Their endpoints are resolved from the partition their name matches, for
+/// instance `cn-*` regions are in the `aws-cn` partition, and their requests are signed for
+/// their name.
+///
+/// ```
+///     # use rusoto_signature::Region;
+///     let region: Region = "il-central-1".parse().unwrap();
+///     assert_eq!(region, Region::Other("il-central-1".to_owned()));
+///     assert_eq!(region.partition().dns_suffix(), "amazonaws.com");
+/// ```
+///
 /// # AWS-compatible services
 ///
 /// `Region::Custom` can be used to connect to AWS-compatible services such as DynamoDB Local or Ceph.
@@ -113,6 +128,10 @@ pub enum Region {
     /// Region that covers southern part Africa
     AfSouth1,
 
+    /// Any other region, named like `ap-southeast-3`. Its endpoints are resolved from the
+    /// partition its name matches.
+    Other(String),
+
     /// Specifies a custom region, such as a local Ceph target
     Custom {
         /// Name of the endpoint (e.g. `"eu-east-2"`).
@@ -162,9 +181,21 @@ impl Region {
             Region::CnNorth1 => "cn-north-1",
             Region::CnNorthwest1 => "cn-northwest-1",
             Region::AfSouth1 => "af-south-1",
+            Region::Other(ref name) => name,
             Region::Custom { ref name, .. } => name,
         }
     }
+
+    /// The partition of the region, the `aws` partition for the names matching none.
+    ///
+    /// ```
+    ///     # use rusoto_signature::Region;
+    ///     assert_eq!(Region::CnNorth1.partition().id(), "aws-cn");
+    ///     assert_eq!(Region::Other("us-gov-south-1".to_owned()).partition().id(), "aws-us-gov");
+    /// ```
+    pub fn partition(&self) -> &'static Partition {
+        Partition::for_region(self.name())
+    }
 }
 
 /// An error produced when attempting to convert a `str` into a `Region` fails.
@@ -262,11 +293,31 @@ impl FromStr for Region {
             "cn-north-1" | "cnnorth1" => Ok(Region::CnNorth1),
             "cn-northwest-1" | "cnnorthwest1" => Ok(Region::CnNorthwest1),
             "af-south-1" | "afsouth1" => Ok(Region::AfSouth1),
+            s if is_region_name(s) => Ok(Region::Other(s.to_owned())),
             s => Err(ParseRegionError::new(s)),
         }
     }
 }
 
+/// Whether a name looks like the name of a region, made of lowercase words separated by
+/// hyphens and ending with a number, like `ap-southeast-3` or `us-isob-east-1`.
+fn is_region_name(name: &str) -> bool {
+    let parts: Vec<&str> = name.split('-').collect();
+    match parts.split_last() {
+        Some((number, words)) if words.len() >= 2 => {
+            !number.is_empty()
+                && number.chars().all(|c| c.is_ascii_digit())
+                && words.iter().all(|word| {
+                    !word.is_empty()
+                        && word
+                            .chars()
+                            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+                })
+        }
+        _ => false,
+    }
+}
+
 impl ParseRegionError {
     /// Parses a region given as a string literal into a type `Region'
     pub fn new(input: &str) -> Self {
@@ -339,6 +390,44 @@ mod tests {
         assert_eq!("af-south-1".parse(), Ok(Region::AfSouth1));
     }
 
+    #[test]
+    fn from_str_other_regions() {
+        for name in &[
+            "ap-southeast-3",
+            "il-central-1",
+            "us-isob-east-1",
+            "eusc-de-east-1",
+        ] {
+            assert_eq!(name.parse(), Ok(Region::Other(name.to_string())));
+        }
+        assert_eq!(
+            "IL-Central-1".parse(),
+            Ok(Region::Other("il-central-1".to_owned()))
+        );
+        for name in &[
+            "",
+            "central-1",
+            "il-central",
+            "il--1",
+            "il-central-x",
+            "il central-1",
+        ] {
+            assert!(name.parse::<Region>().is_err(), "parsed {:?}", name);
+        }
+    }
+
+    #[test]
+    fn other_regions_use_their_partition() {
+        let region = Region::Other("cn-southwest-1".to_owned());
+        assert_eq!(region.name(), "cn-southwest-1");
+        assert_eq!(region.partition().id(), "aws-cn");
+        assert_eq!(region.partition().dns_suffix(), "amazonaws.com.cn");
+        assert_eq!(
+            Region::Other("ap-southeast-3".to_owned()).partition().id(),
+            "aws"
+        );
+    }
+
     #[test]
     fn region_serialize_deserialize() {
         assert_tokens(&Region::ApEast1, &tokens_for_region("ap-east-1"));
@@ -366,6 +455,10 @@ mod tests {
         assert_tokens(&Region::CnNorth1, &tokens_for_region("cn-north-1"));
         assert_tokens(&Region::CnNorthwest1, &tokens_for_region("cn-northwest-1"));
         assert_tokens(&Region::AfSouth1, &tokens_for_region("af-south-1"));
+        assert_tokens(
+            &Region::Other("il-central-1".to_owned()),
+            &tokens_for_region("il-central-1"),
+        );
     }
 
     fn tokens_for_region(name: &'static str) -> [Token; 4] {
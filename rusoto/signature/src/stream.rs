@@ -41,7 +41,8 @@ impl ByteStream {
         }
     }
 
-    pub(crate) fn size_hint(&self) -> Option<usize> {
+    /// Returns the size of the stream, if it was given when creating it.
+    pub fn size_hint(&self) -> Option<usize> {
         self.size_hint
     }
 
@@ -0,0 +1,160 @@
+//! AWS Signature Version 4a, signing with an ECDSA P-256 key derived from the secret key.
+//!
+//! The key is derived with the counter mode KDF of NIST SP 800-108 using HMAC-SHA256, as
+//! described in the [SigV4a documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_sigv.html).
+
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+use hmac::{Hmac, Mac, NewMac};
+use p256::ecdsa::signature::Signer;
+use p256::ecdsa::{Signature, SigningKey};
+use sha2::{Digest, Sha256};
+
+/// The name of the algorithm, in the string to sign and the authorization header.
+pub(crate) const ALGORITHM: &str = "AWS4-ECDSA-P256-SHA256";
+
+/// The order of the P-256 curve minus two, the largest value the derived key may take before
+/// being incremented.
+const ORDER_MINUS_TWO: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xbc, 0xe6, 0xfa, 0xad, 0xa7, 0x17, 0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63, 0x25, 0x4f,
+];
+
+/// The number of keys kept by `cached_signing_key`, which forgets all of them once reached.
+const MAX_CACHED_KEYS: usize = 64;
+
+/// The keys derived by `cached_signing_key`, by the SHA-256 digest of the access and secret
+/// keys they were derived from, so that the secret keys are not kept.
+static SIGNING_KEYS: Mutex<Option<HashMap<[u8; 32], SigningKey>>> = Mutex::new(None);
+
+/// Returns the signing key of a pair of access and secret keys, deriving it only the first
+/// time the pair is used.
+pub(crate) fn cached_signing_key(access_key: &str, secret_key: &str) -> SigningKey {
+    // The length of the access key separates it from the secret key
+    let digest: [u8; 32] = Sha256::new()
+        .chain((access_key.len() as u64).to_be_bytes())
+        .chain(access_key)
+        .chain(secret_key)
+        .finalize()
+        .into();
+    let mut keys = SIGNING_KEYS.lock().unwrap_or_else(PoisonError::into_inner);
+    let keys = keys.get_or_insert_with(HashMap::new);
+    if let Some(key) = keys.get(&digest) {
+        return key.clone();
+    }
+    if keys.len() >= MAX_CACHED_KEYS {
+        keys.clear();
+    }
+    let key = signing_key(access_key, secret_key);
+    keys.insert(digest, key.clone());
+    key
+}
+
+/// Derives the signing key of a pair of access and secret keys.
+pub(crate) fn signing_key(access_key: &str, secret_key: &str) -> SigningKey {
+    let input_key = format!("AWS4A{}", secret_key);
+    for counter in 1..=u8::MAX {
+        // The fixed input of the KDF: label, separator, context and length of the key in bits.
+        let mut input = 1u32.to_be_bytes().to_vec();
+        input.extend_from_slice(ALGORITHM.as_bytes());
+        input.push(0);
+        input.extend_from_slice(access_key.as_bytes());
+        input.push(counter);
+        input.extend_from_slice(&256u32.to_be_bytes());
+
+        let mut hmac =
+            Hmac::<Sha256>::new_varkey(input_key.as_bytes()).expect("failed to create hmac");
+        hmac.update(&input);
+        let mut candidate: [u8; 32] = hmac.finalize().into_bytes().into();
+        if candidate <= ORDER_MINUS_TWO {
+            increment(&mut candidate);
+            return SigningKey::from_bytes(&candidate).expect("the key is in the range of P-256");
+        }
+    }
+    unreachable!("no key derived after 255 attempts")
+}
+
+/// Signs a string, returning the hex encoded DER signature.
+pub(crate) fn sign(key: &SigningKey, string_to_sign: &str) -> String {
+    let signature: Signature = key.sign(string_to_sign.as_bytes());
+    hex::encode(signature.to_der().as_bytes())
+}
+
+/// Adds one to a big-endian number which is lower than the maximum.
+fn increment(number: &mut [u8; 32]) {
+    for byte in number.iter_mut().rev() {
+        let (sum, overflow) = byte.overflowing_add(1);
+        *byte = sum;
+        if !overflow {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::signature::Verifier;
+    use p256::ecdsa::VerifyingKey;
+
+    // From the `get-vanilla` case of the SigV4a test suite.
+    const ACCESS_KEY: &str = "AKIDEXAMPLE";
+    const SECRET_KEY: &str = "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY";
+    const PUBLIC_KEY_X: &str = "b6618f6a65740a99e650b33b6b4b5bd0d43b176d721a3edfea7e7d2d56d936b1";
+    const PUBLIC_KEY_Y: &str = "865ed22a7eadc9c5cb9d2cbaca1b3699139fedc5043dc6661864218330c8e518";
+    const STRING_TO_SIGN: &str = "AWS4-ECDSA-P256-SHA256
+20150830T123600Z
+20150830/service/aws4_request
+cf59db423e841c8b7e3444158185aa261b724a5c27cbe762676f3eed19f4dc02";
+    const SIGNATURE: &str = "3045022018b4e277d0281864beb51d3600e23f88510ea5031d68ddfbb68614b82a5eb7d2022100effb9c5f22ed9ef3ae0ab243d21f06bce82365bbb79529a07b6888c343ae5f8c";
+
+    fn verify(key: &VerifyingKey, string_to_sign: &str, signature: &str) -> bool {
+        let signature = Signature::from_der(&hex::decode(signature).unwrap()).unwrap();
+        key.verify(string_to_sign.as_bytes(), &signature).is_ok()
+    }
+
+    #[test]
+    fn derives_the_signing_key() {
+        let key = signing_key(ACCESS_KEY, SECRET_KEY);
+        let point = key.verifying_key().to_encoded_point(false);
+        assert_eq!(hex::encode(point.x().unwrap()), PUBLIC_KEY_X);
+        assert_eq!(hex::encode(point.y().unwrap()), PUBLIC_KEY_Y);
+    }
+
+    #[test]
+    fn signs_strings() {
+        let key = signing_key(ACCESS_KEY, SECRET_KEY);
+        let verifying_key = key.verifying_key();
+        assert!(verify(&verifying_key, STRING_TO_SIGN, SIGNATURE));
+
+        let signature = sign(&key, STRING_TO_SIGN);
+        assert!(verify(&verifying_key, STRING_TO_SIGN, &signature));
+        assert!(!verify(
+            &verifying_key,
+            "AWS4-ECDSA-P256-SHA256",
+            &signature
+        ));
+    }
+
+    #[test]
+    fn caches_signing_keys() {
+        let key = cached_signing_key(ACCESS_KEY, SECRET_KEY);
+        assert_eq!(key, signing_key(ACCESS_KEY, SECRET_KEY));
+        assert_eq!(cached_signing_key(ACCESS_KEY, SECRET_KEY), key);
+
+        // A rotated secret key derives another key.
+        let rotated = cached_signing_key(ACCESS_KEY, "rotated");
+        assert_eq!(rotated, signing_key(ACCESS_KEY, "rotated"));
+        assert_ne!(rotated, key);
+    }
+
+    #[test]
+    fn increments_numbers() {
+        let mut number = [0u8; 32];
+        number[30] = 0x01;
+        number[31] = 0xff;
+        increment(&mut number);
+        assert_eq!(&number[30..], &[0x02, 0x00]);
+    }
+}
@@ -77,6 +77,78 @@ impl ServiceDefinition {
     }
 }
 
+/// The endpoints of every service in every partition, from `endpoints.json`.
+#[derive(Debug, Deserialize)]
+pub struct EndpointsDefinition {
+    pub partitions: Vec<Partition>,
+}
+
+impl EndpointsDefinition {
+    pub fn load() -> Result<Self, Box<dyn error::Error>> {
+        let input_path = Path::new(BOTOCORE_DIR).join("endpoints.json");
+        let input_file = BufReader::new(File::open(&input_path)?);
+        let endpoints: EndpointsDefinition = serde_json::from_reader(input_file)?;
+        Ok(endpoints)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Partition {
+    pub partition: String,
+    #[serde(rename = "dnsSuffix")]
+    pub dns_suffix: String,
+    #[serde(rename = "regionRegex")]
+    pub region_regex: String,
+    #[serde(default)]
+    pub defaults: EndpointDefinition,
+    #[serde(default)]
+    pub regions: BTreeMap<String, Region>,
+    #[serde(default)]
+    pub services: BTreeMap<String, ServiceEndpoints>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Region {
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ServiceEndpoints {
+    #[serde(rename = "partitionEndpoint")]
+    pub partition_endpoint: Option<String>,
+    #[serde(rename = "isRegionalized")]
+    pub is_regionalized: Option<bool>,
+    #[serde(default)]
+    pub defaults: EndpointDefinition,
+    #[serde(default)]
+    pub endpoints: BTreeMap<String, EndpointDefinition>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct EndpointDefinition {
+    pub hostname: Option<String>,
+    #[serde(rename = "credentialScope")]
+    pub credential_scope: Option<CredentialScope>,
+    #[serde(default)]
+    pub variants: Vec<EndpointVariant>,
+    #[serde(default)]
+    pub deprecated: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EndpointVariant {
+    pub hostname: Option<String>,
+    #[serde(rename = "dnsSuffix")]
+    pub dns_suffix: Option<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CredentialScope {
+    pub region: Option<String>,
+    pub service: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct HttpRequest {
     pub method: String,
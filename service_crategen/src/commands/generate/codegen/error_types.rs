@@ -224,7 +224,7 @@ impl XmlErrorTypes {
                     .as_ref()
                     .and_then(|http_error| http_error.code.as_ref())
                     .unwrap_or(&error.shape);
-                type_matchers.push(format!("\"{error_code}\" => return RusotoError::Service({error_type}::{error_name}(parsed_error.message))",
+                type_matchers.push(format!("\"{error_code}\" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, {error_type}::{error_name})",
                     error_code = error_code,
                     error_type = error_type,
                     error_name = error.idiomatic_error_name()))
@@ -276,15 +276,17 @@ impl JsonErrorTypes {
         if operation.errors.is_some() {
             for error in operation.errors() {
                 if error.shape != "ValidationException" {
-                    type_matchers.push(format!("\"{error_shape}\" => return RusotoError::Service({error_type}::{error_name}(err.msg))",
+                    type_matchers.push(format!("\"{error_shape}\" => return RusotoError::service_error(&res, &err.typ, err.msg, {error_type}::{error_name})",
                         error_shape = error.shape,
                         error_type = error_type,
                         error_name = error.idiomatic_error_name()))
                 }
             }
         }
-        type_matchers
-            .push("\"ValidationException\" => return RusotoError::Validation(err.msg)".to_string());
+        type_matchers.push(
+            "\"ValidationException\" => return RusotoError::validation_error(&res, err.msg)"
+                .to_string(),
+        );
         type_matchers.push("_ => {}".to_string());
         type_matchers.join(",\n")
     }
@@ -2,7 +2,8 @@ use inflector::Inflector;
 use std::io::Write;
 
 use super::{
-    error_type_name, eventstream_field_name, get_rust_type, FileWriter, GenerateProtocol, IoResult,
+    error_type_name, eventstream_field_name, generate_signing_algorithm, get_rust_type, FileWriter,
+    GenerateProtocol, IoResult,
 };
 use crate::botocore::{Operation, Shape};
 use crate::Service;
@@ -33,6 +34,28 @@ impl GenerateProtocol for JsonGenerator {
         Ok(())
     }
 
+    fn generate_operation_signature(
+        &self,
+        service: &Service<'_>,
+        operation_name: &str,
+        operation: &Operation,
+    ) -> String {
+        format!(
+            "{method_signature}-> Result<{output_type}, RusotoError<{error_type}>>",
+            method_signature = generate_method_signature(service, operation),
+            error_type = error_type_name(service, operation_name),
+            output_type = operation.output_shape_or("()")
+        )
+    }
+
+    fn operation_input_type<'b>(
+        &self,
+        service: &Service<'_>,
+        operation: &'b Operation,
+    ) -> Option<&'b str> {
+        input_type(service, operation)
+    }
+
     fn generate_method_impls(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
         for (operation_name, operation) in service.operations().iter() {
             let output_type = operation.output_shape_or("()");
@@ -43,6 +66,7 @@ impl GenerateProtocol for JsonGenerator {
                 {documentation}
                 {method_signature} -> Result<{output_type}, RusotoError<{error_type}>> {{
                     let mut request = self.new_signed_request(\"{http_method}\", \"{request_uri}\");
+                    request.set_operation(\"{name}\");
                     request.add_header(\"x-amz-target\", \"{target_prefix}.{name}\");
                     {payload}
 
@@ -84,7 +108,7 @@ impl GenerateProtocol for JsonGenerator {
             impl {type_name} {{
                 fn new_signed_request(&self, http_method: &str, request_uri: &str) -> SignedRequest {{
                     let mut request = SignedRequest::new(http_method, \"{signing_name}\", &self.region, request_uri);
-                    {modify_endpoint_prefix}
+                    {modify_endpoint_prefix}{set_signing_algorithm}
 
                     request.set_content_type(\"application/x-amz-json-{json_version}\".to_owned());
 
@@ -110,6 +134,7 @@ impl GenerateProtocol for JsonGenerator {
             signing_name = service.signing_name(),
             modify_endpoint_prefix =
                 generate_endpoint_modification(service).unwrap_or_else(|| "".to_owned()),
+            set_signing_algorithm = generate_signing_algorithm(service),
             json_version = service.json_version().unwrap(),
         )?;
         if service.needs_serde_json_crate() {
@@ -209,6 +234,21 @@ fn generate_endpoint_modification(service: &Service<'_>) -> Option<String> {
 }
 
 fn generate_method_signature(service: &Service<'_>, operation: &Operation) -> String {
+    match input_type(service, operation) {
+        Some(input_type) => format!(
+            "async fn {method_name}(&self, input: {input_type}) ",
+            input_type = input_type,
+            method_name = operation.name.to_snake_case()
+        ),
+        None => format!(
+            "async fn {method_name}(&self) ",
+            method_name = operation.name.to_snake_case()
+        ),
+    }
+}
+
+// Operations whose input has no members take no input
+fn input_type<'a>(service: &Service<'_>, operation: &'a Operation) -> Option<&'a str> {
     if operation.input.is_some()
         && service
             .get_shape(operation.input_shape())
@@ -217,16 +257,9 @@ fn generate_method_signature(service: &Service<'_>, operation: &Operation) -> St
             .map(|m| !m.is_empty())
             .unwrap_or(false)
     {
-        format!(
-            "async fn {method_name}(&self, input: {input_type}) ",
-            input_type = operation.input_shape(),
-            method_name = operation.name.to_snake_case()
-        )
+        Some(operation.input_shape())
     } else {
-        format!(
-            "async fn {method_name}(&self) ",
-            method_name = operation.name.to_snake_case()
-        )
+        None
     }
 }
 
@@ -11,7 +11,7 @@ use self::rest_json::RestJsonGenerator;
 use self::rest_xml::RestXmlGenerator;
 use self::tests::generate_tests;
 use self::type_filter::filter_types;
-use crate::botocore::{Member, Shape, ShapeType};
+use crate::botocore::{Member, Operation, Shape, ShapeType};
 use crate::util;
 use crate::Service;
 
@@ -40,6 +40,25 @@ pub trait GenerateProtocol {
         service: &Service<'_>,
     ) -> IoResult;
 
+    /// Generate the signature of the method executing an `Operation`, as declared in the
+    /// service trait
+    fn generate_operation_signature(
+        &self,
+        service: &Service<'_>,
+        operation_name: &str,
+        operation: &Operation,
+    ) -> String;
+
+    /// Return the type of the input taken by the method executing an `Operation`, or `None` if
+    /// the method takes no input
+    fn operation_input_type<'b>(
+        &self,
+        _service: &Service<'_>,
+        operation: &'b Operation,
+    ) -> Option<&'b str> {
+        operation.input.as_ref().map(|input| input.shape.as_str())
+    }
+
     /// Generate a method for each `Operation` in the `Service` to execute that method remotely
     ///
     /// The method generated by this method are inserted into an enclosing `impl FooClient {}` block
@@ -114,6 +133,34 @@ pub fn generate_source(service: &Service<'_>, writer: &mut FileWriter) -> IoResu
     }
 }
 
+/// Sets the signing algorithm of a request for the services which are not signed with the
+/// default SigV4. S3, declared `s3`, accepts SigV4 as well as its legacy authentication, so it
+/// keeps the default.
+pub fn generate_signing_algorithm(service: &Service<'_>) -> String {
+    match service.signature_version() {
+        "v2" => "request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);"
+            .to_owned(),
+        _ => "".to_owned(),
+    }
+}
+
+/// Marks the requests of the operations returning a blob as their payload, like S3
+/// `GetObject`, whose response body is user data which is never decompressed.
+pub fn generate_raw_response(service: &Service<'_>, operation: &Operation) -> String {
+    let returns_blob = operation
+        .output
+        .as_ref()
+        .and_then(|output| service.get_shape(&output.shape))
+        .and_then(|shape| shape.members.as_ref()?.get(shape.payload.as_ref()?))
+        .and_then(|member| service.get_shape(&member.shape))
+        .map_or(false, |shape| shape.shape_type == ShapeType::Blob);
+    if returns_blob {
+        "request.set_raw_response(true);".to_owned()
+    } else {
+        "".to_owned()
+    }
+}
+
 /// Translate a botocore field name to something rust-idiomatic and
 /// escape reserved words with an underscore
 pub fn generate_field_name(member_name: &str) -> String {
@@ -158,7 +205,8 @@ where
         use rusoto_core::request::{{BufferedHttpResponse, DispatchSignedRequest}};
         use rusoto_core::region;
         use rusoto_core::credential::ProvideAwsCredentials;
-        use rusoto_core::{{Client, RusotoError}};
+        use rusoto_core::config::region_for_service;
+        use rusoto_core::{{AwsConfig, CallOptions, Client, RusotoError}};
     "
     )?;
 
@@ -204,11 +252,12 @@ where
         impl {type_name} {{
             /// Creates a client backed by the default tokio event loop.
             ///
-            /// The client will use the default credentials provider and tls client.
+            /// The client will use the default credentials provider and tls client, and the
+            /// endpoint URL configured for the service, if any.
             pub fn new(region: region::Region) -> {type_name} {{
                 {type_name} {{
                     client: Client::shared(),
-                    region
+                    region: region_for_service(region, {service_id:?}),
                 }}
             }}
 
@@ -218,7 +267,7 @@ where
             {{
                 {type_name} {{
                     client: Client::new_with(credentials_provider, request_dispatcher),
-                    region
+                    region: region_for_service(region, {service_id:?}),
                 }}
             }}
 
@@ -226,7 +275,24 @@ where
             {{
                 {type_name} {{
                     client,
-                    region
+                    region: region_for_service(region, {service_id:?}),
+                }}
+            }}
+
+            /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+            /// client and retry settings of the given configuration.
+            pub fn new_with_config(config: &AwsConfig) -> {type_name} {{
+                {type_name} {{
+                    client: {config_client},
+                    region: config.get_region_for_service({service_id:?}),
+                }}
+            }}
+
+            /// Returns a copy of the client applying the given options to every call made through it.
+            pub fn with_options(&self, options: CallOptions) -> {type_name} {{
+                {type_name} {{
+                    client: self.client.with_options(options),
+                    region: self.region.clone(),
                 }}
             }}
         }}
@@ -235,10 +301,125 @@ where
         impl {trait_name} for {type_name} {{
         ",
         service_name = service.name(),
+        service_id = service.service_id().unwrap_or_else(|| service.name()),
         type_name = service.client_type_name(),
         trait_name = service.service_type_name(),
+        config_client = config_client(service),
     )?;
     protocol_generator.generate_method_impls(writer, service)?;
+    writeln!(writer, "}}")?;
+
+    generate_blocking_client(writer, service, protocol_generator)
+}
+
+/// The client created by `new_with_config`, the S3 one applying the `s3.addressing_style`
+/// setting of the configuration.
+fn config_client(service: &Service<'_>) -> &'static str {
+    if service.service_id() == Some("S3") {
+        "crate::custom::addressing::with_addressing_style(Client::new_with_config(config), config.get_s3_addressing_style())"
+    } else {
+        "Client::new_with_config(config)"
+    }
+}
+
+fn generate_blocking_client<P>(
+    writer: &mut FileWriter,
+    service: &Service<'_>,
+    protocol_generator: &P,
+) -> IoResult
+where
+    P: GenerateProtocol,
+{
+    writeln!(writer,
+        "/// A blocking client for the {service_name} API, running the calls of a `{type_name}`
+        /// on a `BlockingRuntime`.
+        ///
+        /// The outputs are those of the `{type_name}`: their streaming bodies are still
+        /// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+        #[cfg(feature = \"blocking\")]
+        #[derive(Clone)]
+        pub struct {blocking_type_name} {{
+            client: {type_name},
+            runtime: rusoto_core::BlockingRuntime,
+        }}
+
+        #[cfg(feature = \"blocking\")]
+        impl {blocking_type_name} {{
+            /// Creates a client using the default credentials provider and tls client, running
+            /// its calls on the shared `BlockingRuntime`.
+            pub fn new(region: region::Region) -> {blocking_type_name} {{
+                {blocking_type_name}::from_client({type_name}::new(region))
+            }}
+
+            /// Creates a client using the given request dispatcher and credentials provider,
+            /// running its calls on the shared `BlockingRuntime`.
+            pub fn new_with<P, D>(request_dispatcher: D, credentials_provider: P, region: region::Region) -> {blocking_type_name}
+                where P: ProvideAwsCredentials + Send + Sync + 'static,
+                      D: DispatchSignedRequest + Send + Sync + 'static,
+            {{
+                {blocking_type_name}::from_client({type_name}::new_with(request_dispatcher, credentials_provider, region))
+            }}
+
+            /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+            /// running its calls on the shared `BlockingRuntime`.
+            pub fn new_with_client(client: Client, region: region::Region) -> {blocking_type_name} {{
+                {blocking_type_name}::from_client({type_name}::new_with_client(client, region))
+            }}
+
+            /// Creates a client using the given configuration, running its calls on the
+            /// shared `BlockingRuntime`.
+            pub fn new_with_config(config: &AwsConfig) -> {blocking_type_name} {{
+                {blocking_type_name}::from_client({type_name}::new_with_config(config))
+            }}
+
+            /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+            pub fn from_client(client: {type_name}) -> {blocking_type_name} {{
+                {blocking_type_name} {{
+                    client,
+                    runtime: rusoto_core::BlockingRuntime::shared(),
+                }}
+            }}
+
+            /// Returns a copy of the client running its calls on the given runtime.
+            pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> {blocking_type_name} {{
+                {blocking_type_name} {{
+                    client: self.client.clone(),
+                    runtime,
+                }}
+            }}
+
+            /// Returns a copy of the client applying the given options to every call made through it.
+            pub fn with_options(&self, options: CallOptions) -> {blocking_type_name} {{
+                {blocking_type_name} {{
+                    client: self.client.with_options(options),
+                    runtime: self.runtime.clone(),
+                }}
+            }}
+        ",
+        service_name = service.name(),
+        type_name = service.client_type_name(),
+        blocking_type_name = service.blocking_client_type_name(),
+    )?;
+
+    for (operation_name, operation) in service.operations().iter() {
+        let input_type = protocol_generator.operation_input_type(service, operation);
+        writeln!(
+            writer,
+            "/// Blocking version of [`{trait_name}::{method_name}`].
+            pub fn {method_name}(&self{input_param}) -> Result<{output_type}, RusotoError<{error_type}>> {{
+                self.runtime.block_on(self.client.{method_name}({input}))
+            }}
+            ",
+            trait_name = service.service_type_name(),
+            method_name = operation.name.to_snake_case(),
+            input_param = input_type
+                .map(|input_type| format!(", input: {}", input_type))
+                .unwrap_or_default(),
+            output_type = operation.output_shape_or("()"),
+            error_type = error_type_name(service, operation_name),
+            input = if input_type.is_some() { "input" } else { "" },
+        )?;
+    }
     writeln!(writer, "}}")
 }
 
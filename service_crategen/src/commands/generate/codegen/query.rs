@@ -7,7 +7,8 @@ use crate::Service;
 
 use super::xml_payload_parser;
 use super::{
-    error_type_name, generate_field_name, get_rust_type, FileWriter, GenerateProtocol, IoResult,
+    error_type_name, generate_field_name, generate_signing_algorithm, get_rust_type, FileWriter,
+    GenerateProtocol, IoResult,
 };
 
 pub struct QueryGenerator;
@@ -32,6 +33,15 @@ impl GenerateProtocol for QueryGenerator {
         Ok(())
     }
 
+    fn generate_operation_signature(
+        &self,
+        service: &Service<'_>,
+        operation_name: &str,
+        operation: &Operation,
+    ) -> String {
+        generate_method_signature(operation_name, operation, service)
+    }
+
     fn generate_method_impls(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
         for (operation_name, operation) in service.operations().iter() {
             writeln!(writer,
@@ -39,6 +49,7 @@ impl GenerateProtocol for QueryGenerator {
                 {documentation}
                 {method_signature} {{
                     let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, \"{request_uri}\");
+                    request.set_operation(\"{operation_name}\");{set_signing_algorithm}
                     let params = self.new_params(\"{operation_name}\");
                     {serialize_input}
                     {set_input_params}
@@ -58,6 +69,7 @@ impl GenerateProtocol for QueryGenerator {
                      operation_name = &operation.name,
                      request_uri = &operation.http.request_uri,
                      serialize_input = generate_method_input_serialization(operation),
+                     set_signing_algorithm = generate_signing_algorithm(service),
                      set_input_params = generate_set_input_params(operation))?;
         }
         Ok(())
@@ -3,8 +3,8 @@ use std::io::Write;
 use inflector::Inflector;
 
 use super::{
-    error_type_name, generate_field_name, rest_request_generator, rest_response_parser, FileWriter,
-    GenerateProtocol, IoResult,
+    error_type_name, generate_field_name, generate_raw_response, generate_signing_algorithm,
+    rest_request_generator, rest_response_parser, FileWriter, GenerateProtocol, IoResult,
 };
 use crate::botocore::{Operation, Shape, ShapeType};
 use crate::Service;
@@ -43,6 +43,36 @@ impl GenerateProtocol for RestJsonGenerator {
         Ok(())
     }
 
+    fn generate_operation_signature(
+        &self,
+        service: &Service<'_>,
+        operation_name: &str,
+        operation: &Operation,
+    ) -> String {
+        let input_shape = match &operation.input {
+            Some(_) => service.get_shape(operation.input_shape()),
+            None => None,
+        };
+        format!(
+            "{method_signature} -> Result<{output_type}, RusotoError<{error_type}>>",
+            method_signature = generate_method_signature(operation, input_shape),
+            error_type = error_type_name(service, operation_name),
+            output_type = operation.output_shape_or("()")
+        )
+    }
+
+    fn operation_input_type<'b>(
+        &self,
+        service: &Service<'_>,
+        operation: &'b Operation,
+    ) -> Option<&'b str> {
+        let input_shape = match &operation.input {
+            Some(_) => service.get_shape(operation.input_shape()),
+            None => None,
+        };
+        input_type(operation, input_shape)
+    }
+
     fn generate_method_impls(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
         for (operation_name, operation) in service.operations().iter() {
             let input_type = operation.input_shape_or("()");
@@ -61,9 +91,10 @@ impl GenerateProtocol for RestJsonGenerator {
                     {request_uri_formatter}
 
                     let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, &request_uri);
+                    request.set_operation(\"{operation_name}\");{set_raw_response}
                     {default_headers}
                     {set_headers}
-                    {modify_endpoint_prefix}
+                    {modify_endpoint_prefix}{set_signing_algorithm}
                     {load_payload}
                     {load_headers}
                     {load_params}
@@ -85,7 +116,10 @@ impl GenerateProtocol for RestJsonGenerator {
                 method_signature = generate_method_signature(operation, *input_shape),
                 endpoint_prefix = service.signing_name(),
                 modify_endpoint_prefix = generate_endpoint_modification(service).unwrap_or_else(|| "".to_owned()),
+                set_signing_algorithm = generate_signing_algorithm(service),
+                set_raw_response = generate_raw_response(service, operation),
                 http_method = operation.http.method,
+                operation_name = operation.name,
                 error_type = error_type_name(service, operation_name),
                 status_check = http_code_expected(operation.http.response_code),
                 parse_body = generate_body_parser(operation, service),
@@ -189,18 +223,12 @@ fn generate_endpoint_modification(service: &Service<'_>) -> Option<String> {
 // IoT defines a lot of empty (and therefore unnecessary) request shapes
 // don't clutter method signatures with them
 fn generate_method_signature(operation: &Operation, shape: Option<&Shape>) -> String {
-    match shape {
-        Some(s) => match s.members {
-            Some(ref members) if !members.is_empty() => format!(
-                "async fn {method_name}(&self, input: {input_type})",
-                method_name = operation.name.to_snake_case(),
-                input_type = operation.input_shape()
-            ),
-            _ => format!(
-                "async fn {method_name}(&self)",
-                method_name = operation.name.to_snake_case()
-            ),
-        },
+    match input_type(operation, shape) {
+        Some(input_type) => format!(
+            "async fn {method_name}(&self, input: {input_type})",
+            method_name = operation.name.to_snake_case(),
+            input_type = input_type
+        ),
         None => format!(
             "async fn {method_name}(&self)",
             method_name = operation.name.to_snake_case()
@@ -208,6 +236,14 @@ fn generate_method_signature(operation: &Operation, shape: Option<&Shape>) -> St
     }
 }
 
+// Operations whose input has no members take no input
+fn input_type<'a>(operation: &'a Operation, shape: Option<&Shape>) -> Option<&'a str> {
+    match shape?.members {
+        Some(ref members) if !members.is_empty() => Some(operation.input_shape()),
+        _ => None,
+    }
+}
+
 // Figure out what, if anything, should be sent as the body of the http request
 fn generate_payload(service: &Service<'_>, input_shape: Option<&Shape>) -> Option<String> {
     let i = input_shape.as_ref()?;
@@ -1,7 +1,10 @@
 use inflector::Inflector;
 use std::io::Write;
 
-use super::{error_type_name, generate_field_name, GenerateProtocol};
+use super::{
+    error_type_name, generate_field_name, generate_raw_response, generate_signing_algorithm,
+    GenerateProtocol,
+};
 use super::{
     get_rust_type, mutate_type_name, rest_request_generator, rest_response_parser,
     xml_payload_parser,
@@ -32,6 +35,15 @@ impl GenerateProtocol for RestXmlGenerator {
         Ok(())
     }
 
+    fn generate_operation_signature(
+        &self,
+        service: &Service<'_>,
+        operation_name: &str,
+        operation: &Operation,
+    ) -> String {
+        generate_method_signature(operation_name, operation, service)
+    }
+
     fn generate_method_impls(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
         for (operation_name, operation) in service.operations().iter() {
             let (request_uri, _) =
@@ -46,6 +58,7 @@ impl GenerateProtocol for RestXmlGenerator {
                         {modify_uri}
 
                         let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, &request_uri);
+                        request.set_operation(\"{operation_name}\");{set_raw_response}{set_signing_algorithm}
 
                         {set_headers}
                         {set_parameters}
@@ -58,6 +71,9 @@ impl GenerateProtocol for RestXmlGenerator {
                     ",
                      documentation = generate_documentation(operation, service),
                      http_method = &operation.http.method,
+                     operation_name = &operation.name,
+                     set_signing_algorithm = generate_signing_algorithm(service),
+                     set_raw_response = generate_raw_response(service, operation),
                      endpoint_prefix = service.endpoint_prefix(),
                      method_signature = generate_method_signature(operation_name, operation, service),
                      error_type = error_type_name(service, operation_name),
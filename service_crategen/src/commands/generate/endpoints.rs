@@ -0,0 +1,208 @@
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::botocore::{
+    EndpointDefinition, EndpointVariant, EndpointsDefinition, Partition, ServiceEndpoints,
+};
+
+const DEFAULT_HOSTNAME: &str = "{service}.{region}.{dnsSuffix}";
+
+/// Writes the endpoint resolution table of `rusoto_signature` from the botocore `endpoints.json`.
+///
+/// Only the services whose endpoints differ from the hostname template of their partition are
+/// written, along with the endpoints of these services setting a hostname, a signing region or
+/// variants. Deprecated endpoints, like `fips-us-east-1`, are left out.
+pub fn generate_endpoints(out_path: &Path) {
+    let endpoints = EndpointsDefinition::load().unwrap_or_else(|err| {
+        panic!("Failed to load endpoints.json: {}. Make sure the botocore submodule has been initialized!", err)
+    });
+
+    println!("Generating endpoints @ {}...", out_path.display());
+
+    let mut out = BufWriter::new(
+        OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(out_path)
+            .expect("Unable to write the endpoints"),
+    );
+
+    // The table is too large for rustfmt, so it is written with one entry per line.
+    let partitions: Vec<String> = endpoints
+        .partitions
+        .iter()
+        .map(|partition| generate_partition(partition, 4))
+        .collect();
+    writeln!(
+        out,
+        "// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+use super::{{EndpointData, Partition, ServiceEndpoints, Variant}};
+
+#[rustfmt::skip]
+pub(super) static PARTITIONS: &[Partition] = {partitions};",
+        partitions = generate_list(&partitions, 0)
+    )
+    .expect("Unable to write the endpoints");
+}
+
+fn generate_list(items: &[String], indent: usize) -> String {
+    if items.is_empty() {
+        return "&[]".to_owned();
+    }
+    let mut list = "&[\n".to_owned();
+    for item in items {
+        list.push_str(&format!("{:indent$}{},\n", "", item, indent = indent + 4));
+    }
+    list.push_str(&format!("{:indent$}]", "", indent = indent));
+    list
+}
+
+fn generate_partition(partition: &Partition, indent: usize) -> String {
+    let hostname = partition
+        .defaults
+        .hostname
+        .as_deref()
+        .unwrap_or(DEFAULT_HOSTNAME);
+    let quoted = |values: &mut dyn Iterator<Item = &String>| -> Vec<String> {
+        values.map(|value| format!("{:?}", value)).collect()
+    };
+    let services: Vec<String> = partition
+        .services
+        .iter()
+        .filter_map(|(name, service)| generate_service(name, service, indent + 8))
+        .collect();
+
+    let pad = " ".repeat(indent);
+    format!(
+        "Partition {{
+{pad}    id: {id:?},
+{pad}    dns_suffix: {dns_suffix:?},
+{pad}    hostname: {hostname:?},
+{pad}    region_prefixes: &[{region_prefixes}],
+{pad}    regions: {regions},
+{pad}    variants: {variants},
+{pad}    services: {services},
+{pad}}}",
+        pad = pad,
+        id = partition.partition,
+        dns_suffix = partition.dns_suffix,
+        hostname = hostname,
+        region_prefixes = quoted(&mut region_prefixes(&partition.region_regex).iter()).join(", "),
+        regions = generate_list(&quoted(&mut partition.regions.keys()), indent + 4),
+        variants = generate_variants(&partition.defaults.variants, indent + 4),
+        services = generate_list(&services, indent + 4),
+    )
+}
+
+fn generate_service(name: &str, service: &ServiceEndpoints, indent: usize) -> Option<String> {
+    let is_regionalized = service.is_regionalized.unwrap_or(true);
+    let partition_endpoint = service
+        .partition_endpoint
+        .as_ref()
+        .filter(|_| !is_regionalized);
+    let endpoints: Vec<String> = service
+        .endpoints
+        .iter()
+        .filter(|(_, endpoint)| !endpoint.deprecated)
+        .filter_map(|(region, endpoint)| {
+            match generate_endpoint(endpoint, indent + 8) {
+                Some(endpoint) => Some(endpoint),
+                None if Some(region) == partition_endpoint => {
+                    Some("EndpointData::DEFAULT".to_owned())
+                }
+                None => None,
+            }
+            .map(|endpoint| format!("({:?}, {})", region, endpoint))
+        })
+        .collect();
+    let defaults = generate_endpoint(&service.defaults, indent + 4);
+    if endpoints.is_empty() && defaults.is_none() && partition_endpoint.is_none() {
+        return None;
+    }
+
+    let pad = " ".repeat(indent);
+    Some(format!(
+        "({name:?}, ServiceEndpoints {{
+{pad}    partition_endpoint: {partition_endpoint:?},
+{pad}    is_regionalized: {is_regionalized},
+{pad}    defaults: {defaults},
+{pad}    endpoints: {endpoints},
+{pad}}})",
+        pad = pad,
+        name = name,
+        partition_endpoint = partition_endpoint,
+        is_regionalized = is_regionalized,
+        defaults = defaults.unwrap_or_else(|| "EndpointData::DEFAULT".to_owned()),
+        endpoints = generate_list(&endpoints, indent + 4),
+    ))
+}
+
+fn generate_endpoint(endpoint: &EndpointDefinition, indent: usize) -> Option<String> {
+    let credential_scope_region = endpoint
+        .credential_scope
+        .as_ref()
+        .and_then(|scope| scope.region.as_ref());
+    if endpoint.hostname.is_none()
+        && credential_scope_region.is_none()
+        && endpoint.variants.is_empty()
+    {
+        return None;
+    }
+    Some(format!(
+        "EndpointData {{ hostname: {:?}, credential_scope_region: {:?}, variants: {} }}",
+        endpoint.hostname,
+        credential_scope_region,
+        generate_variants(&endpoint.variants, indent)
+    ))
+}
+
+fn generate_variants(variants: &[EndpointVariant], indent: usize) -> String {
+    let variants: Vec<String> = variants
+        .iter()
+        .map(|variant| {
+            format!(
+                "Variant {{ fips: {}, dualstack: {}, hostname: {:?}, dns_suffix: {:?} }}",
+                variant.tags.iter().any(|tag| tag == "fips"),
+                variant.tags.iter().any(|tag| tag == "dualstack"),
+                variant.hostname,
+                variant.dns_suffix
+            )
+        })
+        .collect();
+    generate_list(&variants, indent)
+}
+
+/// Extracts the region prefixes of a partition from its region regex, which botocore writes
+/// as `^(us|eu|ap)\-\w+\-\d+$`, `^us\-gov\-\w+\-\d+$` or `^eusc\-(de)\-\w+\-\d+$`.
+fn region_prefixes(region_regex: &str) -> Vec<String> {
+    let prefixes = region_regex
+        .strip_prefix('^')
+        .and_then(|regex| regex.strip_suffix(r"\-\w+\-\d+$"))
+        .unwrap_or_else(|| panic!("Unsupported region regex: {}", region_regex));
+    let (head, alternatives) = match prefixes.find('(') {
+        Some(start) => (
+            &prefixes[..start],
+            prefixes[start + 1..]
+                .strip_suffix(')')
+                .unwrap_or_else(|| panic!("Unsupported region regex: {}", region_regex)),
+        ),
+        None => ("", prefixes),
+    };
+    alternatives
+        .split('|')
+        .map(|prefix| format!("{}{}", head, prefix).replace(r"\-", "-"))
+        .collect()
+}
@@ -8,6 +8,9 @@ use rayon::prelude::*;
 use toml;
 
 mod codegen;
+mod endpoints;
+
+pub use self::endpoints::generate_endpoints;
 
 use crate::cargo;
 use crate::{Service, ServiceConfig, ServiceDefinition};
@@ -85,9 +88,11 @@ pub fn generate_services(
         }
 
         let mut features = BTreeMap::new();
+        features.insert("blocking".into(), vec!["rusoto_core/blocking".into()]);
         features.insert("default".into(), vec!["native-tls".into()]);
         features.insert("native-tls".into(), vec!["rusoto_core/native-tls".into()]);
         features.insert("rustls".into(), vec!["rusoto_core/rustls".into()]);
+        features.insert("tracing".into(), vec!["rusoto_core/tracing".into()]);
 
         let mut serialize_feature_dependencies = vec!["bytes/serde".into()];
 
@@ -198,6 +203,8 @@ To use `{crate_name}` in your application, add it as a dependency in your `Cargo
 - `rustls` - use rustls TLS implementation.
 - `serialize_structs` - output structs of most operations get `derive(Serialize)`.
 - `deserialize_structs` - input structs of most operations get `derive(Deserialize)`.
+- `tracing` - emit a `tracing` span for every operation call.
+- `blocking` - add a `{blocking_client_name}` with the same methods as `{client_name}`, waiting for the result of every call. Its outputs are those of `{client_name}`, with streaming bodies read with `ByteStream::into_blocking_read`.
 
 Note: the crate will use the `native-tls` TLS implementation by default.
 
@@ -220,6 +227,8 @@ See [LICENSE][license] for details.
         "##,
                  short_name = service.service_type_name(),
                  aws_name = service.full_name(),
+                 client_name = service.client_type_name(),
+                 blocking_client_name = service.blocking_client_type_name(),
                  crate_name = crate_name,
                  version = service_config.version
         ).expect("Couldn't write README for crate");
@@ -53,6 +53,15 @@ fn main() {
                         .required(false),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("endpoints").arg(
+                Arg::with_name("out_file")
+                    .long("outfile")
+                    .short("o")
+                    .takes_value(true)
+                    .required(true),
+            ),
+        )
         .get_matches();
 
     if let Some(matches) = matches.subcommand_matches("check") {
@@ -75,4 +84,10 @@ fn main() {
 
         commands::generate::generate_services(&service_configs, out_dir, service.as_ref());
     }
+
+    if let Some(matches) = matches.subcommand_matches("endpoints") {
+        let out_file = Path::new(matches.value_of("out_file").unwrap());
+
+        commands::generate::generate_endpoints(out_file);
+    }
 }
@@ -46,6 +46,10 @@ impl<'b> Service<'b> {
         format!("{}Client", self.service_type_name())
     }
 
+    pub fn blocking_client_type_name(&self) -> String {
+        format!("{}BlockingClient", self.service_type_name())
+    }
+
     pub fn service_type_name(&self) -> &str {
         &self.config.base_type_name
     }
@@ -93,6 +97,10 @@ impl<'b> Service<'b> {
             .map(|shape| shape.shape_type)
     }
 
+    pub fn signature_version(&self) -> &str {
+        &self.definition.metadata.signature_version
+    }
+
     pub fn signing_name(&self) -> String {
         match self.definition.metadata.signing_name {
             Some(ref signing_name) => signing_name.to_string(),